@@ -0,0 +1,656 @@
+//! Platform-neutral engine core behind the JavaScript bindings.
+
+use dystrail_game::state::DayIntent;
+use dystrail_game::{
+    BossConfig, CrossingChoice, CrossingConfig, DayOutcome, EncounterData, EndgameTravelCfg,
+    GameState, Graveyard, JourneySession, MechanicalPolicyId, OTDELUXE_PARTY_SIZE,
+    OtDeluxe90sPolicy, OtDeluxeCrossingMethod, OtDeluxeOccupation, OtDeluxePartyRole,
+    OtDeluxeRouteDecision, OtDeluxeStartConfig, OtDeluxeStoreLineItem, StrategyId,
+    can_afford_bribe, can_use_permit, encode_friendly, otdeluxe_crossing_options, parse_share_code,
+    run_boss_minigame,
+};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+const GAME_JSON: &str = include_str!("../../dystrail-web/static/assets/data/game.json");
+const CROSSINGS_JSON: &str = include_str!("../../dystrail-web/static/assets/data/crossings.json");
+
+#[derive(Debug, thiserror::Error)]
+pub enum EngineError {
+    #[error("Invalid share code: {0:?}")]
+    InvalidShareCode(String),
+    #[error("Invalid {field}: {value:?}")]
+    InvalidArgument { field: &'static str, value: String },
+    #[error("Action {action} is not available during the {phase} phase")]
+    WrongPhase { action: &'static str, phase: Phase },
+    #[error("Option {0} is not available")]
+    Unavailable(String),
+    #[error("Incompatible save: {0}")]
+    IncompatibleSave(String),
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+/// Rule set a session runs under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Mechanics {
+    #[default]
+    Dystrail,
+    Otdeluxe,
+}
+
+impl From<Mechanics> for MechanicalPolicyId {
+    fn from(value: Mechanics) -> Self {
+        match value {
+            Mechanics::Dystrail => Self::DystrailLegacy,
+            Mechanics::Otdeluxe => Self::OtDeluxe90s,
+        }
+    }
+}
+
+/// Decision the session is waiting on, in the same priority order as the web client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Phase {
+    Travel,
+    Encounter,
+    Crossing,
+    RoutePrompt,
+    Store,
+    Boss,
+    Result,
+}
+
+impl Phase {
+    #[must_use]
+    pub fn of(state: &GameState) -> Self {
+        let dystrail = state.mechanical_policy == MechanicalPolicyId::DystrailLegacy;
+        let otdeluxe = state.mechanical_policy == MechanicalPolicyId::OtDeluxe90s;
+        if state.ending.is_some() || state.stats.pants >= 100 {
+            Self::Result
+        } else if state.ot_deluxe.route.pending_prompt.is_some() {
+            Self::RoutePrompt
+        } else if (otdeluxe && state.ot_deluxe.crossing.choice_pending)
+            || (dystrail && state.pending_crossing.is_some())
+        {
+            Self::Crossing
+        } else if otdeluxe && state.ot_deluxe.store.pending_node.is_some() {
+            Self::Store
+        } else if state.current_encounter.is_some() {
+            Self::Encounter
+        } else if dystrail && state.boss.readiness.ready && !state.boss.outcome.attempted {
+            Self::Boss
+        } else {
+            Self::Travel
+        }
+    }
+}
+
+impl std::fmt::Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = serde_json::to_value(self)
+            .ok()
+            .and_then(|value| value.as_str().map(str::to_owned))
+            .unwrap_or_default();
+        f.write_str(&name)
+    }
+}
+
+/// Summary of one simulated day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DayReport {
+    pub day: u32,
+    pub ended: bool,
+    pub day_consumed: bool,
+    pub log_key: String,
+    pub event_keys: Vec<String>,
+    pub phase: Phase,
+}
+
+/// The encounter awaiting a choice.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncounterView {
+    pub id: String,
+    pub name: String,
+    pub desc: String,
+    pub choices: Vec<String>,
+}
+
+/// Choices made before an `OTDeluxe` run leaves Independence.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct OtDeluxeSetup {
+    #[serde(default)]
+    pub occupation: Option<OtDeluxeOccupation>,
+    /// Give the leader the occupation's skill unless their role names one.
+    #[serde(default)]
+    pub occupation_skill: bool,
+    /// Age group and skill per member, leader first; missing slots stay neutral.
+    #[serde(default)]
+    pub party: Vec<OtDeluxePartyRole>,
+}
+
+impl OtDeluxeSetup {
+    fn start_config(&self) -> Result<OtDeluxeStartConfig, EngineError> {
+        if self.party.len() > OTDELUXE_PARTY_SIZE {
+            return Err(EngineError::InvalidArgument {
+                field: "party",
+                value: format!("{} members", self.party.len()),
+            });
+        }
+        let occupation = self
+            .occupation
+            .unwrap_or(OtDeluxeStartConfig::default().occupation);
+        let start = self.party.iter().enumerate().fold(
+            OtDeluxeStartConfig::new(occupation),
+            |start, (slot, role)| start.with_role(slot, *role),
+        );
+        if self.occupation_skill {
+            return Ok(start.with_occupation_skill(&OtDeluxe90sPolicy::default()));
+        }
+        Ok(start)
+    }
+}
+
+/// A headless run driven one decision at a time.
+#[derive(Debug, Clone)]
+pub struct Engine {
+    session: JourneySession,
+    crossings: CrossingConfig,
+    boss: BossConfig,
+    share_code: String,
+}
+
+impl Engine {
+    /// Start a new run from a share code such as `CL-ORANGE42`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EngineError::InvalidShareCode`] if the code cannot be decoded.
+    pub fn from_share_code(code: &str, mechanics: Mechanics) -> Result<Self, EngineError> {
+        let start = (mechanics == Mechanics::Otdeluxe).then(OtDeluxeStartConfig::default);
+        Self::start(code, mechanics, start)
+    }
+
+    /// Start an `OTDeluxe` run with a chosen occupation and party.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the code cannot be decoded or the party has too many members.
+    pub fn from_otdeluxe_setup(code: &str, setup: &OtDeluxeSetup) -> Result<Self, EngineError> {
+        Self::start(code, Mechanics::Otdeluxe, Some(setup.start_config()?))
+    }
+
+    fn start(
+        code: &str,
+        mechanics: Mechanics,
+        start: Option<OtDeluxeStartConfig>,
+    ) -> Result<Self, EngineError> {
+        let (mode, seed) = parse_share_code(code)
+            .ok_or_else(|| EngineError::InvalidShareCode(code.to_string()))?;
+        let data = EncounterData::from_json(GAME_JSON)?;
+        let endgame = EndgameTravelCfg::load_from_static();
+        let session = JourneySession::new_with_mechanics(
+            mechanics.into(),
+            mode,
+            StrategyId::Balanced,
+            seed,
+            data,
+            &endgame,
+            start,
+        );
+        Ok(Self {
+            session,
+            crossings: serde_json::from_str(CROSSINGS_JSON)?,
+            boss: BossConfig::load_from_static(),
+            share_code: code.trim().to_ascii_uppercase(),
+        })
+    }
+
+    /// Resume a run from [`Engine::export_save`] output.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is malformed or was written by an incompatible version.
+    pub fn import_save(json: &str) -> Result<Self, EngineError> {
+        let saved: GameState = serde_json::from_str(json)?;
+        let data = EncounterData::from_json(GAME_JSON)?;
+        let state = saved
+            .rehydrate(data)
+            .map_err(|err| EngineError::IncompatibleSave(err.to_string()))?;
+        let endgame = EndgameTravelCfg::load_from_static();
+        let share_code = encode_friendly(state.mode.is_deep(), state.seed);
+        let strategy = state.policy.map_or(StrategyId::Balanced, StrategyId::from);
+        let session = JourneySession::from_state(state, strategy, &endgame);
+        Ok(Self {
+            session,
+            crossings: serde_json::from_str(CROSSINGS_JSON)?,
+            boss: BossConfig::load_from_static(),
+            share_code,
+        })
+    }
+
+    /// Serialize the run in the same format the web client saves.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn export_save(&self) -> Result<String, EngineError> {
+        Ok(serde_json::to_string(self.session.state())?)
+    }
+
+    #[must_use]
+    pub fn share_code(&self) -> &str {
+        &self.share_code
+    }
+
+    #[must_use]
+    pub fn state(&self) -> &GameState {
+        self.session.state()
+    }
+
+    #[must_use]
+    pub fn phase(&self) -> Phase {
+        Phase::of(self.session.state())
+    }
+
+    #[must_use]
+    pub fn encounter(&self) -> Option<EncounterView> {
+        self.session
+            .state()
+            .current_encounter
+            .as_ref()
+            .map(|enc| EncounterView {
+                id: enc.id.clone(),
+                name: enc.name.clone(),
+                desc: enc.desc.clone(),
+                choices: enc
+                    .choices
+                    .iter()
+                    .map(|choice| choice.label.clone())
+                    .collect(),
+            })
+    }
+
+    /// Advance one day with the given intent (`continue`, `rest`, `hunt`, ...).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for unknown intents or when a decision is pending.
+    pub fn tick(&mut self, intent: &str) -> Result<DayReport, EngineError> {
+        let intent: DayIntent = parse_choice("intent", intent)?;
+        if intent == DayIntent::CrossingChoicePending {
+            return Err(EngineError::InvalidArgument {
+                field: "intent",
+                value: String::from("crossing_choice_pending"),
+            });
+        }
+        self.expect_phase("tick", Phase::Travel)?;
+        self.session.with_state_mut(|gs| gs.intent.pending = intent);
+        Ok(self.advance())
+    }
+
+    /// Pick option `index` of the pending encounter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when no encounter is pending or the index is out of range.
+    pub fn choose_encounter_option(&mut self, index: usize) -> Result<Phase, EngineError> {
+        self.expect_phase("chooseEncounterOption", Phase::Encounter)?;
+        let choices = self
+            .session
+            .state()
+            .current_encounter
+            .as_ref()
+            .map_or(0, |enc| enc.choices.len());
+        if index >= choices {
+            return Err(EngineError::Unavailable(index.to_string()));
+        }
+        self.session.with_state_mut(|gs| gs.apply_choice(index));
+        Ok(self.phase())
+    }
+
+    /// Resolve the pending crossing and advance the day.
+    ///
+    /// Dystrail checkpoints take `detour`, `bribe` or `permit`; OTDeluxe rivers take
+    /// `ford`, `caulk_float`, `ferry` or `guide`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when no crossing is pending or the option is unavailable.
+    pub fn resolve_crossing(&mut self, choice: &str) -> Result<DayReport, EngineError> {
+        self.expect_phase("resolveCrossing", Phase::Crossing)?;
+        let gs = self.session.state();
+        if gs.mechanical_policy == MechanicalPolicyId::OtDeluxe90s {
+            let method: OtDeluxeCrossingMethod = parse_choice("crossing choice", choice)?;
+            let crossing = &gs.ot_deluxe.crossing;
+            let allowed = match (crossing.river_kind, crossing.river.as_ref()) {
+                (Some(kind), Some(river)) => otdeluxe_crossing_options(
+                    &OtDeluxe90sPolicy::default().crossings,
+                    kind,
+                    river,
+                    &gs.ot_deluxe.inventory,
+                )
+                .is_allowed(method),
+                _ => false,
+            };
+            if !allowed {
+                return Err(EngineError::Unavailable(choice.to_string()));
+            }
+            self.session
+                .with_state_mut(|gs| gs.set_otdeluxe_crossing_choice(method));
+        } else {
+            let choice_id: CrossingChoice = parse_choice("crossing choice", choice)?;
+            let allowed = gs.pending_crossing.is_some_and(|pending| match choice_id {
+                CrossingChoice::Detour => true,
+                CrossingChoice::Bribe => can_afford_bribe(gs, &self.crossings, pending.kind),
+                CrossingChoice::Permit => can_use_permit(gs, &pending.kind),
+            });
+            if !allowed {
+                return Err(EngineError::Unavailable(choice.to_string()));
+            }
+            self.session
+                .with_state_mut(|gs| gs.set_crossing_choice(choice_id));
+        }
+        Ok(self.advance())
+    }
+
+    /// Answer an OTDeluxe route prompt and advance the day.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when no prompt is pending or the decision is unknown.
+    pub fn resolve_route_prompt(&mut self, decision: &str) -> Result<DayReport, EngineError> {
+        self.expect_phase("resolveRoutePrompt", Phase::RoutePrompt)?;
+        let decision: OtDeluxeRouteDecision = parse_choice("route decision", decision)?;
+        self.session
+            .with_state_mut(|gs| gs.set_route_prompt_choice(decision));
+        Ok(self.advance())
+    }
+
+    /// Buy supplies at a pending OTDeluxe store (empty to leave) and advance the day.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when no store is pending or the purchase is rejected.
+    pub fn purchase_supplies(
+        &mut self,
+        lines: Vec<OtDeluxeStoreLineItem>,
+    ) -> Result<DayReport, EngineError> {
+        self.expect_phase("purchaseSupplies", Phase::Store)?;
+        if !self
+            .session
+            .with_state_mut(|gs| gs.set_otdeluxe_store_purchase(lines))
+        {
+            return Err(EngineError::Unavailable(String::from("purchase")));
+        }
+        Ok(self.advance())
+    }
+
+    /// Play the final boss once it is ready.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the boss is not ready.
+    pub fn fight_boss(&mut self) -> Result<Phase, EngineError> {
+        self.expect_phase("fightBoss", Phase::Boss)?;
+        let boss = &self.boss;
+        self.session
+            .with_state_mut(|gs| run_boss_minigame(gs, boss));
+        Ok(self.phase())
+    }
+
+    /// Surface graves from earlier runs along the trail; the host keeps the
+    /// graveyard between runs since saves do not carry it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graveyard JSON is malformed.
+    pub fn load_graveyard(&mut self, json: &str) -> Result<(), EngineError> {
+        let graveyard = Graveyard::from_json(json)?;
+        self.session
+            .with_state_mut(|gs| gs.set_trail_graves(&graveyard));
+        Ok(())
+    }
+
+    /// Bury this run's dead in `graveyard` (or a new graveyard) and return it as JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graveyard JSON is malformed.
+    pub fn bury_run(&self, graveyard: Option<&str>) -> Result<String, EngineError> {
+        let mut graveyard = graveyard
+            .map(Graveyard::from_json)
+            .transpose()?
+            .unwrap_or_default();
+        graveyard.bury_run(self.session.state(), &self.share_code);
+        Ok(graveyard.to_json()?)
+    }
+
+    /// Carve an epitaph for a dead party member; blank text leaves the grave bare.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the member has not died.
+    pub fn set_epitaph(&mut self, member_index: usize, epitaph: &str) -> Result<(), EngineError> {
+        if self
+            .session
+            .with_state_mut(|gs| gs.ot_deluxe.set_epitaph(member_index, epitaph))
+        {
+            Ok(())
+        } else {
+            Err(EngineError::Unavailable(member_index.to_string()))
+        }
+    }
+
+    fn expect_phase(&self, action: &'static str, expected: Phase) -> Result<(), EngineError> {
+        let phase = self.phase();
+        if phase == expected {
+            Ok(())
+        } else {
+            Err(EngineError::WrongPhase { action, phase })
+        }
+    }
+
+    fn advance(&mut self) -> DayReport {
+        let outcome = self.session.tick_day();
+        self.report(&outcome)
+    }
+
+    fn report(&self, outcome: &DayOutcome) -> DayReport {
+        DayReport {
+            day: self.session.state().day,
+            ended: outcome.ended,
+            day_consumed: outcome.day_consumed,
+            log_key: outcome.log_key.clone(),
+            event_keys: outcome
+                .events
+                .iter()
+                .filter_map(|event| event.ui_key.clone())
+                .collect(),
+            phase: self.phase(),
+        }
+    }
+}
+
+fn parse_choice<T: DeserializeOwned>(field: &'static str, value: &str) -> Result<T, EngineError> {
+    serde_json::from_value(serde_json::Value::String(value.to_string())).map_err(|_| {
+        EngineError::InvalidArgument {
+            field,
+            value: value.to_string(),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dystrail_game::{OtDeluxeAgeGroup, OtDeluxeSkill, OtDeluxeStoreItem};
+
+    fn play(engine: &mut Engine) -> Vec<DayReport> {
+        let mut reports = Vec::new();
+        for _ in 0..2_000 {
+            let report = match engine.phase() {
+                Phase::Result => return reports,
+                Phase::Travel => engine.tick("continue").unwrap(),
+                Phase::Encounter => {
+                    engine.choose_encounter_option(0).unwrap();
+                    continue;
+                }
+                Phase::Crossing => ["detour", "ford", "caulk_float", "ferry", "guide"]
+                    .into_iter()
+                    .find_map(|choice| engine.resolve_crossing(choice).ok())
+                    .expect("some crossing option is available"),
+                Phase::RoutePrompt => engine
+                    .resolve_route_prompt("stay_on_trail")
+                    .or_else(|_| engine.resolve_route_prompt("barlow_road"))
+                    .unwrap(),
+                Phase::Store => {
+                    let lines = if engine.state().ot_deluxe.oxen.healthy == 0 {
+                        vec![
+                            OtDeluxeStoreLineItem {
+                                item: OtDeluxeStoreItem::Oxen,
+                                quantity: 4,
+                            },
+                            OtDeluxeStoreLineItem {
+                                item: OtDeluxeStoreItem::FoodLb,
+                                quantity: 400,
+                            },
+                        ]
+                    } else {
+                        Vec::new()
+                    };
+                    engine.purchase_supplies(lines).unwrap()
+                }
+                Phase::Boss => {
+                    engine.fight_boss().unwrap();
+                    continue;
+                }
+            };
+            reports.push(report);
+        }
+        panic!("run did not finish");
+    }
+
+    #[test]
+    fn share_codes_produce_deterministic_runs() {
+        let mut first = Engine::from_share_code("CL-ORANGE42", Mechanics::Dystrail).unwrap();
+        let mut second = Engine::from_share_code("cl-orange42", Mechanics::Dystrail).unwrap();
+        assert_eq!(first.share_code(), "CL-ORANGE42");
+        let first_reports = play(&mut first);
+        assert_eq!(first_reports, play(&mut second));
+        assert_eq!(first.export_save().unwrap(), second.export_save().unwrap());
+        assert!(first_reports.len() > 1);
+    }
+
+    #[test]
+    fn otdeluxe_runs_start_at_the_store() {
+        let mut engine = Engine::from_share_code("CL-ORANGE42", Mechanics::Otdeluxe).unwrap();
+        assert_eq!(engine.phase(), Phase::Store);
+        assert!(matches!(
+            engine.tick("continue"),
+            Err(EngineError::WrongPhase {
+                phase: Phase::Store,
+                ..
+            })
+        ));
+        play(&mut engine);
+        assert!(engine.state().ot_deluxe.miles_traveled > 0.0);
+    }
+
+    #[test]
+    fn otdeluxe_setup_assigns_the_party() {
+        let setup: OtDeluxeSetup = serde_json::from_str(
+            r#"{"occupation":"farmer","occupation_skill":true,"party":[{"age_group":"elder"},{"age_group":"adult","skill":"medic"}]}"#,
+        )
+        .unwrap();
+        let engine = Engine::from_otdeluxe_setup("CL-ORANGE42", &setup).unwrap();
+        let members = &engine.state().ot_deluxe.party.members;
+        assert_eq!(members[0].age_group, Some(OtDeluxeAgeGroup::Elder));
+        assert_eq!(members[0].skill, Some(OtDeluxeSkill::Hunter));
+        assert_eq!(members[1].skill, Some(OtDeluxeSkill::Medic));
+        assert_eq!(members[4].age_group, None);
+        assert_eq!(members[4].skill, None);
+
+        let plain = OtDeluxeSetup {
+            occupation: Some(OtDeluxeOccupation::Farmer),
+            ..OtDeluxeSetup::default()
+        };
+        let engine = Engine::from_otdeluxe_setup("CL-ORANGE42", &plain).unwrap();
+        assert_eq!(engine.state().ot_deluxe.party.members[0].skill, None);
+
+        let crowded = OtDeluxeSetup {
+            party: vec![OtDeluxePartyRole::new(OtDeluxeAgeGroup::Adult); 6],
+            ..OtDeluxeSetup::default()
+        };
+        assert!(matches!(
+            Engine::from_otdeluxe_setup("CL-ORANGE42", &crowded),
+            Err(EngineError::InvalidArgument { field: "party", .. })
+        ));
+    }
+
+    #[test]
+    fn saves_round_trip_mid_run() {
+        let mut engine = Engine::from_share_code("DP-ORANGE42", Mechanics::Dystrail).unwrap();
+        for _ in 0..5 {
+            if engine.phase() == Phase::Travel {
+                engine.tick("continue").unwrap();
+            }
+        }
+        let save = engine.export_save().unwrap();
+        let mut resumed = Engine::import_save(&save).unwrap();
+        assert_eq!(resumed.share_code(), engine.share_code());
+        assert_eq!(resumed.state().day, engine.state().day);
+        assert_eq!(resumed.state().stats, engine.state().stats);
+        assert_eq!(resumed.phase(), engine.phase());
+        assert!(!play(&mut resumed).is_empty());
+    }
+
+    #[test]
+    fn graveyards_pass_between_runs_through_the_host() {
+        let mut first = Engine::from_share_code("CL-ORANGE42", Mechanics::Otdeluxe).unwrap();
+        first.session.with_state_mut(|gs| {
+            gs.ot_deluxe.miles_traveled = 30.0;
+            gs.ot_deluxe.record_death(
+                0,
+                dystrail_game::OtDeluxeDeathCause::Lost,
+                &dystrail_game::OtDeluxeSkillPolicy::default(),
+            );
+        });
+        first.set_epitaph(0, "Gone ahead").unwrap();
+        assert!(first.set_epitaph(3, "Not yet").is_err());
+        let graveyard = first.bury_run(None).unwrap();
+
+        let mut second = Engine::from_share_code("CL-ORANGE43", Mechanics::Otdeluxe).unwrap();
+        second.load_graveyard(&graveyard).unwrap();
+        let graves = &second.state().trail_graves;
+        assert_eq!(graves.len(), 1);
+        assert_eq!(graves[0].epitaph.as_deref(), Some("Gone ahead"));
+        assert_eq!(graves[0].share_code.as_deref(), Some("CL-ORANGE42"));
+        assert_eq!(second.bury_run(Some(&graveyard)).unwrap(), graveyard);
+        assert!(second.load_graveyard("[").is_err());
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        assert!(matches!(
+            Engine::from_share_code("nonsense", Mechanics::Dystrail),
+            Err(EngineError::InvalidShareCode(_))
+        ));
+        let mut engine = Engine::from_share_code("CL-ORANGE42", Mechanics::Dystrail).unwrap();
+        assert!(matches!(
+            engine.tick("sprint"),
+            Err(EngineError::InvalidArgument {
+                field: "intent",
+                ..
+            })
+        ));
+        assert!(matches!(
+            engine.choose_encounter_option(0),
+            Err(EngineError::WrongPhase { .. })
+        ));
+        assert!(Engine::import_save("{}").is_err());
+    }
+}
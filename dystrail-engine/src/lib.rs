@@ -0,0 +1,258 @@
+//! Headless Dystrail engine for JavaScript and TypeScript.
+//!
+//! Build with `wasm-pack build dystrail-engine --target bundler` (or `--target nodejs`);
+//! the generated package ships `dystrail_engine.d.ts` with the typings below.
+
+pub mod engine;
+
+pub use engine::{DayReport, EncounterView, Engine, EngineError, Mechanics, OtDeluxeSetup, Phase};
+
+use dystrail_game::OtDeluxeStoreLineItem;
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_TYPES: &'static str = r#"
+export type Mechanics = "dystrail" | "otdeluxe";
+export type Phase = "travel" | "encounter" | "crossing" | "route_prompt" | "store" | "boss" | "result";
+export type DayIntent = "continue" | "rest" | "trade" | "hunt" | "fish" | "forage";
+export type CrossingChoice = "detour" | "bribe" | "permit" | "ford" | "caulk_float" | "ferry" | "guide";
+export type RouteDecision = "stay_on_trail" | "sublette_cutoff" | "dalles_shortcut" | "raft_columbia" | "barlow_road";
+export type StoreItem = "oxen" | "clothes_set" | "ammo_box" | "food_lb" | "wheel" | "axle" | "tongue" | "medicine";
+
+export type Occupation = "banker" | "doctor" | "merchant" | "blacksmith" | "carpenter" | "saddlemaker" | "farmer" | "teacher";
+export type AgeGroup = "child" | "adult" | "elder";
+export type Skill = "hunter" | "medic" | "carpenter" | "navigator";
+
+export interface PartyRole {
+  age_group?: AgeGroup;
+  skill?: Skill;
+}
+
+export interface OtDeluxeSetup {
+  occupation?: Occupation;
+  /** Give the leader the occupation's skill unless their role names one. */
+  occupation_skill?: boolean;
+  /** Leader first, up to five members; missing slots stay neutral. */
+  party?: PartyRole[];
+}
+
+export interface StoreLine {
+  item: StoreItem;
+  quantity: number;
+}
+
+export interface DayReport {
+  day: number;
+  ended: boolean;
+  dayConsumed: boolean;
+  logKey: string;
+  eventKeys: string[];
+  phase: Phase;
+}
+
+export interface EncounterView {
+  id: string;
+  name: string;
+  desc: string;
+  choices: string[];
+}
+
+/** Serialized `GameState`; the shape follows the save format of the web client. */
+export type GameStateJson = Record<string, unknown> & {
+  seed: number;
+  day: number;
+  mode: "Classic" | "Deep";
+};
+"#;
+
+fn js_error(err: &EngineError) -> JsError {
+    JsError::new(&err.to_string())
+}
+
+fn to_js<T: Serialize>(value: &T) -> Result<JsValue, JsError> {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|err| JsError::new(&err.to_string()))
+}
+
+/// A Dystrail run exposed to JavaScript.
+#[wasm_bindgen(js_name = DystrailEngine)]
+pub struct JsEngine {
+    inner: Engine,
+}
+
+#[wasm_bindgen(js_class = DystrailEngine)]
+impl JsEngine {
+    /// Start a run from a share code such as `"CL-ORANGE42"`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        share_code: &str,
+        #[wasm_bindgen(unchecked_param_type = "Mechanics | undefined")] mechanics: Option<String>,
+    ) -> Result<Self, JsError> {
+        let mechanics = match mechanics.as_deref() {
+            None => Mechanics::default(),
+            Some("dystrail") => Mechanics::Dystrail,
+            Some("otdeluxe") => Mechanics::Otdeluxe,
+            Some(other) => {
+                return Err(js_error(&EngineError::InvalidArgument {
+                    field: "mechanics",
+                    value: other.to_string(),
+                }));
+            }
+        };
+        Engine::from_share_code(share_code, mechanics)
+            .map(|inner| Self { inner })
+            .map_err(|err| js_error(&err))
+    }
+
+    /// Start an Oregon Trail Deluxe run with a chosen occupation and party.
+    #[wasm_bindgen(js_name = startOtdeluxe)]
+    pub fn start_otdeluxe(
+        share_code: &str,
+        #[wasm_bindgen(unchecked_param_type = "OtDeluxeSetup")] setup: JsValue,
+    ) -> Result<Self, JsError> {
+        let setup: OtDeluxeSetup =
+            serde_wasm_bindgen::from_value(setup).map_err(|err| JsError::new(&err.to_string()))?;
+        Engine::from_otdeluxe_setup(share_code, &setup)
+            .map(|inner| Self { inner })
+            .map_err(|err| js_error(&err))
+    }
+
+    /// Resume a run from `exportSave()` output.
+    #[wasm_bindgen(js_name = importSave)]
+    pub fn import_save(json: &str) -> Result<Self, JsError> {
+        Engine::import_save(json)
+            .map(|inner| Self { inner })
+            .map_err(|err| js_error(&err))
+    }
+
+    #[wasm_bindgen(js_name = exportSave)]
+    pub fn export_save(&self) -> Result<String, JsError> {
+        self.inner.export_save().map_err(|err| js_error(&err))
+    }
+
+    #[wasm_bindgen(getter, js_name = shareCode)]
+    pub fn share_code(&self) -> String {
+        self.inner.share_code().to_string()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn day(&self) -> u32 {
+        self.inner.state().day
+    }
+
+    #[wasm_bindgen(getter, unchecked_return_type = "Phase")]
+    pub fn phase(&self) -> String {
+        self.inner.phase().to_string()
+    }
+
+    /// The full game state as a JSON string.
+    #[wasm_bindgen(js_name = stateJson)]
+    pub fn state_json(&self) -> Result<String, JsError> {
+        self.export_save()
+    }
+
+    /// The full game state as a plain object.
+    #[wasm_bindgen(unchecked_return_type = "GameStateJson")]
+    pub fn state(&self) -> Result<JsValue, JsError> {
+        to_js(self.inner.state())
+    }
+
+    #[wasm_bindgen(unchecked_return_type = "EncounterView | undefined")]
+    pub fn encounter(&self) -> Result<JsValue, JsError> {
+        self.inner
+            .encounter()
+            .map_or(Ok(JsValue::UNDEFINED), |view| to_js(&view))
+    }
+
+    /// Advance one day; `intent` defaults to `"continue"`.
+    #[wasm_bindgen(unchecked_return_type = "DayReport")]
+    pub fn tick(
+        &mut self,
+        #[wasm_bindgen(unchecked_param_type = "DayIntent | undefined")] intent: Option<String>,
+    ) -> Result<JsValue, JsError> {
+        let report = self
+            .inner
+            .tick(intent.as_deref().unwrap_or("continue"))
+            .map_err(|err| js_error(&err))?;
+        to_js(&report)
+    }
+
+    #[wasm_bindgen(js_name = chooseEncounterOption, unchecked_return_type = "Phase")]
+    pub fn choose_encounter_option(&mut self, index: usize) -> Result<String, JsError> {
+        self.inner
+            .choose_encounter_option(index)
+            .map(|phase| phase.to_string())
+            .map_err(|err| js_error(&err))
+    }
+
+    #[wasm_bindgen(js_name = resolveCrossing, unchecked_return_type = "DayReport")]
+    pub fn resolve_crossing(
+        &mut self,
+        #[wasm_bindgen(unchecked_param_type = "CrossingChoice")] choice: &str,
+    ) -> Result<JsValue, JsError> {
+        let report = self
+            .inner
+            .resolve_crossing(choice)
+            .map_err(|err| js_error(&err))?;
+        to_js(&report)
+    }
+
+    #[wasm_bindgen(js_name = resolveRoutePrompt, unchecked_return_type = "DayReport")]
+    pub fn resolve_route_prompt(
+        &mut self,
+        #[wasm_bindgen(unchecked_param_type = "RouteDecision")] decision: &str,
+    ) -> Result<JsValue, JsError> {
+        let report = self
+            .inner
+            .resolve_route_prompt(decision)
+            .map_err(|err| js_error(&err))?;
+        to_js(&report)
+    }
+
+    #[wasm_bindgen(js_name = purchaseSupplies, unchecked_return_type = "DayReport")]
+    pub fn purchase_supplies(
+        &mut self,
+        #[wasm_bindgen(unchecked_param_type = "StoreLine[]")] lines: JsValue,
+    ) -> Result<JsValue, JsError> {
+        let lines: Vec<OtDeluxeStoreLineItem> =
+            serde_wasm_bindgen::from_value(lines).map_err(|err| JsError::new(&err.to_string()))?;
+        let report = self
+            .inner
+            .purchase_supplies(lines)
+            .map_err(|err| js_error(&err))?;
+        to_js(&report)
+    }
+
+    /// Surface graves from earlier runs; pass the JSON returned by `buryRun`.
+    #[wasm_bindgen(js_name = loadGraveyard)]
+    pub fn load_graveyard(&mut self, json: &str) -> Result<(), JsError> {
+        self.inner
+            .load_graveyard(json)
+            .map_err(|err| js_error(&err))
+    }
+
+    /// Bury this run's dead in the given graveyard JSON and return the updated graveyard.
+    #[wasm_bindgen(js_name = buryRun)]
+    pub fn bury_run(&self, graveyard: Option<String>) -> Result<String, JsError> {
+        self.inner
+            .bury_run(graveyard.as_deref())
+            .map_err(|err| js_error(&err))
+    }
+
+    #[wasm_bindgen(js_name = setEpitaph)]
+    pub fn set_epitaph(&mut self, member_index: usize, epitaph: &str) -> Result<(), JsError> {
+        self.inner
+            .set_epitaph(member_index, epitaph)
+            .map_err(|err| js_error(&err))
+    }
+
+    #[wasm_bindgen(js_name = fightBoss, unchecked_return_type = "Phase")]
+    pub fn fight_boss(&mut self) -> Result<String, JsError> {
+        self.inner
+            .fight_boss()
+            .map(|phase| phase.to_string())
+            .map_err(|err| js_error(&err))
+    }
+}
@@ -0,0 +1,22 @@
+#![no_main]
+
+use std::sync::OnceLock;
+
+use dystrail_game::{EncounterData, GameState};
+use libfuzzer_sys::fuzz_target;
+
+fn encounter_data() -> &'static EncounterData {
+    static DATA: OnceLock<EncounterData> = OnceLock::new();
+    DATA.get_or_init(|| {
+        EncounterData::from_json(include_str!(
+            "../../../dystrail-web/static/assets/data/game.json"
+        ))
+        .expect("game data parses")
+    })
+}
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(state) = serde_json::from_slice::<GameState>(data) {
+        let _ = state.rehydrate(encounter_data().clone());
+    }
+});
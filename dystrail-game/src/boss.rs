@@ -182,7 +182,7 @@ mod tests {
     use crate::data::EncounterData;
     use crate::journey::RngBundle;
     use crate::state::{GameMode, PolicyKind};
-    use std::rc::Rc;
+    use std::sync::Arc;
 
     #[test]
     fn run_boss_probability_branches_cover_edges() {
@@ -194,7 +194,7 @@ mod tests {
         fail_state.stats.credibility = 0;
         fail_state.stats.allies = 0;
         fail_state.stats.sanity = 6;
-        fail_state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(17)));
+        fail_state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(17)));
         let mut fail_cfg = BossConfig::load_from_static();
         fail_cfg.rounds = 0;
         fail_cfg.distance_required = 5_000.0;
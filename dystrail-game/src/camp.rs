@@ -2,8 +2,10 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::constants::VEHICLE_JURY_RIG_HEAL;
 use crate::endgame::EndgameTravelCfg;
 use crate::journey::{DailyTickKernel, resolve_cfg_for_state};
+use crate::vehicle::VehicleConfig;
 use crate::{Stats, TravelDayKind, numbers::round_f64_to_i32};
 
 const DEFAULT_CAMP_DATA: &str = include_str!("../../dystrail-web/static/assets/data/camp.json");
@@ -111,7 +113,8 @@ pub fn camp_rest_with_endgame(
     let max_hp = Stats::default().hp;
     let max_sanity = Stats::default().sanity;
     let journey_cfg = resolve_cfg_for_state(gs);
-    let kernel = DailyTickKernel::new(&journey_cfg, endgame_cfg);
+    let pipeline = gs.day_pipeline();
+    let kernel = DailyTickKernel::with_pipeline(&journey_cfg, endgame_cfg, &pipeline);
     for day_idx in 0..rest_days {
         let apply_effects = day_idx == 0;
         kernel.tick_non_travel_day_with_hook(gs, TravelDayKind::NonTravel, 0.0, "camp", |state| {
@@ -198,7 +201,8 @@ pub fn camp_forage_with_endgame(
 
     let forage_days = forage_cfg.day.max(1);
     let journey_cfg = resolve_cfg_for_state(gs);
-    let kernel = DailyTickKernel::new(&journey_cfg, endgame_cfg);
+    let pipeline = gs.day_pipeline();
+    let kernel = DailyTickKernel::with_pipeline(&journey_cfg, endgame_cfg, &pipeline);
     for day_idx in 0..forage_days {
         let apply_forage = day_idx == 0 && supplies_delta != 0;
         kernel.tick_non_travel_day_with_hook(gs, TravelDayKind::NonTravel, 0.0, "camp", |state| {
@@ -226,23 +230,74 @@ pub fn camp_therapy(_gs: &mut crate::GameState, _cfg: &CampConfig) -> CampOutcom
     }
 }
 
+/// Fit a spare for the broken part, charging `vehicle.json` repair costs.
 pub fn camp_repair_spare(
-    _gs: &mut crate::GameState,
+    gs: &mut crate::GameState,
     _cfg: &CampConfig,
-    _part: crate::vehicle::Part,
+    part: crate::vehicle::Part,
 ) -> CampOutcome {
+    let mut supplies_delta = 0;
+    if gs.breakdown.as_ref().is_some_and(|b| b.part == part) {
+        if !gs.consume_spare_for_part(part) {
+            return CampOutcome {
+                message: String::from("log.camp.repair.no_spare"),
+                rested: false,
+                supplies_delta: 0,
+            };
+        }
+        let costs = &VehicleConfig::default_config().repair_costs;
+        let cost = costs
+            .use_spare_supplies
+            .max(0)
+            .min(gs.stats.supplies.max(0));
+        gs.stats.supplies -= cost;
+        supplies_delta = -cost;
+        gs.vehicle.repair(VEHICLE_JURY_RIG_HEAL);
+        gs.clear_breakdown("camp_spare");
+        gs.push_log("log.camp.repair");
+    }
     CampOutcome {
         message: String::from("log.camp.repair"),
         rested: false,
-        supplies_delta: 0,
+        supplies_delta,
     }
 }
 
-pub fn camp_repair_hack(_gs: &mut crate::GameState, _cfg: &CampConfig) -> CampOutcome {
+/// Patch the vehicle without a spare, paying supplies, credibility and days.
+pub fn camp_repair_hack(gs: &mut crate::GameState, cfg: &CampConfig) -> CampOutcome {
+    let endgame_cfg = EndgameTravelCfg::default_config();
+    camp_repair_hack_with_endgame(gs, cfg, &endgame_cfg)
+}
+
+pub fn camp_repair_hack_with_endgame(
+    gs: &mut crate::GameState,
+    _cfg: &CampConfig,
+    endgame_cfg: &EndgameTravelCfg,
+) -> CampOutcome {
+    if gs.breakdown.is_none() {
+        return CampOutcome {
+            message: String::from("log.camp.repair.hack"),
+            rested: false,
+            supplies_delta: 0,
+        };
+    }
+    let costs = VehicleConfig::default_config().repair_costs.clone();
+    let cost = costs.hack_supplies.max(0).min(gs.stats.supplies.max(0));
+    gs.stats.supplies -= cost;
+    gs.stats.credibility -= costs.hack_cred.max(0);
+    gs.stats.clamp();
+    gs.clear_breakdown("camp_hack");
+    let journey_cfg = resolve_cfg_for_state(gs);
+    let pipeline = gs.day_pipeline();
+    let kernel = DailyTickKernel::with_pipeline(&journey_cfg, endgame_cfg, &pipeline);
+    for _ in 0..costs.hack_day {
+        kernel.tick_non_travel_day_with_hook(gs, TravelDayKind::NonTravel, 0.0, "repair", |_| {});
+    }
+    gs.push_log("log.camp.repair.hack");
     CampOutcome {
         message: String::from("log.camp.repair.hack"),
         rested: false,
-        supplies_delta: 0,
+        supplies_delta: -cost,
     }
 }
 
@@ -263,6 +318,43 @@ mod tests {
     use super::*;
     use crate::state::{Region, Stats};
 
+    #[test]
+    fn repair_actions_charge_vehicle_costs() {
+        let costs = VehicleConfig::default_config().repair_costs.clone();
+        let cfg = CampConfig::default();
+        let broken = |part| crate::GameState {
+            breakdown: Some(crate::vehicle::Breakdown {
+                part,
+                day_started: 0,
+            }),
+            stats: Stats {
+                supplies: 10,
+                ..Stats::default()
+            },
+            ..crate::GameState::default()
+        };
+
+        let mut state = broken(crate::vehicle::Part::Tire);
+        let missing = camp_repair_spare(&mut state, &cfg, crate::vehicle::Part::Tire);
+        assert_eq!(missing.message, "log.camp.repair.no_spare");
+        assert!(state.breakdown.is_some());
+
+        state.inventory.spares.tire = 1;
+        let outcome = camp_repair_spare(&mut state, &cfg, crate::vehicle::Part::Tire);
+        assert_eq!(outcome.supplies_delta, -costs.use_spare_supplies);
+        assert_eq!(state.stats.supplies, 10 - costs.use_spare_supplies);
+        assert!(state.breakdown.is_none());
+
+        let mut state = broken(crate::vehicle::Part::Battery);
+        let cred_before = state.stats.credibility;
+        let day_before = state.day;
+        let outcome = camp_repair_hack(&mut state, &cfg);
+        assert_eq!(outcome.supplies_delta, -costs.hack_supplies);
+        assert_eq!(state.stats.credibility, cred_before - costs.hack_cred);
+        assert_eq!(state.day, day_before + costs.hack_day);
+        assert!(state.breakdown.is_none());
+    }
+
     #[test]
     fn forage_negative_supplies_apply_region_multiplier() {
         let mut cfg = CampConfig::default();
@@ -15,6 +15,8 @@ pub(crate) const LOG_TRAVEL_BLOCKED: &str = "log.travel-blocked";
 pub(crate) const LOG_TRAVELED: &str = "log.traveled";
 pub(crate) const LOG_TRADE: &str = "log.trade";
 pub(crate) const LOG_HUNT: &str = "log.hunt";
+pub(crate) const LOG_FISH: &str = "log.fish";
+pub(crate) const LOG_FORAGE: &str = "log.forage";
 pub(crate) const LOG_STORE: &str = "log.store";
 pub(crate) const LOG_EXEC_START_PREFIX: &str = "exec.start.";
 pub(crate) const LOG_EXEC_END_PREFIX: &str = "exec.end.";
@@ -44,6 +46,9 @@ pub(crate) const LOG_OT_CROSSING_WET: &str = "log.crossing.ot.wet";
 pub(crate) const LOG_OT_CROSSING_TIPPED: &str = "log.crossing.ot.tipped";
 pub(crate) const LOG_OT_CROSSING_SANK: &str = "log.crossing.ot.sank";
 pub(crate) const LOG_OT_CROSSING_DROWNED: &str = "log.crossing.ot.drowned";
+pub(crate) const LOG_OT_TOMBSTONE: &str = "log.otdeluxe.tombstone";
+pub(crate) const LOG_OT_OX_EXHAUSTED: &str = "log.otdeluxe.ox_exhausted";
+pub(crate) const LOG_OT_OX_STARVED: &str = "log.otdeluxe.ox_starved";
 pub(crate) const LOG_TRAVEL_PARTIAL: &str = "log.travel.partial";
 pub(crate) const LOG_TRAVEL_REST_CREDIT: &str = "log.travel.rest-credit";
 pub(crate) const LOG_TRAVEL_DELAY_CREDIT: &str = "log.travel.delay-credit";
@@ -95,6 +100,7 @@ pub(crate) const ENCOUNTER_SOFT_CAP_THRESHOLD: u32 = 5;
 pub(crate) const ENCOUNTER_HISTORY_WINDOW: usize = 10;
 pub(crate) const MAX_ENCOUNTERS_PER_DAY: u8 = 2;
 pub(crate) const ENCOUNTER_RECENT_MEMORY: usize = 8;
+pub(crate) const ENCOUNTER_LOG_LIMIT: usize = 200;
 pub(crate) const ENCOUNTER_REPEAT_WINDOW_DAYS: u32 = 6;
 pub(crate) const ENCOUNTER_EXTENDED_MEMORY_DAYS: u32 = ENCOUNTER_REPEAT_WINDOW_DAYS * 2;
 pub(crate) const ENCOUNTER_REROLL_PENALTY: f32 = 0.8;
@@ -173,6 +179,7 @@ pub(crate) const DISEASE_LOW_HP_BONUS: f32 = 0.01;
 pub(crate) const DISEASE_MAX_DAILY_CHANCE: f32 = 0.18;
 pub(crate) const DISEASE_TICK_HP_LOSS: i32 = 1;
 pub(crate) const DISEASE_TICK_SANITY_LOSS: i32 = 1;
+pub(crate) const MEDICINE_ILLNESS_DAYS_DIVISOR: u32 = 2;
 
 // Starvation tuning --------------------------------------------------------
 pub(crate) const STARVATION_BASE_HP_LOSS: i32 = 1;
@@ -397,12 +397,12 @@ mod tests {
     use super::*;
     use crate::journey::{RngBundle, TravelDayKind};
     use crate::state::{GameState, Region, Season};
-    use std::rc::Rc;
+    use std::sync::Arc;
 
     #[test]
     fn apply_detour_records_partial_day() {
         let mut state = GameState::default();
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(5)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(5)));
         let cfg = CrossingConfig::default();
         let baseline_records = state.day_records.len();
 
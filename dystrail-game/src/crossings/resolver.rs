@@ -3,7 +3,7 @@ use std::convert::TryFrom;
 
 use super::CrossingKind;
 use crate::journey::{
-    BribePolicy, CrossingPolicy, EventDecisionTrace, RollValue, WeightedCandidate,
+    BribePolicy, CrossingPolicy, EventDecisionTrace, RollScale, RollValue, WeightedCandidate,
 };
 use crate::numbers::clamp_f64_to_f32;
 
@@ -107,6 +107,7 @@ pub fn resolve_crossing_with_trace<R: RngCore>(
     let trace = EventDecisionTrace {
         pool_id: pool_id.to_string(),
         roll: RollValue::F32(draw),
+        roll_scale: RollScale::Final,
         candidates,
         chosen_id: chosen_id.to_string(),
     };
@@ -5,9 +5,10 @@ use crate::constants::{
 use crate::journey::{DayRecord, TravelDayKind};
 use crate::numbers::clamp_f64_to_f32;
 use crate::state::{GameState, PolicyKind, TravelProgressKind};
+use serde::{Deserialize, Serialize};
 
 /// Aggregate metrics derived from recorded day history.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct DayLedgerMetrics {
     pub total_days: u32,
     pub travel_days: u32,
@@ -66,6 +67,19 @@ pub fn compute_day_ledger_metrics(records: &[DayRecord]) -> DayLedgerMetrics {
     metrics
 }
 
+/// Cumulative miles at the end of each recorded day, in ledger order.
+#[must_use]
+pub fn cumulative_miles_curve(records: &[DayRecord]) -> Vec<f32> {
+    let mut total = 0.0_f32;
+    records
+        .iter()
+        .map(|record| {
+            total += record.miles;
+            total
+        })
+        .collect()
+}
+
 fn compute_ratio(numerator: u32, denominator: u32) -> f32 {
     if denominator == 0 {
         return 1.0;
@@ -290,6 +304,17 @@ mod tests {
         let _ = record_travel_day(&mut state, TravelDayKind::Travel, 1.0);
     }
 
+    #[test]
+    fn cumulative_miles_curve_accumulates_in_order() {
+        let records = vec![
+            DayRecord::new(0, TravelDayKind::Travel, 12.0),
+            DayRecord::new(1, TravelDayKind::NonTravel, 0.0),
+            DayRecord::new(2, TravelDayKind::Partial, 4.5),
+        ];
+        assert_eq!(cumulative_miles_curve(&records), vec![12.0, 12.0, 16.5]);
+        assert!(cumulative_miles_curve(&[]).is_empty());
+    }
+
     #[test]
     fn apply_endgame_wear_shave_ignores_invalid_ratio() {
         let mut state = fresh_state();
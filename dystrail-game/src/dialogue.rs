@@ -0,0 +1,329 @@
+//! Landmark "talk to people" dialogue.
+//!
+//! Each landmark carries a list of NPC lines gated on what the party faces
+//! next: the depth of the upcoming river, party health, the season and any
+//! route prompt ahead. Talking never advances the day and draws from its own
+//! seed-derived RNG, so it cannot perturb the simulation streams.
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+use crate::journey::{MechanicalPolicyId, derive_stream_seed};
+use crate::otdeluxe_state::{OtDeluxeRiver, OtDeluxeRoutePrompt};
+use crate::state::{GameState, HealthLabel, Region, Season};
+
+const DEFAULT_DIALOGUE_DATA: &str =
+    include_str!("../../dystrail-web/static/assets/data/dialogue.json");
+
+const fn default_weight() -> u32 {
+    1
+}
+
+const fn default_lines_per_talk() -> u8 {
+    2
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DialogueCatalog {
+    /// Maximum number of lines returned by a single talk.
+    #[serde(default = "default_lines_per_talk")]
+    pub lines_per_talk: u8,
+    #[serde(default)]
+    pub landmarks: Vec<DialogueLandmark>,
+}
+
+impl Default for DialogueCatalog {
+    fn default() -> Self {
+        Self {
+            lines_per_talk: default_lines_per_talk(),
+            landmarks: Vec::new(),
+        }
+    }
+}
+
+impl DialogueCatalog {
+    #[must_use]
+    pub fn load_from_static() -> Self {
+        serde_json::from_str(DEFAULT_DIALOGUE_DATA).unwrap_or_default()
+    }
+
+    /// Landmark the party is currently standing at, if it has anyone to talk to.
+    #[must_use]
+    pub fn landmark_for(&self, state: &GameState) -> Option<&DialogueLandmark> {
+        self.landmarks.iter().find(|landmark| {
+            landmark.mechanics == state.mechanical_policy
+                && match state.mechanical_policy {
+                    MechanicalPolicyId::OtDeluxe90s => {
+                        landmark.node == Some(state.ot_deluxe.route.current_node_index)
+                    }
+                    MechanicalPolicyId::DystrailLegacy => landmark.region == Some(state.region),
+                }
+        })
+    }
+}
+
+/// People to talk to at one landmark.
+///
+/// `OTDeluxe` landmarks are keyed by trail node; Dystrail landmarks by region.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DialogueLandmark {
+    pub id: String,
+    pub mechanics: MechanicalPolicyId,
+    #[serde(default)]
+    pub node: Option<u8>,
+    #[serde(default)]
+    pub region: Option<Region>,
+    #[serde(default)]
+    pub lines: Vec<DialogueLine>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DialogueLine {
+    pub id: String,
+    /// i18n key; may interpolate `{depth}` with the upcoming river depth.
+    pub key: String,
+    #[serde(default = "default_weight")]
+    pub weight: u32,
+    #[serde(default)]
+    pub when: DialogueCondition,
+}
+
+/// Conditions a line needs to be eligible; empty fields always match.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DialogueCondition {
+    #[serde(default)]
+    pub river_depth_min_ft: Option<f32>,
+    #[serde(default)]
+    pub river_depth_max_ft: Option<f32>,
+    #[serde(default)]
+    pub health: Vec<HealthLabel>,
+    #[serde(default)]
+    pub seasons: Vec<Season>,
+    #[serde(default)]
+    pub upcoming_prompt: Option<OtDeluxeRoutePrompt>,
+}
+
+impl DialogueCondition {
+    #[must_use]
+    pub fn matches(&self, context: &DialogueContext) -> bool {
+        let depth_ok = match (self.river_depth_min_ft, self.river_depth_max_ft) {
+            (None, None) => true,
+            (min, max) => context.river_depth_ft.is_some_and(|depth| {
+                min.is_none_or(|min| depth >= min) && max.is_none_or(|max| depth <= max)
+            }),
+        };
+        depth_ok
+            && (self.health.is_empty() || self.health.contains(&context.health))
+            && (self.seasons.is_empty() || self.seasons.contains(&context.season))
+            && self
+                .upcoming_prompt
+                .is_none_or(|prompt| context.upcoming_prompt == Some(prompt))
+    }
+}
+
+/// What the people at a landmark know about the party's situation.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DialogueContext {
+    /// Next river still to be crossed.
+    pub river: Option<OtDeluxeRiver>,
+    pub river_depth_ft: Option<f32>,
+    pub health: HealthLabel,
+    pub season: Season,
+    pub upcoming_prompt: Option<OtDeluxeRoutePrompt>,
+}
+
+impl DialogueContext {
+    #[must_use]
+    pub fn from_state(state: &GameState) -> Self {
+        let health = state.party_health_label();
+        let season = state.weather_season();
+        if state.mechanical_policy != MechanicalPolicyId::OtDeluxe90s {
+            return Self {
+                river: None,
+                river_depth_ft: None,
+                health,
+                season,
+                upcoming_prompt: None,
+            };
+        }
+        let upcoming = state.otdeluxe_upcoming_river();
+        let upcoming_prompt = state.ot_deluxe.route.pending_prompt.or_else(|| {
+            state
+                .otdeluxe_next_prompt_marker()
+                .map(|(prompt, _)| prompt)
+        });
+        Self {
+            river: upcoming.as_ref().map(|(river, _)| *river),
+            river_depth_ft: upcoming.map(|(_, river_state)| river_state.depth_ft),
+            health,
+            season,
+            upcoming_prompt,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TalkLine {
+    pub id: String,
+    pub key: String,
+}
+
+/// Lines heard while talking to people at a landmark.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TalkOutcome {
+    pub landmark_id: String,
+    pub lines: Vec<TalkLine>,
+    pub context: DialogueContext,
+}
+
+#[must_use]
+pub fn catalog() -> &'static DialogueCatalog {
+    static CATALOG: OnceLock<DialogueCatalog> = OnceLock::new();
+    CATALOG.get_or_init(DialogueCatalog::load_from_static)
+}
+
+/// Pick the lines heard at the current landmark.
+///
+/// Selection is a weighted draw without replacement over eligible lines,
+/// seeded from the run seed, day and landmark so repeated talks on the same
+/// day return the same lines. Returns `None` when nobody is here to talk to.
+#[must_use]
+pub fn talk(catalog: &DialogueCatalog, state: &GameState) -> Option<TalkOutcome> {
+    let landmark = catalog.landmark_for(state)?;
+    let context = DialogueContext::from_state(state);
+    let mut eligible: Vec<&DialogueLine> = landmark
+        .lines
+        .iter()
+        .filter(|line| line.weight > 0 && line.when.matches(&context))
+        .collect();
+    let domain = format!("dialogue:{}:{}", landmark.id, state.day);
+    let mut rng = SmallRng::seed_from_u64(derive_stream_seed(state.seed, domain.as_bytes()));
+    let mut lines = Vec::new();
+    while lines.len() < usize::from(catalog.lines_per_talk) && !eligible.is_empty() {
+        let total: u32 = eligible.iter().map(|line| line.weight).sum();
+        let mut roll = rng.gen_range(0..total);
+        let idx = eligible
+            .iter()
+            .position(|line| {
+                if roll < line.weight {
+                    true
+                } else {
+                    roll -= line.weight;
+                    false
+                }
+            })
+            .unwrap_or(0);
+        let line = eligible.remove(idx);
+        lines.push(TalkLine {
+            id: line.id.clone(),
+            key: line.key.clone(),
+        });
+    }
+    Some(TalkOutcome {
+        landmark_id: landmark.id.clone(),
+        lines,
+        context,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn otdeluxe_state_at(node: u8) -> GameState {
+        let mut state = GameState {
+            mechanical_policy: MechanicalPolicyId::OtDeluxe90s,
+            seed: 0xD1A1,
+            day: 12,
+            ..GameState::default()
+        };
+        state.ot_deluxe.route.current_node_index = node;
+        state
+    }
+
+    #[test]
+    fn static_catalog_lines_resolve_to_english_strings() {
+        let catalog = DialogueCatalog::load_from_static();
+        assert!(!catalog.landmarks.is_empty());
+        let en: serde_json::Value =
+            serde_json::from_str(include_str!("../../dystrail-web/i18n/en.json")).unwrap();
+        for line in catalog
+            .landmarks
+            .iter()
+            .flat_map(|landmark| &landmark.lines)
+        {
+            let pointer = format!("/{}", line.key.replace('.', "/"));
+            assert!(en.pointer(&pointer).is_some(), "missing {}", line.key);
+        }
+    }
+
+    #[test]
+    fn talk_is_deterministic_and_leaves_the_day_alone() {
+        let catalog = DialogueCatalog::load_from_static();
+        let state = otdeluxe_state_at(0);
+        let first = talk(&catalog, &state).expect("Independence has townsfolk");
+        let second = talk(&catalog, &state).expect("Independence has townsfolk");
+        assert_eq!(first, second);
+        assert_eq!(state.day, 12);
+        assert_eq!(first.lines.len(), usize::from(catalog.lines_per_talk));
+        assert_eq!(first.context.river, Some(OtDeluxeRiver::Kansas));
+
+        assert!(talk(&catalog, &otdeluxe_state_at(6)).is_none());
+    }
+
+    #[test]
+    fn conditions_gate_lines_on_depth_health_and_prompts() {
+        let context = DialogueContext {
+            river: Some(OtDeluxeRiver::Green),
+            river_depth_ft: Some(6.0),
+            health: HealthLabel::Poor,
+            season: Season::Summer,
+            upcoming_prompt: Some(OtDeluxeRoutePrompt::SubletteCutoff),
+        };
+        let deep = DialogueCondition {
+            river_depth_min_ft: Some(4.0),
+            ..DialogueCondition::default()
+        };
+        assert!(deep.matches(&context));
+        let shallow = DialogueCondition {
+            river_depth_max_ft: Some(2.5),
+            ..DialogueCondition::default()
+        };
+        assert!(!shallow.matches(&context));
+        let sickly = DialogueCondition {
+            health: vec![HealthLabel::Poor, HealthLabel::VeryPoor],
+            seasons: vec![Season::Summer],
+            upcoming_prompt: Some(OtDeluxeRoutePrompt::SubletteCutoff),
+            ..DialogueCondition::default()
+        };
+        assert!(sickly.matches(&context));
+        let barlow = DialogueCondition {
+            upcoming_prompt: Some(OtDeluxeRoutePrompt::DallesFinal),
+            ..DialogueCondition::default()
+        };
+        assert!(!barlow.matches(&context));
+        let no_river = DialogueContext {
+            river_depth_ft: None,
+            ..context
+        };
+        assert!(!deep.matches(&no_river));
+    }
+
+    #[test]
+    fn dystrail_regions_reuse_the_catalog() {
+        let catalog = DialogueCatalog::load_from_static();
+        let state = GameState {
+            region: Region::Beltway,
+            ..GameState::default()
+        };
+        let outcome = talk(&catalog, &state).expect("the Beltway always has opinions");
+        assert!(
+            outcome
+                .lines
+                .iter()
+                .all(|line| line.key.starts_with("dialogue.dystrail."))
+        );
+        assert_eq!(outcome.context.river_depth_ft, None);
+    }
+}
@@ -2,7 +2,7 @@ use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::sync::OnceLock;
 
-use crate::journey::{EventDecisionTrace, RollValue, WeightFactor, WeightedCandidate};
+use crate::journey::{EventDecisionTrace, RollScale, RollValue, WeightFactor, WeightedCandidate};
 use crate::mechanics::otdeluxe90s::OtDeluxeAfflictionPolicy;
 use crate::weather::Weather;
 
@@ -26,6 +26,13 @@ impl DiseaseKind {
     }
 }
 
+/// Remedies a disease definition can declare as effective.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiseaseTreatment {
+    Medicine,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct DiseaseCatalog {
     #[serde(default)]
@@ -126,6 +133,11 @@ impl DiseaseCatalog {
         let trace = chosen.map(|disease| EventDecisionTrace {
             pool_id: format!("otdeluxe.affliction_disease.{}", kind.key()),
             roll: RollValue::U32(roll),
+            roll_scale: if uniform_fallback {
+                RollScale::Index
+            } else {
+                RollScale::Final
+            },
             candidates: weighted_candidates,
             chosen_id: disease.id.clone(),
         });
@@ -169,9 +181,17 @@ pub struct DiseaseDef {
     pub fatality_model: Option<FatalityModel>,
     #[serde(default)]
     pub tags: Vec<String>,
+    /// Treatments that shorten this disease; empty means none help.
+    #[serde(default)]
+    pub treatments: Vec<DiseaseTreatment>,
 }
 
 impl DiseaseDef {
+    #[must_use]
+    pub fn treatable_by(&self, treatment: DiseaseTreatment) -> bool {
+        self.treatments.contains(&treatment)
+    }
+
     #[must_use]
     pub fn duration_for(&self, policy: &OtDeluxeAfflictionPolicy) -> u8 {
         let duration = self.duration_days.unwrap_or(match self.kind {
@@ -238,6 +258,10 @@ pub enum FatalityModifier {
         weather: Weather,
         mult: f32,
     },
+    /// Applies only while the afflicted member is under treatment.
+    Treated {
+        mult: f32,
+    },
 }
 
 const fn default_weight() -> u16 {
@@ -294,6 +318,7 @@ mod tests {
                     daily_tick_effects: DiseaseEffects::default(),
                     fatality_model: None,
                     tags: Vec::new(),
+                    treatments: Vec::new(),
                 },
                 DiseaseDef {
                     id: "d2".into(),
@@ -305,6 +330,7 @@ mod tests {
                     daily_tick_effects: DiseaseEffects::default(),
                     fatality_model: None,
                     tags: Vec::new(),
+                    treatments: Vec::new(),
                 },
             ],
         };
@@ -330,6 +356,7 @@ mod tests {
                 daily_tick_effects: DiseaseEffects::default(),
                 fatality_model: None,
                 tags: Vec::new(),
+                treatments: Vec::new(),
             }],
         };
 
@@ -355,6 +382,7 @@ mod tests {
                     daily_tick_effects: DiseaseEffects::default(),
                     fatality_model: None,
                     tags: Vec::new(),
+                    treatments: Vec::new(),
                 },
                 DiseaseDef {
                     id: "d2".into(),
@@ -366,6 +394,7 @@ mod tests {
                     daily_tick_effects: DiseaseEffects::default(),
                     fatality_model: None,
                     tags: Vec::new(),
+                    treatments: Vec::new(),
                 },
             ],
         };
@@ -404,6 +433,7 @@ mod tests {
                     daily_tick_effects: DiseaseEffects::default(),
                     fatality_model: None,
                     tags: Vec::new(),
+                    treatments: Vec::new(),
                 },
                 DiseaseDef {
                     id: "weighted".into(),
@@ -415,6 +445,7 @@ mod tests {
                     daily_tick_effects: DiseaseEffects::default(),
                     fatality_model: None,
                     tags: Vec::new(),
+                    treatments: Vec::new(),
                 },
             ],
         };
@@ -438,6 +469,7 @@ mod tests {
             daily_tick_effects: DiseaseEffects::default(),
             fatality_model: None,
             tags: Vec::new(),
+            treatments: Vec::new(),
         };
         assert_eq!(
             def.duration_for(&policy.affliction),
@@ -452,6 +484,37 @@ mod tests {
         assert_eq!(injury.duration_for(&policy.affliction), 5);
     }
 
+    #[test]
+    fn treatment_lowers_fatality_in_data_and_default_models() {
+        use crate::kernel::systems::fatality::{
+            OtDeluxeFatalityContext, otdeluxe_fatality_probability,
+        };
+        use crate::mechanics::otdeluxe90s::{OtDeluxePace, OtDeluxeRations};
+
+        let policy = OtDeluxe90sPolicy::default();
+        let catalog = DiseaseCatalog::default_catalog();
+        let cholera = catalog.find_by_id("cholera").expect("cholera");
+        let measles = catalog.find_by_id("measles").expect("measles");
+        let cholera_model = cholera.fatality_model.as_ref().expect("cholera model");
+        assert!(measles.fatality_model.is_none());
+
+        let context = |treated| OtDeluxeFatalityContext {
+            health_general: 120,
+            pace: OtDeluxePace::Steady,
+            rations: OtDeluxeRations::Filling,
+            weather: Weather::Clear,
+            occupation: None,
+            treated,
+            age_group: None,
+        };
+        for model in [cholera_model, &policy.disease_fatality] {
+            let untreated = otdeluxe_fatality_probability(model, context(false), &policy);
+            let treated = otdeluxe_fatality_probability(model, context(true), &policy);
+            assert!(untreated > 0.0);
+            assert!(treated < untreated, "{treated} < {untreated}");
+        }
+    }
+
     #[test]
     fn disease_effects_defaults_are_stable() {
         let effects = DiseaseEffects::default();
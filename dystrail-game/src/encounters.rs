@@ -3,7 +3,9 @@
 use crate::constants::DEBUG_ENV_VAR;
 use crate::constants::{ENCOUNTER_REPEAT_WINDOW_DAYS, ROTATION_LOOKBACK_DAYS};
 use crate::data::{Encounter, EncounterData};
-use crate::journey::event::{EventDecisionTrace, RollValue, WeightFactor, WeightedCandidate};
+use crate::journey::event::{
+    EventDecisionTrace, RollScale, RollValue, WeightFactor, WeightedCandidate,
+};
 use crate::state::{PolicyKind, RecentEncounter, Region};
 use rand::Rng;
 use std::collections::{HashMap, VecDeque};
@@ -224,6 +226,7 @@ fn build_decision_trace(
     EventDecisionTrace {
         pool_id: String::from(POOL_ID),
         roll: RollValue::U32(roll),
+        roll_scale: RollScale::Final,
         candidates: weighted_candidates,
         chosen_id: chosen.id.clone(),
     }
@@ -0,0 +1,394 @@
+//! Fishing and foraging resolution for `OTDeluxe` parity scaffolding.
+//!
+//! Yields come from a data table keyed by season, terrain, weather and party
+//! health. Fishing additionally needs a river nearby and burns food as bait;
+//! foraging can tear a set of clothing in the brush.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::journey::{EventDecisionTrace, RollScale, RollValue, WeightFactor, WeightedCandidate};
+use crate::mechanics::otdeluxe90s::OtDeluxeTrailPolicy;
+use crate::numbers::round_f32_to_i32;
+use crate::otdeluxe_crossings;
+use crate::otdeluxe_state::{OtDeluxeRiver, OtDeluxeTerrain};
+use crate::otdeluxe_trail;
+use crate::state::{GameState, HealthLabel, Season};
+use crate::weather::Weather;
+
+const DEFAULT_FORAGING_DATA: &str =
+    include_str!("../../dystrail-web/static/assets/data/otdeluxe/foraging.json");
+
+const RIVERS: [OtDeluxeRiver; 4] = [
+    OtDeluxeRiver::Kansas,
+    OtDeluxeRiver::BigBlue,
+    OtDeluxeRiver::Green,
+    OtDeluxeRiver::Snake,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ForageKind {
+    Fish,
+    Forage,
+}
+
+impl ForageKind {
+    #[must_use]
+    pub const fn id(self) -> &'static str {
+        match self {
+            Self::Fish => "fish",
+            Self::Forage => "forage",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ForageBlockReason {
+    NoRiverNearby,
+    NoBait,
+    SevereWeather,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ForageOutcome {
+    Success {
+        kind: ForageKind,
+        food_gained_lbs: u16,
+        bait_spent_lbs: u16,
+        clothes_torn: u16,
+    },
+    Blocked {
+        kind: ForageKind,
+        reason: ForageBlockReason,
+    },
+}
+
+/// Yield table for one food-gathering activity.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ForageYieldTable {
+    pub base_lbs_min: u16,
+    pub base_lbs_max: u16,
+    /// Food spent as bait per attempt.
+    #[serde(default)]
+    pub bait_food_lbs: u16,
+    /// Chance that a set of clothing is torn during the attempt.
+    #[serde(default)]
+    pub clothes_wear_chance: f32,
+    /// Yield falls off linearly to zero this far from the nearest river;
+    /// `None` means rivers do not matter.
+    #[serde(default)]
+    pub river_range_miles: Option<f32>,
+    #[serde(default)]
+    pub seasons: HashMap<Season, f32>,
+    #[serde(default)]
+    pub terrain: HashMap<OtDeluxeTerrain, f32>,
+    #[serde(default)]
+    pub weather: HashMap<Weather, f32>,
+    #[serde(default)]
+    pub health: HashMap<HealthLabel, f32>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ForagingCatalog {
+    pub fish: ForageYieldTable,
+    pub forage: ForageYieldTable,
+}
+
+impl ForagingCatalog {
+    #[must_use]
+    pub fn load_from_static() -> Self {
+        serde_json::from_str(DEFAULT_FORAGING_DATA).unwrap_or_default()
+    }
+
+    #[must_use]
+    pub const fn table(&self, kind: ForageKind) -> &ForageYieldTable {
+        match kind {
+            ForageKind::Fish => &self.fish,
+            ForageKind::Forage => &self.forage,
+        }
+    }
+}
+
+#[must_use]
+pub fn catalog() -> &'static ForagingCatalog {
+    static CATALOG: OnceLock<ForagingCatalog> = OnceLock::new();
+    CATALOG.get_or_init(ForagingCatalog::load_from_static)
+}
+
+/// Miles from `miles_traveled` to the closest river crossing on the route.
+#[must_use]
+pub fn miles_to_nearest_river(trail: &OtDeluxeTrailPolicy, state: &GameState) -> Option<f32> {
+    let variant = state.ot_deluxe.route.variant;
+    RIVERS
+        .iter()
+        .filter_map(|river| {
+            let node = otdeluxe_crossings::node_index_for_river(*river);
+            otdeluxe_trail::mile_marker_for_node(trail, variant, node)
+        })
+        .map(|marker| (f32::from(marker) - state.ot_deluxe.miles_traveled).abs())
+        .min_by(f32::total_cmp)
+}
+
+/// Resolve a fishing or foraging attempt, returning its outcome and trace.
+pub fn resolve_forage_with_rng(
+    state: &mut GameState,
+    kind: ForageKind,
+    trail: &OtDeluxeTrailPolicy,
+    catalog: &ForagingCatalog,
+    rng: &mut impl Rng,
+) -> (ForageOutcome, Option<EventDecisionTrace>) {
+    resolve_forage_rolls(state, kind, trail, catalog, |table, can_tear| {
+        let min = table.base_lbs_min.min(table.base_lbs_max);
+        let base = rng.gen_range(min..=table.base_lbs_max);
+        let torn = can_tear && rng.r#gen::<f32>() < table.clothes_wear_chance;
+        (base, torn)
+    })
+}
+
+/// Resolve a fishing or foraging attempt without randomness: the yield is the
+/// table minimum and no clothing is torn.
+pub fn resolve_forage(
+    state: &mut GameState,
+    kind: ForageKind,
+    trail: &OtDeluxeTrailPolicy,
+    catalog: &ForagingCatalog,
+) -> (ForageOutcome, Option<EventDecisionTrace>) {
+    resolve_forage_rolls(state, kind, trail, catalog, |table, _| {
+        (table.base_lbs_min.min(table.base_lbs_max), false)
+    })
+}
+
+/// Shared resolution; `roll` returns the base yield and whether clothing
+/// tears, and is told whether a tear is possible at all.
+fn resolve_forage_rolls(
+    state: &mut GameState,
+    kind: ForageKind,
+    trail: &OtDeluxeTrailPolicy,
+    catalog: &ForagingCatalog,
+    roll: impl FnOnce(&ForageYieldTable, bool) -> (u16, bool),
+) -> (ForageOutcome, Option<EventDecisionTrace>) {
+    let table = catalog.table(kind);
+    let weather = state.weather_state.today;
+    let blocked = |reason| (ForageOutcome::Blocked { kind, reason }, None);
+    if matches!(weather, Weather::Storm | Weather::Smoke) {
+        return blocked(ForageBlockReason::SevereWeather);
+    }
+    let proximity = match table.river_range_miles {
+        Some(range) if range > 0.0 => {
+            let distance = miles_to_nearest_river(trail, state).unwrap_or(f32::INFINITY);
+            if distance >= range {
+                return blocked(ForageBlockReason::NoRiverNearby);
+            }
+            1.0 - distance / range
+        }
+        _ => 1.0,
+    };
+    if state.ot_deluxe.inventory.food_lbs < table.bait_food_lbs {
+        return blocked(ForageBlockReason::NoBait);
+    }
+
+    let factors = [
+        ("season", factor(&table.seasons, &state.ot_deluxe.season)),
+        ("terrain", factor(&table.terrain, &state.ot_deluxe.terrain)),
+        ("weather", factor(&table.weather, &weather)),
+        ("health", factor(&table.health, &state.party_health_label())),
+        ("river_proximity", proximity),
+    ];
+    let can_tear = table.clothes_wear_chance > 0.0 && state.ot_deluxe.inventory.clothes_sets > 0;
+    let (base, torn) = roll(table, can_tear);
+    let mult: f32 = factors.iter().map(|(_, value)| *value).product();
+    let food_gained = scale_lbs(base, mult);
+    let torn = u16::from(torn);
+
+    let inventory = &mut state.ot_deluxe.inventory;
+    inventory.food_lbs = inventory
+        .food_lbs
+        .saturating_sub(table.bait_food_lbs)
+        .saturating_add(food_gained);
+    inventory.clothes_sets = inventory.clothes_sets.saturating_sub(torn);
+
+    let trace = EventDecisionTrace {
+        pool_id: format!("otdeluxe.{}", kind.id()),
+        roll: RollValue::U32(u32::from(base)),
+        roll_scale: RollScale::Value,
+        candidates: vec![WeightedCandidate {
+            id: String::from("yield_lbs"),
+            base_weight: f64::from(base),
+            multipliers: factors
+                .iter()
+                .map(|(label, value)| WeightFactor {
+                    label: (*label).to_string(),
+                    value: f64::from(*value),
+                })
+                .collect(),
+            final_weight: f64::from(food_gained),
+        }],
+        chosen_id: String::from("yield_lbs"),
+    };
+    let outcome = ForageOutcome::Success {
+        kind,
+        food_gained_lbs: food_gained,
+        bait_spent_lbs: table.bait_food_lbs,
+        clothes_torn: torn,
+    };
+    (outcome, Some(trace))
+}
+
+fn factor<K: Eq + std::hash::Hash>(table: &HashMap<K, f32>, key: &K) -> f32 {
+    table
+        .get(key)
+        .copied()
+        .filter(|value| value.is_finite())
+        .map_or(1.0, |value| value.max(0.0))
+}
+
+fn scale_lbs(base: u16, mult: f32) -> u16 {
+    let scaled = round_f32_to_i32(f32::from(base) * mult).max(0);
+    u16::try_from(scaled).unwrap_or(u16::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mechanics::OtDeluxe90sPolicy;
+    use crate::otdeluxe_state::OtDeluxePartyState;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    fn state_at(miles: f32) -> GameState {
+        let mut state = GameState::default();
+        state.ot_deluxe.party = OtDeluxePartyState::from_names(["A", "B"]);
+        state.ot_deluxe.miles_traveled = miles;
+        state.ot_deluxe.inventory.food_lbs = 50;
+        state.ot_deluxe.inventory.clothes_sets = 4;
+        state
+    }
+
+    #[test]
+    fn fishing_needs_a_river_and_spends_bait() {
+        let policy = OtDeluxe90sPolicy::default();
+        let catalog = ForagingCatalog::load_from_static();
+        let mut rng = SmallRng::seed_from_u64(11);
+
+        let kansas = otdeluxe_trail::mile_marker_for_node(
+            &policy.trail,
+            state_at(0.0).ot_deluxe.route.variant,
+            otdeluxe_crossings::node_index_for_river(OtDeluxeRiver::Kansas),
+        )
+        .expect("kansas marker");
+        let mut near = state_at(f32::from(kansas));
+        let (outcome, trace) = resolve_forage_with_rng(
+            &mut near,
+            ForageKind::Fish,
+            &policy.trail,
+            &catalog,
+            &mut rng,
+        );
+        let ForageOutcome::Success {
+            food_gained_lbs,
+            bait_spent_lbs,
+            ..
+        } = outcome
+        else {
+            panic!("expected a catch at the Kansas River, got {outcome:?}");
+        };
+        assert_eq!(bait_spent_lbs, catalog.fish.bait_food_lbs);
+        assert_eq!(
+            near.ot_deluxe.inventory.food_lbs,
+            50 - bait_spent_lbs + food_gained_lbs
+        );
+        let trace = trace.expect("yield trace");
+        assert_eq!(trace.pool_id, "otdeluxe.fish");
+        assert!(
+            trace.candidates[0]
+                .multipliers
+                .iter()
+                .any(|f| f.label == "river_proximity")
+        );
+
+        let range = catalog.fish.river_range_miles.expect("fish river range");
+        let mut far = state_at(650.0);
+        let distance = miles_to_nearest_river(&policy.trail, &far).expect("rivers on route");
+        assert!(distance > range);
+        let (outcome, trace) = resolve_forage_with_rng(
+            &mut far,
+            ForageKind::Fish,
+            &policy.trail,
+            &catalog,
+            &mut rng,
+        );
+        assert_eq!(
+            outcome,
+            ForageOutcome::Blocked {
+                kind: ForageKind::Fish,
+                reason: ForageBlockReason::NoRiverNearby,
+            }
+        );
+        assert!(trace.is_none());
+        assert_eq!(far.ot_deluxe.inventory.food_lbs, 50);
+    }
+
+    #[test]
+    fn foraging_scales_with_season_and_blocks_in_storms() {
+        let policy = OtDeluxe90sPolicy::default();
+        let catalog = ForagingCatalog::load_from_static();
+        let summer = factor(&catalog.forage.seasons, &Season::Summer);
+        let winter = factor(&catalog.forage.seasons, &Season::Winter);
+        assert!(summer > winter);
+
+        let mut stormy = state_at(300.0);
+        stormy.weather_state.today = Weather::Storm;
+        let mut rng = SmallRng::seed_from_u64(5);
+        let (outcome, _) = resolve_forage_with_rng(
+            &mut stormy,
+            ForageKind::Forage,
+            &policy.trail,
+            &catalog,
+            &mut rng,
+        );
+        assert_eq!(
+            outcome,
+            ForageOutcome::Blocked {
+                kind: ForageKind::Forage,
+                reason: ForageBlockReason::SevereWeather,
+            }
+        );
+
+        let mut calm = state_at(300.0);
+        let (outcome, trace) = resolve_forage_with_rng(
+            &mut calm,
+            ForageKind::Forage,
+            &policy.trail,
+            &catalog,
+            &mut rng,
+        );
+        assert!(matches!(outcome, ForageOutcome::Success { .. }));
+        assert!(trace.is_some());
+    }
+
+    #[test]
+    fn rng_free_forage_yields_the_table_minimum_and_tears_nothing() {
+        let policy = OtDeluxe90sPolicy::default();
+        let catalog = ForagingCatalog::load_from_static();
+        let mut state = state_at(300.0);
+        let (first, trace) =
+            resolve_forage(&mut state, ForageKind::Forage, &policy.trail, &catalog);
+        let (second, _) = resolve_forage(&mut state, ForageKind::Forage, &policy.trail, &catalog);
+        assert_eq!(first, second);
+        let ForageOutcome::Success { clothes_torn, .. } = first else {
+            panic!("calm weather forage succeeds");
+        };
+        assert_eq!(clothes_torn, 0);
+        assert_eq!(state.ot_deluxe.inventory.clothes_sets, 4);
+        let trace = trace.expect("trace");
+        assert_eq!(
+            trace.roll,
+            RollValue::U32(u32::from(catalog.forage.base_lbs_min))
+        );
+    }
+}
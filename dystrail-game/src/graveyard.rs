@@ -0,0 +1,289 @@
+//! Trail graveyard persisted across runs.
+//!
+//! `OTDeluxe` party deaths are buried as [`Tombstone`]s when a run finishes.
+//! Platforms keep the [`Graveyard`] between runs and hand it to the next
+//! [`GameState`], which surfaces each grave as the wagon passes its mile.
+use crate::journey::MechanicalPolicyId;
+use crate::mechanics::otdeluxe90s::OtDeluxeTrailVariant;
+use crate::otdeluxe_state::{
+    OtDeluxeAfflictionKind, OtDeluxeCalendar, OtDeluxeDeathCause, OtDeluxeDeathRecord,
+};
+use crate::state::GameState;
+use serde::{Deserialize, Serialize};
+
+/// Schema version for serialized graveyard payloads.
+pub const GRAVEYARD_SCHEMA_VERSION: u16 = 1;
+/// Graves kept per trail segment; the oldest in a segment are dropped first.
+pub const GRAVEYARD_MAX_PER_SEGMENT: usize = 3;
+
+/// A grave left on the trail by an earlier run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tombstone {
+    pub name: String,
+    pub cause: OtDeluxeDeathCause,
+    pub date: OtDeluxeCalendar,
+    pub miles: f32,
+    /// Trail node the party had last passed; graves are capped per node segment.
+    pub node_index: u8,
+    pub variant: OtDeluxeTrailVariant,
+    #[serde(default)]
+    pub epitaph: Option<String>,
+    /// Share code of the run that left the grave.
+    #[serde(default)]
+    pub share_code: Option<String>,
+}
+
+impl Tombstone {
+    #[must_use]
+    pub fn from_death(death: &OtDeluxeDeathRecord, variant: OtDeluxeTrailVariant) -> Self {
+        Self {
+            name: death.name.clone(),
+            cause: death.cause.clone(),
+            date: death.date.clone(),
+            miles: death.miles,
+            node_index: death.node_index,
+            variant,
+            epitaph: death.epitaph.clone(),
+            share_code: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_share_code(mut self, share_code: impl Into<String>) -> Self {
+        self.share_code = Some(share_code.into());
+        self
+    }
+
+    /// i18n key describing how the person died.
+    #[must_use]
+    pub const fn cause_key(&self) -> &'static str {
+        match &self.cause {
+            OtDeluxeDeathCause::Affliction {
+                kind: OtDeluxeAfflictionKind::Illness,
+                ..
+            } => "tombstone.cause.illness",
+            OtDeluxeDeathCause::Affliction {
+                kind: OtDeluxeAfflictionKind::Injury,
+                ..
+            } => "tombstone.cause.injury",
+            OtDeluxeDeathCause::Drowning => "tombstone.cause.drowning",
+            OtDeluxeDeathCause::Exhaustion => "tombstone.cause.exhaustion",
+            OtDeluxeDeathCause::Lost => "tombstone.cause.lost",
+        }
+    }
+
+    fn same_grave(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.date == other.date
+            && self.variant == other.variant
+            && (self.miles - other.miles).abs() < f32::EPSILON
+    }
+
+    const fn segment(&self) -> (OtDeluxeTrailVariant, u8) {
+        (self.variant, self.node_index)
+    }
+}
+
+/// Graves from every recorded run, oldest first.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Graveyard {
+    pub version: u16,
+    pub tombstones: Vec<Tombstone>,
+}
+
+impl Default for Graveyard {
+    fn default() -> Self {
+        Self {
+            version: GRAVEYARD_SCHEMA_VERSION,
+            tombstones: Vec::new(),
+        }
+    }
+}
+
+impl Graveyard {
+    /// Add a grave, dropping the oldest grave in its segment beyond the cap.
+    ///
+    /// Returns `false` when the same grave is already present.
+    pub fn bury(&mut self, tombstone: Tombstone) -> bool {
+        if self
+            .tombstones
+            .iter()
+            .any(|existing| existing.same_grave(&tombstone))
+        {
+            return false;
+        }
+        let segment = tombstone.segment();
+        self.tombstones.push(tombstone);
+        let in_segment = self
+            .tombstones
+            .iter()
+            .filter(|grave| grave.segment() == segment)
+            .count();
+        if in_segment > GRAVEYARD_MAX_PER_SEGMENT
+            && let Some(oldest) = self
+                .tombstones
+                .iter()
+                .position(|grave| grave.segment() == segment)
+        {
+            self.tombstones.remove(oldest);
+        }
+        true
+    }
+
+    /// Bury every death from an `OTDeluxe` run; returns the number of new graves.
+    pub fn bury_run(&mut self, state: &GameState, share_code: &str) -> usize {
+        tombstones_for_run(state, share_code)
+            .into_iter()
+            .filter(|tombstone| self.bury(tombstone.clone()))
+            .count()
+    }
+
+    /// Graves on `variant` lying after `from_miles` and up to `to_miles`.
+    pub fn passed_between(
+        &self,
+        variant: OtDeluxeTrailVariant,
+        from_miles: f32,
+        to_miles: f32,
+    ) -> impl Iterator<Item = &Tombstone> {
+        passed_between(&self.tombstones, variant, from_miles, to_miles)
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.tombstones.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.tombstones.is_empty()
+    }
+
+    /// Serialize the graveyard as pretty-printed JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Parse a graveyard previously produced by [`Graveyard::to_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload is malformed.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+/// Tombstones for every death in an `OTDeluxe` run, in the order they died.
+#[must_use]
+pub fn tombstones_for_run(state: &GameState, share_code: &str) -> Vec<Tombstone> {
+    if state.mechanical_policy != MechanicalPolicyId::OtDeluxe90s {
+        return Vec::new();
+    }
+    let variant = state.ot_deluxe.route.variant;
+    state
+        .ot_deluxe
+        .deaths
+        .iter()
+        .map(|death| Tombstone::from_death(death, variant).with_share_code(share_code))
+        .collect()
+}
+
+pub(crate) fn passed_between(
+    graves: &[Tombstone],
+    variant: OtDeluxeTrailVariant,
+    from_miles: f32,
+    to_miles: f32,
+) -> impl Iterator<Item = &Tombstone> {
+    graves.iter().filter(move |grave| {
+        grave.variant == variant && grave.miles > from_miles && grave.miles <= to_miles
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mechanics::otdeluxe90s::OtDeluxeSkillPolicy;
+
+    fn grave(name: &str, miles: f32, node_index: u8) -> Tombstone {
+        Tombstone {
+            name: name.to_string(),
+            cause: OtDeluxeDeathCause::Drowning,
+            date: OtDeluxeCalendar::default(),
+            miles,
+            node_index,
+            variant: OtDeluxeTrailVariant::Main,
+            epitaph: None,
+            share_code: None,
+        }
+    }
+
+    #[test]
+    fn bury_skips_duplicates_and_caps_each_segment() {
+        let mut graveyard = Graveyard::default();
+        assert!(graveyard.bury(grave("Ada", 10.0, 1)));
+        assert!(!graveyard.bury(grave("Ada", 10.0, 1)));
+        for (idx, name) in ["Ben", "Cy", "Dee"].into_iter().enumerate() {
+            let miles = 20.0 + f32::from(u8::try_from(idx).unwrap());
+            assert!(graveyard.bury(grave(name, miles, 1)));
+        }
+        assert!(graveyard.bury(grave("Eve", 300.0, 5)));
+
+        let names: Vec<_> = graveyard
+            .tombstones
+            .iter()
+            .map(|g| g.name.as_str())
+            .collect();
+        assert_eq!(names, ["Ben", "Cy", "Dee", "Eve"]);
+    }
+
+    #[test]
+    fn passed_between_is_exclusive_of_start_and_filters_variant() {
+        let mut graveyard = Graveyard::default();
+        graveyard.bury(grave("Ada", 10.0, 1));
+        graveyard.bury(grave("Ben", 25.0, 2));
+        let mut other = grave("Cy", 20.0, 2);
+        other.variant = OtDeluxeTrailVariant::SubletteCutoff;
+        graveyard.bury(other);
+
+        let passed: Vec<_> = graveyard
+            .passed_between(OtDeluxeTrailVariant::Main, 10.0, 25.0)
+            .map(|g| g.name.as_str())
+            .collect();
+        assert_eq!(passed, ["Ben"]);
+    }
+
+    #[test]
+    fn bury_run_records_otdeluxe_deaths_with_epitaphs() {
+        let mut state = GameState {
+            mechanical_policy: MechanicalPolicyId::OtDeluxe90s,
+            ..GameState::default()
+        };
+        state.ot_deluxe.party =
+            crate::otdeluxe_state::OtDeluxePartyState::from_names(["Ada", "Ben"]);
+        state.ot_deluxe.miles_traveled = 88.0;
+        state.ot_deluxe.record_death(
+            0,
+            OtDeluxeDeathCause::Exhaustion,
+            &OtDeluxeSkillPolicy::default(),
+        );
+        assert!(state.ot_deluxe.set_epitaph(0, "  Here lies Ada  "));
+
+        let mut graveyard = Graveyard::default();
+        assert_eq!(graveyard.bury_run(&state, "CL-ABC"), 1);
+        assert_eq!(graveyard.bury_run(&state, "CL-ABC"), 0);
+        let grave = &graveyard.tombstones[0];
+        assert_eq!(grave.epitaph.as_deref(), Some("Here lies Ada"));
+        assert_eq!(grave.share_code.as_deref(), Some("CL-ABC"));
+        assert_eq!(grave.cause_key(), "tombstone.cause.exhaustion");
+
+        let json = graveyard.to_json().unwrap();
+        assert_eq!(Graveyard::from_json(&json).unwrap(), graveyard);
+
+        state.mechanical_policy = MechanicalPolicyId::DystrailLegacy;
+        assert_eq!(Graveyard::default().bury_run(&state, "CL-ABC"), 0);
+    }
+}
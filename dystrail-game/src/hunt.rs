@@ -3,7 +3,8 @@
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-use crate::state::{GameState, Region};
+use crate::otdeluxe_skills::{self, OtDeluxeSkill};
+use crate::state::{GameState, Region, default_otdeluxe_policy};
 use crate::weather::Weather;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -34,7 +35,7 @@ pub fn resolve_hunt_with_rng(state: &mut GameState, rng: &mut impl Rng) -> HuntO
     let max_spend = bullets_available.clamp(1, 40);
     let bullets_spent = rng.gen_range(1..=max_spend);
     let food_per_bullet: u16 = rng.gen_range(2..=6);
-    let food_shot = u32::from(bullets_spent) * u32::from(food_per_bullet);
+    let food_shot = hunter_yield(state, u32::from(bullets_spent) * u32::from(food_per_bullet));
     let carry_cap = carry_cap_lbs(state);
     let food_gained = clamp_u16(food_shot.min(u32::from(carry_cap)));
 
@@ -62,7 +63,7 @@ pub fn resolve_hunt(state: &mut GameState) -> HuntOutcome {
     }
 
     let bullets_spent = 1;
-    let food_shot = u32::from(bullets_spent) * 2;
+    let food_shot = hunter_yield(state, u32::from(bullets_spent) * 2);
     let carry_cap = carry_cap_lbs(state);
     let food_gained = clamp_u16(food_shot.min(u32::from(carry_cap)));
 
@@ -104,6 +105,28 @@ const fn is_crowded_location(region: Region) -> bool {
     matches!(region, Region::Beltway)
 }
 
+/// Boost the food shot when a hunter is in the party, tracing the bonus.
+fn hunter_yield(state: &mut GameState, food_shot: u32) -> u32 {
+    let Some(factor) = otdeluxe_skills::skill_factor(
+        &state.ot_deluxe.party,
+        OtDeluxeSkill::Hunter,
+        &default_otdeluxe_policy().skills,
+    ) else {
+        return food_shot;
+    };
+    let boosted = otdeluxe_skills::scale_amount(food_shot, &factor);
+    state
+        .decision_traces_today
+        .push(otdeluxe_skills::modifier_trace(
+            "otdeluxe.skills.hunt_yield",
+            "hunt",
+            f64::from(food_shot),
+            factor,
+            f64::from(boosted),
+        ));
+    boosted
+}
+
 fn carry_cap_lbs(state: &GameState) -> u16 {
     let alive = state.otdeluxe_alive_party_count();
     alive.saturating_mul(100)
@@ -116,7 +139,7 @@ fn clamp_u16(value: u32) -> u16 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::otdeluxe_state::OtDeluxePartyState;
+    use crate::otdeluxe_state::{OtDeluxePartyMember, OtDeluxePartyState};
     use rand::SeedableRng;
 
     fn base_state() -> GameState {
@@ -187,4 +210,26 @@ mod tests {
             HuntOutcome::Blocked(_) => panic!("expected hunt success"),
         }
     }
+
+    #[test]
+    fn hunter_boosts_yield_and_traces_bonus() {
+        let mut state = base_state();
+        state.ot_deluxe.party.members[0] =
+            OtDeluxePartyMember::new("A").with_skill(OtDeluxeSkill::Hunter);
+        state.ot_deluxe.inventory.bullets = 5;
+        let outcome = resolve_hunt(&mut state);
+        assert_eq!(
+            outcome,
+            HuntOutcome::Success {
+                bullets_spent: 1,
+                food_gained_lbs: 3,
+            }
+        );
+        let trace = state
+            .decision_traces_today
+            .iter()
+            .find(|trace| trace.pool_id == "otdeluxe.skills.hunt_yield")
+            .expect("hunter trace");
+        assert_eq!(trace.candidates[0].multipliers[0].label, "skill_hunter");
+    }
 }
@@ -61,6 +61,12 @@ pub enum EventKind {
     CrossingResolved,
     /// Travel is blocked for the day (vehicle, navigation, or other gates).
     TravelBlocked,
+    /// `OTDeluxe` wagon passed a grave left by an earlier run.
+    TombstonePassed,
+    /// Fish or forage intent resolved with a catch or block reason.
+    ForageResolved,
+    /// Emitted by a registered `DaySystem`; the `system` tag names the source.
+    SystemEmitted,
 }
 
 /// Severity tier for a simulation event.
@@ -134,6 +140,9 @@ pub struct EventDecisionTrace {
     pub pool_id: String,
     /// Random draw used to select from the weighted pool.
     pub roll: RollValue,
+    /// Scale `roll` was drawn on.
+    #[serde(default)]
+    pub roll_scale: RollScale,
     /// Candidate weights considered during selection.
     pub candidates: Vec<WeightedCandidate>,
     /// Identifier of the selected candidate.
@@ -159,6 +168,23 @@ pub enum RollValue {
     F64(f64),
 }
 
+/// Scale a trace's roll was drawn on, recorded by the producer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RollScale {
+    /// Roll falls within the summed final (post-multiplier) weights.
+    Final,
+    /// Roll falls within the summed base weights.
+    Base,
+    /// Roll is a `0..1` draw over each candidate's share of the final weight.
+    Share,
+    /// Roll is a uniform index into the candidate list.
+    Index,
+    /// Roll is a sampled value rather than a position in the pool.
+    #[default]
+    Value,
+}
+
 /// Single multiplicative weight factor used in an event selection trace.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WeightFactor {
@@ -193,6 +219,7 @@ mod tests {
         let trace = EventDecisionTrace {
             pool_id: String::from("example.pool"),
             roll: RollValue::U32(7),
+            roll_scale: RollScale::Final,
             candidates: vec![WeightedCandidate {
                 id: String::from("candidate-a"),
                 base_weight: 1.0,
@@ -221,6 +248,7 @@ mod tests {
         let trace = EventDecisionTrace {
             pool_id: String::from("example.pool"),
             roll: RollValue::F64(12.5),
+            roll_scale: RollScale::Value,
             candidates: Vec::new(),
             chosen_id: String::from("candidate-a"),
         };
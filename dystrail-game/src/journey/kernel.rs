@@ -2,29 +2,15 @@
 
 use crate::day_accounting;
 use crate::endgame::EndgameTravelCfg;
-use crate::journey::phase::{
-    BossPhase, ExecOrderPhase, HealthPhase, IntentPhase, PacingPhase, PendingPhase, SuppliesPhase,
-    TravelPhase, WaitPhase, WeatherPhase,
-};
+use crate::journey::system::{DayPipeline, DayStage};
 use crate::journey::{
     DayEffects, DayInputs, DayOutcome, Event, EventId, JourneyCfg, MechanicalPolicyId, StatsDelta,
     TravelDayKind,
 };
-use crate::state::{DayIntent, DietId, GameMode, GameState, PaceId, Region, Season};
+use crate::state::{DayIntent, DietId, GameMode, GameState, PaceId, Region, Season, Stats};
 use std::collections::HashSet;
 
-#[derive(Debug, Clone, Copy)]
-struct StatsSnapshot {
-    supplies: i32,
-    hp: i32,
-    sanity: i32,
-    credibility: i32,
-    morale: i32,
-    allies: i32,
-    pants: i32,
-}
-
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 struct DaySnapshot {
     day: u32,
     intent: DayIntent,
@@ -34,7 +20,7 @@ struct DaySnapshot {
     season: Season,
     mode: GameMode,
     mechanical_policy: MechanicalPolicyId,
-    stats: StatsSnapshot,
+    stats: Stats,
     budget: i32,
     budget_cents: i64,
     miles_traveled: f32,
@@ -42,7 +28,7 @@ struct DaySnapshot {
 }
 
 impl DaySnapshot {
-    const fn capture(state: &GameState) -> Self {
+    fn capture(state: &GameState) -> Self {
         Self {
             day: state.day,
             intent: state.intent.pending,
@@ -52,15 +38,7 @@ impl DaySnapshot {
             season: state.season,
             mode: state.mode,
             mechanical_policy: state.mechanical_policy,
-            stats: StatsSnapshot {
-                supplies: state.stats.supplies,
-                hp: state.stats.hp,
-                sanity: state.stats.sanity,
-                credibility: state.stats.credibility,
-                morale: state.stats.morale,
-                allies: state.stats.allies,
-                pants: state.stats.pants,
-            },
+            stats: state.stats.clone(),
             budget: state.budget,
             budget_cents: state.budget_cents,
             miles_traveled: state.miles_traveled,
@@ -72,33 +50,37 @@ impl DaySnapshot {
 pub(crate) struct DailyTickKernel<'a> {
     cfg: &'a JourneyCfg,
     endgame_cfg: &'a EndgameTravelCfg,
+    pipeline: &'a DayPipeline,
 }
 
 impl<'a> DailyTickKernel<'a> {
-    pub(crate) const fn new(cfg: &'a JourneyCfg, endgame_cfg: &'a EndgameTravelCfg) -> Self {
-        Self { cfg, endgame_cfg }
+    #[cfg(test)]
+    pub(crate) fn new(cfg: &'a JourneyCfg, endgame_cfg: &'a EndgameTravelCfg) -> Self {
+        Self::with_pipeline(cfg, endgame_cfg, DayPipeline::builtin_ref())
+    }
+
+    pub(crate) const fn with_pipeline(
+        cfg: &'a JourneyCfg,
+        endgame_cfg: &'a EndgameTravelCfg,
+        pipeline: &'a DayPipeline,
+    ) -> Self {
+        Self {
+            cfg,
+            endgame_cfg,
+            pipeline,
+        }
+    }
+
+    fn run_stage(&self, stage: DayStage, state: &mut GameState) -> Option<(bool, String, bool)> {
+        self.pipeline
+            .run_stage(stage, state, self.cfg, self.endgame_cfg)
     }
 
     pub(crate) fn apply_daily_physics(&self, state: &mut GameState) {
         let starting_new_day = !state.day_state.lifecycle.day_initialized;
         state.start_of_day();
         if starting_new_day {
-            {
-                let mut phase = WeatherPhase::new(state);
-                phase.run();
-            }
-            {
-                let mut phase = ExecOrderPhase::new(state);
-                phase.run();
-            }
-            {
-                let mut phase = SuppliesPhase::new(state);
-                phase.run(&self.cfg.daily);
-            }
-            {
-                let mut phase = HealthPhase::new(state);
-                phase.run(&self.cfg.daily, &self.cfg.strain);
-            }
+            let _ = self.run_stage(DayStage::Physics, state);
         }
     }
 
@@ -111,38 +93,18 @@ impl<'a> DailyTickKernel<'a> {
         F: FnOnce(&mut GameState),
     {
         let snapshot = DaySnapshot::capture(state);
-        let pending_route = PendingPhase::new(state).resolve_pending_route_prompt();
-        if let Some((ended, log_key, breakdown_started)) = pending_route {
-            return Self::build_outcome(state, snapshot, ended, log_key, breakdown_started);
-        }
-        let pending_crossing = PendingPhase::new(state).resolve_pending_crossing();
-        if let Some((ended, log_key, breakdown_started)) = pending_crossing {
-            return Self::build_outcome(state, snapshot, ended, log_key, breakdown_started);
-        }
-        let pending_store = PendingPhase::new(state).resolve_pending_store();
-        if let Some((ended, log_key, breakdown_started)) = pending_store {
-            return Self::build_outcome(state, snapshot, ended, log_key, breakdown_started);
-        }
-        self.apply_daily_physics(state);
+        if let Some((ended, log_key, breakdown_started)) = self.run_stage(DayStage::Pending, state)
         {
-            let mut phase = PacingPhase::new(state);
-            phase.apply();
-        }
-        hook(state);
-
-        if let Some((ended, log_key, breakdown_started)) = BossPhase::new(state).run() {
-            return Self::build_outcome(state, snapshot, ended, log_key, breakdown_started);
-        }
-
-        if let Some((ended, log_key, breakdown_started)) = WaitPhase::new(state).run() {
             return Self::build_outcome(state, snapshot, ended, log_key, breakdown_started);
         }
-
-        if let Some((ended, log_key, breakdown_started)) = IntentPhase::new(state).run() {
+        self.apply_daily_physics(state);
+        if let Some((ended, log_key, breakdown_started)) = self.run_stage(DayStage::Setup, state) {
             return Self::build_outcome(state, snapshot, ended, log_key, breakdown_started);
         }
+        hook(state);
 
-        let (ended, log_key, breakdown_started) = TravelPhase::new(state).run(self.endgame_cfg);
+        let (ended, log_key, breakdown_started) =
+            self.pipeline.resolve_day(state, self.cfg, self.endgame_cfg);
         Self::build_outcome(state, snapshot, ended, log_key, breakdown_started)
     }
 
@@ -213,17 +175,8 @@ impl<'a> DailyTickKernel<'a> {
             mechanical_policy: snapshot.mechanical_policy,
             weather: state.weather_state.today,
         };
-        let stats_delta = StatsDelta {
-            supplies: state.stats.supplies - snapshot.stats.supplies,
-            hp: state.stats.hp - snapshot.stats.hp,
-            sanity: state.stats.sanity - snapshot.stats.sanity,
-            credibility: state.stats.credibility - snapshot.stats.credibility,
-            morale: state.stats.morale - snapshot.stats.morale,
-            allies: state.stats.allies - snapshot.stats.allies,
-            pants: state.stats.pants - snapshot.stats.pants,
-        };
         let effects = DayEffects {
-            stats: stats_delta,
+            stats: StatsDelta::between(&snapshot.stats, &state.stats),
             budget_delta: state.budget - snapshot.budget,
             budget_cents_delta: state.budget_cents - snapshot.budget_cents,
             miles_traveled_delta: state.miles_traveled - snapshot.miles_traveled,
@@ -293,6 +246,7 @@ mod tests {
     };
     use crate::crossings::{CrossingChoice, CrossingKind};
     use crate::exec_orders::ExecOrder;
+    use crate::journey::phase::{PendingPhase, WaitPhase};
     use crate::journey::{
         DailyChannelConfig, DailyTickConfig, EventKind, HealthTickConfig, JourneyCfg,
         MechanicalPolicyId, RngBundle,
@@ -308,7 +262,7 @@ mod tests {
     use crate::state::{DayIntent, GameState, PendingCrossing, Region, Spares, Stats};
     use crate::vehicle::{Breakdown, Part};
     use crate::weather::{Weather, WeatherConfig, WeatherState, select_weather_for_today};
-    use std::rc::Rc;
+    use std::sync::Arc;
 
     fn seed_with_non_clear_weather(cfg: &WeatherConfig) -> (u64, Weather) {
         let base_state = GameState {
@@ -370,7 +324,7 @@ mod tests {
             },
             ..GameState::default()
         };
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(seed)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(seed)));
 
         kernel.apply_daily_physics(&mut state);
 
@@ -419,7 +373,7 @@ mod tests {
             },
             ..GameState::default()
         };
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(11)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(11)));
 
         kernel.apply_daily_physics(&mut state);
 
@@ -474,7 +428,7 @@ mod tests {
             },
             ..GameState::default()
         };
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(seed)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(seed)));
 
         kernel.apply_daily_physics(&mut state);
 
@@ -517,7 +471,7 @@ mod tests {
             ..GameState::default()
         };
 
-        let bundle = Rc::new(RngBundle::from_user_seed(123));
+        let bundle = Arc::new(RngBundle::from_user_seed(123));
         state.attach_rng_bundle(bundle.clone());
 
         kernel.apply_daily_physics(&mut state);
@@ -587,7 +541,7 @@ mod tests {
         state.ot_deluxe.inventory.food_lbs = 200;
         state.ot_deluxe.party.members = vec![OtDeluxePartyMember::new("Ada")];
         state.ot_deluxe.oxen.healthy = 4;
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(33)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(33)));
 
         kernel.apply_daily_physics(&mut state);
 
@@ -620,7 +574,7 @@ mod tests {
             ..GameState::default()
         };
         state.logs.push(String::from("log.previous"));
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(44)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(44)));
 
         let outcome = kernel.tick_day_with_hook(&mut state, |state| {
             state.logs.push(String::from("log.hook"));
@@ -649,7 +603,7 @@ mod tests {
             },
             ..GameState::default()
         };
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(45)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(45)));
         state.push_log("log.test.pre");
 
         let outcome = kernel.tick_day_with_hook(&mut state, |state| {
@@ -679,7 +633,7 @@ mod tests {
             },
             ..GameState::default()
         };
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(11)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(11)));
 
         let outcome = kernel.tick_day(&mut state);
 
@@ -975,7 +929,7 @@ mod tests {
         };
         state.boss.readiness.ready = true;
         state.ot_deluxe.oxen.healthy = 4;
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(88)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(88)));
 
         let outcome = kernel.tick_day(&mut state);
 
@@ -1196,7 +1150,7 @@ mod tests {
         };
         state.ot_deluxe.oxen.healthy = 4;
         state.vehicle.breakdown_cooldown = 2;
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(101)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(101)));
 
         let outcome = kernel.tick_day(&mut state);
 
@@ -1222,7 +1176,7 @@ mod tests {
             ..GameState::default()
         };
         state.vehicle.breakdown_cooldown = 2;
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(42)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(42)));
 
         let outcome = kernel.tick_day(&mut state);
 
@@ -1283,7 +1237,7 @@ mod tests {
         let mut state = GameState::default();
         state.intent.pending = DayIntent::Trade;
         state.ot_deluxe.inventory.cash_cents = 2_500;
-        let bundle = Rc::new(RngBundle::from_user_seed(77));
+        let bundle = Arc::new(RngBundle::from_user_seed(77));
         state.attach_rng_bundle(bundle.clone());
 
         let outcome = kernel.tick_day(&mut state);
@@ -1334,7 +1288,7 @@ mod tests {
         state.intent.pending = DayIntent::Hunt;
         state.ot_deluxe.inventory.bullets = 10;
         state.ot_deluxe.party.members = vec![crate::otdeluxe_state::OtDeluxePartyMember::new("A")];
-        let bundle = Rc::new(RngBundle::from_user_seed(93));
+        let bundle = Arc::new(RngBundle::from_user_seed(93));
         state.attach_rng_bundle(bundle.clone());
 
         let outcome = kernel.tick_day(&mut state);
@@ -1462,7 +1416,7 @@ mod tests {
         state.budget_cents = 0;
         state.inventory.spares = Spares::default();
         state.vehicle.breakdown_cooldown = 0;
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(13)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(13)));
 
         let outcome = kernel.tick_day(&mut state);
 
@@ -1661,7 +1615,7 @@ mod tests {
 
         let mut state = GameState::default();
         state.vehicle.breakdown_cooldown = 2;
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(5)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(5)));
 
         let outcome = kernel.tick_day_with_hook(&mut state, |state| {
             state.encounter_chance_today = 0.0;
@@ -5,15 +5,14 @@ use rand::rngs::SmallRng;
 use serde::{Deserialize, Serialize};
 use sha2::Sha256;
 use smallvec::SmallVec;
-use std::cell::{RefCell, RefMut};
 use std::collections::HashMap;
-use std::rc::Rc;
-use std::sync::OnceLock;
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, TryLockError};
 use thiserror::Error;
 
 use crate::endgame::EndgameTravelCfg;
-use crate::state::{DayIntent, DietId, GameMode, PaceId, PolicyKind, Region, Season};
-use crate::vehicle::PartWeights;
+use crate::exec_orders::ExecOrder;
+use crate::state::{DayIntent, DietId, GameMode, PaceId, PolicyKind, Region, Season, Stats};
+use crate::vehicle::{Part, PartWeights, VehicleConfig};
 use crate::weather::Weather;
 
 pub mod daily;
@@ -21,13 +20,15 @@ pub mod event;
 pub mod kernel;
 pub(crate) mod phase;
 pub mod session;
+pub mod system;
 pub use daily::DailyTickOutcome;
 pub use event::{
-    Event, EventDecisionTrace, EventId, EventKind, EventSeverity, RollValue, UiSurfaceHint,
-    WeightFactor, WeightedCandidate,
+    Event, EventDecisionTrace, EventId, EventKind, EventSeverity, RollScale, RollValue,
+    UiSurfaceHint, WeightFactor, WeightedCandidate,
 };
 pub(crate) use kernel::DailyTickKernel;
 pub use session::JourneySession;
+pub use system::{DayFlow, DayPipeline, DayPipelineError, DayStage, DaySystem, DaySystemContext};
 
 /// Maximum tag capacity stored inline without additional allocations.
 pub type DayTagSet = SmallVec<[DayTag; 4]>;
@@ -83,6 +84,82 @@ pub struct DayRecord {
     /// Descriptive tags (camp, repair, detour, etc.).
     #[serde(default)]
     pub tags: DayTagSet,
+    /// State captured when the day closed; absent for records from older saves.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot: Option<EndOfDaySnapshot>,
+}
+
+/// End-of-day state attached to a [`DayRecord`] for time-series exports.
+///
+/// While a day is in progress the open record holds the start-of-day state,
+/// which [`EndOfDaySnapshot::deltas`] is measured against when the day closes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EndOfDaySnapshot {
+    pub stats: Stats,
+    pub weather: Weather,
+    /// Part blocking travel when the day closed, if any.
+    #[serde(default)]
+    pub breakdown: Option<Part>,
+    #[serde(default)]
+    pub budget_cents: i64,
+    #[serde(default)]
+    pub vehicle_health: f32,
+    #[serde(default)]
+    pub vehicle_wear: f32,
+    pub region: Region,
+    #[serde(default)]
+    pub exec_order: Option<ExecOrder>,
+    #[serde(default)]
+    pub malnutrition_level: u32,
+    /// Surviving party members; only tracked under OTDeluxe mechanics.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub party_alive: Option<u16>,
+    /// Net change across the day; zero fields are omitted when serialized.
+    #[serde(default, skip_serializing_if = "DayDeltas::is_zero")]
+    pub deltas: DayDeltas,
+}
+
+/// Summed changes over a single recorded day.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct DayDeltas {
+    #[serde(default, skip_serializing_if = "StatsDelta::is_zero")]
+    pub stats: StatsDelta,
+    #[serde(default, skip_serializing_if = "is_zero_i64")]
+    pub budget_cents: i64,
+    #[serde(default, skip_serializing_if = "is_zero_f32")]
+    pub vehicle_health: f32,
+    #[serde(default, skip_serializing_if = "is_zero_f32")]
+    pub vehicle_wear: f32,
+}
+
+impl DayDeltas {
+    /// Change from `start` to `end`.
+    #[must_use]
+    pub fn between(start: &EndOfDaySnapshot, end: &EndOfDaySnapshot) -> Self {
+        Self {
+            stats: StatsDelta::between(&start.stats, &end.stats),
+            budget_cents: end.budget_cents - start.budget_cents,
+            vehicle_health: end.vehicle_health - start.vehicle_health,
+            vehicle_wear: end.vehicle_wear - start.vehicle_wear,
+        }
+    }
+
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+const fn is_zero_i32(value: &i32) -> bool {
+    *value == 0
+}
+
+const fn is_zero_i64(value: &i64) -> bool {
+    *value == 0
+}
+
+fn is_zero_f32(value: &f32) -> bool {
+    *value == 0.0
 }
 
 impl DayRecord {
@@ -94,6 +171,7 @@ impl DayRecord {
             kind,
             miles,
             tags: DayTagSet::new(),
+            snapshot: None,
         }
     }
 
@@ -121,17 +199,45 @@ pub struct DayInputs {
 }
 
 /// Delta for each stat field applied during a day.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub struct StatsDelta {
+    #[serde(default, skip_serializing_if = "is_zero_i32")]
     pub supplies: i32,
+    #[serde(default, skip_serializing_if = "is_zero_i32")]
     pub hp: i32,
+    #[serde(default, skip_serializing_if = "is_zero_i32")]
     pub sanity: i32,
+    #[serde(default, skip_serializing_if = "is_zero_i32")]
     pub credibility: i32,
+    #[serde(default, skip_serializing_if = "is_zero_i32")]
     pub morale: i32,
+    #[serde(default, skip_serializing_if = "is_zero_i32")]
     pub allies: i32,
+    #[serde(default, skip_serializing_if = "is_zero_i32")]
     pub pants: i32,
 }
 
+impl StatsDelta {
+    /// Field-wise change from `start` to `end`.
+    #[must_use]
+    pub const fn between(start: &Stats, end: &Stats) -> Self {
+        Self {
+            supplies: end.supplies - start.supplies,
+            hp: end.hp - start.hp,
+            sanity: end.sanity - start.sanity,
+            credibility: end.credibility - start.credibility,
+            morale: end.morale - start.morale,
+            allies: end.allies - start.allies,
+            pants: end.pants - start.pants,
+        }
+    }
+
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 /// Aggregate resource, health, and progress deltas for a day.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct DayEffects {
@@ -1472,6 +1578,9 @@ impl WearConfig {
 }
 
 /// Breakdown probability configuration bundle.
+///
+/// Fields missing from a journey file fall back to `vehicle.json`; see
+/// [`VehicleConfig`] for the precedence rule.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BreakdownConfig {
     #[serde(default = "BreakdownConfig::default_base")]
@@ -1485,30 +1594,20 @@ pub struct BreakdownConfig {
 }
 
 impl BreakdownConfig {
-    const fn default_base() -> f32 {
-        crate::constants::VEHICLE_BREAKDOWN_BASE_CHANCE
+    fn default_base() -> f32 {
+        VehicleConfig::default_config().base_breakdown_chance
     }
 
-    const fn default_beta() -> f32 {
-        crate::constants::VEHICLE_BREAKDOWN_WEAR_COEFFICIENT
+    fn default_beta() -> f32 {
+        VehicleConfig::default_config().wear_beta
     }
 
     fn default_pace_factor() -> HashMap<PaceId, f32> {
-        HashMap::from([
-            (PaceId::Steady, crate::constants::PACE_BREAKDOWN_STEADY),
-            (PaceId::Heated, crate::constants::PACE_BREAKDOWN_HEATED),
-            (PaceId::Blitz, crate::constants::PACE_BREAKDOWN_BLITZ),
-        ])
+        VehicleConfig::default_config().pace_factor.clone()
     }
 
     fn default_weather_factor() -> HashMap<Weather, f32> {
-        HashMap::from([
-            (Weather::Clear, 1.0),
-            (Weather::Storm, 1.3),
-            (Weather::HeatWave, 1.4),
-            (Weather::ColdSnap, 1.1),
-            (Weather::Smoke, 1.1),
-        ])
+        VehicleConfig::default_config().weather_factor.clone()
     }
 }
 
@@ -1717,8 +1816,9 @@ pub struct DayOutcome {
     pub decision_traces: Vec<EventDecisionTrace>,
 }
 
+/// Named RNG stream inside an [`RngBundle`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum RngStream {
+pub enum RngStream {
     Weather,
     Health,
     Travel,
@@ -1748,29 +1848,41 @@ impl RngStream {
     }
 }
 
+/// Set of RNG streams a phase may draw from.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) struct RngStreamMask(u16);
+pub struct RngStreamMask(u16);
 
 impl RngStreamMask {
-    pub(crate) const fn empty() -> Self {
+    #[must_use]
+    pub const fn empty() -> Self {
         Self(0)
     }
 
-    pub(crate) const fn single(stream: RngStream) -> Self {
+    #[must_use]
+    pub const fn single(stream: RngStream) -> Self {
         Self(stream.bit())
     }
 
-    pub(crate) const fn pair(first: RngStream, second: RngStream) -> Self {
+    #[must_use]
+    pub const fn pair(first: RngStream, second: RngStream) -> Self {
         Self(first.bit() | second.bit())
     }
 
-    pub(crate) const fn contains(self, stream: RngStream) -> bool {
+    /// Add another stream to the mask.
+    #[must_use]
+    pub const fn with(self, stream: RngStream) -> Self {
+        Self(self.0 | stream.bit())
+    }
+
+    #[must_use]
+    pub const fn contains(self, stream: RngStream) -> bool {
         (self.0 & stream.bit()) != 0
     }
 }
 
+/// Tick phase used to guard which RNG streams may be drawn.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum RngPhase {
+pub enum RngPhase {
     DailyEffects,
     ExecOrders,
     HealthTick,
@@ -1783,10 +1895,13 @@ pub(crate) enum RngPhase {
     BossTick,
     TradeTick,
     HuntTick,
+    /// Phase declared by a registered [`DaySystem`] with its own stream set.
+    Custom(RngStreamMask),
 }
 
 impl RngPhase {
-    pub(crate) const fn allowed_streams(self) -> RngStreamMask {
+    #[must_use]
+    pub const fn allowed_streams(self) -> RngStreamMask {
         match self {
             Self::DailyEffects | Self::ExecOrders | Self::RandomEventTick => {
                 RngStreamMask::single(RngStream::Events)
@@ -1800,6 +1915,7 @@ impl RngPhase {
             Self::BossTick => RngStreamMask::single(RngStream::Boss),
             Self::TradeTick => RngStreamMask::single(RngStream::Trade),
             Self::HuntTick => RngStreamMask::single(RngStream::Hunt),
+            Self::Custom(mask) => mask,
         }
     }
 }
@@ -1830,25 +1946,24 @@ impl Drop for RngPhaseGuard<'_> {
         if !self.enabled {
             return;
         }
-        let mut state = self.bundle.phase_guard_state.borrow_mut();
-        *state = self.prev;
+        *lock_stream(&self.bundle.phase_guard_state, "phase_guard_state") = self.prev;
     }
 }
 
 /// Deterministic bundle of RNG streams segregated by simulation domain.
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct RngBundle {
-    weather: RefCell<CountingRng<SmallRng>>,
-    health: RefCell<CountingRng<SmallRng>>,
-    travel: RefCell<CountingRng<SmallRng>>,
-    events: RefCell<CountingRng<SmallRng>>,
-    breakdown: RefCell<CountingRng<SmallRng>>,
-    encounter: RefCell<CountingRng<SmallRng>>,
-    crossing: RefCell<CountingRng<SmallRng>>,
-    boss: RefCell<CountingRng<SmallRng>>,
-    trade: RefCell<CountingRng<SmallRng>>,
-    hunt: RefCell<CountingRng<SmallRng>>,
-    phase_guard_state: RefCell<RngPhaseGuardState>,
+    weather: Mutex<CountingRng<SmallRng>>,
+    health: Mutex<CountingRng<SmallRng>>,
+    travel: Mutex<CountingRng<SmallRng>>,
+    events: Mutex<CountingRng<SmallRng>>,
+    breakdown: Mutex<CountingRng<SmallRng>>,
+    encounter: Mutex<CountingRng<SmallRng>>,
+    crossing: Mutex<CountingRng<SmallRng>>,
+    boss: Mutex<CountingRng<SmallRng>>,
+    trade: Mutex<CountingRng<SmallRng>>,
+    hunt: Mutex<CountingRng<SmallRng>>,
+    phase_guard_state: Mutex<RngPhaseGuardState>,
 }
 
 impl RngBundle {
@@ -1866,95 +1981,84 @@ impl RngBundle {
         let trade = CountingRng::new(derive_stream_seed(seed, b"trade"));
         let hunt = CountingRng::new(derive_stream_seed(seed, b"hunt"));
         Self {
-            weather: RefCell::new(weather),
-            health: RefCell::new(health),
-            travel: RefCell::new(travel),
-            events: RefCell::new(events),
-            breakdown: RefCell::new(breakdown),
-            encounter: RefCell::new(encounter),
-            crossing: RefCell::new(crossing),
-            boss: RefCell::new(boss),
-            trade: RefCell::new(trade),
-            hunt: RefCell::new(hunt),
-            phase_guard_state: RefCell::new(RngPhaseGuardState::default()),
+            weather: Mutex::new(weather),
+            health: Mutex::new(health),
+            travel: Mutex::new(travel),
+            events: Mutex::new(events),
+            breakdown: Mutex::new(breakdown),
+            encounter: Mutex::new(encounter),
+            crossing: Mutex::new(crossing),
+            boss: Mutex::new(boss),
+            trade: Mutex::new(trade),
+            hunt: Mutex::new(hunt),
+            phase_guard_state: Mutex::new(RngPhaseGuardState::default()),
         }
     }
 
     /// Access the weather RNG stream.
-    #[must_use]
-    pub fn weather(&self) -> RefMut<'_, CountingRng<SmallRng>> {
+    pub fn weather(&self) -> MutexGuard<'_, CountingRng<SmallRng>> {
         self.assert_stream_allowed(RngStream::Weather);
-        self.weather.borrow_mut()
+        lock_stream(&self.weather, "weather")
     }
 
     /// Access the health RNG stream.
-    #[must_use]
-    pub fn health(&self) -> RefMut<'_, CountingRng<SmallRng>> {
+    pub fn health(&self) -> MutexGuard<'_, CountingRng<SmallRng>> {
         self.assert_stream_allowed(RngStream::Health);
-        self.health.borrow_mut()
+        lock_stream(&self.health, "health")
     }
 
     /// Access the travel RNG stream.
-    #[must_use]
-    pub fn travel(&self) -> RefMut<'_, CountingRng<SmallRng>> {
+    pub fn travel(&self) -> MutexGuard<'_, CountingRng<SmallRng>> {
         self.assert_stream_allowed(RngStream::Travel);
-        self.travel.borrow_mut()
+        lock_stream(&self.travel, "travel")
     }
 
     /// Access the events RNG stream.
-    #[must_use]
-    pub fn events(&self) -> RefMut<'_, CountingRng<SmallRng>> {
+    pub fn events(&self) -> MutexGuard<'_, CountingRng<SmallRng>> {
         self.assert_stream_allowed(RngStream::Events);
-        self.events.borrow_mut()
+        lock_stream(&self.events, "events")
     }
 
     /// Access the breakdown RNG stream.
-    #[must_use]
-    pub fn breakdown(&self) -> RefMut<'_, CountingRng<SmallRng>> {
+    pub fn breakdown(&self) -> MutexGuard<'_, CountingRng<SmallRng>> {
         self.assert_stream_allowed(RngStream::Breakdown);
-        self.breakdown.borrow_mut()
+        lock_stream(&self.breakdown, "breakdown")
     }
 
     /// Access the vehicle RNG stream (alias for breakdown/vehicle incidents).
-    #[must_use]
-    pub fn vehicle(&self) -> RefMut<'_, CountingRng<SmallRng>> {
+    pub fn vehicle(&self) -> MutexGuard<'_, CountingRng<SmallRng>> {
         self.assert_stream_allowed(RngStream::Breakdown);
-        self.breakdown.borrow_mut()
+        lock_stream(&self.breakdown, "breakdown")
     }
 
     /// Access the encounter RNG stream.
-    #[must_use]
-    pub fn encounter(&self) -> RefMut<'_, CountingRng<SmallRng>> {
+    pub fn encounter(&self) -> MutexGuard<'_, CountingRng<SmallRng>> {
         self.assert_stream_allowed(RngStream::Encounter);
-        self.encounter.borrow_mut()
+        lock_stream(&self.encounter, "encounter")
     }
 
     /// Access the crossing RNG stream.
-    #[must_use]
-    pub fn crossing(&self) -> RefMut<'_, CountingRng<SmallRng>> {
+    pub fn crossing(&self) -> MutexGuard<'_, CountingRng<SmallRng>> {
         self.assert_stream_allowed(RngStream::Crossing);
-        self.crossing.borrow_mut()
+        lock_stream(&self.crossing, "crossing")
     }
 
     /// Access the boss RNG stream.
-    #[must_use]
-    pub fn boss(&self) -> RefMut<'_, CountingRng<SmallRng>> {
+    pub fn boss(&self) -> MutexGuard<'_, CountingRng<SmallRng>> {
         self.assert_stream_allowed(RngStream::Boss);
-        self.boss.borrow_mut()
+        lock_stream(&self.boss, "boss")
     }
 
     /// Access the trade RNG stream.
-    #[must_use]
-    pub fn trade(&self) -> RefMut<'_, CountingRng<SmallRng>> {
+    pub fn trade(&self) -> MutexGuard<'_, CountingRng<SmallRng>> {
         self.assert_stream_allowed(RngStream::Trade);
-        self.trade.borrow_mut()
+        lock_stream(&self.trade, "trade")
     }
 
     /// Access the hunt RNG stream.
-    #[must_use]
-    pub fn hunt(&self) -> RefMut<'_, CountingRng<SmallRng>> {
+    pub fn hunt(&self) -> MutexGuard<'_, CountingRng<SmallRng>> {
         self.assert_stream_allowed(RngStream::Hunt);
-        self.hunt.borrow_mut()
+        lock_stream(&self.hunt, "hunt")
     }
 
     pub(crate) fn phase_guard_for(&self, phase: RngPhase) -> RngPhaseGuard<'_> {
@@ -1965,7 +2069,7 @@ impl RngBundle {
                 enabled: false,
             };
         }
-        let mut state = self.phase_guard_state.borrow_mut();
+        let mut state = lock_stream(&self.phase_guard_state, "phase_guard_state");
         let prev = *state;
         *state = RngPhaseGuardState {
             phase: Some(phase),
@@ -1982,7 +2086,7 @@ impl RngBundle {
         if !phase_guard_enabled() {
             return;
         }
-        let state = self.phase_guard_state.borrow();
+        let state = lock_stream(&self.phase_guard_state, "phase_guard_state");
         let Some(phase) = state.phase else {
             return;
         };
@@ -1996,6 +2100,41 @@ impl RngBundle {
     }
 }
 
+impl Clone for RngBundle {
+    fn clone(&self) -> Self {
+        Self {
+            weather: Mutex::new(lock_stream(&self.weather, "weather").clone()),
+            health: Mutex::new(lock_stream(&self.health, "health").clone()),
+            travel: Mutex::new(lock_stream(&self.travel, "travel").clone()),
+            events: Mutex::new(lock_stream(&self.events, "events").clone()),
+            breakdown: Mutex::new(lock_stream(&self.breakdown, "breakdown").clone()),
+            encounter: Mutex::new(lock_stream(&self.encounter, "encounter").clone()),
+            crossing: Mutex::new(lock_stream(&self.crossing, "crossing").clone()),
+            boss: Mutex::new(lock_stream(&self.boss, "boss").clone()),
+            trade: Mutex::new(lock_stream(&self.trade, "trade").clone()),
+            hunt: Mutex::new(lock_stream(&self.hunt, "hunt").clone()),
+            phase_guard_state: Mutex::new(*lock_stream(
+                &self.phase_guard_state,
+                "phase_guard_state",
+            )),
+        }
+    }
+}
+
+/// Lock a bundle slot; a panic while a stream was held leaves the stream usable.
+///
+/// # Panics
+///
+/// Panics naming the slot when it is already held, rather than deadlocking on
+/// a nested borrow of the same stream.
+fn lock_stream<'a, T>(slot: &'a Mutex<T>, name: &str) -> MutexGuard<'a, T> {
+    match slot.try_lock() {
+        Ok(guard) => guard,
+        Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+        Err(TryLockError::WouldBlock) => panic!("RNG stream `{name}` is already borrowed"),
+    }
+}
+
 /// Counting wrapper for RNG streams providing instrumentation.
 #[derive(Debug, Clone)]
 pub struct CountingRng<R> {
@@ -2042,7 +2181,7 @@ impl<R: rand::RngCore> rand::RngCore for CountingRng<R> {
     }
 }
 
-fn derive_stream_seed(user_seed: u64, domain_tag: &[u8]) -> u64 {
+pub(crate) fn derive_stream_seed(user_seed: u64, domain_tag: &[u8]) -> u64 {
     let mut mac =
         Hmac::<Sha256>::new_from_slice(&user_seed.to_le_bytes()).expect("64-bit seed is valid key");
     mac.update(domain_tag);
@@ -2062,8 +2201,9 @@ pub struct JourneyController {
     policy: PolicyId,
     strategy: StrategyId,
     cfg: JourneyCfg,
-    rng: Rc<RngBundle>,
+    rng: Arc<RngBundle>,
     endgame_cfg: EndgameTravelCfg,
+    pipeline: Arc<DayPipeline>,
 }
 
 impl JourneyController {
@@ -2106,8 +2246,9 @@ impl JourneyController {
             policy,
             strategy,
             cfg: resolved_cfg,
-            rng: Rc::new(RngBundle::from_user_seed(seed)),
+            rng: Arc::new(RngBundle::from_user_seed(seed)),
             endgame_cfg,
+            pipeline: Arc::new(DayPipeline::builtin()),
         }
     }
 
@@ -2131,20 +2272,32 @@ impl JourneyController {
         &self.cfg
     }
 
+    /// Systems run by each day tick, in order.
+    #[must_use]
+    pub fn pipeline(&self) -> &DayPipeline {
+        &self.pipeline
+    }
+
+    /// Mutable access for registering extra day systems.
+    pub fn pipeline_mut(&mut self) -> &mut DayPipeline {
+        Arc::make_mut(&mut self.pipeline)
+    }
+
     /// Expose the shared RNG bundle for session initialization.
     #[must_use]
-    pub fn rng_bundle(&self) -> Rc<RngBundle> {
+    pub fn rng_bundle(&self) -> Arc<RngBundle> {
         self.rng.clone()
     }
 
     /// Replace the controller RNG bundle to keep sessions deterministic across state rehydration.
-    pub fn set_rng_bundle(&mut self, bundle: Rc<RngBundle>) {
+    pub fn set_rng_bundle(&mut self, bundle: Arc<RngBundle>) {
         self.rng = bundle;
     }
 
     /// Apply controller configuration to a game state before ticking.
     pub fn configure_state(&self, state: &mut crate::state::GameState) {
         state.attach_rng_bundle(self.rng.clone());
+        self.attach_pipeline(state);
         state.mechanical_policy = self.mechanics;
         state.policy = Some(self.strategy.into());
         if self.mechanics == MechanicalPolicyId::DystrailLegacy {
@@ -2158,6 +2311,11 @@ impl JourneyController {
         }
     }
 
+    /// Hand the controller's day systems to a state so days off the road run them.
+    pub(crate) fn attach_pipeline(&self, state: &mut crate::state::GameState) {
+        state.attach_day_pipeline(self.pipeline.clone());
+    }
+
     /// Override the controller's endgame travel configuration.
     pub fn set_endgame_config(&mut self, cfg: EndgameTravelCfg) {
         self.endgame_cfg = cfg;
@@ -2165,13 +2323,14 @@ impl JourneyController {
 
     /// Deterministically reseed controller-owned RNGs.
     pub fn reseed(&mut self, seed: u64) {
-        self.rng = Rc::new(RngBundle::from_user_seed(seed));
+        self.rng = Arc::new(RngBundle::from_user_seed(seed));
     }
 
     /// Perform a single day tick using the current game state.
     #[must_use]
     pub fn tick_day(&mut self, state: &mut crate::state::GameState) -> DayOutcome {
-        let kernel = DailyTickKernel::new(&self.cfg, &self.endgame_cfg);
+        self.attach_pipeline(state);
+        let kernel = DailyTickKernel::with_pipeline(&self.cfg, &self.endgame_cfg, &self.pipeline);
         kernel.tick_day(state)
     }
 }
@@ -2185,30 +2344,11 @@ fn normalize_cfg(mut cfg: JourneyCfg) -> JourneyCfg {
     cfg.wear.comfort_miles = cfg.wear.comfort_miles.max(0.0);
     cfg.breakdown.base = cfg.breakdown.base.clamp(0.0, 1.0);
     cfg.breakdown.beta = cfg.breakdown.beta.max(0.0);
-    cfg.breakdown.pace_factor.insert(
-        PaceId::Steady,
-        cfg.breakdown
-            .pace_factor
-            .get(&PaceId::Steady)
-            .copied()
-            .unwrap_or(crate::constants::PACE_BREAKDOWN_STEADY),
-    );
-    cfg.breakdown.pace_factor.insert(
-        PaceId::Heated,
-        cfg.breakdown
-            .pace_factor
-            .get(&PaceId::Heated)
-            .copied()
-            .unwrap_or(crate::constants::PACE_BREAKDOWN_HEATED),
-    );
-    cfg.breakdown.pace_factor.insert(
-        PaceId::Blitz,
-        cfg.breakdown
-            .pace_factor
-            .get(&PaceId::Blitz)
-            .copied()
-            .unwrap_or(crate::constants::PACE_BREAKDOWN_BLITZ),
-    );
+    let default_pace = BreakdownConfig::default_pace_factor();
+    for pace in [PaceId::Steady, PaceId::Heated, PaceId::Blitz] {
+        let default = default_pace.get(&pace).copied().unwrap_or(1.0);
+        cfg.breakdown.pace_factor.entry(pace).or_insert(default);
+    }
     for weather in [
         Weather::Clear,
         Weather::Storm,
@@ -2247,7 +2387,8 @@ pub(crate) fn tick_non_travel_day_for_state(
     reason_tag: &str,
 ) -> f32 {
     let cfg = resolve_cfg_for_state(state);
-    let kernel = DailyTickKernel::new(&cfg, default_endgame_config());
+    let pipeline = state.day_pipeline();
+    let kernel = DailyTickKernel::with_pipeline(&cfg, default_endgame_config(), &pipeline);
     kernel.tick_non_travel_day(state, kind, miles, reason_tag)
 }
 
@@ -2643,6 +2784,10 @@ mod tests {
             RngPhase::HuntTick.allowed_streams(),
             RngStreamMask::single(RngStream::Hunt)
         );
+        let custom = RngStreamMask::single(RngStream::Events).with(RngStream::Trade);
+        assert_eq!(RngPhase::Custom(custom).allowed_streams(), custom);
+        assert!(custom.contains(RngStream::Trade));
+        assert!(!custom.contains(RngStream::Weather));
     }
 
     #[test]
@@ -2697,6 +2842,29 @@ mod tests {
         assert_eq!(record.tags[0].0, "camp");
     }
 
+    #[test]
+    fn day_deltas_serialize_sparsely() {
+        let start = GameState::default().day_snapshot();
+        let mut end = start.clone();
+        end.stats.hp -= 2;
+        end.budget_cents += 150;
+        end.deltas = DayDeltas::between(&start, &end);
+        assert_eq!(end.deltas.stats.hp, -2);
+        assert!(!end.deltas.is_zero());
+
+        let json = serde_json::to_value(end.deltas).expect("serialize deltas");
+        assert_eq!(
+            json,
+            serde_json::json!({"stats": {"hp": -2}, "budget_cents": 150})
+        );
+        let back: DayDeltas = serde_json::from_value(json).expect("deserialize deltas");
+        assert_eq!(back, end.deltas);
+
+        let untouched = serde_json::to_value(&start).expect("serialize snapshot");
+        assert!(untouched.get("deltas").is_none());
+        assert!(untouched.get("party_alive").is_none());
+    }
+
     #[test]
     fn journey_config_rejects_invalid_victory_miles() {
         let cfg = JourneyCfg {
@@ -3345,16 +3513,24 @@ mod tests {
         ));
     }
 
+    #[test]
+    #[should_panic(expected = "RNG stream `hunt` is already borrowed")]
+    fn nested_stream_borrow_panics_instead_of_deadlocking() {
+        let bundle = RngBundle::from_user_seed(7);
+        let _held = bundle.hunt();
+        let _nested = bundle.hunt();
+    }
+
     #[test]
     fn phase_guard_sets_and_restores_state() {
         with_phase_guard_env(|| {
             let bundle = RngBundle::from_user_seed(7);
             {
                 let _guard = bundle.phase_guard_for(RngPhase::WeatherTick);
-                let state = bundle.phase_guard_state.borrow();
+                let state = lock_stream(&bundle.phase_guard_state, "phase_guard_state");
                 assert_eq!(state.phase, Some(RngPhase::WeatherTick));
             }
-            let state = bundle.phase_guard_state.borrow();
+            let state = lock_stream(&bundle.phase_guard_state, "phase_guard_state");
             assert!(state.phase.is_none());
         });
     }
@@ -3363,7 +3539,7 @@ mod tests {
     fn phase_guard_returns_when_phase_missing() {
         with_phase_guard_env(|| {
             let bundle = RngBundle::from_user_seed(9);
-            let _ = bundle.weather();
+            drop(bundle.weather());
         });
     }
 
@@ -3373,7 +3549,7 @@ mod tests {
         with_phase_guard_env(|| {
             let bundle = RngBundle::from_user_seed(11);
             let _guard = bundle.phase_guard_for(RngPhase::HealthTick);
-            let _ = bundle.travel();
+            drop(bundle.travel());
         });
     }
 
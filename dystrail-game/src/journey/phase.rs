@@ -5,15 +5,18 @@
 
 use std::sync::OnceLock;
 
-use crate::constants::{LOG_HUNT, LOG_STORE, LOG_TRADE, LOG_TRAVEL_BLOCKED, LOG_TRAVELED};
+use crate::constants::{
+    LOG_FISH, LOG_FORAGE, LOG_HUNT, LOG_STORE, LOG_TRADE, LOG_TRAVEL_BLOCKED, LOG_TRAVELED,
+};
 use crate::endgame::{self, EndgameTravelCfg};
+use crate::forage::{self, ForageKind};
 use crate::journey::daily::{apply_daily_health, apply_daily_supplies_sanity};
 use crate::journey::{
     DayTagSet, EventKind, EventSeverity, MechanicalPolicyId, RngPhase, TravelDayKind,
 };
 use crate::mechanics::otdeluxe90s::OtDeluxeNavigationPolicy;
 use crate::pacing::PacingConfig;
-use crate::state::{DayIntent, GameState};
+use crate::state::{DayIntent, GameState, default_otdeluxe_policy};
 use crate::weather::DystrailRegionalWeather;
 use crate::{hunt, trade};
 
@@ -330,6 +333,8 @@ impl<'a> HealthPhase<'a> {
         let health_before = self.state.ot_deluxe.health_general;
         let delta = self.state.apply_otdeluxe_health_update();
         let health_after = self.state.ot_deluxe.health_general;
+        let herd = self.state.tick_otdeluxe_herd();
+        self.state.tick_otdeluxe_party_morale();
         let mut payload = serde_json::Map::new();
         payload.insert(String::from("policy"), serde_json::json!("otdeluxe90s"));
         payload.insert(
@@ -341,6 +346,9 @@ impl<'a> HealthPhase<'a> {
             serde_json::json!(health_after),
         );
         payload.insert(String::from("health_delta"), serde_json::json!(delta));
+        if !herd.died.is_empty() || !herd.recovered.is_empty() {
+            payload.insert(String::from("oxen"), serde_json::json!(herd));
+        }
         let kind = EventKind::HealthTickApplied;
         let severity = EventSeverity::Info;
         let tags = DayTagSet::new();
@@ -411,6 +419,8 @@ impl<'a> IntentPhase<'a> {
             DayIntent::Rest => Some(self.handle_rest_intent()),
             DayIntent::Trade => Some(self.handle_trade_intent()),
             DayIntent::Hunt => Some(self.handle_hunt_intent()),
+            DayIntent::Fish => Some(self.handle_forage_intent(ForageKind::Fish)),
+            DayIntent::Forage => Some(self.handle_forage_intent(ForageKind::Forage)),
         }
     }
 
@@ -464,6 +474,64 @@ impl<'a> IntentPhase<'a> {
         record_gate_day(self.state, "intent_hunt");
         (false, String::from(LOG_HUNT), false)
     }
+
+    /// Gathers food on the first day, then spends any remaining time cost
+    /// from `OtDeluxeActionTimeCosts` as plain gate days.
+    fn handle_forage_intent(&mut self, kind: ForageKind) -> (bool, String, bool) {
+        let (reason_tag, log_key) = match kind {
+            ForageKind::Fish => ("intent_fish", LOG_FISH),
+            ForageKind::Forage => ("intent_forage", LOG_FORAGE),
+        };
+        if self.state.intent.action_days_remaining > 0 {
+            self.state.intent.action_days_remaining -= 1;
+            if self.state.intent.action_days_remaining == 0 {
+                self.state.intent.pending = DayIntent::Continue;
+            }
+            record_gate_day(self.state, reason_tag);
+            return (false, String::from(log_key), false);
+        }
+
+        let policy = default_otdeluxe_policy();
+        let cost_days = match kind {
+            ForageKind::Fish => policy.actions.fish_cost_days,
+            ForageKind::Forage => policy.actions.forage_cost_days,
+        }
+        .max(1);
+        self.state.intent.rest_days_remaining = 0;
+        self.state.intent.action_days_remaining = cost_days - 1;
+        if cost_days == 1 {
+            self.state.intent.pending = DayIntent::Continue;
+        }
+
+        self.state.start_of_day();
+        let rng_bundle = self.state.rng_bundle.clone();
+        let (outcome, trace) = if let Some(bundle) = rng_bundle.as_ref() {
+            let _guard = bundle.phase_guard_for(RngPhase::HuntTick);
+            let mut rng = bundle.hunt();
+            forage::resolve_forage_with_rng(
+                self.state,
+                kind,
+                &policy.trail,
+                forage::catalog(),
+                &mut *rng,
+            )
+        } else {
+            forage::resolve_forage(self.state, kind, &policy.trail, forage::catalog())
+        };
+        if let Some(trace) = trace {
+            self.state.decision_traces_today.push(trace);
+        }
+        let payload = serde_json::to_value(outcome).unwrap_or(serde_json::Value::Null);
+        emit_event(
+            self.state,
+            EventKind::ForageResolved,
+            EventSeverity::Info,
+            DayTagSet::new(),
+            payload,
+        );
+        record_gate_day(self.state, reason_tag);
+        (false, String::from(log_key), false)
+    }
 }
 
 pub(super) struct PendingPhase<'a> {
@@ -556,7 +624,7 @@ impl<'a> TravelPhase<'a> {
         endgame_cfg: &EndgameTravelCfg,
         navigation_policy: Option<&OtDeluxeNavigationPolicy>,
     ) -> (bool, String, bool) {
-        let rng_bundle = self.state.rng_bundle.as_ref().map(std::rc::Rc::clone);
+        let rng_bundle = self.state.rng_bundle.as_ref().map(std::sync::Arc::clone);
         let bundle_ref = rng_bundle.as_ref();
         let guard_bundle = rng_bundle.as_deref();
 
@@ -565,9 +633,10 @@ impl<'a> TravelPhase<'a> {
         }
 
         let breakdown_started = with_phase_guard(guard_bundle, RngPhase::VehicleBreakdown, || {
-            self.state.vehicle_roll()
+            let started = self.state.vehicle_roll();
+            self.state.resolve_breakdown();
+            started
         });
-        self.state.resolve_breakdown();
         if let Some(result) = self.state.handle_vehicle_state(breakdown_started) {
             return result;
         }
@@ -658,7 +727,7 @@ fn apply_otdeluxe_weather_overrides(state: &mut GameState) {
     overrides.weather_effects.apply(&mut state.weather_effects);
 }
 
-fn phase_guard(
+pub(super) fn phase_guard(
     bundle: Option<&crate::journey::RngBundle>,
     phase: RngPhase,
 ) -> Option<crate::journey::RngPhaseGuard<'_>> {
@@ -677,7 +746,7 @@ where
     action()
 }
 
-fn emit_event(
+pub(super) fn emit_event(
     state: &mut GameState,
     kind: EventKind,
     severity: EventSeverity,
@@ -726,11 +795,11 @@ mod tests {
         DayIntent, DayState, GameMode, IntentState, LifecycleState, PendingCrossing, PolicyKind,
         Spares,
     };
-    use std::rc::Rc;
+    use std::sync::Arc;
 
     fn state_with_rng(seed: u64) -> GameState {
         let mut state = GameState::default();
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(seed)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(seed)));
         state
     }
 
@@ -860,6 +929,7 @@ mod tests {
             intent: IntentState {
                 pending: DayIntent::Rest,
                 rest_days_remaining: 1,
+                action_days_remaining: 0,
             },
             ..GameState::default()
         };
@@ -876,6 +946,7 @@ mod tests {
             intent: IntentState {
                 pending: DayIntent::Trade,
                 rest_days_remaining: 0,
+                action_days_remaining: 0,
             },
             ..state_with_rng(6)
         };
@@ -891,6 +962,7 @@ mod tests {
             intent: IntentState {
                 pending: DayIntent::Hunt,
                 rest_days_remaining: 0,
+                action_days_remaining: 0,
             },
             ot_deluxe: OtDeluxeState {
                 inventory: OtDeluxeInventory {
@@ -916,6 +988,7 @@ mod tests {
             intent: IntentState {
                 pending: DayIntent::Rest,
                 rest_days_remaining: 12,
+                action_days_remaining: 0,
             },
             ..GameState::default()
         };
@@ -927,6 +1000,7 @@ mod tests {
             intent: IntentState {
                 pending: DayIntent::Rest,
                 rest_days_remaining: 0,
+                action_days_remaining: 0,
             },
             ..GameState::default()
         };
@@ -1185,6 +1259,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn intent_phase_forage_branch_emits_event_and_trace() {
+        let mut state = state_with_rng(12);
+        state.intent.pending = DayIntent::Forage;
+        state.ot_deluxe.miles_traveled = 300.0;
+        state.ot_deluxe.inventory.food_lbs = 20;
+        let outcome = IntentPhase::new(&mut state).run();
+        assert!(outcome.is_some());
+        assert!(matches!(state.intent.pending, DayIntent::Continue));
+        assert!(
+            state
+                .events_today
+                .iter()
+                .any(|event| event.kind == EventKind::ForageResolved)
+        );
+        assert!(
+            state
+                .decision_traces_today
+                .iter()
+                .any(|trace| trace.pool_id == "otdeluxe.forage")
+        );
+        let tagged = state
+            .day_records
+            .last()
+            .is_some_and(|record| record.tags.contains(&DayTag::new("intent_forage")));
+        assert!(tagged);
+    }
+
+    #[test]
+    fn fish_intent_spends_extra_days_from_action_costs() {
+        let mut state = state_with_rng(13);
+        state.intent = IntentState {
+            pending: DayIntent::Fish,
+            rest_days_remaining: 0,
+            action_days_remaining: 2,
+        };
+        IntentPhase::new(&mut state).run();
+        assert!(matches!(state.intent.pending, DayIntent::Fish));
+        assert_eq!(state.intent.action_days_remaining, 1);
+        assert!(
+            !state
+                .events_today
+                .iter()
+                .any(|event| event.kind == EventKind::ForageResolved)
+        );
+        IntentPhase::new(&mut state).run();
+        assert!(matches!(state.intent.pending, DayIntent::Continue));
+        assert_eq!(state.intent.action_days_remaining, 0);
+    }
+
     #[test]
     fn pending_phase_resolves_store_purchase() {
         let mut state = state_with_rng(10);
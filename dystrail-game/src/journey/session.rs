@@ -1,6 +1,9 @@
 use crate::endgame::EndgameTravelCfg;
-use crate::journey::{JourneyController, MechanicalPolicyId, PolicyId, StrategyId};
-use crate::mechanics::OtDeluxeOccupation;
+use crate::journey::{DayPipeline, JourneyController, MechanicalPolicyId, PolicyId, StrategyId};
+use crate::kernel::KernelEvent;
+use crate::kernel::events::EventSink;
+use crate::kernel::events::sink::emit_day;
+use crate::otdeluxe_state::OtDeluxeStartConfig;
 use crate::state::GameState;
 use crate::{DayOutcome, EncounterData, GameMode};
 
@@ -41,15 +44,15 @@ impl JourneySession {
         seed: u64,
         data: EncounterData,
         endgame_cfg: &EndgameTravelCfg,
-        otdeluxe_occupation: Option<OtDeluxeOccupation>,
+        otdeluxe_start: Option<OtDeluxeStartConfig>,
     ) -> Self {
         let state = GameState::default().with_seed(seed, mode, data);
         let controller = Self::build_controller(mechanics, mode, strategy, seed, endgame_cfg);
         let mut session = Self { controller, state };
         session.reset_state_policy();
         if mechanics == MechanicalPolicyId::OtDeluxe90s {
-            let occupation = otdeluxe_occupation.unwrap_or(OtDeluxeOccupation::Banker);
-            session.state.apply_otdeluxe_start_config(occupation);
+            let start = otdeluxe_start.unwrap_or_default();
+            session.state.apply_otdeluxe_start_config(&start);
         }
         session.state.queue_otdeluxe_store_if_available();
         session
@@ -99,6 +102,19 @@ impl JourneySession {
         self.controller.tick_day(&mut self.state)
     }
 
+    /// Advance one day, streaming its events and decision traces to `sink`.
+    pub fn tick_day_with_sink<S: EventSink + ?Sized>(&mut self, sink: &mut S) -> DayOutcome {
+        let outcome = self.tick_day();
+        let events: Vec<KernelEvent> = outcome
+            .events
+            .iter()
+            .cloned()
+            .map(KernelEvent::from_journey_event)
+            .collect();
+        emit_day(sink, outcome.inputs.day, &events, &outcome.decision_traces);
+        outcome
+    }
+
     /// Current strategy assigned to the session.
     #[must_use]
     pub const fn strategy(&self) -> StrategyId {
@@ -117,14 +133,15 @@ impl JourneySession {
         &self.state
     }
 
-    /// Borrow the underlying mutable game state.
-    pub const fn state_mut(&mut self) -> &mut GameState {
+    /// Borrow the underlying mutable game state, carrying the session's day systems.
+    pub fn state_mut(&mut self) -> &mut GameState {
+        self.controller.attach_pipeline(&mut self.state);
         &mut self.state
     }
 
     /// Apply a closure to the mutable game state.
     pub fn with_state_mut<R>(&mut self, f: impl FnOnce(&mut GameState) -> R) -> R {
-        f(&mut self.state)
+        f(self.state_mut())
     }
 
     /// Borrow the controller.
@@ -133,6 +150,11 @@ impl JourneySession {
         &self.controller
     }
 
+    /// Register extra day systems on the session's controller.
+    pub fn pipeline_mut(&mut self) -> &mut DayPipeline {
+        self.controller.pipeline_mut()
+    }
+
     /// Deterministically reseed the session.
     pub fn reseed(&mut self, seed: u64) {
         self.controller.reseed(seed);
@@ -194,6 +216,24 @@ mod tests {
         let _ = session.tick_day();
     }
 
+    #[test]
+    fn tick_day_with_sink_streams_every_event() {
+        let data = EncounterData::empty();
+        let endgame = EndgameTravelCfg::default_config();
+        let mut session =
+            JourneySession::new(GameMode::Classic, StrategyId::Balanced, 11, data, &endgame);
+        let mut sink = crate::JsonlEventSink::new(Vec::new());
+        let mut expected = 0;
+        for _ in 0..5 {
+            let outcome = session.tick_day_with_sink(&mut sink);
+            expected += outcome.events.len() + outcome.decision_traces.len();
+        }
+        assert!(expected > 0);
+        assert_eq!(sink.lines_written(), expected);
+        let text = String::from_utf8(sink.into_inner().expect("flush")).expect("utf8");
+        assert_eq!(text.lines().count(), expected);
+    }
+
     #[test]
     fn session_construction_supports_otdeluxe_mechanics() {
         let data = EncounterData::empty();
@@ -205,7 +245,7 @@ mod tests {
             7,
             data,
             &endgame,
-            Some(OtDeluxeOccupation::Doctor),
+            Some(OtDeluxeStartConfig::new(OtDeluxeOccupation::Doctor)),
         );
         assert_eq!(
             session.state().mechanical_policy,
@@ -228,5 +268,6 @@ mod tests {
         session.state_mut().day = 2;
         assert_eq!(session.state().day, 2);
         assert_eq!(session.controller().strategy(), StrategyId::Balanced);
+        assert!(session.pipeline_mut().names().any(|name| name == "travel"));
     }
 }
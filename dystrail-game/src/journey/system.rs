@@ -0,0 +1,529 @@
+//! Pluggable systems for the daily tick pipeline.
+//!
+//! The kernel runs every registered [`DaySystem`] stage by stage, in
+//! registration order. The built-in phases (weather, pacing, supplies, health,
+//! gates, intent, travel) are registered as systems too, so game variants can
+//! slot new behaviour between them without touching `state.rs`.
+
+use std::fmt;
+use std::sync::{Arc, OnceLock};
+
+use thiserror::Error;
+
+use crate::constants::LOG_TRAVEL_BLOCKED;
+use crate::endgame::EndgameTravelCfg;
+use crate::journey::phase::{
+    BossPhase, ExecOrderPhase, HealthPhase, IntentPhase, PacingPhase, PendingPhase, SuppliesPhase,
+    TravelPhase, WaitPhase, WeatherPhase, emit_event, phase_guard,
+};
+use crate::journey::{
+    DayTag, DayTagSet, EventKind, EventSeverity, JourneyCfg, RngPhase, RngStreamMask,
+};
+use crate::state::GameState;
+
+/// Point in the daily tick where a system runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayStage {
+    /// Before the day starts; resolves choices left over from the previous tick.
+    Pending,
+    /// Once per new day; the returned flow is ignored. Camp actions and
+    /// crossing detours run this stage through the session's pipeline too.
+    Physics,
+    /// After physics on travel ticks, before the controller hook.
+    Setup,
+    /// After the controller hook; the first system to finish decides the day.
+    Resolve,
+}
+
+/// What the kernel should do after a system has run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DayFlow {
+    /// Hand over to the next system.
+    Continue,
+    /// Stop the pipeline and build the day outcome.
+    Finish {
+        ended: bool,
+        log_key: String,
+        breakdown_started: bool,
+    },
+}
+
+impl DayFlow {
+    fn from_phase(result: Option<(bool, String, bool)>) -> Self {
+        result.map_or(Self::Continue, |(ended, log_key, breakdown_started)| {
+            Self::Finish {
+                ended,
+                log_key,
+                breakdown_started,
+            }
+        })
+    }
+}
+
+/// Mutable view handed to a system while it runs.
+pub struct DaySystemContext<'a> {
+    pub state: &'a mut GameState,
+    pub cfg: &'a JourneyCfg,
+    pub endgame_cfg: &'a EndgameTravelCfg,
+    system: &'a str,
+}
+
+impl DaySystemContext<'_> {
+    /// Name of the running system.
+    #[must_use]
+    pub const fn system(&self) -> &str {
+        self.system
+    }
+
+    /// Push an event into today's stream.
+    pub fn emit_event(
+        &mut self,
+        kind: EventKind,
+        severity: EventSeverity,
+        tags: DayTagSet,
+        payload: serde_json::Value,
+    ) {
+        emit_event(self.state, kind, severity, tags, payload);
+    }
+
+    /// Push an [`EventKind::SystemEmitted`] event tagged with the running system's name.
+    pub fn emit_system_event(&mut self, severity: EventSeverity, payload: serde_json::Value) {
+        let mut tags = DayTagSet::new();
+        tags.push(DayTag::new(self.system));
+        self.emit_event(EventKind::SystemEmitted, severity, tags, payload);
+    }
+}
+
+/// One step of the daily tick.
+///
+/// Systems are shared between cloned controllers, so they hold no per-run
+/// state; anything that must persist belongs in [`GameState`].
+pub trait DaySystem: Send + Sync {
+    /// Unique name used for ordering and diagnostics.
+    fn name(&self) -> &str;
+
+    fn stage(&self) -> DayStage;
+
+    /// RNG phase the kernel guards the run with.
+    ///
+    /// Defaults to a phase with no streams, so a system that draws randomness
+    /// must declare it. `None` leaves guarding to the system itself.
+    fn rng_phase(&self) -> Option<RngPhase> {
+        Some(RngPhase::Custom(RngStreamMask::empty()))
+    }
+
+    fn run(&self, ctx: &mut DaySystemContext<'_>) -> DayFlow;
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DayPipelineError {
+    #[error("a day system named {0} is already registered")]
+    DuplicateName(String),
+    #[error("no day system named {0} is registered")]
+    UnknownAnchor(String),
+    #[error("{name} runs in {stage:?} but {anchor} runs in {anchor_stage:?}")]
+    StageMismatch {
+        name: String,
+        stage: DayStage,
+        anchor: String,
+        anchor_stage: DayStage,
+    },
+}
+
+/// Ordered list of systems making up the daily tick.
+#[derive(Clone)]
+pub struct DayPipeline {
+    systems: Vec<Arc<dyn DaySystem>>,
+}
+
+impl fmt::Debug for DayPipeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
+
+impl Default for DayPipeline {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl DayPipeline {
+    /// Pipeline with only the built-in phases.
+    #[must_use]
+    pub fn builtin() -> Self {
+        let systems: Vec<Arc<dyn DaySystem>> = vec![
+            Arc::new(PendingRouteSystem),
+            Arc::new(PendingCrossingSystem),
+            Arc::new(PendingStoreSystem),
+            Arc::new(WeatherSystem),
+            Arc::new(ExecOrderSystem),
+            Arc::new(SuppliesSystem),
+            Arc::new(HealthSystem),
+            Arc::new(PacingSystem),
+            Arc::new(BossSystem),
+            Arc::new(WaitSystem),
+            Arc::new(IntentSystem),
+            Arc::new(TravelSystem),
+        ];
+        Self { systems }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn builtin_ref() -> &'static Self {
+        static PIPELINE: OnceLock<DayPipeline> = OnceLock::new();
+        PIPELINE.get_or_init(Self::builtin)
+    }
+
+    pub(crate) fn builtin_shared() -> Arc<Self> {
+        static PIPELINE: OnceLock<Arc<DayPipeline>> = OnceLock::new();
+        PIPELINE.get_or_init(|| Arc::new(Self::builtin())).clone()
+    }
+
+    /// System names in run order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.systems.iter().map(|system| system.name())
+    }
+
+    /// Append a system; it runs after every system already in its stage.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when a system with the same name is registered.
+    pub fn register(&mut self, system: impl DaySystem + 'static) -> Result<(), DayPipelineError> {
+        self.ensure_unique(system.name())?;
+        self.systems.push(Arc::new(system));
+        Ok(())
+    }
+
+    /// Insert a system directly before `anchor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the name is taken, `anchor` is unknown, or the two
+    /// systems run in different stages.
+    pub fn register_before(
+        &mut self,
+        anchor: &str,
+        system: impl DaySystem + 'static,
+    ) -> Result<(), DayPipelineError> {
+        let index = self.anchor_index(anchor, &system)?;
+        self.systems.insert(index, Arc::new(system));
+        Ok(())
+    }
+
+    /// Insert a system directly after `anchor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the name is taken, `anchor` is unknown, or the two
+    /// systems run in different stages.
+    pub fn register_after(
+        &mut self,
+        anchor: &str,
+        system: impl DaySystem + 'static,
+    ) -> Result<(), DayPipelineError> {
+        let index = self.anchor_index(anchor, &system)?;
+        self.systems.insert(index + 1, Arc::new(system));
+        Ok(())
+    }
+
+    fn ensure_unique(&self, name: &str) -> Result<(), DayPipelineError> {
+        if self.names().any(|existing| existing == name) {
+            return Err(DayPipelineError::DuplicateName(name.to_string()));
+        }
+        Ok(())
+    }
+
+    fn anchor_index(
+        &self,
+        anchor: &str,
+        system: &dyn DaySystem,
+    ) -> Result<usize, DayPipelineError> {
+        self.ensure_unique(system.name())?;
+        let index = self
+            .systems
+            .iter()
+            .position(|existing| existing.name() == anchor)
+            .ok_or_else(|| DayPipelineError::UnknownAnchor(anchor.to_string()))?;
+        let anchor_stage = self.systems[index].stage();
+        if anchor_stage != system.stage() {
+            return Err(DayPipelineError::StageMismatch {
+                name: system.name().to_string(),
+                stage: system.stage(),
+                anchor: anchor.to_string(),
+                anchor_stage,
+            });
+        }
+        Ok(index)
+    }
+
+    /// Run every system in `stage`, stopping at the first one that finishes the day.
+    pub(crate) fn run_stage(
+        &self,
+        stage: DayStage,
+        state: &mut GameState,
+        cfg: &JourneyCfg,
+        endgame_cfg: &EndgameTravelCfg,
+    ) -> Option<(bool, String, bool)> {
+        for system in self.systems.iter().filter(|system| system.stage() == stage) {
+            let bundle = state.rng_bundle.clone();
+            let _guard = system
+                .rng_phase()
+                .and_then(|phase| phase_guard(bundle.as_deref(), phase));
+            let mut ctx = DaySystemContext {
+                state,
+                cfg,
+                endgame_cfg,
+                system: system.name(),
+            };
+            if let DayFlow::Finish {
+                ended,
+                log_key,
+                breakdown_started,
+            } = system.run(&mut ctx)
+            {
+                return Some((ended, log_key, breakdown_started));
+            }
+        }
+        None
+    }
+
+    /// Like [`Self::run_stage`], but a day always ends once the resolve stage is done.
+    pub(crate) fn resolve_day(
+        &self,
+        state: &mut GameState,
+        cfg: &JourneyCfg,
+        endgame_cfg: &EndgameTravelCfg,
+    ) -> (bool, String, bool) {
+        self.run_stage(DayStage::Resolve, state, cfg, endgame_cfg)
+            .unwrap_or_else(|| (false, String::from(LOG_TRAVEL_BLOCKED), false))
+    }
+}
+
+macro_rules! builtin_system {
+    ($ty:ident, $name:literal, $stage:ident, $phase:expr, |$ctx:ident| $body:block) => {
+        struct $ty;
+
+        impl DaySystem for $ty {
+            fn name(&self) -> &str {
+                $name
+            }
+
+            fn stage(&self) -> DayStage {
+                DayStage::$stage
+            }
+
+            fn rng_phase(&self) -> Option<RngPhase> {
+                $phase
+            }
+
+            fn run(&self, $ctx: &mut DaySystemContext<'_>) -> DayFlow $body
+        }
+    };
+}
+
+builtin_system!(PendingRouteSystem, "pending_route", Pending, None, |ctx| {
+    DayFlow::from_phase(PendingPhase::new(ctx.state).resolve_pending_route_prompt())
+});
+builtin_system!(
+    PendingCrossingSystem,
+    "pending_crossing",
+    Pending,
+    None,
+    |ctx| { DayFlow::from_phase(PendingPhase::new(ctx.state).resolve_pending_crossing()) }
+);
+builtin_system!(PendingStoreSystem, "pending_store", Pending, None, |ctx| {
+    DayFlow::from_phase(PendingPhase::new(ctx.state).resolve_pending_store())
+});
+builtin_system!(WeatherSystem, "weather", Physics, None, |ctx| {
+    WeatherPhase::new(ctx.state).run();
+    DayFlow::Continue
+});
+builtin_system!(ExecOrderSystem, "exec_orders", Physics, None, |ctx| {
+    ExecOrderPhase::new(ctx.state).run();
+    DayFlow::Continue
+});
+builtin_system!(SuppliesSystem, "supplies", Physics, None, |ctx| {
+    SuppliesPhase::new(ctx.state).run(&ctx.cfg.daily);
+    DayFlow::Continue
+});
+builtin_system!(HealthSystem, "health", Physics, None, |ctx| {
+    HealthPhase::new(ctx.state).run(&ctx.cfg.daily, &ctx.cfg.strain);
+    DayFlow::Continue
+});
+builtin_system!(PacingSystem, "pacing", Setup, None, |ctx| {
+    PacingPhase::new(ctx.state).apply();
+    DayFlow::Continue
+});
+builtin_system!(BossSystem, "boss", Resolve, None, |ctx| {
+    DayFlow::from_phase(BossPhase::new(ctx.state).run())
+});
+builtin_system!(WaitSystem, "wait", Resolve, None, |ctx| {
+    DayFlow::from_phase(WaitPhase::new(ctx.state).run())
+});
+builtin_system!(IntentSystem, "intent", Resolve, None, |ctx| {
+    DayFlow::from_phase(IntentPhase::new(ctx.state).run())
+});
+builtin_system!(TravelSystem, "travel", Resolve, None, |ctx| {
+    DayFlow::from_phase(Some(TravelPhase::new(ctx.state).run(ctx.endgame_cfg)))
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::journey::{DailyTickKernel, RngStream};
+
+    struct Reputation;
+
+    impl DaySystem for Reputation {
+        fn name(&self) -> &str {
+            "reputation_decay"
+        }
+
+        fn stage(&self) -> DayStage {
+            DayStage::Physics
+        }
+
+        fn run(&self, ctx: &mut DaySystemContext<'_>) -> DayFlow {
+            ctx.emit_system_event(EventSeverity::Info, serde_json::json!({"delta": -1}));
+            DayFlow::Continue
+        }
+    }
+
+    struct NewsCycle;
+
+    impl DaySystem for NewsCycle {
+        fn name(&self) -> &str {
+            "news_cycle"
+        }
+
+        fn stage(&self) -> DayStage {
+            DayStage::Resolve
+        }
+
+        fn rng_phase(&self) -> Option<RngPhase> {
+            Some(RngPhase::Custom(RngStreamMask::single(RngStream::Events)))
+        }
+
+        fn run(&self, _ctx: &mut DaySystemContext<'_>) -> DayFlow {
+            DayFlow::Finish {
+                ended: false,
+                log_key: String::from("log.news"),
+                breakdown_started: false,
+            }
+        }
+    }
+
+    #[test]
+    fn builtin_pipeline_lists_phases_in_tick_order() {
+        let names: Vec<&str> = DayPipeline::builtin_ref().names().collect();
+        assert_eq!(
+            names,
+            [
+                "pending_route",
+                "pending_crossing",
+                "pending_store",
+                "weather",
+                "exec_orders",
+                "supplies",
+                "health",
+                "pacing",
+                "boss",
+                "wait",
+                "intent",
+                "travel",
+            ]
+        );
+    }
+
+    #[test]
+    fn registration_orders_systems_and_rejects_conflicts() {
+        let mut pipeline = DayPipeline::builtin();
+        pipeline.register_after("supplies", Reputation).unwrap();
+        let names: Vec<&str> = pipeline.names().collect();
+        let supplies = names.iter().position(|name| *name == "supplies").unwrap();
+        assert_eq!(names[supplies + 1], "reputation_decay");
+
+        assert_eq!(
+            pipeline.register(Reputation),
+            Err(DayPipelineError::DuplicateName(String::from(
+                "reputation_decay"
+            )))
+        );
+        assert_eq!(
+            pipeline.register_before("nope", NewsCycle),
+            Err(DayPipelineError::UnknownAnchor(String::from("nope")))
+        );
+        assert!(matches!(
+            pipeline.register_before("weather", NewsCycle),
+            Err(DayPipelineError::StageMismatch { .. })
+        ));
+        pipeline.register_before("travel", NewsCycle).unwrap();
+        let names: Vec<&str> = pipeline.names().collect();
+        assert_eq!(names[names.len() - 2..], ["news_cycle", "travel"]);
+    }
+
+    #[test]
+    fn registered_systems_run_inside_the_tick() {
+        let mut pipeline = DayPipeline::builtin();
+        pipeline.register_after("health", Reputation).unwrap();
+        pipeline.register_before("travel", NewsCycle).unwrap();
+        let cfg = JourneyCfg::default();
+        let endgame = EndgameTravelCfg::default_config();
+        let kernel = DailyTickKernel::with_pipeline(&cfg, &endgame, &pipeline);
+
+        let mut state = GameState::default();
+        let outcome = kernel.tick_day(&mut state);
+
+        assert_eq!(outcome.log_key, "log.news");
+        assert!(state.day_records.is_empty());
+        let position = |kind: EventKind| {
+            outcome
+                .events
+                .iter()
+                .position(|event| event.kind == kind)
+                .expect("event emitted")
+        };
+        let system = position(EventKind::SystemEmitted);
+        assert!(system > position(EventKind::HealthTickApplied));
+        assert_eq!(
+            outcome.events[system].tags.first(),
+            Some(&DayTag::new("reputation_decay"))
+        );
+    }
+
+    #[test]
+    fn days_off_the_road_run_the_session_systems() {
+        let endgame = EndgameTravelCfg::default_config();
+        let mut session = crate::journey::JourneySession::new(
+            crate::GameMode::Classic,
+            crate::journey::StrategyId::Balanced,
+            11,
+            crate::EncounterData::empty(),
+            &endgame,
+        );
+        session
+            .pipeline_mut()
+            .register_after("health", Reputation)
+            .unwrap();
+        let mut camp = crate::camp::CampConfig::default_config();
+        camp.rest.day = 1;
+        let state = session.state_mut();
+        let outcome = crate::camp::camp_rest(state, &camp);
+        assert!(outcome.rested);
+        assert!(state.events_today.iter().any(|event| {
+            event.kind == EventKind::SystemEmitted
+                && event.tags.first() == Some(&DayTag::new("reputation_decay"))
+        }));
+
+        let mut detached = GameState::default();
+        let _ = crate::camp::camp_rest(&mut detached, &camp);
+        assert!(
+            !detached
+                .events_today
+                .iter()
+                .any(|event| event.kind == EventKind::SystemEmitted)
+        );
+    }
+}
@@ -0,0 +1,311 @@
+//! Human-readable breakdowns of weighted-pool decision traces.
+//!
+//! Producers record rolls on different scales: some roll on the cumulative
+//! final weight, some on the unmodified base weight, and a few record an index
+//! or a normalised draw. Each trace carries its [`RollScale`], which decides
+//! the band each candidate covers; sampled values are reported without bands.
+use std::fmt::{self, Write as _};
+
+use serde::Serialize;
+
+use crate::journey::{EventDecisionTrace, RollScale, RollValue, WeightFactor};
+
+/// One candidate of an explained trace.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CandidateExplanation {
+    pub id: String,
+    pub base_weight: f64,
+    pub factors: Vec<WeightFactor>,
+    pub final_weight: f64,
+    /// Fraction of the total final weight held by this candidate.
+    pub share: f64,
+    /// Half-open `[lo, hi)` slice of the roll scale covered by this candidate.
+    pub band: Option<(f64, f64)>,
+    pub chosen: bool,
+}
+
+/// Readable breakdown of an [`EventDecisionTrace`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TraceExplanation {
+    pub pool_id: String,
+    pub roll: f64,
+    pub scale: RollScale,
+    pub total_weight: f64,
+    pub chosen_id: String,
+    pub candidates: Vec<CandidateExplanation>,
+}
+
+impl EventDecisionTrace {
+    /// Explain how the roll and candidate weights produced `chosen_id`.
+    #[must_use]
+    pub fn explain(&self) -> TraceExplanation {
+        let roll = roll_value(self.roll);
+        let total_final: f64 = self.candidates.iter().map(|c| c.final_weight).sum();
+        let scale = self.roll_scale;
+
+        let mut cursor = 0.0_f64;
+        let candidates = self
+            .candidates
+            .iter()
+            .map(|candidate| {
+                let width = match scale {
+                    RollScale::Final => Some(candidate.final_weight),
+                    RollScale::Base => Some(candidate.base_weight),
+                    RollScale::Share if total_final > 0.0 => {
+                        Some(candidate.final_weight / total_final)
+                    }
+                    RollScale::Index => Some(1.0),
+                    RollScale::Share | RollScale::Value => None,
+                };
+                let band = width.map(|width| {
+                    let lo = cursor;
+                    cursor += width.max(0.0);
+                    (lo, cursor)
+                });
+                CandidateExplanation {
+                    id: candidate.id.clone(),
+                    base_weight: candidate.base_weight,
+                    factors: candidate.multipliers.clone(),
+                    final_weight: candidate.final_weight,
+                    share: if total_final > 0.0 {
+                        candidate.final_weight / total_final
+                    } else {
+                        0.0
+                    },
+                    band,
+                    chosen: candidate.id == self.chosen_id,
+                }
+            })
+            .collect();
+
+        TraceExplanation {
+            pool_id: self.pool_id.clone(),
+            roll,
+            scale,
+            total_weight: total_final,
+            chosen_id: self.chosen_id.clone(),
+            candidates,
+        }
+    }
+}
+
+impl CandidateExplanation {
+    /// Weight arithmetic, e.g. `1 ×storm 1.4 ×deep_mode 1.2 = 1.68 (36%)`.
+    #[must_use]
+    pub fn weight_line(&self) -> String {
+        let mut line = number(self.base_weight);
+        for factor in &self.factors {
+            let _ = write!(line, " ×{} {}", factor.label, number(factor.value));
+        }
+        let _ = write!(
+            line,
+            " = {} ({:.0}%)",
+            number(self.final_weight),
+            self.share * 100.0
+        );
+        line
+    }
+}
+
+impl TraceExplanation {
+    /// The chosen candidate, when it appears in the candidate list.
+    #[must_use]
+    pub fn chosen(&self) -> Option<&CandidateExplanation> {
+        self.candidates.iter().find(|candidate| candidate.chosen)
+    }
+
+    /// One-line answer to "why did this happen?".
+    #[must_use]
+    pub fn summary(&self) -> String {
+        let mut line = String::new();
+        if let Some(chosen) = self.chosen() {
+            for factor in &chosen.factors {
+                let _ = write!(line, "{} ×{}, ", factor.label, number(factor.value));
+            }
+            match chosen.band {
+                Some((lo, hi)) if lo > 0.0 => {
+                    let _ = write!(
+                        line,
+                        "rolled {} in {}..{}",
+                        number(self.roll),
+                        number(lo),
+                        number(hi)
+                    );
+                }
+                Some((_, hi)) => {
+                    let _ = write!(line, "rolled {} < {}", number(self.roll), number(hi));
+                }
+                None => {
+                    let _ = write!(line, "rolled {}", number(self.roll));
+                }
+            }
+        } else {
+            let _ = write!(line, "rolled {}", number(self.roll));
+        }
+        let _ = write!(line, " → {}", self.chosen_id);
+        line
+    }
+}
+
+impl fmt::Display for TraceExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}: {}", self.pool_id, self.summary())?;
+        for candidate in &self.candidates {
+            let marker = if candidate.chosen { '*' } else { ' ' };
+            write!(
+                f,
+                "  {marker} {}: {}",
+                candidate.id,
+                candidate.weight_line()
+            )?;
+            if let Some((lo, hi)) = candidate.band {
+                write!(f, " [{}, {})", number(lo), number(hi))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn roll_value(roll: RollValue) -> f64 {
+    match roll {
+        RollValue::U32(value) => f64::from(value),
+        RollValue::F32(value) => f64::from(value),
+        RollValue::F64(value) => value,
+    }
+}
+
+/// Format with up to three decimals, trimming trailing zeros.
+fn number(value: f64) -> String {
+    let text = format!("{value:.3}");
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    if text == "-0" {
+        String::from("0")
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::journey::WeightedCandidate;
+
+    fn candidate(id: &str, base: f64, factors: &[(&str, f64)]) -> WeightedCandidate {
+        let multipliers: Vec<WeightFactor> = factors
+            .iter()
+            .map(|&(label, value)| WeightFactor {
+                label: label.to_string(),
+                value,
+            })
+            .collect();
+        let final_weight = multipliers.iter().fold(base, |acc, f| acc * f.value);
+        WeightedCandidate {
+            id: id.to_string(),
+            base_weight: base,
+            multipliers,
+            final_weight,
+        }
+    }
+
+    fn breakdown_trace(roll: RollValue) -> EventDecisionTrace {
+        EventDecisionTrace {
+            pool_id: String::from("dystrail.breakdown"),
+            roll,
+            roll_scale: RollScale::Final,
+            candidates: vec![
+                candidate("breakdown", 0.25, &[("Storm", 1.4), ("Deep mode", 1.2)]),
+                candidate("none", 0.58, &[("Storm", 1.0)]),
+            ],
+            chosen_id: String::from("breakdown"),
+        }
+    }
+
+    #[test]
+    fn summary_reads_factors_and_roll_band() {
+        let explanation = breakdown_trace(RollValue::F32(0.31)).explain();
+        assert_eq!(explanation.scale, RollScale::Final);
+        assert_eq!(
+            explanation.summary(),
+            "Storm ×1.4, Deep mode ×1.2, rolled 0.31 < 0.42 → breakdown"
+        );
+        let chosen = explanation.chosen().unwrap();
+        assert!((chosen.share - 0.42 / 1.0).abs() < 1e-9);
+        assert_eq!(
+            chosen.weight_line(),
+            "0.25 ×Storm 1.4 ×Deep mode 1.2 = 0.42 (42%)"
+        );
+    }
+
+    #[test]
+    fn later_candidates_report_their_slice() {
+        let mut trace = breakdown_trace(RollValue::F64(0.9));
+        trace.chosen_id = String::from("none");
+        let explanation = trace.explain();
+        assert_eq!(
+            explanation.summary(),
+            "Storm ×1, rolled 0.9 in 0.42..1 → none"
+        );
+        let text = explanation.to_string();
+        assert!(text.starts_with("dystrail.breakdown: "));
+        assert!(text.contains("  * none: 0.58 ×Storm 1 = 0.58 (58%) [0.42, 1)"));
+        assert!(text.contains("    breakdown: "));
+    }
+
+    #[test]
+    fn bands_follow_the_recorded_roll_scale() {
+        // 0.31 fits both scales; the recorded scale decides which band applies.
+        let mut trace = breakdown_trace(RollValue::F32(0.31));
+        trace.candidates[0].base_weight = 0.2;
+        trace.roll_scale = RollScale::Base;
+        trace.chosen_id = String::from("none");
+        let explanation = trace.explain();
+        assert_eq!(explanation.scale, RollScale::Base);
+        assert_eq!(explanation.candidates[0].band, Some((0.0, 0.2)));
+        assert_eq!(
+            explanation.summary(),
+            "Storm ×1, rolled 0.31 in 0.2..0.78 → none"
+        );
+
+        let mut trace = breakdown_trace(RollValue::U32(1));
+        trace.roll_scale = RollScale::Index;
+        trace.chosen_id = String::from("none");
+        assert_eq!(trace.explain().candidates[1].band, Some((1.0, 2.0)));
+
+        let mut trace = breakdown_trace(RollValue::F32(0.5));
+        trace.roll_scale = RollScale::Share;
+        let explanation = trace.explain();
+        let (_, hi) = explanation.candidates[0].band.expect("share band");
+        assert!((hi - 0.42).abs() < 1e-9);
+
+        let mut trace = breakdown_trace(RollValue::U32(40));
+        trace.roll_scale = RollScale::Value;
+        let explanation = trace.explain();
+        assert_eq!(explanation.candidates[0].band, None);
+        assert_eq!(
+            explanation.summary(),
+            "Storm ×1.4, Deep mode ×1.2, rolled 40 → breakdown"
+        );
+    }
+
+    #[test]
+    fn explains_live_session_traces() {
+        let mut session = crate::journey::JourneySession::new(
+            crate::state::GameMode::Deep,
+            crate::journey::StrategyId::Balanced,
+            11,
+            crate::data::EncounterData::load_from_static(),
+            &crate::endgame::EndgameTravelCfg::default_config(),
+        );
+        let mut explained = 0;
+        for _ in 0..60 {
+            for trace in session.tick_day().decision_traces {
+                let explanation = trace.explain();
+                assert!(explanation.summary().ends_with(&trace.chosen_id));
+                assert_eq!(explanation.candidates.len(), trace.candidates.len());
+                explained += 1;
+            }
+        }
+        assert!(explained > 0);
+    }
+}
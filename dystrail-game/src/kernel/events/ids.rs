@@ -1,4 +1,5 @@
 use crate::journey::EventKind;
+use serde::{Serialize, Serializer};
 
 /// Version for the externally stable kernel event code schema.
 pub const KERNEL_EVENT_CODE_SCHEMA_VERSION: u16 = 1;
@@ -23,10 +24,13 @@ pub enum KernelEventCode {
     NavigationEvent,
     CrossingResolved,
     TravelBlocked,
+    TombstonePassed,
+    ForageResolved,
+    SystemEmitted,
 }
 
 /// Ordered canonical list of all stable kernel event codes.
-pub const KERNEL_EVENT_CODES: [KernelEventCode; 17] = [
+pub const KERNEL_EVENT_CODES: [KernelEventCode; 20] = [
     KernelEventCode::LegacyLog,
     KernelEventCode::WeatherResolved,
     KernelEventCode::DailyConsumptionApplied,
@@ -44,6 +48,9 @@ pub const KERNEL_EVENT_CODES: [KernelEventCode; 17] = [
     KernelEventCode::NavigationEvent,
     KernelEventCode::CrossingResolved,
     KernelEventCode::TravelBlocked,
+    KernelEventCode::TombstonePassed,
+    KernelEventCode::ForageResolved,
+    KernelEventCode::SystemEmitted,
 ];
 
 impl KernelEventCode {
@@ -67,10 +74,19 @@ impl KernelEventCode {
             Self::NavigationEvent => "event.navigation.resolved",
             Self::CrossingResolved => "event.crossing.resolved",
             Self::TravelBlocked => "event.travel.blocked",
+            Self::TombstonePassed => "event.tombstone.passed",
+            Self::ForageResolved => "event.forage.resolved",
+            Self::SystemEmitted => "event.system.emitted",
         }
     }
 }
 
+impl Serialize for KernelEventCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
 impl From<&EventKind> for KernelEventCode {
     fn from(value: &EventKind) -> Self {
         match value {
@@ -91,6 +107,9 @@ impl From<&EventKind> for KernelEventCode {
             EventKind::NavigationEvent => Self::NavigationEvent,
             EventKind::CrossingResolved => Self::CrossingResolved,
             EventKind::TravelBlocked => Self::TravelBlocked,
+            EventKind::TombstonePassed => Self::TombstonePassed,
+            EventKind::ForageResolved => Self::ForageResolved,
+            EventKind::SystemEmitted => Self::SystemEmitted,
         }
     }
 }
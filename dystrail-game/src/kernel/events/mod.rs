@@ -1,7 +1,12 @@
+pub mod explain;
 pub mod ids;
 pub mod payload;
+pub mod sink;
 pub mod trace;
 
+pub use crate::journey::RollScale;
+pub use explain::{CandidateExplanation, TraceExplanation};
 pub use ids::{KERNEL_EVENT_CODE_SCHEMA_VERSION, KERNEL_EVENT_CODES, KernelEventCode};
 pub use payload::KernelEventPayload;
+pub use sink::{EventSink, JsonlEventSink};
 pub use trace::KernelDecisionTrace;
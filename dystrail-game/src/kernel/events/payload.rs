@@ -1,5 +1,8 @@
+use serde::Serialize;
+
 /// Stable payload container used by kernel events.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(transparent)]
 pub struct KernelEventPayload(pub serde_json::Value);
 
 impl KernelEventPayload {
@@ -0,0 +1,216 @@
+use std::io::{self, Write};
+
+use serde::Serialize;
+
+use super::{KERNEL_EVENT_CODE_SCHEMA_VERSION, KernelDecisionTrace};
+use crate::kernel::KernelEvent;
+
+/// Observer for the kernel event stream, fed as each day resolves.
+pub trait EventSink {
+    /// Marks the start of a new run; subsequent records belong to it.
+    fn begin_run(&mut self, _run: &str) {}
+
+    /// Receives one event in emission order.
+    fn emit_event(&mut self, event: &KernelEvent);
+
+    /// Receives one decision trace recorded while simulating `day`.
+    fn emit_trace(&mut self, day: u32, trace: &KernelDecisionTrace);
+}
+
+/// Forward a resolved day to `sink`: events first, then decision traces.
+pub(crate) fn emit_day<S: EventSink + ?Sized>(
+    sink: &mut S,
+    day: u32,
+    events: &[KernelEvent],
+    traces: &[KernelDecisionTrace],
+) {
+    for event in events {
+        sink.emit_event(event);
+    }
+    for trace in traces {
+        sink.emit_trace(day, trace);
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum StreamRecord<'a> {
+    Event {
+        event: &'a KernelEvent,
+    },
+    Trace {
+        day: u32,
+        trace: &'a KernelDecisionTrace,
+    },
+}
+
+#[derive(Serialize)]
+struct StreamLine<'a> {
+    schema_version: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    run: Option<&'a str>,
+    #[serde(flatten)]
+    record: StreamRecord<'a>,
+}
+
+/// Writes the event stream as JSON Lines, one record per line.
+///
+/// Every line carries `schema_version` (the kernel event code schema), the
+/// current run label when one was set, and a `kind` of `event` or `trace`.
+/// Write failures are latched and reported by [`JsonlEventSink::flush`].
+#[derive(Debug)]
+pub struct JsonlEventSink<W: Write> {
+    writer: W,
+    run: Option<String>,
+    lines: usize,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> JsonlEventSink<W> {
+    pub const fn new(writer: W) -> Self {
+        Self {
+            writer,
+            run: None,
+            lines: 0,
+            error: None,
+        }
+    }
+
+    /// Number of records written so far.
+    #[must_use]
+    pub const fn lines_written(&self) -> usize {
+        self.lines
+    }
+
+    /// Flush the writer, surfacing the first write error encountered.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first serialization or I/O error seen while streaming, or
+    /// the error from flushing the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+        self.writer.flush()
+    }
+
+    /// Flush and return the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`JsonlEventSink::flush`].
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.flush()?;
+        Ok(self.writer)
+    }
+
+    fn write_record(&mut self, record: StreamRecord<'_>) {
+        if self.error.is_some() {
+            return;
+        }
+        let line = StreamLine {
+            schema_version: KERNEL_EVENT_CODE_SCHEMA_VERSION,
+            run: self.run.as_deref(),
+            record,
+        };
+        let result = serde_json::to_writer(&mut self.writer, &line)
+            .map_err(io::Error::from)
+            .and_then(|()| self.writer.write_all(b"\n"));
+        match result {
+            Ok(()) => self.lines += 1,
+            Err(err) => self.error = Some(err),
+        }
+    }
+}
+
+impl<W: Write> EventSink for JsonlEventSink<W> {
+    fn begin_run(&mut self, run: &str) {
+        self.run = Some(run.to_string());
+    }
+
+    fn emit_event(&mut self, event: &KernelEvent) {
+        self.write_record(StreamRecord::Event { event });
+    }
+
+    fn emit_trace(&mut self, day: u32, trace: &KernelDecisionTrace) {
+        self.write_record(StreamRecord::Trace { day, trace });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::journey::{DayTagSet, EventId, EventSeverity, RollScale, RollValue};
+    use crate::kernel::KernelEventCode;
+
+    fn sample_event() -> KernelEvent {
+        KernelEvent {
+            id: EventId::new(3, 1),
+            day: 3,
+            code: KernelEventCode::WeatherResolved,
+            severity: EventSeverity::Warning,
+            tags: DayTagSet::new(),
+            ui_key: None,
+            payload: serde_json::json!({"weather": "Storm"}).into(),
+        }
+    }
+
+    fn sample_trace() -> KernelDecisionTrace {
+        KernelDecisionTrace {
+            pool_id: String::from("dystrail.encounters"),
+            roll: RollValue::F32(0.25),
+            roll_scale: RollScale::Final,
+            candidates: Vec::new(),
+            chosen_id: String::from("rally"),
+        }
+    }
+
+    struct FailingWriter;
+
+    impl Write for FailingWriter {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::Error::other("disk full"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn jsonl_sink_writes_versioned_lines() {
+        let mut sink = JsonlEventSink::new(Vec::new());
+        sink.begin_run("classic-7");
+        emit_day(&mut sink, 3, &[sample_event()], &[sample_trace()]);
+        assert_eq!(sink.lines_written(), 2);
+
+        let bytes = sink.into_inner().expect("flush");
+        let text = String::from_utf8(bytes).expect("utf8");
+        let lines: Vec<serde_json::Value> = text
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("json line"))
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["schema_version"], KERNEL_EVENT_CODE_SCHEMA_VERSION);
+        assert_eq!(lines[0]["run"], "classic-7");
+        assert_eq!(lines[0]["kind"], "event");
+        assert_eq!(lines[0]["event"]["code"], "event.weather.resolved");
+        assert_eq!(lines[0]["event"]["severity"], "warning");
+        assert_eq!(lines[0]["event"]["payload"]["weather"], "Storm");
+        assert_eq!(lines[1]["kind"], "trace");
+        assert_eq!(lines[1]["day"], 3);
+        assert_eq!(lines[1]["trace"]["chosen_id"], "rally");
+    }
+
+    #[test]
+    fn jsonl_sink_latches_write_errors() {
+        let mut sink = JsonlEventSink::new(FailingWriter);
+        sink.emit_event(&sample_event());
+        sink.emit_trace(1, &sample_trace());
+        assert_eq!(sink.lines_written(), 0);
+        let err = sink.flush().expect_err("write error");
+        assert_eq!(err.to_string(), "disk full");
+        assert!(sink.flush().is_ok());
+    }
+}
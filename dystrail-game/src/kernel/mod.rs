@@ -11,8 +11,9 @@ pub mod systems;
 pub mod types;
 
 pub use events::{
-    KERNEL_EVENT_CODE_SCHEMA_VERSION, KERNEL_EVENT_CODES, KernelDecisionTrace, KernelEventCode,
-    KernelEventPayload,
+    CandidateExplanation, EventSink, JsonlEventSink, KERNEL_EVENT_CODE_SCHEMA_VERSION,
+    KERNEL_EVENT_CODES, KernelDecisionTrace, KernelEventCode, KernelEventPayload, RollScale,
+    TraceExplanation,
 };
 pub use session::{KernelSession, KernelSessionError};
 pub use types::{KernelConfig, KernelEvent, KernelState, KernelTickInput, KernelTickOutput};
@@ -26,7 +27,7 @@ mod tests {
         EventSeverity, JourneyCfg, MechanicalPolicyId, PolicyId, StatsDelta, StrategyId,
         TravelDayKind,
     };
-    use crate::mechanics::OtDeluxeOccupation;
+    use crate::otdeluxe_state::OtDeluxeStartConfig;
     use crate::state::{DayIntent, DietId, GameMode, GameState, PaceId, Region, Season};
     use crate::weather::Weather;
 
@@ -102,6 +103,9 @@ mod tests {
             EventKind::NavigationEvent,
             EventKind::CrossingResolved,
             EventKind::TravelBlocked,
+            EventKind::TombstonePassed,
+            EventKind::ForageResolved,
+            EventKind::SystemEmitted,
         ];
         assert_eq!(pairs.len(), KERNEL_EVENT_CODES.len());
         for (index, kind) in pairs.into_iter().enumerate() {
@@ -152,7 +156,7 @@ mod tests {
             99,
             data,
             &endgame,
-            Some(OtDeluxeOccupation::Banker),
+            Some(OtDeluxeStartConfig::default()),
         );
         assert_eq!(session.mechanics(), MechanicalPolicyId::OtDeluxe90s);
     }
@@ -188,7 +192,7 @@ mod tests {
             41,
             data,
             &endgame,
-            Some(OtDeluxeOccupation::Banker),
+            Some(OtDeluxeStartConfig::default()),
         );
         let output = session.tick_day(KernelTickInput {
             intent: DayIntent::Continue,
@@ -198,6 +202,56 @@ mod tests {
         assert_eq!(state.mechanical_policy, MechanicalPolicyId::OtDeluxe90s);
     }
 
+    #[test]
+    fn kernel_session_talk_does_not_advance_the_day() {
+        let data = crate::EncounterData::empty();
+        let endgame = EndgameTravelCfg::default_config();
+        let session = KernelSession::new(
+            GameMode::Classic,
+            StrategyId::Balanced,
+            41,
+            data,
+            &endgame,
+            Some(OtDeluxeStartConfig::default()),
+        );
+        let day = session.state().day;
+        let talk = session.talk().expect("Independence has townsfolk");
+        assert_eq!(talk.landmark_id, "independence");
+        assert_eq!(session.talk(), Some(talk));
+        assert_eq!(session.state().day, day);
+    }
+
+    #[test]
+    fn kernel_session_tick_with_sink_matches_output() {
+        let data = crate::EncounterData::empty();
+        let endgame = EndgameTravelCfg::default_config();
+        let mut session = KernelSession::new(
+            GameMode::Classic,
+            StrategyId::Balanced,
+            41,
+            data,
+            &endgame,
+            Some(OtDeluxeStartConfig::default()),
+        );
+        let mut sink = JsonlEventSink::new(Vec::new());
+        sink.begin_run("otdeluxe-41");
+        let output = session.tick_day_with_sink(
+            KernelTickInput {
+                intent: DayIntent::Continue,
+            },
+            &mut sink,
+        );
+        assert_eq!(
+            sink.lines_written(),
+            output.events.len() + output.decision_traces.len()
+        );
+        let text = String::from_utf8(sink.into_inner().expect("flush")).expect("utf8");
+        let first: serde_json::Value =
+            serde_json::from_str(text.lines().next().expect("line")).expect("json");
+        assert_eq!(first["run"], "otdeluxe-41");
+        assert_eq!(first["event"]["code"], output.events[0].code.as_str());
+    }
+
     #[test]
     fn kernel_aliases_match_legacy_surface() {
         let _config: KernelConfig = JourneyCfg::default();
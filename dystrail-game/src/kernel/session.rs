@@ -1,9 +1,10 @@
 use crate::endgame::EndgameTravelCfg;
 use crate::journey::{JourneySession, MechanicalPolicyId, PolicyId, StrategyId};
-use crate::mechanics::OtDeluxeOccupation;
+use crate::otdeluxe_state::OtDeluxeStartConfig;
 use crate::state::GameMode;
 use thiserror::Error;
 
+use super::events::{EventSink, sink};
 use super::{KernelTickInput, KernelTickOutput, phases};
 
 /// Errors constructing or running the `OTDeluxe` kernel facade.
@@ -28,7 +29,7 @@ impl KernelSession {
         seed: u64,
         data: crate::EncounterData,
         endgame_cfg: &EndgameTravelCfg,
-        occupation: Option<OtDeluxeOccupation>,
+        start: Option<OtDeluxeStartConfig>,
     ) -> Self {
         let inner = JourneySession::new_with_mechanics(
             MechanicalPolicyId::OtDeluxe90s,
@@ -37,7 +38,7 @@ impl KernelSession {
             seed,
             data,
             endgame_cfg,
-            occupation,
+            start,
         );
         Self { inner }
     }
@@ -65,6 +66,24 @@ impl KernelSession {
         phases::tick_day(&mut self.inner, input)
     }
 
+    /// Advances one day and streams its events and decision traces to `sink`.
+    pub fn tick_day_with_sink<S: EventSink + ?Sized>(
+        &mut self,
+        input: KernelTickInput,
+        sink: &mut S,
+    ) -> KernelTickOutput {
+        let day = self.inner.state().day;
+        let output = self.tick_day(input);
+        sink::emit_day(sink, day, &output.events, &output.decision_traces);
+        output
+    }
+
+    /// Talks to people at the current landmark without advancing the day.
+    #[must_use]
+    pub fn talk(&self) -> Option<crate::TalkOutcome> {
+        self.inner.state().talk_at_landmark()
+    }
+
     /// Returns the immutable game state.
     #[must_use]
     pub const fn state(&self) -> &crate::GameState {
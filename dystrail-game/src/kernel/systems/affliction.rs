@@ -1,6 +1,6 @@
 use rand::Rng;
 
-use crate::journey::{EventDecisionTrace, RollValue, WeightedCandidate};
+use crate::journey::{EventDecisionTrace, RollScale, RollValue, WeightedCandidate};
 use crate::mechanics::otdeluxe90s::{OtDeluxeAfflictionPolicy, OtDeluxePolicyOverride};
 use crate::otdeluxe_state::{OtDeluxeAfflictionKind, OtDeluxeAfflictionOutcome};
 
@@ -84,6 +84,7 @@ pub fn roll_otdeluxe_affliction_kind_with_trace<R: Rng + ?Sized>(
     let trace = EventDecisionTrace {
         pool_id: String::from("otdeluxe.affliction_kind"),
         roll: RollValue::U32(roll),
+        roll_scale: RollScale::Final,
         candidates,
         chosen_id: chosen_id.to_string(),
     };
@@ -3,6 +3,8 @@ use rand::Rng;
 use crate::disease::{FatalityModel, FatalityModifier};
 use crate::mechanics::OtDeluxeOccupation;
 use crate::mechanics::otdeluxe90s::{OtDeluxe90sPolicy, OtDeluxePace, OtDeluxeRations};
+use crate::otdeluxe_skills::OtDeluxeAgeGroup;
+use crate::state::HealthLabel;
 use crate::weather::Weather;
 
 #[derive(Debug, Clone, Copy)]
@@ -12,14 +14,9 @@ pub struct OtDeluxeFatalityContext {
     pub rations: OtDeluxeRations,
     pub weather: Weather,
     pub occupation: Option<OtDeluxeOccupation>,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum OtDeluxeHealthLabel {
-    Good,
-    Fair,
-    Poor,
-    VeryPoor,
+    pub treated: bool,
+    /// Age group of the sick member; unassigned members count as adults.
+    pub age_group: Option<OtDeluxeAgeGroup>,
 }
 
 #[must_use]
@@ -37,11 +34,11 @@ pub fn otdeluxe_fatality_probability(
                 fair,
                 poor,
                 very_poor,
-            } => match otdeluxe_health_label(context.health_general, policy) {
-                OtDeluxeHealthLabel::Good => *good,
-                OtDeluxeHealthLabel::Fair => *fair,
-                OtDeluxeHealthLabel::Poor => *poor,
-                OtDeluxeHealthLabel::VeryPoor => *very_poor,
+            } => match policy.health.label_ranges.label(context.health_general) {
+                HealthLabel::Good => *good,
+                HealthLabel::Fair => *fair,
+                HealthLabel::Poor => *poor,
+                HealthLabel::VeryPoor => *very_poor,
             },
             FatalityModifier::Pace {
                 steady,
@@ -68,12 +65,22 @@ pub fn otdeluxe_fatality_probability(
                     1.0
                 }
             }
+            FatalityModifier::Treated { mult } => {
+                if context.treated {
+                    *mult
+                } else {
+                    1.0
+                }
+            }
         };
         prob *= sanitize_multiplier(mult);
     }
     if model.apply_doctor_mult && matches!(context.occupation, Some(OtDeluxeOccupation::Doctor)) {
         prob *= sanitize_multiplier(policy.occupation_advantages.doctor_fatality_mult);
     }
+    if let Some(age_group) = context.age_group {
+        prob *= sanitize_multiplier(age_group.fatality_mult(&policy.skills));
+    }
     if prob.is_finite() {
         prob.clamp(0.0, 1.0)
     } else {
@@ -95,21 +102,6 @@ where
     prob > 0.0 && rng.r#gen::<f32>() < prob
 }
 
-const fn otdeluxe_health_label(
-    health_general: u16,
-    policy: &OtDeluxe90sPolicy,
-) -> OtDeluxeHealthLabel {
-    if health_general <= policy.health.label_ranges.good_max {
-        OtDeluxeHealthLabel::Good
-    } else if health_general <= policy.health.label_ranges.fair_max {
-        OtDeluxeHealthLabel::Fair
-    } else if health_general <= policy.health.label_ranges.poor_max {
-        OtDeluxeHealthLabel::Poor
-    } else {
-        OtDeluxeHealthLabel::VeryPoor
-    }
-}
-
 const fn sanitize_multiplier(mult: f32) -> f32 {
     if mult.is_finite() { mult.max(0.0) } else { 1.0 }
 }
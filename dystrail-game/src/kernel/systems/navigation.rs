@@ -1,6 +1,6 @@
 use rand::Rng;
 
-use crate::journey::{EventDecisionTrace, RollValue, WeightedCandidate};
+use crate::journey::{EventDecisionTrace, RollScale, RollValue, WeightedCandidate};
 use crate::mechanics::otdeluxe90s::{OtDeluxeNavigationDelay, OtDeluxeNavigationPolicy};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -123,6 +123,7 @@ pub fn roll_otdeluxe_navigation_event_with_trace<R: Rng>(
     let trace = Some(EventDecisionTrace {
         pool_id: String::from("otdeluxe.navigation"),
         roll: RollValue::U32(roll),
+        roll_scale: RollScale::Base,
         candidates,
         chosen_id: otdeluxe_navigation_event_id(selected_event).to_string(),
     });
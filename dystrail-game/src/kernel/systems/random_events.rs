@@ -1,3 +1,14 @@
+use std::collections::BTreeMap;
+
+use rand::Rng;
+
+use crate::numbers::round_f32_to_i32;
+use crate::otdeluxe_random_events::{
+    OtDeluxeAfflictionTarget, OtDeluxeOxenChange, OtDeluxeRandomEventEffect,
+    OtDeluxeRandomEventItem,
+};
+use crate::otdeluxe_state::{OtDeluxeAfflictionKind, OtDeluxeAfflictionOutcome};
+
 #[must_use]
 pub fn sanitize_event_weight_mult(weight_mult: f32) -> f32 {
     if weight_mult.is_finite() && weight_mult >= 0.0 {
@@ -6,3 +17,279 @@ pub fn sanitize_event_weight_mult(weight_mult: f32) -> f32 {
         1.0
     }
 }
+
+/// State the random event effect interpreter reads and mutates.
+///
+/// Every method returns the change actually applied after clamping.
+pub(crate) trait RandomEventHost {
+    fn adjust_health(&mut self, delta: i32) -> i32;
+    fn inventory_level(&self, item: OtDeluxeRandomEventItem) -> u16;
+    fn adjust_inventory(&mut self, item: OtDeluxeRandomEventItem, delta: i32) -> i32;
+    fn add_delay_days(&mut self, days: u8) -> u8;
+    fn afflict<R: Rng + ?Sized>(
+        &mut self,
+        rng: &mut R,
+        kind: OtDeluxeAfflictionKind,
+        target: OtDeluxeAfflictionTarget,
+    ) -> Option<OtDeluxeAfflictionOutcome>;
+    fn lose_members<R: Rng + ?Sized>(&mut self, rng: &mut R, count: u8) -> Vec<usize>;
+    /// Returns the `(healthy, sick)` oxen deltas.
+    fn change_oxen(&mut self, change: OtDeluxeOxenChange) -> (i16, i16);
+    /// Returns the poor-grazing days added.
+    fn spoil_grazing(&mut self, days: u8) -> u8;
+    fn lose_spare<R: Rng + ?Sized>(&mut self, rng: &mut R) -> Option<&'static str>;
+}
+
+/// Applied result of a variant's effect list.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RandomEventEffectOutcome {
+    /// Net change per payload key, e.g. `food_lbs` or `oxen_sick`.
+    pub deltas: BTreeMap<&'static str, i32>,
+    /// One entry per affliction effect; `None` when nobody could be afflicted.
+    pub afflictions: Vec<Option<OtDeluxeAfflictionOutcome>>,
+    /// Set when the list contained a member-loss effect.
+    pub lost_members: Option<Vec<usize>>,
+    /// Set when the list contained a spare-part effect; the inner value is the part lost.
+    pub spare_lost: Option<Option<&'static str>>,
+}
+
+impl RandomEventEffectOutcome {
+    fn add_delta(&mut self, key: &'static str, delta: i32) {
+        *self.deltas.entry(key).or_insert(0) += delta;
+    }
+}
+
+/// Apply `effects` in order, drawing from `rng` only where an effect needs it.
+pub(crate) fn apply_random_event_effects<H, R>(
+    host: &mut H,
+    effects: &[OtDeluxeRandomEventEffect],
+    rng: &mut R,
+) -> RandomEventEffectOutcome
+where
+    H: RandomEventHost,
+    R: Rng + ?Sized,
+{
+    let mut outcome = RandomEventEffectOutcome::default();
+    apply_effects_into(host, effects, rng, &mut outcome);
+    outcome
+}
+
+fn apply_effects_into<H, R>(
+    host: &mut H,
+    effects: &[OtDeluxeRandomEventEffect],
+    rng: &mut R,
+    outcome: &mut RandomEventEffectOutcome,
+) where
+    H: RandomEventHost,
+    R: Rng + ?Sized,
+{
+    for effect in effects {
+        match effect {
+            OtDeluxeRandomEventEffect::Health { delta } => {
+                let applied = host.adjust_health(*delta);
+                outcome.add_delta("health_general", applied);
+            }
+            OtDeluxeRandomEventEffect::Inventory {
+                item,
+                amount,
+                ratio,
+            } => {
+                let delta = amount.unwrap_or_else(|| {
+                    let level = f32::from(host.inventory_level(*item));
+                    round_f32_to_i32(level * ratio.unwrap_or(0.0))
+                });
+                let applied = host.adjust_inventory(*item, delta);
+                outcome.add_delta(item.key(), applied);
+            }
+            OtDeluxeRandomEventEffect::DelayDays { days } => {
+                let applied = host.add_delay_days(*days);
+                outcome.add_delta("delay_days", i32::from(applied));
+            }
+            OtDeluxeRandomEventEffect::Affliction { kind, target } => {
+                let afflicted = host.afflict(rng, *kind, *target);
+                outcome.afflictions.push(afflicted);
+            }
+            OtDeluxeRandomEventEffect::LoseMembers { count } => {
+                let lost = host.lose_members(rng, *count);
+                outcome
+                    .lost_members
+                    .get_or_insert_with(Vec::new)
+                    .extend(lost);
+            }
+            OtDeluxeRandomEventEffect::Oxen { change, count } => {
+                let (mut healthy, mut sick) = (0_i32, 0_i32);
+                for _ in 0..*count {
+                    let (healthy_delta, sick_delta) = host.change_oxen(*change);
+                    healthy += i32::from(healthy_delta);
+                    sick += i32::from(sick_delta);
+                }
+                outcome.add_delta("oxen_healthy", healthy);
+                outcome.add_delta("oxen_sick", sick);
+            }
+            OtDeluxeRandomEventEffect::NoGrass { days } => {
+                let applied = host.spoil_grazing(*days);
+                outcome.add_delta("no_grass_days", i32::from(applied));
+            }
+            OtDeluxeRandomEventEffect::LoseSpare { fallback } => {
+                let lost = host.lose_spare(rng);
+                outcome.spare_lost = Some(lost);
+                if lost.is_none() {
+                    apply_effects_into(host, fallback, rng, outcome);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    #[derive(Default)]
+    struct Host {
+        health: i32,
+        food: u16,
+        spares: u8,
+        delay: u8,
+        no_grass: u8,
+        calls: Vec<&'static str>,
+    }
+
+    impl RandomEventHost for Host {
+        fn adjust_health(&mut self, delta: i32) -> i32 {
+            self.calls.push("health");
+            self.health += delta;
+            delta
+        }
+
+        fn inventory_level(&self, _item: OtDeluxeRandomEventItem) -> u16 {
+            self.food
+        }
+
+        fn adjust_inventory(&mut self, item: OtDeluxeRandomEventItem, delta: i32) -> i32 {
+            self.calls.push(item.key());
+            let next = (i32::from(self.food) + delta).max(0);
+            let applied = next - i32::from(self.food);
+            self.food = u16::try_from(next).unwrap_or(u16::MAX);
+            applied
+        }
+
+        fn add_delay_days(&mut self, days: u8) -> u8 {
+            self.delay += days;
+            days
+        }
+
+        fn afflict<R: Rng + ?Sized>(
+            &mut self,
+            _rng: &mut R,
+            kind: OtDeluxeAfflictionKind,
+            target: OtDeluxeAfflictionTarget,
+        ) -> Option<OtDeluxeAfflictionOutcome> {
+            self.calls.push("afflict");
+            let OtDeluxeAfflictionTarget::Member(member_index) = target else {
+                return None;
+            };
+            Some(OtDeluxeAfflictionOutcome {
+                member_index,
+                died: false,
+                kind,
+                disease_id: None,
+                display_key: None,
+            })
+        }
+
+        fn lose_members<R: Rng + ?Sized>(&mut self, _rng: &mut R, count: u8) -> Vec<usize> {
+            (0..usize::from(count)).collect()
+        }
+
+        fn change_oxen(&mut self, change: OtDeluxeOxenChange) -> (i16, i16) {
+            match change {
+                OtDeluxeOxenChange::Lose => (-1, 0),
+                OtDeluxeOxenChange::Sicken => (-1, 1),
+            }
+        }
+
+        fn spoil_grazing(&mut self, days: u8) -> u8 {
+            self.no_grass = self.no_grass.max(days);
+            days
+        }
+
+        fn lose_spare<R: Rng + ?Sized>(&mut self, _rng: &mut R) -> Option<&'static str> {
+            if self.spares == 0 {
+                return None;
+            }
+            self.spares -= 1;
+            Some("wheel")
+        }
+    }
+
+    fn parse(json: &str) -> Vec<OtDeluxeRandomEventEffect> {
+        serde_json::from_str(json).expect("parse effects")
+    }
+
+    #[test]
+    fn effects_apply_in_declared_order_and_sum_deltas() {
+        let effects = parse(
+            r#"[
+                { "type": "affliction", "kind": "illness", "target": { "member": 2 } },
+                { "type": "health", "delta": 2 },
+                { "type": "inventory", "item": "food_lbs", "ratio": -0.25 },
+                { "type": "inventory", "item": "food_lbs", "amount": 5 },
+                { "type": "delay_days", "days": 2 },
+                { "type": "oxen", "change": "sicken", "count": 2 },
+                { "type": "no_grass", "days": 3 },
+                { "type": "lose_members" }
+            ]"#,
+        );
+        let mut host = Host {
+            food: 100,
+            ..Host::default()
+        };
+        let mut rng = SmallRng::seed_from_u64(1);
+        let outcome = apply_random_event_effects(&mut host, &effects, &mut rng);
+
+        assert_eq!(host.calls[..3], ["afflict", "health", "food_lbs"]);
+        assert_eq!(host.food, 80);
+        assert_eq!(host.delay, 2);
+        assert_eq!(outcome.deltas["food_lbs"], -20);
+        assert_eq!(outcome.deltas["health_general"], 2);
+        assert_eq!(outcome.deltas["oxen_healthy"], -2);
+        assert_eq!(outcome.deltas["oxen_sick"], 2);
+        assert_eq!(outcome.deltas["delay_days"], 2);
+        assert_eq!(outcome.deltas["no_grass_days"], 3);
+        assert_eq!(host.no_grass, 3);
+        assert_eq!(outcome.afflictions[0].as_ref().unwrap().member_index, 2);
+        assert_eq!(outcome.lost_members, Some(vec![0]));
+        assert_eq!(outcome.spare_lost, None);
+    }
+
+    #[test]
+    fn lose_spare_runs_fallback_only_without_spares() {
+        let effects = parse(
+            r#"[{ "type": "lose_spare", "fallback": [
+                { "type": "inventory", "item": "food_lbs", "amount": -15 }
+            ] }]"#,
+        );
+        let mut rng = SmallRng::seed_from_u64(2);
+
+        let mut stocked = Host {
+            food: 50,
+            spares: 1,
+            ..Host::default()
+        };
+        let outcome = apply_random_event_effects(&mut stocked, &effects, &mut rng);
+        assert_eq!(outcome.spare_lost, Some(Some("wheel")));
+        assert_eq!(stocked.food, 50);
+        assert!(outcome.deltas.is_empty());
+
+        let mut empty = Host {
+            food: 50,
+            ..Host::default()
+        };
+        let outcome = apply_random_event_effects(&mut empty, &effects, &mut rng);
+        assert_eq!(outcome.spare_lost, Some(None));
+        assert_eq!(outcome.deltas["food_lbs"], -15);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::journey::{EventDecisionTrace, RollValue, WeightedCandidate};
+use crate::journey::{EventDecisionTrace, RollScale, RollValue, WeightedCandidate};
 use crate::mechanics::otdeluxe90s::{OtDeluxe90sPolicy, OtDeluxeOccupation};
 use crate::otdeluxe_state::OtDeluxeInventory;
 use crate::vehicle::{Part, PartWeights};
@@ -105,6 +105,7 @@ pub fn select_breakdown_part_with_trace<R: Rng + ?Sized>(
     let trace = EventDecisionTrace {
         pool_id: String::from("dystrail.breakdown_part"),
         roll: RollValue::U32(roll),
+        roll_scale: RollScale::Final,
         candidates,
         chosen_id: selected.key().to_string(),
     };
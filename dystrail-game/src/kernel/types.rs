@@ -2,6 +2,7 @@ use crate::journey::{
     DayOutcome, DayTagSet, Event, EventId, EventSeverity, JourneyCfg, TravelDayKind,
 };
 use crate::state::DayIntent;
+use serde::Serialize;
 
 use super::events::{KernelDecisionTrace, KernelEventCode, KernelEventPayload};
 
@@ -19,7 +20,7 @@ pub struct KernelTickInput {
 }
 
 /// A kernel event with stable code plus structured payload.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct KernelEvent {
     pub id: EventId,
     pub day: u32,
@@ -31,7 +32,7 @@ pub struct KernelEvent {
 }
 
 impl KernelEvent {
-    fn from_journey_event(event: Event) -> Self {
+    pub(crate) fn from_journey_event(event: Event) -> Self {
         Self {
             id: event.id,
             day: event.day,
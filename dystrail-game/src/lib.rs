@@ -9,28 +9,39 @@ pub mod constants;
 pub mod crossings;
 pub mod data;
 pub mod day_accounting;
+pub mod dialogue;
 pub mod disease;
 pub mod encounters;
 pub mod endgame;
 pub mod exec_orders;
+pub mod forage;
+pub mod graveyard;
 pub mod hunt;
 pub mod journey;
 pub mod kernel;
 pub mod mechanics;
 pub mod numbers;
+pub mod otdeluxe_cargo;
 pub mod otdeluxe_crossings;
+pub mod otdeluxe_oxen;
 pub mod otdeluxe_random_events;
 pub mod otdeluxe_score;
+pub mod otdeluxe_skills;
 pub mod otdeluxe_state;
 pub mod otdeluxe_store;
 pub mod otdeluxe_trail;
 pub mod pacing;
 pub mod personas;
+pub mod player_action;
 pub mod result;
+pub mod run_history;
+pub mod run_timeline;
 pub mod seed;
 pub mod state;
+pub mod state_hash;
 pub mod store;
 pub mod trade;
+pub mod treatment;
 pub mod vehicle;
 pub mod weather;
 
@@ -38,47 +49,78 @@ pub mod weather;
 pub use boss::{BossConfig, BossOutcome, run_boss_minigame};
 pub use camp::{
     CampConfig, CampOutcome, CampState, camp_forage, camp_forage_with_endgame, camp_repair_hack,
-    camp_repair_spare, camp_rest, camp_rest_with_endgame, camp_therapy, can_repair, can_therapy,
+    camp_repair_hack_with_endgame, camp_repair_spare, camp_rest, camp_rest_with_endgame,
+    camp_therapy, can_repair, can_therapy,
 };
 pub use crossings::{
     CrossingChoice, CrossingConfig, CrossingKind, ThresholdEntry, ThresholdTable, apply_bribe,
     apply_detour, apply_permit, calculate_bribe_cost, can_afford_bribe, can_use_permit,
 };
 pub use data::{Choice, Effects, Encounter, EncounterData};
-pub use day_accounting::{DayLedgerMetrics, compute_day_ledger_metrics};
+pub use day_accounting::{DayLedgerMetrics, compute_day_ledger_metrics, cumulative_miles_curve};
+pub use dialogue::{
+    DialogueCatalog, DialogueCondition, DialogueContext, DialogueLandmark, DialogueLine, TalkLine,
+    TalkOutcome,
+};
 pub use disease::{
-    DiseaseCatalog, DiseaseDef, DiseaseEffects, DiseaseKind, FatalityModel, FatalityModifier,
+    DiseaseCatalog, DiseaseDef, DiseaseEffects, DiseaseKind, DiseaseTreatment, FatalityModel,
+    FatalityModifier,
 };
 pub use endgame::{EndgameState, EndgameTravelCfg};
+pub use forage::{
+    ForageBlockReason, ForageKind, ForageOutcome, ForagingCatalog, resolve_forage,
+    resolve_forage_with_rng,
+};
+pub use graveyard::{
+    GRAVEYARD_MAX_PER_SEGMENT, GRAVEYARD_SCHEMA_VERSION, Graveyard, Tombstone, tombstones_for_run,
+};
 pub use hunt::{HuntBlockReason, HuntOutcome, resolve_hunt, resolve_hunt_with_rng};
 pub use journey::{
     AcceptanceGuards, BribePolicy, CrossingPolicy, CrossingPolicyOverlay, DailyTickOutcome,
-    DayEffects, DayEvents, DayInputs, DayOutcome, DayRecord, DayTag, DayTagSet, DetourPolicy,
-    Event, EventDecisionTrace, EventId, EventKind, EventSeverity, JourneyCfg, JourneyController,
-    JourneySession, MechanicalPolicyId, PermitPolicy, PolicyId, RngBundle, StatsDelta, StrategyId,
-    TravelDayKind, UiSurfaceHint,
+    DayDeltas, DayEffects, DayEvents, DayFlow, DayInputs, DayOutcome, DayPipeline,
+    DayPipelineError, DayRecord, DayStage, DaySystem, DaySystemContext, DayTag, DayTagSet,
+    DetourPolicy, EndOfDaySnapshot, Event, EventDecisionTrace, EventId, EventKind, EventSeverity,
+    JourneyCfg, JourneyController, JourneySession, MechanicalPolicyId, PermitPolicy, PolicyId,
+    RngBundle, RngPhase, RngStream, RngStreamMask, StatsDelta, StrategyId, TravelDayKind,
+    UiSurfaceHint,
 };
 pub use kernel::{
-    KERNEL_EVENT_CODE_SCHEMA_VERSION, KERNEL_EVENT_CODES, KernelConfig, KernelDecisionTrace,
-    KernelEvent, KernelEventCode, KernelEventPayload, KernelSession, KernelSessionError,
-    KernelState, KernelTickInput, KernelTickOutput,
+    CandidateExplanation, EventSink, JsonlEventSink, KERNEL_EVENT_CODE_SCHEMA_VERSION,
+    KERNEL_EVENT_CODES, KernelConfig, KernelDecisionTrace, KernelEvent, KernelEventCode,
+    KernelEventPayload, KernelSession, KernelSessionError, KernelState, KernelTickInput,
+    KernelTickOutput, RollScale, TraceExplanation,
 };
 pub use mechanics::{
     OtDeluxe90sPolicy, OtDeluxeAfflictionWeightOverride, OtDeluxeBreakdownPolicy,
-    OtDeluxeOccupation, OtDeluxePace, OtDeluxePolicyOverride, OtDeluxeRations,
-    OtDeluxeTrailVariant, OtDeluxeWeatherEffectsOverride,
+    OtDeluxeCargoPolicy, OtDeluxeOccupation, OtDeluxePace, OtDeluxePolicyOverride, OtDeluxeRations,
+    OtDeluxeSkillPolicy, OtDeluxeTrailVariant, OtDeluxeTreatmentPolicy,
+    OtDeluxeWeatherEffectsOverride,
+};
+pub use otdeluxe_cargo::{
+    OtDeluxeCargoItem, OtDeluxeCargoLoad, cargo_load as otdeluxe_cargo_load,
+    load_after_purchase as otdeluxe_cargo_after_purchase,
 };
 pub use otdeluxe_crossings::{
-    OtDeluxeCrossingOptions, OtDeluxeCrossingOutcome, OtDeluxeCrossingResolution,
+    OtDeluxeCrossingInfo, OtDeluxeCrossingMethodInfo, OtDeluxeCrossingOptions,
+    OtDeluxeCrossingOutcome, OtDeluxeCrossingResolution, OtDeluxeGuidePayment,
+    crossing_info as otdeluxe_crossing_info, crossing_odds as otdeluxe_crossing_odds,
     crossing_options as otdeluxe_crossing_options,
-    derive_river_state as otdeluxe_derive_river_state,
+    derive_river_state as otdeluxe_derive_river_state, guide_payment as otdeluxe_guide_payment,
     node_index_for_river as otdeluxe_node_index_for_river,
     river_for_index as otdeluxe_river_for_index,
 };
+pub use otdeluxe_oxen::{
+    OtDeluxeHerd, OtDeluxeHerdTick, OtDeluxeOx, OtDeluxeOxDeath, OtDeluxeOxDeathCause,
+};
 pub use otdeluxe_score::compute_score as compute_otdeluxe_score;
+pub use otdeluxe_skills::{
+    DEFAULT_PARTY_ROLES, OTDELUXE_PARTY_SIZE, OtDeluxeAgeGroup, OtDeluxePartyRole, OtDeluxeSkill,
+};
 pub use otdeluxe_state::{
-    OtDeluxeCrossingMethod, OtDeluxeDallesChoice, OtDeluxeRiver, OtDeluxeRiverBed,
-    OtDeluxeRouteDecision, OtDeluxeRoutePrompt,
+    OTDELUXE_DEPARTURE_MONTHS, OTDELUXE_EPITAPH_MAX_CHARS, OtDeluxeCalendar,
+    OtDeluxeCrossingMethod, OtDeluxeDallesChoice, OtDeluxeDeathCause, OtDeluxeDeathRecord,
+    OtDeluxeRiver, OtDeluxeRiverBed, OtDeluxeRouteDecision, OtDeluxeRoutePrompt,
+    OtDeluxeStartConfig,
 };
 pub use otdeluxe_store::{
     OtDeluxeStoreError, OtDeluxeStoreItem, OtDeluxeStoreLineItem, OtDeluxeStoreReceipt,
@@ -97,14 +139,20 @@ pub use otdeluxe_trail::{
 };
 pub use pacing::{DietCfg, PaceCfg, PacingConfig, PacingLimits};
 pub use personas::{Persona, PersonaMods, PersonaStart, PersonasList};
+pub use player_action::{ActionConfigs, ActionFollowUp, PlayerAction};
 pub use result::{ResultConfig, ResultSummary, load_result_config, result_summary};
+pub use run_history::{
+    RUN_HISTORY_MAX_ENTRIES, RUN_HISTORY_SCHEMA_VERSION, RunHistory, RunHistoryEntry,
+};
+pub use run_timeline::{BreakdownSpan, RunTimeline, TimelineDay, WeatherStreak};
 pub use seed::{decode_to_seed, encode_friendly, generate_code_from_entropy, parse_share_code};
 pub use state::{
     BossProgress, BossReadiness, BossResolution, CollapseCause, CrossingOutcomeTelemetry,
-    CrossingTelemetry, DayState, DietId, EncounterState, Ending, FeatureFlags, GameMode, GamePhase,
-    GameState, GuardState, Inventory, PaceId, PendingCrossing, PolicyKind, Region,
-    SAVE_SCHEMA_VERSION, SaveVersionError, Spares, Stats,
+    CrossingTelemetry, DayState, DietId, EncounterChoiceRecord, EncounterState, Ending,
+    FeatureFlags, GameMode, GamePhase, GameState, GuardState, HealthLabel, Inventory, PaceId,
+    PendingCrossing, PolicyKind, Region, SAVE_SCHEMA_VERSION, SaveVersionError, Spares, Stats,
 };
+pub use state_hash::state_hash;
 pub use store::{
     Cart, CartLine, Grants, Store, StoreItem, calculate_cart_total, calculate_effective_price,
 };
@@ -112,7 +160,11 @@ pub use trade::{
     TradeGood, TradeGoodKind, TradeOffer, TradeOutcome, TradeResolution, resolve_trade,
     resolve_trade_with_rng,
 };
-pub use vehicle::{Breakdown, Part, Vehicle, VehicleConfig};
+pub use treatment::{OtDeluxeTreatmentOutcome, TreatmentError};
+pub use vehicle::{
+    Breakdown, MechanicHook, Part, RepairCosts, Vehicle, VehicleClass, VehicleConfig,
+    VehicleModifiers, VehicleUpgrade,
+};
 pub use weather::{
     DystrailRegionalWeather, OtDeluxeStationsWeather, Weather, WeatherAccumulationConfig,
     WeatherConfig, WeatherEffect, WeatherMitigation, WeatherModel, WeatherReportConfig,
@@ -168,6 +220,78 @@ pub trait GameStorage {
     fn delete_save(&self, save_name: &str) -> Result<(), Self::Error>;
 }
 
+/// Trait for persisting the local run history
+/// Platform-specific implementations should provide this alongside `GameStorage`
+pub trait RunHistoryStorage {
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Load the stored run history, or an empty history if none exists
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the history cannot be read or parsed.
+    fn load_history(&self) -> Result<RunHistory, Self::Error>;
+
+    /// Replace the stored run history
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the history cannot be written.
+    fn save_history(&self, history: &RunHistory) -> Result<(), Self::Error>;
+
+    /// Append a finished run to the stored history
+    ///
+    /// Returns `false` if the entry repeated the most recent run.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the history cannot be loaded or saved.
+    fn record_run(&self, entry: RunHistoryEntry) -> Result<bool, Self::Error> {
+        let mut history = self.load_history()?;
+        let recorded = history.record(entry);
+        if recorded {
+            self.save_history(&history)?;
+        }
+        Ok(recorded)
+    }
+}
+
+/// Trait for persisting the trail graveyard between runs
+/// Platform-specific implementations should provide this alongside `GameStorage`
+pub trait GraveyardStorage {
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Load the stored graveyard, or an empty graveyard if none exists
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graveyard cannot be read or parsed.
+    fn load_graveyard(&self) -> Result<Graveyard, Self::Error>;
+
+    /// Replace the stored graveyard
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graveyard cannot be written.
+    fn save_graveyard(&self, graveyard: &Graveyard) -> Result<(), Self::Error>;
+
+    /// Bury the deaths from a finished run in the stored graveyard
+    ///
+    /// Returns the number of new graves.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graveyard cannot be loaded or saved.
+    fn bury_run(&self, state: &GameState, share_code: &str) -> Result<usize, Self::Error> {
+        let mut graveyard = self.load_graveyard()?;
+        let buried = graveyard.bury_run(state, share_code);
+        if buried > 0 {
+            self.save_graveyard(&graveyard)?;
+        }
+        Ok(buried)
+    }
+}
+
 /// Main game engine for managing game instances
 pub struct GameEngine<L, S>
 where
@@ -228,11 +352,11 @@ where
         seed: u64,
         mode: GameMode,
         strategy: StrategyId,
-        occupation: Option<OtDeluxeOccupation>,
+        start: Option<OtDeluxeStartConfig>,
     ) -> Result<KernelSession, L::Error> {
         let data = self.data_loader.load_encounter_data()?;
         let endgame_cfg = EndgameTravelCfg::default_config();
-        let session = KernelSession::new(mode, strategy, seed, data, &endgame_cfg, occupation);
+        let session = KernelSession::new(mode, strategy, seed, data, &endgame_cfg, start);
         Ok(session)
     }
 
@@ -321,6 +445,24 @@ mod tests {
         }
     }
 
+    #[derive(Clone, Default)]
+    struct MemoryHistory {
+        history: Rc<RefCell<Option<RunHistory>>>,
+    }
+
+    impl RunHistoryStorage for MemoryHistory {
+        type Error = Infallible;
+
+        fn load_history(&self) -> Result<RunHistory, Self::Error> {
+            Ok(self.history.borrow().clone().unwrap_or_default())
+        }
+
+        fn save_history(&self, history: &RunHistory) -> Result<(), Self::Error> {
+            *self.history.borrow_mut() = Some(history.clone());
+            Ok(())
+        }
+    }
+
     #[test]
     fn engine_creates_and_roundtrips_state() {
         let engine = GameEngine::new(FixtureLoader, MemoryStorage::default());
@@ -373,7 +515,7 @@ mod tests {
                 17,
                 GameMode::Classic,
                 StrategyId::Balanced,
-                Some(OtDeluxeOccupation::Doctor),
+                Some(OtDeluxeStartConfig::new(OtDeluxeOccupation::Doctor).with_departure(5, 1848)),
             )
             .unwrap();
         assert_eq!(session.mechanics(), MechanicalPolicyId::OtDeluxe90s);
@@ -381,5 +523,20 @@ mod tests {
             session.state().ot_deluxe.mods.occupation,
             Some(OtDeluxeOccupation::Doctor)
         );
+        assert_eq!(session.state().ot_deluxe.calendar.month, 5);
+    }
+
+    #[test]
+    fn run_history_storage_records_runs_once() {
+        let storage = MemoryHistory::default();
+        let state = GameState::default();
+        let summary = result_summary(&state, &ResultConfig::default()).unwrap();
+        let entry = RunHistoryEntry::from_run(&state, &summary);
+
+        assert!(storage.record_run(entry.clone()).unwrap());
+        assert!(!storage.record_run(entry).unwrap());
+        let history = storage.load_history().unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history.entries[0].share_code, summary.seed);
     }
 }
@@ -8,6 +8,7 @@ pub mod otdeluxe90s;
 
 pub use otdeluxe90s::{
     OtDeluxe90sPolicy, OtDeluxeAfflictionWeightOverride, OtDeluxeBreakdownPolicy,
-    OtDeluxeOccupation, OtDeluxePace, OtDeluxePolicyOverride, OtDeluxeRations,
-    OtDeluxeTrailVariant, OtDeluxeWeatherEffectsOverride,
+    OtDeluxeCargoPolicy, OtDeluxeOccupation, OtDeluxePace, OtDeluxePolicyOverride, OtDeluxeRations,
+    OtDeluxeSkillPolicy, OtDeluxeTrailVariant, OtDeluxeTreatmentPolicy,
+    OtDeluxeWeatherEffectsOverride,
 };
@@ -11,8 +11,10 @@ use crate::constants::{
     PACE_BREAKDOWN_BLITZ, PACE_BREAKDOWN_HEATED, PACE_BREAKDOWN_STEADY,
     VEHICLE_BREAKDOWN_BASE_CHANCE, VEHICLE_BREAKDOWN_WEAR_COEFFICIENT,
 };
+use crate::disease::{FatalityModel, FatalityModifier};
+use crate::otdeluxe_skills::OtDeluxeSkill;
 use crate::otdeluxe_state::{OtDeluxeRiver, OtDeluxeRiverBed};
-use crate::state::{Region, Season};
+use crate::state::{HealthLabel, Region, Season};
 use crate::weather::{Weather, WeatherEffects};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -95,6 +97,14 @@ pub struct OtDeluxeStoreBasePricesCents {
     pub wheel: u32,
     pub axle: u32,
     pub tongue: u32,
+    #[serde(default = "OtDeluxeStoreBasePricesCents::default_medicine")]
+    pub medicine: u32,
+}
+
+impl OtDeluxeStoreBasePricesCents {
+    const fn default_medicine() -> u32 {
+        500
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -106,6 +116,14 @@ pub struct OtDeluxeStoreMaxBuy {
     pub axles: u16,
     pub tongues: u16,
     pub food_lbs: u16,
+    #[serde(default = "OtDeluxeStoreMaxBuy::default_medicine")]
+    pub medicine: u16,
+}
+
+impl OtDeluxeStoreMaxBuy {
+    const fn default_medicine() -> u16 {
+        10
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -113,6 +131,10 @@ pub struct OtDeluxeOccupationSpec {
     pub occupation: OtDeluxeOccupation,
     pub starting_cash_dollars: u16,
     pub final_bonus_mult: f32,
+    /// Skill the party leader brings from this trade when the setup opts in
+    /// with `OtDeluxeStartConfig::with_occupation_skill`.
+    #[serde(default)]
+    pub leader_skill: Option<OtDeluxeSkill>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -186,10 +208,29 @@ pub struct OtDeluxeHealthLabelRanges {
     pub very_poor_max: u16,
 }
 
+impl OtDeluxeHealthLabelRanges {
+    /// Label for a general health value; higher values are worse.
+    #[must_use]
+    pub const fn label(self, health_general: u16) -> HealthLabel {
+        if health_general <= self.good_max {
+            HealthLabel::Good
+        } else if health_general <= self.fair_max {
+            HealthLabel::Fair
+        } else if health_general <= self.poor_max {
+            HealthLabel::Poor
+        } else {
+            HealthLabel::VeryPoor
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct OtDeluxeCrossingPolicy {
     pub ferry_cost_cents: u32,
     pub guide_cost_clothes_sets: u16,
+    /// Cash a guide accepts instead of clothing; zero means clothing only.
+    #[serde(default)]
+    pub guide_cost_cents: u32,
     pub ferry_min_depth_ft: f32,
     pub float_min_depth_ft: f32,
     pub wet_goods_min_depth_ft: f32,
@@ -240,6 +281,9 @@ pub struct OtDeluxeCrossingPolicy {
     pub drownings_min: u8,
     #[serde(default)]
     pub drownings_max: u8,
+    /// Largest relative error when sizing up a river before choosing a method.
+    #[serde(default)]
+    pub info_fuzz_max: f32,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -270,6 +314,9 @@ pub struct OtDeluxeCrossingOutcomeWeightsByMethod {
     pub ford: OtDeluxeCrossingOutcomeWeights,
     pub caulk_float: OtDeluxeCrossingOutcomeWeights,
     pub ferry: OtDeluxeCrossingOutcomeWeights,
+    /// Guided fords; `guide_risk_mult` still scales the non-safe outcomes.
+    #[serde(default)]
+    pub guide: OtDeluxeCrossingOutcomeWeights,
 }
 
 impl Default for OtDeluxeCrossingOutcomeWeightsByMethod {
@@ -292,6 +339,7 @@ impl Default for OtDeluxeCrossingOutcomeWeightsByMethod {
                 sank: 0.02,
                 drowned: 0.0,
             },
+            guide: OtDeluxeCrossingOutcomeWeights::default(),
         }
     }
 }
@@ -412,6 +460,16 @@ pub struct OtDeluxeActionTimeCosts {
     pub rest_days_max: u8,
     pub trade_cost_days: u8,
     pub hunt_cost_days: u8,
+    #[serde(default = "OtDeluxeActionTimeCosts::default_gather_cost_days")]
+    pub fish_cost_days: u8,
+    #[serde(default = "OtDeluxeActionTimeCosts::default_gather_cost_days")]
+    pub forage_cost_days: u8,
+}
+
+impl OtDeluxeActionTimeCosts {
+    const fn default_gather_cost_days() -> u8 {
+        1
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -419,6 +477,105 @@ pub struct OtDeluxeHuntPolicy {
     pub carry_cap_lbs_per_alive_member: u16,
 }
 
+/// Modifiers tied to party member skills, age groups and morale.
+///
+/// A member whose morale has fallen below `min_morale` no longer lends
+/// their skill to the party.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OtDeluxeSkillPolicy {
+    /// Yield multiplier on food shot while hunting.
+    pub hunter_yield_mult: f32,
+    /// Multiplier on affliction durations.
+    pub medic_duration_mult: f32,
+    /// Weight multiplier on random event variants that name the carpenter skill.
+    pub carpenter_repair_mult: f32,
+    /// Multiplier on navigation delay days.
+    pub navigator_delay_mult: f32,
+    pub min_morale: u8,
+    /// Morale each survivor loses when a party member dies.
+    pub death_morale_loss: u8,
+    /// Morale a member loses when struck by an illness or injury.
+    pub affliction_morale_loss: u8,
+    /// Morale every living member regains on a rest day.
+    pub rest_morale_gain: u8,
+    /// Disease fatality multiplier for children.
+    pub child_fatality_mult: f32,
+    /// Disease fatality multiplier for elders.
+    pub elder_fatality_mult: f32,
+}
+
+impl Default for OtDeluxeSkillPolicy {
+    fn default() -> Self {
+        Self {
+            hunter_yield_mult: 1.25,
+            medic_duration_mult: 0.67,
+            carpenter_repair_mult: 2.0,
+            navigator_delay_mult: 0.5,
+            min_morale: 25,
+            death_morale_loss: 20,
+            affliction_morale_loss: 10,
+            rest_morale_gain: 5,
+            child_fatality_mult: 1.5,
+            elder_fatality_mult: 1.25,
+        }
+    }
+}
+
+/// How medicine treats an afflicted party member.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OtDeluxeTreatmentPolicy {
+    pub medicine_per_treatment: u16,
+    /// Multiplier on the remaining days of a treated affliction.
+    pub duration_mult: f32,
+}
+
+impl Default for OtDeluxeTreatmentPolicy {
+    fn default() -> Self {
+        Self {
+            medicine_per_treatment: 1,
+            duration_mult: 0.5,
+        }
+    }
+}
+
+/// Per-item cargo weights and wagon capacity.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct OtDeluxeCargoPolicy {
+    pub food_lb_weight: f32,
+    pub bullet_weight_lbs: f32,
+    pub clothes_set_weight_lbs: f32,
+    pub wheel_weight_lbs: f32,
+    pub axle_weight_lbs: f32,
+    pub tongue_weight_lbs: f32,
+    #[serde(default = "OtDeluxeCargoPolicy::default_medicine_weight_lbs")]
+    pub medicine_weight_lbs: f32,
+    /// Capacity per effective ox; sick oxen count at `sick_ox_weight`.
+    pub capacity_lbs_per_ox: f32,
+}
+
+impl Default for OtDeluxeCargoPolicy {
+    fn default() -> Self {
+        Self {
+            food_lb_weight: 1.0,
+            bullet_weight_lbs: 0.1,
+            clothes_set_weight_lbs: 5.0,
+            wheel_weight_lbs: 60.0,
+            axle_weight_lbs: 40.0,
+            tongue_weight_lbs: 40.0,
+            medicine_weight_lbs: Self::default_medicine_weight_lbs(),
+            capacity_lbs_per_ox: 500.0,
+        }
+    }
+}
+
+impl OtDeluxeCargoPolicy {
+    const fn default_medicine_weight_lbs() -> f32 {
+        1.0
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct OtDeluxeStorePolicy {
     pub buy_only_at_forts: bool,
@@ -496,6 +653,15 @@ pub struct OtDeluxeOccupationAdvantages {
     pub doctor_fatality_mult: f32,
     pub repair_success_mult: f32,
     pub mobility_failure_mult: f32,
+    /// Scales how far off a farmer's read of a river can be.
+    #[serde(default = "OtDeluxeOccupationAdvantages::default_crossing_info_fuzz_mult")]
+    pub crossing_info_fuzz_mult: f32,
+}
+
+impl OtDeluxeOccupationAdvantages {
+    const fn default_crossing_info_fuzz_mult() -> f32 {
+        0.5
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -515,6 +681,11 @@ pub struct OtDeluxeTravelPolicy {
     pub snow_speed_floor: f32,
     #[serde(default = "OtDeluxeTravelPolicy::default_partial_ratio")]
     pub partial_ratio: f32,
+    /// Speed lost per unit of overload ratio (excess weight / capacity).
+    #[serde(default = "OtDeluxeTravelPolicy::default_overload_speed_penalty")]
+    pub overload_speed_penalty: f32,
+    #[serde(default = "OtDeluxeTravelPolicy::default_overload_speed_floor")]
+    pub overload_speed_floor: f32,
 }
 
 impl Default for OtDeluxeTravelPolicy {
@@ -529,6 +700,8 @@ impl Default for OtDeluxeTravelPolicy {
             snow_speed_penalty_per_in: 0.0,
             snow_speed_floor: 0.0,
             partial_ratio: Self::default_partial_ratio(),
+            overload_speed_penalty: Self::default_overload_speed_penalty(),
+            overload_speed_floor: Self::default_overload_speed_floor(),
         }
     }
 }
@@ -549,6 +722,14 @@ impl OtDeluxeTravelPolicy {
     const fn default_encounter_ceiling() -> f32 {
         1.0
     }
+
+    const fn default_overload_speed_penalty() -> f32 {
+        1.0
+    }
+
+    const fn default_overload_speed_floor() -> f32 {
+        0.25
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -612,6 +793,9 @@ pub struct OtDeluxeBreakdownPolicy {
     pub pace_mult_strenuous: f32,
     pub pace_mult_grueling: f32,
     pub max_chance: f32,
+    /// Extra breakdown odds per unit of overload ratio.
+    #[serde(default = "OtDeluxeBreakdownPolicy::default_overload_mult")]
+    pub overload_mult: f32,
 }
 
 impl OtDeluxeBreakdownPolicy {
@@ -623,6 +807,10 @@ impl OtDeluxeBreakdownPolicy {
             OtDeluxePace::Grueling => self.pace_mult_grueling,
         }
     }
+
+    const fn default_overload_mult() -> f32 {
+        2.0
+    }
 }
 
 impl Default for OtDeluxeBreakdownPolicy {
@@ -634,6 +822,7 @@ impl Default for OtDeluxeBreakdownPolicy {
             pace_mult_strenuous: PACE_BREAKDOWN_HEATED,
             pace_mult_grueling: PACE_BREAKDOWN_BLITZ,
             max_chance: 0.35,
+            overload_mult: Self::default_overload_mult(),
         }
     }
 }
@@ -643,6 +832,9 @@ pub struct OtDeluxeOxenPolicy {
     pub sick_ox_weight: f32,
     pub min_to_move: f32,
     pub min_for_base: f32,
+    /// Per-animal herd upkeep; see `otdeluxe_oxen`.
+    #[serde(default)]
+    pub herd: OtDeluxeHerdPolicy,
 }
 
 impl Default for OtDeluxeOxenPolicy {
@@ -651,6 +843,79 @@ impl Default for OtDeluxeOxenPolicy {
             sick_ox_weight: 0.5,
             min_to_move: 0.0,
             min_for_base: 4.0,
+            herd: OtDeluxeHerdPolicy::default(),
+        }
+    }
+}
+
+/// Daily health bookkeeping for individual oxen.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OtDeluxeHerdPolicy {
+    /// Health regained from a full day of good grazing.
+    pub graze_gain: u8,
+    pub graze_mult_spring: f32,
+    pub graze_mult_summer: f32,
+    pub graze_mult_fall: f32,
+    pub graze_mult_winter: f32,
+    /// Days of poor grazing after a "no grass" shortage.
+    pub no_grass_days: u8,
+    pub work_cost_steady: u8,
+    pub work_cost_strenuous: u8,
+    pub work_cost_grueling: u8,
+    /// Extra health regained on a rest day.
+    pub rest_recovery: u8,
+    /// Rest days count this many times toward sickness and injury recovery.
+    pub rest_recovery_days: u8,
+    pub sick_days: u8,
+    /// Oxen this old or older pay `old_age_cost` extra each working day.
+    pub old_age_years: u8,
+    pub old_age_cost: u8,
+    /// Ages assigned to newly bought oxen.
+    pub min_age_years: u8,
+    pub max_age_years: u8,
+}
+
+impl Default for OtDeluxeHerdPolicy {
+    fn default() -> Self {
+        Self {
+            graze_gain: 3,
+            graze_mult_spring: 1.0,
+            graze_mult_summer: 1.0,
+            graze_mult_fall: 0.6,
+            graze_mult_winter: 0.2,
+            no_grass_days: 4,
+            work_cost_steady: 2,
+            work_cost_strenuous: 3,
+            work_cost_grueling: 6,
+            rest_recovery: 4,
+            rest_recovery_days: 2,
+            sick_days: 6,
+            old_age_years: 10,
+            old_age_cost: 1,
+            min_age_years: 3,
+            max_age_years: 11,
+        }
+    }
+}
+
+impl OtDeluxeHerdPolicy {
+    #[must_use]
+    pub const fn graze_mult(&self, season: Season) -> f32 {
+        match season {
+            Season::Spring => self.graze_mult_spring,
+            Season::Summer => self.graze_mult_summer,
+            Season::Fall => self.graze_mult_fall,
+            Season::Winter => self.graze_mult_winter,
+        }
+    }
+
+    #[must_use]
+    pub const fn work_cost(&self, pace: OtDeluxePace) -> u8 {
+        match pace {
+            OtDeluxePace::Steady => self.work_cost_steady,
+            OtDeluxePace::Strenuous => self.work_cost_strenuous,
+            OtDeluxePace::Grueling => self.work_cost_grueling,
         }
     }
 }
@@ -801,6 +1066,15 @@ pub struct OtDeluxe90sPolicy {
     pub crossings: OtDeluxeCrossingPolicy,
     pub actions: OtDeluxeActionTimeCosts,
     pub hunt: OtDeluxeHuntPolicy,
+    #[serde(default)]
+    pub cargo: OtDeluxeCargoPolicy,
+    #[serde(default)]
+    pub skills: OtDeluxeSkillPolicy,
+    #[serde(default)]
+    pub treatment: OtDeluxeTreatmentPolicy,
+    /// Daily fatality model for diseases that do not declare their own.
+    #[serde(default = "default_disease_fatality")]
+    pub disease_fatality: FatalityModel,
     pub trail: OtDeluxeTrailPolicy,
     pub score: OtDeluxeScorePolicy,
     #[serde(default)]
@@ -820,41 +1094,49 @@ const DEFAULT_OCCUPATIONS: [OtDeluxeOccupationSpec; 8] = [
         occupation: OtDeluxeOccupation::Banker,
         starting_cash_dollars: 1600,
         final_bonus_mult: 1.0,
+        leader_skill: None,
     },
     OtDeluxeOccupationSpec {
         occupation: OtDeluxeOccupation::Doctor,
         starting_cash_dollars: 1200,
         final_bonus_mult: 1.0,
+        leader_skill: Some(OtDeluxeSkill::Medic),
     },
     OtDeluxeOccupationSpec {
         occupation: OtDeluxeOccupation::Merchant,
         starting_cash_dollars: 1200,
         final_bonus_mult: 1.5,
+        leader_skill: None,
     },
     OtDeluxeOccupationSpec {
         occupation: OtDeluxeOccupation::Blacksmith,
         starting_cash_dollars: 800,
         final_bonus_mult: 2.0,
+        leader_skill: Some(OtDeluxeSkill::Carpenter),
     },
     OtDeluxeOccupationSpec {
         occupation: OtDeluxeOccupation::Carpenter,
         starting_cash_dollars: 800,
         final_bonus_mult: 2.0,
+        leader_skill: Some(OtDeluxeSkill::Carpenter),
     },
     OtDeluxeOccupationSpec {
         occupation: OtDeluxeOccupation::Saddlemaker,
         starting_cash_dollars: 800,
         final_bonus_mult: 2.5,
+        leader_skill: None,
     },
     OtDeluxeOccupationSpec {
         occupation: OtDeluxeOccupation::Farmer,
         starting_cash_dollars: 400,
         final_bonus_mult: 3.0,
+        leader_skill: Some(OtDeluxeSkill::Hunter),
     },
     OtDeluxeOccupationSpec {
         occupation: OtDeluxeOccupation::Teacher,
         starting_cash_dollars: 400,
         final_bonus_mult: 3.5,
+        leader_skill: Some(OtDeluxeSkill::Navigator),
     },
 ];
 
@@ -912,6 +1194,7 @@ impl Default for OtDeluxeStorePolicy {
                 wheel: 1000,
                 axle: 1000,
                 tongue: 1000,
+                medicine: OtDeluxeStoreBasePricesCents::default_medicine(),
             },
             price_mult_pct_by_node: [
                 100, 100, 100, 100, 125, 125, 150, 150, 150, 175, 175, 175, 200, 200, 225, 250,
@@ -925,6 +1208,7 @@ impl Default for OtDeluxeStorePolicy {
                 axles: 3,
                 tongues: 3,
                 food_lbs: 2000,
+                medicine: OtDeluxeStoreMaxBuy::default_medicine(),
             },
         }
     }
@@ -1010,6 +1294,8 @@ impl Default for OtDeluxeActionTimeCosts {
             rest_days_max: 9,
             trade_cost_days: 1,
             hunt_cost_days: 1,
+            fish_cost_days: Self::default_gather_cost_days(),
+            forage_cost_days: Self::default_gather_cost_days(),
         }
     }
 }
@@ -1028,6 +1314,7 @@ impl Default for OtDeluxeOccupationAdvantages {
             doctor_fatality_mult: 0.50,
             repair_success_mult: 1.25,
             mobility_failure_mult: 0.75,
+            crossing_info_fuzz_mult: Self::default_crossing_info_fuzz_mult(),
         }
     }
 }
@@ -1050,6 +1337,7 @@ impl Default for OtDeluxeCrossingPolicy {
         Self {
             ferry_cost_cents: 500,
             guide_cost_clothes_sets: 3,
+            guide_cost_cents: 0,
             ferry_min_depth_ft: 2.5,
             float_min_depth_ft: 1.5,
             wet_goods_min_depth_ft: 2.5,
@@ -1080,10 +1368,32 @@ impl Default for OtDeluxeCrossingPolicy {
             sank_loss_ratio: 0.30,
             drownings_min: 1,
             drownings_max: 1,
+            info_fuzz_max: 0.25,
         }
     }
 }
 
+fn default_disease_fatality() -> FatalityModel {
+    FatalityModel {
+        base_prob_per_day: 0.01,
+        prob_modifiers: vec![
+            FatalityModifier::HealthLabel {
+                good: 0.25,
+                fair: 0.5,
+                poor: 1.5,
+                very_poor: 3.0,
+            },
+            FatalityModifier::Rations {
+                filling: 1.0,
+                meager: 1.25,
+                bare_bones: 1.5,
+            },
+            FatalityModifier::Treated { mult: 0.5 },
+        ],
+        apply_doctor_mult: true,
+    }
+}
+
 impl Default for OtDeluxe90sPolicy {
     fn default() -> Self {
         Self {
@@ -1106,6 +1416,10 @@ impl Default for OtDeluxe90sPolicy {
             crossings: OtDeluxeCrossingPolicy::default(),
             actions: OtDeluxeActionTimeCosts::default(),
             hunt: OtDeluxeHuntPolicy::default(),
+            cargo: OtDeluxeCargoPolicy::default(),
+            skills: OtDeluxeSkillPolicy::default(),
+            treatment: OtDeluxeTreatmentPolicy::default(),
+            disease_fatality: default_disease_fatality(),
             trail: OtDeluxeTrailPolicy::default(),
             score: OtDeluxeScorePolicy::default(),
             per_region_overrides: HashMap::new(),
@@ -1134,6 +1448,17 @@ mod tests {
     use crate::state::{Region, Season};
     use crate::weather::WeatherEffects;
 
+    #[test]
+    fn health_label_ranges_are_inclusive_upper_bounds() {
+        let ranges = OtDeluxe90sPolicy::default().health.label_ranges;
+        assert_eq!(ranges.label(0), HealthLabel::Good);
+        assert_eq!(ranges.label(ranges.good_max), HealthLabel::Good);
+        assert_eq!(ranges.label(ranges.good_max + 1), HealthLabel::Fair);
+        assert_eq!(ranges.label(ranges.fair_max + 1), HealthLabel::Poor);
+        assert_eq!(ranges.label(ranges.poor_max + 1), HealthLabel::VeryPoor);
+        assert_eq!(ranges.label(u16::MAX), HealthLabel::VeryPoor);
+    }
+
     fn assert_f32_eq(a: f32, b: f32) {
         let epsilon = 1e-6_f32;
         assert!(
@@ -1189,6 +1514,11 @@ mod tests {
         assert_eq!(policy.crossings.drying_cost_days, 1);
         assert_eq!(policy.crossings.crossing_cost_days, 1);
         assert_f32_eq(policy.crossings.guide_risk_mult, 0.20);
+        assert_eq!(policy.crossings.guide_cost_cents, 0);
+        assert_eq!(
+            policy.crossings.outcome_weights.guide,
+            policy.crossings.outcome_weights.ford
+        );
         assert_eq!(policy.crossings.ferry_wait_days_min, 0);
         assert_eq!(policy.crossings.ferry_wait_days_max, 6);
         assert_f32_eq(policy.oxen.sick_ox_weight, 0.5);
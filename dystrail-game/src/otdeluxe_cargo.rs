@@ -0,0 +1,292 @@
+//! Wagon cargo weight and overload rules for Oregon Trail Deluxe parity.
+//!
+//! Every carried item has a weight and the wagon's capacity scales with the
+//! oxen still pulling it. Overloading slows travel and raises breakdown odds;
+//! the party can lighten the load by discarding items.
+
+use serde::{Deserialize, Serialize};
+
+use crate::mechanics::otdeluxe90s::{
+    OtDeluxe90sPolicy, OtDeluxeBreakdownPolicy, OtDeluxeCargoPolicy, OtDeluxeStorePolicy,
+    OtDeluxeTravelPolicy,
+};
+use crate::otdeluxe_state::{OtDeluxeInventory, OtDeluxeOxenState};
+use crate::otdeluxe_store::{OtDeluxeStoreItem, OtDeluxeStoreLineItem};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OtDeluxeCargoItem {
+    Food,
+    Bullets,
+    Clothes,
+    Wheel,
+    Axle,
+    Tongue,
+    Medicine,
+}
+
+impl OtDeluxeCargoItem {
+    pub const ALL: [Self; 7] = [
+        Self::Food,
+        Self::Bullets,
+        Self::Clothes,
+        Self::Wheel,
+        Self::Axle,
+        Self::Tongue,
+        Self::Medicine,
+    ];
+
+    #[must_use]
+    pub const fn unit_weight_lbs(self, policy: &OtDeluxeCargoPolicy) -> f32 {
+        match self {
+            Self::Food => policy.food_lb_weight,
+            Self::Bullets => policy.bullet_weight_lbs,
+            Self::Clothes => policy.clothes_set_weight_lbs,
+            Self::Wheel => policy.wheel_weight_lbs,
+            Self::Axle => policy.axle_weight_lbs,
+            Self::Tongue => policy.tongue_weight_lbs,
+            Self::Medicine => policy.medicine_weight_lbs,
+        }
+    }
+
+    #[must_use]
+    pub fn quantity(self, inventory: &OtDeluxeInventory) -> u16 {
+        match self {
+            Self::Food => inventory.food_lbs,
+            Self::Bullets => inventory.bullets,
+            Self::Clothes => inventory.clothes_sets,
+            Self::Wheel => inventory.spares_wheels.into(),
+            Self::Axle => inventory.spares_axles.into(),
+            Self::Tongue => inventory.spares_tongues.into(),
+            Self::Medicine => inventory.medicine,
+        }
+    }
+}
+
+/// Current cargo weight against wagon capacity.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct OtDeluxeCargoLoad {
+    pub weight_lbs: f32,
+    pub capacity_lbs: f32,
+}
+
+impl OtDeluxeCargoLoad {
+    #[must_use]
+    pub fn overload_lbs(&self) -> f32 {
+        (self.weight_lbs - self.capacity_lbs).max(0.0)
+    }
+
+    /// Excess weight as a fraction of capacity; a wagon with no capacity but
+    /// some cargo counts as fully overloaded.
+    #[must_use]
+    pub fn overload_ratio(&self) -> f32 {
+        let overload = self.overload_lbs();
+        if overload <= 0.0 {
+            0.0
+        } else if self.capacity_lbs <= 0.0 {
+            1.0
+        } else {
+            overload / self.capacity_lbs
+        }
+    }
+
+    #[must_use]
+    pub fn is_overloaded(&self) -> bool {
+        self.overload_lbs() > 0.0
+    }
+}
+
+#[must_use]
+pub fn cargo_weight_lbs(policy: &OtDeluxeCargoPolicy, inventory: &OtDeluxeInventory) -> f32 {
+    OtDeluxeCargoItem::ALL
+        .iter()
+        .map(|item| f32::from(item.quantity(inventory)) * item.unit_weight_lbs(policy).max(0.0))
+        .sum()
+}
+
+#[must_use]
+pub fn wagon_capacity_lbs(policy: &OtDeluxe90sPolicy, oxen: OtDeluxeOxenState) -> f32 {
+    oxen.effective_oxen(policy.oxen.sick_ox_weight) * policy.cargo.capacity_lbs_per_ox.max(0.0)
+}
+
+#[must_use]
+pub fn cargo_load(
+    policy: &OtDeluxe90sPolicy,
+    inventory: &OtDeluxeInventory,
+    oxen: OtDeluxeOxenState,
+) -> OtDeluxeCargoLoad {
+    OtDeluxeCargoLoad {
+        weight_lbs: cargo_weight_lbs(&policy.cargo, inventory),
+        capacity_lbs: wagon_capacity_lbs(policy, oxen),
+    }
+}
+
+/// Travel multiplier for an overloaded wagon.
+#[must_use]
+pub fn overload_speed_mult(policy: &OtDeluxeTravelPolicy, load: &OtDeluxeCargoLoad) -> f32 {
+    let penalty = policy.overload_speed_penalty.max(0.0);
+    let floor = policy.overload_speed_floor.clamp(0.0, 1.0);
+    penalty
+        .mul_add(-load.overload_ratio(), 1.0)
+        .clamp(floor, 1.0)
+}
+
+/// Breakdown chance multiplier for an overloaded wagon.
+#[must_use]
+pub fn overload_breakdown_mult(policy: &OtDeluxeBreakdownPolicy, load: &OtDeluxeCargoLoad) -> f32 {
+    policy
+        .overload_mult
+        .max(0.0)
+        .mul_add(load.overload_ratio(), 1.0)
+}
+
+/// Cargo load the wagon would carry after a store purchase.
+///
+/// Oxen bought in the same purchase add capacity; per-item caps are ignored
+/// here since the store quote reports them separately.
+#[must_use]
+pub fn load_after_purchase(
+    policy: &OtDeluxe90sPolicy,
+    inventory: &OtDeluxeInventory,
+    oxen: OtDeluxeOxenState,
+    lines: &[OtDeluxeStoreLineItem],
+) -> OtDeluxeCargoLoad {
+    let mut inventory = inventory.clone();
+    let mut oxen = oxen;
+    for line in lines {
+        add_store_line(&policy.store, &mut inventory, &mut oxen, *line);
+    }
+    cargo_load(policy, &inventory, oxen)
+}
+
+fn add_store_line(
+    store: &OtDeluxeStorePolicy,
+    inventory: &mut OtDeluxeInventory,
+    oxen: &mut OtDeluxeOxenState,
+    line: OtDeluxeStoreLineItem,
+) {
+    let qty = line.quantity;
+    let qty_u8 = u8::try_from(qty).unwrap_or(u8::MAX);
+    match line.item {
+        OtDeluxeStoreItem::Oxen => oxen.healthy = oxen.healthy.saturating_add(qty),
+        OtDeluxeStoreItem::ClothesSet => {
+            inventory.clothes_sets = inventory.clothes_sets.saturating_add(qty);
+        }
+        OtDeluxeStoreItem::AmmoBox => {
+            let bullets = qty.saturating_mul(store.bullets_per_box.max(1));
+            inventory.bullets = inventory.bullets.saturating_add(bullets);
+        }
+        OtDeluxeStoreItem::FoodLb => inventory.food_lbs = inventory.food_lbs.saturating_add(qty),
+        OtDeluxeStoreItem::Wheel => {
+            inventory.spares_wheels = inventory.spares_wheels.saturating_add(qty_u8);
+        }
+        OtDeluxeStoreItem::Axle => {
+            inventory.spares_axles = inventory.spares_axles.saturating_add(qty_u8);
+        }
+        OtDeluxeStoreItem::Tongue => {
+            inventory.spares_tongues = inventory.spares_tongues.saturating_add(qty_u8);
+        }
+        OtDeluxeStoreItem::Medicine => inventory.medicine = inventory.medicine.saturating_add(qty),
+    }
+}
+
+/// Remove up to `quantity` of an item from the wagon, returning how many
+/// were actually discarded.
+pub fn discard(inventory: &mut OtDeluxeInventory, item: OtDeluxeCargoItem, quantity: u16) -> u16 {
+    let removed = quantity.min(item.quantity(inventory));
+    let removed_u8 = u8::try_from(removed).unwrap_or(u8::MAX);
+    match item {
+        OtDeluxeCargoItem::Food => inventory.food_lbs -= removed,
+        OtDeluxeCargoItem::Bullets => inventory.bullets -= removed,
+        OtDeluxeCargoItem::Clothes => inventory.clothes_sets -= removed,
+        OtDeluxeCargoItem::Wheel => inventory.spares_wheels -= removed_u8,
+        OtDeluxeCargoItem::Axle => inventory.spares_axles -= removed_u8,
+        OtDeluxeCargoItem::Tongue => inventory.spares_tongues -= removed_u8,
+        OtDeluxeCargoItem::Medicine => inventory.medicine -= removed,
+    }
+    removed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn loaded_inventory() -> OtDeluxeInventory {
+        OtDeluxeInventory {
+            food_lbs: 1_500,
+            bullets: 200,
+            clothes_sets: 10,
+            spares_wheels: 2,
+            spares_axles: 1,
+            spares_tongues: 1,
+            ..OtDeluxeInventory::default()
+        }
+    }
+
+    #[test]
+    fn capacity_tracks_live_oxen_and_overload_slows_travel() {
+        let policy = OtDeluxe90sPolicy::default();
+        let inventory = loaded_inventory();
+        let weight = cargo_weight_lbs(&policy.cargo, &inventory);
+        assert!((weight - (1_500.0 + 20.0 + 50.0 + 120.0 + 40.0 + 40.0)).abs() < 0.01);
+
+        let strong = OtDeluxeOxenState {
+            healthy: 6,
+            sick: 0,
+        };
+        let load = cargo_load(&policy, &inventory, strong);
+        assert!(!load.is_overloaded());
+        assert!((overload_speed_mult(&policy.travel, &load) - 1.0).abs() < f32::EPSILON);
+        assert!((overload_breakdown_mult(&policy.breakdown, &load) - 1.0).abs() < f32::EPSILON);
+
+        let weak = OtDeluxeOxenState {
+            healthy: 2,
+            sick: 2,
+        };
+        let load = cargo_load(&policy, &inventory, weak);
+        assert!(load.is_overloaded());
+        assert!((load.capacity_lbs - 1_500.0).abs() < 0.01);
+        assert!(overload_speed_mult(&policy.travel, &load) < 1.0);
+        assert!(overload_speed_mult(&policy.travel, &load) >= policy.travel.overload_speed_floor);
+        assert!(overload_breakdown_mult(&policy.breakdown, &load) > 1.0);
+
+        let no_oxen = cargo_load(&policy, &inventory, OtDeluxeOxenState::default());
+        assert!((no_oxen.overload_ratio() - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn discard_clamps_to_carried_quantity() {
+        let mut inventory = loaded_inventory();
+        assert_eq!(discard(&mut inventory, OtDeluxeCargoItem::Food, 500), 500);
+        assert_eq!(inventory.food_lbs, 1_000);
+        assert_eq!(discard(&mut inventory, OtDeluxeCargoItem::Wheel, 5), 2);
+        assert_eq!(inventory.spares_wheels, 0);
+        assert_eq!(discard(&mut inventory, OtDeluxeCargoItem::Wheel, 1), 0);
+    }
+
+    #[test]
+    fn purchase_projection_counts_new_oxen_and_cargo() {
+        let policy = OtDeluxe90sPolicy::default();
+        let inventory = OtDeluxeInventory::default();
+        let oxen = OtDeluxeOxenState {
+            healthy: 2,
+            sick: 0,
+        };
+        let heavy = [OtDeluxeStoreLineItem {
+            item: OtDeluxeStoreItem::FoodLb,
+            quantity: 1_200,
+        }];
+        assert!(load_after_purchase(&policy, &inventory, oxen, &heavy).is_overloaded());
+
+        let with_oxen = [
+            heavy[0],
+            OtDeluxeStoreLineItem {
+                item: OtDeluxeStoreItem::Oxen,
+                quantity: 2,
+            },
+        ];
+        let load = load_after_purchase(&policy, &inventory, oxen, &with_oxen);
+        assert!(!load.is_overloaded());
+        assert!((load.capacity_lbs - 2_000.0).abs() < 0.01);
+    }
+}
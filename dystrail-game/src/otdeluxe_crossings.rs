@@ -3,7 +3,7 @@
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
 
-use crate::journey::{EventDecisionTrace, RollValue, WeightFactor, WeightedCandidate};
+use crate::journey::{EventDecisionTrace, RollScale, RollValue, WeightFactor, WeightedCandidate};
 use crate::mechanics::otdeluxe90s::{OtDeluxeCrossingOutcomeWeights, OtDeluxeCrossingPolicy};
 use crate::numbers::{clamp_f64_to_f32, round_f64_to_i32};
 use crate::otdeluxe_state::{
@@ -126,6 +126,33 @@ impl OtDeluxeCrossingResolution {
     }
 }
 
+/// What a river guide takes for a crossing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OtDeluxeGuidePayment {
+    ClothesSets(u16),
+    Cash(u32),
+}
+
+/// Outcome odds for one crossing method as the party sees them.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct OtDeluxeCrossingMethodInfo {
+    pub method: OtDeluxeCrossingMethod,
+    pub available: bool,
+    /// Normalized outcome probabilities.
+    pub odds: OtDeluxeCrossingOutcomeWeights,
+}
+
+/// "Size up the situation" report shown before choosing how to cross.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OtDeluxeCrossingInfo {
+    pub river: OtDeluxeRiver,
+    /// River as observed; off by up to `fuzz` from the true state.
+    pub observed: OtDeluxeRiverState,
+    pub fuzz: f32,
+    pub methods: Vec<OtDeluxeCrossingMethodInfo>,
+}
+
 #[derive(Debug, Clone, Copy)]
 struct RiskContext {
     risk_mult: f32,
@@ -156,8 +183,8 @@ pub fn crossing_options(
         && river_state.depth_ft >= policy.ferry_min_depth_ft
         && inventory.cash_cents >= policy.ferry_cost_cents;
     let caulk_available = river_state.depth_ft >= policy.float_min_depth_ft;
-    let guide_available = matches!(river, OtDeluxeRiver::Snake)
-        && inventory.clothes_sets >= policy.guide_cost_clothes_sets;
+    let guide_available =
+        matches!(river, OtDeluxeRiver::Snake) && guide_payment(policy, inventory).is_some();
     let mut options = OtDeluxeCrossingOptions::empty().with_ford();
     if caulk_available {
         options = options.with_caulk_float();
@@ -171,6 +198,107 @@ pub fn crossing_options(
     options
 }
 
+/// Clothing is offered first; cash only when the guide accepts it.
+#[must_use]
+pub const fn guide_payment(
+    policy: &OtDeluxeCrossingPolicy,
+    inventory: &OtDeluxeInventory,
+) -> Option<OtDeluxeGuidePayment> {
+    if inventory.clothes_sets >= policy.guide_cost_clothes_sets {
+        Some(OtDeluxeGuidePayment::ClothesSets(
+            policy.guide_cost_clothes_sets,
+        ))
+    } else if policy.guide_cost_cents > 0 && inventory.cash_cents >= policy.guide_cost_cents {
+        Some(OtDeluxeGuidePayment::Cash(policy.guide_cost_cents))
+    } else {
+        None
+    }
+}
+
+/// Normalized outcome odds for a method, using the resolver's own weighting.
+#[must_use]
+pub fn crossing_odds(
+    policy: &OtDeluxeCrossingPolicy,
+    river_state: &OtDeluxeRiverState,
+    method: OtDeluxeCrossingMethod,
+) -> OtDeluxeCrossingOutcomeWeights {
+    let (weights, _) = adjusted_weights(policy, river_state, method);
+    let total = weights.safe
+        + weights.stuck
+        + weights.wet
+        + weights.tipped
+        + weights.sank
+        + weights.drowned;
+    if total <= f32::EPSILON {
+        return OtDeluxeCrossingOutcomeWeights {
+            safe: 1.0,
+            stuck: 0.0,
+            wet: 0.0,
+            tipped: 0.0,
+            sank: 0.0,
+            drowned: 0.0,
+        };
+    }
+    OtDeluxeCrossingOutcomeWeights {
+        safe: weights.safe / total,
+        stuck: weights.stuck / total,
+        wet: weights.wet / total,
+        tipped: weights.tipped / total,
+        sank: weights.sank / total,
+        drowned: weights.drowned / total,
+    }
+}
+
+/// Size up a river: observe its state with up to `fuzz` relative error and
+/// report the odds of every method against that observation.
+#[must_use]
+pub fn crossing_info<R: RngCore>(
+    policy: &OtDeluxeCrossingPolicy,
+    river: OtDeluxeRiver,
+    river_state: &OtDeluxeRiverState,
+    options: OtDeluxeCrossingOptions,
+    fuzz: f32,
+    rng: &mut R,
+) -> OtDeluxeCrossingInfo {
+    let fuzz = if fuzz.is_finite() {
+        fuzz.clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let mut blur = |value: f32| {
+        if fuzz <= 0.0 {
+            return value;
+        }
+        let offset = safe_sample_ratio(rng.next_u32()).mul_add(2.0, -1.0);
+        (value * offset.mul_add(fuzz, 1.0)).max(0.0)
+    };
+    let observed = OtDeluxeRiverState {
+        width_ft: blur(river_state.width_ft),
+        depth_ft: blur(river_state.depth_ft),
+        swiftness: blur(river_state.swiftness),
+        bed: river_state.bed,
+    };
+    let methods = [
+        OtDeluxeCrossingMethod::Ford,
+        OtDeluxeCrossingMethod::CaulkFloat,
+        OtDeluxeCrossingMethod::Ferry,
+        OtDeluxeCrossingMethod::Guide,
+    ]
+    .into_iter()
+    .map(|method| OtDeluxeCrossingMethodInfo {
+        method,
+        available: options.is_allowed(method),
+        odds: crossing_odds(policy, &observed, method),
+    })
+    .collect();
+    OtDeluxeCrossingInfo {
+        river,
+        observed,
+        fuzz,
+        methods,
+    }
+}
+
 #[must_use]
 pub fn derive_river_state(
     policy: &OtDeluxeCrossingPolicy,
@@ -375,7 +503,8 @@ const fn base_weights_for_method(
     method: OtDeluxeCrossingMethod,
 ) -> OtDeluxeCrossingOutcomeWeights {
     match method {
-        OtDeluxeCrossingMethod::Ford | OtDeluxeCrossingMethod::Guide => policy.outcome_weights.ford,
+        OtDeluxeCrossingMethod::Ford => policy.outcome_weights.ford,
+        OtDeluxeCrossingMethod::Guide => policy.outcome_weights.guide,
         OtDeluxeCrossingMethod::CaulkFloat => policy.outcome_weights.caulk_float,
         OtDeluxeCrossingMethod::Ferry => policy.outcome_weights.ferry,
     }
@@ -538,6 +667,7 @@ fn build_trace(
     EventDecisionTrace {
         pool_id: pool_id.to_string(),
         roll: RollValue::F32(draw),
+        roll_scale: RollScale::Share,
         candidates,
         chosen_id: outcome.id().to_string(),
     }
@@ -758,6 +888,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn guide_takes_clothing_first_then_cash_when_accepted() {
+        let mut policy = OtDeluxe90sPolicy::default().crossings;
+        let mut inventory = OtDeluxeInventory {
+            clothes_sets: policy.guide_cost_clothes_sets,
+            cash_cents: 10_000,
+            ..OtDeluxeInventory::default()
+        };
+        assert_eq!(
+            guide_payment(&policy, &inventory),
+            Some(OtDeluxeGuidePayment::ClothesSets(
+                policy.guide_cost_clothes_sets
+            ))
+        );
+        inventory.clothes_sets = 0;
+        assert_eq!(guide_payment(&policy, &inventory), None);
+        policy.guide_cost_cents = 2_500;
+        assert_eq!(
+            guide_payment(&policy, &inventory),
+            Some(OtDeluxeGuidePayment::Cash(2_500))
+        );
+        inventory.cash_cents = 2_499;
+        assert_eq!(guide_payment(&policy, &inventory), None);
+    }
+
+    #[test]
+    fn crossing_info_reports_normalized_odds_per_method() {
+        let policy = OtDeluxe90sPolicy::default();
+        let river_state = OtDeluxeRiverState {
+            depth_ft: 4.0,
+            width_ft: 900.0,
+            swiftness: 0.6,
+            bed: OtDeluxeRiverBed::Rocky,
+        };
+        let options = OtDeluxeCrossingOptions::empty().with_ford().with_guide();
+        let mut rng = SmallRng::seed_from_u64(3);
+        let info = crossing_info(
+            &policy.crossings,
+            OtDeluxeRiver::Snake,
+            &river_state,
+            options,
+            0.0,
+            &mut rng,
+        );
+        assert_eq!(info.observed, river_state);
+        assert_eq!(info.methods.len(), 4);
+        for entry in &info.methods {
+            let odds = entry.odds;
+            let total = odds.safe + odds.stuck + odds.wet + odds.tipped + odds.sank + odds.drowned;
+            assert!((total - 1.0).abs() < 1e-5);
+            assert_eq!(entry.available, options.is_allowed(entry.method));
+        }
+        let ford = crossing_odds(
+            &policy.crossings,
+            &river_state,
+            OtDeluxeCrossingMethod::Ford,
+        );
+        let guide = crossing_odds(
+            &policy.crossings,
+            &river_state,
+            OtDeluxeCrossingMethod::Guide,
+        );
+        assert!(guide.safe > ford.safe);
+
+        let fuzzed = crossing_info(
+            &policy.crossings,
+            OtDeluxeRiver::Snake,
+            &river_state,
+            options,
+            0.25,
+            &mut rng,
+        );
+        let error = (fuzzed.observed.depth_ft - river_state.depth_ft).abs();
+        assert!(error <= river_state.depth_ft * 0.25 + f32::EPSILON);
+        assert_eq!(fuzzed.observed.bed, river_state.bed);
+    }
+
     #[test]
     fn crossing_options_reflect_inventory_and_river_profiles() {
         let policy = OtDeluxe90sPolicy::default();
@@ -949,7 +1156,7 @@ mod tests {
     #[test]
     fn resolve_crossing_with_trace_covers_loss_branches() {
         let mut policy = OtDeluxe90sPolicy::default();
-        policy.crossings.outcome_weights.ford = OtDeluxeCrossingOutcomeWeights {
+        policy.crossings.outcome_weights.guide = OtDeluxeCrossingOutcomeWeights {
             safe: 0.0,
             stuck: 0.0,
             wet: 0.0,
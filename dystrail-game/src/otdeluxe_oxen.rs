@@ -0,0 +1,407 @@
+//! Individual oxen for Oregon Trail Deluxe parity.
+//!
+//! `OtDeluxeOxenState` keeps the healthy/sick head counts the rest of the
+//! simulation reads and writes. The herd gives those counts names, ages and
+//! health: it is reconciled against the counts whenever they change
+//! elsewhere, and its daily tick writes grazing, rest recovery and exhaustion
+//! deaths back into them.
+
+use serde::{Deserialize, Serialize};
+
+use crate::mechanics::otdeluxe90s::{OtDeluxeHerdPolicy, OtDeluxeOxenPolicy, OtDeluxePace};
+use crate::numbers::round_f32_to_i32;
+use crate::otdeluxe_state::OtDeluxeOxenState;
+use crate::state::Season;
+
+pub const OX_MAX_HEALTH: u8 = 100;
+
+const OX_NAMES: [&str; 12] = [
+    "Buck", "Bright", "Star", "Duke", "Blue", "Red", "Tom", "Jerry", "Pete", "Bill", "Sam", "Ned",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OtDeluxeOx {
+    pub name: String,
+    pub health: u8,
+    pub age_years: u8,
+    #[serde(default)]
+    pub sick_days_remaining: u8,
+    #[serde(default)]
+    pub injured_days_remaining: u8,
+}
+
+impl OtDeluxeOx {
+    #[must_use]
+    pub const fn is_sick(&self) -> bool {
+        self.sick_days_remaining > 0 || self.injured_days_remaining > 0
+    }
+
+    /// Pulling strength in whole-ox units: health scales it from one half to
+    /// one, and a sick or injured ox pulls at `sick_ox_weight` of that.
+    #[must_use]
+    pub fn strength(&self, policy: &OtDeluxeOxenPolicy) -> f32 {
+        let health = f32::from(self.health.min(OX_MAX_HEALTH)) / f32::from(OX_MAX_HEALTH);
+        let fitness = 0.5_f32.mul_add(health, 0.5);
+        if self.is_sick() {
+            fitness * policy.sick_ox_weight.max(0.0)
+        } else {
+            fitness
+        }
+    }
+}
+
+/// How an ox died on the trail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OtDeluxeOxDeathCause {
+    /// Worked to death at a grueling pace.
+    Exhaustion,
+    /// Wasted away from poor grazing.
+    Starvation,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OtDeluxeOxDeath {
+    pub name: String,
+    pub cause: OtDeluxeOxDeathCause,
+}
+
+/// Conditions the herd faces today.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OtDeluxeHerdDay {
+    pub season: Season,
+    pub pace: OtDeluxePace,
+    pub resting: bool,
+    /// The team pulls the wagon today; idle days only graze.
+    pub working: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OtDeluxeHerdTick {
+    pub died: Vec<OtDeluxeOxDeath>,
+    pub recovered: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OtDeluxeHerd {
+    #[serde(default)]
+    pub oxen: Vec<OtDeluxeOx>,
+    /// Days of poor grazing left from a "no grass" shortage.
+    #[serde(default)]
+    pub no_grass_days_remaining: u8,
+    /// Oxen ever added, used to hand out names and ages.
+    #[serde(default)]
+    pub acquired: u16,
+}
+
+impl OtDeluxeHerd {
+    /// Head counts implied by the herd.
+    #[must_use]
+    pub fn counts(&self) -> OtDeluxeOxenState {
+        let sick = self.oxen.iter().filter(|ox| ox.is_sick()).count();
+        let sick = u16::try_from(sick).unwrap_or(u16::MAX);
+        let total = u16::try_from(self.oxen.len()).unwrap_or(u16::MAX);
+        OtDeluxeOxenState {
+            healthy: total.saturating_sub(sick),
+            sick,
+        }
+    }
+
+    #[must_use]
+    pub fn matches(&self, counts: OtDeluxeOxenState) -> bool {
+        self.counts() == counts
+    }
+
+    /// Aggregate pulling strength of the herd.
+    #[must_use]
+    pub fn strength(&self, policy: &OtDeluxeOxenPolicy) -> f32 {
+        self.oxen.iter().map(|ox| ox.strength(policy)).sum()
+    }
+
+    /// Bring the herd in line with head counts changed elsewhere: animals
+    /// that fell sick or recovered are chosen by health, lost animals are
+    /// the weakest, and new animals arrive at full health.
+    pub fn reconcile(&mut self, counts: OtDeluxeOxenState, policy: &OtDeluxeHerdPolicy) {
+        let mut current = self.counts();
+        while current.sick < counts.sick && current.healthy > counts.healthy {
+            if self.sicken_weakest(policy).is_none() {
+                break;
+            }
+            current = self.counts();
+        }
+        while current.sick > counts.sick && current.healthy < counts.healthy {
+            if self.heal_strongest().is_none() {
+                break;
+            }
+            current = self.counts();
+        }
+        while current.sick > counts.sick {
+            self.remove_weakest(true);
+            current = self.counts();
+        }
+        while current.healthy > counts.healthy {
+            self.remove_weakest(false);
+            current = self.counts();
+        }
+        while current.sick < counts.sick {
+            self.acquire(policy, true);
+            current = self.counts();
+        }
+        while current.healthy < counts.healthy {
+            self.acquire(policy, false);
+            current = self.counts();
+        }
+    }
+
+    /// Lose the weakest healthy ox, else the weakest sick one.
+    pub fn lose_one(&mut self) -> Option<String> {
+        let has_healthy = self.oxen.iter().any(|ox| !ox.is_sick());
+        self.remove_weakest(!has_healthy)
+    }
+
+    /// The weakest healthy ox falls sick; returns its name.
+    pub fn sicken_weakest(&mut self, policy: &OtDeluxeHerdPolicy) -> Option<String> {
+        let ox = self
+            .oxen
+            .iter_mut()
+            .filter(|ox| !ox.is_sick())
+            .min_by_key(|ox| ox.health)?;
+        ox.sick_days_remaining = policy.sick_days.max(1);
+        Some(ox.name.clone())
+    }
+
+    pub fn spoil_grazing(&mut self, days: u8) -> u8 {
+        let before = self.no_grass_days_remaining;
+        self.no_grass_days_remaining = before.max(days);
+        self.no_grass_days_remaining - before
+    }
+
+    /// Advance one day of grazing, work and recovery.
+    pub fn tick_day(
+        &mut self,
+        policy: &OtDeluxeHerdPolicy,
+        day: OtDeluxeHerdDay,
+    ) -> OtDeluxeHerdTick {
+        let grazing = if self.no_grass_days_remaining > 0 {
+            0.0
+        } else {
+            policy.graze_mult(day.season).max(0.0)
+        };
+        self.no_grass_days_remaining = self.no_grass_days_remaining.saturating_sub(1);
+        let graze = round_f32_to_i32(f32::from(policy.graze_gain) * grazing);
+        let recovery_days = if day.resting {
+            policy.rest_recovery_days.max(1)
+        } else {
+            1
+        };
+
+        let mut tick = OtDeluxeHerdTick::default();
+        for ox in &mut self.oxen {
+            let was_sick = ox.is_sick();
+            let mut delta = if was_sick { 0 } else { graze };
+            if day.resting {
+                delta += i32::from(policy.rest_recovery);
+            } else if day.working {
+                delta -= i32::from(policy.work_cost(day.pace));
+                if ox.age_years >= policy.old_age_years {
+                    delta -= i32::from(policy.old_age_cost);
+                }
+            }
+            let health = (i32::from(ox.health) + delta).clamp(0, i32::from(OX_MAX_HEALTH));
+            ox.health = u8::try_from(health).unwrap_or(0);
+            ox.sick_days_remaining = ox.sick_days_remaining.saturating_sub(recovery_days);
+            ox.injured_days_remaining = ox.injured_days_remaining.saturating_sub(recovery_days);
+            if was_sick && !ox.is_sick() && ox.health > 0 {
+                tick.recovered.push(ox.name.clone());
+            }
+        }
+
+        let cause = if day.pace == OtDeluxePace::Grueling && day.working && !day.resting {
+            OtDeluxeOxDeathCause::Exhaustion
+        } else {
+            OtDeluxeOxDeathCause::Starvation
+        };
+        self.oxen.retain(|ox| {
+            if ox.health == 0 {
+                tick.died.push(OtDeluxeOxDeath {
+                    name: ox.name.clone(),
+                    cause,
+                });
+                false
+            } else {
+                true
+            }
+        });
+        tick
+    }
+
+    fn heal_strongest(&mut self) -> Option<String> {
+        let ox = self
+            .oxen
+            .iter_mut()
+            .filter(|ox| ox.is_sick())
+            .max_by_key(|ox| ox.health)?;
+        ox.sick_days_remaining = 0;
+        ox.injured_days_remaining = 0;
+        Some(ox.name.clone())
+    }
+
+    fn remove_weakest(&mut self, sick: bool) -> Option<String> {
+        let idx = self
+            .oxen
+            .iter()
+            .enumerate()
+            .filter(|(_, ox)| ox.is_sick() == sick)
+            .min_by_key(|(_, ox)| ox.health)
+            .map(|(idx, _)| idx)?;
+        Some(self.oxen.remove(idx).name)
+    }
+
+    fn acquire(&mut self, policy: &OtDeluxeHerdPolicy, sick: bool) {
+        let serial = usize::from(self.acquired);
+        self.acquired = self.acquired.saturating_add(1);
+        let base = OX_NAMES[serial % OX_NAMES.len()];
+        let name = match serial / OX_NAMES.len() {
+            0 => base.to_string(),
+            round => format!("{base} {}", round + 1),
+        };
+        let min_age = policy.min_age_years.min(policy.max_age_years);
+        let span = usize::from(policy.max_age_years - min_age) + 1;
+        // Spread ages with a fixed stride so consecutive purchases differ.
+        let offset = u8::try_from(serial.wrapping_mul(5) % span).unwrap_or(0);
+        self.oxen.push(OtDeluxeOx {
+            name,
+            health: OX_MAX_HEALTH,
+            age_years: min_age + offset,
+            sick_days_remaining: if sick { policy.sick_days.max(1) } else { 0 },
+            injured_days_remaining: 0,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn herd_of(healthy: u16, sick: u16) -> OtDeluxeHerd {
+        let mut herd = OtDeluxeHerd::default();
+        herd.reconcile(
+            OtDeluxeOxenState { healthy, sick },
+            &OtDeluxeHerdPolicy::default(),
+        );
+        herd
+    }
+
+    #[test]
+    fn reconcile_names_new_oxen_and_follows_count_changes() {
+        let policy = OtDeluxeHerdPolicy::default();
+        let mut herd = herd_of(4, 0);
+        assert_eq!(herd.oxen.len(), 4);
+        assert_eq!(herd.oxen[0].name, "Buck");
+        assert_ne!(herd.oxen[0].age_years, herd.oxen[1].age_years);
+
+        herd.oxen[2].health = 40;
+        let weak = herd.oxen[2].name.clone();
+        herd.reconcile(
+            OtDeluxeOxenState {
+                healthy: 3,
+                sick: 1,
+            },
+            &policy,
+        );
+        let sick: Vec<_> = herd.oxen.iter().filter(|ox| ox.is_sick()).collect();
+        assert_eq!(sick.len(), 1);
+        assert_eq!(sick[0].name, weak);
+
+        herd.reconcile(
+            OtDeluxeOxenState {
+                healthy: 3,
+                sick: 0,
+            },
+            &policy,
+        );
+        assert!(herd.oxen.iter().all(|ox| ox.name != weak));
+        assert!(herd.matches(OtDeluxeOxenState {
+            healthy: 3,
+            sick: 0
+        }));
+    }
+
+    #[test]
+    fn rest_and_good_grass_restore_health_while_no_grass_wears_it_down() {
+        let policy = OtDeluxeHerdPolicy::default();
+        let mut herd = herd_of(2, 1);
+        for ox in &mut herd.oxen {
+            ox.health = 50;
+        }
+        let rest = OtDeluxeHerdDay {
+            season: Season::Summer,
+            pace: OtDeluxePace::Steady,
+            resting: true,
+            working: false,
+        };
+        let tick = herd.tick_day(&policy, rest);
+        assert!(tick.died.is_empty());
+        assert!(herd.oxen.iter().all(|ox| ox.health > 50));
+
+        herd.spoil_grazing(policy.no_grass_days);
+        let before: Vec<u8> = herd.oxen.iter().map(|ox| ox.health).collect();
+        let work = OtDeluxeHerdDay {
+            resting: false,
+            working: true,
+            ..rest
+        };
+        herd.tick_day(&policy, work);
+        assert!(
+            herd.oxen
+                .iter()
+                .zip(before)
+                .all(|(ox, health)| ox.health < health)
+        );
+        assert_eq!(herd.no_grass_days_remaining, policy.no_grass_days - 1);
+    }
+
+    #[test]
+    fn grueling_pace_can_work_oxen_to_death() {
+        let policy = OtDeluxeHerdPolicy::default();
+        let mut herd = herd_of(2, 0);
+        herd.oxen[0].health = 2;
+        let day = OtDeluxeHerdDay {
+            season: Season::Winter,
+            pace: OtDeluxePace::Grueling,
+            resting: false,
+            working: true,
+        };
+        let tick = herd.tick_day(&policy, day);
+        assert_eq!(tick.died.len(), 1);
+        assert_eq!(tick.died[0].cause, OtDeluxeOxDeathCause::Exhaustion);
+        assert_eq!(herd.counts().total(), 1);
+    }
+
+    #[test]
+    fn idle_days_graze_without_work_cost() {
+        let policy = OtDeluxeHerdPolicy::default();
+        let mut herd = herd_of(2, 0);
+        herd.oxen[0].health = 2;
+        herd.oxen[1].health = 50;
+        let day = OtDeluxeHerdDay {
+            season: Season::Summer,
+            pace: OtDeluxePace::Grueling,
+            resting: false,
+            working: false,
+        };
+        let tick = herd.tick_day(&policy, day);
+        assert!(tick.died.is_empty());
+        assert!(herd.oxen[0].health >= 2);
+        assert!(herd.oxen[1].health >= 50);
+    }
+
+    #[test]
+    fn strength_scales_with_health_and_sickness() {
+        let policy = OtDeluxeOxenPolicy::default();
+        let mut herd = herd_of(1, 1);
+        assert!((herd.strength(&policy) - 1.5).abs() < f32::EPSILON);
+        herd.oxen[0].health = 0;
+        assert!(herd.strength(&policy) < 1.5);
+    }
+}
@@ -1,8 +1,14 @@
 use rand::Rng;
 use serde::Deserialize;
 use std::sync::OnceLock;
-
-use crate::journey::{EventDecisionTrace, RollValue, WeightFactor, WeightedCandidate};
+use thiserror::Error;
+
+use crate::journey::{
+    EventDecisionTrace, EventSeverity, RollScale, RollValue, WeightFactor, WeightedCandidate,
+};
+use crate::numbers::round_f64_to_i32;
+use crate::otdeluxe_skills::OtDeluxeSkill;
+use crate::otdeluxe_state::OtDeluxeAfflictionKind;
 use crate::state::Season;
 
 const DEFAULT_RANDOM_EVENTS_DATA: &str =
@@ -12,6 +18,14 @@ const fn default_weight() -> u32 {
     1
 }
 
+const fn default_severity() -> EventSeverity {
+    EventSeverity::Info
+}
+
+const fn default_count() -> u8 {
+    1
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct OtDeluxeRandomEventCatalog {
     #[serde(default)]
@@ -21,10 +35,132 @@ pub struct OtDeluxeRandomEventCatalog {
 }
 
 impl OtDeluxeRandomEventCatalog {
+    /// Parse a catalog and validate every variant's effect list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is malformed or [`Self::validate`] fails.
+    pub fn from_json(json: &str) -> Result<Self, OtDeluxeRandomEventError> {
+        let catalog: Self = serde_json::from_str(json)
+            .map_err(|err| OtDeluxeRandomEventError::Parse(err.to_string()))?;
+        catalog.validate()?;
+        Ok(catalog)
+    }
+
+    /// Load the bundled catalog.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bundled catalog fails to parse or validate.
     #[must_use]
     pub fn load_from_static() -> Self {
-        serde_json::from_str(DEFAULT_RANDOM_EVENTS_DATA).unwrap_or_default()
+        Self::from_json(DEFAULT_RANDOM_EVENTS_DATA)
+            .unwrap_or_else(|err| panic!("bundled OTDeluxe random event catalog: {err}"))
+    }
+
+    /// Look up the definition of a selected variant.
+    #[must_use]
+    pub fn variant(&self, event_id: &str, variant_id: &str) -> Option<&OtDeluxeRandomEventVariant> {
+        self.events
+            .iter()
+            .find(|event| event.id == event_id)?
+            .variants
+            .iter()
+            .find(|variant| variant.id == variant_id)
+    }
+
+    /// Check that every variant declares a well-formed effect list.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first event or variant that cannot be resolved.
+    pub fn validate(&self) -> Result<(), OtDeluxeRandomEventError> {
+        for event in &self.events {
+            if event.variants.is_empty() {
+                return Err(OtDeluxeRandomEventError::NoVariants {
+                    event: event.id.clone(),
+                });
+            }
+            for variant in &event.variants {
+                let effects = variant.effects.as_deref().ok_or_else(|| {
+                    OtDeluxeRandomEventError::MissingEffects {
+                        event: event.id.clone(),
+                        variant: variant.id.clone(),
+                    }
+                })?;
+                validate_effects(effects).map_err(|reason| {
+                    OtDeluxeRandomEventError::InvalidEffect {
+                        event: event.id.clone(),
+                        variant: variant.id.clone(),
+                        reason,
+                    }
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn validate_effects(effects: &[OtDeluxeRandomEventEffect]) -> Result<(), String> {
+    for effect in effects {
+        match effect {
+            OtDeluxeRandomEventEffect::Health { .. } => {}
+            OtDeluxeRandomEventEffect::Inventory {
+                item,
+                amount,
+                ratio,
+            } => match (amount, ratio) {
+                (Some(_), None) => {}
+                (None, Some(ratio)) if ratio.is_finite() && (-1.0..=1.0).contains(ratio) => {}
+                (None, Some(ratio)) => {
+                    return Err(format!(
+                        "{} ratio {ratio} must be within -1..=1",
+                        item.key()
+                    ));
+                }
+                _ => {
+                    return Err(format!(
+                        "{} needs exactly one of amount or ratio",
+                        item.key()
+                    ));
+                }
+            },
+            OtDeluxeRandomEventEffect::DelayDays { days: 0 } => {
+                return Err(String::from("delay_days must be positive"));
+            }
+            OtDeluxeRandomEventEffect::NoGrass { days: 0 } => {
+                return Err(String::from("no_grass days must be positive"));
+            }
+            OtDeluxeRandomEventEffect::LoseMembers { count: 0 }
+            | OtDeluxeRandomEventEffect::Oxen { count: 0, .. } => {
+                return Err(String::from("count must be positive"));
+            }
+            OtDeluxeRandomEventEffect::LoseSpare { fallback } => validate_effects(fallback)?,
+            OtDeluxeRandomEventEffect::DelayDays { .. }
+            | OtDeluxeRandomEventEffect::Affliction { .. }
+            | OtDeluxeRandomEventEffect::LoseMembers { .. }
+            | OtDeluxeRandomEventEffect::Oxen { .. }
+            | OtDeluxeRandomEventEffect::NoGrass { .. } => {}
+        }
     }
+    Ok(())
+}
+
+/// Errors raised when the random event catalog cannot be resolved.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum OtDeluxeRandomEventError {
+    #[error("random event catalog is malformed: {0}")]
+    Parse(String),
+    #[error("random event {event} has no variants")]
+    NoVariants { event: String },
+    #[error("random event {event}.{variant} declares no effects")]
+    MissingEffects { event: String, variant: String },
+    #[error("random event {event}.{variant} has an invalid effect: {reason}")]
+    InvalidEffect {
+        event: String,
+        variant: String,
+        reason: String,
+    },
 }
 
 impl Default for OtDeluxeRandomEventCatalog {
@@ -50,6 +186,115 @@ pub struct OtDeluxeRandomEventVariant {
     pub id: String,
     #[serde(default = "default_weight")]
     pub weight: u32,
+    #[serde(default = "default_severity")]
+    pub severity: EventSeverity,
+    /// Log key override; defaults to `log.otdeluxe.random_event.{event}.{variant}`.
+    #[serde(default)]
+    pub ui_key: Option<String>,
+    /// Effects applied in order. `None` means the variant was never described.
+    #[serde(default)]
+    pub effects: Option<Vec<OtDeluxeRandomEventEffect>>,
+    /// Party skill that makes this variant likelier; its multiplier scales the weight.
+    #[serde(default)]
+    pub skill: Option<OtDeluxeSkill>,
+}
+
+impl OtDeluxeRandomEventVariant {
+    #[must_use]
+    pub fn log_key(&self, event_id: &str) -> String {
+        self.ui_key
+            .clone()
+            .unwrap_or_else(|| format!("log.otdeluxe.random_event.{event_id}.{}", self.id))
+    }
+}
+
+/// Inventory line a random event can adjust.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OtDeluxeRandomEventItem {
+    FoodLbs,
+    Bullets,
+    ClothesSets,
+    SparesWheels,
+    SparesAxles,
+    SparesTongues,
+}
+
+impl OtDeluxeRandomEventItem {
+    /// Key used for this item in event payload deltas.
+    #[must_use]
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::FoodLbs => "food_lbs",
+            Self::Bullets => "bullets",
+            Self::ClothesSets => "clothes_sets",
+            Self::SparesWheels => "spares_wheels",
+            Self::SparesAxles => "spares_axles",
+            Self::SparesTongues => "spares_tongues",
+        }
+    }
+}
+
+/// Party member an affliction effect lands on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum OtDeluxeAfflictionTarget {
+    /// A random living member.
+    #[default]
+    Random,
+    /// The member at this party index.
+    Member(usize),
+}
+
+/// What happens to the oxen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OtDeluxeOxenChange {
+    /// A healthy ox is lost, else a sick one.
+    Lose,
+    /// A healthy ox falls sick, else a sick one dies.
+    Sicken,
+}
+
+/// One step of a random event variant's outcome.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OtDeluxeRandomEventEffect {
+    /// Shift general health; positive values are worse.
+    Health { delta: i32 },
+    /// Adjust an inventory line by a fixed `amount` or a `ratio` of the current stock.
+    Inventory {
+        item: OtDeluxeRandomEventItem,
+        #[serde(default)]
+        amount: Option<i32>,
+        #[serde(default)]
+        ratio: Option<f32>,
+    },
+    /// Hold the wagon in place for extra days.
+    DelayDays { days: u8 },
+    /// Illness or injury drawn from the disease catalog.
+    Affliction {
+        kind: OtDeluxeAfflictionKind,
+        #[serde(default)]
+        target: OtDeluxeAfflictionTarget,
+    },
+    /// Random living members leave the party for good.
+    LoseMembers {
+        #[serde(default = "default_count")]
+        count: u8,
+    },
+    Oxen {
+        change: OtDeluxeOxenChange,
+        #[serde(default = "default_count")]
+        count: u8,
+    },
+    /// Spoil grazing so the oxen cannot feed for `days` days.
+    NoGrass { days: u8 },
+    /// Break a random spare part; `fallback` applies when none are carried.
+    LoseSpare {
+        #[serde(default)]
+        fallback: Vec<Self>,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -62,6 +307,8 @@ pub struct OtDeluxeRandomEventContext {
     pub spares_total: u16,
     pub weight_mult: f64,
     pub weight_cap: Option<f64>,
+    /// Skill modifiers the party currently lends; variants naming a skill take its factor.
+    pub skill_factors: Vec<(OtDeluxeSkill, WeightFactor)>,
 }
 
 #[derive(Debug, Clone)]
@@ -163,13 +410,15 @@ where
     let decision_trace = EventDecisionTrace {
         pool_id: String::from("otdeluxe.random_events"),
         roll: RollValue::F64(roll_f64),
+        roll_scale: RollScale::Final,
         candidates,
         chosen_id: selected.id.clone(),
     };
 
     let event_id = &selected.id;
     let variants = &selected.variants;
-    let (variant_id, variant_trace) = pick_variant_with_trace(event_id, variants, rng);
+    let (variant_id, variant_trace) =
+        pick_variant_with_trace(event_id, variants, &ctx.skill_factors, rng);
     let selection = OtDeluxeRandomEventSelection {
         event_id: selected.id.clone(),
         variant_id,
@@ -282,44 +531,102 @@ fn apply_factor(weight: &mut f64, factors: &mut Vec<WeightFactor>, label: &str,
     *weight *= value;
 }
 
+/// Resolution used for variant weights once a multiplier applies, so that
+/// fractional multipliers survive the integer roll.
+const VARIANT_WEIGHT_SCALE: f64 = 100.0;
+
+fn variant_factors(
+    variant: &OtDeluxeRandomEventVariant,
+    skill_factors: &[(OtDeluxeSkill, WeightFactor)],
+) -> Vec<WeightFactor> {
+    variant
+        .skill
+        .and_then(|skill| skill_factors.iter().find(|(active, _)| *active == skill))
+        .map(|(_, factor)| vec![factor.clone()])
+        .unwrap_or_default()
+}
+
+fn factored_weight(base: u32, factors: &[WeightFactor]) -> f64 {
+    factors
+        .iter()
+        .fold(f64::from(base), |weight, factor| weight * factor.value)
+}
+
 fn pick_variant_with_trace<R: Rng + ?Sized>(
     event_id: &str,
     variants: &[OtDeluxeRandomEventVariant],
+    skill_factors: &[(OtDeluxeSkill, WeightFactor)],
     rng: &mut R,
 ) -> (Option<String>, Option<EventDecisionTrace>) {
     if variants.is_empty() {
         return (None, None);
     }
 
-    let total_weight: u32 = variants.iter().map(|variant| variant.weight).sum();
-    let (chosen_idx, roll) = if total_weight == 0 {
+    let factors: Vec<Vec<WeightFactor>> = variants
+        .iter()
+        .map(|variant| variant_factors(variant, skill_factors))
+        .collect();
+    let factored = !factors.iter().all(Vec::is_empty);
+    let weights: Vec<u32> = if factored {
+        variants
+            .iter()
+            .zip(&factors)
+            .map(|(variant, factors)| {
+                let scaled = factored_weight(variant.weight, factors) * VARIANT_WEIGHT_SCALE;
+                u32::try_from(round_f64_to_i32(scaled).max(0)).unwrap_or(0)
+            })
+            .collect()
+    } else {
+        variants.iter().map(|variant| variant.weight).collect()
+    };
+
+    let total_weight: u32 = weights.iter().sum();
+    let (chosen_idx, roll, roll_scale) = if total_weight == 0 {
         let idx = rng.gen_range(0..variants.len());
-        (idx, u32::try_from(idx).unwrap_or(0))
+        (
+            idx,
+            RollValue::U32(u32::try_from(idx).unwrap_or(0)),
+            RollScale::Index,
+        )
     } else {
         let roll = rng.gen_range(0..total_weight);
         let mut cursor = 0_u32;
         let mut selected = None;
-        for (idx, variant) in variants.iter().enumerate() {
-            cursor = cursor.saturating_add(variant.weight);
+        for (idx, weight) in weights.iter().enumerate() {
+            cursor = cursor.saturating_add(*weight);
             if selected.is_none() && roll < cursor {
                 selected = Some(idx);
             }
         }
-        (selected.unwrap_or(0), roll)
+        // Factored weights are rolled in hundredths; report the roll on the
+        // same scale as the final weights it was compared against.
+        let roll = if factored {
+            RollValue::F64(f64::from(roll) / VARIANT_WEIGHT_SCALE)
+        } else {
+            RollValue::U32(roll)
+        };
+        (selected.unwrap_or(0), roll, RollScale::Final)
     };
 
     let candidates = variants
         .iter()
-        .map(|variant| WeightedCandidate {
+        .zip(factors)
+        .zip(&weights)
+        .map(|((variant, multipliers), weight)| WeightedCandidate {
             id: variant.id.clone(),
             base_weight: f64::from(variant.weight),
-            multipliers: Vec::new(),
-            final_weight: f64::from(variant.weight),
+            final_weight: if factored {
+                f64::from(*weight) / VARIANT_WEIGHT_SCALE
+            } else {
+                f64::from(*weight)
+            },
+            multipliers,
         })
         .collect();
     let trace = EventDecisionTrace {
         pool_id: format!("otdeluxe.random_events.{event_id}.variant"),
-        roll: RollValue::U32(roll),
+        roll,
+        roll_scale,
         candidates,
         chosen_id: variants[chosen_idx].id.clone(),
     };
@@ -344,6 +651,17 @@ mod tests {
             .unwrap_or_else(|| panic!("missing event {id}"))
     }
 
+    fn test_variant(id: &str, weight: u32) -> OtDeluxeRandomEventVariant {
+        OtDeluxeRandomEventVariant {
+            id: id.to_string(),
+            weight,
+            severity: EventSeverity::Info,
+            ui_key: None,
+            effects: Some(Vec::new()),
+            skill: None,
+        }
+    }
+
     fn variant_ids(event: &OtDeluxeRandomEventDef) -> Vec<&str> {
         event
             .variants
@@ -423,6 +741,111 @@ mod tests {
         );
     }
 
+    #[test]
+    fn static_catalog_resolves_every_variant() {
+        assert_eq!(catalog().validate(), Ok(()));
+        let fire = catalog()
+            .variant("resource_change", "fire")
+            .expect("fire variant");
+        assert_eq!(fire.severity, EventSeverity::Critical);
+        assert_eq!(
+            fire.log_key("resource_change"),
+            "log.otdeluxe.random_event.resource_change.fire"
+        );
+        assert!(catalog().variant("resource_change", "unknown").is_none());
+    }
+
+    #[test]
+    fn from_json_validates_catalogs() {
+        assert!(OtDeluxeRandomEventCatalog::from_json(DEFAULT_RANDOM_EVENTS_DATA).is_ok());
+        assert!(matches!(
+            OtDeluxeRandomEventCatalog::from_json("{ \"events\": 3 }"),
+            Err(OtDeluxeRandomEventError::Parse(_))
+        ));
+        assert_eq!(
+            OtDeluxeRandomEventCatalog::from_json(
+                r#"{ "events": [{ "id": "storm", "variants": [{ "id": "hail" }] }] }"#
+            )
+            .map(|_| ()),
+            Err(OtDeluxeRandomEventError::MissingEffects {
+                event: String::from("storm"),
+                variant: String::from("hail"),
+            })
+        );
+    }
+
+    #[test]
+    fn validate_reports_unresolved_variants() {
+        let parse = |json: &str| -> OtDeluxeRandomEventCatalog {
+            serde_json::from_str(json).expect("parse catalog")
+        };
+        let missing = parse(r#"{ "events": [{ "id": "storm", "variants": [{ "id": "hail" }] }] }"#);
+        assert_eq!(
+            missing.validate(),
+            Err(OtDeluxeRandomEventError::MissingEffects {
+                event: String::from("storm"),
+                variant: String::from("hail"),
+            })
+        );
+
+        let empty = parse(r#"{ "events": [{ "id": "storm" }] }"#);
+        assert!(matches!(
+            empty.validate(),
+            Err(OtDeluxeRandomEventError::NoVariants { .. })
+        ));
+
+        let ambiguous = parse(
+            r#"{ "events": [{ "id": "storm", "variants": [{ "id": "hail", "effects": [
+                { "type": "lose_spare", "fallback": [
+                    { "type": "inventory", "item": "food_lbs", "amount": -5, "ratio": -0.1 }
+                ] }
+            ] }] }] }"#,
+        );
+        let err = ambiguous
+            .validate()
+            .expect_err("ambiguous inventory effect");
+        assert!(err.to_string().contains("food_lbs needs exactly one"));
+
+        let out_of_range = parse(
+            r#"{ "events": [{ "id": "storm", "variants": [{ "id": "hail", "effects": [
+                { "type": "inventory", "item": "bullets", "ratio": -1.5 }
+            ] }] }] }"#,
+        );
+        assert!(out_of_range.validate().is_err());
+
+        let zero_delay = parse(
+            r#"{ "events": [{ "id": "storm", "variants": [{ "id": "hail", "effects": [
+                { "type": "delay_days", "days": 0 }
+            ] }] }] }"#,
+        );
+        assert!(zero_delay.validate().is_err());
+    }
+
+    #[test]
+    fn effects_parse_targets_and_ui_key() {
+        let variant: OtDeluxeRandomEventVariant = serde_json::from_str(
+            r#"{ "id": "snakebite", "severity": "warning", "ui_key": "log.custom", "effects": [
+                { "type": "affliction", "kind": "injury", "target": { "member": 1 } },
+                { "type": "affliction", "kind": "illness" }
+            ] }"#,
+        )
+        .expect("parse variant");
+        assert_eq!(variant.log_key("party_incident"), "log.custom");
+        assert_eq!(
+            variant.effects,
+            Some(vec![
+                OtDeluxeRandomEventEffect::Affliction {
+                    kind: OtDeluxeAfflictionKind::Injury,
+                    target: OtDeluxeAfflictionTarget::Member(1),
+                },
+                OtDeluxeRandomEventEffect::Affliction {
+                    kind: OtDeluxeAfflictionKind::Illness,
+                    target: OtDeluxeAfflictionTarget::Random,
+                },
+            ])
+        );
+    }
+
     #[test]
     fn random_event_catalog_defaults_weights_when_missing() {
         let json = r#"{
@@ -462,6 +885,7 @@ mod tests {
             spares_total: 0,
             weight_mult: 1.0,
             weight_cap: None,
+            skill_factors: Vec::new(),
         };
         let spring_ctx = OtDeluxeRandomEventContext {
             season: Season::Spring,
@@ -472,6 +896,7 @@ mod tests {
             spares_total: 2,
             weight_mult: 1.0,
             weight_cap: None,
+            skill_factors: Vec::new(),
         };
         let weather = OtDeluxeRandomEventDef {
             id: String::from("weather_catastrophe"),
@@ -558,6 +983,7 @@ mod tests {
             spares_total: 1,
             weight_mult: 0.5,
             weight_cap: Some(3.0),
+            skill_factors: Vec::new(),
         };
         let event = OtDeluxeRandomEventDef {
             id: String::from("resource_change"),
@@ -582,43 +1008,78 @@ mod tests {
     #[test]
     fn pick_variant_with_trace_handles_empty_and_zero_weights() {
         let mut rng = SmallRng::seed_from_u64(42);
-        let (variant_id, trace) = pick_variant_with_trace("empty", &[], &mut rng);
+        let (variant_id, trace) = pick_variant_with_trace("empty", &[], &[], &mut rng);
         assert!(variant_id.is_none());
         assert!(trace.is_none());
 
-        let variants = vec![
-            OtDeluxeRandomEventVariant {
-                id: String::from("a"),
-                weight: 0,
-            },
-            OtDeluxeRandomEventVariant {
-                id: String::from("b"),
-                weight: 0,
-            },
-        ];
-        let (variant_id, trace) = pick_variant_with_trace("zero", &variants, &mut rng);
+        let variants = vec![test_variant("a", 0), test_variant("b", 0)];
+        let (variant_id, trace) = pick_variant_with_trace("zero", &variants, &[], &mut rng);
         assert!(variant_id.is_some());
         assert!(trace.is_some());
     }
 
     #[test]
     fn pick_variant_with_trace_selects_weighted_variant() {
-        let variants = vec![
-            OtDeluxeRandomEventVariant {
-                id: String::from("a"),
-                weight: 1,
-            },
-            OtDeluxeRandomEventVariant {
-                id: String::from("b"),
-                weight: 3,
-            },
-        ];
+        let variants = vec![test_variant("a", 1), test_variant("b", 3)];
         let mut rng = SmallRng::seed_from_u64(8);
-        let (variant_id, trace) = pick_variant_with_trace("weighted", &variants, &mut rng);
+        let (variant_id, trace) = pick_variant_with_trace("weighted", &variants, &[], &mut rng);
         assert!(variant_id.is_some());
         assert!(trace.is_some());
     }
 
+    #[test]
+    fn variant_skill_takes_the_matching_party_factor() {
+        let mut repairable = test_variant("repairable", 1);
+        repairable.skill = Some(OtDeluxeSkill::Carpenter);
+        let variants = vec![
+            repairable,
+            test_variant("replaceable", 1),
+            test_variant("unrepairable", 1),
+        ];
+        let factor = WeightFactor {
+            label: String::from("skill_carpenter"),
+            value: 2.0,
+        };
+        let factors = vec![(OtDeluxeSkill::Carpenter, factor.clone())];
+        let mut rng = StepRng::new(0, 0);
+        let (variant_id, trace) =
+            pick_variant_with_trace("wagon_part_break", &variants, &factors, &mut rng);
+        assert_eq!(variant_id.as_deref(), Some("repairable"));
+        let trace = trace.expect("variant trace");
+        let repairable = &trace.candidates[0];
+        assert_eq!(repairable.multipliers, vec![factor]);
+        assert!((repairable.final_weight - 2.0).abs() < f64::EPSILON);
+        assert!(trace.candidates[1].multipliers.is_empty());
+
+        let hunter = vec![(
+            OtDeluxeSkill::Hunter,
+            WeightFactor {
+                label: String::from("skill_hunter"),
+                value: 1.25,
+            },
+        )];
+        let (_, trace) = pick_variant_with_trace("wagon_part_break", &variants, &hunter, &mut rng);
+        let trace = trace.expect("variant trace");
+        assert!(trace.candidates.iter().all(|c| c.multipliers.is_empty()));
+    }
+
+    #[test]
+    fn default_catalog_names_the_carpenter_on_repairable_breaks() {
+        let event = find_event(catalog(), "wagon_part_break");
+        let skills: Vec<_> = event
+            .variants
+            .iter()
+            .map(|variant| (variant.id.as_str(), variant.skill))
+            .collect();
+        assert!(skills.contains(&("repairable", Some(OtDeluxeSkill::Carpenter))));
+        assert!(
+            skills
+                .iter()
+                .filter(|(id, _)| *id != "repairable")
+                .all(|(_, skill)| skill.is_none())
+        );
+    }
+
     #[test]
     fn pick_random_event_emits_decision_trace() {
         let catalog = OtDeluxeRandomEventCatalog {
@@ -627,18 +1088,12 @@ mod tests {
                 OtDeluxeRandomEventDef {
                     id: String::from("resource_change"),
                     weight: 2,
-                    variants: vec![OtDeluxeRandomEventVariant {
-                        id: String::from("wild_fruit"),
-                        weight: 1,
-                    }],
+                    variants: vec![test_variant("wild_fruit", 1)],
                 },
                 OtDeluxeRandomEventDef {
                     id: String::from("travel_hazard"),
                     weight: 1,
-                    variants: vec![OtDeluxeRandomEventVariant {
-                        id: String::from("rough_trail"),
-                        weight: 1,
-                    }],
+                    variants: vec![test_variant("rough_trail", 1)],
                 },
             ],
         };
@@ -651,6 +1106,7 @@ mod tests {
             spares_total: 1,
             weight_mult: 1.0,
             weight_cap: None,
+            skill_factors: Vec::new(),
         };
         let mut rng = SmallRng::seed_from_u64(7);
         let pick = pick_random_event_with_trace(&catalog, &ctx, &mut rng).expect("expected pick");
@@ -679,6 +1135,7 @@ mod tests {
             spares_total: 0,
             weight_mult: 1.0,
             weight_cap: None,
+            skill_factors: Vec::new(),
         };
         let mut rng = StepRng::new(0, 0);
         assert!(pick_random_event_with_trace(&catalog, &ctx, &mut rng).is_none());
@@ -691,10 +1148,7 @@ mod tests {
             events: vec![OtDeluxeRandomEventDef {
                 id: String::from("resource_change"),
                 weight: 1,
-                variants: vec![OtDeluxeRandomEventVariant {
-                    id: String::from("wild_fruit"),
-                    weight: 1,
-                }],
+                variants: vec![test_variant("wild_fruit", 1)],
             }],
         };
         let mut rng = StepRng::new(0, 0);
@@ -707,6 +1161,7 @@ mod tests {
             spares_total: 1,
             weight_mult: 1.0,
             weight_cap: None,
+            skill_factors: Vec::new(),
         };
         assert!(pick_random_event_with_trace(&catalog, &winter_ctx, &mut rng).is_some());
 
@@ -737,6 +1192,7 @@ mod tests {
             spares_total: 0,
             weight_mult: 1.0,
             weight_cap: None,
+            skill_factors: Vec::new(),
         };
         let mut rng = StepRng::new(0, 0);
         assert!(pick_random_event_with_trace(&catalog, &ctx, &mut rng).is_none());
@@ -755,10 +1211,7 @@ mod tests {
                 OtDeluxeRandomEventDef {
                     id: String::from("resource_change"),
                     weight: 1,
-                    variants: vec![OtDeluxeRandomEventVariant {
-                        id: String::from("wild_fruit"),
-                        weight: 1,
-                    }],
+                    variants: vec![test_variant("wild_fruit", 1)],
                 },
             ],
         };
@@ -771,6 +1224,7 @@ mod tests {
             spares_total: 1,
             weight_mult: 1.0,
             weight_cap: None,
+            skill_factors: Vec::new(),
         };
         let mut rng = StepRng::new(0, 0);
         let pick = pick_random_event_with_trace(&catalog, &ctx, &mut rng).expect("pick");
@@ -785,14 +1239,8 @@ mod tests {
                 id: String::from("resource_change"),
                 weight: 1,
                 variants: vec![
-                    OtDeluxeRandomEventVariant {
-                        id: String::from("wild_fruit"),
-                        weight: 1,
-                    },
-                    OtDeluxeRandomEventVariant {
-                        id: String::from("mutual_aid_food"),
-                        weight: 1,
-                    },
+                    test_variant("wild_fruit", 1),
+                    test_variant("mutual_aid_food", 1),
                 ],
             }],
         };
@@ -805,6 +1253,7 @@ mod tests {
             spares_total: 1,
             weight_mult: 1.0,
             weight_cap: None,
+            skill_factors: Vec::new(),
         };
         let mut rng = SmallRng::seed_from_u64(9);
         let pick = pick_random_event_with_trace(&catalog, &ctx, &mut rng).expect("pick");
@@ -815,18 +1264,10 @@ mod tests {
 
     #[test]
     fn pick_variant_with_trace_uses_weighted_roll() {
-        let variants = vec![
-            OtDeluxeRandomEventVariant {
-                id: String::from("a"),
-                weight: 1,
-            },
-            OtDeluxeRandomEventVariant {
-                id: String::from("b"),
-                weight: 2,
-            },
-        ];
+        let variants = vec![test_variant("a", 1), test_variant("b", 2)];
         let mut rng = StepRng::new(1, 0);
-        let (variant_id, trace) = pick_variant_with_trace("resource_change", &variants, &mut rng);
+        let (variant_id, trace) =
+            pick_variant_with_trace("resource_change", &variants, &[], &mut rng);
         assert!(variant_id.is_some());
         assert!(trace.is_some());
     }
@@ -842,6 +1283,7 @@ mod tests {
             spares_total: 2,
             weight_mult: 1.0,
             weight_cap: None,
+            skill_factors: Vec::new(),
         };
         let weather = OtDeluxeRandomEventDef {
             id: String::from("weather_catastrophe"),
@@ -897,6 +1339,7 @@ mod tests {
             spares_total: 1,
             weight_mult: 1.0,
             weight_cap: None,
+            skill_factors: Vec::new(),
         };
         let event = OtDeluxeRandomEventDef {
             id: String::from("resource_shortage"),
@@ -919,6 +1362,7 @@ mod tests {
             spares_total: 0,
             weight_mult: 1.0,
             weight_cap: None,
+            skill_factors: Vec::new(),
         };
         let event = OtDeluxeRandomEventDef {
             id: String::from("mystery_event"),
@@ -3,49 +3,26 @@
 use std::sync::OnceLock;
 
 use crate::mechanics::otdeluxe90s::{
-    OtDeluxe90sPolicy, OtDeluxeHealthLabelRanges, OtDeluxeOccupation,
-    OtDeluxeScorePointsPerPersonByHealth,
+    OtDeluxe90sPolicy, OtDeluxeOccupation, OtDeluxeScorePointsPerPersonByHealth,
 };
 use crate::numbers::round_f64_to_i32;
 use crate::otdeluxe_state::OtDeluxeState;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum OtDeluxeScoreHealthLabel {
-    Good,
-    Fair,
-    Poor,
-    VeryPoor,
-}
+use crate::state::HealthLabel;
 
 fn default_policy() -> &'static OtDeluxe90sPolicy {
     static POLICY: OnceLock<OtDeluxe90sPolicy> = OnceLock::new();
     POLICY.get_or_init(OtDeluxe90sPolicy::default)
 }
 
-const fn score_health_label(
-    health_general: u16,
-    ranges: OtDeluxeHealthLabelRanges,
-) -> OtDeluxeScoreHealthLabel {
-    if health_general <= ranges.good_max {
-        OtDeluxeScoreHealthLabel::Good
-    } else if health_general <= ranges.fair_max {
-        OtDeluxeScoreHealthLabel::Fair
-    } else if health_general <= ranges.poor_max {
-        OtDeluxeScoreHealthLabel::Poor
-    } else {
-        OtDeluxeScoreHealthLabel::VeryPoor
-    }
-}
-
 const fn points_per_person(
-    label: OtDeluxeScoreHealthLabel,
+    label: HealthLabel,
     points: &OtDeluxeScorePointsPerPersonByHealth,
 ) -> u32 {
     match label {
-        OtDeluxeScoreHealthLabel::Good => points.good,
-        OtDeluxeScoreHealthLabel::Fair => points.fair,
-        OtDeluxeScoreHealthLabel::Poor => points.poor,
-        OtDeluxeScoreHealthLabel::VeryPoor => points.very_poor,
+        HealthLabel::Good => points.good,
+        HealthLabel::Fair => points.fair,
+        HealthLabel::Poor => points.poor,
+        HealthLabel::VeryPoor => points.very_poor,
     }
 }
 
@@ -72,7 +49,7 @@ pub fn compute_score(state: &OtDeluxeState) -> i32 {
 /// Compute the `OTDeluxe` parity score using an explicit policy.
 #[must_use]
 pub fn compute_score_with_policy(state: &OtDeluxeState, policy: &OtDeluxe90sPolicy) -> i32 {
-    let health_label = score_health_label(state.health_general, policy.health.label_ranges);
+    let health_label = policy.health.label_ranges.label(state.health_general);
     let points_people = points_per_person(health_label, &policy.score.points_per_person_by_health);
     let alive = u32::from(state.party.alive_count());
     let mut total: u32 = 0;
@@ -137,6 +114,7 @@ mod tests {
                 spares_wheels: 1,
                 spares_axles: 1,
                 spares_tongues: 1,
+                medicine: 0,
             },
             terrain: OtDeluxeTerrain::Plains,
             season: Season::Spring,
@@ -187,6 +165,7 @@ mod tests {
                 spares_wheels: 0,
                 spares_axles: 0,
                 spares_tongues: 0,
+                medicine: 0,
             },
             oxen: crate::otdeluxe_state::OtDeluxeOxenState {
                 healthy: 0,
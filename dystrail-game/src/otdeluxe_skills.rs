@@ -0,0 +1,250 @@
+//! Party member skills and morale for Oregon Trail Deluxe parity.
+//!
+//! A member may carry a skill that nudges one system in the party's favour
+//! for as long as they live and keep their spirits up. Each applied modifier
+//! is reported as a `WeightFactor` so decision traces show why an outcome
+//! moved.
+
+use serde::{Deserialize, Serialize};
+
+use crate::journey::{EventDecisionTrace, RollScale, RollValue, WeightFactor, WeightedCandidate};
+use crate::mechanics::otdeluxe90s::OtDeluxeSkillPolicy;
+use crate::numbers::round_f64_to_i32;
+use crate::otdeluxe_state::OtDeluxePartyState;
+
+/// Upper bound for individual member morale.
+pub const OTDELUXE_MORALE_MAX: u8 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OtDeluxeSkill {
+    Hunter,
+    Medic,
+    Carpenter,
+    Navigator,
+}
+
+impl OtDeluxeSkill {
+    pub const ALL: [Self; 4] = [Self::Hunter, Self::Medic, Self::Carpenter, Self::Navigator];
+
+    #[must_use]
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::Hunter => "hunter",
+            Self::Medic => "medic",
+            Self::Carpenter => "carpenter",
+            Self::Navigator => "navigator",
+        }
+    }
+
+    #[must_use]
+    pub const fn mult(self, policy: &OtDeluxeSkillPolicy) -> f32 {
+        match self {
+            Self::Hunter => policy.hunter_yield_mult,
+            Self::Medic => policy.medic_duration_mult,
+            Self::Carpenter => policy.carpenter_repair_mult,
+            Self::Navigator => policy.navigator_delay_mult,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OtDeluxeAgeGroup {
+    Child,
+    Adult,
+    Elder,
+}
+
+impl OtDeluxeAgeGroup {
+    pub const ALL: [Self; 3] = [Self::Child, Self::Adult, Self::Elder];
+
+    #[must_use]
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::Child => "child",
+            Self::Adult => "adult",
+            Self::Elder => "elder",
+        }
+    }
+
+    /// Multiplier on a member's daily disease fatality chance.
+    #[must_use]
+    pub const fn fatality_mult(self, policy: &OtDeluxeSkillPolicy) -> f32 {
+        match self {
+            Self::Child => policy.child_fatality_mult,
+            Self::Adult => 1.0,
+            Self::Elder => policy.elder_fatality_mult,
+        }
+    }
+}
+
+/// Age group and skill chosen for one party slot when the run starts; unset
+/// attributes leave the member neutral.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OtDeluxePartyRole {
+    #[serde(default)]
+    pub age_group: Option<OtDeluxeAgeGroup>,
+    #[serde(default)]
+    pub skill: Option<OtDeluxeSkill>,
+}
+
+impl OtDeluxePartyRole {
+    /// No age group and no skill.
+    pub const NEUTRAL: Self = Self {
+        age_group: None,
+        skill: None,
+    };
+
+    #[must_use]
+    pub const fn new(age_group: OtDeluxeAgeGroup) -> Self {
+        Self {
+            age_group: Some(age_group),
+            skill: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_skill(mut self, skill: OtDeluxeSkill) -> Self {
+        self.skill = Some(skill);
+        self
+    }
+}
+
+/// Party size on the `OTDeluxe` setup screens: the leader and four companions.
+pub const OTDELUXE_PARTY_SIZE: usize = 5;
+
+/// Default roles: every member is neutral until the setup screens pick an
+/// age group or skill.
+pub const DEFAULT_PARTY_ROLES: [OtDeluxePartyRole; OTDELUXE_PARTY_SIZE] =
+    [OtDeluxePartyRole::NEUTRAL; OTDELUXE_PARTY_SIZE];
+
+/// Modifier the party gains from `skill`, if a living member with enough
+/// morale has it. Neutral or malformed multipliers yield no factor.
+#[must_use]
+pub fn skill_factor(
+    party: &OtDeluxePartyState,
+    skill: OtDeluxeSkill,
+    policy: &OtDeluxeSkillPolicy,
+) -> Option<WeightFactor> {
+    if !party.has_skill(skill, policy.min_morale) {
+        return None;
+    }
+    let mult = skill.mult(policy);
+    if !mult.is_finite() || mult < 0.0 || (mult - 1.0).abs() <= f32::EPSILON {
+        return None;
+    }
+    Some(WeightFactor {
+        label: format!("skill_{}", skill.key()),
+        value: f64::from(mult),
+    })
+}
+
+/// Every skill modifier the party currently lends, for data that names skills.
+#[must_use]
+pub fn party_skill_factors(
+    party: &OtDeluxePartyState,
+    policy: &OtDeluxeSkillPolicy,
+) -> Vec<(OtDeluxeSkill, WeightFactor)> {
+    OtDeluxeSkill::ALL
+        .into_iter()
+        .filter_map(|skill| skill_factor(party, skill, policy).map(|factor| (skill, factor)))
+        .collect()
+}
+
+/// Scale a day count by a skill factor, keeping at least one day when the
+/// input was non-zero.
+#[must_use]
+pub fn scale_days(days: u8, factor: &WeightFactor) -> u8 {
+    if days == 0 {
+        return 0;
+    }
+    let scaled = round_f64_to_i32(f64::from(days) * factor.value).max(1);
+    u8::try_from(scaled).unwrap_or(u8::MAX)
+}
+
+/// Scale an integer amount by a skill factor.
+#[must_use]
+pub fn scale_amount(amount: u32, factor: &WeightFactor) -> u32 {
+    let scaled = round_f64_to_i32(f64::from(amount) * factor.value).max(0);
+    u32::try_from(scaled).unwrap_or(0)
+}
+
+/// Trace recording a skill modifier applied outside a weighted pick.
+///
+/// The roll carries the unmodified value and the single candidate shows the
+/// skill factor and the value that was actually used.
+#[must_use]
+pub fn modifier_trace(
+    pool_id: &str,
+    chosen_id: &str,
+    base: f64,
+    factor: WeightFactor,
+    final_value: f64,
+) -> EventDecisionTrace {
+    EventDecisionTrace {
+        pool_id: pool_id.to_string(),
+        roll: RollValue::F64(base),
+        roll_scale: RollScale::Value,
+        candidates: vec![WeightedCandidate {
+            id: chosen_id.to_string(),
+            base_weight: base,
+            multipliers: vec![factor],
+            final_weight: final_value,
+        }],
+        chosen_id: chosen_id.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::otdeluxe_state::OtDeluxePartyMember;
+
+    fn party_with(skill: OtDeluxeSkill) -> OtDeluxePartyState {
+        OtDeluxePartyState {
+            members: vec![
+                OtDeluxePartyMember::new("Ada").with_skill(skill),
+                OtDeluxePartyMember::new("Ben"),
+            ],
+        }
+    }
+
+    #[test]
+    fn skill_factor_requires_living_member_with_morale() {
+        let policy = OtDeluxeSkillPolicy::default();
+        let mut party = party_with(OtDeluxeSkill::Medic);
+        let factor = skill_factor(&party, OtDeluxeSkill::Medic, &policy).expect("medic");
+        assert_eq!(factor.label, "skill_medic");
+        assert!(skill_factor(&party, OtDeluxeSkill::Hunter, &policy).is_none());
+
+        party.members[0].morale = policy.min_morale - 1;
+        assert!(skill_factor(&party, OtDeluxeSkill::Medic, &policy).is_none());
+
+        party.members[0].morale = OTDELUXE_MORALE_MAX;
+        party.members[0].alive = false;
+        assert!(skill_factor(&party, OtDeluxeSkill::Medic, &policy).is_none());
+    }
+
+    #[test]
+    fn neutral_multiplier_adds_no_factor() {
+        let policy = OtDeluxeSkillPolicy {
+            navigator_delay_mult: 1.0,
+            ..OtDeluxeSkillPolicy::default()
+        };
+        let party = party_with(OtDeluxeSkill::Navigator);
+        assert!(skill_factor(&party, OtDeluxeSkill::Navigator, &policy).is_none());
+    }
+
+    #[test]
+    fn scaling_keeps_at_least_one_day() {
+        let factor = WeightFactor {
+            label: String::from("skill_navigator"),
+            value: 0.5,
+        };
+        assert_eq!(scale_days(0, &factor), 0);
+        assert_eq!(scale_days(1, &factor), 1);
+        assert_eq!(scale_days(6, &factor), 3);
+        assert_eq!(scale_amount(30, &factor), 15);
+    }
+}
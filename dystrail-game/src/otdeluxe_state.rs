@@ -5,12 +5,19 @@ use serde::{Deserialize, Serialize};
 use rand::seq::SliceRandom;
 
 use crate::mechanics::otdeluxe90s::{
-    OtDeluxe90sPolicy, OtDeluxeOccupation, OtDeluxePace, OtDeluxeRations, OtDeluxeTrailVariant,
+    OtDeluxe90sPolicy, OtDeluxeOccupation, OtDeluxePace, OtDeluxeRations, OtDeluxeSkillPolicy,
+    OtDeluxeTrailVariant,
+};
+use crate::otdeluxe_cargo::{self, OtDeluxeCargoLoad};
+use crate::otdeluxe_oxen::OtDeluxeHerd;
+use crate::otdeluxe_skills::{
+    DEFAULT_PARTY_ROLES, OTDELUXE_MORALE_MAX, OTDELUXE_PARTY_SIZE, OtDeluxeAgeGroup,
+    OtDeluxePartyRole, OtDeluxeSkill,
 };
 use crate::otdeluxe_store::OtDeluxeStoreLineItem;
 use crate::state::Season;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum OtDeluxeTerrain {
     #[default]
@@ -99,6 +106,52 @@ pub struct OtDeluxeAfflictionOutcome {
     pub display_key: Option<String>,
 }
 
+/// What killed a party member.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OtDeluxeDeathCause {
+    Affliction {
+        kind: OtDeluxeAfflictionKind,
+        #[serde(default)]
+        disease_id: Option<String>,
+    },
+    Drowning,
+    /// General health ran out after the death-imminent grace period.
+    Exhaustion,
+    /// Lost to a random event such as wandering off.
+    Lost,
+}
+
+impl OtDeluxeDeathCause {
+    #[must_use]
+    pub fn affliction(kind: OtDeluxeAfflictionKind, disease_id: Option<&str>) -> Self {
+        Self::Affliction {
+            kind,
+            disease_id: disease_id.map(str::to_string),
+        }
+    }
+}
+
+/// Longest epitaph kept, in characters.
+pub const OTDELUXE_EPITAPH_MAX_CHARS: usize = 60;
+
+/// Where and when a party member died.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OtDeluxeDeathRecord {
+    pub member_index: usize,
+    pub name: String,
+    pub cause: OtDeluxeDeathCause,
+    pub day: u32,
+    pub date: OtDeluxeCalendar,
+    pub miles: f32,
+    pub node_index: u8,
+    #[serde(default)]
+    pub epitaph: Option<String>,
+    /// Whether the player has been asked for an epitaph.
+    #[serde(default)]
+    pub epitaph_offered: bool,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct OtDeluxeRiverState {
     pub width_ft: f32,
@@ -142,6 +195,19 @@ pub struct OtDeluxePartyMember {
     pub illness_id: Option<String>,
     #[serde(default)]
     pub injury_id: Option<String>,
+    #[serde(default)]
+    pub age_group: Option<OtDeluxeAgeGroup>,
+    #[serde(default)]
+    pub skill: Option<OtDeluxeSkill>,
+    #[serde(default = "default_member_morale")]
+    pub morale: u8,
+    /// Set once medicine has been given for the current affliction.
+    #[serde(default)]
+    pub treated: bool,
+}
+
+const fn default_member_morale() -> u8 {
+    OTDELUXE_MORALE_MAX
 }
 
 impl OtDeluxePartyMember {
@@ -154,9 +220,33 @@ impl OtDeluxePartyMember {
             injured_days_remaining: 0,
             illness_id: None,
             injury_id: None,
+            age_group: None,
+            skill: None,
+            morale: OTDELUXE_MORALE_MAX,
+            treated: false,
         }
     }
 
+    #[must_use]
+    pub const fn with_skill(mut self, skill: OtDeluxeSkill) -> Self {
+        self.skill = Some(skill);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_age_group(mut self, age_group: OtDeluxeAgeGroup) -> Self {
+        self.age_group = Some(age_group);
+        self
+    }
+
+    pub const fn lower_morale(&mut self, amount: u8) {
+        self.morale = self.morale.saturating_sub(amount);
+    }
+
+    pub fn raise_morale(&mut self, amount: u8) {
+        self.morale = self.morale.saturating_add(amount).min(OTDELUXE_MORALE_MAX);
+    }
+
     #[must_use]
     pub const fn is_sick(&self) -> bool {
         self.sick_days_remaining > 0
@@ -177,6 +267,7 @@ impl OtDeluxePartyMember {
         self.injured_days_remaining = 0;
         self.illness_id = None;
         self.injury_id = None;
+        self.treated = false;
     }
 
     pub fn apply_affliction(
@@ -194,6 +285,7 @@ impl OtDeluxePartyMember {
             return true;
         }
         let days = days.max(1);
+        self.treated = false;
         match kind {
             OtDeluxeAfflictionKind::Illness => {
                 self.sick_days_remaining = days;
@@ -235,6 +327,28 @@ impl OtDeluxePartyState {
         u16::try_from(count).unwrap_or(u16::MAX)
     }
 
+    /// Whether a living member with at least `min_morale` has `skill`.
+    #[must_use]
+    pub fn has_skill(&self, skill: OtDeluxeSkill, min_morale: u8) -> bool {
+        self.members.iter().any(|member| {
+            member.alive && member.skill == Some(skill) && member.morale >= min_morale
+        })
+    }
+
+    /// Lower the morale of every living member.
+    pub fn lower_morale(&mut self, amount: u8) {
+        for member in self.members.iter_mut().filter(|member| member.alive) {
+            member.lower_morale(amount);
+        }
+    }
+
+    /// Raise the morale of every living member.
+    pub fn raise_morale(&mut self, amount: u8) {
+        for member in self.members.iter_mut().filter(|member| member.alive) {
+            member.raise_morale(amount);
+        }
+    }
+
     #[must_use]
     pub fn sick_count(&self) -> u16 {
         let count = self
@@ -302,6 +416,9 @@ impl OtDeluxePartyState {
                     member.injury_id = None;
                 }
             }
+            if !member.has_affliction() {
+                member.treated = false;
+            }
         }
     }
 }
@@ -336,6 +453,8 @@ pub struct OtDeluxeInventory {
     pub spares_wheels: u8,
     pub spares_axles: u8,
     pub spares_tongues: u8,
+    #[serde(default)]
+    pub medicine: u16,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -355,10 +474,32 @@ impl Default for OtDeluxeCalendar {
     }
 }
 
+/// Months a wagon party may leave Independence in (March through July).
+pub const OTDELUXE_DEPARTURE_MONTHS: std::ops::RangeInclusive<u8> = 3..=7;
+
 impl OtDeluxeCalendar {
+    /// First day of `month`, clamped to the allowed departure months.
+    #[must_use]
+    pub fn departure(month: u8, year: u16) -> Self {
+        Self {
+            month: month.clamp(
+                *OTDELUXE_DEPARTURE_MONTHS.start(),
+                *OTDELUXE_DEPARTURE_MONTHS.end(),
+            ),
+            day_in_month: 1,
+            year,
+        }
+    }
+
     #[must_use]
     pub fn from_day_index(day: u32) -> Self {
-        let mut calendar = Self::default();
+        Self::default().on_day(day)
+    }
+
+    /// Date on 1-based trail `day` when `self` is day 1.
+    #[must_use]
+    pub fn on_day(&self, day: u32) -> Self {
+        let mut calendar = self.clone();
         if day > 1 {
             calendar.advance_days(day.saturating_sub(1));
         }
@@ -492,6 +633,72 @@ impl Default for OtDeluxeRouteState {
     }
 }
 
+/// Choices made on the `OTDeluxe` setup screens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OtDeluxeStartConfig {
+    pub occupation: OtDeluxeOccupation,
+    pub departure_month: u8,
+    pub departure_year: u16,
+    /// Age group and skill per party slot; slot 0 is the leader.
+    pub roles: [OtDeluxePartyRole; OTDELUXE_PARTY_SIZE],
+}
+
+impl OtDeluxeStartConfig {
+    #[must_use]
+    pub fn new(occupation: OtDeluxeOccupation) -> Self {
+        Self {
+            occupation,
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub const fn with_departure(mut self, month: u8, year: u16) -> Self {
+        self.departure_month = month;
+        self.departure_year = year;
+        self
+    }
+
+    /// Give the leader the skill their occupation brings, unless their role
+    /// already names one.
+    #[must_use]
+    pub fn with_occupation_skill(mut self, policy: &OtDeluxe90sPolicy) -> Self {
+        let leader_skill = policy
+            .occupations
+            .iter()
+            .find(|spec| spec.occupation == self.occupation)
+            .and_then(|spec| spec.leader_skill);
+        self.roles[0].skill = self.roles[0].skill.or(leader_skill);
+        self
+    }
+
+    /// Replace the role for one party slot; out-of-range slots are ignored.
+    #[must_use]
+    pub const fn with_role(mut self, slot: usize, role: OtDeluxePartyRole) -> Self {
+        if slot < OTDELUXE_PARTY_SIZE {
+            self.roles[slot] = role;
+        }
+        self
+    }
+
+    #[must_use]
+    pub fn departure(&self) -> OtDeluxeCalendar {
+        OtDeluxeCalendar::departure(self.departure_month, self.departure_year)
+    }
+}
+
+impl Default for OtDeluxeStartConfig {
+    fn default() -> Self {
+        let calendar = OtDeluxeCalendar::default();
+        Self {
+            occupation: OtDeluxeOccupation::Banker,
+            departure_month: calendar.month,
+            departure_year: calendar.year,
+            roles: DEFAULT_PARTY_ROLES,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub struct OtDeluxeModifiers {
     pub occupation: Option<OtDeluxeOccupation>,
@@ -506,11 +713,17 @@ pub struct OtDeluxeState {
     pub terrain: OtDeluxeTerrain,
     pub season: Season,
     pub calendar: OtDeluxeCalendar,
+    /// Date the party left on; trail day 1.
+    #[serde(default)]
+    pub departure: OtDeluxeCalendar,
     pub party: OtDeluxePartyState,
     pub health_general: u16,
     pub death_imminent_days_remaining: u8,
     pub general_strain: f32,
     pub oxen: OtDeluxeOxenState,
+    /// Individual animals behind `oxen`; see `otdeluxe_oxen`.
+    #[serde(default)]
+    pub herd: OtDeluxeHerd,
     pub inventory: OtDeluxeInventory,
     pub pace: OtDeluxePace,
     pub rations: OtDeluxeRations,
@@ -521,6 +734,9 @@ pub struct OtDeluxeState {
     pub store: OtDeluxeStoreState,
     pub route: OtDeluxeRouteState,
     pub mods: OtDeluxeModifiers,
+    /// Party deaths in the order they happened.
+    #[serde(default)]
+    pub deaths: Vec<OtDeluxeDeathRecord>,
 }
 
 impl Default for OtDeluxeState {
@@ -531,12 +747,14 @@ impl Default for OtDeluxeState {
             miles_traveled: 0.0,
             terrain: OtDeluxeTerrain::default(),
             season: calendar.season(),
+            departure: calendar.clone(),
             calendar,
             party: OtDeluxePartyState::default(),
             health_general: 0,
             death_imminent_days_remaining: 0,
             general_strain: 0.0,
             oxen: OtDeluxeOxenState::default(),
+            herd: OtDeluxeHerd::default(),
             inventory: OtDeluxeInventory::default(),
             pace: OtDeluxePace::Steady,
             rations: OtDeluxeRations::Filling,
@@ -546,11 +764,81 @@ impl Default for OtDeluxeState {
             store: OtDeluxeStoreState::default(),
             route: OtDeluxeRouteState::default(),
             mods: OtDeluxeModifiers::default(),
+            deaths: Vec::new(),
         }
     }
 }
 
 impl OtDeluxeState {
+    /// Move the departure date and re-derive today's date and season from it.
+    pub fn set_departure(&mut self, departure: OtDeluxeCalendar) {
+        self.calendar = departure.on_day(self.day);
+        self.season = self.calendar.season();
+        self.departure = departure;
+    }
+
+    /// Log the death of `member_index` at the wagon's current place and date;
+    /// survivors lose the grief morale set by the active policy.
+    ///
+    /// The caller is responsible for marking the member dead.
+    pub fn record_death(
+        &mut self,
+        member_index: usize,
+        cause: OtDeluxeDeathCause,
+        policy: &OtDeluxeSkillPolicy,
+    ) {
+        let Some(member) = self.party.members.get(member_index) else {
+            return;
+        };
+        if self
+            .deaths
+            .iter()
+            .any(|death| death.member_index == member_index)
+        {
+            return;
+        }
+        self.deaths.push(OtDeluxeDeathRecord {
+            member_index,
+            name: member.name.clone(),
+            cause,
+            day: self.day,
+            date: self.calendar.clone(),
+            miles: self.miles_traveled,
+            node_index: self.route.current_node_index,
+            epitaph: None,
+            epitaph_offered: false,
+        });
+        self.party.lower_morale(policy.death_morale_loss);
+    }
+
+    /// The earliest death the player has not yet been asked to mark.
+    #[must_use]
+    pub fn pending_epitaph(&self) -> Option<&OtDeluxeDeathRecord> {
+        self.deaths.iter().find(|death| !death.epitaph_offered)
+    }
+
+    /// Carve an epitaph for a recorded death; blank text clears it.
+    /// Either way the death no longer counts as [`Self::pending_epitaph`].
+    ///
+    /// Returns `false` when `member_index` has no recorded death.
+    pub fn set_epitaph(&mut self, member_index: usize, epitaph: &str) -> bool {
+        let Some(death) = self
+            .deaths
+            .iter_mut()
+            .find(|death| death.member_index == member_index)
+        else {
+            return false;
+        };
+        let trimmed: String = epitaph
+            .trim()
+            .chars()
+            .take(OTDELUXE_EPITAPH_MAX_CHARS)
+            .collect();
+        death.epitaph = (!trimmed.is_empty()).then_some(trimmed);
+        death.epitaph_offered = true;
+        true
+    }
+
     pub fn advance_days(&mut self, days: u32) {
         if days == 0 {
             return;
@@ -560,24 +848,41 @@ impl OtDeluxeState {
         self.season = self.calendar.season();
     }
 
+    /// Pulling strength of the team. Uses the herd's per-animal health when
+    /// it is in step with the head counts, else the counts alone.
     #[must_use]
     pub fn effective_oxen(&self, policy: &OtDeluxe90sPolicy) -> f32 {
-        self.oxen.effective_oxen(policy.oxen.sick_ox_weight)
+        if !self.herd.oxen.is_empty() && self.herd.matches(self.oxen) {
+            self.herd.strength(&policy.oxen)
+        } else {
+            self.oxen.effective_oxen(policy.oxen.sick_ox_weight)
+        }
+    }
+
+    /// Reconcile the herd with head counts changed elsewhere.
+    pub fn sync_herd(&mut self, policy: &OtDeluxe90sPolicy) {
+        self.herd.reconcile(self.oxen, &policy.oxen.herd);
     }
 
     #[must_use]
     pub fn travel_blocked_by_oxen(&self, policy: &OtDeluxe90sPolicy) -> bool {
         self.effective_oxen(policy) <= policy.oxen.min_to_move
     }
+
+    #[must_use]
+    pub fn cargo_load(&self, policy: &OtDeluxe90sPolicy) -> OtDeluxeCargoLoad {
+        otdeluxe_cargo::cargo_load(policy, &self.inventory, self.oxen)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{
-        OtDeluxeAfflictionKind, OtDeluxeCalendar, OtDeluxeOxenState, OtDeluxePartyMember,
-        OtDeluxePartyState, OtDeluxeState,
+        OtDeluxeAfflictionKind, OtDeluxeCalendar, OtDeluxeDeathCause, OtDeluxeOxenState,
+        OtDeluxePartyMember, OtDeluxePartyState, OtDeluxeState,
     };
-    use crate::mechanics::otdeluxe90s::OtDeluxe90sPolicy;
+    use crate::mechanics::otdeluxe90s::{OtDeluxe90sPolicy, OtDeluxeSkillPolicy};
+    use crate::otdeluxe_skills::OTDELUXE_MORALE_MAX;
     use crate::state::Season;
     use rand::SeedableRng;
     use rand::rngs::SmallRng;
@@ -790,4 +1095,50 @@ mod tests {
         state.oxen.sick = 1;
         assert!(!state.travel_blocked_by_oxen(&policy));
     }
+
+    #[test]
+    fn record_death_captures_place_and_date_once() {
+        let mut state = OtDeluxeState {
+            party: OtDeluxePartyState::from_names(["Ada", "Ben"]),
+            miles_traveled: 412.5,
+            ..OtDeluxeState::default()
+        };
+        state.route.current_node_index = 4;
+        state.advance_days(9);
+        let skills = OtDeluxeSkillPolicy {
+            death_morale_loss: 7,
+            ..OtDeluxeSkillPolicy::default()
+        };
+
+        state.record_death(1, OtDeluxeDeathCause::Drowning, &skills);
+        state.record_death(1, OtDeluxeDeathCause::Lost, &skills);
+        state.record_death(7, OtDeluxeDeathCause::Lost, &skills);
+
+        assert_eq!(state.deaths.len(), 1);
+        let death = &state.deaths[0];
+        assert_eq!(death.name, "Ben");
+        assert_eq!(death.cause, OtDeluxeDeathCause::Drowning);
+        assert_eq!(death.node_index, 4);
+        assert_eq!(death.date.day_in_month, 10);
+        assert!((death.miles - 412.5).abs() < f32::EPSILON);
+        assert_eq!(state.party.members[0].morale, OTDELUXE_MORALE_MAX - 7);
+    }
+
+    #[test]
+    fn pending_epitaph_clears_once_the_player_answers() {
+        let mut state = OtDeluxeState {
+            party: OtDeluxePartyState::from_names(["Ada", "Ben"]),
+            ..OtDeluxeState::default()
+        };
+        let skills = OtDeluxeSkillPolicy::default();
+        state.record_death(0, OtDeluxeDeathCause::Lost, &skills);
+        state.record_death(1, OtDeluxeDeathCause::Drowning, &skills);
+
+        assert_eq!(state.pending_epitaph().map(|d| d.member_index), Some(0));
+        assert!(state.set_epitaph(0, "   "));
+        assert_eq!(state.deaths[0].epitaph, None);
+        assert_eq!(state.pending_epitaph().map(|d| d.member_index), Some(1));
+        assert!(state.set_epitaph(1, "Swept away"));
+        assert!(state.pending_epitaph().is_none());
+    }
 }
@@ -17,6 +17,7 @@ pub enum OtDeluxeStoreItem {
     Wheel,
     Axle,
     Tongue,
+    Medicine,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -54,6 +55,7 @@ pub const fn base_price_cents(policy: &OtDeluxeStorePolicy, item: OtDeluxeStoreI
         OtDeluxeStoreItem::Wheel => policy.base_prices_cents.wheel,
         OtDeluxeStoreItem::Axle => policy.base_prices_cents.axle,
         OtDeluxeStoreItem::Tongue => policy.base_prices_cents.tongue,
+        OtDeluxeStoreItem::Medicine => policy.base_prices_cents.medicine,
     }
 }
 
@@ -67,6 +69,7 @@ pub const fn max_inventory(policy: &OtDeluxeStorePolicy, item: OtDeluxeStoreItem
         OtDeluxeStoreItem::Wheel => policy.max_buy.wheels,
         OtDeluxeStoreItem::Axle => policy.max_buy.axles,
         OtDeluxeStoreItem::Tongue => policy.max_buy.tongues,
+        OtDeluxeStoreItem::Medicine => policy.max_buy.medicine,
     }
 }
 
@@ -102,6 +105,7 @@ fn current_inventory(
         OtDeluxeStoreItem::Wheel => inventory.spares_wheels.into(),
         OtDeluxeStoreItem::Axle => inventory.spares_axles.into(),
         OtDeluxeStoreItem::Tongue => inventory.spares_tongues.into(),
+        OtDeluxeStoreItem::Medicine => inventory.medicine,
     }
 }
 
@@ -237,6 +241,10 @@ pub fn apply_purchase(
                     .saturating_add(added)
                     .min(u8::try_from(cap.min(u16::from(u8::MAX))).unwrap_or(u8::MAX));
             }
+            OtDeluxeStoreItem::Medicine => {
+                let cap = max_inventory(policy, line.item);
+                inventory.medicine = inventory.medicine.saturating_add(line.quantity).min(cap);
+            }
         }
     }
 
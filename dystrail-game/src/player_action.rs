@@ -0,0 +1,252 @@
+//! Stable player action ids shared by the browser test bridge and the tester.
+//!
+//! Ids are `travel`, `trade`, `hunt`, `fish`, `forage`, `camp:rest`,
+//! `camp:forage`, `pace:<pace>`, `diet:<diet>`, `encounter:<index>`,
+//! `crossing:<choice>`, `route:<decision>`, `store:leave`,
+//! `store:buy:<item>=<qty>,...` and `boss`; arguments use the serde names of
+//! the underlying types.
+
+use std::fmt;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::boss::{BossConfig, run_boss_minigame};
+use crate::camp::{CampConfig, camp_forage_with_endgame, camp_rest_with_endgame};
+use crate::crossings::CrossingChoice;
+use crate::endgame::EndgameTravelCfg;
+use crate::otdeluxe_state::{OtDeluxeCrossingMethod, OtDeluxeRouteDecision};
+use crate::otdeluxe_store::OtDeluxeStoreLineItem;
+use crate::state::{DayIntent, DietId, GameState, PaceId};
+
+/// A player decision addressed by its action id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlayerAction {
+    Travel,
+    Intent(DayIntent),
+    CampRest,
+    CampForage,
+    Pace(PaceId),
+    Diet(DietId),
+    Encounter(usize),
+    Crossing(CrossingChoice),
+    OtCrossing(OtDeluxeCrossingMethod),
+    Route(OtDeluxeRouteDecision),
+    StoreLeave,
+    StoreBuy(Vec<OtDeluxeStoreLineItem>),
+    Boss,
+}
+
+/// Configs needed to resolve actions that do not go through the daily tick.
+pub struct ActionConfigs<'a> {
+    pub camp: &'a CampConfig,
+    pub endgame: &'a EndgameTravelCfg,
+    pub boss: &'a BossConfig,
+}
+
+/// What the caller still has to do once an action has been applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionFollowUp {
+    /// The action queued a decision; tick a day through the session to resolve it.
+    TickDay,
+    /// The action was resolved in place.
+    Done,
+}
+
+fn action_arg<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+fn parse_arg<T: DeserializeOwned>(raw: &str) -> Option<T> {
+    serde_json::from_value(serde_json::Value::String(raw.to_string())).ok()
+}
+
+fn parse_store_lines(raw: &str) -> Option<Vec<OtDeluxeStoreLineItem>> {
+    raw.split(',')
+        .map(|line| {
+            let (item, quantity) = line.split_once('=')?;
+            Some(OtDeluxeStoreLineItem {
+                item: parse_arg(item.trim())?,
+                quantity: quantity.trim().parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+impl PlayerAction {
+    /// Parse an action id; `crossing:` arguments name an Oregon Trail Deluxe
+    /// method when `otdeluxe` is set and a Dystrail choice otherwise.
+    #[must_use]
+    pub fn parse(id: &str, otdeluxe: bool) -> Option<Self> {
+        let action = match id.split_once(':') {
+            None => match id {
+                "travel" => Self::Travel,
+                "trade" => Self::Intent(DayIntent::Trade),
+                "hunt" => Self::Intent(DayIntent::Hunt),
+                "fish" => Self::Intent(DayIntent::Fish),
+                "forage" => Self::Intent(DayIntent::Forage),
+                "boss" => Self::Boss,
+                _ => return None,
+            },
+            Some(("camp", "rest")) => Self::CampRest,
+            Some(("camp", "forage")) => Self::CampForage,
+            Some(("pace", raw)) => Self::Pace(parse_arg(raw)?),
+            Some(("diet", raw)) => Self::Diet(parse_arg(raw)?),
+            Some(("encounter", raw)) => Self::Encounter(raw.parse().ok()?),
+            Some(("crossing", raw)) if otdeluxe => Self::OtCrossing(parse_arg(raw)?),
+            Some(("crossing", raw)) => Self::Crossing(parse_arg(raw)?),
+            Some(("route", raw)) => Self::Route(parse_arg(raw)?),
+            Some(("store", "leave")) => Self::StoreLeave,
+            Some(("store", raw)) => Self::StoreBuy(parse_store_lines(raw.strip_prefix("buy:")?)?),
+            Some(_) => return None,
+        };
+        Some(action)
+    }
+
+    /// Apply the action to `gs`.
+    ///
+    /// Returns `None` when the state refuses it, such as a store purchase
+    /// outside a store or one the party cannot afford.
+    pub fn apply(self, gs: &mut GameState, cfgs: &ActionConfigs<'_>) -> Option<ActionFollowUp> {
+        let follow_up = match self {
+            Self::Travel => ActionFollowUp::TickDay,
+            Self::Intent(intent) => {
+                gs.intent.pending = intent;
+                ActionFollowUp::TickDay
+            }
+            Self::CampRest => {
+                let _ = camp_rest_with_endgame(gs, cfgs.camp, cfgs.endgame);
+                ActionFollowUp::Done
+            }
+            Self::CampForage => {
+                let _ = camp_forage_with_endgame(gs, cfgs.camp, cfgs.endgame);
+                ActionFollowUp::Done
+            }
+            Self::Pace(pace) => {
+                gs.pace = pace;
+                ActionFollowUp::Done
+            }
+            Self::Diet(diet) => {
+                gs.diet = diet;
+                ActionFollowUp::Done
+            }
+            Self::Encounter(idx) => {
+                gs.apply_choice(idx);
+                ActionFollowUp::Done
+            }
+            Self::Crossing(choice) => {
+                gs.set_crossing_choice(choice);
+                ActionFollowUp::TickDay
+            }
+            Self::OtCrossing(method) => {
+                gs.set_otdeluxe_crossing_choice(method);
+                ActionFollowUp::TickDay
+            }
+            Self::Route(decision) => {
+                gs.set_route_prompt_choice(decision);
+                ActionFollowUp::TickDay
+            }
+            Self::StoreLeave => {
+                let _ = gs.set_otdeluxe_store_purchase(Vec::new());
+                ActionFollowUp::TickDay
+            }
+            Self::StoreBuy(lines) => {
+                if !gs.set_otdeluxe_store_purchase(lines) {
+                    return None;
+                }
+                ActionFollowUp::TickDay
+            }
+            Self::Boss => {
+                let _ = run_boss_minigame(gs, cfgs.boss);
+                ActionFollowUp::Done
+            }
+        };
+        Some(follow_up)
+    }
+}
+
+impl fmt::Display for PlayerAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Travel => f.write_str("travel"),
+            Self::Intent(DayIntent::Trade) => f.write_str("trade"),
+            Self::Intent(DayIntent::Hunt) => f.write_str("hunt"),
+            Self::Intent(DayIntent::Fish) => f.write_str("fish"),
+            Self::Intent(DayIntent::Forage) => f.write_str("forage"),
+            Self::Intent(intent) => write!(f, "{}", action_arg(intent)),
+            Self::CampRest => f.write_str("camp:rest"),
+            Self::CampForage => f.write_str("camp:forage"),
+            Self::Pace(pace) => write!(f, "pace:{}", action_arg(pace)),
+            Self::Diet(diet) => write!(f, "diet:{}", action_arg(diet)),
+            Self::Encounter(idx) => write!(f, "encounter:{idx}"),
+            Self::Crossing(choice) => write!(f, "crossing:{}", action_arg(choice)),
+            Self::OtCrossing(method) => write!(f, "crossing:{}", action_arg(method)),
+            Self::Route(decision) => write!(f, "route:{}", action_arg(decision)),
+            Self::StoreLeave => f.write_str("store:leave"),
+            Self::StoreBuy(lines) => {
+                f.write_str("store:buy:")?;
+                for (idx, line) in lines.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{}={}", action_arg(&line.item), line.quantity)?;
+                }
+                Ok(())
+            }
+            Self::Boss => f.write_str("boss"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::otdeluxe_store::OtDeluxeStoreItem;
+
+    #[test]
+    fn ids_round_trip_through_parse() {
+        let actions = [
+            PlayerAction::Travel,
+            PlayerAction::Intent(DayIntent::Hunt),
+            PlayerAction::CampRest,
+            PlayerAction::Pace(PaceId::Blitz),
+            PlayerAction::Diet(DietId::Doom),
+            PlayerAction::Encounter(2),
+            PlayerAction::Route(OtDeluxeRouteDecision::SubletteCutoff),
+            PlayerAction::StoreLeave,
+            PlayerAction::StoreBuy(vec![OtDeluxeStoreLineItem {
+                item: OtDeluxeStoreItem::Oxen,
+                quantity: 2,
+            }]),
+            PlayerAction::Boss,
+        ];
+        for action in actions {
+            let id = action.to_string();
+            assert_eq!(PlayerAction::parse(&id, false), Some(action), "{id}");
+        }
+        assert_eq!(
+            PlayerAction::parse("crossing:ferry", true),
+            Some(PlayerAction::OtCrossing(OtDeluxeCrossingMethod::Ferry))
+        );
+        assert_eq!(
+            PlayerAction::parse("crossing:bribe", false),
+            Some(PlayerAction::Crossing(CrossingChoice::Bribe))
+        );
+        assert_eq!(PlayerAction::parse("dance", false), None);
+    }
+
+    #[test]
+    fn store_buy_parses_line_items() {
+        let Some(PlayerAction::StoreBuy(lines)) =
+            PlayerAction::parse("store:buy:oxen=2, food_lb=300", true)
+        else {
+            panic!("store lines parse");
+        };
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1].quantity, 300);
+        assert!(PlayerAction::parse("store:buy:wagons=1", true).is_none());
+    }
+}
@@ -0,0 +1,394 @@
+//! Local run history and personal bests.
+//!
+//! Every finished run is condensed into a [`RunHistoryEntry`] so platforms can
+//! keep a rolling history, surface personal bests, and export the log.
+use crate::day_accounting::cumulative_miles_curve;
+use crate::journey::MechanicalPolicyId;
+use crate::result::ResultSummary;
+use crate::state::{Ending, GameMode, GameState};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+
+/// Schema version for serialized run history payloads.
+pub const RUN_HISTORY_SCHEMA_VERSION: u16 = 1;
+/// Maximum number of entries retained; the oldest runs are dropped first.
+pub const RUN_HISTORY_MAX_ENTRIES: usize = 200;
+
+const CSV_HEADER: &str = "recorded_at_ms,share_code,mode,mechanics,persona_id,ending,ending_cause,score,passed_threshold,days,miles_traveled,miles_curve";
+
+/// Condensed record of a single finished run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunHistoryEntry {
+    pub share_code: String,
+    pub seed: u64,
+    pub mode: GameMode,
+    #[serde(default)]
+    pub mechanics: MechanicalPolicyId,
+    #[serde(default)]
+    pub persona_id: Option<String>,
+    pub ending: Ending,
+    pub headline_key: String,
+    #[serde(default)]
+    pub ending_cause: Option<String>,
+    pub score: i32,
+    pub passed_threshold: bool,
+    pub days: i32,
+    pub miles_traveled: f32,
+    /// Cumulative miles at the end of each recorded day.
+    #[serde(default)]
+    pub miles_curve: Vec<f32>,
+    /// Wall-clock completion time supplied by the platform, if available.
+    #[serde(default)]
+    pub recorded_at_ms: Option<u64>,
+}
+
+impl RunHistoryEntry {
+    /// Build an entry from the final game state and its result summary.
+    #[must_use]
+    pub fn from_run(state: &GameState, summary: &ResultSummary) -> Self {
+        Self {
+            share_code: summary.seed.clone(),
+            seed: state.seed,
+            mode: state.mode,
+            mechanics: state.mechanical_policy,
+            persona_id: state.persona_id.clone().filter(|id| !id.is_empty()),
+            ending: summary.ending,
+            headline_key: summary.headline_key.clone(),
+            ending_cause: summary.ending_cause.clone(),
+            score: summary.score,
+            passed_threshold: summary.passed_threshold,
+            days: summary.days,
+            miles_traveled: summary.miles_traveled,
+            miles_curve: cumulative_miles_curve(&state.day_records),
+            recorded_at_ms: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_recorded_at(mut self, recorded_at_ms: u64) -> Self {
+        self.recorded_at_ms = Some(recorded_at_ms);
+        self
+    }
+
+    /// Whether two entries describe the same run, ignoring the timestamp.
+    #[must_use]
+    pub fn same_run(&self, other: &Self) -> bool {
+        Self {
+            recorded_at_ms: None,
+            ..self.clone()
+        } == Self {
+            recorded_at_ms: None,
+            ..other.clone()
+        }
+    }
+
+    fn outranks(&self, other: &Self) -> bool {
+        (self.score, self.miles_traveled) > (other.score, other.miles_traveled)
+    }
+}
+
+/// Rolling history of finished runs, oldest first.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunHistory {
+    pub version: u16,
+    pub entries: Vec<RunHistoryEntry>,
+}
+
+impl Default for RunHistory {
+    fn default() -> Self {
+        Self {
+            version: RUN_HISTORY_SCHEMA_VERSION,
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl RunHistory {
+    /// Append a run, trimming the oldest entries beyond the retention cap.
+    ///
+    /// Returns `false` when the entry repeats the most recent run, which keeps
+    /// re-rendered result screens from recording the same run twice.
+    pub fn record(&mut self, entry: RunHistoryEntry) -> bool {
+        if self
+            .entries
+            .last()
+            .is_some_and(|last| last.same_run(&entry))
+        {
+            return false;
+        }
+        self.entries.push(entry);
+        if self.entries.len() > RUN_HISTORY_MAX_ENTRIES {
+            let overflow = self.entries.len() - RUN_HISTORY_MAX_ENTRIES;
+            self.entries.drain(..overflow);
+        }
+        true
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Best run recorded for a share code.
+    #[must_use]
+    pub fn personal_best(&self, share_code: &str) -> Option<&RunHistoryEntry> {
+        self.best_where(|entry| entry.share_code.eq_ignore_ascii_case(share_code))
+    }
+
+    /// Best run recorded for a mode under the given mechanics.
+    #[must_use]
+    pub fn personal_best_for_mode(
+        &self,
+        mode: GameMode,
+        mechanics: MechanicalPolicyId,
+    ) -> Option<&RunHistoryEntry> {
+        self.best_where(|entry| entry.mode == mode && entry.mechanics == mechanics)
+    }
+
+    /// Whether `entry` is the best recorded run for its share code.
+    #[must_use]
+    pub fn is_personal_best(&self, entry: &RunHistoryEntry) -> bool {
+        self.personal_best(&entry.share_code)
+            .is_some_and(|best| best.same_run(entry))
+    }
+
+    fn best_where(&self, filter: impl Fn(&RunHistoryEntry) -> bool) -> Option<&RunHistoryEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| filter(entry))
+            .fold(None, |best, entry| match best {
+                Some(current) if !entry.outranks(current) => Some(current),
+                _ => Some(entry),
+            })
+    }
+
+    /// Serialize the history as pretty-printed JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Parse a history previously produced by [`RunHistory::to_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload is malformed.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Render the history as CSV, one run per row.
+    ///
+    /// The miles curve is emitted as a `;`-separated list in a single column.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from(CSV_HEADER);
+        out.push('\n');
+        for entry in &self.entries {
+            let curve = entry
+                .miles_curve
+                .iter()
+                .map(|miles| format!("{miles:.1}"))
+                .collect::<Vec<_>>()
+                .join(";");
+            let _ = writeln!(
+                out,
+                "{},{},{},{},{},{},{},{},{},{},{:.1},{}",
+                entry
+                    .recorded_at_ms
+                    .map_or_else(String::new, |ms| ms.to_string()),
+                csv_field(&entry.share_code),
+                mode_label(entry.mode),
+                mechanics_label(entry.mechanics),
+                csv_field(entry.persona_id.as_deref().unwrap_or_default()),
+                ending_label(entry.ending),
+                csv_field(&ending_cause(entry)),
+                entry.score,
+                entry.passed_threshold,
+                entry.days,
+                entry.miles_traveled,
+                curve,
+            );
+        }
+        out
+    }
+}
+
+const fn ending_label(ending: Ending) -> &'static str {
+    match ending {
+        Ending::Collapse { .. } => "collapse",
+        Ending::SanityLoss => "sanity_loss",
+        Ending::VehicleFailure { .. } => "vehicle_failure",
+        Ending::Exposure { .. } => "exposure",
+        Ending::BossVoteFailed => "boss_vote_failed",
+        Ending::BossVictory => "boss_victory",
+    }
+}
+
+/// The summary's cause token, falling back to the cause carried by the ending.
+fn ending_cause(entry: &RunHistoryEntry) -> String {
+    if let Some(cause) = entry.ending_cause.as_deref() {
+        return cause.to_string();
+    }
+    match entry.ending {
+        Ending::Collapse { cause } => cause.key().to_string(),
+        Ending::VehicleFailure { cause } => cause.key().to_string(),
+        Ending::Exposure { kind } => kind.key().to_string(),
+        Ending::SanityLoss | Ending::BossVoteFailed | Ending::BossVictory => String::new(),
+    }
+}
+
+const fn mode_label(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Classic => "classic",
+        GameMode::Deep => "deep",
+    }
+}
+
+const fn mechanics_label(mechanics: MechanicalPolicyId) -> &'static str {
+    match mechanics {
+        MechanicalPolicyId::DystrailLegacy => "dystrail_legacy",
+        MechanicalPolicyId::OtDeluxe90s => "otdeluxe90s",
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::journey::{DayRecord, TravelDayKind};
+    use crate::result::{ResultConfig, result_summary};
+    use crate::state::CollapseCause;
+
+    fn entry(share_code: &str, mode: GameMode, score: i32) -> RunHistoryEntry {
+        RunHistoryEntry {
+            share_code: share_code.to_string(),
+            seed: 1,
+            mode,
+            mechanics: MechanicalPolicyId::DystrailLegacy,
+            persona_id: Some("journalist".to_string()),
+            ending: Ending::BossVictory,
+            headline_key: "result.headline.victory".to_string(),
+            ending_cause: None,
+            score,
+            passed_threshold: true,
+            days: 10,
+            miles_traveled: 120.0,
+            miles_curve: vec![12.0, 24.0],
+            recorded_at_ms: None,
+        }
+    }
+
+    #[test]
+    fn from_run_captures_summary_and_curve() {
+        let mut state = GameState {
+            seed: 42,
+            persona_id: Some("organizer".to_string()),
+            ..GameState::default()
+        };
+        state.day_records = vec![
+            DayRecord::new(0, TravelDayKind::Travel, 10.0),
+            DayRecord::new(1, TravelDayKind::Partial, 5.0),
+        ];
+        let summary = result_summary(&state, &ResultConfig::default()).unwrap();
+        let entry = RunHistoryEntry::from_run(&state, &summary).with_recorded_at(99);
+        assert_eq!(entry.share_code, summary.seed);
+        assert_eq!(entry.seed, 42);
+        assert_eq!(entry.persona_id.as_deref(), Some("organizer"));
+        assert_eq!(entry.miles_curve, vec![10.0, 15.0]);
+        assert_eq!(entry.recorded_at_ms, Some(99));
+    }
+
+    #[test]
+    fn record_skips_repeated_runs_and_trims_oldest() {
+        let mut history = RunHistory::default();
+        assert!(history.record(entry("CL-ORANGE42", GameMode::Classic, 10)));
+        assert!(!history.record(entry("CL-ORANGE42", GameMode::Classic, 10).with_recorded_at(5)));
+        assert_eq!(history.len(), 1);
+
+        for score in 0..i32::try_from(RUN_HISTORY_MAX_ENTRIES).unwrap() {
+            history.record(entry("CL-ORANGE42", GameMode::Classic, score + 100));
+        }
+        assert_eq!(history.len(), RUN_HISTORY_MAX_ENTRIES);
+        assert_eq!(history.entries[0].score, 100);
+    }
+
+    #[test]
+    fn personal_bests_group_by_share_code_and_mode() {
+        let mut history = RunHistory::default();
+        history.record(entry("CL-ORANGE42", GameMode::Classic, 500));
+        history.record(entry("CL-ORANGE42", GameMode::Classic, 900));
+        history.record(entry("DP-PEACH07", GameMode::Deep, 1_300));
+        history.record(entry("CL-MANGO11", GameMode::Classic, 700));
+
+        let best = history.personal_best("cl-orange42").unwrap();
+        assert_eq!(best.score, 900);
+        assert!(history.is_personal_best(&history.entries[1]));
+        assert!(!history.is_personal_best(&history.entries[0]));
+        assert!(history.personal_best("XX-NONE00").is_none());
+
+        let classic = history
+            .personal_best_for_mode(GameMode::Classic, MechanicalPolicyId::DystrailLegacy)
+            .unwrap();
+        assert_eq!(classic.score, 900);
+        assert!(
+            history
+                .personal_best_for_mode(GameMode::Deep, MechanicalPolicyId::OtDeluxe90s)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_and_csv_export() {
+        let mut history = RunHistory::default();
+        assert!(history.is_empty());
+        let mut quoted = entry("CL-ORANGE42", GameMode::Deep, 42).with_recorded_at(7);
+        quoted.persona_id = Some("a,\"b\"".to_string());
+        history.record(quoted);
+        let mut collapsed = entry("CL-ORANGE43", GameMode::Classic, 3);
+        collapsed.ending = Ending::Collapse {
+            cause: CollapseCause::Hunger,
+        };
+        collapsed.headline_key = "result.headline.collapse_hunger".to_string();
+        history.record(collapsed);
+
+        let json = history.to_json().unwrap();
+        assert_eq!(RunHistory::from_json(&json).unwrap(), history);
+
+        let csv = history.to_csv();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some(CSV_HEADER));
+        assert_eq!(
+            lines.next(),
+            Some(
+                "7,CL-ORANGE42,deep,dystrail_legacy,\"a,\"\"b\"\"\",boss_victory,,42,true,10,120.0,12.0;24.0"
+            )
+        );
+        assert_eq!(
+            lines.next(),
+            Some(
+                ",CL-ORANGE43,classic,dystrail_legacy,journalist,collapse,hunger,3,true,10,120.0,12.0;24.0"
+            )
+        );
+        assert_eq!(
+            mechanics_label(MechanicalPolicyId::OtDeluxe90s),
+            "otdeluxe90s"
+        );
+        assert_eq!(mode_label(GameMode::Classic), "classic");
+    }
+}
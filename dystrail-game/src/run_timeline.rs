@@ -0,0 +1,339 @@
+//! Per-run time-series export built from the day ledger.
+//!
+//! `GameState` keeps running aggregates; this module flattens the recorded
+//! [`DayRecord`](crate::journey::DayRecord) snapshots, encounter choices, and
+//! crossing telemetry into a timeline that dashboards and tools can chart.
+use crate::day_accounting::{DayLedgerMetrics, compute_day_ledger_metrics};
+use crate::journey::TravelDayKind;
+use crate::state::{CrossingTelemetry, EncounterChoiceRecord, GameState};
+use crate::vehicle::Part;
+use crate::weather::Weather;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+
+const DAYS_CSV_HEADER: &str =
+    "day,kind,miles,cumulative_miles,hp,sanity,pants,supplies,weather,breakdown,tags";
+
+/// One charted day of a run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimelineDay {
+    pub day_index: u16,
+    pub kind: TravelDayKind,
+    pub miles: f32,
+    pub cumulative_miles: f32,
+    pub hp: Option<i32>,
+    pub sanity: Option<i32>,
+    pub pants: Option<i32>,
+    pub supplies: Option<i32>,
+    pub weather: Option<Weather>,
+    pub breakdown: Option<Part>,
+    pub tags: Vec<String>,
+}
+
+/// Consecutive days sharing the same weather.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WeatherStreak {
+    pub weather: Weather,
+    pub start_day: u16,
+    pub length: u16,
+}
+
+/// A breakdown and the number of recorded days it blocked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BreakdownSpan {
+    pub part: Part,
+    pub start_day: u16,
+    pub days: u16,
+}
+
+/// Exported time series for a single run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunTimeline {
+    pub days: Vec<TimelineDay>,
+    pub weather_streaks: Vec<WeatherStreak>,
+    pub breakdowns: Vec<BreakdownSpan>,
+    pub encounters: Vec<EncounterChoiceRecord>,
+    pub crossings: Vec<CrossingTelemetry>,
+    pub ledger: DayLedgerMetrics,
+}
+
+impl RunTimeline {
+    /// Build the timeline from the state's day ledger and telemetry.
+    #[must_use]
+    pub fn from_state(state: &GameState) -> Self {
+        let mut cumulative = 0.0_f32;
+        let days: Vec<TimelineDay> = state
+            .day_records
+            .iter()
+            .map(|record| {
+                cumulative += record.miles;
+                let snapshot = record.snapshot.as_ref();
+                TimelineDay {
+                    day_index: record.day_index,
+                    kind: record.kind,
+                    miles: record.miles,
+                    cumulative_miles: cumulative,
+                    hp: snapshot.map(|s| s.stats.hp),
+                    sanity: snapshot.map(|s| s.stats.sanity),
+                    pants: snapshot.map(|s| s.stats.pants),
+                    supplies: snapshot.map(|s| s.stats.supplies),
+                    weather: snapshot.map(|s| s.weather),
+                    breakdown: snapshot.and_then(|s| s.breakdown),
+                    tags: record.tags.iter().map(|tag| tag.0.clone()).collect(),
+                }
+            })
+            .collect();
+        Self {
+            weather_streaks: weather_streaks(&days),
+            breakdowns: breakdown_spans(&days),
+            days,
+            encounters: state.encounter_log.clone(),
+            crossings: state.crossing_events.clone(),
+            ledger: compute_day_ledger_metrics(&state.day_records),
+        }
+    }
+
+    /// Longest run of identical weather, if any weather was recorded.
+    #[must_use]
+    pub fn longest_weather_streak(&self) -> Option<WeatherStreak> {
+        self.weather_streaks
+            .iter()
+            .copied()
+            .fold(None, |best: Option<WeatherStreak>, streak| match best {
+                Some(current) if current.length >= streak.length => Some(current),
+                _ => Some(streak),
+            })
+    }
+
+    /// Serialize the timeline as pretty-printed JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Render the per-day series as CSV; tags are `;`-separated.
+    #[must_use]
+    pub fn days_to_csv(&self) -> String {
+        let mut out = String::from(DAYS_CSV_HEADER);
+        out.push('\n');
+        for day in &self.days {
+            let _ = writeln!(
+                out,
+                "{},{},{:.1},{:.1},{},{},{},{},{},{},{}",
+                day.day_index,
+                kind_label(day.kind),
+                day.miles,
+                day.cumulative_miles,
+                optional(day.hp),
+                optional(day.sanity),
+                optional(day.pants),
+                optional(day.supplies),
+                day.weather
+                    .map_or_else(String::new, |weather| format!("{weather:?}")),
+                day.breakdown.map_or("", Part::key),
+                day.tags.join(";"),
+            );
+        }
+        out
+    }
+}
+
+fn weather_streaks(days: &[TimelineDay]) -> Vec<WeatherStreak> {
+    let mut streaks: Vec<WeatherStreak> = Vec::new();
+    for day in days {
+        let Some(weather) = day.weather else {
+            continue;
+        };
+        match streaks.last_mut() {
+            Some(last)
+                if last.weather == weather
+                    && last.start_day.saturating_add(last.length) == day.day_index =>
+            {
+                last.length = last.length.saturating_add(1);
+            }
+            _ => streaks.push(WeatherStreak {
+                weather,
+                start_day: day.day_index,
+                length: 1,
+            }),
+        }
+    }
+    streaks
+}
+
+fn breakdown_spans(days: &[TimelineDay]) -> Vec<BreakdownSpan> {
+    let mut spans: Vec<BreakdownSpan> = Vec::new();
+    let mut previous: Option<Part> = None;
+    for day in days {
+        match (day.breakdown, previous, spans.last_mut()) {
+            (Some(part), Some(prev), Some(last)) if part == prev => {
+                last.days = last.days.saturating_add(1);
+            }
+            (Some(part), _, _) => spans.push(BreakdownSpan {
+                part,
+                start_day: day.day_index,
+                days: 1,
+            }),
+            (None, _, _) => {}
+        }
+        previous = day.breakdown;
+    }
+    spans
+}
+
+const fn kind_label(kind: TravelDayKind) -> &'static str {
+    match kind {
+        TravelDayKind::Travel => "travel",
+        TravelDayKind::Partial => "partial",
+        TravelDayKind::NonTravel => "non_travel",
+    }
+}
+
+fn optional(value: Option<i32>) -> String {
+    value.map_or_else(String::new, |v| v.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::journey::{DayRecord, DayTag, EndOfDaySnapshot};
+    use crate::state::Stats;
+
+    fn record(day: u16, miles: f32, weather: Weather, breakdown: Option<Part>) -> DayRecord {
+        let mut record = DayRecord::new(day, TravelDayKind::Travel, miles);
+        record.snapshot = Some(EndOfDaySnapshot {
+            stats: Stats {
+                hp: 10 - i32::from(day),
+                ..Stats::default()
+            },
+            weather,
+            breakdown,
+            ..GameState::default().day_snapshot()
+        });
+        record
+    }
+
+    fn sample_state() -> GameState {
+        let mut state = GameState {
+            day_records: vec![
+                record(0, 10.0, Weather::Clear, None),
+                record(1, 12.0, Weather::Clear, Some(Part::Tire)),
+                record(2, 0.0, Weather::Storm, Some(Part::Tire)),
+                record(3, 8.0, Weather::Clear, None),
+                record(4, 0.0, Weather::Clear, Some(Part::Battery)),
+            ],
+            ..GameState::default()
+        };
+        state.day_records[2].kind = TravelDayKind::NonTravel;
+        state.day_records[2].push_tag(DayTag::new("repair"));
+        state.encounter_log.push(EncounterChoiceRecord {
+            day: 2,
+            encounter_id: "town_hall".to_string(),
+            name: "Town Hall".to_string(),
+            choice_index: 1,
+            choice_label: "Speak up".to_string(),
+        });
+        state
+    }
+
+    #[test]
+    fn timeline_tracks_days_streaks_and_breakdowns() {
+        let timeline = RunTimeline::from_state(&sample_state());
+        assert_eq!(timeline.days.len(), 5);
+        assert!((timeline.days[4].cumulative_miles - 30.0).abs() < f32::EPSILON);
+        assert_eq!(timeline.days[1].hp, Some(9));
+        assert_eq!(
+            timeline.weather_streaks,
+            vec![
+                WeatherStreak {
+                    weather: Weather::Clear,
+                    start_day: 0,
+                    length: 2
+                },
+                WeatherStreak {
+                    weather: Weather::Storm,
+                    start_day: 2,
+                    length: 1
+                },
+                WeatherStreak {
+                    weather: Weather::Clear,
+                    start_day: 3,
+                    length: 2
+                },
+            ]
+        );
+        assert_eq!(
+            timeline.longest_weather_streak().map(|s| s.start_day),
+            Some(0)
+        );
+        assert_eq!(
+            timeline.breakdowns,
+            vec![
+                BreakdownSpan {
+                    part: Part::Tire,
+                    start_day: 1,
+                    days: 2
+                },
+                BreakdownSpan {
+                    part: Part::Battery,
+                    start_day: 4,
+                    days: 1
+                },
+            ]
+        );
+        assert_eq!(timeline.encounters.len(), 1);
+        assert_eq!(timeline.ledger.non_travel_days, 1);
+    }
+
+    #[test]
+    fn records_without_snapshots_leave_series_empty() {
+        let mut state = GameState::default();
+        state
+            .day_records
+            .push(DayRecord::new(0, TravelDayKind::Partial, 4.0));
+        let timeline = RunTimeline::from_state(&state);
+        assert_eq!(timeline.days[0].hp, None);
+        assert!(timeline.weather_streaks.is_empty());
+        assert!(timeline.longest_weather_streak().is_none());
+        assert!(timeline.breakdowns.is_empty());
+        assert!(
+            timeline
+                .days_to_csv()
+                .ends_with("0,partial,4.0,4.0,,,,,,,\n")
+        );
+    }
+
+    #[test]
+    fn exports_json_and_csv() {
+        let timeline = RunTimeline::from_state(&sample_state());
+        let json = timeline.to_json().unwrap();
+        assert!(json.contains("\"weather_streaks\""));
+        let csv = timeline.days_to_csv();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some(DAYS_CSV_HEADER));
+        assert_eq!(
+            lines.nth(2),
+            Some("2,non_travel,0.0,22.0,8,10,0,10,Storm,vehicle.parts.tire,repair")
+        );
+    }
+
+    #[test]
+    fn live_ticks_record_snapshots() {
+        let mut session = crate::journey::JourneySession::new(
+            crate::state::GameMode::Classic,
+            crate::journey::StrategyId::Balanced,
+            7,
+            crate::data::EncounterData::empty(),
+            &crate::endgame::EndgameTravelCfg::default_config(),
+        );
+        for _ in 0..3 {
+            session.tick_day();
+        }
+        let timeline = RunTimeline::from_state(session.state());
+        assert!(!timeline.days.is_empty());
+        assert!(timeline.days.iter().all(|day| day.hp.is_some()));
+    }
+}
@@ -1,13 +1,11 @@
 use rand::rngs::SmallRng;
 use rand::{Rng, RngCore, SeedableRng};
 use serde::{Deserialize, Serialize};
-use std::cell::RefMut;
 use std::collections::{HashSet, VecDeque};
 use std::convert::TryFrom;
 use std::fmt;
-use std::rc::Rc;
 use std::str::FromStr;
-use std::sync::OnceLock;
+use std::sync::{Arc, MutexGuard, OnceLock};
 
 use crate::camp::CampState;
 #[cfg(debug_assertions)]
@@ -29,21 +27,22 @@ use crate::constants::{
     EMERGENCY_LIMP_MILE_WINDOW, EMERGENCY_LIMP_REPAIR_COST_CENTS, EMERGENCY_LIMP_WEAR_REDUCTION,
     EMERGENCY_REPAIR_COST, ENCOUNTER_BASE_DEFAULT, ENCOUNTER_COOLDOWN_DAYS,
     ENCOUNTER_CRITICAL_VEHICLE_BONUS, ENCOUNTER_EXTENDED_MEMORY_DAYS, ENCOUNTER_HISTORY_WINDOW,
-    ENCOUNTER_RECENT_MEMORY, ENCOUNTER_REPEAT_WINDOW_DAYS, ENCOUNTER_REROLL_PENALTY,
-    ENCOUNTER_SOFT_CAP_FACTOR, ENCOUNTER_SOFT_CAP_THRESHOLD, EXEC_BREAKDOWN_BONUS_CLAMP_MAX,
-    EXEC_ORDER_BREAKDOWN_BONUS, EXEC_ORDER_DAILY_CHANCE, EXEC_ORDER_MAX_COOLDOWN,
-    EXEC_ORDER_MAX_DURATION, EXEC_ORDER_MIN_COOLDOWN, EXEC_ORDER_MIN_DURATION,
-    EXEC_ORDER_SPEED_BONUS, EXEC_TRAVEL_MULTIPLIER_CLAMP_MIN, ILLNESS_TRAVEL_PENALTY,
-    LOG_ALLIES_GONE, LOG_ALLY_LOST, LOG_BOSS_AWAIT, LOG_BOSS_COMPOSE, LOG_BOSS_COMPOSE_FUNDS,
-    LOG_BOSS_COMPOSE_SUPPLIES, LOG_CROSSING_DECISION_BRIBE, LOG_CROSSING_DECISION_PERMIT,
-    LOG_CROSSING_DETOUR, LOG_CROSSING_FAILURE, LOG_CROSSING_PASSED,
+    ENCOUNTER_LOG_LIMIT, ENCOUNTER_RECENT_MEMORY, ENCOUNTER_REPEAT_WINDOW_DAYS,
+    ENCOUNTER_REROLL_PENALTY, ENCOUNTER_SOFT_CAP_FACTOR, ENCOUNTER_SOFT_CAP_THRESHOLD,
+    EXEC_BREAKDOWN_BONUS_CLAMP_MAX, EXEC_ORDER_BREAKDOWN_BONUS, EXEC_ORDER_DAILY_CHANCE,
+    EXEC_ORDER_MAX_COOLDOWN, EXEC_ORDER_MAX_DURATION, EXEC_ORDER_MIN_COOLDOWN,
+    EXEC_ORDER_MIN_DURATION, EXEC_ORDER_SPEED_BONUS, EXEC_TRAVEL_MULTIPLIER_CLAMP_MIN,
+    ILLNESS_TRAVEL_PENALTY, LOG_ALLIES_GONE, LOG_ALLY_LOST, LOG_BOSS_AWAIT, LOG_BOSS_COMPOSE,
+    LOG_BOSS_COMPOSE_FUNDS, LOG_BOSS_COMPOSE_SUPPLIES, LOG_CROSSING_DECISION_BRIBE,
+    LOG_CROSSING_DECISION_PERMIT, LOG_CROSSING_DETOUR, LOG_CROSSING_FAILURE, LOG_CROSSING_PASSED,
     LOG_DEEP_AGGRESSIVE_FIELD_REPAIR, LOG_DISEASE_HIT, LOG_DISEASE_RECOVER, LOG_DISEASE_TICK,
     LOG_EMERGENCY_REPAIR_FORCED, LOG_ENCOUNTER_ROTATION, LOG_EXEC_END_PREFIX,
     LOG_EXEC_START_PREFIX, LOG_HEALTH_COLLAPSE, LOG_OT_CROSSING_DROWNED, LOG_OT_CROSSING_SAFE,
     LOG_OT_CROSSING_SANK, LOG_OT_CROSSING_STUCK, LOG_OT_CROSSING_TIPPED, LOG_OT_CROSSING_WET,
-    LOG_PANTS_EMERGENCY, LOG_REST_REQUESTED_ENCOUNTER, LOG_SANITY_COLLAPSE,
-    LOG_STARVATION_BACKSTOP, LOG_STARVATION_RELIEF, LOG_STARVATION_TICK, LOG_TRAVEL_BLOCKED,
-    LOG_TRAVEL_BONUS, LOG_TRAVEL_DELAY_CREDIT, LOG_TRAVEL_PARTIAL, LOG_TRAVEL_REST_CREDIT,
+    LOG_OT_OX_EXHAUSTED, LOG_OT_OX_STARVED, LOG_OT_TOMBSTONE, LOG_PANTS_EMERGENCY,
+    LOG_REST_REQUESTED_ENCOUNTER, LOG_SANITY_COLLAPSE, LOG_STARVATION_BACKSTOP,
+    LOG_STARVATION_RELIEF, LOG_STARVATION_TICK, LOG_TRAVEL_BLOCKED, LOG_TRAVEL_BONUS,
+    LOG_TRAVEL_DELAY_CREDIT, LOG_TRAVEL_PARTIAL, LOG_TRAVEL_REST_CREDIT,
     LOG_VEHICLE_EMERGENCY_LIMP, LOG_VEHICLE_FAILURE, LOG_VEHICLE_FIELD_REPAIR_GUARD,
     LOG_VEHICLE_REPAIR_EMERGENCY, LOG_VEHICLE_REPAIR_SPARE, MAX_ENCOUNTERS_PER_DAY,
     PROBABILITY_FLOOR, PROBABILITY_MAX, REST_TRAVEL_CREDIT_MILES, ROTATION_FORCE_INTERVAL,
@@ -67,15 +66,17 @@ use crate::constants::{ASSERT_MIN_AVG_MPD, FLOAT_EPSILON};
 use crate::crossings::{self, CrossingChoice, CrossingConfig, CrossingContext, CrossingKind};
 use crate::data::{Encounter, EncounterData};
 use crate::day_accounting::{self, DayLedgerMetrics};
+use crate::dialogue::{self, TalkOutcome};
 use crate::disease::{DiseaseCatalog, DiseaseDef, DiseaseKind};
 use crate::encounters::{EncounterRequest, pick_encounter};
 use crate::endgame::{self, EndgameState};
 use crate::exec_orders::{ExecOrder, ExecOrderEffects};
+use crate::graveyard::{self, Graveyard, Tombstone};
 use crate::journey::{
-    BreakdownConfig, CountingRng, CrossingPolicy, DayRecord, DayTag, DayTagSet, Event,
-    EventDecisionTrace, EventId, EventKind, EventSeverity, JourneyCfg, MechanicalPolicyId,
-    RngBundle, RollValue, StrainConfig, TravelConfig, TravelDayKind, UiSurfaceHint, WearConfig,
-    WeightedCandidate,
+    BreakdownConfig, CountingRng, CrossingPolicy, DayDeltas, DayPipeline, DayRecord, DayTag,
+    DayTagSet, EndOfDaySnapshot, Event, EventDecisionTrace, EventId, EventKind, EventSeverity,
+    JourneyCfg, MechanicalPolicyId, RngBundle, RollScale, RollValue, StrainConfig, TravelConfig,
+    TravelDayKind, UiSurfaceHint, WearConfig, WeightedCandidate, derive_stream_seed,
 };
 use crate::kernel::systems::affliction::{
     otdeluxe_affliction_duration, otdeluxe_affliction_payload, otdeluxe_affliction_probability,
@@ -105,7 +106,9 @@ use crate::kernel::systems::navigation::{
     roll_otdeluxe_navigation_delay_days, roll_otdeluxe_navigation_event_with_trace,
 };
 use crate::kernel::systems::party::alive_member_indices;
-use crate::kernel::systems::random_events::sanitize_event_weight_mult;
+use crate::kernel::systems::random_events::{
+    RandomEventHost, apply_random_event_effects, sanitize_event_weight_mult,
+};
 use crate::kernel::systems::supplies::otdeluxe_rations_food_per_person_scaled;
 #[cfg(test)]
 use crate::kernel::systems::supplies::{
@@ -119,25 +122,35 @@ use crate::kernel::systems::vehicle::{
     sanitize_breakdown_max_chance, select_breakdown_part_with_trace,
 };
 use crate::mechanics::otdeluxe90s::{
-    OtDeluxe90sPolicy, OtDeluxeHealthPolicy, OtDeluxeNavigationPolicy, OtDeluxeOccupation,
-    OtDeluxePace, OtDeluxePolicyOverride, OtDeluxeRations, OtDeluxeTrailVariant,
+    OtDeluxe90sPolicy, OtDeluxeNavigationPolicy, OtDeluxeOccupation, OtDeluxePace,
+    OtDeluxePolicyOverride, OtDeluxeRations, OtDeluxeSkillPolicy, OtDeluxeTrailVariant,
 };
-use crate::otdeluxe_crossings;
+use crate::numbers::round_f64_to_i32;
+use crate::otdeluxe_cargo::{self, OtDeluxeCargoItem, OtDeluxeCargoLoad};
+use crate::otdeluxe_crossings::{self, OtDeluxeCrossingInfo, OtDeluxeGuidePayment};
+use crate::otdeluxe_oxen::{OtDeluxeHerd, OtDeluxeHerdDay, OtDeluxeHerdTick, OtDeluxeOxDeathCause};
 use crate::otdeluxe_random_events::{
-    self, OtDeluxeRandomEventContext, OtDeluxeRandomEventSelection,
+    self, OtDeluxeAfflictionTarget, OtDeluxeOxenChange, OtDeluxeRandomEventContext,
+    OtDeluxeRandomEventItem, OtDeluxeRandomEventSelection,
 };
+use crate::otdeluxe_skills::{self, OTDELUXE_PARTY_SIZE, OtDeluxeSkill};
 #[cfg(test)]
 use crate::otdeluxe_state::OtDeluxeTravelState;
 use crate::otdeluxe_state::{
-    OtDeluxeAfflictionKind, OtDeluxeAfflictionOutcome, OtDeluxeCalendar, OtDeluxeCrossingMethod,
-    OtDeluxeDallesChoice, OtDeluxeInventory, OtDeluxePartyState, OtDeluxeRiver, OtDeluxeRiverState,
-    OtDeluxeRouteDecision, OtDeluxeRoutePrompt, OtDeluxeState, OtDeluxeTerrain, OtDeluxeWagonState,
+    OtDeluxeAfflictionKind, OtDeluxeAfflictionOutcome, OtDeluxeCrossingMethod,
+    OtDeluxeDallesChoice, OtDeluxeDeathCause, OtDeluxeInventory, OtDeluxeOxenState,
+    OtDeluxePartyState, OtDeluxeRiver, OtDeluxeRiverState, OtDeluxeRouteDecision,
+    OtDeluxeRoutePrompt, OtDeluxeStartConfig, OtDeluxeState, OtDeluxeTerrain, OtDeluxeWagonState,
 };
 use crate::otdeluxe_store::{OtDeluxeStoreError, OtDeluxeStoreLineItem, OtDeluxeStoreReceipt};
 use crate::otdeluxe_trail;
 use crate::pacing::PacingLimits;
 use crate::personas::{Persona, PersonaMods};
-use crate::vehicle::{Breakdown, Part, PartWeights, Vehicle};
+use crate::treatment::{self, OtDeluxeTreatmentOutcome, TreatmentError};
+use crate::vehicle::{
+    Breakdown, MechanicHook, Part, PartWeights, Vehicle, VehicleClass, VehicleConfig,
+    VehicleModifiers,
+};
 use crate::weather::{Weather, WeatherEffects, WeatherState};
 
 const ENCOUNTER_UNIQUE_WINDOW: u32 = 20;
@@ -299,7 +312,7 @@ const fn default_pace() -> PaceId {
     PaceId::Steady
 }
 
-fn default_otdeluxe_policy() -> &'static OtDeluxe90sPolicy {
+pub(crate) fn default_otdeluxe_policy() -> &'static OtDeluxe90sPolicy {
     static POLICY: OnceLock<OtDeluxe90sPolicy> = OnceLock::new();
     POLICY.get_or_init(OtDeluxe90sPolicy::default)
 }
@@ -335,35 +348,35 @@ mod tests {
     };
     use crate::otdeluxe_crossings::{OtDeluxeCrossingOutcome, OtDeluxeCrossingResolution};
     use crate::otdeluxe_random_events::OtDeluxeRandomEventSelection;
+    use crate::otdeluxe_skills::{OtDeluxeAgeGroup, OtDeluxePartyRole};
     use crate::otdeluxe_state::{
-        OtDeluxeCrossingMethod, OtDeluxeCrossingState, OtDeluxeDallesChoice, OtDeluxeInventory,
-        OtDeluxeOxenState, OtDeluxePartyMember, OtDeluxePartyState, OtDeluxeRiver,
-        OtDeluxeRiverBed, OtDeluxeRiverState, OtDeluxeRouteDecision, OtDeluxeRoutePrompt,
-        OtDeluxeRouteState, OtDeluxeState, OtDeluxeWagonState,
+        OtDeluxeCalendar, OtDeluxeCrossingMethod, OtDeluxeCrossingState, OtDeluxeDallesChoice,
+        OtDeluxeInventory, OtDeluxeOxenState, OtDeluxePartyMember, OtDeluxePartyState,
+        OtDeluxeRiver, OtDeluxeRiverBed, OtDeluxeRiverState, OtDeluxeRouteDecision,
+        OtDeluxeRoutePrompt, OtDeluxeRouteState, OtDeluxeState, OtDeluxeWagonState,
     };
     use crate::pacing::{PaceCfg, PacingLimits};
     use crate::personas::{Persona, PersonaMods, PersonaStart};
     use crate::store::Grants;
+    use crate::vehicle::VehicleUpgrade;
     use crate::weather::Weather;
     use rand::Rng;
     use rand::SeedableRng;
     use rand::rngs::SmallRng;
     use rand::rngs::mock::StepRng;
-    use std::cell::RefMut;
     use std::collections::{HashMap, VecDeque};
-    use std::rc::Rc;
-    use std::sync::{Mutex, OnceLock};
+    use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
 
     fn bundle_with_roll_below(
         threshold: f32,
-        domain: fn(&RngBundle) -> RefMut<'_, CountingRng<SmallRng>>,
-    ) -> Rc<RngBundle> {
+        domain: fn(&RngBundle) -> MutexGuard<'_, CountingRng<SmallRng>>,
+    ) -> Arc<RngBundle> {
         for seed in 0..10_000 {
             let probe = RngBundle::from_user_seed(seed);
             {
                 let mut rng = domain(&probe);
                 if rng.r#gen::<f32>() < threshold {
-                    return Rc::new(RngBundle::from_user_seed(seed));
+                    return Arc::new(RngBundle::from_user_seed(seed));
                 }
             }
         }
@@ -577,6 +590,8 @@ mod tests {
             rations: OtDeluxeRations::Filling,
             weather: Weather::Clear,
             occupation: None,
+            treated: false,
+            age_group: None,
         };
         let prob = otdeluxe_fatality_probability(&model, context, &policy);
         assert!(prob.abs() <= f32::EPSILON);
@@ -595,15 +610,17 @@ mod tests {
                 daily_tick_effects: DiseaseEffects::default(),
                 fatality_model: None,
                 tags: Vec::new(),
+                treatments: Vec::new(),
             }],
         };
         let mut state = GameState::default();
         state.ot_deluxe.party.members = vec![OtDeluxePartyMember::new("Ada")];
         let mut rng = SmallRng::seed_from_u64(13);
-        let outcome = state.apply_otdeluxe_random_affliction_with_catalog(
+        let outcome = state.apply_otdeluxe_affliction_with_catalog(
             &catalog,
             &mut rng,
             OtDeluxeAfflictionKind::Illness,
+            OtDeluxeAfflictionTarget::Random,
         );
         assert!(outcome.is_some());
     }
@@ -613,39 +630,50 @@ mod tests {
         let mut state = GameState::default();
         state.ot_deluxe.party.members = vec![OtDeluxePartyMember::new("Ada")];
         let mut rng = SmallRng::seed_from_u64(14);
-        let shortage =
-            state.apply_otdeluxe_random_resource_shortage("bad_water", 0.1, 0.2, &mut rng);
+        let shortage = state.apply_otdeluxe_random_event_selection(
+            &make_event_selection("resource_shortage", "bad_water"),
+            &mut rng,
+        );
         assert!(shortage.is_some());
-        let grass = state.apply_otdeluxe_random_resource_shortage("no_grass", 0.1, 0.2, &mut rng);
+        let grass = state.apply_otdeluxe_random_event_selection(
+            &make_event_selection("resource_shortage", "no_grass"),
+            &mut rng,
+        );
         assert!(grass.is_some());
-        let incident = state.apply_otdeluxe_random_party_incident("snakebite", 0.1, 0.2, &mut rng);
+        let incident = state.apply_otdeluxe_random_event_selection(
+            &make_event_selection("party_incident", "snakebite"),
+            &mut rng,
+        );
         assert!(incident.is_some());
-        let missing = state.apply_otdeluxe_random_party_incident("unknown", 0.1, 0.2, &mut rng);
+        let missing = state.apply_otdeluxe_random_event_selection(
+            &make_event_selection("party_incident", "unknown"),
+            &mut rng,
+        );
         assert!(missing.is_none());
     }
 
-    fn events_bundle_with_roll_below(threshold: f32) -> Rc<RngBundle> {
+    fn events_bundle_with_roll_below(threshold: f32) -> Arc<RngBundle> {
         bundle_with_roll_below(threshold, RngBundle::events)
     }
 
-    fn health_bundle_with_roll_below(threshold: f32) -> Rc<RngBundle> {
+    fn health_bundle_with_roll_below(threshold: f32) -> Arc<RngBundle> {
         bundle_with_roll_below(threshold, RngBundle::health)
     }
 
-    fn breakdown_bundle_with_roll_below(threshold: f32) -> Rc<RngBundle> {
+    fn breakdown_bundle_with_roll_below(threshold: f32) -> Arc<RngBundle> {
         bundle_with_roll_below(threshold, RngBundle::breakdown)
     }
 
     fn bundle_with_roll_at_or_above(
         threshold: f32,
-        domain: fn(&RngBundle) -> RefMut<'_, CountingRng<SmallRng>>,
-    ) -> Rc<RngBundle> {
+        domain: fn(&RngBundle) -> MutexGuard<'_, CountingRng<SmallRng>>,
+    ) -> Arc<RngBundle> {
         for seed in 0..10_000 {
             let probe = RngBundle::from_user_seed(seed);
             {
                 let mut rng = domain(&probe);
                 if rng.r#gen::<f32>() >= threshold {
-                    return Rc::new(RngBundle::from_user_seed(seed));
+                    return Arc::new(RngBundle::from_user_seed(seed));
                 }
             }
         }
@@ -655,22 +683,22 @@ mod tests {
     fn bundle_with_roll_between(
         min_inclusive: f32,
         max_exclusive: f32,
-        domain: fn(&RngBundle) -> RefMut<'_, CountingRng<SmallRng>>,
-    ) -> Rc<RngBundle> {
+        domain: fn(&RngBundle) -> MutexGuard<'_, CountingRng<SmallRng>>,
+    ) -> Arc<RngBundle> {
         for seed in 0..10_000 {
             let probe = RngBundle::from_user_seed(seed);
             {
                 let mut rng = domain(&probe);
                 let roll = rng.r#gen::<f32>();
                 if roll >= min_inclusive && roll < max_exclusive {
-                    return Rc::new(RngBundle::from_user_seed(seed));
+                    return Arc::new(RngBundle::from_user_seed(seed));
                 }
             }
         }
         panic!("unable to find deterministic seed between {min_inclusive} and {max_exclusive}");
     }
 
-    fn breakdown_bundle_with_roll_at_or_above(threshold: f32) -> Rc<RngBundle> {
+    fn breakdown_bundle_with_roll_at_or_above(threshold: f32) -> Arc<RngBundle> {
         bundle_with_roll_at_or_above(threshold, RngBundle::breakdown)
     }
 
@@ -755,6 +783,35 @@ mod tests {
         assert_eq!(state.non_travel_days, 0);
     }
 
+    #[test]
+    fn ledger_records_snapshot_and_day_deltas() {
+        let mut state = GameState::default();
+        state.start_of_day();
+        state.stats.hp -= 3;
+        state.budget_cents -= 250;
+        state.vehicle.wear += 1.5;
+        state.record_travel_day(TravelDayKind::Travel, 9.0, "");
+        state.end_of_day();
+
+        let snapshot = state.day_records[0]
+            .snapshot
+            .as_ref()
+            .expect("end-of-day snapshot");
+        assert_eq!(snapshot.stats, state.stats);
+        assert_eq!(snapshot.budget_cents, state.budget_cents);
+        assert_eq!(snapshot.region, state.region);
+        assert_eq!(snapshot.party_alive, None);
+        assert_eq!(snapshot.deltas.stats.hp, -3);
+        assert_eq!(snapshot.deltas.budget_cents, -250);
+        approx_eq(snapshot.deltas.vehicle_wear, 1.5);
+
+        state.mechanical_policy = MechanicalPolicyId::OtDeluxe90s;
+        assert_eq!(
+            state.day_snapshot().party_alive,
+            Some(state.ot_deluxe.party.alive_count())
+        );
+    }
+
     #[test]
     fn ledger_serializes_and_roundtrips() {
         let mut state = GameState::default();
@@ -969,7 +1026,7 @@ mod tests {
             data: Some(EncounterData::empty()),
             ..GameState::default()
         };
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(1)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(1)));
 
         let cfg = endgame_cfg();
         let _ = tick_day(&mut state, &cfg);
@@ -996,7 +1053,7 @@ mod tests {
             data: Some(EncounterData::empty()),
             ..GameState::default()
         };
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(2)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(2)));
 
         let cfg = endgame_cfg();
         let outcome = tick_day(&mut state, &cfg);
@@ -1014,6 +1071,46 @@ mod tests {
         assert_eq!(state.repairs_spent_cents, EMERGENCY_REPAIR_COST);
     }
 
+    #[test]
+    fn mechanic_clears_breakdown_for_day_cost() {
+        let hook = MechanicHook {
+            enabled: true,
+            chance_clear: 0.5,
+            day_cost: 2,
+        };
+        let mut state = GameState {
+            breakdown: Some(Breakdown {
+                part: Part::Tire,
+                day_started: 0,
+            }),
+            ..GameState::default()
+        };
+        state.attach_rng_bundle(breakdown_bundle_with_roll_below(0.5));
+        let health_before = state.vehicle.health;
+
+        assert!(state.try_mechanic_repair(&hook));
+        assert!(state.breakdown.is_none());
+        assert!(state.day_state.travel.travel_blocked);
+        assert!((state.vehicle.health - health_before).abs() <= f32::EPSILON);
+
+        state.resolve_breakdown();
+        assert!(state.day_state.travel.travel_blocked);
+        state.resolve_breakdown();
+        assert!(!state.day_state.travel.travel_blocked);
+
+        let mut unlucky = GameState {
+            breakdown: Some(Breakdown {
+                part: Part::Tire,
+                day_started: 0,
+            }),
+            ..GameState::default()
+        };
+        unlucky.attach_rng_bundle(breakdown_bundle_with_roll_at_or_above(0.5));
+        assert!(!unlucky.try_mechanic_repair(&hook));
+        assert!(!unlucky.try_mechanic_repair(&MechanicHook::default()));
+        assert!(unlucky.breakdown.is_some());
+    }
+
     #[test]
     fn otdeluxe_breakdown_consumes_ot_spare_and_unblocks() {
         let mut state = GameState {
@@ -1101,7 +1198,7 @@ mod tests {
             data: Some(EncounterData::empty()),
             ..GameState::default()
         };
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(3)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(3)));
 
         let cfg = endgame_cfg();
         let _ = tick_day(&mut state, &cfg);
@@ -1377,7 +1474,7 @@ mod tests {
             current_encounter: None,
             ..GameState::default()
         };
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(42)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(42)));
         let encounter = Encounter {
             id: "test".to_string(),
             name: "Test".to_string(),
@@ -1410,7 +1507,7 @@ mod tests {
     #[test]
     fn allows_two_encounters_before_cooldown() {
         let mut state = GameState::default();
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(99)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(99)));
         let encounter = Encounter {
             id: "test".to_string(),
             name: "Test".to_string(),
@@ -1567,7 +1664,16 @@ mod tests {
 
         state.roll_daily_illness();
         assert!(state.illness_days_remaining > 0);
+        let illness = state.illness_id.as_deref().expect("named illness");
+        assert!(
+            DiseaseCatalog::default_catalog()
+                .find_by_id(illness)
+                .is_some_and(|disease| disease.kind == DiseaseKind::Illness)
+        );
         assert!(state.logs.iter().any(|log| log == LOG_DISEASE_HIT));
+
+        state.clear_illness_penalty();
+        assert_eq!(state.illness_id, None);
     }
 
     #[test]
@@ -1584,7 +1690,7 @@ mod tests {
             },
             ..GameState::default()
         };
-        let bundle = Rc::new(RngBundle::from_user_seed(444));
+        let bundle = Arc::new(RngBundle::from_user_seed(444));
         state.attach_rng_bundle(bundle.clone());
 
         state.roll_daily_illness();
@@ -2373,6 +2479,212 @@ mod tests {
         assert!((miles - 16.0).abs() <= 1e-6);
     }
 
+    #[test]
+    fn otdeluxe_overloaded_wagon_travels_slower_until_lightened() {
+        let mut state = GameState {
+            mechanical_policy: MechanicalPolicyId::OtDeluxe90s,
+            ..GameState::default()
+        };
+        let policy = OtDeluxe90sPolicy::default();
+        state.ot_deluxe.oxen.healthy = 4;
+        let unloaded = state.compute_otdeluxe_miles_for_today(&policy);
+
+        state.ot_deluxe.inventory.food_lbs = 3_000;
+        assert!(state.otdeluxe_cargo_load().is_overloaded());
+        let overloaded = state.compute_otdeluxe_miles_for_today(&policy);
+        assert!(overloaded < unloaded);
+
+        let dropped = state.discard_otdeluxe_cargo(OtDeluxeCargoItem::Food, 1_200);
+        assert_eq!(dropped, 1_200);
+        assert!(!state.otdeluxe_cargo_load().is_overloaded());
+        let lightened = state.compute_otdeluxe_miles_for_today(&policy);
+        assert!((lightened - unloaded).abs() <= 1e-6);
+    }
+
+    #[test]
+    fn otdeluxe_herd_health_drives_travel_and_recovers_on_rest() {
+        let mut state = GameState {
+            mechanical_policy: MechanicalPolicyId::OtDeluxe90s,
+            ..GameState::default()
+        };
+        let policy = OtDeluxe90sPolicy::default();
+        state.ot_deluxe.oxen.healthy = 4;
+        let fresh = state.compute_otdeluxe_miles_for_today(&policy);
+
+        state.ot_deluxe.sync_herd(&policy);
+        assert_eq!(state.otdeluxe_herd().oxen.len(), 4);
+        for ox in &mut state.ot_deluxe.herd.oxen {
+            ox.health = 20;
+        }
+        let worn = state.compute_otdeluxe_miles_for_today(&policy);
+        assert!(worn < fresh);
+
+        state.intent.pending = DayIntent::Rest;
+        let tick = state.tick_otdeluxe_herd();
+        assert!(tick.died.is_empty());
+        assert!(state.ot_deluxe.herd.oxen.iter().all(|ox| ox.health > 20));
+        assert!(state.compute_otdeluxe_miles_for_today(&policy) > worn);
+    }
+
+    #[test]
+    fn otdeluxe_herd_only_pays_work_cost_on_travel_days() {
+        let mut state = GameState {
+            mechanical_policy: MechanicalPolicyId::OtDeluxe90s,
+            ..GameState::default()
+        };
+        state.ot_deluxe.oxen.healthy = 2;
+        state.ot_deluxe.pace = OtDeluxePace::Grueling;
+        state.ot_deluxe.sync_herd(default_otdeluxe_policy());
+        state.ot_deluxe.herd.oxen[0].health = 1;
+
+        for intent in [DayIntent::Hunt, DayIntent::Forage, DayIntent::Trade] {
+            state.intent.pending = intent;
+            let tick = state.tick_otdeluxe_herd();
+            assert!(tick.died.is_empty(), "{intent:?} drained the herd");
+            assert!(state.ot_deluxe.herd.oxen[0].health >= 1);
+        }
+
+        state.ot_deluxe.herd.oxen[0].health = 1;
+        state.intent.pending = DayIntent::Continue;
+        let tick = state.tick_otdeluxe_herd();
+        assert_eq!(tick.died.len(), 1);
+        assert_eq!(tick.died[0].cause, OtDeluxeOxDeathCause::Exhaustion);
+    }
+
+    #[test]
+    fn otdeluxe_oxen_sickness_event_picks_the_weakest_ox() {
+        let mut state = GameState {
+            mechanical_policy: MechanicalPolicyId::OtDeluxe90s,
+            ..GameState::default()
+        };
+        state.ot_deluxe.oxen.healthy = 3;
+        state.ot_deluxe.sync_herd(default_otdeluxe_policy());
+        state.ot_deluxe.herd.oxen[1].health = 30;
+        let weakest = state.ot_deluxe.herd.oxen[1].name.clone();
+
+        assert_eq!(state.apply_otdeluxe_oxen_sickness(), (-1, 1));
+        assert!(state.ot_deluxe.herd.oxen[1].is_sick());
+        assert_eq!(state.ot_deluxe.herd.oxen[1].name, weakest);
+        assert_eq!(state.apply_otdeluxe_oxen_wander(), (-1, 0));
+        assert_eq!(state.ot_deluxe.oxen.total(), 2);
+    }
+
+    #[test]
+    fn otdeluxe_medic_shortens_afflictions_until_lost() {
+        let catalog = DiseaseCatalog {
+            diseases: vec![DiseaseDef {
+                id: "illness_1".into(),
+                kind: DiseaseKind::Illness,
+                display_key: "disease.illness_1".into(),
+                weight: 1,
+                duration_days: Some(6),
+                onset_effects: DiseaseEffects::default(),
+                daily_tick_effects: DiseaseEffects::default(),
+                fatality_model: None,
+                tags: Vec::new(),
+                treatments: Vec::new(),
+            }],
+        };
+        let skills = default_otdeluxe_policy().skills;
+        let mut state = GameState::default();
+        state.ot_deluxe.party.members = vec![
+            OtDeluxePartyMember::new("Ada").with_skill(OtDeluxeSkill::Medic),
+            OtDeluxePartyMember::new("Ben"),
+        ];
+        let mut rng = SmallRng::seed_from_u64(13);
+        let outcome = state
+            .apply_otdeluxe_affliction_with_catalog(
+                &catalog,
+                &mut rng,
+                OtDeluxeAfflictionKind::Illness,
+                OtDeluxeAfflictionTarget::Member(1),
+            )
+            .expect("affliction applies");
+        assert!(!outcome.died);
+        let ben = &state.ot_deluxe.party.members[1];
+        assert_eq!(ben.sick_days_remaining, 4);
+        assert_eq!(ben.morale, 100 - skills.affliction_morale_loss);
+        let trace = state
+            .decision_traces_today
+            .iter()
+            .find(|trace| trace.pool_id == "otdeluxe.skills.affliction_duration")
+            .expect("medic trace");
+        assert_eq!(trace.chosen_id, "illness_1");
+        assert_eq!(trace.candidates[0].multipliers[0].label, "skill_medic");
+
+        state
+            .ot_deluxe
+            .record_death(0, OtDeluxeDeathCause::Lost, &skills);
+        state.ot_deluxe.party.members[0].alive = false;
+        assert_eq!(
+            state.ot_deluxe.party.members[1].morale,
+            100 - skills.affliction_morale_loss - skills.death_morale_loss
+        );
+        state.ot_deluxe.party.members[1].clear_afflictions();
+        state.decision_traces_today.clear();
+        state.apply_otdeluxe_affliction_with_catalog(
+            &catalog,
+            &mut rng,
+            OtDeluxeAfflictionKind::Illness,
+            OtDeluxeAfflictionTarget::Member(1),
+        );
+        assert_eq!(state.ot_deluxe.party.members[1].sick_days_remaining, 6);
+        assert!(
+            state
+                .decision_traces_today
+                .iter()
+                .all(|trace| { trace.pool_id != "otdeluxe.skills.affliction_duration" })
+        );
+    }
+
+    #[test]
+    fn otdeluxe_navigator_cuts_navigation_delay() {
+        let delay = OtDeluxeNavigationDelay {
+            min_days: 4,
+            max_days: 4,
+        };
+        let policy = OtDeluxeNavigationPolicy {
+            chance_per_day: 1.0,
+            lost_weight: 1,
+            wrong_weight: 0,
+            impassable_weight: 0,
+            snowbound_weight: 0,
+            lost_delay: delay,
+            wrong_delay: delay,
+            impassable_delay: delay,
+            snowbound_delay: delay,
+            snowbound_min_depth_in: 0.0,
+        };
+        let run = |members: Vec<OtDeluxePartyMember>| {
+            let mut state = GameState {
+                mechanical_policy: MechanicalPolicyId::OtDeluxe90s,
+                ..GameState::default()
+            };
+            state.ot_deluxe.party.members = members;
+            state.start_of_day();
+            state.distance_today = 10.0;
+            state.distance_today_raw = 10.0;
+            state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(77)));
+            assert!(state.apply_otdeluxe_navigation_event_with_policy(&policy));
+            state
+        };
+
+        let plain = run(vec![OtDeluxePartyMember::new("Ada")]);
+        assert_eq!(plain.ot_deluxe.travel.delay_days_remaining, 3);
+
+        let guided = run(vec![
+            OtDeluxePartyMember::new("Ada").with_skill(OtDeluxeSkill::Navigator),
+        ]);
+        assert_eq!(guided.ot_deluxe.travel.delay_days_remaining, 1);
+        let trace = guided
+            .decision_traces_today
+            .iter()
+            .find(|trace| trace.pool_id == "otdeluxe.skills.navigation_delay")
+            .expect("navigator trace");
+        assert_eq!(trace.chosen_id, "lost_trail");
+        assert!((trace.candidates[0].final_weight - 2.0).abs() < f64::EPSILON);
+    }
+
     #[test]
     fn otdeluxe_terrain_updates_from_miles() {
         let mut state = GameState::default();
@@ -2421,7 +2733,7 @@ mod tests {
         state.distance_today_raw = 10.0;
         state.partial_distance_today = 2.0;
         state.ot_deluxe.oxen.healthy = 4;
-        let bundle = Rc::new(RngBundle::from_user_seed(77));
+        let bundle = Arc::new(RngBundle::from_user_seed(77));
         state.attach_rng_bundle(bundle.clone());
 
         let policy = OtDeluxeNavigationPolicy {
@@ -2822,20 +3134,20 @@ mod tests {
         let policy = OtDeluxe90sPolicy::default();
         state.ot_deluxe.health_general = policy.health.death_threshold;
 
-        state.update_otdeluxe_death_imminent(&policy.health);
+        state.update_otdeluxe_death_imminent(&policy);
         assert_eq!(
             state.ot_deluxe.death_imminent_days_remaining,
             policy.health.death_imminent_grace_days
         );
 
-        state.update_otdeluxe_death_imminent(&policy.health);
+        state.update_otdeluxe_death_imminent(&policy);
         assert_eq!(
             state.ot_deluxe.death_imminent_days_remaining,
             policy.health.death_imminent_grace_days.saturating_sub(1)
         );
 
         state.ot_deluxe.health_general = policy.health.death_threshold.saturating_sub(1);
-        state.update_otdeluxe_death_imminent(&policy.health);
+        state.update_otdeluxe_death_imminent(&policy);
         assert_eq!(state.ot_deluxe.death_imminent_days_remaining, 0);
     }
 
@@ -2853,6 +3165,8 @@ mod tests {
             rations: OtDeluxeRations::Filling,
             weather: Weather::Clear,
             occupation: Some(OtDeluxeOccupation::Doctor),
+            treated: false,
+            age_group: None,
         };
 
         let prob = otdeluxe_fatality_probability(&model, context, &policy);
@@ -2860,6 +3174,31 @@ mod tests {
         assert!((prob - policy.occupation_advantages.doctor_fatality_mult).abs() <= 1e-6);
     }
 
+    #[test]
+    fn otdeluxe_fatality_weighs_children_and_elders_heavier() {
+        let policy = OtDeluxe90sPolicy::default();
+        let model = FatalityModel {
+            base_prob_per_day: 0.1,
+            apply_doctor_mult: false,
+            prob_modifiers: Vec::new(),
+        };
+        let context = |age_group| OtDeluxeFatalityContext {
+            health_general: 0,
+            pace: OtDeluxePace::Steady,
+            rations: OtDeluxeRations::Filling,
+            weather: Weather::Clear,
+            occupation: None,
+            treated: false,
+            age_group,
+        };
+        let prob = |age_group| otdeluxe_fatality_probability(&model, context(age_group), &policy);
+
+        let adult = prob(Some(OtDeluxeAgeGroup::Adult));
+        assert!((adult - prob(None)).abs() <= f32::EPSILON);
+        assert!(prob(Some(OtDeluxeAgeGroup::Child)) > adult);
+        assert!(prob(Some(OtDeluxeAgeGroup::Elder)) > adult);
+    }
+
     #[test]
     fn otdeluxe_route_prompt_clamps_at_south_pass() {
         let mut state = GameState {
@@ -2983,6 +3322,8 @@ mod tests {
             spare_alt: 1,
             spare_pump: 1,
             enabled: true,
+            medicine: 0,
+            vehicle_upgrades: Vec::new(),
         };
         let tags = vec![String::from("safety"), String::from("comfort")];
         state.apply_store_purchase(500, &grants, &tags);
@@ -2999,6 +3340,137 @@ mod tests {
         assert!(state.inventory.tags.contains("comfort"));
     }
 
+    #[test]
+    fn store_purchase_installs_upgrades_and_caps_spares() {
+        let mut state = GameState::default();
+        assert!(state.select_vehicle_class(VehicleClass::EBikeConvoy));
+        let capacity = state.vehicle_modifiers().spare_capacity;
+        let grants = Grants {
+            spare_tire: capacity,
+            spare_battery: 3,
+            vehicle_upgrades: vec![VehicleUpgrade::SolarPanel],
+            ..Grants::default()
+        };
+        state.apply_store_purchase(0, &grants, &[]);
+
+        assert!(state.vehicle.has_upgrade(VehicleUpgrade::SolarPanel));
+        assert_eq!(state.inventory.spares.tire, capacity);
+        assert_eq!(state.inventory.spares.battery, 0);
+
+        let rack = Grants {
+            spare_battery: 3,
+            vehicle_upgrades: vec![VehicleUpgrade::RoofRack],
+            ..Grants::default()
+        };
+        state.apply_store_purchase(0, &rack, &[]);
+        assert!(state.inventory.spares.battery > 0);
+        assert!(state.total_spares() <= state.vehicle_modifiers().spare_capacity);
+    }
+
+    #[test]
+    fn spares_never_exceed_the_vehicle_capacity() {
+        let mut state = GameState::default();
+        assert!(state.select_vehicle_class(VehicleClass::Rv));
+        let capacity = state.vehicle_modifiers().spare_capacity;
+        let dropped = state.stow_spares(Spares {
+            tire: capacity - 1,
+            battery: 3,
+            ..Spares::default()
+        });
+        assert_eq!(dropped, 2);
+        assert_eq!(state.total_spares(), capacity);
+        assert_eq!(state.inventory.spares.battery, 1);
+
+        assert!(state.select_vehicle_class(VehicleClass::EBikeConvoy));
+        let smaller = state.vehicle_modifiers().spare_capacity;
+        assert_eq!(state.total_spares(), smaller);
+        assert_eq!(state.inventory.spares.battery, 0);
+    }
+
+    #[test]
+    fn otdeluxe_treatment_outside_otdeluxe_reports_wrong_mechanics() {
+        let mut state = GameState::default();
+        assert_eq!(
+            state.treat_otdeluxe_member(0),
+            Err(TreatmentError::WrongMechanics)
+        );
+    }
+
+    #[test]
+    fn store_cart_spare_room_counts_the_cart() {
+        let store: crate::store::Store = serde_json::from_str(include_str!(
+            "../../dystrail-web/static/assets/data/store.json"
+        ))
+        .expect("store data");
+        let mut state = GameState::default();
+        let capacity = state.vehicle_modifiers().spare_capacity;
+        state.inventory.spares.tire = 1;
+        let mut cart = crate::store::Cart::new();
+        cart.add_item("spare_tire", 2);
+        assert_eq!(state.store_cart_spare_room(&cart, &store), capacity - 3);
+        cart.add_item("spare_tire", capacity);
+        assert_eq!(state.store_cart_spare_room(&cart, &store), 0);
+        cart.add_item("roof_rack", 1);
+        assert!(state.store_cart_spare_room(&cart, &store) > 0);
+    }
+
+    #[test]
+    fn fit_store_cart_counts_room_from_upgrades_in_the_cart() {
+        let store: crate::store::Store = serde_json::from_str(include_str!(
+            "../../dystrail-web/static/assets/data/store.json"
+        ))
+        .expect("store data");
+        let spare = store.find_item("spare_tire").expect("spare tire");
+        let rack = store.find_item("roof_rack").expect("roof rack");
+
+        let state = GameState::default();
+        let capacity = state.vehicle_modifiers().spare_capacity;
+        let mut cart = crate::store::Cart::new();
+        cart.add_item(&spare.id, capacity + 10);
+        assert_eq!(state.fit_store_cart(&mut cart, &store, 0.0), 10);
+        assert_eq!(cart.get_quantity(&spare.id), capacity);
+        assert_eq!(cart.total_cents, spare.price_cents * i64::from(capacity));
+
+        let mut cart = crate::store::Cart::new();
+        cart.add_item(&rack.id, 1);
+        cart.add_item(&spare.id, capacity + 10);
+        state.fit_store_cart(&mut cart, &store, 0.0);
+        assert!(cart.get_quantity(&spare.id) > capacity);
+
+        let mut bought = state.clone();
+        let grants = crate::store::Grants {
+            spare_tire: cart.get_quantity(&spare.id),
+            vehicle_upgrades: vec![VehicleUpgrade::RoofRack],
+            ..crate::store::Grants::default()
+        };
+        bought.apply_store_purchase(cart.total_cents, &grants, &[]);
+        assert_eq!(bought.total_spares(), cart.get_quantity(&spare.id));
+    }
+
+    #[test]
+    fn vehicle_class_is_locked_after_departure_and_scales_score() {
+        let mut state = GameState::default();
+        state.stats.supplies = 10;
+        let minivan_score = state.journey_score();
+        assert!(state.select_vehicle_class(VehicleClass::EBikeConvoy));
+        assert!(state.journey_score() > minivan_score);
+
+        let json = serde_json::to_string(&state).expect("serialize state");
+        let restored: GameState = serde_json::from_str(&json).expect("deserialize state");
+        assert_eq!(restored.vehicle.class, VehicleClass::EBikeConvoy);
+
+        state.day = 2;
+        assert!(!state.select_vehicle_class(VehicleClass::Rv));
+        assert_eq!(state.vehicle.class, VehicleClass::EBikeConvoy);
+
+        let mut wagon = GameState {
+            mechanical_policy: MechanicalPolicyId::OtDeluxe90s,
+            ..GameState::default()
+        };
+        assert!(!wagon.select_vehicle_class(VehicleClass::SchoolBus));
+        assert_eq!(wagon.vehicle_modifiers(), VehicleModifiers::default());
+    }
+
     #[test]
     fn otdeluxe_store_pending_flow_updates_state() {
         let mut state = GameState::default();
@@ -3074,6 +3546,7 @@ mod tests {
             pending_crossing_choice: Some(CrossingChoice::Detour),
             pending_route_choice: Some(OtDeluxeRouteDecision::StayOnTrail),
             rng_bundle: None,
+            day_pipeline: None,
             ..GameState::default()
         };
         state.party.leader = String::from("Leader");
@@ -3131,7 +3604,7 @@ mod tests {
         let mut state = GameState::default().with_seed(7, GameMode::Classic, encounters);
         state.mechanical_policy = MechanicalPolicyId::OtDeluxe90s;
 
-        state.apply_otdeluxe_start_config(OtDeluxeOccupation::Doctor);
+        state.apply_otdeluxe_start_config(&OtDeluxeStartConfig::new(OtDeluxeOccupation::Doctor));
 
         assert_eq!(
             state.ot_deluxe.mods.occupation,
@@ -3139,6 +3612,82 @@ mod tests {
         );
         assert_eq!(state.ot_deluxe.inventory.cash_cents, 120_000);
         assert_eq!(state.ot_deluxe.party.members.len(), 5);
+        assert_eq!(state.ot_deluxe.calendar, OtDeluxeCalendar::default());
+    }
+
+    #[test]
+    fn apply_otdeluxe_start_config_assigns_party_roles() {
+        let mut state =
+            GameState::default().with_seed(7, GameMode::Classic, EncounterData::empty());
+        state.mechanical_policy = MechanicalPolicyId::OtDeluxe90s;
+        let config = OtDeluxeStartConfig::new(OtDeluxeOccupation::Doctor)
+            .with_occupation_skill(default_otdeluxe_policy())
+            .with_role(
+                3,
+                OtDeluxePartyRole::new(OtDeluxeAgeGroup::Child).with_skill(OtDeluxeSkill::Hunter),
+            );
+
+        state.apply_otdeluxe_start_config(&config);
+
+        let members = &state.ot_deluxe.party.members;
+        assert_eq!(members[0].skill, Some(OtDeluxeSkill::Medic));
+        assert_eq!(members[0].age_group, None);
+        assert_eq!(members[1].skill, None);
+        assert_eq!(members[3].skill, Some(OtDeluxeSkill::Hunter));
+        assert_eq!(members[3].age_group, Some(OtDeluxeAgeGroup::Child));
+    }
+
+    #[test]
+    fn default_otdeluxe_start_leaves_the_party_neutral() {
+        let mut state =
+            GameState::default().with_seed(7, GameMode::Classic, EncounterData::empty());
+        state.mechanical_policy = MechanicalPolicyId::OtDeluxe90s;
+
+        state.apply_otdeluxe_start_config(&OtDeluxeStartConfig::new(OtDeluxeOccupation::Doctor));
+
+        assert!(
+            state
+                .ot_deluxe
+                .party
+                .members
+                .iter()
+                .all(|member| member.age_group.is_none() && member.skill.is_none())
+        );
+    }
+
+    #[test]
+    fn apply_otdeluxe_start_config_moves_departure_calendar() {
+        let mut state =
+            GameState::default().with_seed(7, GameMode::Classic, EncounterData::empty());
+        state.mechanical_policy = MechanicalPolicyId::OtDeluxe90s;
+
+        state.apply_otdeluxe_start_config(
+            &OtDeluxeStartConfig::new(OtDeluxeOccupation::Farmer).with_departure(7, 1849),
+        );
+
+        assert_eq!(
+            state.ot_deluxe.departure,
+            OtDeluxeCalendar::departure(7, 1849)
+        );
+        assert_eq!(state.ot_deluxe.season, Season::Summer);
+        state.ot_deluxe.advance_days(119);
+        assert_eq!(state.ot_deluxe.calendar.month, 10);
+        assert_eq!(state.weather_season(), Season::Fall);
+
+        let policy = default_otdeluxe_policy();
+        let spring = otdeluxe_crossings::derive_river_state(
+            &policy.crossings,
+            OtDeluxeRiver::Kansas,
+            OtDeluxeCalendar::departure(3, 1849).season(),
+            0.0,
+        );
+        let fall = otdeluxe_crossings::derive_river_state(
+            &policy.crossings,
+            OtDeluxeRiver::Kansas,
+            state.ot_deluxe.season,
+            0.0,
+        );
+        assert!((spring.depth_ft - fall.depth_ft).abs() > f32::EPSILON);
     }
 
     #[test]
@@ -3252,7 +3801,7 @@ mod tests {
             ..CrossingPolicy::default()
         };
         state.journey_crossing.permit.disable_terminal = true;
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(21)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(21)));
 
         let outcome = kernel.tick_day(&mut state);
 
@@ -3282,7 +3831,7 @@ mod tests {
             ..GameState::default()
         };
         state.journey_crossing.detour_days = DetourPolicy { min: 2, max: 2 };
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(31)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(31)));
 
         let outcome = kernel.tick_day(&mut state);
 
@@ -3312,7 +3861,7 @@ mod tests {
             },
             ..GameState::default()
         };
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(17)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(17)));
 
         let outcome = kernel.tick_day(&mut state);
 
@@ -3368,7 +3917,7 @@ mod tests {
             chainable: false,
         };
         let data = EncounterData::from_encounters(vec![encounter]);
-        let bundle = Rc::new(RngBundle::from_user_seed(41));
+        let bundle = Arc::new(RngBundle::from_user_seed(41));
 
         let mut state = GameState {
             data: Some(data),
@@ -3417,6 +3966,12 @@ mod tests {
         state.apply_otdeluxe_crossing_costs(policy, OtDeluxeCrossingMethod::Guide);
         assert_eq!(state.ot_deluxe.inventory.clothes_sets, 2);
 
+        let mut cash_guide = policy.clone();
+        cash_guide.crossings.guide_cost_cents = 40;
+        state.apply_otdeluxe_crossing_costs(&cash_guide, OtDeluxeCrossingMethod::Guide);
+        assert_eq!(state.ot_deluxe.inventory.clothes_sets, 2);
+        assert_eq!(state.ot_deluxe.inventory.cash_cents, 60);
+
         let losses = state.apply_otdeluxe_crossing_losses(0.5);
         assert!(losses.food_lbs > 0);
         assert!(losses.bullets > 0);
@@ -3426,10 +3981,16 @@ mod tests {
             OtDeluxePartyMember::new("B"),
             OtDeluxePartyMember::new("C"),
         ];
-        let drowned = state.apply_otdeluxe_drownings(&[1, 2]);
+        let drowned = state.apply_otdeluxe_drownings(&[1, 2], &OtDeluxeSkillPolicy::default());
         assert_eq!(drowned, 2);
         assert!(!state.ot_deluxe.party.members[1].alive);
         assert!(!state.ot_deluxe.party.members[2].alive);
+        assert_eq!(state.ot_deluxe.deaths.len(), 2);
+        assert_eq!(state.ot_deluxe.deaths[0].name, "B");
+        assert_eq!(
+            state.ot_deluxe.deaths[0].cause,
+            OtDeluxeDeathCause::Drowning
+        );
     }
 
     #[test]
@@ -3561,6 +4122,47 @@ mod tests {
         assert!(state.boss.readiness.reached);
     }
 
+    #[test]
+    fn apply_travel_progress_surfaces_passed_tombstones() {
+        let mut graveyard = Graveyard::default();
+        let mut dead = GameState {
+            mechanical_policy: MechanicalPolicyId::OtDeluxe90s,
+            ..GameState::default()
+        };
+        dead.ot_deluxe.party = OtDeluxePartyState::from_names(["Ada", "Ben"]);
+        for (idx, miles) in [(0, 12.0), (1, 40.0)] {
+            dead.ot_deluxe.miles_traveled = miles;
+            dead.ot_deluxe.record_death(
+                idx,
+                OtDeluxeDeathCause::Lost,
+                &OtDeluxeSkillPolicy::default(),
+            );
+        }
+        assert_eq!(graveyard.bury_run(&dead, "CL-TEST"), 2);
+
+        let mut state = GameState {
+            mechanical_policy: MechanicalPolicyId::OtDeluxe90s,
+            miles_traveled_actual: 10.0,
+            miles_traveled: 10.0,
+            ..GameState::default()
+        };
+        state.set_trail_graves(&graveyard);
+        state.apply_travel_progress(5.0, TravelProgressKind::Full);
+
+        let passed: Vec<_> = state
+            .events_today
+            .iter()
+            .filter(|event| event.kind == EventKind::TombstonePassed)
+            .collect();
+        assert_eq!(passed.len(), 1);
+        assert_eq!(passed[0].payload["name"], "Ada");
+        assert_eq!(passed[0].payload["cause_key"], "tombstone.cause.lost");
+        assert_eq!(
+            state.logs.last().map(String::as_str),
+            Some(LOG_OT_TOMBSTONE)
+        );
+    }
+
     #[test]
     fn apply_travel_progress_sets_otdeluxe_ending_on_finish() {
         let policy = default_otdeluxe_policy();
@@ -3706,7 +4308,7 @@ mod tests {
         assert!(state.crossing_rng().is_none());
         assert!(state.boss_rng().is_none());
 
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(5)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(5)));
         assert!(state.events_rng().is_some());
         assert!(state.breakdown_rng().is_some());
         assert!(state.crossing_rng().is_some());
@@ -3861,6 +4463,8 @@ mod tests {
                 rations: OtDeluxeRations::Filling,
                 weather: Weather::Storm,
                 occupation: Some(OtDeluxeOccupation::Doctor),
+                treated: false,
+                age_group: None,
             },
             OtDeluxeFatalityContext {
                 health_general: bounds.fair_max,
@@ -3868,6 +4472,8 @@ mod tests {
                 rations: OtDeluxeRations::Meager,
                 weather: Weather::Clear,
                 occupation: None,
+                treated: false,
+                age_group: None,
             },
             OtDeluxeFatalityContext {
                 health_general: bounds.poor_max,
@@ -3875,6 +4481,8 @@ mod tests {
                 rations: OtDeluxeRations::BareBones,
                 weather: Weather::Storm,
                 occupation: None,
+                treated: false,
+                age_group: None,
             },
             OtDeluxeFatalityContext {
                 health_general: bounds.poor_max.saturating_add(1),
@@ -3882,6 +4490,8 @@ mod tests {
                 rations: OtDeluxeRations::Filling,
                 weather: Weather::Clear,
                 occupation: None,
+                treated: false,
+                age_group: None,
             },
         ];
         for context in contexts {
@@ -3985,76 +4595,115 @@ mod tests {
         let mut weather_state = otdeluxe_state_with_party();
         assert!(
             weather_state
-                .apply_otdeluxe_random_weather_catastrophe("hailstorm", 0.1, 0.2)
+                .apply_otdeluxe_random_event_selection(
+                    &make_event_selection("weather_catastrophe", "hailstorm"),
+                    &mut rng
+                )
                 .is_some()
         );
         assert!(
             weather_state
-                .apply_otdeluxe_random_weather_catastrophe("thunderstorm", 0.1, 0.2)
+                .apply_otdeluxe_random_event_selection(
+                    &make_event_selection("weather_catastrophe", "thunderstorm"),
+                    &mut rng
+                )
                 .is_some()
         );
         assert!(
             weather_state
-                .apply_otdeluxe_random_weather_catastrophe("heavy_fog", 0.1, 0.2)
+                .apply_otdeluxe_random_event_selection(
+                    &make_event_selection("weather_catastrophe", "heavy_fog"),
+                    &mut rng
+                )
                 .is_some()
         );
 
         let mut shortage_state = otdeluxe_state_with_party();
         assert!(
             shortage_state
-                .apply_otdeluxe_random_resource_shortage("bad_water", 0.1, 0.2, &mut rng)
+                .apply_otdeluxe_random_event_selection(
+                    &make_event_selection("resource_shortage", "bad_water"),
+                    &mut rng
+                )
                 .is_some()
         );
         assert!(
             shortage_state
-                .apply_otdeluxe_random_resource_shortage("no_water", 0.1, 0.2, &mut rng)
+                .apply_otdeluxe_random_event_selection(
+                    &make_event_selection("resource_shortage", "no_water"),
+                    &mut rng
+                )
                 .is_some()
         );
         shortage_state.ot_deluxe.oxen.healthy = 1;
         shortage_state.ot_deluxe.oxen.sick = 0;
         assert!(
             shortage_state
-                .apply_otdeluxe_random_resource_shortage("no_grass", 0.1, 0.2, &mut rng)
+                .apply_otdeluxe_random_event_selection(
+                    &make_event_selection("resource_shortage", "no_grass"),
+                    &mut rng
+                )
                 .is_some()
         );
 
         let mut party_state = otdeluxe_state_with_party();
         assert!(
             party_state
-                .apply_otdeluxe_random_party_incident("lost_member", 0.1, 0.2, &mut rng)
+                .apply_otdeluxe_random_event_selection(
+                    &make_event_selection("party_incident", "lost_member"),
+                    &mut rng
+                )
                 .is_some()
         );
         let mut snake_state = otdeluxe_state_with_party();
         assert!(
             snake_state
-                .apply_otdeluxe_random_party_incident("snakebite", 0.1, 0.2, &mut rng)
+                .apply_otdeluxe_random_event_selection(
+                    &make_event_selection("party_incident", "snakebite"),
+                    &mut rng
+                )
                 .is_some()
         );
 
         let mut resource_state = otdeluxe_state_with_party();
         assert!(
             resource_state
-                .apply_otdeluxe_random_resource_change("fire", 0.1, 0.2)
+                .apply_otdeluxe_random_event_selection(
+                    &make_event_selection("resource_change", "fire"),
+                    &mut rng
+                )
                 .is_some()
         );
         assert!(
             resource_state
-                .apply_otdeluxe_random_resource_change("abandoned_wagon_empty", 0.1, 0.2)
+                .apply_otdeluxe_random_event_selection(
+                    &make_event_selection("resource_change", "abandoned_wagon_empty"),
+                    &mut rng
+                )
                 .is_some()
         );
         assert!(
             resource_state
-                .apply_otdeluxe_random_resource_change("thief", 0.1, 0.2)
+                .apply_otdeluxe_random_event_selection(
+                    &make_event_selection("resource_change", "thief"),
+                    &mut rng
+                )
                 .is_some()
         );
         assert!(
             resource_state
-                .apply_otdeluxe_random_resource_change("mutual_aid_food", 0.1, 0.2)
+                .apply_otdeluxe_random_event_selection(
+                    &make_event_selection("resource_change", "mutual_aid_food"),
+                    &mut rng
+                )
                 .is_some()
         );
         assert!(
             resource_state
-                .apply_otdeluxe_random_resource_change("gravesite", 0.1, 0.2)
+                .apply_otdeluxe_random_event_selection(
+                    &make_event_selection("resource_change", "gravesite"),
+                    &mut rng
+                )
                 .is_some()
         );
 
@@ -4064,12 +4713,18 @@ mod tests {
         wagon_state.ot_deluxe.inventory.spares_tongues = 0;
         assert!(
             wagon_state
-                .apply_otdeluxe_random_wagon_part_break("unrepairable", 0.1, 0.2, &mut rng)
+                .apply_otdeluxe_random_event_selection(
+                    &make_event_selection("wagon_part_break", "unrepairable"),
+                    &mut rng
+                )
                 .is_some()
         );
         assert!(
             wagon_state
-                .apply_otdeluxe_random_wagon_part_break("replaceable", 0.1, 0.2, &mut rng)
+                .apply_otdeluxe_random_event_selection(
+                    &make_event_selection("wagon_part_break", "replaceable"),
+                    &mut rng
+                )
                 .is_some()
         );
     }
@@ -4100,6 +4755,7 @@ mod tests {
                     apply_doctor_mult: false,
                 }),
                 tags: Vec::new(),
+                treatments: Vec::new(),
             },
             DiseaseDef {
                 id: String::from("injury"),
@@ -4115,6 +4771,7 @@ mod tests {
                 },
                 fatality_model: Some(fatal),
                 tags: Vec::new(),
+                treatments: Vec::new(),
             },
         ];
         let catalog = DiseaseCatalog { diseases };
@@ -4154,22 +4811,24 @@ mod tests {
     #[test]
     fn otdeluxe_death_imminent_progression_and_reset() {
         let mut state = otdeluxe_state_with_party();
-        let mut health_policy = OtDeluxe90sPolicy::default().health;
-        health_policy.death_imminent_grace_days = 1;
-        health_policy.death_imminent_resets_on_recovery_below_threshold = true;
-        state.ot_deluxe.health_general = health_policy.death_threshold;
+        let mut policy = OtDeluxe90sPolicy::default();
+        policy.health.death_imminent_grace_days = 1;
+        policy
+            .health
+            .death_imminent_resets_on_recovery_below_threshold = true;
+        state.ot_deluxe.health_general = policy.health.death_threshold;
         state.ot_deluxe.death_imminent_days_remaining = 0;
-        state.update_otdeluxe_death_imminent(&health_policy);
+        state.update_otdeluxe_death_imminent(&policy);
         assert_eq!(state.ot_deluxe.death_imminent_days_remaining, 1);
 
-        state.update_otdeluxe_death_imminent(&health_policy);
+        state.update_otdeluxe_death_imminent(&policy);
         assert_eq!(state.ot_deluxe.death_imminent_days_remaining, 0);
         assert!(state.ending.is_some());
 
-        state.ot_deluxe.health_general = health_policy.death_threshold.saturating_sub(1);
+        state.ot_deluxe.health_general = policy.health.death_threshold.saturating_sub(1);
         state.ot_deluxe.death_imminent_days_remaining = 2;
         state.ending = None;
-        state.update_otdeluxe_death_imminent(&health_policy);
+        state.update_otdeluxe_death_imminent(&policy);
         assert_eq!(state.ot_deluxe.death_imminent_days_remaining, 0);
     }
 
@@ -4202,16 +4861,6 @@ mod tests {
     #[test]
     fn otdeluxe_oxen_loss_helpers_cover_all_paths() {
         let mut state = otdeluxe_state_with_party();
-        state.ot_deluxe.oxen.healthy = 1;
-        state.ot_deluxe.oxen.sick = 0;
-        assert_eq!(state.apply_otdeluxe_no_grass_loss(), (-1, 1));
-        state.ot_deluxe.oxen.healthy = 0;
-        state.ot_deluxe.oxen.sick = 1;
-        assert_eq!(state.apply_otdeluxe_no_grass_loss(), (0, -1));
-        state.ot_deluxe.oxen.healthy = 0;
-        state.ot_deluxe.oxen.sick = 0;
-        assert_eq!(state.apply_otdeluxe_no_grass_loss(), (0, 0));
-
         state.ot_deluxe.oxen.healthy = 1;
         state.ot_deluxe.oxen.sick = 0;
         assert_eq!(state.apply_otdeluxe_oxen_wander(), (-1, 0));
@@ -4481,7 +5130,7 @@ mod tests {
         let grace = policy.health.death_imminent_grace_days;
         state.ot_deluxe.health_general = policy.health.death_threshold;
         state.ot_deluxe.death_imminent_days_remaining = grace.saturating_add(2);
-        state.update_otdeluxe_death_imminent(&policy.health);
+        state.update_otdeluxe_death_imminent(policy);
         assert_eq!(
             state.ot_deluxe.death_imminent_days_remaining,
             grace.saturating_sub(1)
@@ -4571,6 +5220,22 @@ mod tests {
         assert!(state.recent_encounters.len() <= ENCOUNTER_RECENT_MEMORY);
     }
 
+    #[test]
+    fn encounter_log_keeps_most_recent_choices() {
+        let mut state = GameState::default();
+        for day in 0..=u32::try_from(ENCOUNTER_LOG_LIMIT).unwrap() {
+            state.log_encounter_choice(EncounterChoiceRecord {
+                day,
+                encounter_id: format!("enc{day}"),
+                name: String::new(),
+                choice_index: 0,
+                choice_label: String::new(),
+            });
+        }
+        assert_eq!(state.encounter_log.len(), ENCOUNTER_LOG_LIMIT);
+        assert_eq!(state.encounter_log[0].day, 1);
+    }
+
     #[test]
     fn travel_progress_and_wear_cover_edges() {
         let mut state = GameState {
@@ -4655,7 +5320,7 @@ mod tests {
         };
         success_state.start_of_day();
         let seed = seed_for_roll_below(0.1);
-        success_state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(seed)));
+        success_state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(seed)));
         assert!(success_state.try_deep_aggressive_field_repair());
     }
 
@@ -5039,6 +5704,8 @@ mod tests {
             rations: OtDeluxeRations::Filling,
             weather: Weather::Clear,
             occupation: None,
+            treated: false,
+            age_group: None,
         };
         assert!(
             (otdeluxe_fatality_probability(&model, context, &policy) - 0.0).abs() <= f32::EPSILON
@@ -5223,6 +5890,10 @@ mod tests {
                         injured_days_remaining: 0,
                         illness_id: None,
                         injury_id: None,
+                        age_group: None,
+                        skill: None,
+                        morale: 100,
+                        treated: false,
                     }],
                 },
                 crossing: OtDeluxeCrossingState {
@@ -5252,6 +5923,31 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn otdeluxe_crossing_info_is_stable_and_sharper_for_farmers() {
+        let mut state = GameState {
+            mechanical_policy: MechanicalPolicyId::OtDeluxe90s,
+            seed: 77,
+            ..GameState::default()
+        };
+        assert!(state.otdeluxe_crossing_info().is_none());
+        state.ot_deluxe.crossing.river_kind = Some(OtDeluxeRiver::Snake);
+        state.ot_deluxe.crossing.river = Some(OtDeluxeRiverState {
+            width_ft: 1000.0,
+            depth_ft: 6.0,
+            swiftness: 0.7,
+            bed: OtDeluxeRiverBed::Rocky,
+        });
+        let info = state.otdeluxe_crossing_info().expect("pending river");
+        assert_eq!(state.otdeluxe_crossing_info(), Some(info.clone()));
+        let policy = default_otdeluxe_policy();
+        assert!((info.fuzz - policy.crossings.info_fuzz_max).abs() < f32::EPSILON);
+
+        state.ot_deluxe.mods.occupation = Some(OtDeluxeOccupation::Farmer);
+        let farmer = state.otdeluxe_crossing_info().expect("pending river");
+        assert!(farmer.fuzz < info.fuzz);
+    }
+
     #[test]
     fn otdeluxe_crossing_context_returns_none_when_invalid() {
         let mut legacy = GameState::default();
@@ -5476,21 +6172,87 @@ mod tests {
     }
 
     #[test]
-    fn random_weather_catastrophe_rejects_unknown() {
+    fn random_event_selection_resolves_every_catalog_variant() {
+        let mut rng = SmallRng::seed_from_u64(12);
+        for event in &otdeluxe_random_events::catalog().events {
+            for variant in &event.variants {
+                let mut state = otdeluxe_state_with_party();
+                let selection = make_event_selection(&event.id, &variant.id);
+                let (_log_key, severity, payload) = state
+                    .apply_otdeluxe_random_event_selection(&selection, &mut rng)
+                    .unwrap_or_else(|| panic!("{}.{} did not resolve", event.id, variant.id));
+                assert_eq!(severity, variant.severity);
+                assert!(payload.get("deltas").is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn random_event_affliction_can_target_specific_member() {
         let mut state = otdeluxe_state_with_party();
+        state.ot_deluxe.party = OtDeluxePartyState::from_names(["Ada", "Ben"]);
+        let mut rng = SmallRng::seed_from_u64(4);
+        let outcome = state
+            .apply_otdeluxe_affliction(
+                &mut rng,
+                OtDeluxeAfflictionKind::Injury,
+                OtDeluxeAfflictionTarget::Member(1),
+            )
+            .expect("member afflicted");
+        assert_eq!(outcome.member_index, 1);
+        assert!(state.ot_deluxe.party.members[1].injured_days_remaining > 0);
         assert!(
             state
-                .apply_otdeluxe_random_weather_catastrophe("unknown", 0.2, 0.3)
+                .apply_otdeluxe_affliction(
+                    &mut rng,
+                    OtDeluxeAfflictionKind::Injury,
+                    OtDeluxeAfflictionTarget::Member(99),
+                )
                 .is_none()
         );
     }
 
+    #[test]
+    fn random_event_delay_days_hold_the_wagon() {
+        let mut state = otdeluxe_state_with_party();
+        state.ot_deluxe.travel.delay_days_remaining = 1;
+        assert_eq!(state.add_delay_days(2), 2);
+        assert_eq!(state.ot_deluxe.travel.delay_days_remaining, 3);
+        assert_eq!(
+            state.ot_deluxe.travel.wagon_state,
+            OtDeluxeWagonState::Delayed
+        );
+    }
+
+    #[test]
+    fn random_event_selection_rejects_unknown_variants_in_every_family() {
+        let mut state = otdeluxe_state_with_party();
+        let mut rng = SmallRng::seed_from_u64(2);
+        for event in [
+            "weather_catastrophe",
+            "oxen_incident",
+            "resource_change",
+            "travel_hazard",
+        ] {
+            assert!(
+                state
+                    .apply_otdeluxe_random_event_selection(
+                        &make_event_selection(event, "unknown"),
+                        &mut rng
+                    )
+                    .is_none()
+            );
+        }
+    }
+
     #[test]
     fn random_resource_shortage_bad_water_payload() {
         let mut state = otdeluxe_state_with_party();
         let mut rng = SmallRng::seed_from_u64(7);
-        let outcome =
-            state.apply_otdeluxe_random_resource_shortage("bad_water", 0.2, 0.3, &mut rng);
+        let outcome = state.apply_otdeluxe_random_event_selection(
+            &make_event_selection("resource_shortage", "bad_water"),
+            &mut rng,
+        );
         assert!(outcome.is_some());
     }
 
@@ -5500,37 +6262,26 @@ mod tests {
         let mut rng = SmallRng::seed_from_u64(5);
         assert!(
             state
-                .apply_otdeluxe_random_party_incident("lost_member", 0.2, 0.3, &mut rng)
+                .apply_otdeluxe_random_event_selection(
+                    &make_event_selection("party_incident", "lost_member"),
+                    &mut rng
+                )
                 .is_some()
         );
         assert!(
             state
-                .apply_otdeluxe_random_party_incident("snakebite", 0.2, 0.3, &mut rng)
+                .apply_otdeluxe_random_event_selection(
+                    &make_event_selection("party_incident", "snakebite"),
+                    &mut rng
+                )
                 .is_some()
         );
         assert!(
             state
-                .apply_otdeluxe_random_party_incident("unknown", 0.2, 0.3, &mut rng)
-                .is_none()
-        );
-    }
-
-    #[test]
-    fn random_oxen_incident_rejects_unknown() {
-        let mut state = otdeluxe_state_with_party();
-        assert!(
-            state
-                .apply_otdeluxe_random_oxen_incident("unknown", 0.2, 0.3)
-                .is_none()
-        );
-    }
-
-    #[test]
-    fn random_resource_change_rejects_unknown() {
-        let mut state = otdeluxe_state_with_party();
-        assert!(
-            state
-                .apply_otdeluxe_random_resource_change("unknown", 0.2, 0.3)
+                .apply_otdeluxe_random_event_selection(
+                    &make_event_selection("party_incident", "unknown"),
+                    &mut rng
+                )
                 .is_none()
         );
     }
@@ -5544,22 +6295,18 @@ mod tests {
         let mut rng = SmallRng::seed_from_u64(3);
         assert!(
             state
-                .apply_otdeluxe_random_wagon_part_break("repairable", 0.2, 0.3, &mut rng)
+                .apply_otdeluxe_random_event_selection(
+                    &make_event_selection("wagon_part_break", "repairable"),
+                    &mut rng
+                )
                 .is_some()
         );
         assert!(
             state
-                .apply_otdeluxe_random_wagon_part_break("unknown", 0.2, 0.3, &mut rng)
-                .is_none()
-        );
-    }
-
-    #[test]
-    fn random_travel_hazard_rejects_unknown() {
-        let mut state = otdeluxe_state_with_party();
-        assert!(
-            state
-                .apply_otdeluxe_random_travel_hazard("unknown", 0.2, 0.3)
+                .apply_otdeluxe_random_event_selection(
+                    &make_event_selection("wagon_part_break", "unknown"),
+                    &mut rng
+                )
                 .is_none()
         );
     }
@@ -5571,10 +6318,11 @@ mod tests {
             diseases: Vec::new(),
         };
         let mut rng = SmallRng::seed_from_u64(9);
-        let outcome = state.apply_otdeluxe_random_affliction_with_catalog(
+        let outcome = state.apply_otdeluxe_affliction_with_catalog(
             &empty_catalog,
             &mut rng,
             OtDeluxeAfflictionKind::Illness,
+            OtDeluxeAfflictionTarget::Random,
         );
         assert!(outcome.is_some());
     }
@@ -5583,7 +6331,7 @@ mod tests {
     fn lose_random_party_members_breaks_when_empty() {
         let mut state = otdeluxe_state_with_party();
         let mut rng = SmallRng::seed_from_u64(2);
-        let lost = state.lose_random_party_members(&mut rng, 2);
+        let lost = state.lose_random_party_members(&mut rng, 2, &OtDeluxeSkillPolicy::default());
         assert_eq!(lost.len(), 1);
     }
 
@@ -5618,7 +6366,7 @@ mod tests {
             data: Some(EncounterData::from_encounters(vec![encounter])),
             ..GameState::default()
         };
-        state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(1)));
+        state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(1)));
         state.start_of_day();
         let rng_bundle = state.rng_bundle.clone();
         let outcome = state.process_encounter_flow(rng_bundle.as_ref(), false);
@@ -6229,7 +6977,10 @@ mod tests {
     fn apply_otdeluxe_random_resource_shortage_rejects_unknown() {
         let mut state = otdeluxe_state_with_party();
         let mut rng = SmallRng::seed_from_u64(3);
-        let outcome = state.apply_otdeluxe_random_resource_shortage("unknown", 0.2, 0.4, &mut rng);
+        let outcome = state.apply_otdeluxe_random_event_selection(
+            &make_event_selection("resource_shortage", "unknown"),
+            &mut rng,
+        );
         assert!(outcome.is_none());
     }
 
@@ -6237,9 +6988,15 @@ mod tests {
     fn apply_otdeluxe_random_party_incident_payloads() {
         let mut state = otdeluxe_state_with_party();
         let mut rng = SmallRng::seed_from_u64(4);
-        let lost = state.apply_otdeluxe_random_party_incident("lost_member", 0.1, 0.4, &mut rng);
+        let lost = state.apply_otdeluxe_random_event_selection(
+            &make_event_selection("party_incident", "lost_member"),
+            &mut rng,
+        );
         assert!(lost.is_some());
-        let snake = state.apply_otdeluxe_random_party_incident("snakebite", 0.1, 0.4, &mut rng);
+        let snake = state.apply_otdeluxe_random_event_selection(
+            &make_event_selection("party_incident", "snakebite"),
+            &mut rng,
+        );
         assert!(snake.is_some());
     }
 
@@ -6250,8 +7007,10 @@ mod tests {
         state.ot_deluxe.inventory.spares_axles = 0;
         state.ot_deluxe.inventory.spares_tongues = 0;
         let mut rng = SmallRng::seed_from_u64(6);
-        let outcome =
-            state.apply_otdeluxe_random_wagon_part_break("repairable", 0.2, 0.3, &mut rng);
+        let outcome = state.apply_otdeluxe_random_event_selection(
+            &make_event_selection("wagon_part_break", "repairable"),
+            &mut rng,
+        );
         assert!(outcome.is_some());
     }
 
@@ -6269,13 +7028,15 @@ mod tests {
                 daily_tick_effects: DiseaseEffects::default(),
                 fatality_model: None,
                 tags: Vec::new(),
+                treatments: Vec::new(),
             }],
         };
         let mut rng = SmallRng::seed_from_u64(2);
-        let outcome = state.apply_otdeluxe_random_affliction_with_catalog(
+        let outcome = state.apply_otdeluxe_affliction_with_catalog(
             &catalog,
             &mut rng,
             OtDeluxeAfflictionKind::Injury,
+            OtDeluxeAfflictionTarget::Random,
         );
         let outcome = outcome.expect("injury outcome");
         assert_eq!(outcome.display_key.as_deref(), Some("disease.injury"));
@@ -6285,7 +7046,7 @@ mod tests {
     fn lose_random_party_members_handles_exhausted_pool() {
         let mut state = otdeluxe_state_with_party();
         let mut rng = SmallRng::seed_from_u64(2);
-        let lost = state.lose_random_party_members(&mut rng, 2);
+        let lost = state.lose_random_party_members(&mut rng, 2, &OtDeluxeSkillPolicy::default());
         assert_eq!(lost.len(), 1);
     }
 
@@ -6444,7 +7205,10 @@ mod tests {
     fn apply_otdeluxe_random_resource_shortage_no_grass_payload() {
         let mut state = otdeluxe_state_with_party();
         let mut rng = SmallRng::seed_from_u64(21);
-        let outcome = state.apply_otdeluxe_random_resource_shortage("no_grass", 0.2, 0.4, &mut rng);
+        let outcome = state.apply_otdeluxe_random_event_selection(
+            &make_event_selection("resource_shortage", "no_grass"),
+            &mut rng,
+        );
         assert!(outcome.is_some());
     }
 
@@ -6455,10 +7219,11 @@ mod tests {
             diseases: Vec::new(),
         };
         let mut rng = SmallRng::seed_from_u64(22);
-        let outcome = state.apply_otdeluxe_random_affliction_with_catalog(
+        let outcome = state.apply_otdeluxe_affliction_with_catalog(
             &catalog,
             &mut rng,
             OtDeluxeAfflictionKind::Injury,
+            OtDeluxeAfflictionTarget::Random,
         );
         let policy = OtDeluxe90sPolicy::default();
         let member = &state.ot_deluxe.party.members[0];
@@ -6474,7 +7239,7 @@ mod tests {
         let mut state = otdeluxe_state_with_party();
         state.ot_deluxe.party.members[0].alive = false;
         let mut rng = StepRng::new(0, 0);
-        let lost = state.lose_random_party_members(&mut rng, 2);
+        let lost = state.lose_random_party_members(&mut rng, 2, &OtDeluxeSkillPolicy::default());
         assert!(lost.is_empty());
     }
 
@@ -6690,6 +7455,8 @@ pub enum DayIntent {
     Rest,
     Trade,
     Hunt,
+    Fish,
+    Forage,
     CrossingChoicePending,
 }
 
@@ -6713,6 +7480,9 @@ struct OtDeluxeCrossingLosses {
 pub struct IntentState {
     pub pending: DayIntent,
     pub rest_days_remaining: u8,
+    /// Extra days still owed by a multi-day fish or forage intent.
+    #[serde(default)]
+    pub action_days_remaining: u8,
 }
 
 impl Default for IntentState {
@@ -6720,6 +7490,7 @@ impl Default for IntentState {
         Self {
             pending: DayIntent::Continue,
             rest_days_remaining: 0,
+            action_days_remaining: 0,
         }
     }
 }
@@ -6808,7 +7579,8 @@ impl Stats {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum HealthLabel {
     Good,
     Fair,
@@ -6821,6 +7593,9 @@ pub enum HealthLabel {
 pub struct Inventory {
     #[serde(default)]
     pub spares: Spares,
+    /// Doses of medicine for treating illness.
+    #[serde(default)]
+    pub medicine: i32,
     #[serde(default)]
     pub tags: HashSet<String>,
 }
@@ -6895,6 +7670,16 @@ impl RecentEncounter {
     }
 }
 
+/// Resolved encounter and the choice taken, kept for run timelines.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EncounterChoiceRecord {
+    pub day: u32,
+    pub encounter_id: String,
+    pub name: String,
+    pub choice_index: usize,
+    pub choice_label: String,
+}
+
 const fn default_rest_threshold() -> i32 {
     4
 }
@@ -6966,6 +7751,9 @@ pub struct GameState {
     pub miles_traveled_actual: f32,
     #[serde(default)]
     pub vehicle_breakdowns: i32,
+    /// Days still spent waiting on a mechanic who cleared a breakdown.
+    #[serde(default)]
+    pub mechanic_wait_days: u32,
     #[serde(default)]
     pub crossings_completed: u32,
     #[serde(default)]
@@ -7063,12 +7851,19 @@ pub struct GameState {
     pub wait: WaitState,
     #[serde(default)]
     pub ot_deluxe: OtDeluxeState,
+    /// Graves from earlier runs surfaced as the wagon passes them; loaded
+    /// from platform storage at session start, never carried in saves.
+    #[serde(skip)]
+    pub trail_graves: Vec<Tombstone>,
     #[serde(default)]
     pub encounters_today: u8,
     #[serde(default)]
     pub encounter_history: VecDeque<u8>,
     #[serde(default)]
     pub recent_encounters: VecDeque<RecentEncounter>,
+    /// Most recent encounter choices, capped at `ENCOUNTER_LOG_LIMIT`.
+    #[serde(default)]
+    pub encounter_log: Vec<EncounterChoiceRecord>,
     #[serde(default)]
     pub encounter_cooldown: u8,
     #[serde(default)]
@@ -7089,6 +7884,9 @@ pub struct GameState {
     pub illness_travel_penalty: f32,
     #[serde(default)]
     pub illness_days_remaining: u32,
+    /// Disease catalog id of the traveler's current illness in Dystrail mode.
+    #[serde(default)]
+    pub illness_id: Option<String>,
     #[serde(default)]
     pub current_encounter: Option<Encounter>,
     /// Vehicle state and spares
@@ -7118,7 +7916,10 @@ pub struct GameState {
     #[serde(skip)]
     pub rotation_backlog: VecDeque<String>,
     #[serde(skip)]
-    pub rng_bundle: Option<Rc<RngBundle>>,
+    pub rng_bundle: Option<Arc<RngBundle>>,
+    /// Day systems of the owning session; camp actions and detours tick through them.
+    #[serde(skip)]
+    pub day_pipeline: Option<Arc<DayPipeline>>,
     #[serde(skip)]
     pub data: Option<EncounterData>,
     #[serde(skip)]
@@ -7167,6 +7968,7 @@ macro_rules! game_state_defaults {
             miles_traveled: 0.0,
             miles_traveled_actual: 0.0,
             vehicle_breakdowns: 0,
+            mechanic_wait_days: 0,
             crossings_completed: 0,
             crossing_detours_taken: 0,
             crossing_failures: 0,
@@ -7214,9 +8016,11 @@ macro_rules! game_state_defaults {
             intent: IntentState::default(),
             wait: WaitState::default(),
             ot_deluxe: OtDeluxeState::default(),
+            trail_graves: Vec::new(),
             encounters_today: 0,
             encounter_history: VecDeque::with_capacity(ENCOUNTER_HISTORY_WINDOW + 2),
             recent_encounters: VecDeque::with_capacity(ENCOUNTER_RECENT_MEMORY),
+            encounter_log: Vec::new(),
             encounter_cooldown: 0,
             repairs_spent_cents: 0,
             bribes_spent_cents: 0,
@@ -7228,6 +8032,7 @@ macro_rules! game_state_defaults {
             weather_travel_multiplier: 1.0,
             illness_travel_penalty: 1.0,
             illness_days_remaining: 0,
+            illness_id: None,
             vehicle: Vehicle::default(),
             breakdown: None,
             weather_state: WeatherState::default(),
@@ -7240,6 +8045,7 @@ macro_rules! game_state_defaults {
             day_reason_history: Vec::new(),
             rotation_backlog: VecDeque::new(),
             rng_bundle: None,
+            day_pipeline: None,
             data: None,
             last_damage: None,
             decision_traces_today: Vec::new(),
@@ -7323,7 +8129,7 @@ impl CrossingTelemetry {
 
 impl GameState {
     /// Attach a shared RNG bundle for deterministic domain draws.
-    pub fn attach_rng_bundle(&mut self, bundle: Rc<RngBundle>) {
+    pub fn attach_rng_bundle(&mut self, bundle: Arc<RngBundle>) {
         self.rng_bundle = Some(bundle);
     }
 
@@ -7332,27 +8138,46 @@ impl GameState {
         self.rng_bundle = None;
     }
 
-    fn health_rng(&self) -> Option<RefMut<'_, CountingRng<SmallRng>>> {
+    /// Attach the owning session's day systems.
+    pub fn attach_day_pipeline(&mut self, pipeline: Arc<DayPipeline>) {
+        self.day_pipeline = Some(pipeline);
+    }
+
+    /// Day systems ticked by days spent off the road; built-ins when no session is attached.
+    #[must_use]
+    pub fn day_pipeline(&self) -> Arc<DayPipeline> {
+        self.day_pipeline
+            .clone()
+            .unwrap_or_else(DayPipeline::builtin_shared)
+    }
+
+    fn health_rng(&self) -> Option<MutexGuard<'_, CountingRng<SmallRng>>> {
         self.rng_bundle.as_ref().map(|bundle| bundle.health())
     }
 
-    fn events_rng(&self) -> Option<RefMut<'_, CountingRng<SmallRng>>> {
+    fn events_rng(&self) -> Option<MutexGuard<'_, CountingRng<SmallRng>>> {
         self.rng_bundle.as_ref().map(|bundle| bundle.events())
     }
 
-    fn breakdown_rng(&self) -> Option<RefMut<'_, CountingRng<SmallRng>>> {
+    fn breakdown_rng(&self) -> Option<MutexGuard<'_, CountingRng<SmallRng>>> {
         self.rng_bundle.as_ref().map(|bundle| bundle.breakdown())
     }
 
-    fn crossing_rng(&self) -> Option<RefMut<'_, CountingRng<SmallRng>>> {
+    fn crossing_rng(&self) -> Option<MutexGuard<'_, CountingRng<SmallRng>>> {
         self.rng_bundle.as_ref().map(|bundle| bundle.crossing())
     }
 
-    fn boss_rng(&self) -> Option<RefMut<'_, CountingRng<SmallRng>>> {
+    fn boss_rng(&self) -> Option<MutexGuard<'_, CountingRng<SmallRng>>> {
         self.rng_bundle.as_ref().map(|bundle| bundle.boss())
     }
 
-    fn journey_pace_factor(&self) -> f32 {
+    /// Journey part weights adjusted for the vehicle class and upgrades.
+    pub(crate) fn vehicle_part_weights(&self) -> PartWeights {
+        self.vehicle_modifiers()
+            .part_weights(&self.journey_part_weights)
+    }
+
+    pub(crate) fn journey_pace_factor(&self) -> f32 {
         self.journey_breakdown
             .pace_factor
             .get(&self.pace)
@@ -7360,7 +8185,7 @@ impl GameState {
             .unwrap_or(1.0)
     }
 
-    fn journey_weather_factor(&self) -> f32 {
+    pub(crate) fn journey_weather_factor(&self) -> f32 {
         let mult = self.weather_effects.breakdown_mult;
         if mult.is_finite() && mult > 0.0 {
             mult
@@ -7398,10 +8223,10 @@ impl GameState {
                 names.push(companion.clone());
             }
         }
-        if names.len() > 5 {
-            names.truncate(5);
+        if names.len() > OTDELUXE_PARTY_SIZE {
+            names.truncate(OTDELUXE_PARTY_SIZE);
         }
-        while names.len() < 5 {
+        while names.len() < OTDELUXE_PARTY_SIZE {
             let slot = names.len() + 1;
             names.push(placeholder_party_member_name(slot));
         }
@@ -7419,13 +8244,15 @@ impl GameState {
 
         let cash_cents = u32::try_from(self.budget_cents.max(0)).unwrap_or(u32::MAX);
 
-        let calendar = OtDeluxeCalendar::from_day_index(self.day);
+        let departure = self.ot_deluxe.departure.clone();
+        let calendar = departure.on_day(self.day);
         let mut ot_state = OtDeluxeState {
             day: self.day,
             miles_traveled: self.miles_traveled_actual,
             terrain: OtDeluxeTerrain::default(),
             season: calendar.season(),
             calendar,
+            departure,
             party: OtDeluxePartyState::from_names(names),
             inventory: OtDeluxeInventory {
                 cash_cents,
@@ -7444,21 +8271,76 @@ impl GameState {
         ot_state
     }
 
-    pub fn apply_otdeluxe_start_config(&mut self, occupation: OtDeluxeOccupation) {
+    pub fn apply_otdeluxe_start_config(&mut self, config: &OtDeluxeStartConfig) {
         if self.mechanical_policy != MechanicalPolicyId::OtDeluxe90s {
             return;
         }
         if self.ot_deluxe.party.members.is_empty() {
             self.ot_deluxe = self.build_ot_deluxe_state_from_legacy();
         }
+        let occupation = config.occupation;
+        self.ot_deluxe.set_departure(config.departure());
         self.ot_deluxe.mods.occupation = Some(occupation);
         if self.day <= 1 && self.day_records.is_empty() {
             let policy = default_otdeluxe_policy();
             self.ot_deluxe.inventory.cash_cents = otdeluxe_starting_cash_cents(occupation, policy);
+            self.assign_otdeluxe_party_roles(config);
         }
         self.sync_otdeluxe_trail_distance();
     }
 
+    /// Give each member the age group and skill from their start slot.
+    fn assign_otdeluxe_party_roles(&mut self, config: &OtDeluxeStartConfig) {
+        let members = self.ot_deluxe.party.members.iter_mut();
+        for (member, role) in members.zip(config.roles) {
+            member.age_group = role.age_group;
+            member.skill = role.skill;
+        }
+    }
+
+    /// Load graves from earlier runs so the trail can surface them.
+    pub fn set_trail_graves(&mut self, graveyard: &Graveyard) {
+        self.trail_graves.clone_from(&graveyard.tombstones);
+    }
+
+    fn surface_passed_tombstones(&mut self, from_miles: f32, to_miles: f32) {
+        let passed: Vec<Tombstone> = graveyard::passed_between(
+            &self.trail_graves,
+            self.ot_deluxe.route.variant,
+            from_miles,
+            to_miles,
+        )
+        .cloned()
+        .collect();
+        for grave in passed {
+            self.logs.push(String::from(LOG_OT_TOMBSTONE));
+            self.push_event(
+                EventKind::TombstonePassed,
+                EventSeverity::Info,
+                DayTagSet::new(),
+                Some(UiSurfaceHint::Toast),
+                Some(String::from(LOG_OT_TOMBSTONE)),
+                serde_json::json!({
+                    "name": grave.name,
+                    "cause": grave.cause,
+                    "cause_key": grave.cause_key(),
+                    "date": grave.date,
+                    "miles": grave.miles,
+                    "epitaph": grave.epitaph,
+                }),
+            );
+        }
+    }
+
+    /// Season the weather models sample from; `OTDeluxe` follows its trail calendar.
+    #[must_use]
+    pub const fn weather_season(&self) -> Season {
+        match self.mechanical_policy {
+            MechanicalPolicyId::OtDeluxe90s => self.ot_deluxe.season,
+            MechanicalPolicyId::DystrailLegacy => self.season,
+        }
+    }
+
     fn sync_otdeluxe_trail_distance(&mut self) {
         if self.mechanical_policy != MechanicalPolicyId::OtDeluxe90s {
             return;
@@ -7469,7 +8351,7 @@ impl GameState {
         self.trail_distance = f32::from(total).max(1.0);
     }
 
-    fn otdeluxe_next_prompt_marker(&self) -> Option<(OtDeluxeRoutePrompt, u16)> {
+    pub(crate) fn otdeluxe_next_prompt_marker(&self) -> Option<(OtDeluxeRoutePrompt, u16)> {
         if self.mechanical_policy != MechanicalPolicyId::OtDeluxe90s {
             return None;
         }
@@ -7571,13 +8453,14 @@ impl GameState {
         let current = i32::from(self.ot_deluxe.health_general);
         let next = (current + total_delta).max(0);
         self.ot_deluxe.health_general = u16::try_from(next).unwrap_or(u16::MAX);
-        self.update_otdeluxe_death_imminent(&policy.health);
+        self.update_otdeluxe_death_imminent(policy);
         total_delta
     }
 
-    fn update_otdeluxe_death_imminent(&mut self, policy: &OtDeluxeHealthPolicy) {
-        if self.ot_deluxe.health_general >= policy.death_threshold {
-            let grace = policy.death_imminent_grace_days;
+    fn update_otdeluxe_death_imminent(&mut self, policy: &OtDeluxe90sPolicy) {
+        let health = &policy.health;
+        if self.ot_deluxe.health_general >= health.death_threshold {
+            let grace = health.death_imminent_grace_days;
             if self.ot_deluxe.death_imminent_days_remaining == 0 {
                 self.ot_deluxe.death_imminent_days_remaining = grace;
             } else {
@@ -7592,6 +8475,13 @@ impl GameState {
                 }
             }
             if grace == 0 || self.ot_deluxe.death_imminent_days_remaining == 0 {
+                for idx in alive_member_indices(&self.ot_deluxe.party.members) {
+                    self.ot_deluxe.record_death(
+                        idx,
+                        OtDeluxeDeathCause::Exhaustion,
+                        &policy.skills,
+                    );
+                }
                 for member in &mut self.ot_deluxe.party.members {
                     member.alive = false;
                     member.clear_afflictions();
@@ -7600,7 +8490,7 @@ impl GameState {
                     cause: CollapseCause::Disease,
                 });
             }
-        } else if policy.death_imminent_resets_on_recovery_below_threshold {
+        } else if health.death_imminent_resets_on_recovery_below_threshold {
             self.ot_deluxe.death_imminent_days_remaining = 0;
         }
     }
@@ -7694,7 +8584,9 @@ impl GameState {
         self.events_today.clear();
         self.weather_effects = WeatherEffects::default();
         let day_index = u16::try_from(self.day.saturating_sub(1)).unwrap_or(u16::MAX);
-        self.current_day_record = Some(DayRecord::new(day_index, TravelDayKind::NonTravel, 0.0));
+        let mut record = DayRecord::new(day_index, TravelDayKind::NonTravel, 0.0);
+        record.snapshot = Some(self.day_snapshot());
+        self.current_day_record = Some(record);
         self.terminal_log_key = None;
         self.day_state.lifecycle.log_cursor = u32::try_from(self.logs.len()).unwrap_or(u32::MAX);
         self.day_state.lifecycle.event_seq = 0;
@@ -7819,6 +8711,7 @@ impl GameState {
             let trace = EventDecisionTrace {
                 pool_id: String::from("dystrail.exec_order"),
                 roll: RollValue::U32(roll),
+                roll_scale: RollScale::Index,
                 candidates,
                 chosen_id: order.key().to_string(),
             };
@@ -8052,6 +8945,25 @@ impl GameState {
         day_kind
     }
 
+    /// Capture the ledger-facing state recorded on each [`DayRecord`].
+    #[must_use]
+    pub fn day_snapshot(&self) -> EndOfDaySnapshot {
+        EndOfDaySnapshot {
+            stats: self.stats.clone(),
+            weather: self.weather_state.today,
+            breakdown: self.breakdown.as_ref().map(|breakdown| breakdown.part),
+            budget_cents: self.budget_cents,
+            vehicle_health: self.vehicle.health,
+            vehicle_wear: self.vehicle.wear,
+            region: self.region,
+            exec_order: self.current_order,
+            malnutrition_level: self.malnutrition_level,
+            party_alive: (self.mechanical_policy == MechanicalPolicyId::OtDeluxe90s)
+                .then(|| self.ot_deluxe.party.alive_count()),
+            deltas: DayDeltas::default(),
+        }
+    }
+
     fn finalize_day(&mut self, day_kind: TravelDayKind) {
         if self.rotation_travel_days >= self.rotation_force_interval() {
             self.encounters.force_rotation_pending = true;
@@ -8061,9 +8973,14 @@ impl GameState {
             self.recent_travel_days.pop_front();
         }
         self.recent_travel_days.push_back(day_kind);
+        let mut snapshot = self.day_snapshot();
         if let Some(record) = self.current_day_record.as_mut() {
+            if let Some(start) = record.snapshot.as_ref() {
+                snapshot.deltas = DayDeltas::between(start, &snapshot);
+            }
             record.kind = day_kind;
             record.miles = self.current_day_miles;
+            record.snapshot = Some(snapshot);
         }
         let reason_entry = if self.current_day_reason_tags.is_empty() {
             String::new()
@@ -8086,7 +9003,7 @@ impl GameState {
             self.ot_deluxe.advance_days(1);
         } else {
             self.ot_deluxe.day = self.day;
-            self.ot_deluxe.calendar = OtDeluxeCalendar::from_day_index(self.day);
+            self.ot_deluxe.calendar = self.ot_deluxe.departure.on_day(self.day);
             self.ot_deluxe.season = self.ot_deluxe.calendar.season();
         }
         self.ot_deluxe.miles_traveled = self.miles_traveled_actual;
@@ -8156,7 +9073,8 @@ impl GameState {
         if wear_delta <= 0.0 {
             return;
         }
-        self.vehicle.apply_scaled_wear(wear_delta);
+        self.vehicle
+            .apply_scaled_wear(wear_delta * self.vehicle_modifiers().wear_mult);
     }
 
     pub(crate) fn apply_travel_wear_for_day(&mut self, baseline_miles: f32) {
@@ -8248,6 +9166,7 @@ impl GameState {
             }
         }
         if self.mechanical_policy == MechanicalPolicyId::OtDeluxe90s {
+            self.surface_passed_tombstones(before, self.miles_traveled_actual);
             self.ot_deluxe.miles_traveled = self.miles_traveled_actual;
             let policy = default_otdeluxe_policy();
             self.ot_deluxe.route.current_node_index = otdeluxe_trail::node_index_for_miles(
@@ -8615,7 +9534,7 @@ impl GameState {
         let receipts = i32::try_from(self.receipts.len()).unwrap_or(0);
         let breakdown_penalty = (self.vehicle_breakdowns * 12).min(600);
 
-        supplies * 10
+        let score = supplies * 10
             + hp * 50
             + morale * 25
             + credibility * 15
@@ -8623,7 +9542,36 @@ impl GameState {
             + days * 4
             + encounters * 6
             + receipts * 8
-            - breakdown_penalty
+            - breakdown_penalty;
+        let vehicle_mult = self.vehicle_modifiers().score_mult;
+        if (vehicle_mult - 1.0).abs() <= f32::EPSILON {
+            return score;
+        }
+        round_f64_to_i32(f64::from(score) * f64::from(vehicle_mult))
+    }
+
+    /// Class and upgrade modifiers for the Dystrail vehicle.
+    ///
+    /// `OTDeluxe` wagons have no vehicle class, so they always get neutral modifiers.
+    #[must_use]
+    pub fn vehicle_modifiers(&self) -> VehicleModifiers {
+        if self.mechanical_policy == MechanicalPolicyId::OtDeluxe90s {
+            return VehicleModifiers::default();
+        }
+        VehicleConfig::default_config().modifiers(&self.vehicle)
+    }
+
+    /// Pick the Dystrail vehicle class; only allowed before the first day is played.
+    pub fn select_vehicle_class(&mut self, class: VehicleClass) -> bool {
+        if self.mechanical_policy == MechanicalPolicyId::OtDeluxe90s
+            || self.day > 1
+            || !self.day_records.is_empty()
+        {
+            return false;
+        }
+        self.vehicle.class = class;
+        self.trim_spares_to_capacity();
+        true
     }
 
     #[must_use]
@@ -8721,6 +9669,7 @@ impl GameState {
                 rng.gen_range(DISEASE_DURATION_RANGE.0..=DISEASE_DURATION_RANGE.1)
             });
         self.illness_days_remaining = duration;
+        self.illness_id = self.pick_dystrail_illness();
         self.stats.hp -= DISEASE_HP_PENALTY;
         self.stats.sanity -= DISEASE_SANITY_PENALTY;
         self.stats.supplies = (self.stats.supplies - DISEASE_SUPPLY_PENALTY).max(0);
@@ -8731,6 +9680,16 @@ impl GameState {
         self.push_log(LOG_DISEASE_HIT);
     }
 
+    /// Name the traveler's illness from the disease catalog. The pick uses its
+    /// own seeded rng so the health stream keeps its rolls.
+    fn pick_dystrail_illness(&self) -> Option<String> {
+        let seed_mix = self.seed ^ (u64::from(self.day) << 32) ^ 0x11_1e55;
+        let mut rng = SmallRng::seed_from_u64(seed_mix);
+        DiseaseCatalog::default_catalog()
+            .pick_by_kind(DiseaseKind::Illness, &mut rng)
+            .map(|disease| disease.id.clone())
+    }
+
     pub(crate) fn tick_otdeluxe_afflictions(&mut self) -> Option<OtDeluxeAfflictionOutcome> {
         if self.mechanical_policy != MechanicalPolicyId::OtDeluxe90s {
             return None;
@@ -8771,6 +9730,7 @@ impl GameState {
         let rations = self.ot_deluxe.rations;
 
         let mut travel_mult = 1.0;
+        let mut deaths = Vec::new();
         {
             let OtDeluxeState {
                 party,
@@ -8779,11 +9739,12 @@ impl GameState {
                 travel,
                 ..
             } = &mut self.ot_deluxe;
-            for member in &mut party.members {
+            for (idx, member) in party.members.iter_mut().enumerate() {
                 if !member.alive {
                     continue;
                 }
                 let mut died = false;
+                let mut cause = None;
                 if member.sick_days_remaining > 0
                     && let Some(id) = member.illness_id.as_deref()
                     && let Some(disease) = catalog.find_by_id(id)
@@ -8793,16 +9754,20 @@ impl GameState {
                         inventory,
                         &disease.daily_tick_effects,
                     );
-                    if let Some(model) = disease.fatality_model.as_ref() {
-                        let context = OtDeluxeFatalityContext {
-                            health_general: *health_general,
-                            pace,
-                            rations,
-                            weather: weather_today,
-                            occupation,
-                        };
-                        died = otdeluxe_roll_disease_fatality(model, rng, context, policy);
-                    }
+                    let model = disease
+                        .fatality_model
+                        .as_ref()
+                        .unwrap_or(&policy.disease_fatality);
+                    let context = OtDeluxeFatalityContext {
+                        health_general: *health_general,
+                        pace,
+                        rations,
+                        weather: weather_today,
+                        occupation,
+                        treated: member.treated,
+                        age_group: member.age_group,
+                    };
+                    died = otdeluxe_roll_disease_fatality(model, rng, context, policy);
                 }
                 if !died
                     && member.injured_days_remaining > 0
@@ -8814,18 +9779,35 @@ impl GameState {
                         inventory,
                         &disease.daily_tick_effects,
                     );
-                    if let Some(model) = disease.fatality_model.as_ref() {
-                        let context = OtDeluxeFatalityContext {
-                            health_general: *health_general,
-                            pace,
-                            rations,
-                            weather: weather_today,
-                            occupation,
-                        };
-                        died = otdeluxe_roll_disease_fatality(model, rng, context, policy);
+                    let model = disease
+                        .fatality_model
+                        .as_ref()
+                        .unwrap_or(&policy.disease_fatality);
+                    let context = OtDeluxeFatalityContext {
+                        health_general: *health_general,
+                        pace,
+                        rations,
+                        weather: weather_today,
+                        occupation,
+                        treated: member.treated,
+                        age_group: member.age_group,
+                    };
+                    if otdeluxe_roll_disease_fatality(model, rng, context, policy) {
+                        died = true;
+                        cause = Some(OtDeluxeDeathCause::affliction(
+                            OtDeluxeAfflictionKind::Injury,
+                            Some(id),
+                        ));
                     }
                 }
                 if died {
+                    let cause = cause.unwrap_or_else(|| {
+                        OtDeluxeDeathCause::affliction(
+                            OtDeluxeAfflictionKind::Illness,
+                            member.illness_id.as_deref(),
+                        )
+                    });
+                    deaths.push((idx, cause));
                     member.alive = false;
                     member.clear_afflictions();
                 }
@@ -8833,6 +9815,9 @@ impl GameState {
             party.tick_afflictions();
             travel.disease_speed_mult = sanitize_disease_multiplier(travel_mult);
         }
+        for (idx, cause) in deaths {
+            self.ot_deluxe.record_death(idx, cause, &policy.skills);
+        }
     }
 
     fn roll_otdeluxe_affliction_with_catalog(
@@ -8869,10 +9854,19 @@ impl GameState {
             |selected| selected.duration_for(&policy.affliction),
         );
         let disease_id = disease.map(|selected| selected.id.as_str());
+        let duration = self.otdeluxe_medic_duration(duration, kind, disease_id, policy);
         let mut outcome = self
             .ot_deluxe
             .party
             .apply_affliction_random(rng, kind, duration, disease_id);
+        self.lower_afflicted_member_morale(outcome.as_ref(), policy);
+        if let Some(result) = outcome.as_ref().filter(|result| result.died) {
+            self.ot_deluxe.record_death(
+                result.member_index,
+                OtDeluxeDeathCause::affliction(kind, disease_id),
+                &policy.skills,
+            );
+        }
         if let (Some(selected), Some(ref mut result)) = (disease, outcome.as_mut()) {
             result.display_key = Some(selected.display_key.clone());
             result.disease_id = Some(selected.id.clone());
@@ -9068,6 +10062,7 @@ impl GameState {
             };
         }
         multiplier *= self.endgame_bias();
+        multiplier *= self.vehicle_modifiers().speed_mult;
         let behind_boost = self.behind_schedule_multiplier();
         if behind_boost > 1.0 {
             multiplier *= behind_boost;
@@ -9167,6 +10162,8 @@ impl GameState {
             .max(0.0);
         let disease_mult = self.ot_deluxe.travel.disease_speed_mult.max(0.0);
         let snow_mult = otdeluxe_snow_speed_mult(self.ot_deluxe.weather.snow_depth, &policy.travel);
+        let cargo_mult =
+            otdeluxe_cargo::overload_speed_mult(&policy.travel, &self.ot_deluxe.cargo_load(policy));
         let overrides = policy.overrides_for(self.region, self.ot_deluxe.season);
         let travel_override = overrides.travel_multiplier.unwrap_or(1.0);
         let travel_override = if travel_override.is_finite() && travel_override >= 0.0 {
@@ -9181,6 +10178,7 @@ impl GameState {
             * sick_penalty
             * disease_mult
             * snow_mult
+            * cargo_mult
             * travel_override)
             .max(0.0);
         let ratio = policy.travel.partial_ratio.clamp(0.0, 1.0);
@@ -9555,7 +10553,7 @@ impl GameState {
         let policy = default_otdeluxe_policy();
         self.apply_otdeluxe_crossing_costs(policy, method);
         let losses = self.apply_otdeluxe_crossing_losses(resolution.loss_ratio);
-        let drown_count = self.apply_otdeluxe_drownings(&drowned_indices);
+        let drown_count = self.apply_otdeluxe_drownings(&drowned_indices, &policy.skills);
 
         let (log_key, severity) = Self::otdeluxe_crossing_log_and_severity(resolution.outcome);
         self.push_log(log_key);
@@ -9617,6 +10615,41 @@ impl GameState {
         Some((river_kind, river_state))
     }
 
+    /// Size up the pending river before choosing how to cross.
+    ///
+    /// The observation is blurred by the policy's info fuzz (less for farmers)
+    /// from a stream derived from the seed, river and day, so it is stable
+    /// within a day and never touches the crossing RNG.
+    #[must_use]
+    pub fn otdeluxe_crossing_info(&self) -> Option<OtDeluxeCrossingInfo> {
+        let river_kind = self.ot_deluxe.crossing.river_kind?;
+        let river_state = self.ot_deluxe.crossing.river.as_ref()?;
+        let policy = default_otdeluxe_policy();
+        let options = otdeluxe_crossings::crossing_options(
+            &policy.crossings,
+            river_kind,
+            river_state,
+            &self.ot_deluxe.inventory,
+        );
+        let mut fuzz = policy.crossings.info_fuzz_max;
+        if matches!(
+            self.ot_deluxe.mods.occupation,
+            Some(OtDeluxeOccupation::Farmer)
+        ) {
+            fuzz *= policy.occupation_advantages.crossing_info_fuzz_mult;
+        }
+        let domain = format!("crossing_info:{}:{}", self.crossings_completed, self.day);
+        let mut rng = SmallRng::seed_from_u64(derive_stream_seed(self.seed, domain.as_bytes()));
+        Some(otdeluxe_crossings::crossing_info(
+            &policy.crossings,
+            river_kind,
+            river_state,
+            options,
+            fuzz,
+            &mut rng,
+        ))
+    }
+
     fn roll_otdeluxe_crossing(
         &self,
         river_kind: OtDeluxeRiver,
@@ -9673,9 +10706,20 @@ impl GameState {
                     self.ot_deluxe.inventory.cash_cents.saturating_sub(cost);
             }
             OtDeluxeCrossingMethod::Guide => {
-                let cost = policy.crossings.guide_cost_clothes_sets;
-                self.ot_deluxe.inventory.clothes_sets =
-                    self.ot_deluxe.inventory.clothes_sets.saturating_sub(cost);
+                match otdeluxe_crossings::guide_payment(
+                    &policy.crossings,
+                    &self.ot_deluxe.inventory,
+                ) {
+                    Some(OtDeluxeGuidePayment::ClothesSets(sets)) => {
+                        self.ot_deluxe.inventory.clothes_sets =
+                            self.ot_deluxe.inventory.clothes_sets.saturating_sub(sets);
+                    }
+                    Some(OtDeluxeGuidePayment::Cash(cents)) => {
+                        self.ot_deluxe.inventory.cash_cents =
+                            self.ot_deluxe.inventory.cash_cents.saturating_sub(cents);
+                    }
+                    None => {}
+                }
             }
             OtDeluxeCrossingMethod::Ford | OtDeluxeCrossingMethod::CaulkFloat => {}
         }
@@ -9717,8 +10761,22 @@ impl GameState {
         }
     }
 
-    fn apply_otdeluxe_drownings(&mut self, drowned_indices: &[usize]) -> u8 {
+    fn apply_otdeluxe_drownings(
+        &mut self,
+        drowned_indices: &[usize],
+        policy: &OtDeluxeSkillPolicy,
+    ) -> u8 {
         for idx in drowned_indices {
+            if self
+                .ot_deluxe
+                .party
+                .members
+                .get(*idx)
+                .is_some_and(|member| member.alive)
+            {
+                self.ot_deluxe
+                    .record_death(*idx, OtDeluxeDeathCause::Drowning, policy);
+            }
             if let Some(member) = self.ot_deluxe.party.members.get_mut(*idx) {
                 member.alive = false;
             }
@@ -10015,7 +11073,7 @@ impl GameState {
         self.pending_route_choice = None;
         self.push_log("log.seed-set");
         self.data = Some(data);
-        self.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(seed)));
+        self.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(seed)));
         self
     }
 
@@ -10045,7 +11103,7 @@ impl GameState {
         self.pending_route_choice = None;
         self.ot_deluxe.crossing.chosen_method = None;
         if self.rng_bundle.is_none() {
-            self.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(self.seed)));
+            self.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(self.seed)));
         }
         self.day_state.lifecycle.log_cursor = u32::try_from(self.logs.len()).unwrap_or(u32::MAX);
         self.day_state.lifecycle.event_seq = 0;
@@ -10197,10 +11255,33 @@ impl GameState {
         if let Some(trace) = trace {
             self.decision_traces_today.push(trace);
         }
+        let delay_days = self.otdeluxe_navigator_delay(event, delay_days);
         self.apply_otdeluxe_navigation_hard_stop(event, delay_days);
         true
     }
 
+    /// Shorten a navigation delay when a navigator is on hand, tracing the cut.
+    fn otdeluxe_navigator_delay(&mut self, event: OtDeluxeNavigationEvent, delay_days: u8) -> u8 {
+        let policy = default_otdeluxe_policy();
+        let Some(factor) = otdeluxe_skills::skill_factor(
+            &self.ot_deluxe.party,
+            OtDeluxeSkill::Navigator,
+            &policy.skills,
+        ) else {
+            return delay_days;
+        };
+        let cut = otdeluxe_skills::scale_days(delay_days, &factor);
+        self.decision_traces_today
+            .push(otdeluxe_skills::modifier_trace(
+                "otdeluxe.skills.navigation_delay",
+                otdeluxe_navigation_event_id(event),
+                f64::from(delay_days),
+                factor,
+                f64::from(cut),
+            ));
+        cut
+    }
+
     fn apply_otdeluxe_navigation_hard_stop(
         &mut self,
         event: OtDeluxeNavigationEvent,
@@ -10273,6 +11354,10 @@ impl GameState {
             spares_total,
             weight_mult: f64::from(weight_mult),
             weight_cap,
+            skill_factors: otdeluxe_skills::party_skill_factors(
+                &self.ot_deluxe.party,
+                &policy.skills,
+            ),
         };
         let bundle = self.rng_bundle.take()?;
         let result = {
@@ -10310,321 +11395,75 @@ impl GameState {
         rng: &mut R,
     ) -> Option<(String, EventSeverity, serde_json::Value)> {
         let event_id = selection.event_id.as_str();
-        let variant = selection.variant_id.as_deref()?;
-        let log_key = format!("log.otdeluxe.random_event.{event_id}.{variant}");
-        let roll = selection.chance_roll;
-        let threshold = selection.chance_threshold;
-
-        let (severity, payload) = match event_id {
-            "weather_catastrophe" => {
-                self.apply_otdeluxe_random_weather_catastrophe(variant, roll, threshold)?
-            }
-            "resource_shortage" => {
-                self.apply_otdeluxe_random_resource_shortage(variant, roll, threshold, rng)?
-            }
-            "party_incident" => {
-                self.apply_otdeluxe_random_party_incident(variant, roll, threshold, rng)?
-            }
-            "oxen_incident" => {
-                self.apply_otdeluxe_random_oxen_incident(variant, roll, threshold)?
-            }
-            "resource_change" => {
-                self.apply_otdeluxe_random_resource_change(variant, roll, threshold)?
-            }
-            "wagon_part_break" => {
-                self.apply_otdeluxe_random_wagon_part_break(variant, roll, threshold, rng)?
-            }
-            "travel_hazard" => {
-                self.apply_otdeluxe_random_travel_hazard(variant, roll, threshold)?
-            }
-            _ => return None,
-        };
-
-        Some((log_key, severity, payload))
-    }
-
-    fn apply_otdeluxe_random_weather_catastrophe(
-        &mut self,
-        variant: &str,
-        chance_roll: f32,
-        chance_threshold: f32,
-    ) -> Option<(EventSeverity, serde_json::Value)> {
-        let (health_delta, food_delta, severity) = match variant {
-            "blizzard" => (5, -10, EventSeverity::Warning),
-            "hailstorm" => (3, -5, EventSeverity::Warning),
-            "thunderstorm" => (2, 0, EventSeverity::Info),
-            "heavy_fog" | "strong_winds" => (1, 0, EventSeverity::Info),
-            _ => return None,
-        };
-        let applied_health = self.apply_otdeluxe_health_delta(health_delta);
-        let applied_food = self.apply_otdeluxe_food_delta(food_delta);
-        let payload = serde_json::json!({
-            "event": "weather_catastrophe",
-            "variant": variant,
-            "chance_roll": chance_roll,
-            "chance_threshold": chance_threshold,
-            "deltas": {
-                "health_general": applied_health,
-                "food_lbs": applied_food
-            }
-        });
-        Some((severity, payload))
-    }
+        let variant_id = selection.variant_id.as_deref()?;
+        let variant = otdeluxe_random_events::catalog().variant(event_id, variant_id)?;
+        let effects = variant.effects.as_deref()?;
+        let outcome = apply_random_event_effects(self, effects, rng);
 
-    fn apply_otdeluxe_random_resource_shortage<R: Rng + ?Sized>(
-        &mut self,
-        variant: &str,
-        chance_roll: f32,
-        chance_threshold: f32,
-        rng: &mut R,
-    ) -> Option<(EventSeverity, serde_json::Value)> {
-        let mut affliction_payload = None;
-        let (health_delta, oxen_delta, severity) = match variant {
-            "bad_water" => {
-                let kind = OtDeluxeAfflictionKind::Illness;
-                let outcome = self.apply_otdeluxe_random_affliction(rng, kind);
-                affliction_payload = outcome.as_ref().map(otdeluxe_affliction_payload);
-                (2, (0_i16, 0_i16), EventSeverity::Warning)
-            }
-            "no_water" => (4, (0_i16, 0_i16), EventSeverity::Warning),
-            "no_grass" => {
-                let (healthy_delta, sick_delta) = self.apply_otdeluxe_no_grass_loss();
-                (0, (healthy_delta, sick_delta), EventSeverity::Warning)
-            }
-            _ => return None,
-        };
-        let applied_health = if health_delta != 0 {
-            self.apply_otdeluxe_health_delta(health_delta)
-        } else {
-            0
-        };
-        let (oxen_healthy_delta, oxen_sick_delta) = oxen_delta;
-        let mut deltas = serde_json::Map::new();
-        deltas.insert(
-            String::from("health_general"),
-            serde_json::json!(applied_health),
-        );
-        deltas.insert(
-            String::from("oxen_healthy"),
-            serde_json::json!(oxen_healthy_delta),
-        );
-        deltas.insert(
-            String::from("oxen_sick"),
-            serde_json::json!(oxen_sick_delta),
-        );
         let mut payload = serde_json::Map::new();
+        payload.insert(String::from("event"), serde_json::json!(event_id));
+        payload.insert(String::from("variant"), serde_json::json!(variant_id));
         payload.insert(
-            String::from("event"),
-            serde_json::json!("resource_shortage"),
-        );
-        payload.insert(String::from("variant"), serde_json::json!(variant));
-        payload.insert(String::from("chance_roll"), serde_json::json!(chance_roll));
-        payload.insert(
-            String::from("chance_threshold"),
-            serde_json::json!(chance_threshold),
-        );
-        payload.insert(String::from("deltas"), serde_json::Value::Object(deltas));
-        payload.insert(
-            String::from("affliction"),
-            affliction_payload.unwrap_or(serde_json::Value::Null),
+            String::from("chance_roll"),
+            serde_json::json!(selection.chance_roll),
         );
-        let payload = serde_json::Value::Object(payload);
-        Some((severity, payload))
-    }
-
-    fn apply_otdeluxe_random_party_incident<R: Rng + ?Sized>(
-        &mut self,
-        variant: &str,
-        chance_roll: f32,
-        chance_threshold: f32,
-        rng: &mut R,
-    ) -> Option<(EventSeverity, serde_json::Value)> {
-        let mut affliction_payload = None;
-        let lost_members = if variant == "lost_member" {
-            self.lose_random_party_members(rng, 1)
-        } else if variant == "snakebite" {
-            let kind = OtDeluxeAfflictionKind::Injury;
-            let outcome = self.apply_otdeluxe_random_affliction(rng, kind);
-            affliction_payload = outcome.as_ref().map(otdeluxe_affliction_payload);
-            Vec::new()
-        } else {
-            return None;
-        };
-        let severity = if variant == "lost_member" {
-            EventSeverity::Critical
-        } else {
-            EventSeverity::Warning
-        };
-        let mut payload = serde_json::Map::new();
-        payload.insert(String::from("event"), serde_json::json!("party_incident"));
-        payload.insert(String::from("variant"), serde_json::json!(variant));
-        payload.insert(String::from("chance_roll"), serde_json::json!(chance_roll));
         payload.insert(
             String::from("chance_threshold"),
-            serde_json::json!(chance_threshold),
-        );
-        payload.insert(
-            String::from("lost_members"),
-            serde_json::json!(lost_members),
-        );
-        payload.insert(
-            String::from("affliction"),
-            affliction_payload.unwrap_or(serde_json::Value::Null),
-        );
-        let payload = serde_json::Value::Object(payload);
-        Some((severity, payload))
-    }
-
-    fn apply_otdeluxe_random_oxen_incident(
-        &mut self,
-        variant: &str,
-        chance_roll: f32,
-        chance_threshold: f32,
-    ) -> Option<(EventSeverity, serde_json::Value)> {
-        let (healthy_delta, sick_delta) = match variant {
-            "ox_wandered_off" => self.apply_otdeluxe_oxen_wander(),
-            "ox_sickness" => self.apply_otdeluxe_oxen_sickness(),
-            _ => return None,
-        };
-        let payload = serde_json::json!({
-            "event": "oxen_incident",
-            "variant": variant,
-            "chance_roll": chance_roll,
-            "chance_threshold": chance_threshold,
-            "deltas": {
-                "oxen_healthy": healthy_delta,
-                "oxen_sick": sick_delta
-            }
-        });
-        Some((EventSeverity::Warning, payload))
-    }
-
-    fn apply_otdeluxe_random_resource_change(
-        &mut self,
-        variant: &str,
-        chance_roll: f32,
-        chance_threshold: f32,
-    ) -> Option<(EventSeverity, serde_json::Value)> {
-        let (food_delta, bullets_delta, clothes_delta, spares_delta, severity) = match variant {
-            "abandoned_wagon_empty" => (0, 0, 0, (0_i16, 0_i16, 0_i16), EventSeverity::Info),
-            "abandoned_wagon_supplies" => (25, 10, 1, (0_i16, 0_i16, 0_i16), EventSeverity::Info),
-            "thief" => (-30, -10, 0, (0_i16, 0_i16, 0_i16), EventSeverity::Warning),
-            "wild_fruit" => (15, 0, 0, (0_i16, 0_i16, 0_i16), EventSeverity::Info),
-            "mutual_aid_food" => (25, 0, 0, (0_i16, 0_i16, 0_i16), EventSeverity::Info),
-            "gravesite" => (0, 5, 1, (0_i16, 0_i16, 0_i16), EventSeverity::Info),
-            "fire" => (
-                -40,
-                -20,
-                -1,
-                (-1_i16, -1_i16, -1_i16),
-                EventSeverity::Critical,
-            ),
-            _ => return None,
-        };
-        let applied_food = self.apply_otdeluxe_food_delta(food_delta);
-        let applied_bullets = self.apply_otdeluxe_bullets_delta(bullets_delta);
-        let applied_clothes = self.apply_otdeluxe_clothes_delta(clothes_delta);
-        let (wheels_delta, axles_delta, tongues_delta) =
-            self.apply_otdeluxe_spares_delta(spares_delta.0, spares_delta.1, spares_delta.2);
-        let payload = serde_json::json!({
-            "event": "resource_change",
-            "variant": variant,
-            "chance_roll": chance_roll,
-            "chance_threshold": chance_threshold,
-            "deltas": {
-                "food_lbs": applied_food,
-                "bullets": applied_bullets,
-                "clothes_sets": applied_clothes,
-                "spares_wheels": wheels_delta,
-                "spares_axles": axles_delta,
-                "spares_tongues": tongues_delta
-            }
-        });
-        Some((severity, payload))
-    }
-
-    fn apply_otdeluxe_random_wagon_part_break<R: Rng + ?Sized>(
-        &mut self,
-        variant: &str,
-        chance_roll: f32,
-        chance_threshold: f32,
-        rng: &mut R,
-    ) -> Option<(EventSeverity, serde_json::Value)> {
-        let (fallback_food, fallback_clothes, severity) = match variant {
-            "repairable" => (5, 0, EventSeverity::Warning),
-            "replaceable" => (10, 0, EventSeverity::Warning),
-            "unrepairable" => (15, 1, EventSeverity::Critical),
-            _ => return None,
-        };
-        let spare_lost = self.lose_random_spare(rng);
-        let mut applied_food = 0;
-        let mut applied_clothes = 0;
-        if spare_lost.is_none() {
-            applied_food = self.apply_otdeluxe_food_delta(-fallback_food);
-            if fallback_clothes != 0 {
-                applied_clothes = self.apply_otdeluxe_clothes_delta(-fallback_clothes);
-            }
+            serde_json::json!(selection.chance_threshold),
+        );
+        payload.insert(String::from("deltas"), serde_json::json!(outcome.deltas));
+        if let Some(afflicted) = outcome.afflictions.first() {
+            payload.insert(
+                String::from("affliction"),
+                afflicted
+                    .as_ref()
+                    .map_or(serde_json::Value::Null, otdeluxe_affliction_payload),
+            );
+        }
+        if let Some(lost_members) = outcome.lost_members {
+            payload.insert(
+                String::from("lost_members"),
+                serde_json::json!(lost_members),
+            );
+        }
+        if let Some(spare_lost) = outcome.spare_lost {
+            payload.insert(String::from("spare_lost"), serde_json::json!(spare_lost));
         }
-        let mut deltas = serde_json::Map::new();
-        deltas.insert(String::from("food_lbs"), serde_json::json!(applied_food));
-        deltas.insert(
-            String::from("clothes_sets"),
-            serde_json::json!(applied_clothes),
-        );
-        let mut payload = serde_json::Map::new();
-        payload.insert(String::from("event"), serde_json::json!("wagon_part_break"));
-        payload.insert(String::from("variant"), serde_json::json!(variant));
-        payload.insert(String::from("chance_roll"), serde_json::json!(chance_roll));
-        payload.insert(
-            String::from("chance_threshold"),
-            serde_json::json!(chance_threshold),
-        );
-        payload.insert(String::from("spare_lost"), serde_json::json!(spare_lost));
-        payload.insert(String::from("deltas"), serde_json::Value::Object(deltas));
-        let payload = serde_json::Value::Object(payload);
-        Some((severity, payload))
-    }
 
-    fn apply_otdeluxe_random_travel_hazard(
-        &mut self,
-        variant: &str,
-        chance_roll: f32,
-        chance_threshold: f32,
-    ) -> Option<(EventSeverity, serde_json::Value)> {
-        let (health_delta, food_delta, severity) = match variant {
-            "rough_trail" => (2, -5, EventSeverity::Warning),
-            _ => return None,
-        };
-        let applied_health = self.apply_otdeluxe_health_delta(health_delta);
-        let applied_food = self.apply_otdeluxe_food_delta(food_delta);
-        let payload = serde_json::json!({
-            "event": "travel_hazard",
-            "variant": variant,
-            "chance_roll": chance_roll,
-            "chance_threshold": chance_threshold,
-            "deltas": {
-                "health_general": applied_health,
-                "food_lbs": applied_food
-            }
-        });
-        Some((severity, payload))
+        Some((
+            variant.log_key(event_id),
+            variant.severity,
+            serde_json::Value::Object(payload),
+        ))
     }
 
-    fn apply_otdeluxe_random_affliction<R: Rng + ?Sized>(
+    fn apply_otdeluxe_affliction<R: Rng + ?Sized>(
         &mut self,
         rng: &mut R,
         kind: OtDeluxeAfflictionKind,
+        target: OtDeluxeAfflictionTarget,
     ) -> Option<OtDeluxeAfflictionOutcome> {
         let catalog = DiseaseCatalog::default_catalog();
-        self.apply_otdeluxe_random_affliction_with_catalog(catalog, rng, kind)
+        self.apply_otdeluxe_affliction_with_catalog(catalog, rng, kind, target)
     }
 
-    fn apply_otdeluxe_random_affliction_with_catalog<R: Rng + ?Sized>(
+    fn apply_otdeluxe_affliction_with_catalog<R: Rng + ?Sized>(
         &mut self,
         catalog: &DiseaseCatalog,
         rng: &mut R,
         kind: OtDeluxeAfflictionKind,
+        target: OtDeluxeAfflictionTarget,
     ) -> Option<OtDeluxeAfflictionOutcome> {
+        if let OtDeluxeAfflictionTarget::Member(index) = target
+            && !self
+                .ot_deluxe
+                .party
+                .members
+                .get(index)
+                .is_some_and(|member| member.alive)
+        {
+            return None;
+        }
         let policy = default_otdeluxe_policy();
         let disease_kind = match kind {
             OtDeluxeAfflictionKind::Illness => DiseaseKind::Illness,
@@ -10642,8 +11481,31 @@ impl GameState {
             |selected| selected.duration_for(&policy.affliction),
         );
         let disease_id = disease.map(|selected| selected.id.as_str());
+        let duration = self.otdeluxe_medic_duration(duration, kind, disease_id, policy);
         let party = &mut self.ot_deluxe.party;
-        let mut outcome = party.apply_affliction_random(rng, kind, duration, disease_id);
+        let mut outcome = match target {
+            OtDeluxeAfflictionTarget::Random => {
+                party.apply_affliction_random(rng, kind, duration, disease_id)
+            }
+            OtDeluxeAfflictionTarget::Member(member_index) => {
+                let died = party.members[member_index].apply_affliction(kind, duration, disease_id);
+                Some(OtDeluxeAfflictionOutcome {
+                    member_index,
+                    died,
+                    kind,
+                    disease_id: disease_id.map(str::to_string),
+                    display_key: None,
+                })
+            }
+        };
+        self.lower_afflicted_member_morale(outcome.as_ref(), policy);
+        if let Some(result) = outcome.as_ref().filter(|result| result.died) {
+            self.ot_deluxe.record_death(
+                result.member_index,
+                OtDeluxeDeathCause::affliction(kind, disease_id),
+                &policy.skills,
+            );
+        }
         if let (Some(selected), Some(ref mut result)) = (disease, outcome.as_mut()) {
             result.disease_id = Some(selected.id.clone());
             result.display_key = Some(selected.display_key.clone());
@@ -10654,12 +11516,63 @@ impl GameState {
         outcome
     }
 
-    fn lose_random_party_members<R: Rng + ?Sized>(&mut self, rng: &mut R, count: u8) -> Vec<usize> {
+    /// Shorten an affliction when a medic is on hand, tracing the cut.
+    fn otdeluxe_medic_duration(
+        &mut self,
+        duration: u8,
+        kind: OtDeluxeAfflictionKind,
+        disease_id: Option<&str>,
+        policy: &OtDeluxe90sPolicy,
+    ) -> u8 {
+        let Some(factor) = otdeluxe_skills::skill_factor(
+            &self.ot_deluxe.party,
+            OtDeluxeSkill::Medic,
+            &policy.skills,
+        ) else {
+            return duration;
+        };
+        let cut = otdeluxe_skills::scale_days(duration, &factor);
+        let chosen_id = disease_id.unwrap_or(match kind {
+            OtDeluxeAfflictionKind::Illness => "illness",
+            OtDeluxeAfflictionKind::Injury => "injury",
+        });
+        self.decision_traces_today
+            .push(otdeluxe_skills::modifier_trace(
+                "otdeluxe.skills.affliction_duration",
+                chosen_id,
+                f64::from(duration),
+                factor,
+                f64::from(cut),
+            ));
+        cut
+    }
+
+    fn lower_afflicted_member_morale(
+        &mut self,
+        outcome: Option<&OtDeluxeAfflictionOutcome>,
+        policy: &OtDeluxe90sPolicy,
+    ) {
+        let Some(result) = outcome.filter(|result| !result.died) else {
+            return;
+        };
+        if let Some(member) = self.ot_deluxe.party.members.get_mut(result.member_index) {
+            member.lower_morale(policy.skills.affliction_morale_loss);
+        }
+    }
+
+    fn lose_random_party_members<R: Rng + ?Sized>(
+        &mut self,
+        rng: &mut R,
+        count: u8,
+        policy: &OtDeluxeSkillPolicy,
+    ) -> Vec<usize> {
         let mut alive_indices = alive_member_indices(&self.ot_deluxe.party.members);
         let mut lost = Vec::new();
         while !alive_indices.is_empty() && lost.len() < usize::from(count) {
             let idx = rng.gen_range(0..alive_indices.len());
             let member_idx = alive_indices.swap_remove(idx);
+            self.ot_deluxe
+                .record_death(member_idx, OtDeluxeDeathCause::Lost, policy);
             if let Some(member) = self.ot_deluxe.party.members.get_mut(member_idx) {
                 member.alive = false;
                 lost.push(member_idx);
@@ -10724,42 +11637,95 @@ impl GameState {
         (wheels_delta, axles_delta, tongues_delta)
     }
 
-    const fn apply_otdeluxe_no_grass_loss(&mut self) -> (i16, i16) {
-        if self.ot_deluxe.oxen.healthy > 0 {
-            self.ot_deluxe.oxen.healthy = self.ot_deluxe.oxen.healthy.saturating_sub(1);
-            self.ot_deluxe.oxen.sick = self.ot_deluxe.oxen.sick.saturating_add(1);
-            (-1, 1)
-        } else if self.ot_deluxe.oxen.sick > 0 {
-            self.ot_deluxe.oxen.sick = self.ot_deluxe.oxen.sick.saturating_sub(1);
-            (0, -1)
-        } else {
-            (0, 0)
+    fn apply_otdeluxe_oxen_wander(&mut self) -> (i16, i16) {
+        self.ot_deluxe.sync_herd(default_otdeluxe_policy());
+        let before = self.ot_deluxe.oxen;
+        self.ot_deluxe.herd.lose_one();
+        self.ot_deluxe.oxen = self.ot_deluxe.herd.counts();
+        Self::oxen_count_delta(before, self.ot_deluxe.oxen)
+    }
+
+    fn apply_otdeluxe_oxen_sickness(&mut self) -> (i16, i16) {
+        let policy = default_otdeluxe_policy();
+        self.ot_deluxe.sync_herd(policy);
+        let before = self.ot_deluxe.oxen;
+        if self
+            .ot_deluxe
+            .herd
+            .sicken_weakest(&policy.oxen.herd)
+            .is_none()
+        {
+            self.ot_deluxe.herd.lose_one();
         }
+        self.ot_deluxe.oxen = self.ot_deluxe.herd.counts();
+        Self::oxen_count_delta(before, self.ot_deluxe.oxen)
     }
 
-    const fn apply_otdeluxe_oxen_wander(&mut self) -> (i16, i16) {
-        if self.ot_deluxe.oxen.healthy > 0 {
-            self.ot_deluxe.oxen.healthy = self.ot_deluxe.oxen.healthy.saturating_sub(1);
-            (-1, 0)
-        } else if self.ot_deluxe.oxen.sick > 0 {
-            self.ot_deluxe.oxen.sick = self.ot_deluxe.oxen.sick.saturating_sub(1);
-            (0, -1)
-        } else {
-            (0, 0)
+    fn oxen_count_delta(before: OtDeluxeOxenState, after: OtDeluxeOxenState) -> (i16, i16) {
+        let delta =
+            |from: u16, to: u16| i16::try_from(i32::from(to) - i32::from(from)).unwrap_or_default();
+        (
+            delta(before.healthy, after.healthy),
+            delta(before.sick, after.sick),
+        )
+    }
+
+    /// Run the herd's daily grazing, work and recovery, then write the
+    /// resulting head counts back.
+    pub(crate) fn tick_otdeluxe_herd(&mut self) -> OtDeluxeHerdTick {
+        if self.mechanical_policy != MechanicalPolicyId::OtDeluxe90s {
+            return OtDeluxeHerdTick::default();
+        }
+        let policy = default_otdeluxe_policy();
+        self.ot_deluxe.sync_herd(policy);
+        let day = OtDeluxeHerdDay {
+            season: self.ot_deluxe.season,
+            pace: self.ot_deluxe.pace,
+            resting: matches!(self.intent.pending, DayIntent::Rest),
+            working: self.otdeluxe_team_pulling_today(),
+        };
+        let tick = self.ot_deluxe.herd.tick_day(&policy.oxen.herd, day);
+        self.ot_deluxe.oxen = self.ot_deluxe.herd.counts();
+        for death in &tick.died {
+            let key = match death.cause {
+                OtDeluxeOxDeathCause::Exhaustion => LOG_OT_OX_EXHAUSTED,
+                OtDeluxeOxDeathCause::Starvation => LOG_OT_OX_STARVED,
+            };
+            self.logs.push(String::from(key));
         }
+        tick
     }
 
-    const fn apply_otdeluxe_oxen_sickness(&mut self) -> (i16, i16) {
-        if self.ot_deluxe.oxen.healthy > 0 {
-            self.ot_deluxe.oxen.healthy = self.ot_deluxe.oxen.healthy.saturating_sub(1);
-            self.ot_deluxe.oxen.sick = self.ot_deluxe.oxen.sick.saturating_add(1);
-            (-1, 1)
-        } else if self.ot_deluxe.oxen.sick > 0 {
-            self.ot_deluxe.oxen.sick = self.ot_deluxe.oxen.sick.saturating_sub(1);
-            (0, -1)
-        } else {
-            (0, 0)
+    /// Whether today's day can move the wagon: a plain travel day with no
+    /// stop, wait or multi-day action holding the party in place.
+    fn otdeluxe_team_pulling_today(&self) -> bool {
+        matches!(self.intent.pending, DayIntent::Continue)
+            && self.intent.action_days_remaining == 0
+            && self.wait.ferry_wait_days_remaining == 0
+            && self.wait.drying_days_remaining == 0
+            && self.pending_crossing.is_none()
+            && !self.ot_deluxe.crossing.choice_pending
+            && self.ot_deluxe.store.pending_node.is_none()
+            && self.ot_deluxe.route.pending_prompt.is_none()
+    }
+
+    /// Rest days lift every living member's morale.
+    pub(crate) fn tick_otdeluxe_party_morale(&mut self) {
+        if self.mechanical_policy != MechanicalPolicyId::OtDeluxe90s
+            || !matches!(self.intent.pending, DayIntent::Rest)
+        {
+            return;
         }
+        let gain = default_otdeluxe_policy().skills.rest_morale_gain;
+        self.ot_deluxe.party.raise_morale(gain);
+    }
+
+    /// The herd as it stands, reconciled with the current head counts.
+    #[must_use]
+    pub fn otdeluxe_herd(&self) -> OtDeluxeHerd {
+        let mut herd = self.ot_deluxe.herd.clone();
+        herd.reconcile(self.ot_deluxe.oxen, &default_otdeluxe_policy().oxen.herd);
+        herd
     }
 
     fn apply_u16_delta(value: &mut u16, delta: i32) -> i32 {
@@ -10829,7 +11795,7 @@ impl GameState {
 
     pub(crate) fn process_encounter_flow(
         &mut self,
-        rng_bundle: Option<&Rc<RngBundle>>,
+        rng_bundle: Option<&Arc<RngBundle>>,
         breakdown_started: bool,
     ) -> Option<(bool, String, bool)> {
         if self.encounters.occurred_today || self.encounters_today >= MAX_ENCOUNTERS_PER_DAY {
@@ -10939,7 +11905,7 @@ impl GameState {
         self.push_log(LOG_TRAVEL_PARTIAL);
     }
 
-    fn should_trigger_encounter(&self, rng_bundle: Option<&Rc<RngBundle>>) -> bool {
+    fn should_trigger_encounter(&self, rng_bundle: Option<&Arc<RngBundle>>) -> bool {
         let Some(bundle) = rng_bundle else {
             return false;
         };
@@ -10952,7 +11918,7 @@ impl GameState {
 
     fn maybe_reroll_encounter(
         &mut self,
-        rng_bundle: Option<&Rc<RngBundle>>,
+        rng_bundle: Option<&Arc<RngBundle>>,
         recent_snapshot: &[RecentEncounter],
         mut rotation_backlog: VecDeque<String>,
         encounter: Option<Encounter>,
@@ -11046,7 +12012,7 @@ impl GameState {
             } else {
                 (
                     self.journey_breakdown.base,
-                    self.journey_breakdown.beta,
+                    self.journey_breakdown.beta * self.vehicle_modifiers().wear_beta_mult,
                     self.journey_pace_factor(),
                     0.35,
                 )
@@ -11071,6 +12037,10 @@ impl GameState {
             let policy = default_otdeluxe_policy();
             breakdown_chance *=
                 otdeluxe_mobility_failure_mult(self.ot_deluxe.mods.occupation, policy);
+            breakdown_chance *= otdeluxe_cargo::overload_breakdown_mult(
+                &policy.breakdown,
+                &self.ot_deluxe.cargo_load(policy),
+            );
         }
         let max_chance = sanitize_breakdown_max_chance(max_chance);
         breakdown_chance = breakdown_chance
@@ -11084,8 +12054,9 @@ impl GameState {
             return false;
         }
 
+        let part_weights = self.vehicle_part_weights();
         let (part, trace) = self.breakdown_rng().map_or((Part::Tire, None), |mut rng| {
-            select_breakdown_part_with_trace(&mut *rng, &self.journey_part_weights)
+            select_breakdown_part_with_trace(&mut *rng, &part_weights)
         });
         if let Some(trace) = trace {
             self.decision_traces_today.push(trace);
@@ -11131,6 +12102,14 @@ impl GameState {
         self.vehicle_roll()
     }
 
+    fn log_encounter_choice(&mut self, record: EncounterChoiceRecord) {
+        if self.encounter_log.len() >= ENCOUNTER_LOG_LIMIT {
+            let overflow = self.encounter_log.len() + 1 - ENCOUNTER_LOG_LIMIT;
+            self.encounter_log.drain(..overflow);
+        }
+        self.encounter_log.push(record);
+    }
+
     pub fn apply_choice(&mut self, idx: usize) {
         let Some(enc) = self.current_encounter.clone() else {
             self.finalize_encounter();
@@ -11138,6 +12117,13 @@ impl GameState {
         };
 
         if let Some(choice) = enc.choices.get(idx) {
+            self.log_encounter_choice(EncounterChoiceRecord {
+                day: self.day,
+                encounter_id: enc.id.clone(),
+                name: enc.name.clone(),
+                choice_index: idx,
+                choice_label: choice.label.clone(),
+            });
             #[cfg(debug_assertions)]
             let (hp_before, sanity_before) = (self.stats.hp, self.stats.sanity);
 
@@ -11285,6 +12271,10 @@ impl GameState {
                 return;
             }
 
+            if self.try_mechanic_repair(&VehicleConfig::default_config().mechanic_hook) {
+                return;
+            }
+
             let day_started = u32::try_from(breakdown.day_started).unwrap_or(0);
             if self.day.saturating_sub(day_started) >= 1 {
                 self.vehicle
@@ -11308,11 +12298,55 @@ impl GameState {
             } else {
                 self.day_state.travel.travel_blocked = true;
             }
+        } else if self.mechanic_wait_days > 0 {
+            self.mechanic_wait_days -= 1;
+            self.day_state.travel.travel_blocked = true;
         } else {
             self.day_state.travel.travel_blocked = false;
         }
     }
 
+    /// Roll for a passing mechanic to clear the current breakdown.
+    ///
+    /// A successful roll clears the breakdown without jury-rig damage, but the
+    /// party stays put for `day_cost` days, starting today.
+    pub(crate) fn try_mechanic_repair(&mut self, hook: &MechanicHook) -> bool {
+        if !hook.enabled || self.breakdown.is_none() {
+            return false;
+        }
+        let roll = self
+            .breakdown_rng()
+            .map_or(1.0, |mut rng| rng.r#gen::<f32>());
+        if roll >= hook.chance_clear.clamp(0.0, 1.0) {
+            return false;
+        }
+        self.clear_breakdown("mechanic");
+        self.day_state.travel.travel_blocked = hook.day_cost > 0;
+        self.mechanic_wait_days = hook.day_cost.saturating_sub(1);
+        self.push_log("log.breakdown-mechanic");
+        true
+    }
+
+    /// Clear the active breakdown and record how it was resolved.
+    pub(crate) fn clear_breakdown(&mut self, resolution: &str) {
+        let Some(breakdown) = self.breakdown.take() else {
+            return;
+        };
+        self.day_state.travel.travel_blocked = false;
+        self.last_breakdown_part = None;
+        self.push_event(
+            EventKind::BreakdownResolved,
+            EventSeverity::Info,
+            DayTagSet::new(),
+            None,
+            None,
+            serde_json::json!({
+                "part": breakdown.part.key(),
+                "resolution": resolution
+            }),
+        );
+    }
+
     const fn consume_otdeluxe_spare_for_breakdown(&mut self, part: Part) -> bool {
         consume_otdeluxe_spare_for_breakdown(&mut self.ot_deluxe.inventory, part)
     }
@@ -11404,6 +12438,7 @@ impl GameState {
     pub fn clear_illness_penalty(&mut self) {
         let was_ill = self.illness_days_remaining > 0 || self.illness_travel_penalty < 1.0;
         self.illness_days_remaining = 0;
+        self.illness_id = None;
         self.illness_travel_penalty = 1.0;
         if was_ill {
             self.push_log(LOG_DISEASE_RECOVER);
@@ -11733,6 +12768,103 @@ impl GameState {
         self.start_of_day();
     }
 
+    /// Spare room the vehicle will have once the cart's upgrades are installed.
+    fn store_cart_spare_capacity(
+        &self,
+        cart: &crate::store::Cart,
+        store: &crate::store::Store,
+    ) -> i32 {
+        let mut vehicle = self.vehicle.clone();
+        for line in &cart.lines {
+            if let Some(item) = store.find_item(&line.item_id) {
+                for upgrade in &item.grants.vehicle_upgrades {
+                    vehicle.install_upgrade(*upgrade);
+                }
+            }
+        }
+        if self.mechanical_policy == MechanicalPolicyId::OtDeluxe90s {
+            VehicleModifiers::default().spare_capacity
+        } else {
+            VehicleConfig::default_config()
+                .modifiers(&vehicle)
+                .spare_capacity
+        }
+    }
+
+    /// Spares that still fit on top of the inventory and the cart's spares,
+    /// counting room from upgrades in the cart; stores stop adding spares at zero.
+    #[must_use]
+    pub fn store_cart_spare_room(
+        &self,
+        cart: &crate::store::Cart,
+        store: &crate::store::Store,
+    ) -> i32 {
+        let in_cart: i32 = cart
+            .lines
+            .iter()
+            .filter_map(|line| {
+                store
+                    .find_item(&line.item_id)
+                    .map(|item| item.grants.spare_count() * line.qty)
+            })
+            .sum();
+        (self.store_cart_spare_capacity(cart, store) - self.total_spares() - in_cart).max(0)
+    }
+
+    /// Trim the cart's spare parts to the room the vehicle will have once the
+    /// cart's upgrades are installed, so checkout never charges for spares that
+    /// would be thrown away. Returns the number of spares dropped.
+    pub fn fit_store_cart(
+        &self,
+        cart: &mut crate::store::Cart,
+        store: &crate::store::Store,
+        discount_pct: f64,
+    ) -> i32 {
+        let room = self.store_cart_spare_capacity(cart, store) - self.total_spares();
+        crate::store::fit_cart_spares(cart, store, room, discount_pct)
+    }
+
+    /// Stow spare parts up to the vehicle's spare capacity, in tire, battery,
+    /// alternator, pump order. Every source of spares goes through here.
+    /// Returns the number that did not fit.
+    pub fn stow_spares(&mut self, granted: Spares) -> i32 {
+        let mut room = (self.vehicle_modifiers().spare_capacity - self.total_spares()).max(0);
+        let mut dropped = 0;
+        let spares = &mut self.inventory.spares;
+        for (count, granted) in [
+            (&mut spares.tire, granted.tire),
+            (&mut spares.battery, granted.battery),
+            (&mut spares.alt, granted.alt),
+            (&mut spares.pump, granted.pump),
+        ] {
+            let granted = granted.max(0);
+            let stowed = granted.min(room);
+            *count += stowed;
+            room -= stowed;
+            dropped += granted - stowed;
+        }
+        dropped
+    }
+
+    /// Drop spares, pumps first, until the inventory fits the vehicle again.
+    fn trim_spares_to_capacity(&mut self) {
+        let mut excess = self.total_spares() - self.vehicle_modifiers().spare_capacity;
+        let spares = &mut self.inventory.spares;
+        for count in [
+            &mut spares.pump,
+            &mut spares.alt,
+            &mut spares.battery,
+            &mut spares.tire,
+        ] {
+            if excess <= 0 {
+                break;
+            }
+            let dropped = excess.min(*count);
+            *count -= dropped;
+            excess -= dropped;
+        }
+    }
+
     /// Apply store purchase effects
     pub fn apply_store_purchase(
         &mut self,
@@ -11756,10 +12888,16 @@ impl GameState {
         // Apply grants
         self.stats.supplies += grants.supplies;
         self.stats.credibility += grants.credibility;
-        self.inventory.spares.tire += grants.spare_tire;
-        self.inventory.spares.battery += grants.spare_battery;
-        self.inventory.spares.alt += grants.spare_alt;
-        self.inventory.spares.pump += grants.spare_pump;
+        for upgrade in &grants.vehicle_upgrades {
+            self.vehicle.install_upgrade(*upgrade);
+        }
+        self.stow_spares(Spares {
+            tire: grants.spare_tire,
+            battery: grants.spare_battery,
+            alt: grants.spare_alt,
+            pump: grants.spare_pump,
+        });
+        self.inventory.medicine += grants.medicine;
 
         // Add tags
         for tag in tags {
@@ -11781,15 +12919,75 @@ impl GameState {
         lines: &[OtDeluxeStoreLineItem],
     ) -> Result<OtDeluxeStoreReceipt, OtDeluxeStoreError> {
         let policy = default_otdeluxe_policy();
-        crate::otdeluxe_store::apply_purchase(
+        let receipt = crate::otdeluxe_store::apply_purchase(
             &policy.store,
             node_index,
             &mut self.ot_deluxe.inventory,
             &mut self.ot_deluxe.oxen,
             lines,
+        )?;
+        self.ot_deluxe.sync_herd(policy);
+        Ok(receipt)
+    }
+
+    /// Wagon load the pending store purchase would leave the party with.
+    #[must_use]
+    pub fn otdeluxe_cargo_after_purchase(
+        &self,
+        lines: &[OtDeluxeStoreLineItem],
+    ) -> OtDeluxeCargoLoad {
+        otdeluxe_cargo::load_after_purchase(
+            default_otdeluxe_policy(),
+            &self.ot_deluxe.inventory,
+            self.ot_deluxe.oxen,
+            lines,
         )
     }
 
+    #[must_use]
+    pub fn otdeluxe_cargo_load(&self) -> OtDeluxeCargoLoad {
+        self.ot_deluxe.cargo_load(default_otdeluxe_policy())
+    }
+
+    /// Throw cargo off the wagon to lighten the load. Takes no time.
+    pub fn discard_otdeluxe_cargo(&mut self, item: OtDeluxeCargoItem, quantity: u16) -> u16 {
+        if self.mechanical_policy != MechanicalPolicyId::OtDeluxe90s {
+            return 0;
+        }
+        otdeluxe_cargo::discard(&mut self.ot_deluxe.inventory, item, quantity)
+    }
+
+    /// Give medicine to an afflicted `OTDeluxe` party member.
+    ///
+    /// # Errors
+    ///
+    /// See [`treatment::treat_otdeluxe_member`]; outside `OTDeluxe` this
+    /// returns [`TreatmentError::WrongMechanics`].
+    pub fn treat_otdeluxe_member(
+        &mut self,
+        member_index: usize,
+    ) -> Result<OtDeluxeTreatmentOutcome, TreatmentError> {
+        if self.mechanical_policy != MechanicalPolicyId::OtDeluxe90s {
+            return Err(TreatmentError::WrongMechanics);
+        }
+        let policy = default_otdeluxe_policy();
+        treatment::treat_otdeluxe_member(
+            self,
+            DiseaseCatalog::default_catalog(),
+            &policy.treatment,
+            member_index,
+        )
+    }
+
+    /// Take medicine against the current illness in Dystrail mode.
+    ///
+    /// # Errors
+    ///
+    /// See [`treatment::treat_illness`].
+    pub fn treat_illness(&mut self) -> Result<u32, TreatmentError> {
+        treatment::treat_illness(self, DiseaseCatalog::default_catalog())
+    }
+
     pub fn set_otdeluxe_store_purchase(&mut self, lines: Vec<OtDeluxeStoreLineItem>) -> bool {
         if self.mechanical_policy != MechanicalPolicyId::OtDeluxe90s {
             return false;
@@ -11839,6 +13037,39 @@ impl GameState {
         )
     }
 
+    /// Next river the `OTDeluxe` party must cross and how it runs today.
+    #[must_use]
+    pub fn otdeluxe_upcoming_river(&self) -> Option<(OtDeluxeRiver, OtDeluxeRiverState)> {
+        let next_idx = usize::try_from(self.crossings_completed).ok()?;
+        let river = otdeluxe_crossings::river_for_index(next_idx)?;
+        let policy = default_otdeluxe_policy();
+        let river_state = otdeluxe_crossings::derive_river_state(
+            &policy.crossings,
+            river,
+            self.ot_deluxe.season,
+            self.ot_deluxe.weather.rain_accum,
+        );
+        Some((river, river_state))
+    }
+
+    /// Party health label under the active mechanics.
+    #[must_use]
+    pub fn party_health_label(&self) -> HealthLabel {
+        if self.mechanical_policy != MechanicalPolicyId::OtDeluxe90s {
+            return self.general_strain_label(&StrainConfig::default());
+        }
+        default_otdeluxe_policy()
+            .health
+            .label_ranges
+            .label(self.ot_deluxe.health_general)
+    }
+
+    /// Talk to people at the current landmark; does not advance the day.
+    #[must_use]
+    pub fn talk_at_landmark(&self) -> Option<TalkOutcome> {
+        dialogue::talk(dialogue::catalog(), self)
+    }
+
     fn resolve_otdeluxe_dalles_choice(&mut self, choice: OtDeluxeDallesChoice) -> bool {
         let policy = default_otdeluxe_policy();
         if matches!(choice, OtDeluxeDallesChoice::Barlow)
@@ -11915,7 +13146,7 @@ impl GameState {
                 self.apply_otdeluxe_crossing_losses(policy.dalles.loss_ratio)
             }
         };
-        let drownings = self.apply_otdeluxe_drownings(&drowned_indices);
+        let drownings = self.apply_otdeluxe_drownings(&drowned_indices, &policy.skills);
         self.emit_otdeluxe_dalles_resolved(
             choice_id,
             outcome,
@@ -12039,3 +13270,78 @@ impl GameState {
         handled
     }
 }
+
+impl RandomEventHost for GameState {
+    fn adjust_health(&mut self, delta: i32) -> i32 {
+        self.apply_otdeluxe_health_delta(delta)
+    }
+
+    fn inventory_level(&self, item: OtDeluxeRandomEventItem) -> u16 {
+        let inventory = &self.ot_deluxe.inventory;
+        match item {
+            OtDeluxeRandomEventItem::FoodLbs => inventory.food_lbs,
+            OtDeluxeRandomEventItem::Bullets => inventory.bullets,
+            OtDeluxeRandomEventItem::ClothesSets => inventory.clothes_sets,
+            OtDeluxeRandomEventItem::SparesWheels => u16::from(inventory.spares_wheels),
+            OtDeluxeRandomEventItem::SparesAxles => u16::from(inventory.spares_axles),
+            OtDeluxeRandomEventItem::SparesTongues => u16::from(inventory.spares_tongues),
+        }
+    }
+
+    fn adjust_inventory(&mut self, item: OtDeluxeRandomEventItem, delta: i32) -> i32 {
+        let spare_delta = i16::try_from(delta.clamp(i32::from(i16::MIN), i32::from(i16::MAX)))
+            .unwrap_or_default();
+        match item {
+            OtDeluxeRandomEventItem::FoodLbs => self.apply_otdeluxe_food_delta(delta),
+            OtDeluxeRandomEventItem::Bullets => self.apply_otdeluxe_bullets_delta(delta),
+            OtDeluxeRandomEventItem::ClothesSets => self.apply_otdeluxe_clothes_delta(delta),
+            OtDeluxeRandomEventItem::SparesWheels => {
+                i32::from(self.apply_otdeluxe_spares_delta(spare_delta, 0, 0).0)
+            }
+            OtDeluxeRandomEventItem::SparesAxles => {
+                i32::from(self.apply_otdeluxe_spares_delta(0, spare_delta, 0).1)
+            }
+            OtDeluxeRandomEventItem::SparesTongues => {
+                i32::from(self.apply_otdeluxe_spares_delta(0, 0, spare_delta).2)
+            }
+        }
+    }
+
+    fn add_delay_days(&mut self, days: u8) -> u8 {
+        let travel = &mut self.ot_deluxe.travel;
+        let before = travel.delay_days_remaining;
+        travel.delay_days_remaining = before.saturating_add(days);
+        if travel.wagon_state == OtDeluxeWagonState::Moving {
+            travel.wagon_state = OtDeluxeWagonState::Delayed;
+        }
+        travel.delay_days_remaining - before
+    }
+
+    fn afflict<R: Rng + ?Sized>(
+        &mut self,
+        rng: &mut R,
+        kind: OtDeluxeAfflictionKind,
+        target: OtDeluxeAfflictionTarget,
+    ) -> Option<OtDeluxeAfflictionOutcome> {
+        self.apply_otdeluxe_affliction(rng, kind, target)
+    }
+
+    fn lose_members<R: Rng + ?Sized>(&mut self, rng: &mut R, count: u8) -> Vec<usize> {
+        self.lose_random_party_members(rng, count, &default_otdeluxe_policy().skills)
+    }
+
+    fn change_oxen(&mut self, change: OtDeluxeOxenChange) -> (i16, i16) {
+        match change {
+            OtDeluxeOxenChange::Lose => self.apply_otdeluxe_oxen_wander(),
+            OtDeluxeOxenChange::Sicken => self.apply_otdeluxe_oxen_sickness(),
+        }
+    }
+
+    fn spoil_grazing(&mut self, days: u8) -> u8 {
+        self.ot_deluxe.herd.spoil_grazing(days)
+    }
+
+    fn lose_spare<R: Rng + ?Sized>(&mut self, rng: &mut R) -> Option<&'static str> {
+        self.lose_random_spare(rng)
+    }
+}
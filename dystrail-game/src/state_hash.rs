@@ -0,0 +1,63 @@
+//! Platform-independent fingerprint of a [`GameState`].
+
+use crate::state::GameState;
+use serde_json::Value;
+use std::hash::Hasher;
+use twox_hash::XxHash64;
+
+/// Hash the serialized state with object keys sorted, as 16 hex digits.
+///
+/// Native and WASM builds produce the same string for equal states, so it can
+/// be compared across the JavaScript boundary without losing precision.
+#[must_use]
+pub fn state_hash(state: &GameState) -> String {
+    let mut hasher = XxHash64::with_seed(0);
+    match serde_json::to_value(state) {
+        Ok(value) => write_canonical(&value, &mut hasher),
+        Err(err) => hasher.write(err.to_string().as_bytes()),
+    }
+    format!("{:016x}", hasher.finish())
+}
+
+fn write_canonical(value: &Value, hasher: &mut XxHash64) {
+    match value {
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            hasher.write(b"{");
+            for key in keys {
+                hasher.write(Value::from(key.as_str()).to_string().as_bytes());
+                hasher.write(b":");
+                write_canonical(&map[key], hasher);
+                hasher.write(b",");
+            }
+            hasher.write(b"}");
+        }
+        Value::Array(items) => {
+            hasher.write(b"[");
+            for item in items {
+                write_canonical(item, hasher);
+                hasher.write(b",");
+            }
+            hasher.write(b"]");
+        }
+        scalar => hasher.write(scalar.to_string().as_bytes()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_is_stable_and_sensitive_to_fields() {
+        let state = GameState::default();
+        let hash = state_hash(&state);
+        assert_eq!(hash.len(), 16);
+        assert_eq!(hash, state_hash(&state.clone()));
+
+        let mut changed = state;
+        changed.stats.hp -= 1;
+        assert_ne!(hash, state_hash(&changed));
+    }
+}
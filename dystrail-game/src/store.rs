@@ -1,5 +1,6 @@
 //! Store management and shopping cart
 use crate::numbers::{ceil_f64_to_i64, i64_to_f64};
+use crate::vehicle::VehicleUpgrade;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -56,9 +57,22 @@ pub struct Grants {
     #[serde(default)]
     pub spare_pump: i32,
     #[serde(default)]
+    pub medicine: i32,
+    /// Vehicle upgrades installed on purchase.
+    #[serde(default)]
+    pub vehicle_upgrades: Vec<VehicleUpgrade>,
+    #[serde(default)]
     pub enabled: bool,
 }
 
+impl Grants {
+    /// Spare parts granted per unit bought.
+    #[must_use]
+    pub const fn spare_count(&self) -> i32 {
+        self.spare_tire + self.spare_battery + self.spare_alt + self.spare_pump
+    }
+}
+
 /// A line item in the shopping cart.
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CartLine {
@@ -214,6 +228,29 @@ pub fn calculate_cart_total(cart: &Cart, store: &Store, discount_pct: f64) -> i6
     total
 }
 
+/// Trim spare-part lines, in cart order, so the cart grants at most `room`
+/// spares, then re-price it. Returns the number of spares dropped.
+pub fn fit_cart_spares(cart: &mut Cart, store: &Store, room: i32, discount_pct: f64) -> i32 {
+    let mut room = room.max(0);
+    let mut dropped = 0;
+    for line in &mut cart.lines {
+        let per_unit = store
+            .find_item(&line.item_id)
+            .map_or(0, |item| item.grants.spare_count());
+        if per_unit <= 0 {
+            continue;
+        }
+        let fits = (room / per_unit).min(line.qty);
+        dropped += (line.qty - fits) * per_unit;
+        room -= fits * per_unit;
+        line.qty = fits;
+        line.quantity = fits;
+    }
+    cart.lines.retain(|line| line.qty > 0);
+    cart.total_cents = calculate_cart_total(cart, store, discount_pct);
+    dropped
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -268,6 +305,37 @@ mod tests {
         assert!(cart.is_empty());
     }
 
+    #[test]
+    fn fit_cart_spares_drops_what_does_not_fit_and_reprices() {
+        let mut store = mock_store();
+        store.items.push(StoreItem {
+            id: "tire".into(),
+            name: "Tire".into(),
+            desc: "Round".into(),
+            price_cents: 2_000,
+            unique: false,
+            max_qty: 9,
+            grants: Grants {
+                spare_tire: 1,
+                ..Grants::default()
+            },
+            tags: vec![],
+            category: "spares".into(),
+        });
+        let mut cart = Cart::new();
+        cart.add_item("rope", 1);
+        cart.add_item("tire", 5);
+
+        assert_eq!(fit_cart_spares(&mut cart, &store, 2, 0.0), 3);
+        assert_eq!(cart.get_quantity("tire"), 2);
+        assert_eq!(cart.get_quantity("rope"), 1);
+        assert_eq!(cart.total_cents, 1_000 + 2 * 2_000);
+
+        assert_eq!(fit_cart_spares(&mut cart, &store, 0, 0.0), 2);
+        assert!(cart.find_line("tire").is_none());
+        assert_eq!(cart.total_cents, 1_000);
+    }
+
     #[test]
     fn store_lookup_variants() {
         let store = mock_store();
@@ -3,7 +3,7 @@
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-use crate::journey::{EventDecisionTrace, RollValue, WeightedCandidate};
+use crate::journey::{EventDecisionTrace, RollScale, RollValue, WeightedCandidate};
 use crate::state::GameState;
 
 const TRADE_MAX_OXEN: u32 = 2;
@@ -168,6 +168,7 @@ fn record_trade_trace(
     let trace = EventDecisionTrace {
         pool_id: pool_id.to_string(),
         roll: RollValue::U32(u32::try_from(roll).unwrap_or(0)),
+        roll_scale: RollScale::Index,
         candidates,
         chosen_id: chosen.key().to_string(),
     };
@@ -413,6 +414,7 @@ mod tests {
             spares_wheels: 2,
             spares_axles: 2,
             spares_tongues: 2,
+            medicine: 0,
         };
         state
     }
@@ -429,6 +431,7 @@ mod tests {
             spares_wheels: 1,
             spares_axles: 1,
             spares_tongues: 1,
+            medicine: 0,
         };
 
         let mut rng = SmallRng::seed_from_u64(7);
@@ -0,0 +1,271 @@
+//! Medicine and treatment of illnesses and injuries.
+//!
+//! `OTDeluxe` parties treat one afflicted member at a time: a dose shortens
+//! the affliction and marks the member as treated for `FatalityModifier::Treated`.
+//! Dystrail mode treats the traveler's current illness. Either way the
+//! disease must list medicine among its `treatments`.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::constants::MEDICINE_ILLNESS_DAYS_DIVISOR;
+use crate::disease::{DiseaseCatalog, DiseaseTreatment};
+use crate::journey::MechanicalPolicyId;
+use crate::mechanics::otdeluxe90s::OtDeluxeTreatmentPolicy;
+use crate::numbers::round_f32_to_i32;
+use crate::state::GameState;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum TreatmentError {
+    #[error("no party member at index {0}")]
+    UnknownMember(usize),
+    #[error("nobody needs treatment")]
+    NotAfflicted,
+    #[error("the affliction is already being treated")]
+    AlreadyTreated,
+    #[error("medicine does not help this affliction")]
+    Untreatable,
+    #[error("not enough medicine")]
+    NoMedicine,
+    #[error("this treatment is not part of the active mechanics")]
+    WrongMechanics,
+}
+
+/// Result of treating an `OTDeluxe` party member.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OtDeluxeTreatmentOutcome {
+    pub member_index: usize,
+    pub days_before: u8,
+    pub days_after: u8,
+    pub medicine_used: u16,
+}
+
+/// Give medicine to a sick or injured party member.
+///
+/// # Errors
+///
+/// Returns an error when the member is unknown, dead or well, already
+/// treated, suffering from a disease medicine does not help, or when the
+/// wagon is short on medicine.
+pub fn treat_otdeluxe_member(
+    state: &mut GameState,
+    catalog: &DiseaseCatalog,
+    policy: &OtDeluxeTreatmentPolicy,
+    member_index: usize,
+) -> Result<OtDeluxeTreatmentOutcome, TreatmentError> {
+    let member = state
+        .ot_deluxe
+        .party
+        .members
+        .get(member_index)
+        .ok_or(TreatmentError::UnknownMember(member_index))?;
+    if !member.alive || !member.has_affliction() {
+        return Err(TreatmentError::NotAfflicted);
+    }
+    if member.treated {
+        return Err(TreatmentError::AlreadyTreated);
+    }
+    let disease_id = if member.is_sick() {
+        member.illness_id.as_deref()
+    } else {
+        member.injury_id.as_deref()
+    };
+    if disease_id
+        .and_then(|id| catalog.find_by_id(id))
+        .is_some_and(|disease| !disease.treatable_by(DiseaseTreatment::Medicine))
+    {
+        return Err(TreatmentError::Untreatable);
+    }
+    let doses = policy.medicine_per_treatment;
+    if state.ot_deluxe.inventory.medicine < doses {
+        return Err(TreatmentError::NoMedicine);
+    }
+
+    state.ot_deluxe.inventory.medicine -= doses;
+    let member = &mut state.ot_deluxe.party.members[member_index];
+    let days = if member.is_sick() {
+        &mut member.sick_days_remaining
+    } else {
+        &mut member.injured_days_remaining
+    };
+    let days_before = *days;
+    let scaled = round_f32_to_i32(f32::from(days_before) * policy.duration_mult.clamp(0.0, 1.0));
+    *days = u8::try_from(scaled.max(1)).unwrap_or(days_before);
+    let days_after = *days;
+    member.treated = true;
+    Ok(OtDeluxeTreatmentOutcome {
+        member_index,
+        days_before,
+        days_after,
+        medicine_used: doses,
+    })
+}
+
+/// Take a dose of medicine against the traveler's illness in Dystrail mode,
+/// returning the illness days left.
+///
+/// # Errors
+///
+/// Returns an error under `OTDeluxe` mechanics, when the traveler is well,
+/// the illness does not respond to medicine, or there is no medicine.
+pub fn treat_illness(
+    state: &mut GameState,
+    catalog: &DiseaseCatalog,
+) -> Result<u32, TreatmentError> {
+    if state.mechanical_policy == MechanicalPolicyId::OtDeluxe90s {
+        return Err(TreatmentError::WrongMechanics);
+    }
+    if state.illness_days_remaining == 0 {
+        return Err(TreatmentError::NotAfflicted);
+    }
+    if state
+        .illness_id
+        .as_deref()
+        .and_then(|id| catalog.find_by_id(id))
+        .is_some_and(|disease| !disease.treatable_by(DiseaseTreatment::Medicine))
+    {
+        return Err(TreatmentError::Untreatable);
+    }
+    if state.inventory.medicine <= 0 {
+        return Err(TreatmentError::NoMedicine);
+    }
+    state.inventory.medicine -= 1;
+    state.illness_days_remaining =
+        (state.illness_days_remaining / MEDICINE_ILLNESS_DAYS_DIVISOR.max(1)).max(1);
+    Ok(state.illness_days_remaining)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disease::{DiseaseDef, DiseaseEffects, DiseaseKind};
+    use crate::otdeluxe_state::{OtDeluxeAfflictionKind, OtDeluxePartyState};
+
+    fn disease(id: &str, treatments: Vec<DiseaseTreatment>) -> DiseaseDef {
+        DiseaseDef {
+            id: id.into(),
+            kind: DiseaseKind::Illness,
+            display_key: format!("disease.{id}"),
+            weight: 1,
+            duration_days: Some(8),
+            onset_effects: DiseaseEffects::default(),
+            daily_tick_effects: DiseaseEffects::default(),
+            fatality_model: None,
+            tags: Vec::new(),
+            treatments,
+        }
+    }
+
+    fn afflicted_state(disease_id: &str) -> GameState {
+        let mut state = GameState {
+            mechanical_policy: MechanicalPolicyId::OtDeluxe90s,
+            ..GameState::default()
+        };
+        state.ot_deluxe.party = OtDeluxePartyState::from_names(["Ada", "Ben"]);
+        state.ot_deluxe.party.members[1].apply_affliction(
+            OtDeluxeAfflictionKind::Illness,
+            8,
+            Some(disease_id),
+        );
+        state.ot_deluxe.inventory.medicine = 1;
+        state
+    }
+
+    #[test]
+    fn medicine_halves_remaining_days_once() {
+        let catalog = DiseaseCatalog {
+            diseases: vec![disease("cholera", vec![DiseaseTreatment::Medicine])],
+        };
+        let policy = OtDeluxeTreatmentPolicy::default();
+        let mut state = afflicted_state("cholera");
+
+        let outcome = treat_otdeluxe_member(&mut state, &catalog, &policy, 1).expect("treated");
+        assert_eq!((outcome.days_before, outcome.days_after), (8, 4));
+        assert!(state.ot_deluxe.party.members[1].treated);
+        assert_eq!(state.ot_deluxe.inventory.medicine, 0);
+
+        state.ot_deluxe.inventory.medicine = 1;
+        assert_eq!(
+            treat_otdeluxe_member(&mut state, &catalog, &policy, 1),
+            Err(TreatmentError::AlreadyTreated)
+        );
+        assert_eq!(
+            treat_otdeluxe_member(&mut state, &catalog, &policy, 0),
+            Err(TreatmentError::NotAfflicted)
+        );
+        assert_eq!(
+            treat_otdeluxe_member(&mut state, &catalog, &policy, 5),
+            Err(TreatmentError::UnknownMember(5))
+        );
+    }
+
+    #[test]
+    fn diseases_declare_which_treatments_work() {
+        let catalog = DiseaseCatalog {
+            diseases: vec![disease("exhaustion", Vec::new())],
+        };
+        let policy = OtDeluxeTreatmentPolicy::default();
+        let mut state = afflicted_state("exhaustion");
+        assert_eq!(
+            treat_otdeluxe_member(&mut state, &catalog, &policy, 1),
+            Err(TreatmentError::Untreatable)
+        );
+
+        let mut state = afflicted_state("cholera");
+        state.ot_deluxe.inventory.medicine = 0;
+        let catalog = DiseaseCatalog {
+            diseases: vec![disease("cholera", vec![DiseaseTreatment::Medicine])],
+        };
+        assert_eq!(
+            treat_otdeluxe_member(&mut state, &catalog, &policy, 1),
+            Err(TreatmentError::NoMedicine)
+        );
+    }
+
+    #[test]
+    fn dystrail_medicine_shortens_illness() {
+        let catalog = DiseaseCatalog {
+            diseases: vec![disease("cholera", vec![DiseaseTreatment::Medicine])],
+        };
+        let mut state = GameState::default();
+        assert_eq!(
+            treat_illness(&mut state, &catalog),
+            Err(TreatmentError::NotAfflicted)
+        );
+        state.illness_days_remaining = 5;
+        state.illness_id = Some(String::from("cholera"));
+        assert_eq!(
+            treat_illness(&mut state, &catalog),
+            Err(TreatmentError::NoMedicine)
+        );
+        state.inventory.medicine = 2;
+        assert_eq!(treat_illness(&mut state, &catalog), Ok(2));
+        assert_eq!(treat_illness(&mut state, &catalog), Ok(1));
+        assert_eq!(state.inventory.medicine, 0);
+
+        state.mechanical_policy = MechanicalPolicyId::OtDeluxe90s;
+        assert_eq!(
+            treat_illness(&mut state, &catalog),
+            Err(TreatmentError::WrongMechanics)
+        );
+    }
+
+    #[test]
+    fn dystrail_medicine_respects_declared_treatments() {
+        let catalog = DiseaseCatalog {
+            diseases: vec![disease("exhaustion", Vec::new())],
+        };
+        let mut state = GameState {
+            illness_days_remaining: 4,
+            illness_id: Some(String::from("exhaustion")),
+            ..GameState::default()
+        };
+        state.inventory.medicine = 1;
+        assert_eq!(
+            treat_illness(&mut state, &catalog),
+            Err(TreatmentError::Untreatable)
+        );
+        assert_eq!(state.inventory.medicine, 1);
+        assert_eq!(state.illness_days_remaining, 4);
+    }
+}
@@ -1,6 +1,21 @@
 //! Vehicle breakdown system
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::constants::{
+    PACE_BREAKDOWN_BLITZ, PACE_BREAKDOWN_HEATED, PACE_BREAKDOWN_STEADY,
+    VEHICLE_BREAKDOWN_BASE_CHANCE, VEHICLE_BREAKDOWN_WEAR_COEFFICIENT,
+};
+use crate::state::PaceId;
+use crate::weather::Weather;
+
+/// Spare parts a vehicle carries when its class sets no capacity.
+pub const DEFAULT_SPARE_CAPACITY: i32 = 8;
+
+const DEFAULT_VEHICLE_DATA: &str =
+    include_str!("../../dystrail-web/static/assets/data/vehicle.json");
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum Part {
@@ -23,8 +38,63 @@ impl Part {
     }
 }
 
+/// Selectable Dystrail vehicle body; tuning lives in `vehicle.json`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum VehicleClass {
+    #[default]
+    Minivan,
+    Rv,
+    EBikeConvoy,
+    SchoolBus,
+}
+
+impl VehicleClass {
+    pub const ALL: [Self; 4] = [Self::Minivan, Self::Rv, Self::EBikeConvoy, Self::SchoolBus];
+
+    /// Get the translation key for this class
+    #[must_use]
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::Minivan => "vehicle.classes.minivan",
+            Self::Rv => "vehicle.classes.rv",
+            Self::EBikeConvoy => "vehicle.classes.e_bike_convoy",
+            Self::SchoolBus => "vehicle.classes.school_bus",
+        }
+    }
+}
+
+/// Permanent vehicle upgrade bought in the outfitting store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VehicleUpgrade {
+    ReinforcedTires,
+    SolarPanel,
+    RoofRack,
+}
+
+impl VehicleUpgrade {
+    pub const ALL: [Self; 3] = [Self::ReinforcedTires, Self::SolarPanel, Self::RoofRack];
+
+    /// Get the translation key for this upgrade
+    #[must_use]
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::ReinforcedTires => "vehicle.upgrades.reinforced_tires",
+            Self::SolarPanel => "vehicle.upgrades.solar_panel",
+            Self::RoofRack => "vehicle.upgrades.roof_rack",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Vehicle {
+    /// Vehicle body chosen at the start of the run
+    #[serde(default)]
+    pub class: VehicleClass,
+    /// Upgrades installed so far, in purchase order
+    #[serde(default)]
+    pub upgrades: Vec<VehicleUpgrade>,
     /// Future: wear level that increases base breakdown chance
     #[serde(default)]
     pub wear: f32,
@@ -42,6 +112,8 @@ pub struct Vehicle {
 impl Default for Vehicle {
     fn default() -> Self {
         Self {
+            class: VehicleClass::default(),
+            upgrades: Vec::new(),
             wear: 0.0,
             health: Self::default_health(),
             breakdown_cooldown: 0,
@@ -59,6 +131,20 @@ impl Vehicle {
         1.0
     }
 
+    #[must_use]
+    pub fn has_upgrade(&self, upgrade: VehicleUpgrade) -> bool {
+        self.upgrades.contains(&upgrade)
+    }
+
+    /// Install an upgrade; returns false when it was already fitted.
+    pub fn install_upgrade(&mut self, upgrade: VehicleUpgrade) -> bool {
+        if self.has_upgrade(upgrade) {
+            return false;
+        }
+        self.upgrades.push(upgrade);
+        true
+    }
+
     /// Apply durability damage, clamping at zero.
     pub fn apply_damage(&mut self, amount: f32) {
         if amount <= 0.0 {
@@ -156,9 +242,267 @@ pub struct Breakdown {
     pub day_started: i32,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+/// Breakdown model for the Dystrail vehicle, loaded from `vehicle.json`.
+///
+/// `vehicle.json` is the baseline. Journey family files and strategy overlays
+/// win field by field: a `breakdown` or `part_weights` entry in
+/// `journey/*.json` replaces the matching value here, and anything they omit
+/// falls back to this file. Repair costs and the mechanic hook only live here.
+/// `OTDeluxe` runs use their own policy breakdown model instead.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VehicleConfig {
-    pub breakdown_chance: f32,
+    #[serde(default = "VehicleConfig::default_base_breakdown_chance")]
+    pub base_breakdown_chance: f32,
+    #[serde(default = "VehicleConfig::default_wear_beta")]
+    pub wear_beta: f32,
+    #[serde(default = "VehicleConfig::default_pace_factor")]
+    pub pace_factor: HashMap<PaceId, f32>,
+    #[serde(default = "VehicleConfig::default_weather_factor")]
+    pub weather_factor: HashMap<Weather, f32>,
+    #[serde(default = "PartWeights::baseline")]
+    pub part_weights: PartWeights,
+    #[serde(default)]
+    pub repair_costs: RepairCosts,
+    #[serde(default)]
+    pub mechanic_hook: MechanicHook,
+    #[serde(default)]
+    pub classes: HashMap<VehicleClass, VehicleClassProfile>,
+    #[serde(default)]
+    pub upgrades: HashMap<VehicleUpgrade, VehicleUpgradeProfile>,
+}
+
+impl VehicleConfig {
+    const fn default_base_breakdown_chance() -> f32 {
+        VEHICLE_BREAKDOWN_BASE_CHANCE
+    }
+
+    const fn default_wear_beta() -> f32 {
+        VEHICLE_BREAKDOWN_WEAR_COEFFICIENT
+    }
+
+    fn default_pace_factor() -> HashMap<PaceId, f32> {
+        HashMap::from([
+            (PaceId::Steady, PACE_BREAKDOWN_STEADY),
+            (PaceId::Heated, PACE_BREAKDOWN_HEATED),
+            (PaceId::Blitz, PACE_BREAKDOWN_BLITZ),
+        ])
+    }
+
+    fn default_weather_factor() -> HashMap<Weather, f32> {
+        HashMap::from([
+            (Weather::Clear, 1.0),
+            (Weather::Storm, 1.3),
+            (Weather::HeatWave, 1.4),
+            (Weather::ColdSnap, 1.1),
+            (Weather::Smoke, 1.1),
+        ])
+    }
+
+    #[must_use]
+    pub fn load_from_static() -> Self {
+        serde_json::from_str(DEFAULT_VEHICLE_DATA).unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn default_config() -> &'static Self {
+        static CONFIG: OnceLock<VehicleConfig> = OnceLock::new();
+        CONFIG.get_or_init(Self::load_from_static)
+    }
+
+    /// Combine the vehicle's class profile with its installed upgrades.
+    ///
+    /// Classes or upgrades missing from the config behave like a stock minivan.
+    #[must_use]
+    pub fn modifiers(&self, vehicle: &Vehicle) -> VehicleModifiers {
+        let class = self
+            .classes
+            .get(&vehicle.class)
+            .cloned()
+            .unwrap_or_default();
+        let mut mods = VehicleModifiers {
+            speed_mult: class.speed_mult,
+            spare_capacity: class.spare_capacity,
+            part_weight_pct: class.part_weight_pct,
+            wear_mult: class.wear_mult,
+            wear_beta_mult: class.wear_beta_mult,
+            score_mult: class.score_mult,
+        };
+        for upgrade in &vehicle.upgrades {
+            let Some(profile) = self.upgrades.get(upgrade) else {
+                continue;
+            };
+            mods.speed_mult *= profile.speed_mult;
+            mods.spare_capacity += profile.spare_capacity_bonus;
+            mods.part_weight_pct = mods.part_weight_pct.scaled_by_pct(&profile.part_weight_pct);
+            mods.wear_mult *= profile.wear_mult;
+            mods.score_mult *= profile.score_mult;
+        }
+        mods.sanitize()
+    }
+}
+
+impl Default for VehicleConfig {
+    fn default() -> Self {
+        Self {
+            base_breakdown_chance: Self::default_base_breakdown_chance(),
+            wear_beta: Self::default_wear_beta(),
+            pace_factor: Self::default_pace_factor(),
+            weather_factor: Self::default_weather_factor(),
+            part_weights: PartWeights::baseline(),
+            repair_costs: RepairCosts::default(),
+            mechanic_hook: MechanicHook::default(),
+            classes: HashMap::new(),
+            upgrades: HashMap::new(),
+        }
+    }
+}
+
+/// Tuning for one vehicle class.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VehicleClassProfile {
+    /// Scales daily travel distance.
+    pub speed_mult: f32,
+    /// Spare parts the vehicle can carry.
+    pub spare_capacity: i32,
+    /// Per-part breakdown weights, in percent of the journey part weights.
+    pub part_weight_pct: PartWeights,
+    /// Scales daily wear.
+    pub wear_mult: f32,
+    /// Scales how fast accumulated wear raises breakdown odds.
+    pub wear_beta_mult: f32,
+    /// Scales the journey score.
+    pub score_mult: f32,
+}
+
+impl Default for VehicleClassProfile {
+    fn default() -> Self {
+        Self {
+            speed_mult: 1.0,
+            spare_capacity: DEFAULT_SPARE_CAPACITY,
+            part_weight_pct: PartWeights::neutral_pct(),
+            wear_mult: 1.0,
+            wear_beta_mult: 1.0,
+            score_mult: 1.0,
+        }
+    }
+}
+
+/// Tuning for one vehicle upgrade, stacked on top of the class profile.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VehicleUpgradeProfile {
+    pub speed_mult: f32,
+    pub spare_capacity_bonus: i32,
+    pub part_weight_pct: PartWeights,
+    pub wear_mult: f32,
+    pub score_mult: f32,
+}
+
+impl Default for VehicleUpgradeProfile {
+    fn default() -> Self {
+        Self {
+            speed_mult: 1.0,
+            spare_capacity_bonus: 0,
+            part_weight_pct: PartWeights::neutral_pct(),
+            wear_mult: 1.0,
+            score_mult: 1.0,
+        }
+    }
+}
+
+/// Effective class and upgrade modifiers for a vehicle.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VehicleModifiers {
+    pub speed_mult: f32,
+    pub spare_capacity: i32,
+    pub part_weight_pct: PartWeights,
+    pub wear_mult: f32,
+    pub wear_beta_mult: f32,
+    pub score_mult: f32,
+}
+
+impl Default for VehicleModifiers {
+    fn default() -> Self {
+        Self {
+            speed_mult: 1.0,
+            spare_capacity: DEFAULT_SPARE_CAPACITY,
+            part_weight_pct: PartWeights::neutral_pct(),
+            wear_mult: 1.0,
+            wear_beta_mult: 1.0,
+            score_mult: 1.0,
+        }
+    }
+}
+
+impl VehicleModifiers {
+    fn sanitize(mut self) -> Self {
+        let positive = |value: f32| {
+            if value.is_finite() {
+                value.max(0.0)
+            } else {
+                1.0
+            }
+        };
+        self.speed_mult = positive(self.speed_mult);
+        self.spare_capacity = self.spare_capacity.max(0);
+        self.wear_mult = positive(self.wear_mult);
+        self.wear_beta_mult = positive(self.wear_beta_mult);
+        self.score_mult = positive(self.score_mult);
+        self
+    }
+
+    /// Apply the part weight percentages to a set of journey part weights.
+    #[must_use]
+    pub fn part_weights(&self, base: &PartWeights) -> PartWeights {
+        base.scaled_by_pct(&self.part_weight_pct)
+    }
+}
+
+/// Costs charged by the camp repair actions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RepairCosts {
+    /// Supplies spent fitting a spare part.
+    pub use_spare_supplies: i32,
+    /// Supplies spent on a hack fix.
+    pub hack_supplies: i32,
+    /// Credibility lost on a hack fix.
+    pub hack_cred: i32,
+    /// Days a hack fix takes.
+    pub hack_day: u32,
+}
+
+impl Default for RepairCosts {
+    fn default() -> Self {
+        Self {
+            use_spare_supplies: 1,
+            hack_supplies: 3,
+            hack_cred: 1,
+            hack_day: 1,
+        }
+    }
+}
+
+/// A passing mechanic who may clear a breakdown the party cannot fix itself.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MechanicHook {
+    pub enabled: bool,
+    /// Chance per stranded day that a mechanic stops and clears the breakdown.
+    pub chance_clear: f32,
+    /// Days the repair takes, counting the day the mechanic arrives.
+    pub day_cost: u32,
+}
+
+impl Default for MechanicHook {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            chance_clear: 0.15,
+            day_cost: 1,
+        }
+    }
 }
 
 /// Part weights for weighted random selection
@@ -170,8 +514,8 @@ pub struct PartWeights {
     pub pump: u32,
 }
 
-impl Default for PartWeights {
-    fn default() -> Self {
+impl PartWeights {
+    const fn baseline() -> Self {
         Self {
             tire: 50,
             battery: 20,
@@ -179,6 +523,35 @@ impl Default for PartWeights {
             pump: 15,
         }
     }
+
+    const fn neutral_pct() -> Self {
+        Self {
+            tire: 100,
+            battery: 100,
+            alt: 100,
+            pump: 100,
+        }
+    }
+
+    /// Scale each weight by a percentage, where 100 leaves it unchanged.
+    #[must_use]
+    pub const fn scaled_by_pct(&self, pct: &Self) -> Self {
+        const fn scale(weight: u32, pct: u32) -> u32 {
+            weight.saturating_mul(pct) / 100
+        }
+        Self {
+            tire: scale(self.tire, pct.tire),
+            battery: scale(self.battery, pct.battery),
+            alt: scale(self.alt, pct.alt),
+            pump: scale(self.pump, pct.pump),
+        }
+    }
+}
+
+impl Default for PartWeights {
+    fn default() -> Self {
+        VehicleConfig::default_config().part_weights.clone()
+    }
 }
 
 /// Weighted random selection from a list of options
@@ -213,11 +586,19 @@ pub fn breakdown_roll<R: Rng>(base_chance: f32, rng: &mut R) -> bool {
     rng.r#gen::<f32>() < base_chance
 }
 
-/// Process daily breakdown chance
+/// Process daily breakdown chance using the state's breakdown model.
+///
+/// The base chance, pace and weather factors and part weights come from
+/// `vehicle.json` as overridden by the active journey configuration.
 pub fn process_daily_breakdown<R: Rng>(game_state: &mut crate::state::GameState, rng: &mut R) {
-    let breakdown_chance = 0.1; // 10% chance per day
-    if breakdown_roll(breakdown_chance, rng) && game_state.breakdown.is_none() {
-        let weights = PartWeights::default();
+    if game_state.breakdown.is_some() {
+        return;
+    }
+    let breakdown_chance = game_state.journey_breakdown.base
+        * game_state.journey_pace_factor()
+        * game_state.journey_weather_factor();
+    if breakdown_roll(breakdown_chance, rng) {
+        let weights = game_state.vehicle_part_weights();
         let options = [
             (Part::Tire, weights.tire),
             (Part::Battery, weights.battery),
@@ -293,6 +674,91 @@ mod tests {
         assert!(state.day_state.travel.travel_blocked);
     }
 
+    #[test]
+    fn vehicle_json_seeds_the_journey_breakdown_model() {
+        let cfg = VehicleConfig::default_config();
+        assert!(!cfg.mechanic_hook.enabled);
+        assert_eq!(cfg.repair_costs, RepairCosts::default());
+        assert_eq!(PartWeights::default(), cfg.part_weights);
+
+        let breakdown = crate::journey::BreakdownConfig::default();
+        assert!((breakdown.base - cfg.base_breakdown_chance).abs() <= f32::EPSILON);
+        assert!((breakdown.beta - cfg.wear_beta).abs() <= f32::EPSILON);
+        assert_eq!(breakdown.pace_factor, cfg.pace_factor);
+        assert_eq!(breakdown.weather_factor, cfg.weather_factor);
+    }
+
+    #[test]
+    fn process_daily_breakdown_uses_configured_part_weights() {
+        let mut state = GameState {
+            journey_part_weights: PartWeights {
+                tire: 0,
+                battery: 0,
+                alt: 0,
+                pump: 1,
+            },
+            ..GameState::default()
+        };
+        process_daily_breakdown(&mut state, &mut StepRng::new(0, 0));
+        assert_eq!(state.breakdown.map(|b| b.part), Some(Part::FuelPump));
+
+        let mut calm = GameState::default();
+        calm.journey_breakdown.base = 0.0;
+        process_daily_breakdown(&mut calm, &mut StepRng::new(0, 0));
+        assert!(calm.breakdown.is_none());
+    }
+
+    #[test]
+    fn vehicle_json_defines_every_class_and_upgrade() {
+        let cfg = VehicleConfig::default_config();
+        for class in VehicleClass::ALL {
+            assert!(cfg.classes.contains_key(&class), "missing class {class:?}");
+        }
+        for upgrade in VehicleUpgrade::ALL {
+            assert!(
+                cfg.upgrades.contains_key(&upgrade),
+                "missing upgrade {upgrade:?}"
+            );
+        }
+        let stock = cfg.modifiers(&Vehicle::default());
+        assert_eq!(stock, VehicleModifiers::default());
+    }
+
+    #[test]
+    fn upgrades_stack_on_the_class_profile() {
+        let cfg = VehicleConfig::default_config();
+        let mut vehicle = Vehicle {
+            class: VehicleClass::Rv,
+            ..Vehicle::default()
+        };
+        let rv = cfg.modifiers(&vehicle);
+        assert!(vehicle.install_upgrade(VehicleUpgrade::RoofRack));
+        assert!(vehicle.install_upgrade(VehicleUpgrade::ReinforcedTires));
+        assert!(!vehicle.install_upgrade(VehicleUpgrade::RoofRack));
+        assert_eq!(vehicle.upgrades.len(), 2);
+
+        let upgraded = cfg.modifiers(&vehicle);
+        assert_eq!(upgraded.spare_capacity, rv.spare_capacity + 4);
+        assert!(upgraded.speed_mult < rv.speed_mult);
+        let weights = upgraded.part_weights(&PartWeights::baseline());
+        assert!(weights.tire < rv.part_weights(&PartWeights::baseline()).tire);
+    }
+
+    #[test]
+    fn scaled_by_pct_can_zero_out_parts() {
+        let pct = PartWeights {
+            tire: 150,
+            battery: 100,
+            alt: 0,
+            pump: 50,
+        };
+        let scaled = PartWeights::baseline().scaled_by_pct(&pct);
+        assert_eq!(scaled.tire, 75);
+        assert_eq!(scaled.battery, 20);
+        assert_eq!(scaled.alt, 0);
+        assert_eq!(scaled.pump, 7);
+    }
+
     #[test]
     fn weighted_pick_breaks_on_match() {
         let options = vec![(Part::Tire, 2), (Part::Battery, 1)];
@@ -328,7 +328,7 @@ impl WeatherModel for DystrailRegionalWeather {
     }
 
     fn sample_from_weather(&self, gs: &GameState, weather: Weather) -> WeatherSample {
-        let base_temp = base_temperature_f(gs.season);
+        let base_temp = base_temperature_f(gs.weather_season());
         let delta = weather_temperature_delta_f(weather);
         let temp_f = base_temp.saturating_add(delta);
         let (rain, snow) = self
@@ -363,7 +363,7 @@ impl WeatherModel for OtDeluxeStationsWeather {
     ) -> Result<WeatherSample, String> {
         Ok(WeatherSample {
             weather: Weather::Clear,
-            temperature_f: base_temperature_f(gs.season),
+            temperature_f: base_temperature_f(gs.weather_season()),
             precip_in: 0.0,
         })
     }
@@ -382,7 +382,7 @@ impl WeatherModel for OtDeluxeStationsWeather {
     fn sample_from_weather(&self, gs: &GameState, _weather: Weather) -> WeatherSample {
         WeatherSample {
             weather: Weather::Clear,
-            temperature_f: base_temperature_f(gs.season),
+            temperature_f: base_temperature_f(gs.weather_season()),
             precip_in: 0.0,
         }
     }
@@ -630,7 +630,7 @@ fn select_weather_with_rng<R: Rng>(
         }
     }
 
-    let mut final_weather = seasonal_override(gs.season, candidate, rng);
+    let mut final_weather = seasonal_override(gs.weather_season(), candidate, rng);
 
     if gs.weather_state.neutral_buffer > 0 {
         final_weather = pick_neutral_weather(region_weights, rng);
@@ -1716,6 +1716,32 @@ mod tests {
         assert!((-200..=200).contains(&sample.temperature_f));
     }
 
+    #[test]
+    fn otdeluxe_weather_follows_departure_calendar_season() {
+        let mut state = GameState {
+            mechanical_policy: crate::journey::MechanicalPolicyId::OtDeluxe90s,
+            season: Season::Spring,
+            ..GameState::default()
+        };
+        state.ot_deluxe.day = 160;
+        state
+            .ot_deluxe
+            .set_departure(crate::otdeluxe_state::OtDeluxeCalendar::departure(7, 1848));
+        assert_eq!(state.weather_season(), Season::Winter);
+
+        let model = OtDeluxeStationsWeather::default();
+        let sample = model.sample_from_weather(&state, Weather::Clear);
+        assert_eq!(sample.temperature_f, base_temperature_f(Season::Winter));
+
+        let storm = WeatherSample {
+            weather: Weather::Storm,
+            temperature_f: sample.temperature_f,
+            precip_in: 1.0,
+        };
+        model.apply_weather_effects(&mut state, storm);
+        assert!(state.ot_deluxe.weather.snow_depth > 0.0);
+    }
+
     #[test]
     fn weather_config_validation_rejects_negative_rates() {
         let mut cfg = WeatherConfig::default_config();
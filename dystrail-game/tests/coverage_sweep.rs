@@ -11,7 +11,7 @@ use dystrail_game::encounters::{EncounterRequest, pick_encounter};
 use dystrail_game::endgame::{
     self, EndgamePolicyCfg, EndgameState, EndgameTravelCfg, ResourceKind,
 };
-use dystrail_game::journey::{Event, EventId, RngBundle, UiSurfaceHint};
+use dystrail_game::journey::{Event, EventId, EventSeverity, RngBundle, UiSurfaceHint};
 use dystrail_game::otdeluxe_random_events::{
     OtDeluxeRandomEventCatalog, OtDeluxeRandomEventContext, OtDeluxeRandomEventDef,
     OtDeluxeRandomEventVariant, pick_random_event_with_trace,
@@ -43,7 +43,7 @@ use rand::{RngCore, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 use std::cell::RefCell;
 use std::collections::{HashMap, VecDeque};
-use std::rc::Rc;
+use std::sync::Arc;
 
 fn empty_state() -> GameState {
     let data = EncounterData::empty();
@@ -297,7 +297,8 @@ fn camp_actions_cover_branches() {
         camp::camp_repair_spare(&mut state, &camp_cfg, Part::Tire).message,
         "log.camp.repair"
     );
-    assert!(camp::can_repair(&state, &camp_cfg));
+    assert_eq!(state.inventory.spares.tire, 0);
+    assert!(!camp::can_repair(&state, &camp_cfg));
     assert!(camp::can_therapy(&state, &camp_cfg));
 }
 
@@ -669,14 +670,14 @@ fn vehicle_and_weather_paths() {
     state.stats.sanity = 5;
     state.stats.hp = 6;
     state.features.travel_v2 = true;
-    state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(0xDEAD)));
+    state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(0xDEAD)));
 
     let cfg = WeatherConfig::load_from_static();
     let model = DystrailRegionalWeather::new(cfg.clone());
     let rng_shared = state
         .rng_bundle
         .as_ref()
-        .map(Rc::clone)
+        .map(Arc::clone)
         .expect("rng attached for weather");
     let _today = select_weather_for_today(&mut state, &cfg, rng_shared.as_ref());
     let sample = model.sample_from_weather(&state, state.weather_state.today);
@@ -1247,7 +1248,7 @@ fn test_loader_config_lookup_handles_missing_and_present_entries() {
 #[rustfmt::skip]
 fn disease_uniform_fallback_trace_records_weights() {
     let catalog = DiseaseCatalog {
-        diseases: vec![DiseaseDef { id: "d1".into(), kind: DiseaseKind::Illness, display_key: "disease.d1".into(), weight: 0, duration_days: None, onset_effects: DiseaseEffects::default(), daily_tick_effects: DiseaseEffects::default(), fatality_model: None, tags: Vec::new() }, DiseaseDef { id: "d2".into(), kind: DiseaseKind::Illness, display_key: "disease.d2".into(), weight: 0, duration_days: None, onset_effects: DiseaseEffects::default(), daily_tick_effects: DiseaseEffects::default(), fatality_model: None, tags: Vec::new() }],
+        diseases: vec![DiseaseDef { id: "d1".into(), kind: DiseaseKind::Illness, display_key: "disease.d1".into(), weight: 0, duration_days: None, onset_effects: DiseaseEffects::default(), daily_tick_effects: DiseaseEffects::default(), fatality_model: None, tags: Vec::new(), treatments: Vec::new() }, DiseaseDef { id: "d2".into(), kind: DiseaseKind::Illness, display_key: "disease.d2".into(), weight: 0, duration_days: None, onset_effects: DiseaseEffects::default(), daily_tick_effects: DiseaseEffects::default(), fatality_model: None, tags: Vec::new(), treatments: Vec::new() }],
     };
     let mut rng = SmallRng::seed_from_u64(42);
     let (_, trace) = catalog.pick_by_kind_with_trace(DiseaseKind::Illness, &mut rng);
@@ -1337,7 +1338,7 @@ fn pick_encounter_weighted_selection_records_trace() {
 fn pick_random_event_with_variant_emits_trace() {
     let catalog = OtDeluxeRandomEventCatalog {
         chance_per_day: 1.0,
-        events: vec![OtDeluxeRandomEventDef { id: "resource_shortage".into(), weight: 1, variants: vec![OtDeluxeRandomEventVariant { id: "bad_water".into(), weight: 1 }] }],
+        events: vec![OtDeluxeRandomEventDef { id: "resource_shortage".into(), weight: 1, variants: vec![OtDeluxeRandomEventVariant { id: "bad_water".into(), weight: 1, severity: EventSeverity::Warning, ui_key: None, effects: Some(Vec::new()), skill: None }] }],
     };
     let ctx = OtDeluxeRandomEventContext {
         season: Season::Spring,
@@ -1348,6 +1349,7 @@ fn pick_random_event_with_variant_emits_trace() {
         spares_total: 1,
         weight_mult: 1.0,
         weight_cap: None,
+        skill_factors: Vec::new(),
     };
     let mut rng = StepRng::new(0, 0);
     let pick = pick_random_event_with_trace(&catalog, &ctx, &mut rng).expect("pick");
@@ -0,0 +1,118 @@
+use std::thread;
+
+use dystrail_game::journey::RngBundle;
+use dystrail_game::{
+    EncounterData, EndgameTravelCfg, GameMode, GameState, JourneyController, JourneySession,
+    StrategyId,
+};
+
+const fn assert_send_sync<T: Send + Sync>() {}
+
+const _: () = {
+    assert_send_sync::<RngBundle>();
+    assert_send_sync::<GameState>();
+    assert_send_sync::<JourneyController>();
+    assert_send_sync::<JourneySession>();
+};
+
+fn load_encounters() -> EncounterData {
+    EncounterData::from_json(include_str!(
+        "../../dystrail-web/static/assets/data/game.json"
+    ))
+    .unwrap()
+}
+
+fn run_session(mut session: JourneySession, days: u32) -> (Vec<String>, GameState) {
+    let mut log_keys = Vec::new();
+    for _ in 0..days {
+        let outcome = session.tick_day();
+        log_keys.push(outcome.log_key.clone());
+        if outcome.ended {
+            break;
+        }
+    }
+    (log_keys, session.into_state())
+}
+
+fn fingerprint(state: &GameState) -> (u32, u32, i32, i32, i32, String) {
+    (
+        state.day,
+        state.miles_traveled_actual.to_bits(),
+        state.stats.hp,
+        state.stats.supplies,
+        state.stats.sanity,
+        format!("{:?}", state.region),
+    )
+}
+
+#[test]
+fn sessions_are_deterministic_across_threads() {
+    let data = load_encounters();
+    let endgame = EndgameTravelCfg::default_config();
+    let seeds = [7_u64, 42, 1_337, 0xDEAD_BEEF];
+
+    let serial: Vec<_> = seeds
+        .iter()
+        .map(|&seed| {
+            let session = JourneySession::new(
+                GameMode::Classic,
+                StrategyId::Balanced,
+                seed,
+                data.clone(),
+                &endgame,
+            );
+            let (logs, state) = run_session(session, 40);
+            (logs, fingerprint(&state))
+        })
+        .collect();
+
+    let handles: Vec<_> = seeds
+        .iter()
+        .map(|&seed| {
+            let session = JourneySession::new(
+                GameMode::Classic,
+                StrategyId::Balanced,
+                seed,
+                data.clone(),
+                &endgame,
+            );
+            thread::spawn(move || {
+                let (logs, state) = run_session(session, 40);
+                (logs, fingerprint(&state))
+            })
+        })
+        .collect();
+    let threaded: Vec<_> = handles
+        .into_iter()
+        .map(|handle| handle.join().expect("session thread panicked"))
+        .collect();
+
+    assert_eq!(serial, threaded);
+}
+
+#[test]
+fn session_resumes_on_another_thread() {
+    let data = load_encounters();
+    let endgame = EndgameTravelCfg::default_config();
+    let build = || {
+        JourneySession::new(
+            GameMode::Deep,
+            StrategyId::Aggressive,
+            2_024,
+            data.clone(),
+            &endgame,
+        )
+    };
+
+    let (expected_logs, expected_state) = run_session(build(), 30);
+
+    let mut session = build();
+    let mut logs: Vec<String> = (0..10).map(|_| session.tick_day().log_key).collect();
+    let (rest, state) = thread::spawn(move || run_session(session, 20))
+        .join()
+        .expect("session thread panicked");
+    logs.extend(rest);
+
+    assert_eq!(logs, expected_logs);
+    assert_eq!(fingerprint(&state), fingerprint(&expected_state));
+}
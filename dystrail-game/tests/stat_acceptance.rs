@@ -24,7 +24,7 @@ fn breakdown_probability_tracks_base_rate() {
     state.pace = PaceId::Steady;
     state.weather_state.today = dystrail_game::weather::Weather::Clear;
     state.vehicle.set_wear(0.0);
-    state.attach_rng_bundle(std::rc::Rc::new(
+    state.attach_rng_bundle(std::sync::Arc::new(
         dystrail_game::journey::RngBundle::from_user_seed(1234),
     ));
 
@@ -172,7 +172,7 @@ fn endgame_breakdown_scale_reduces_breaks() {
     state.journey_breakdown.pace_factor = std::iter::once((PaceId::Steady, 1.0)).collect();
     state.journey_breakdown.weather_factor =
         std::iter::once((dystrail_game::weather::Weather::Clear, 1.0)).collect();
-    state.attach_rng_bundle(std::rc::Rc::new(
+    state.attach_rng_bundle(std::sync::Arc::new(
         dystrail_game::journey::RngBundle::from_user_seed(55),
     ));
 
@@ -189,7 +189,7 @@ fn endgame_breakdown_scale_reduces_breaks() {
 
     // Baseline without scale
     state.endgame.breakdown_scale = 1.0;
-    state.attach_rng_bundle(std::rc::Rc::new(
+    state.attach_rng_bundle(std::sync::Arc::new(
         dystrail_game::journey::RngBundle::from_user_seed(55),
     ));
     let mut breaks_baseline = 0usize;
@@ -14,7 +14,7 @@ use dystrail_game::{
 };
 use rand::Rng;
 use rand::rngs::mock::StepRng;
-use std::rc::Rc;
+use std::sync::Arc;
 
 fn encounter_seed_where(predicate: impl Fn(u8) -> bool) -> u64 {
     (0..50_000u64)
@@ -122,7 +122,7 @@ fn boss_minigame_exercises_outcomes() {
         (rng.r#gen::<u32>() % 100) as u8
     };
     assert!(preview > 20);
-    lose_state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(seed)));
+    lose_state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(seed)));
 
     let outcome = run_boss_minigame(&mut lose_state, &lose_cfg);
     assert_eq!(outcome, BossOutcome::SurvivedFlood);
@@ -233,6 +233,8 @@ fn store_cart_covers_operations() {
         spare_alt: 0,
         spare_pump: 0,
         enabled: true,
+        medicine: 0,
+        vehicle_upgrades: Vec::new(),
     };
 
     let gear_item = StoreItem { id: "rope".into(), name: "Rope".into(), desc: "Sturdy rope".into(), price_cents: 1_200, unique: false, max_qty: 5, grants: grants.clone(), tags: vec!["gear".into()], category: "supplies".into() };
@@ -335,7 +337,7 @@ fn weather_effects_and_selection() {
         exposure_streak_heat: 2,
         ..GameState::default()
     };
-    state.attach_rng_bundle(Rc::new(RngBundle::from_user_seed(2)));
+    state.attach_rng_bundle(Arc::new(RngBundle::from_user_seed(2)));
     state.inventory.tags.clear();
 
     let sample = model.sample_from_weather(&state, state.weather_state.today);
@@ -357,7 +359,7 @@ fn weather_effects_and_selection() {
     let weather_rng = state
         .rng_bundle
         .as_ref()
-        .map(Rc::clone)
+        .map(Arc::clone)
         .expect("rng attached");
     let selected = select_weather_for_today(&mut state, &cfg, weather_rng.as_ref())
         .expect("weather selection works");
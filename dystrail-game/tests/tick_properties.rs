@@ -0,0 +1,359 @@
+use dystrail_game::otdeluxe_state::OtDeluxePartyState;
+use dystrail_game::state::DayIntent;
+use dystrail_game::{
+    CrossingChoice, CrossingKind, DietId, EncounterData, EndgameTravelCfg, GameMode, GameState,
+    JourneySession, MechanicalPolicyId, OtDeluxeCrossingMethod, OtDeluxeOccupation,
+    OtDeluxeRouteDecision, OtDeluxeStartConfig, OtDeluxeStoreItem, OtDeluxeStoreLineItem, PaceId,
+    PendingCrossing, Stats, StrategyId, Weather,
+};
+use proptest::prelude::*;
+use std::sync::OnceLock;
+
+fn encounter_data() -> &'static EncounterData {
+    static DATA: OnceLock<EncounterData> = OnceLock::new();
+    DATA.get_or_init(|| {
+        EncounterData::from_json(include_str!(
+            "../../dystrail-web/static/assets/data/game.json"
+        ))
+        .expect("game data parses")
+    })
+}
+
+/// A randomized starting point layered over a freshly seeded session.
+#[derive(Debug, Clone)]
+struct Start {
+    mechanics: MechanicalPolicyId,
+    mode: GameMode,
+    strategy: StrategyId,
+    seed: u64,
+    occupation: OtDeluxeOccupation,
+    departure_month: u8,
+    stats: Stats,
+    budget_cents: i64,
+    spares: [i32; 4],
+    medicine: i32,
+    pending_crossing: Option<(CrossingKind, f32)>,
+    party_sick_days: Vec<u8>,
+    weather: Weather,
+}
+
+/// One player decision taken before (or instead of) a day tick.
+#[derive(Debug, Clone, Copy)]
+enum Step {
+    Travel,
+    Intent(DayIntent),
+    Pace(PaceId),
+    Diet(DietId),
+    Choice(usize),
+    Crossing(CrossingChoice),
+    OtCrossing(OtDeluxeCrossingMethod),
+    Route(OtDeluxeRouteDecision),
+    Store(u16),
+}
+
+fn stats() -> impl Strategy<Value = Stats> {
+    (
+        0..=20_i32,
+        0..=10_i32,
+        0..=10_i32,
+        0..=20_i32,
+        0..=10_i32,
+        0..=50_i32,
+        0..=100_i32,
+    )
+        .prop_map(
+            |(supplies, hp, sanity, credibility, morale, allies, pants)| Stats {
+                supplies,
+                hp,
+                sanity,
+                credibility,
+                morale,
+                allies,
+                pants,
+            },
+        )
+}
+
+fn start() -> impl Strategy<Value = Start> {
+    (
+        (
+            prop_oneof![
+                Just(MechanicalPolicyId::DystrailLegacy),
+                Just(MechanicalPolicyId::OtDeluxe90s),
+            ],
+            prop_oneof![Just(GameMode::Classic), Just(GameMode::Deep)],
+            prop_oneof![
+                Just(StrategyId::Balanced),
+                Just(StrategyId::Conservative),
+                Just(StrategyId::Aggressive),
+                Just(StrategyId::ResourceManager),
+            ],
+            any::<u64>(),
+            prop_oneof![
+                Just(OtDeluxeOccupation::Banker),
+                Just(OtDeluxeOccupation::Doctor),
+                Just(OtDeluxeOccupation::Farmer),
+                Just(OtDeluxeOccupation::Teacher),
+            ],
+            3..=7_u8,
+        ),
+        (
+            stats(),
+            0..=500_000_i64,
+            prop::array::uniform4(0..=3_i32),
+            0..=5_i32,
+            prop::option::of((
+                prop_oneof![
+                    Just(CrossingKind::Checkpoint),
+                    Just(CrossingKind::BridgeOut)
+                ],
+                0.0..30.0_f32,
+            )),
+            prop::collection::vec(0..=5_u8, 1..=5),
+            prop_oneof![
+                Just(Weather::Clear),
+                Just(Weather::Storm),
+                Just(Weather::HeatWave),
+                Just(Weather::ColdSnap),
+                Just(Weather::Smoke),
+            ],
+        ),
+    )
+        .prop_map(
+            |(
+                (mechanics, mode, strategy, seed, occupation, departure_month),
+                (stats, budget_cents, spares, medicine, pending_crossing, party_sick_days, weather),
+            )| Start {
+                mechanics,
+                mode,
+                strategy,
+                seed,
+                occupation,
+                departure_month,
+                stats,
+                budget_cents,
+                spares,
+                medicine,
+                pending_crossing,
+                party_sick_days,
+                weather,
+            },
+        )
+}
+
+fn step() -> impl Strategy<Value = Step> {
+    prop_oneof![
+        4 => Just(Step::Travel),
+        2 => prop_oneof![
+            Just(DayIntent::Rest),
+            Just(DayIntent::Trade),
+            Just(DayIntent::Hunt),
+            Just(DayIntent::Fish),
+            Just(DayIntent::Forage),
+        ]
+        .prop_map(Step::Intent),
+        1 => prop_oneof![Just(PaceId::Steady), Just(PaceId::Heated), Just(PaceId::Blitz)]
+            .prop_map(Step::Pace),
+        1 => prop_oneof![Just(DietId::Mixed), Just(DietId::Quiet), Just(DietId::Doom)]
+            .prop_map(Step::Diet),
+        2 => (0..4_usize).prop_map(Step::Choice),
+        1 => prop_oneof![
+            Just(CrossingChoice::Detour),
+            Just(CrossingChoice::Bribe),
+            Just(CrossingChoice::Permit),
+        ]
+        .prop_map(Step::Crossing),
+        1 => prop_oneof![
+            Just(OtDeluxeCrossingMethod::Ford),
+            Just(OtDeluxeCrossingMethod::CaulkFloat),
+            Just(OtDeluxeCrossingMethod::Ferry),
+            Just(OtDeluxeCrossingMethod::Guide),
+        ]
+        .prop_map(Step::OtCrossing),
+        1 => prop_oneof![
+            Just(OtDeluxeRouteDecision::StayOnTrail),
+            Just(OtDeluxeRouteDecision::SubletteCutoff),
+            Just(OtDeluxeRouteDecision::DallesShortcut),
+        ]
+        .prop_map(Step::Route),
+        2 => (0..=200_u16).prop_map(Step::Store),
+    ]
+}
+
+fn build_session(start: &Start) -> JourneySession {
+    let otdeluxe = start.mechanics == MechanicalPolicyId::OtDeluxe90s;
+    let mut session = JourneySession::new_with_mechanics(
+        start.mechanics,
+        start.mode,
+        start.strategy,
+        start.seed,
+        encounter_data().clone(),
+        &EndgameTravelCfg::default_config(),
+        otdeluxe.then(|| OtDeluxeStartConfig {
+            departure_month: start.departure_month,
+            ..OtDeluxeStartConfig::new(start.occupation)
+        }),
+    );
+    session.with_state_mut(|gs| {
+        gs.stats = start.stats.clone();
+        gs.budget_cents = start.budget_cents;
+        gs.budget = i32::try_from(start.budget_cents / 100).unwrap_or(0);
+        let [tire, battery, alt, pump] = start.spares;
+        gs.inventory.spares.tire = tire;
+        gs.inventory.spares.battery = battery;
+        gs.inventory.spares.alt = alt;
+        gs.inventory.spares.pump = pump;
+        gs.inventory.medicine = start.medicine;
+        gs.weather_state.today = start.weather;
+        if !otdeluxe {
+            gs.pending_crossing =
+                start
+                    .pending_crossing
+                    .map(|(kind, computed_miles_today)| PendingCrossing {
+                        kind,
+                        computed_miles_today,
+                    });
+        }
+        if otdeluxe {
+            let names = (0..start.party_sick_days.len()).map(|idx| format!("Member {idx}"));
+            gs.ot_deluxe.party = OtDeluxePartyState::from_names(names);
+            for (member, &sick) in gs
+                .ot_deluxe
+                .party
+                .members
+                .iter_mut()
+                .zip(&start.party_sick_days)
+            {
+                member.sick_days_remaining = sick;
+            }
+        }
+    });
+    session
+}
+
+/// Apply `step`; returns whether it consumed a day tick.
+fn apply(session: &mut JourneySession, step: Step) -> bool {
+    match step {
+        Step::Travel => true,
+        Step::Intent(intent) => {
+            session.with_state_mut(|gs| gs.intent.pending = intent);
+            true
+        }
+        Step::Pace(pace) => {
+            session.with_state_mut(|gs| gs.pace = pace);
+            false
+        }
+        Step::Diet(diet) => {
+            session.with_state_mut(|gs| gs.diet = diet);
+            false
+        }
+        Step::Choice(idx) => session.with_state_mut(|gs| {
+            let offered = gs
+                .current_encounter
+                .as_ref()
+                .is_some_and(|encounter| idx < encounter.choices.len());
+            if offered {
+                gs.apply_choice(idx);
+            }
+            false
+        }),
+        Step::Crossing(choice) => {
+            session.with_state_mut(|gs| gs.set_crossing_choice(choice));
+            true
+        }
+        Step::OtCrossing(method) => {
+            session.with_state_mut(|gs| gs.set_otdeluxe_crossing_choice(method));
+            true
+        }
+        Step::Route(decision) => {
+            session.with_state_mut(|gs| gs.set_route_prompt_choice(decision));
+            true
+        }
+        Step::Store(food_lbs) => {
+            let lines = (food_lbs > 0)
+                .then_some(OtDeluxeStoreLineItem {
+                    item: OtDeluxeStoreItem::FoodLb,
+                    quantity: food_lbs,
+                })
+                .into_iter()
+                .collect();
+            session.with_state_mut(|gs| gs.set_otdeluxe_store_purchase(lines));
+            true
+        }
+    }
+}
+
+fn check_invariants(before: &GameState, after: &GameState) -> Result<(), TestCaseError> {
+    // Only Dystrail mechanics keep stats within their clamp bounds; OTDeluxe
+    // runs track the party through `ot_deluxe` and leave the stats unclamped.
+    if after.mechanical_policy == MechanicalPolicyId::DystrailLegacy {
+        let mut clamped = after.stats.clone();
+        clamped.clamp();
+        prop_assert_eq!(&clamped, &after.stats, "stats escaped their clamp bounds");
+    }
+    prop_assert_eq!(
+        i64::from(after.budget),
+        after.budget_cents / 100,
+        "budget drifted from budget_cents"
+    );
+    prop_assert!(
+        after.day >= before.day,
+        "day went backwards: {} -> {}",
+        before.day,
+        after.day
+    );
+    let days = after.day - before.day;
+    let records = after.day_records.len() - before.day_records.len();
+    prop_assert_eq!(
+        records,
+        days as usize,
+        "{} day records for {} consumed days",
+        records,
+        days
+    );
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(48))]
+
+    #[test]
+    fn daily_ticks_preserve_invariants(
+        start in start(),
+        steps in prop::collection::vec(step(), 1..40),
+    ) {
+        let mut session = build_session(&start);
+        for step in steps {
+            if !apply(&mut session, step) {
+                continue;
+            }
+            let before = session.state().clone();
+            let outcome = session.tick_day();
+            check_invariants(&before, session.state())?;
+            if outcome.ended {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn saves_round_trip_through_rehydrate(
+        start in start(),
+        steps in prop::collection::vec(step(), 0..10),
+    ) {
+        let mut session = build_session(&start);
+        for step in steps {
+            if apply(&mut session, step) && session.tick_day().ended {
+                break;
+            }
+        }
+        let json = serde_json::to_string(session.state()).expect("state serializes");
+        let restored: GameState = serde_json::from_str(&json).expect("save deserializes");
+        let restored = restored
+            .rehydrate(encounter_data().clone())
+            .expect("current saves rehydrate");
+        prop_assert_eq!(restored.day, session.state().day);
+        prop_assert_eq!(restored.day_records.len(), session.state().day_records.len());
+        prop_assert_eq!(&restored.stats, &session.state().stats);
+    }
+}
@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 use dystrail_game::GameState;
 use dystrail_game::journey::RngBundle;
@@ -16,7 +16,7 @@ fn weather_selection_and_effects_cover_branches() {
     cfg.limits.max_extreme_streak = 1;
     let model = DystrailRegionalWeather::new(cfg.clone());
 
-    let rng = Rc::new(RngBundle::from_user_seed(1));
+    let rng = Arc::new(RngBundle::from_user_seed(1));
     let mut gs = GameState {
         region: Region::Heartland,
         weather_state: WeatherState {
@@ -42,8 +42,8 @@ fn weather_selection_and_effects_cover_branches() {
 #[test]
 fn select_weather_is_deterministic_for_same_seed() {
     let cfg = deterministic_config();
-    let rng1 = Rc::new(RngBundle::from_user_seed(42));
-    let rng2 = Rc::new(RngBundle::from_user_seed(42));
+    let rng1 = Arc::new(RngBundle::from_user_seed(42));
+    let rng2 = Arc::new(RngBundle::from_user_seed(42));
     let mut gs_one = GameState {
         region: Region::Heartland,
         ..GameState::default()
@@ -63,7 +63,7 @@ fn select_weather_is_deterministic_for_same_seed() {
 #[test]
 fn weather_selection_consumes_weather_rng_only() {
     let cfg = deterministic_config();
-    let rng = Rc::new(RngBundle::from_user_seed(5));
+    let rng = Arc::new(RngBundle::from_user_seed(5));
     let mut gs = GameState {
         region: Region::Heartland,
         ..GameState::default()
@@ -87,7 +87,7 @@ fn neutral_buffer_defaults_to_clear_when_no_neutral_weights() {
             (Weather::ColdSnap, 3),
         ]),
     );
-    let rng = Rc::new(RngBundle::from_user_seed(7));
+    let rng = Arc::new(RngBundle::from_user_seed(7));
     let mut gs = GameState {
         region: Region::Heartland,
         weather_state: WeatherState {
@@ -113,7 +113,7 @@ fn neutral_buffer_prefers_smoke_when_weighted() {
             (Weather::ColdSnap, 2),
         ]),
     );
-    let rng = Rc::new(RngBundle::from_user_seed(9));
+    let rng = Arc::new(RngBundle::from_user_seed(9));
     let mut gs = GameState {
         region: Region::Heartland,
         weather_state: WeatherState {
@@ -1,8 +1,24 @@
 use anyhow::{Context, Result, bail};
+use dystrail_game::journey::Event;
+use dystrail_game::{GameState as FullGameState, PlayerAction};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::time::{Duration, Instant};
 use thirtyfour::prelude::*;
 
+use crate::logic::policy::{PlayerPolicy, PolicyDecision};
+
+/// Crossing ids in the order logic-mode runs prefer them; the bridge only lists affordable ones.
+const CROSSING_PREFERENCE: [&str; 7] = [
+    "crossing:permit",
+    "crossing:bribe",
+    "crossing:detour",
+    "crossing:ferry",
+    "crossing:guide",
+    "crossing:caulk_float",
+    "crossing:ford",
+];
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct GameState {
     pub screen: Option<String>,
@@ -11,6 +27,45 @@ pub struct GameState {
     pub pos: Option<Value>,
 }
 
+/// An action the current phase accepts, as listed by `__dystrailTest.actions()`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BridgeAction {
+    pub id: String,
+    pub label: String,
+}
+
+/// Result of `perform` or `load`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionReport {
+    pub ok: bool,
+    pub phase: String,
+    pub day: Option<u32>,
+    pub ended: bool,
+    #[serde(default)]
+    pub state_hash: Option<String>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// Kernel events recorded by the bridge since a cursor.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct EventPage {
+    pub cursor: usize,
+    pub events: Vec<Event>,
+}
+
+/// One bridge action taken on behalf of a [`PlayerPolicy`].
+#[derive(Debug, Clone)]
+pub struct PolicyStep {
+    pub action_id: String,
+    pub decision: Option<PolicyDecision>,
+    pub report: ActionReport,
+}
+
+/// Interval between `ready()` polls while waiting for a render.
+const READY_POLL: Duration = Duration::from_millis(20);
+
 #[derive(Debug, Clone)]
 pub struct TestBridge<'a> {
     driver: &'a WebDriver,
@@ -76,6 +131,165 @@ impl<'a> TestBridge<'a> {
             .await?;
         Ok(())
     }
+
+    /// Poll until the app has rendered the last [`Self::screen`] change.
+    pub async fn wait_until_ready(&self, timeout: Duration) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            #[rustfmt::skip]
+            let result = self.driver.execute("return window.__dystrailTest.ready()", vec![]).await?;
+            if result.json().as_bool() == Some(true) {
+                return Ok(());
+            }
+            if Instant::now() >= deadline {
+                bail!("bridge did not render within {timeout:?}");
+            }
+            tokio::time::sleep(READY_POLL).await;
+        }
+    }
+}
+
+impl TestBridge<'_> {
+    /// The full serialized game state, or `None` before a run starts.
+    pub async fn game_state(&self) -> Result<Option<FullGameState>> {
+        #[rustfmt::skip]
+        let result = self.driver.execute("return window.__dystrailTest.gameState()", vec![]).await?;
+        parse_full_game_state(result.json())
+    }
+
+    pub async fn phase(&self) -> Result<String> {
+        #[rustfmt::skip]
+        let result = self.driver.execute("return window.__dystrailTest.phase()", vec![]).await?;
+        serde_json::from_value(result.json().clone()).context("parsing phase")
+    }
+
+    pub async fn actions(&self) -> Result<Vec<BridgeAction>> {
+        #[rustfmt::skip]
+        let result = self.driver.execute("return window.__dystrailTest.actions()", vec![]).await?;
+        serde_json::from_value(result.json().clone()).context("parsing actions")
+    }
+
+    /// Perform an action id from [`Self::actions`]; fails if the bridge rejects it.
+    pub async fn perform(&self, action_id: &str) -> Result<ActionReport> {
+        let result = self
+            .driver
+            .execute(
+                "return window.__dystrailTest.perform(arguments[0])",
+                vec![action_id.into()],
+            )
+            .await?;
+        parse_action_report(result.json())
+    }
+
+    /// Export the current run as save JSON.
+    pub async fn save(&self) -> Result<String> {
+        #[rustfmt::skip]
+        let result = self.driver.execute("return window.__dystrailTest.save()", vec![]).await?;
+        result
+            .json()
+            .as_str()
+            .map(str::to_string)
+            .context("no run to save")
+    }
+
+    /// Replace the current run with save JSON from [`Self::save`] or a logic run.
+    pub async fn load(&self, json: &str) -> Result<ActionReport> {
+        let result = self
+            .driver
+            .execute(
+                "return window.__dystrailTest.load(arguments[0])",
+                vec![json.into()],
+            )
+            .await?;
+        parse_action_report(result.json())
+    }
+
+    pub async fn events(&self, cursor: usize) -> Result<EventPage> {
+        let result = self
+            .driver
+            .execute(
+                "return window.__dystrailTest.events(arguments[0])",
+                vec![cursor.into()],
+            )
+            .await?;
+        serde_json::from_value(result.json().clone()).context("parsing EventPage")
+    }
+
+    /// Take the next action the way a logic-mode run would, asking `policy` for
+    /// encounters, route forks and store purchases.
+    pub async fn play_turn(&self, policy: &mut (dyn PlayerPolicy + Send)) -> Result<PolicyStep> {
+        let state = self.game_state().await?.context("no run in progress")?;
+        let actions = self.actions().await?;
+        let (action_id, decision) =
+            choose_policy_action(&state, &actions, policy).context("bridge offered no actions")?;
+        let report = self.perform(&action_id).await?;
+        Ok(PolicyStep {
+            action_id,
+            decision,
+            report,
+        })
+    }
+}
+
+/// Pick the action a logic-mode run would take in `state` from the offered `actions`.
+pub fn choose_policy_action(
+    state: &FullGameState,
+    actions: &[BridgeAction],
+    policy: &mut dyn PlayerPolicy,
+) -> Option<(String, Option<PolicyDecision>)> {
+    let offered = |id: &str| actions.iter().any(|action| action.id == id);
+    if let Some(encounter) = &state.current_encounter {
+        let mut decision = policy.pick_choice(state, encounter);
+        decision.choice_index = decision
+            .choice_index
+            .min(encounter.choices.len().saturating_sub(1));
+        let id = PlayerAction::Encounter(decision.choice_index).to_string();
+        if offered(&id) {
+            return Some((id, Some(decision)));
+        }
+    }
+    if let Some(prompt) = state.ot_deluxe.route.pending_prompt {
+        let id = PlayerAction::Route(policy.pick_route(prompt)).to_string();
+        if offered(&id) {
+            return Some((id, None));
+        }
+    }
+    if state.ot_deluxe.store.pending_node.is_some() && offered("store:leave") {
+        let lines = policy.pick_store(state);
+        if !lines.is_empty() {
+            return Some((PlayerAction::StoreBuy(lines).to_string(), None));
+        }
+    }
+    let preferred = CROSSING_PREFERENCE
+        .into_iter()
+        .chain(["store:leave", "boss", "travel"])
+        .find(|id| offered(id))
+        .map(str::to_string);
+    preferred
+        .or_else(|| actions.first().map(|action| action.id.clone()))
+        .map(|id| (id, None))
+}
+
+fn parse_full_game_state(value: &Value) -> Result<Option<FullGameState>> {
+    match value {
+        Value::Null => Ok(None),
+        Value::String(json) => serde_json::from_str(json)
+            .map(Some)
+            .context("parsing full GameState"),
+        other => bail!("unexpected gameState payload: {other}"),
+    }
+}
+
+fn parse_action_report(value: &Value) -> Result<ActionReport> {
+    let report: ActionReport =
+        serde_json::from_value(value.clone()).context("parsing ActionReport")?;
+    if !report.ok {
+        bail!(
+            "bridge rejected action: {}",
+            report.error.as_deref().unwrap_or("unknown error")
+        );
+    }
+    Ok(report)
 }
 
 fn parse_bridge_available(value: &Value) -> Result<()> {
@@ -95,6 +309,7 @@ fn parse_game_state(value: &Value) -> Result<GameState> {
 mod tests {
     use super::*;
     use crate::browser::{BrowserConfig, BrowserKind, new_session};
+    use crate::logic::GameplayStrategy;
     use hyper::body::to_bytes;
     use hyper::service::{make_service_fn, service_fn};
     use hyper::{Body, Method, Request, Response, Server, StatusCode};
@@ -140,6 +355,135 @@ mod tests {
         assert!(err.to_string().contains("parsing GameState"));
     }
 
+    fn action(id: &str) -> BridgeAction {
+        BridgeAction {
+            id: id.to_string(),
+            label: id.to_string(),
+        }
+    }
+
+    fn encounter_state() -> FullGameState {
+        let choice = |label: &str| dystrail_game::data::Choice {
+            label: label.to_string(),
+            effects: dystrail_game::data::Effects::default(),
+        };
+        FullGameState {
+            current_encounter: Some(dystrail_game::data::Encounter {
+                id: String::from("enc"),
+                name: String::from("Encounter"),
+                desc: String::new(),
+                weight: 1,
+                regions: Vec::new(),
+                modes: Vec::new(),
+                choices: vec![choice("Left"), choice("Right")],
+                hard_stop: false,
+                major_repair: false,
+                chainable: false,
+            }),
+            ..FullGameState::default()
+        }
+    }
+
+    #[test]
+    fn policy_picks_encounter_choices() {
+        let state = encounter_state();
+        let actions = [action("encounter:0"), action("encounter:1")];
+        let mut policy = GameplayStrategy::Balanced.create_policy(7);
+        let (id, decision) =
+            choose_policy_action(&state, &actions, policy.as_mut()).expect("action chosen");
+        let decision = decision.expect("policy decision recorded");
+        assert_eq!(id, format!("encounter:{}", decision.choice_index));
+    }
+
+    #[test]
+    fn policy_prefers_logic_mode_crossing_order() {
+        let state = FullGameState::default();
+        let mut policy = GameplayStrategy::Balanced.create_policy(7);
+        let actions = [action("crossing:detour"), action("crossing:bribe")];
+        let (id, decision) =
+            choose_policy_action(&state, &actions, policy.as_mut()).expect("action chosen");
+        assert_eq!(id, "crossing:bribe");
+        assert!(decision.is_none());
+
+        let actions = [action("crossing:ford"), action("crossing:ferry")];
+        let (id, _) = choose_policy_action(&state, &actions, policy.as_mut()).expect("ferry");
+        assert_eq!(id, "crossing:ferry");
+
+        let actions = [action("pace:heated"), action("travel")];
+        let (id, _) = choose_policy_action(&state, &actions, policy.as_mut()).expect("travel");
+        assert_eq!(id, "travel");
+
+        let actions = [action("route:stay_on_trail")];
+        let (id, _) = choose_policy_action(&state, &actions, policy.as_mut()).expect("route");
+        assert_eq!(id, "route:stay_on_trail");
+        assert!(choose_policy_action(&state, &[], policy.as_mut()).is_none());
+    }
+
+    #[test]
+    fn policy_routes_and_shops_by_strategy() {
+        let mut state = FullGameState {
+            mechanical_policy: dystrail_game::MechanicalPolicyId::OtDeluxe90s,
+            ..FullGameState::default()
+        };
+        state.apply_otdeluxe_start_config(&dystrail_game::OtDeluxeStartConfig::default());
+        state.ot_deluxe.route.pending_prompt =
+            Some(dystrail_game::OtDeluxeRoutePrompt::DallesShortcut);
+        let actions = [
+            action("route:stay_on_trail"),
+            action("route:dalles_shortcut"),
+        ];
+        let mut aggressive = GameplayStrategy::Aggressive.create_policy(7);
+        let mut balanced = GameplayStrategy::Balanced.create_policy(7);
+        let (id, _) = choose_policy_action(&state, &actions, aggressive.as_mut()).expect("route");
+        assert_eq!(id, "route:dalles_shortcut");
+        let (id, _) = choose_policy_action(&state, &actions, balanced.as_mut()).expect("route");
+        assert_eq!(id, "route:stay_on_trail");
+
+        state.ot_deluxe.route.pending_prompt = None;
+        state.ot_deluxe.store.pending_node = Some(0);
+        let actions = [action("store:leave")];
+        let (id, _) = choose_policy_action(&state, &actions, aggressive.as_mut()).expect("store");
+        assert_eq!(id, "store:leave");
+        let mut conservative = GameplayStrategy::Conservative.create_policy(7);
+        let (id, _) = choose_policy_action(&state, &actions, conservative.as_mut()).expect("store");
+        assert!(id.starts_with("store:buy:food_lb="), "{id}");
+        assert!(id.ends_with(",wheel=1,axle=1,tongue=1"), "{id}");
+    }
+
+    #[test]
+    fn parse_full_game_state_handles_missing_run() {
+        assert!(
+            parse_full_game_state(&Value::Null)
+                .expect("null parses")
+                .is_none()
+        );
+        let json = serde_json::to_string(&FullGameState::default()).expect("serialize");
+        let parsed = parse_full_game_state(&Value::String(json)).expect("state parses");
+        assert_eq!(parsed.map(|gs| gs.day), Some(FullGameState::default().day));
+        assert!(parse_full_game_state(&json!(3)).is_err());
+    }
+
+    #[test]
+    fn parse_action_report_surfaces_bridge_errors() {
+        let report = parse_action_report(&json!({
+            "ok": true, "phase": "travel", "day": 3, "ended": false,
+            "stateHash": "00ff00ff00ff00ff"
+        }))
+        .expect("report parses");
+        assert_eq!(report.day, Some(3));
+        assert_eq!(report.state_hash.as_deref(), Some("00ff00ff00ff00ff"));
+
+        let err = parse_action_report(&json!({
+            "ok": false,
+            "phase": "travel",
+            "day": null,
+            "ended": false,
+            "error": "action `boss` is not available in phase `travel`"
+        }))
+        .expect_err("rejected action fails");
+        assert!(err.to_string().contains("not available"));
+    }
+
     fn response_with_value(value: &serde_json::Value) -> Response<Body> {
         let payload = serde_json::json!({ "value": value });
         let body = serde_json::to_vec(&payload).unwrap_or_default();
@@ -186,6 +530,22 @@ mod tests {
                     "day": 2,
                     "pos": { "x": 1 }
                 })
+            } else if script.contains("__dystrailTest.gameState")
+                || script.contains("__dystrailTest.save")
+            {
+                json!(serde_json::to_string(&FullGameState::default()).unwrap_or_default())
+            } else if script.contains("__dystrailTest.phase") {
+                json!("travel")
+            } else if script.contains("__dystrailTest.actions") {
+                json!([{ "id": "travel", "label": "travel one day" }])
+            } else if script.contains("__dystrailTest.perform")
+                || script.contains("__dystrailTest.load")
+            {
+                json!({ "ok": true, "phase": "travel", "day": 2, "ended": false })
+            } else if script.contains("__dystrailTest.ready") {
+                json!(true)
+            } else if script.contains("__dystrailTest.events") {
+                json!({ "cursor": 0, "events": [] })
             } else {
                 serde_json::Value::Null
             };
@@ -247,11 +607,32 @@ mod tests {
         bridge.click(10, 12).await.expect("click ok");
         bridge.key("w").await.expect("key ok");
         bridge.screen("travel").await.expect("screen ok");
+        bridge
+            .wait_until_ready(Duration::from_secs(1))
+            .await
+            .expect("ready ok");
         let state = bridge.state().await.expect("state ok");
         assert_eq!(state.screen.as_deref(), Some("travel"));
         assert_eq!(state.hp, Some(9));
         assert_eq!(state.day, Some(2));
 
+        let full = bridge.game_state().await.expect("game state ok");
+        assert!(full.is_some());
+        assert_eq!(bridge.phase().await.expect("phase ok"), "travel");
+        let actions = bridge.actions().await.expect("actions ok");
+        assert_eq!(actions[0].id, "travel");
+        let report = bridge.perform("travel").await.expect("perform ok");
+        assert_eq!(report.day, Some(2));
+        let snapshot = bridge.save().await.expect("save ok");
+        bridge.load(&snapshot).await.expect("load ok");
+        let page = bridge.events(0).await.expect("events ok");
+        assert!(page.events.is_empty());
+
+        let mut policy = GameplayStrategy::Balanced.create_policy(42);
+        let step = bridge.play_turn(policy.as_mut()).await.expect("turn ok");
+        assert_eq!(step.action_id, "travel");
+        assert!(step.decision.is_none());
+
         driver.quit().await.expect("quit");
         let _ = shutdown.send(());
     }
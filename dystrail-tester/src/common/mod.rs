@@ -1,4 +1,5 @@
 pub mod scenario;
 pub mod util;
+pub mod visual;
 
 pub use util::*;
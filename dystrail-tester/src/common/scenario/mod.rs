@@ -1,7 +1,8 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use thirtyfour::prelude::*;
 
 use crate::browser::TestBridge;
+use crate::common::visual::VisualRegression;
 pub mod catalog;
 
 use crate::logic::{
@@ -11,8 +12,10 @@ use catalog::find_catalog_scenario;
 use dystrail_game::GameMode;
 
 pub mod full_game;
+pub mod parity;
 pub mod playability;
 pub mod smoke;
+pub mod visual;
 
 #[derive(Debug, Clone)]
 pub struct ScenarioCtx<'a> {
@@ -20,6 +23,8 @@ pub struct ScenarioCtx<'a> {
     pub seed: u64,
     pub bridge: TestBridge<'a>,
     pub verbose: bool,
+    pub browser: String,
+    pub visual: &'a VisualRegression,
 }
 
 // Logic test scenario
@@ -50,20 +55,18 @@ pub trait CombinedScenario: BrowserScenario {
     fn as_logic_scenario(&self) -> Option<TestScenario>;
 }
 
+/// Day limit for browser replays of plans without one, matching `SimulationConfig`.
+const BROWSER_REPLAY_MAX_DAYS: u32 = 200;
+
 #[derive(Clone)]
 pub struct SimulationScenario {
     name: &'static str,
     plan: SimulationPlan,
-    browser_message: &'static str,
 }
 
 impl SimulationScenario {
     pub const fn new(name: &'static str, plan: SimulationPlan) -> Self {
-        Self {
-            name,
-            plan,
-            browser_message: "Browser testing not implemented for this simulation scenario",
-        }
+        Self { name, plan }
     }
 
     #[must_use]
@@ -74,8 +77,46 @@ impl SimulationScenario {
 
 #[async_trait::async_trait]
 impl BrowserScenario for SimulationScenario {
-    async fn run_browser(&self, _driver: &WebDriver, _ctx: &ScenarioCtx<'_>) -> Result<()> {
-        anyhow::bail!(self.browser_message)
+    /// Replays the plan's policy through the test bridge until the run ends.
+    async fn run_browser(&self, driver: &WebDriver, ctx: &ScenarioCtx<'_>) -> Result<()> {
+        driver.goto(&ctx.base_url).await?;
+        ctx.bridge.ensure_available().await?;
+        let bridge_seed = i64::try_from(ctx.seed).context("seed exceeds browser bridge range")?;
+        ctx.bridge.seed(bridge_seed).await?;
+        ctx.bridge.screen("travel").await?;
+
+        let max_days = self.plan.max_days.unwrap_or(BROWSER_REPLAY_MAX_DAYS);
+        let mut policy = self.plan.strategy.create_policy(ctx.seed);
+        let mut cursor = 0;
+        loop {
+            let step = ctx.bridge.play_turn(policy.as_mut()).await?;
+            let page = ctx.bridge.events(cursor).await?;
+            cursor = page.cursor;
+            if ctx.verbose {
+                let rationale = step
+                    .decision
+                    .and_then(|decision| decision.rationale)
+                    .map_or_else(String::new, |why| format!(" [{why}]"));
+                println!(
+                    "  🎲 {}{rationale} -> {} (day {}, {} events)",
+                    step.action_id,
+                    step.report.phase,
+                    step.report.day.unwrap_or_default(),
+                    page.events.len()
+                );
+            }
+            if step.report.ended || step.report.day.unwrap_or_default() >= max_days {
+                break;
+            }
+        }
+
+        let state = ctx
+            .bridge
+            .game_state()
+            .await?
+            .context("browser run has no game state")?;
+        anyhow::ensure!(state.day >= 1, "Game day should be >= 1, got {}", state.day);
+        Ok(())
     }
 }
 
@@ -153,6 +194,8 @@ pub fn get_scenario(
             playability::deterministic_verification_scenario(tester.clone()),
         )),
         "edge-case" | "edge" => Some(Box::new(playability::edge_case_survival_scenario())),
+        "parity" | "browser-parity" => Some(Box::new(parity::ParityScenario::new(tester.clone()))),
+        visual::VISUAL_SCENARIO | "visual-regression" => Some(Box::new(visual::VisualScenario)),
 
         // Comprehensive test scenarios
         "basic-game-creation" | "basic" => find_catalog_scenario("Basic Game State Creation")
@@ -203,6 +246,8 @@ pub fn list_scenarios() -> Vec<(&'static str, &'static str)> {
         ("resource-stress", "Resource Management Stress Test"),
         ("deterministic", "Deterministic Playthrough Verification"),
         ("edge-case", "Edge Case Survival Test"),
+        ("parity", "Browser/Native Parity"),
+        (visual::VISUAL_SCENARIO, "Visual Regression Screenshots"),
         ("basic-game-creation", "Basic Game State Creation"),
         (
             "share-code-consistency",
@@ -322,6 +367,8 @@ mod tests {
             "stats-boundaries",
             "inventory-operations",
             "game-mode-variations",
+            "parity",
+            "visual",
         ];
         for name in names {
             assert!(
@@ -331,6 +378,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn visual_scenario_is_browser_only() {
+        let assets = Arc::new(TesterAssets::load_default());
+        let tester = GameTester::new(assets, false);
+        let scenario = get_scenario("visual-regression", &tester).expect("visual scenario");
+        assert!(scenario.as_logic_scenario().is_none());
+    }
+
     #[test]
     fn strategy_scenario_sets_max_days_and_setup() {
         let scenario = strategy_scenario("Demo", GameplayStrategy::Balanced);
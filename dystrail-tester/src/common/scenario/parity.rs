@@ -0,0 +1,133 @@
+use anyhow::{Context, Result};
+use thirtyfour::prelude::*;
+
+use super::{BrowserScenario, CombinedScenario, ScenarioCtx, TestScenario};
+use crate::logic::game_tester::SimulationSummary;
+use crate::logic::parity::{Divergence, ParityReplay, ParityStep, diff_game_states};
+use crate::logic::{GameTester, GameplayStrategy, SimulationPlan, default_policy_setup};
+use dystrail_game::GameMode;
+
+const PARITY_MAX_DAYS: u32 = 30;
+
+/// Plays the browser build and `SimulationSession` in lockstep from one save,
+/// failing on the first day their state hashes disagree.
+pub struct ParityScenario {
+    tester: GameTester,
+}
+
+impl ParityScenario {
+    pub const fn new(tester: GameTester) -> Self {
+        Self { tester }
+    }
+
+    fn plan() -> SimulationPlan {
+        let strategy = GameplayStrategy::Balanced;
+        SimulationPlan::new(GameMode::Classic, strategy)
+            .with_max_days(PARITY_MAX_DAYS)
+            .with_setup(default_policy_setup(strategy))
+            .with_expectation(parity_expectation)
+    }
+
+    async fn divergence_error(
+        ctx: &ScenarioCtx<'_>,
+        replay: &ParityReplay,
+        divergence: &Divergence,
+    ) -> anyhow::Error {
+        let diffs = match ctx.bridge.game_state().await {
+            Ok(Some(browser)) => diff_game_states(&browser, replay.state()),
+            _ => Vec::new(),
+        };
+        anyhow::anyhow!(divergence.describe(&diffs))
+    }
+}
+
+fn parity_expectation(summary: &SimulationSummary) -> Result<()> {
+    anyhow::ensure!(
+        summary.metrics.days_survived > 0,
+        "Parity plan should survive at least 1 day"
+    );
+    Ok(())
+}
+
+#[async_trait::async_trait]
+impl BrowserScenario for ParityScenario {
+    async fn run_browser(&self, driver: &WebDriver, ctx: &ScenarioCtx<'_>) -> Result<()> {
+        driver.goto(&ctx.base_url).await?;
+        ctx.bridge.ensure_available().await?;
+
+        let plan = Self::plan();
+        let save = serde_json::to_string(&self.tester.initial_state(&plan, ctx.seed))?;
+        let loaded = ctx.bridge.load(&save).await?;
+        let mut replay = ParityReplay::new(self.tester.resume_session(&plan, ctx.seed, &save)?);
+        let start_hash = loaded
+            .state_hash
+            .context("bridge load did not report a state hash")?;
+        if let Some(divergence) = replay.check_start(&start_hash) {
+            return Err(Self::divergence_error(ctx, &replay, &divergence).await);
+        }
+
+        let mut policy = plan.strategy.create_policy(ctx.seed);
+        loop {
+            let turn = ctx.bridge.play_turn(policy.as_mut()).await?;
+            let day = turn.report.day.unwrap_or_default();
+            let step = ParityStep {
+                action_id: turn.action_id,
+                day,
+                state_hash: turn
+                    .report
+                    .state_hash
+                    .context("bridge perform did not report a state hash")?,
+            };
+            if let Some(divergence) = replay.apply(&step)? {
+                return Err(Self::divergence_error(ctx, &replay, &divergence).await);
+            }
+            if ctx.verbose {
+                println!(
+                    "  🟰 {} -> day {day} hash {}",
+                    step.action_id, step.state_hash
+                );
+            }
+            if turn.report.ended || day >= PARITY_MAX_DAYS {
+                break;
+            }
+        }
+
+        if ctx.verbose {
+            println!("  ✅ {} steps matched native replay", replay.steps());
+        }
+        Ok(())
+    }
+}
+
+impl CombinedScenario for ParityScenario {
+    fn as_logic_scenario(&self) -> Option<TestScenario> {
+        Some(TestScenario::simulation(
+            "Browser/Native Parity",
+            Self::plan(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::TesterAssets;
+    use std::sync::Arc;
+
+    #[test]
+    fn parity_scenario_builds_logic_plan() {
+        let tester = GameTester::new(Arc::new(TesterAssets::load_default()), false);
+        let logic = ParityScenario::new(tester.clone())
+            .as_logic_scenario()
+            .expect("logic scenario");
+        assert_eq!(logic.plan.max_days, Some(PARITY_MAX_DAYS));
+        assert!(logic.plan.setup.is_some());
+
+        let summary = tester.run_plan(&logic.plan, 42);
+        for expectation in &logic.plan.expectations {
+            expectation
+                .evaluate(&summary)
+                .expect("parity plan survives");
+        }
+    }
+}
@@ -74,6 +74,18 @@ impl BrowserScenario for SmokeScenario {
             anyhow::ensure!(day >= 1, "Game day should be >= 1, got {day}");
         }
 
+        let phase = ctx.bridge.phase().await?;
+        let snapshot = ctx.bridge.save().await?;
+        let reloaded = ctx.bridge.load(&snapshot).await?;
+        anyhow::ensure!(
+            reloaded.phase == phase,
+            "Reloading a save should return to {phase}, got {}",
+            reloaded.phase
+        );
+        if ctx.verbose {
+            println!("  💾 Save round-tripped through the bridge in phase {phase}");
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,82 @@
+use anyhow::{Context, Result};
+use std::time::Duration;
+use thirtyfour::prelude::*;
+
+use super::{BrowserScenario, CombinedScenario, ScenarioCtx, TestScenario};
+use crate::common::visual::BaselineKey;
+
+pub const VISUAL_SCENARIO: &str = "visual";
+
+/// Bridge screen states captured for baselines, in visiting order.
+pub const VISUAL_SCREENS: [&str; 12] = [
+    "menu",
+    "persona",
+    "outfitting",
+    "travel",
+    "pace-diet",
+    "camp",
+    "store",
+    "encounter",
+    "crossing",
+    "boss",
+    "result-victory",
+    "result-pants",
+];
+
+/// Longest wait for the app to render a bridge screen change.
+const RENDER_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Screenshots each bridge screen state at every configured viewport and
+/// compares it against the stored baseline.
+pub struct VisualScenario;
+
+#[async_trait::async_trait]
+impl BrowserScenario for VisualScenario {
+    async fn run_browser(&self, driver: &WebDriver, ctx: &ScenarioCtx<'_>) -> Result<()> {
+        let mut failures = Vec::new();
+        for &viewport in &ctx.visual.config().viewports {
+            driver
+                .set_window_rect(0, 0, viewport.width, viewport.height)
+                .await
+                .with_context(|| format!("resizing window to {viewport}"))?;
+            driver.goto(&ctx.base_url).await?;
+            ctx.bridge.ensure_available().await?;
+            let bridge_seed =
+                i64::try_from(ctx.seed).context("seed exceeds browser bridge range")?;
+            ctx.bridge.seed(bridge_seed).await?;
+
+            for screen in VISUAL_SCREENS {
+                ctx.bridge.screen(screen).await?;
+                ctx.bridge.wait_until_ready(RENDER_TIMEOUT).await?;
+                let png = driver.screenshot_as_png().await?;
+                let key = BaselineKey {
+                    scenario: VISUAL_SCENARIO.to_string(),
+                    screen: screen.to_string(),
+                    browser: ctx.browser.clone(),
+                    viewport,
+                    seed: ctx.seed,
+                };
+                let check = ctx.visual.check(key, &png)?;
+                if ctx.verbose {
+                    println!("  📸 {check}");
+                }
+                if check.status.is_failure() {
+                    failures.push(check.to_string());
+                }
+            }
+        }
+        anyhow::ensure!(
+            failures.is_empty(),
+            "{} screen(s) differ from baseline:\n  {}",
+            failures.len(),
+            failures.join("\n  ")
+        );
+        Ok(())
+    }
+}
+
+impl CombinedScenario for VisualScenario {
+    fn as_logic_scenario(&self) -> Option<TestScenario> {
+        None
+    }
+}
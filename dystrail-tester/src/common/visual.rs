@@ -0,0 +1,504 @@
+//! Screenshot baselines, perceptual image diffs and the HTML comparison report.
+
+use anyhow::{Context, Result};
+use image::{Rgba, RgbaImage};
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+
+/// Per-pixel YIQ distance (0–1) below which two pixels count as equal.
+const PIXEL_TOLERANCE: f64 = 0.1;
+/// Largest possible YIQ delta between two opaque colors.
+const MAX_YIQ_DELTA: f64 = 35_215.0;
+const DIFF_HIGHLIGHT: Rgba<u8> = Rgba([255, 0, 0, 255]);
+
+/// Browser window size screenshots are taken at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Viewport {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl fmt::Display for Viewport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}x{}", self.width, self.height)
+    }
+}
+
+impl FromStr for Viewport {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (width, height) = s
+            .trim()
+            .split_once('x')
+            .with_context(|| format!("viewport `{s}` should look like 1280x800"))?;
+        Ok(Self {
+            width: width.parse().context("viewport width")?,
+            height: height.parse().context("viewport height")?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VisualConfig {
+    pub baseline_dir: PathBuf,
+    pub output_dir: PathBuf,
+    /// Fraction of pixels allowed to differ before a screen fails.
+    pub threshold: f64,
+    pub update_baselines: bool,
+    pub viewports: Vec<Viewport>,
+}
+
+/// Identifies one baseline image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BaselineKey {
+    pub scenario: String,
+    pub screen: String,
+    pub browser: String,
+    pub viewport: Viewport,
+    /// Run seed the screen was rendered from.
+    pub seed: u64,
+}
+
+impl BaselineKey {
+    /// `<scenario>/seed-<seed>/<screen>/<browser>-<viewport>`, shared by baselines and outputs.
+    #[must_use]
+    pub fn relative_path(&self) -> PathBuf {
+        Path::new(&self.scenario)
+            .join(format!("seed-{}", self.seed))
+            .join(&self.screen)
+            .join(format!("{}-{}", self.browser, self.viewport))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VisualStatus {
+    Matched,
+    Changed,
+    SizeMismatch,
+    BaselineCreated,
+    BaselineUpdated,
+}
+
+impl VisualStatus {
+    #[must_use]
+    pub const fn is_failure(self) -> bool {
+        matches!(self, Self::Changed | Self::SizeMismatch)
+    }
+
+    const fn label(self) -> &'static str {
+        match self {
+            Self::Matched => "matched",
+            Self::Changed => "changed",
+            Self::SizeMismatch => "size mismatch",
+            Self::BaselineCreated => "baseline created",
+            Self::BaselineUpdated => "baseline updated",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VisualCheck {
+    pub key: BaselineKey,
+    pub status: VisualStatus,
+    /// Fraction of pixels that differ from the baseline.
+    pub diff_ratio: f64,
+}
+
+impl fmt::Display for VisualCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{} [{} {} seed {}]: {} ({:.3}% pixels differ)",
+            self.key.scenario,
+            self.key.screen,
+            self.key.browser,
+            self.key.viewport,
+            self.key.seed,
+            self.status.label(),
+            self.diff_ratio * 100.0
+        )
+    }
+}
+
+pub struct ImageDiff {
+    pub differing_pixels: u64,
+    pub total_pixels: u64,
+    /// The baseline faded to grayscale with differing pixels in red.
+    pub image: RgbaImage,
+}
+
+impl ImageDiff {
+    #[must_use]
+    pub fn ratio(&self) -> f64 {
+        if self.total_pixels == 0 {
+            return 0.0;
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let ratio = self.differing_pixels as f64 / self.total_pixels as f64;
+        ratio
+    }
+}
+
+/// Compare two images pixel by pixel in YIQ space; `None` when sizes differ.
+#[must_use]
+pub fn diff_images(baseline: &RgbaImage, actual: &RgbaImage) -> Option<ImageDiff> {
+    if baseline.dimensions() != actual.dimensions() {
+        return None;
+    }
+    let cutoff = MAX_YIQ_DELTA * PIXEL_TOLERANCE * PIXEL_TOLERANCE;
+    let mut image = RgbaImage::new(baseline.width(), baseline.height());
+    let mut differing_pixels = 0;
+    for ((x, y, expected), found) in baseline.enumerate_pixels().zip(actual.pixels()) {
+        let pixel = if yiq_delta(*expected, *found) > cutoff {
+            differing_pixels += 1;
+            DIFF_HIGHLIGHT
+        } else {
+            faded(*expected)
+        };
+        image.put_pixel(x, y, pixel);
+    }
+    Some(ImageDiff {
+        differing_pixels,
+        total_pixels: u64::from(baseline.width()) * u64::from(baseline.height()),
+        image,
+    })
+}
+
+/// Colors blended onto white, as the eye sees them on the page.
+fn blended(pixel: Rgba<u8>) -> [f64; 3] {
+    let alpha = f64::from(pixel[3]) / 255.0;
+    [0, 1, 2].map(|channel| 255.0 + (f64::from(pixel[channel]) - 255.0) * alpha)
+}
+
+fn yiq(rgb: [f64; 3]) -> [f64; 3] {
+    let [r, g, b] = rgb;
+    [
+        0.298_895_31 * r + 0.586_622_47 * g + 0.114_482_23 * b,
+        0.595_977_99 * r - 0.274_176_10 * g - 0.321_801_89 * b,
+        0.211_470_17 * r - 0.522_617_11 * g + 0.311_146_94 * b,
+    ]
+}
+
+fn yiq_delta(left: Rgba<u8>, right: Rgba<u8>) -> f64 {
+    let [y1, i1, q1] = yiq(blended(left));
+    let [y2, i2, q2] = yiq(blended(right));
+    0.5053 * (y1 - y2).powi(2) + 0.299 * (i1 - i2).powi(2) + 0.1957 * (q1 - q2).powi(2)
+}
+
+fn faded(pixel: Rgba<u8>) -> Rgba<u8> {
+    let [luma, _, _] = yiq(blended(pixel));
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let value = (255.0 - (255.0 - luma) * 0.2).clamp(0.0, 255.0) as u8;
+    Rgba([value, value, value, 255])
+}
+
+/// Baseline store for one tester run; collects every check for the report.
+#[derive(Debug)]
+pub struct VisualRegression {
+    config: VisualConfig,
+    checks: Mutex<Vec<VisualCheck>>,
+}
+
+impl VisualRegression {
+    pub const fn new(config: VisualConfig) -> Self {
+        Self {
+            config,
+            checks: Mutex::new(Vec::new()),
+        }
+    }
+
+    #[must_use]
+    pub const fn config(&self) -> &VisualConfig {
+        &self.config
+    }
+
+    #[must_use]
+    pub fn checks(&self) -> Vec<VisualCheck> {
+        self.checks
+            .lock()
+            .map(|checks| checks.clone())
+            .unwrap_or_default()
+    }
+
+    /// Compare a PNG screenshot with its baseline, writing baseline/actual/diff
+    /// images next to the report. Missing baselines are recorded, not failed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the screenshot cannot be decoded or images
+    /// cannot be read or written.
+    pub fn check(&self, key: BaselineKey, png: &[u8]) -> Result<VisualCheck> {
+        let actual = image::load_from_memory(png)
+            .context("decoding screenshot")?
+            .to_rgba8();
+        let relative = key.relative_path();
+        let baseline_path = self
+            .config
+            .baseline_dir
+            .join(relative.with_extension("png"));
+        let out_dir = self.config.output_dir.join(&relative);
+        fs::create_dir_all(&out_dir).context("creating visual output dir")?;
+        actual
+            .save(out_dir.join("actual.png"))
+            .context("writing actual screenshot")?;
+
+        let (status, diff_ratio) = if self.config.update_baselines || !baseline_path.exists() {
+            let status = if baseline_path.exists() {
+                VisualStatus::BaselineUpdated
+            } else {
+                VisualStatus::BaselineCreated
+            };
+            if let Some(parent) = baseline_path.parent() {
+                fs::create_dir_all(parent).context("creating baseline dir")?;
+            }
+            actual
+                .save(&baseline_path)
+                .with_context(|| format!("writing baseline {}", baseline_path.display()))?;
+            (status, 0.0)
+        } else {
+            let baseline = image::open(&baseline_path)
+                .with_context(|| format!("reading baseline {}", baseline_path.display()))?
+                .to_rgba8();
+            baseline
+                .save(out_dir.join("baseline.png"))
+                .context("copying baseline")?;
+            match diff_images(&baseline, &actual) {
+                Some(diff) => {
+                    diff.image
+                        .save(out_dir.join("diff.png"))
+                        .context("writing diff image")?;
+                    let ratio = diff.ratio();
+                    let status = if ratio > self.config.threshold {
+                        VisualStatus::Changed
+                    } else {
+                        VisualStatus::Matched
+                    };
+                    (status, ratio)
+                }
+                None => (VisualStatus::SizeMismatch, 1.0),
+            }
+        };
+
+        let check = VisualCheck {
+            key,
+            status,
+            diff_ratio,
+        };
+        if let Ok(mut checks) = self.checks.lock() {
+            checks.push(check.clone());
+        }
+        Ok(check)
+    }
+
+    /// Write `report.html` into the output dir; `None` when nothing was checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the report cannot be written.
+    pub fn write_report(&self) -> Result<Option<PathBuf>> {
+        let checks = self.checks();
+        if checks.is_empty() {
+            return Ok(None);
+        }
+        fs::create_dir_all(&self.config.output_dir).context("creating visual output dir")?;
+        let path = self.config.output_dir.join("report.html");
+        fs::write(&path, render_report(&checks, self.config.threshold))
+            .context("writing visual report")?;
+        Ok(Some(path))
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn image_cell(dir: &str, name: &str, present: bool) -> String {
+    if present {
+        format!("<td><img src=\"{dir}/{name}.png\" alt=\"{name}\"></td>")
+    } else {
+        String::from("<td class=\"missing\">—</td>")
+    }
+}
+
+fn render_report(checks: &[VisualCheck], threshold: f64) -> String {
+    let failures = checks
+        .iter()
+        .filter(|check| check.status.is_failure())
+        .count();
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><title>Dystrail visual regression</title>\n\
+         <style>body{{font-family:sans-serif}}table{{border-collapse:collapse}}\
+         td,th{{border:1px solid #ccc;padding:4px;vertical-align:top}}img{{max-width:360px}}\
+         .fail{{background:#fdd}}.missing{{color:#999;text-align:center}}</style></head><body>\n\
+         <h1>Visual regression</h1>\n<p>{} screens checked, {failures} failed (threshold {:.3}% of pixels)</p>\n\
+         <table><tr><th>Screen</th><th>Status</th><th>Baseline</th><th>Actual</th><th>Diff</th></tr>\n",
+        checks.len(),
+        threshold * 100.0
+    );
+    for check in checks {
+        let dir = escape_html(
+            &check
+                .key
+                .relative_path()
+                .to_string_lossy()
+                .replace('\\', "/"),
+        );
+        let compared = matches!(
+            check.status,
+            VisualStatus::Matched | VisualStatus::Changed | VisualStatus::SizeMismatch
+        );
+        let diffed = matches!(check.status, VisualStatus::Matched | VisualStatus::Changed);
+        let _ = writeln!(
+            html,
+            "<tr class=\"{}\"><td>{}/{}<br>{} {} seed {}</td><td>{} ({:.3}%)</td>{}{}{}</tr>",
+            if check.status.is_failure() {
+                "fail"
+            } else {
+                "ok"
+            },
+            escape_html(&check.key.scenario),
+            escape_html(&check.key.screen),
+            escape_html(&check.key.browser),
+            check.key.viewport,
+            check.key.seed,
+            check.status.label(),
+            check.diff_ratio * 100.0,
+            image_cell(&dir, "baseline", compared),
+            image_cell(&dir, "actual", true),
+            image_cell(&dir, "diff", diffed),
+        );
+    }
+    html.push_str("</table>\n</body></html>\n");
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solid(width: u32, height: u32, color: [u8; 4]) -> RgbaImage {
+        RgbaImage::from_pixel(width, height, Rgba(color))
+    }
+
+    fn png(image: &RgbaImage) -> Vec<u8> {
+        let mut bytes = std::io::Cursor::new(Vec::new());
+        image
+            .write_to(&mut bytes, image::ImageFormat::Png)
+            .expect("encode png");
+        bytes.into_inner()
+    }
+
+    fn temp_config(label: &str, threshold: f64) -> VisualConfig {
+        let root =
+            std::env::temp_dir().join(format!("dystrail-visual-{label}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        VisualConfig {
+            baseline_dir: root.join("baselines"),
+            output_dir: root.join("out"),
+            threshold,
+            update_baselines: false,
+            viewports: vec![Viewport {
+                width: 4,
+                height: 4,
+            }],
+        }
+    }
+
+    fn key(screen: &str) -> BaselineKey {
+        BaselineKey {
+            scenario: String::from("visual"),
+            screen: screen.to_string(),
+            browser: String::from("chrome"),
+            viewport: Viewport {
+                width: 4,
+                height: 4,
+            },
+            seed: 7,
+        }
+    }
+
+    #[test]
+    fn viewport_parses_and_displays() {
+        let viewport: Viewport = "1280x800".parse().expect("viewport parses");
+        assert_eq!(
+            viewport,
+            Viewport {
+                width: 1280,
+                height: 800
+            }
+        );
+        assert_eq!(viewport.to_string(), "1280x800");
+        assert!("wide".parse::<Viewport>().is_err());
+    }
+
+    #[test]
+    fn diff_ignores_imperceptible_changes_and_flags_real_ones() {
+        let baseline = solid(4, 4, [200, 200, 200, 255]);
+        let mut actual = solid(4, 4, [201, 200, 200, 255]);
+        let diff = diff_images(&baseline, &actual).expect("same size");
+        assert_eq!(diff.differing_pixels, 0);
+
+        actual.put_pixel(1, 2, Rgba([0, 0, 0, 255]));
+        let diff = diff_images(&baseline, &actual).expect("same size");
+        assert_eq!(diff.differing_pixels, 1);
+        assert!((diff.ratio() - 1.0 / 16.0).abs() < f64::EPSILON);
+        assert_eq!(*diff.image.get_pixel(1, 2), DIFF_HIGHLIGHT);
+
+        assert!(diff_images(&baseline, &solid(2, 2, [0, 0, 0, 255])).is_none());
+    }
+
+    #[test]
+    fn check_creates_compares_and_updates_baselines() {
+        let mut config = temp_config("check", 0.05);
+        let visual = VisualRegression::new(config.clone());
+        let base = solid(4, 4, [10, 120, 40, 255]);
+        let created = visual.check(key("travel"), &png(&base)).expect("check");
+        assert_eq!(created.status, VisualStatus::BaselineCreated);
+        assert!(
+            config
+                .baseline_dir
+                .join("visual/seed-7/travel/chrome-4x4.png")
+                .exists()
+        );
+
+        let matched = visual.check(key("travel"), &png(&base)).expect("check");
+        assert_eq!(matched.status, VisualStatus::Matched);
+
+        let mut changed = base.clone();
+        changed.put_pixel(0, 0, Rgba([255, 255, 255, 255]));
+        changed.put_pixel(3, 3, Rgba([255, 255, 255, 255]));
+        let failed = visual.check(key("travel"), &png(&changed)).expect("check");
+        assert_eq!(failed.status, VisualStatus::Changed);
+        assert!(failed.status.is_failure());
+        let out = config.output_dir.join("visual/seed-7/travel/chrome-4x4");
+        for name in ["baseline.png", "actual.png", "diff.png"] {
+            assert!(out.join(name).exists(), "{name} written");
+        }
+
+        let report = visual.write_report().expect("report").expect("path");
+        let html = fs::read_to_string(report).expect("read report");
+        assert!(html.contains("3 screens checked, 1 failed"));
+        assert!(html.contains("visual/seed-7/travel/chrome-4x4/diff.png"));
+
+        config.update_baselines = true;
+        let updating = VisualRegression::new(config.clone());
+        let updated = updating
+            .check(key("travel"), &png(&changed))
+            .expect("check");
+        assert_eq!(updated.status, VisualStatus::BaselineUpdated);
+        let _ = fs::remove_dir_all(config.baseline_dir.parent().expect("root"));
+    }
+
+    #[test]
+    fn empty_runs_write_no_report() {
+        let visual = VisualRegression::new(temp_config("empty", 0.0));
+        assert!(visual.write_report().expect("report").is_none());
+    }
+}
@@ -0,0 +1,76 @@
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use dystrail_game::{EventSink, JsonlEventSink};
+
+/// Event sink shared by every simulation a tester run drives.
+pub type SharedEventSink = Arc<Mutex<dyn EventSink + Send>>;
+
+/// JSONL event stream backed by a buffered file.
+pub type FileEventSink = JsonlEventSink<BufWriter<File>>;
+
+/// Create (or truncate) `path` and open a JSONL event stream on it.
+pub fn open_event_stream(path: &Path) -> io::Result<Arc<Mutex<FileEventSink>>> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    let file = File::create(path)?;
+    Ok(Arc::new(Mutex::new(JsonlEventSink::new(BufWriter::new(
+        file,
+    )))))
+}
+
+/// Flush the stream and return the number of records written.
+pub fn finish_event_stream(sink: &Mutex<FileEventSink>) -> io::Result<usize> {
+    let mut sink = lock_sink(sink);
+    sink.flush()?;
+    Ok(sink.lines_written())
+}
+
+pub(crate) fn lock_sink<T: ?Sized>(sink: &Mutex<T>) -> MutexGuard<'_, T> {
+    sink.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::{GameTester, GameplayStrategy, SimulationPlan, TesterAssets};
+    use dystrail_game::GameMode;
+
+    #[test]
+    fn simulation_runs_stream_to_file() {
+        let dir = std::env::temp_dir().join(format!(
+            "dystrail-events-{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos()
+        ));
+        let path = dir.join("events.jsonl");
+        let stream = open_event_stream(&path).unwrap();
+        let tester = GameTester::new(Arc::new(TesterAssets::load_default()), false)
+            .with_event_sink(stream.clone());
+        let plan =
+            SimulationPlan::new(GameMode::Classic, GameplayStrategy::Balanced).with_max_days(6);
+        let _ = tester.run_plan(&plan, 1337);
+
+        let written = finish_event_stream(&stream).unwrap();
+        assert!(written > 0);
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(text.lines().count(), written);
+        let first: serde_json::Value = serde_json::from_str(text.lines().next().unwrap()).unwrap();
+        assert_eq!(
+            first["schema_version"],
+            dystrail_game::KERNEL_EVENT_CODE_SCHEMA_VERSION
+        );
+        let run = format!(
+            "{}:{}",
+            GameplayStrategy::Balanced,
+            dystrail_game::encode_friendly(false, 1337)
+        );
+        assert_eq!(first["run"], run.as_str());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,99 @@
+use std::sync::{Arc, Mutex};
+
+use dystrail_game::{EventSink, KernelDecisionTrace, KernelEvent, TraceExplanation};
+
+use super::events::lock_sink;
+use super::{GameTester, SimulationPlan, TesterAssets};
+
+/// Event sink that keeps the decision traces recorded on a single day.
+#[derive(Debug, Default)]
+pub struct TraceCollector {
+    day: u32,
+    traces: Vec<KernelDecisionTrace>,
+}
+
+impl TraceCollector {
+    #[must_use]
+    pub const fn for_day(day: u32) -> Self {
+        Self {
+            day,
+            traces: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn traces(&self) -> &[KernelDecisionTrace] {
+        &self.traces
+    }
+}
+
+impl EventSink for TraceCollector {
+    fn emit_event(&mut self, _event: &KernelEvent) {}
+
+    fn emit_trace(&mut self, day: u32, trace: &KernelDecisionTrace) {
+        if day == self.day {
+            self.traces.push(trace.clone());
+        }
+    }
+}
+
+/// Replay `plan` for `seed` up to `day` and explain every decision made that day.
+#[must_use]
+pub fn explain_day(
+    assets: Arc<TesterAssets>,
+    plan: &SimulationPlan,
+    seed: u64,
+    day: u32,
+) -> Vec<TraceExplanation> {
+    let collector = Arc::new(Mutex::new(TraceCollector::for_day(day)));
+    let tester = GameTester::new(assets, false).with_event_sink(collector.clone());
+    let plan = plan.clone().with_max_days(day.saturating_add(1));
+    let _ = tester.run_plan(&plan, seed);
+    lock_sink(&collector)
+        .traces()
+        .iter()
+        .map(KernelDecisionTrace::explain)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::GameplayStrategy;
+    use dystrail_game::GameMode;
+
+    #[test]
+    fn explain_day_is_deterministic_and_day_scoped() {
+        let assets = Arc::new(TesterAssets::load_default());
+        let plan = SimulationPlan::new(GameMode::Classic, GameplayStrategy::Balanced);
+        let mut found = None;
+        for day in 1..=30 {
+            let explanations = explain_day(assets.clone(), &plan, 1337, day);
+            if !explanations.is_empty() {
+                found = Some((day, explanations));
+                break;
+            }
+        }
+        let (day, explanations) = found.expect("a decision trace within 30 days");
+        let again = explain_day(assets, &plan, 1337, day);
+        assert_eq!(explanations, again);
+        for explanation in &explanations {
+            assert!(explanation.summary().contains(&explanation.chosen_id));
+        }
+    }
+
+    #[test]
+    fn collector_ignores_other_days() {
+        let mut collector = TraceCollector::for_day(4);
+        let trace = KernelDecisionTrace {
+            pool_id: String::from("pool"),
+            roll: dystrail_game::journey::RollValue::U32(0),
+            roll_scale: dystrail_game::RollScale::Index,
+            candidates: Vec::new(),
+            chosen_id: String::from("a"),
+        };
+        collector.emit_trace(3, &trace);
+        collector.emit_trace(4, &trace);
+        assert_eq!(collector.traces().len(), 1);
+    }
+}
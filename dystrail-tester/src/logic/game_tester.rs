@@ -17,10 +17,12 @@ use dystrail_game::state::{
 use dystrail_game::store::{Grants, Store, StoreItem, calculate_effective_price};
 use dystrail_game::weather::{Weather, WeatherConfig};
 use dystrail_game::{
-    DietId, GameMode, GameState, PaceId, PolicyKind, Region, compute_day_ledger_metrics,
+    DietId, GameMode, GameState, Graveyard, OtDeluxeStartConfig, PaceId, PolicyKind, Region,
+    compute_day_ledger_metrics,
 };
 use serde_json;
 
+use crate::logic::events::{SharedEventSink, lock_sink};
 use crate::logic::policy::GameplayStrategy;
 use crate::logic::simulation::{DecisionRecord, SimulationConfig, SimulationSession, TurnOutcome};
 
@@ -267,6 +269,8 @@ pub struct SimulationPlan {
     pub max_days: Option<u32>,
     pub setup: Option<fn(&mut GameState)>,
     pub expectations: Vec<SimulationExpectation>,
+    /// Runs `OTDeluxe` mechanics with this start when set.
+    pub otdeluxe_start: Option<OtDeluxeStartConfig>,
 }
 
 impl SimulationPlan {
@@ -278,6 +282,7 @@ impl SimulationPlan {
             max_days: None,
             setup: None,
             expectations: Vec::new(),
+            otdeluxe_start: None,
         }
     }
 
@@ -287,6 +292,14 @@ impl SimulationPlan {
         self
     }
 
+    /// Play `OTDeluxe` mechanics leaving on the first of `month`.
+    #[must_use]
+    pub fn with_otdeluxe_departure(mut self, month: u8) -> Self {
+        let start = self.otdeluxe_start.unwrap_or_default();
+        self.otdeluxe_start = Some(start.with_departure(month, start.departure_year));
+        self
+    }
+
     #[must_use]
     pub fn with_setup(mut self, setup: fn(&mut GameState)) -> Self {
         self.setup = Some(setup);
@@ -354,11 +367,32 @@ pub struct SimulationSummary {
 pub struct GameTester {
     verbose: bool,
     assets: Arc<TesterAssets>,
+    event_sink: Option<SharedEventSink>,
+    graveyard: Option<Arc<Graveyard>>,
 }
 
 impl GameTester {
     pub const fn new(assets: Arc<TesterAssets>, verbose: bool) -> Self {
-        Self { verbose, assets }
+        Self {
+            verbose,
+            assets,
+            event_sink: None,
+            graveyard: None,
+        }
+    }
+
+    /// Stream the events of every simulated run to `sink`.
+    #[must_use]
+    pub fn with_event_sink(mut self, sink: SharedEventSink) -> Self {
+        self.event_sink = Some(sink);
+        self
+    }
+
+    /// Surface the graves of earlier runs on every simulated trail.
+    #[must_use]
+    pub fn with_graveyard(mut self, graveyard: Graveyard) -> Self {
+        self.graveyard = Some(Arc::new(graveyard));
+        self
     }
 
     pub const fn verbose(&self) -> bool {
@@ -732,6 +766,8 @@ impl GameTester {
             spare_battery: item.grants.spare_battery * qty,
             spare_alt: item.grants.spare_alt * qty,
             spare_pump: item.grants.spare_pump * qty,
+            medicine: item.grants.medicine * qty,
+            vehicle_upgrades: item.grants.vehicle_upgrades.clone(),
             enabled: item.grants.enabled,
         };
 
@@ -754,16 +790,26 @@ impl GameTester {
         }
     }
 
-    pub fn run_plan(&self, plan: &SimulationPlan, seed: u64) -> SimulationSummary {
+    fn plan_config(plan: &SimulationPlan, seed: u64) -> SimulationConfig {
         let max_days = plan.max_days.unwrap_or(200);
+        let config = SimulationConfig::new(plan.mode, plan.strategy, seed).with_max_days(max_days);
+        match plan.otdeluxe_start {
+            Some(start) => config.with_otdeluxe_start(start),
+            None => config,
+        }
+    }
+
+    fn prepare_session(&self, plan: &SimulationPlan, seed: u64) -> SimulationSession {
         let mut session = SimulationSession::new(
-            SimulationConfig::new(plan.mode, plan.strategy, seed).with_max_days(max_days),
+            Self::plan_config(plan, seed),
             self.assets.encounter_data.clone(),
             self.assets.camp_config.clone(),
             &self.assets.endgame_config,
             self.assets.boss_config.clone(),
         );
-
+        if let Some(graveyard) = &self.graveyard {
+            session.state_mut().set_trail_graves(graveyard);
+        }
         self.assign_party(session.state_mut(), plan.strategy, seed);
         self.apply_persona_choice(session.state_mut(), plan.strategy);
 
@@ -773,6 +819,49 @@ impl GameTester {
 
         self.apply_store_loadout(session.state_mut(), plan.strategy, seed);
         Self::configure_strategy_settings(session.state_mut(), plan.strategy);
+        session
+    }
+
+    /// The state `run_plan` starts simulating from.
+    #[must_use]
+    pub fn initial_state(&self, plan: &SimulationPlan, seed: u64) -> GameState {
+        self.prepare_session(plan, seed).into_state()
+    }
+
+    /// Resume a save the way the web client's bridge `load` does, for native replays.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the save does not parse or fails to rehydrate.
+    pub fn resume_session(
+        &self,
+        plan: &SimulationPlan,
+        seed: u64,
+        save_json: &str,
+    ) -> anyhow::Result<SimulationSession> {
+        let state: GameState = serde_json::from_str(save_json)?;
+        let state = state.rehydrate(self.assets.encounter_data.clone())?;
+        Ok(SimulationSession::from_state(
+            Self::plan_config(plan, seed),
+            state,
+            self.assets.camp_config.clone(),
+            &self.assets.endgame_config,
+            self.assets.boss_config.clone(),
+        ))
+    }
+
+    pub fn run_plan(&self, plan: &SimulationPlan, seed: u64) -> SimulationSummary {
+        let max_days = plan.max_days.unwrap_or(200);
+        let mut session = self.prepare_session(plan, seed);
+        if let Some(sink) = &self.event_sink {
+            let run = format!(
+                "{}:{}",
+                plan.strategy,
+                dystrail_game::encode_friendly(plan.mode.is_deep(), seed)
+            );
+            lock_sink(sink).begin_run(&run);
+            session = session.with_event_sink(Arc::clone(sink));
+        }
 
         if self.verbose {
             log_initial_state(seed, plan, session.state());
@@ -0,0 +1,155 @@
+use std::fs;
+use std::path::PathBuf;
+
+use dystrail_game::{Graveyard, GraveyardStorage};
+use thiserror::Error;
+
+use crate::logic::PlayabilityRecord;
+
+#[derive(Debug, Error)]
+pub enum FileGraveyardError {
+    #[error("graveyard I/O failed for {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("graveyard at {path} is malformed: {source}")]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+/// JSON file-backed trail graveyard shared by successive tester sweeps.
+#[derive(Debug, Clone)]
+pub struct FileGraveyardStorage {
+    path: PathBuf,
+}
+
+impl FileGraveyardStorage {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    fn io_error(&self, source: std::io::Error) -> FileGraveyardError {
+        FileGraveyardError::Io {
+            path: self.path.clone(),
+            source,
+        }
+    }
+
+    fn parse_error(&self, source: serde_json::Error) -> FileGraveyardError {
+        FileGraveyardError::Parse {
+            path: self.path.clone(),
+            source,
+        }
+    }
+}
+
+impl GraveyardStorage for FileGraveyardStorage {
+    type Error = FileGraveyardError;
+
+    fn load_graveyard(&self) -> Result<Graveyard, Self::Error> {
+        match fs::read_to_string(&self.path) {
+            Ok(raw) => Graveyard::from_json(&raw).map_err(|err| self.parse_error(err)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Graveyard::default()),
+            Err(err) => Err(self.io_error(err)),
+        }
+    }
+
+    fn save_graveyard(&self, graveyard: &Graveyard) -> Result<(), Self::Error> {
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(|err| self.io_error(err))?;
+        }
+        let json = graveyard.to_json().map_err(|err| self.parse_error(err))?;
+        fs::write(&self.path, json).map_err(|err| self.io_error(err))
+    }
+}
+
+/// Bury the graves left by every playability run; returns the number of new graves.
+pub fn bury_playability_graves(
+    storage: &FileGraveyardStorage,
+    records: &[PlayabilityRecord],
+) -> Result<usize, FileGraveyardError> {
+    let mut graveyard = storage.load_graveyard()?;
+    let buried = records
+        .iter()
+        .flat_map(|record| record.tombstones.iter().cloned())
+        .filter(|tombstone| graveyard.bury(tombstone.clone()))
+        .count();
+    storage.save_graveyard(&graveyard)?;
+    Ok(buried)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::{GameplayStrategy, PlayabilityMetrics};
+    use dystrail_game::{
+        GameMode, OtDeluxeCalendar, OtDeluxeDeathCause, OtDeluxeTrailVariant, Tombstone,
+    };
+
+    fn temp_path(label: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "dystrail-graveyard-{label}-{}/graveyard.json",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos()
+        ))
+    }
+
+    fn record_with_grave(name: &str, miles: f32) -> PlayabilityRecord {
+        let tombstone = Tombstone {
+            name: name.to_string(),
+            cause: OtDeluxeDeathCause::Drowning,
+            date: OtDeluxeCalendar::default(),
+            miles,
+            node_index: 1,
+            variant: OtDeluxeTrailVariant::Main,
+            epitaph: None,
+            share_code: None,
+        };
+        PlayabilityRecord {
+            scenario_name: "Classic - Balanced - OTDeluxe Mar".to_string(),
+            mode: GameMode::Classic,
+            strategy: GameplayStrategy::Balanced,
+            seed_code: "CL-TEST".to_string(),
+            seed_value: 1,
+            metrics: PlayabilityMetrics::default(),
+            history: None,
+            tombstones: vec![tombstone],
+        }
+    }
+
+    #[test]
+    fn buries_playability_graves_across_sweeps() {
+        let path = temp_path("sweep");
+        let storage = FileGraveyardStorage::new(&path);
+        assert!(storage.load_graveyard().unwrap().is_empty());
+
+        let records = vec![
+            record_with_grave("Ada", 10.0),
+            record_with_grave("Ben", 20.0),
+        ];
+        assert_eq!(bury_playability_graves(&storage, &records).unwrap(), 2);
+        assert_eq!(bury_playability_graves(&storage, &records).unwrap(), 0);
+        let reloaded = FileGraveyardStorage::new(&path).load_graveyard();
+        assert_eq!(reloaded.unwrap().len(), 2);
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn malformed_file_reports_parse_error() {
+        let path = temp_path("malformed");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "[]").unwrap();
+        let err = FileGraveyardStorage::new(&path)
+            .load_graveyard()
+            .unwrap_err();
+        assert!(matches!(err, FileGraveyardError::Parse { .. }));
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+}
@@ -0,0 +1,151 @@
+use std::fs;
+use std::path::PathBuf;
+
+use dystrail_game::{RunHistory, RunHistoryStorage};
+use thiserror::Error;
+
+use crate::logic::PlayabilityRecord;
+
+#[derive(Debug, Error)]
+pub enum FileHistoryError {
+    #[error("run history I/O failed for {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("run history at {path} is malformed: {source}")]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+/// JSON file-backed run history used to collect sweep results natively.
+#[derive(Debug, Clone)]
+pub struct FileRunHistoryStorage {
+    path: PathBuf,
+}
+
+impl FileRunHistoryStorage {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    fn io_error(&self, source: std::io::Error) -> FileHistoryError {
+        FileHistoryError::Io {
+            path: self.path.clone(),
+            source,
+        }
+    }
+
+    fn parse_error(&self, source: serde_json::Error) -> FileHistoryError {
+        FileHistoryError::Parse {
+            path: self.path.clone(),
+            source,
+        }
+    }
+}
+
+impl RunHistoryStorage for FileRunHistoryStorage {
+    type Error = FileHistoryError;
+
+    fn load_history(&self) -> Result<RunHistory, Self::Error> {
+        match fs::read_to_string(&self.path) {
+            Ok(raw) => RunHistory::from_json(&raw).map_err(|err| self.parse_error(err)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(RunHistory::default()),
+            Err(err) => Err(self.io_error(err)),
+        }
+    }
+
+    fn save_history(&self, history: &RunHistory) -> Result<(), Self::Error> {
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(|err| self.io_error(err))?;
+        }
+        let json = history.to_json().map_err(|err| self.parse_error(err))?;
+        fs::write(&self.path, json).map_err(|err| self.io_error(err))
+    }
+}
+
+/// Append every playability run that produced a history entry; returns the number recorded.
+pub fn record_playability_history(
+    storage: &FileRunHistoryStorage,
+    records: &[PlayabilityRecord],
+) -> Result<usize, FileHistoryError> {
+    let mut history = storage.load_history()?;
+    let recorded = records
+        .iter()
+        .filter_map(|record| record.history.clone())
+        .filter(|entry| history.record(entry.clone()))
+        .count();
+    storage.save_history(&history)?;
+    Ok(recorded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::{GameplayStrategy, PlayabilityMetrics};
+    use dystrail_game::{GameMode, GameState, ResultConfig, RunHistoryEntry, result_summary};
+
+    fn temp_path(label: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "dystrail-history-{label}-{}/history.json",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos()
+        ))
+    }
+
+    fn record_with_seed(seed: u64) -> PlayabilityRecord {
+        let state = GameState {
+            seed,
+            ..GameState::default()
+        };
+        let summary = result_summary(&state, &ResultConfig::default()).unwrap();
+        PlayabilityRecord {
+            scenario_name: "Classic - Balanced".to_string(),
+            mode: GameMode::Classic,
+            strategy: GameplayStrategy::Balanced,
+            seed_code: summary.seed.clone(),
+            seed_value: seed,
+            metrics: PlayabilityMetrics::default(),
+            history: Some(RunHistoryEntry::from_run(&state, &summary)),
+            tombstones: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn missing_file_loads_empty_history() {
+        let storage = FileRunHistoryStorage::new(temp_path("missing"));
+        assert!(storage.load_history().unwrap().is_empty());
+    }
+
+    #[test]
+    fn records_playability_runs_to_disk() {
+        let path = temp_path("sweep");
+        let storage = FileRunHistoryStorage::new(&path);
+        let mut without_history = record_with_seed(3);
+        without_history.history = None;
+        let records = vec![record_with_seed(1), record_with_seed(2), without_history];
+
+        assert_eq!(record_playability_history(&storage, &records).unwrap(), 2);
+        let reloaded = FileRunHistoryStorage::new(&path).load_history();
+        assert_eq!(reloaded.unwrap().len(), 2);
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn malformed_file_reports_parse_error() {
+        let path = temp_path("malformed");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "not json").unwrap();
+        let storage = FileRunHistoryStorage::new(&path);
+        let err = storage.load_history().unwrap_err();
+        assert!(matches!(err, FileHistoryError::Parse { .. }));
+        assert!(err.to_string().contains("malformed"));
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+}
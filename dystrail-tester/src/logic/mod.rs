@@ -1,4 +1,9 @@
+pub mod events;
+pub mod explain;
 pub mod game_tester;
+pub mod graveyard;
+pub mod history;
+pub mod parity;
 pub mod playability;
 pub mod policy;
 pub mod reports;
@@ -6,13 +11,17 @@ pub mod seeds;
 pub mod simulation;
 pub mod tester;
 
+pub use events::{FileEventSink, finish_event_stream, open_event_stream};
+pub use explain::explain_day;
 pub use game_tester::{
     DEFAULT_POLICY_SIM_DAYS, GameTester, PlayabilityMetrics, SimulationExpectation, SimulationPlan,
     TesterAssets, default_policy_setup,
 };
+pub use graveyard::{FileGraveyardStorage, bury_playability_graves};
+pub use history::{FileRunHistoryStorage, record_playability_history};
 pub use playability::{
-    PlayabilityAggregate, PlayabilityRecord, aggregate_playability, run_playability_analysis,
-    validate_playability_targets,
+    PlayabilityAggregate, PlayabilityRecord, aggregate_playability, run_departure_sweep,
+    run_playability_analysis, validate_playability_targets,
 };
 pub use policy::GameplayStrategy;
 pub use seeds::{SeedInfo, resolve_seed_inputs};
@@ -0,0 +1,286 @@
+//! Browser/native parity: replay a browser decision log on `SimulationSession`
+//! and locate the first day the two engines disagree.
+
+use std::fmt::Write as _;
+
+use anyhow::{Context, Result};
+use dystrail_game::{GameState, state_hash};
+use serde_json::Value;
+
+use crate::logic::simulation::SimulationSession;
+
+/// Diffs listed in a divergence report before the rest are summarized.
+const MAX_REPORTED_DIFFS: usize = 25;
+
+/// One bridge action and the state hash the browser reported after it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParityStep {
+    pub action_id: String,
+    pub day: u32,
+    pub state_hash: String,
+}
+
+/// A `GameState` field whose value differs between browser and native.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDiff {
+    pub path: String,
+    pub browser: Value,
+    pub native: Value,
+}
+
+/// The first step whose state hashes disagree; `step` 0 is the loaded save.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    pub step: usize,
+    pub day: u32,
+    pub action_id: Option<String>,
+    pub browser_hash: String,
+    pub native_hash: String,
+}
+
+impl Divergence {
+    /// Human-readable report of the divergence and its field-level diff.
+    #[must_use]
+    pub fn describe(&self, diffs: &[FieldDiff]) -> String {
+        let mut report = format!(
+            "browser and native diverged on day {} (step {}, action {}): browser {} vs native {}",
+            self.day,
+            self.step,
+            self.action_id.as_deref().unwrap_or("load"),
+            self.browser_hash,
+            self.native_hash
+        );
+        for diff in diffs.iter().take(MAX_REPORTED_DIFFS) {
+            let _ = write!(
+                report,
+                "\n  {}: browser={} native={}",
+                diff.path, diff.browser, diff.native
+            );
+        }
+        if diffs.len() > MAX_REPORTED_DIFFS {
+            let _ = write!(
+                report,
+                "\n  ... {} more fields differ",
+                diffs.len() - MAX_REPORTED_DIFFS
+            );
+        }
+        report
+    }
+}
+
+/// Native replay of a browser decision log, checked step by step.
+pub struct ParityReplay {
+    session: SimulationSession,
+    steps: usize,
+}
+
+impl ParityReplay {
+    #[must_use]
+    pub const fn new(session: SimulationSession) -> Self {
+        Self { session, steps: 0 }
+    }
+
+    #[must_use]
+    pub const fn state(&self) -> &GameState {
+        self.session.state()
+    }
+
+    #[must_use]
+    pub const fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Compare the browser's hash of the loaded save against the native start.
+    #[must_use]
+    pub fn check_start(&self, browser_hash: &str) -> Option<Divergence> {
+        self.compare(None, browser_hash)
+    }
+
+    /// Apply `step` natively and compare hashes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the step's action id cannot be replayed.
+    pub fn apply(&mut self, step: &ParityStep) -> Result<Option<Divergence>> {
+        self.session
+            .apply_action(&step.action_id)
+            .with_context(|| format!("replaying step {}", self.steps + 1))?;
+        self.steps += 1;
+        Ok(self.compare(Some(&step.action_id), &step.state_hash))
+    }
+
+    fn compare(&self, action_id: Option<&str>, browser_hash: &str) -> Option<Divergence> {
+        let native_hash = state_hash(self.state());
+        (native_hash != browser_hash).then(|| Divergence {
+            step: self.steps,
+            day: self.state().day,
+            action_id: action_id.map(str::to_string),
+            browser_hash: browser_hash.to_string(),
+            native_hash,
+        })
+    }
+}
+
+/// Every leaf field that differs between two states, by JSON path.
+#[must_use]
+pub fn diff_game_states(browser: &GameState, native: &GameState) -> Vec<FieldDiff> {
+    let browser = serde_json::to_value(browser).unwrap_or(Value::Null);
+    let native = serde_json::to_value(native).unwrap_or(Value::Null);
+    let mut diffs = Vec::new();
+    diff_values("", &browser, &native, &mut diffs);
+    diffs
+}
+
+fn child_path(parent: &str, key: &str) -> String {
+    if parent.is_empty() {
+        key.to_string()
+    } else {
+        format!("{parent}.{key}")
+    }
+}
+
+fn diff_values(path: &str, browser: &Value, native: &Value, diffs: &mut Vec<FieldDiff>) {
+    match (browser, native) {
+        (Value::Object(left), Value::Object(right)) => {
+            let mut keys: Vec<&String> = left.keys().chain(right.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                diff_values(
+                    &child_path(path, key),
+                    left.get(key).unwrap_or(&Value::Null),
+                    right.get(key).unwrap_or(&Value::Null),
+                    diffs,
+                );
+            }
+        }
+        (Value::Array(left), Value::Array(right)) => {
+            for idx in 0..left.len().max(right.len()) {
+                diff_values(
+                    &format!("{path}[{idx}]"),
+                    left.get(idx).unwrap_or(&Value::Null),
+                    right.get(idx).unwrap_or(&Value::Null),
+                    diffs,
+                );
+            }
+        }
+        _ if browser != native => diffs.push(FieldDiff {
+            path: path.to_string(),
+            browser: browser.clone(),
+            native: native.clone(),
+        }),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::{GameTester, GameplayStrategy, SimulationPlan, TesterAssets};
+    use dystrail_game::GameMode;
+    use std::sync::Arc;
+
+    const SEED: u64 = 0xFA11;
+
+    fn plan() -> SimulationPlan {
+        SimulationPlan::new(GameMode::Classic, GameplayStrategy::Balanced).with_max_days(20)
+    }
+
+    fn replay_from_save(tester: &GameTester, save: &str) -> ParityReplay {
+        ParityReplay::new(
+            tester
+                .resume_session(&plan(), SEED, save)
+                .expect("save resumes"),
+        )
+    }
+
+    fn replay_log(replay: &mut ParityReplay, log: &[ParityStep]) -> Option<Divergence> {
+        log.iter()
+            .find_map(|step| replay.apply(step).expect("replay runs"))
+    }
+
+    fn recorded_log(tester: &GameTester, save: &str, actions: &[&str]) -> Vec<ParityStep> {
+        let mut session = tester
+            .resume_session(&plan(), SEED, save)
+            .expect("save resumes");
+        actions
+            .iter()
+            .map(|action_id| {
+                session.apply_action(action_id).expect("action applies");
+                ParityStep {
+                    action_id: (*action_id).to_string(),
+                    day: session.state().day,
+                    state_hash: state_hash(session.state()),
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn identical_decision_logs_stay_in_parity() {
+        let tester = GameTester::new(Arc::new(TesterAssets::load_default()), false);
+        let save = serde_json::to_string(&tester.initial_state(&plan(), SEED)).expect("save");
+        let log = recorded_log(
+            &tester,
+            &save,
+            &["travel", "pace:heated", "travel", "travel"],
+        );
+
+        let mut replay = replay_from_save(&tester, &save);
+        let start = state_hash(replay.state());
+        assert!(replay.check_start(&start).is_none());
+        assert!(replay_log(&mut replay, &log).is_none());
+        assert_eq!(replay.steps(), log.len());
+    }
+
+    #[test]
+    fn reports_first_divergent_day_with_field_diff() {
+        let tester = GameTester::new(Arc::new(TesterAssets::load_default()), false);
+        let save = serde_json::to_string(&tester.initial_state(&plan(), SEED)).expect("save");
+        let mut log = recorded_log(&tester, &save, &["travel", "travel", "travel"]);
+        log[1].state_hash = String::from("0000000000000000");
+
+        let mut replay = replay_from_save(&tester, &save);
+        let divergence = replay_log(&mut replay, &log).expect("tampered hash diverges");
+        assert_eq!(divergence.step, 2);
+        assert_eq!(divergence.day, log[1].day);
+        assert_eq!(divergence.action_id.as_deref(), Some("travel"));
+
+        let mut browser = replay.state().clone();
+        browser.stats.hp -= 1;
+        let diffs = diff_game_states(&browser, replay.state());
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].path, "stats.hp");
+        let report = divergence.describe(&diffs);
+        assert!(report.contains("day"));
+        assert!(report.contains("stats.hp"));
+    }
+
+    #[test]
+    fn diff_walks_arrays_and_missing_fields() {
+        let mut browser = GameState::default();
+        let native = GameState::default();
+        browser.logs.push(String::from("log.extra"));
+        let diffs = diff_game_states(&browser, &native);
+        let path = format!("logs[{}]", native.logs.len());
+        assert!(
+            diffs
+                .iter()
+                .any(|diff| diff.path == path && diff.native.is_null())
+        );
+        assert!(diff_game_states(&native, &native).is_empty());
+    }
+
+    #[test]
+    fn unknown_actions_fail_replay() {
+        let tester = GameTester::new(Arc::new(TesterAssets::load_default()), false);
+        let save = serde_json::to_string(&tester.initial_state(&plan(), SEED)).expect("save");
+        let mut replay = replay_from_save(&tester, &save);
+        let step = ParityStep {
+            action_id: String::from("dance"),
+            day: 1,
+            state_hash: String::new(),
+        };
+        assert!(replay.apply(&step).is_err());
+    }
+}
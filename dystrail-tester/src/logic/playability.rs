@@ -22,6 +22,7 @@ use dystrail_game::otdeluxe_crossings::resolve_crossing;
 use dystrail_game::otdeluxe_state::{OtDeluxeInventory, OtDeluxeRiverState};
 use dystrail_game::otdeluxe_total_miles_for_variant;
 use dystrail_game::state::CrossingOutcomeTelemetry;
+use dystrail_game::{ResultConfig, RunHistoryEntry, Tombstone, result_summary, tombstones_for_run};
 
 #[derive(Debug, Clone)]
 pub struct PlayabilityRecord {
@@ -31,6 +32,9 @@ pub struct PlayabilityRecord {
     pub seed_code: String,
     pub seed_value: u64,
     pub metrics: PlayabilityMetrics,
+    pub history: Option<RunHistoryEntry>,
+    /// Graves this run leaves on the trail (`OTDeluxe` only).
+    pub tombstones: Vec<Tombstone>,
 }
 
 #[derive(Debug, Clone)]
@@ -468,6 +472,27 @@ pub fn run_playability_analysis(
     })
 }
 
+/// Replay the playability scenarios under `OTDeluxe` once per departure month.
+///
+/// Runs without expectations: the targets are tuned for the legacy mechanics.
+pub fn run_departure_sweep(
+    tester: &GameTester,
+    seeds: &[SeedInfo],
+    iterations: usize,
+    months: &[u8],
+) -> Result<Vec<PlayabilityRecord>> {
+    let mut records = Vec::new();
+    for &month in months {
+        records.extend(run_playability_analysis_with(
+            tester,
+            seeds,
+            iterations,
+            |mode, strategy| full_game_plan(mode, strategy).with_otdeluxe_departure(month),
+        )?);
+    }
+    Ok(records)
+}
+
 fn run_playability_analysis_with<F>(
     tester: &GameTester,
     seeds: &[SeedInfo],
@@ -496,11 +521,23 @@ where
                 }
 
                 let metrics = summary.metrics.clone();
-                let scenario_name = format!("{} - {}", mode_label(mode), strategy);
+                let scenario_name = match plan.otdeluxe_start {
+                    Some(start) => format!(
+                        "{} - {} - OTDeluxe {}",
+                        mode_label(mode),
+                        strategy,
+                        departure_label(start.departure_month)
+                    ),
+                    None => format!("{} - {}", mode_label(mode), strategy),
+                };
                 let seed_code = dystrail_game::encode_friendly(mode.is_deep(), iteration_seed);
+                let history = result_summary(&summary.final_state, &ResultConfig::default())
+                    .ok()
+                    .map(|result| RunHistoryEntry::from_run(&summary.final_state, &result));
 
+                let tombstones = tombstones_for_run(&summary.final_state, &seed_code);
                 #[rustfmt::skip]
-                let record = PlayabilityRecord { scenario_name, mode, strategy, seed_code, seed_value: iteration_seed, metrics };
+                let record = PlayabilityRecord { scenario_name, mode, strategy, seed_code, seed_value: iteration_seed, metrics, history, tombstones };
                 records.push(record);
             }
         }
@@ -580,6 +617,16 @@ fn ensure_crossing_consistency(records: &[PlayabilityRecord]) -> Result<()> {
     Ok(())
 }
 
+const fn departure_label(month: u8) -> &'static str {
+    match month {
+        3 => "Mar",
+        4 => "Apr",
+        5 => "May",
+        6 => "Jun",
+        _ => "Jul",
+    }
+}
+
 const fn mode_label(mode: GameMode) -> &'static str {
     match mode {
         GameMode::Classic => "Classic",
@@ -829,6 +876,28 @@ mod tests {
         assert!(records.iter().all(|r| !r.seed_code.is_empty()));
     }
 
+    #[test]
+    fn departure_sweep_labels_records_per_month() {
+        let seeds = vec![SeedInfo::from_numeric(1337)];
+        let records = run_departure_sweep(&tester(false), &seeds, 1, &[3, 7]).unwrap();
+        assert_eq!(records.len(), PLAYABILITY_SCENARIOS.len() * 2);
+        assert!(
+            records
+                .iter()
+                .any(|r| r.scenario_name == "Classic - Balanced - OTDeluxe Mar")
+        );
+        assert!(
+            records
+                .iter()
+                .any(|r| r.scenario_name == "Deep - Balanced - OTDeluxe Jul")
+        );
+        assert!(
+            run_departure_sweep(&tester(false), &seeds, 1, &[])
+                .unwrap()
+                .is_empty()
+        );
+    }
+
     #[test]
     fn aggregates_match_record_counts() {
         let seeds = vec![SeedInfo::from_numeric(1337)];
@@ -948,6 +1017,8 @@ mod tests {
             seed_code: "CL-TEST01".to_string(),
             seed_value: 4,
             metrics,
+            history: None,
+            tombstones: Vec::new(),
         };
         emit_record_warnings(&record, &mut warn_counts);
         assert!(!warn_counts.is_empty());
@@ -1117,6 +1188,8 @@ mod tests {
             seed_code: "CL-TEST00".to_string(),
             seed_value: 0,
             metrics: base_metrics(mode),
+            history: None,
+            tombstones: Vec::new(),
         }
     }
 
@@ -1,7 +1,10 @@
 use std::fmt;
 
-use dystrail_game::GameState;
 use dystrail_game::data::{Choice, Encounter};
+use dystrail_game::{
+    GameState, OtDeluxe90sPolicy, OtDeluxeRouteDecision, OtDeluxeRoutePrompt, OtDeluxeStoreItem,
+    OtDeluxeStoreLineItem, quote_otdeluxe_store_purchase,
+};
 
 /// Decision returned by a [`PlayerPolicy`]
 #[derive(Debug, Clone)]
@@ -27,6 +30,19 @@ pub trait PlayerPolicy {
 
     /// Select a choice for an active encounter.
     fn pick_choice(&mut self, state: &GameState, encounter: &Encounter) -> PolicyDecision;
+
+    /// Strategy behind the policy; it drives route and store choices.
+    fn strategy(&self) -> GameplayStrategy;
+
+    /// Fork to take at an `OTDeluxe` route prompt.
+    fn pick_route(&mut self, prompt: OtDeluxeRoutePrompt) -> OtDeluxeRouteDecision {
+        self.strategy().route_decision(prompt)
+    }
+
+    /// Lines to buy at an `OTDeluxe` fort store; empty leaves without buying.
+    fn pick_store(&mut self, state: &GameState) -> Vec<OtDeluxeStoreLineItem> {
+        self.strategy().store_purchase(state)
+    }
 }
 
 /// Built-in gameplay strategies for automated runs.
@@ -49,6 +65,85 @@ impl GameplayStrategy {
         }
     }
 
+    /// Fork taken at an `OTDeluxe` route prompt: aggressive runs take every shortcut.
+    #[must_use]
+    pub const fn route_decision(self, prompt: OtDeluxeRoutePrompt) -> OtDeluxeRouteDecision {
+        match (prompt, self) {
+            (OtDeluxeRoutePrompt::SubletteCutoff, Self::Aggressive) => {
+                OtDeluxeRouteDecision::SubletteCutoff
+            }
+            (OtDeluxeRoutePrompt::DallesShortcut, Self::Aggressive) => {
+                OtDeluxeRouteDecision::DallesShortcut
+            }
+            (OtDeluxeRoutePrompt::DallesFinal, Self::Aggressive) => {
+                OtDeluxeRouteDecision::RaftColumbia
+            }
+            (OtDeluxeRoutePrompt::DallesFinal, _) => OtDeluxeRouteDecision::BarlowRoad,
+            _ => OtDeluxeRouteDecision::StayOnTrail,
+        }
+    }
+
+    /// Pounds of food per living member the strategy restocks to at a fort.
+    const fn food_per_member_lbs(self) -> u16 {
+        match self {
+            Self::Aggressive => 0,
+            Self::Balanced => 100,
+            Self::Conservative => 150,
+            Self::ResourceManager => 200,
+        }
+    }
+
+    /// Purchase at the pending `OTDeluxe` fort store.
+    ///
+    /// Food comes first, then one spare of each wagon part for cautious
+    /// strategies; a line is dropped when the cart would stop being affordable.
+    #[must_use]
+    pub fn store_purchase(self, state: &GameState) -> Vec<OtDeluxeStoreLineItem> {
+        let ot = &state.ot_deluxe;
+        let Some(node) = ot.store.pending_node else {
+            return Vec::new();
+        };
+        let inventory = &ot.inventory;
+        let food_target = self
+            .food_per_member_lbs()
+            .saturating_mul(ot.party.alive_count());
+        let mut wanted = vec![(
+            OtDeluxeStoreItem::FoodLb,
+            food_target.saturating_sub(inventory.food_lbs),
+        )];
+        if matches!(self, Self::Conservative | Self::ResourceManager) {
+            wanted.extend([
+                (
+                    OtDeluxeStoreItem::Wheel,
+                    u16::from(inventory.spares_wheels == 0),
+                ),
+                (
+                    OtDeluxeStoreItem::Axle,
+                    u16::from(inventory.spares_axles == 0),
+                ),
+                (
+                    OtDeluxeStoreItem::Tongue,
+                    u16::from(inventory.spares_tongues == 0),
+                ),
+            ]);
+        }
+        let policy = OtDeluxe90sPolicy::default().store;
+        let mut lines = Vec::new();
+        for (item, quantity) in wanted {
+            if quantity == 0 {
+                continue;
+            }
+            lines.push(OtDeluxeStoreLineItem { item, quantity });
+            let affordable =
+                quote_otdeluxe_store_purchase(&policy, node, inventory, ot.oxen, &lines)
+                    .is_ok_and(|receipt| receipt.total_cost_cents <= inventory.cash_cents);
+            if !affordable {
+                lines.pop();
+            }
+        }
+        lines
+    }
+
     #[must_use]
     pub fn create_policy(self, _seed: u64) -> Box<dyn PlayerPolicy + Send> {
         match self {
@@ -76,6 +171,10 @@ impl PlayerPolicy for ConservativePolicy {
         "Conservative"
     }
 
+    fn strategy(&self) -> GameplayStrategy {
+        GameplayStrategy::Conservative
+    }
+
     fn pick_choice(&mut self, _state: &GameState, encounter: &Encounter) -> PolicyDecision {
         let (idx, risk) = encounter
             .choices
@@ -86,9 +185,7 @@ impl PlayerPolicy for ConservativePolicy {
             .unwrap_or((0, 0));
 
         PolicyDecision::new(
-            Some(idx)
-                .filter(|_| !encounter.choices.is_empty())
-                .unwrap_or(0),
+            if encounter.choices.is_empty() { 0 } else { idx },
             Some(format!("risk {risk}")),
         )
     }
@@ -99,6 +196,10 @@ impl PlayerPolicy for AggressivePolicy {
         "Aggressive"
     }
 
+    fn strategy(&self) -> GameplayStrategy {
+        GameplayStrategy::Aggressive
+    }
+
     fn pick_choice(&mut self, _state: &GameState, encounter: &Encounter) -> PolicyDecision {
         let (idx, reward) = encounter
             .choices
@@ -109,9 +210,7 @@ impl PlayerPolicy for AggressivePolicy {
             .unwrap_or((0, 0));
 
         PolicyDecision::new(
-            Some(idx)
-                .filter(|_| !encounter.choices.is_empty())
-                .unwrap_or(0),
+            if encounter.choices.is_empty() { 0 } else { idx },
             Some(format!("reward {reward}")),
         )
     }
@@ -122,6 +221,10 @@ impl PlayerPolicy for BalancedPolicy {
         "Balanced"
     }
 
+    fn strategy(&self) -> GameplayStrategy {
+        GameplayStrategy::Balanced
+    }
+
     fn pick_choice(&mut self, _state: &GameState, encounter: &Encounter) -> PolicyDecision {
         let (idx, score) = encounter
             .choices
@@ -132,9 +235,7 @@ impl PlayerPolicy for BalancedPolicy {
             .unwrap_or((0, 0));
 
         PolicyDecision::new(
-            Some(idx)
-                .filter(|_| !encounter.choices.is_empty())
-                .unwrap_or(0),
+            if encounter.choices.is_empty() { 0 } else { idx },
             Some(format!("score {score}")),
         )
     }
@@ -145,6 +246,10 @@ impl PlayerPolicy for ResourceManagerPolicy {
         "Resource Manager"
     }
 
+    fn strategy(&self) -> GameplayStrategy {
+        GameplayStrategy::ResourceManager
+    }
+
     fn pick_choice(&mut self, _state: &GameState, encounter: &Encounter) -> PolicyDecision {
         let (idx, penalty) = encounter
             .choices
@@ -155,9 +260,7 @@ impl PlayerPolicy for ResourceManagerPolicy {
             .unwrap_or((0, 0));
 
         PolicyDecision::new(
-            Some(idx)
-                .filter(|_| !encounter.choices.is_empty())
-                .unwrap_or(0),
+            if encounter.choices.is_empty() { 0 } else { idx },
             Some(format!("penalty {penalty}")),
         )
     }
@@ -395,6 +395,8 @@ mod tests {
             seed_code: "CL-ORANGE42".to_string(),
             seed_value: 42,
             metrics: sample_metrics(),
+            history: None,
+            tombstones: Vec::new(),
         }
     }
 
@@ -3,12 +3,13 @@ use dystrail_game::camp::{self, CampConfig};
 use dystrail_game::data::EncounterData;
 use dystrail_game::endgame::EndgameTravelCfg;
 use dystrail_game::{
-    CrossingChoice, CrossingConfig, DayOutcome, GameMode, GameState, JourneySession,
-    MechanicalPolicyId, OtDeluxe90sPolicy, OtDeluxeCrossingMethod, OtDeluxeRouteDecision,
-    OtDeluxeRoutePrompt, PaceId, StrategyId, can_afford_bribe, can_use_permit,
+    ActionConfigs, ActionFollowUp, CrossingChoice, CrossingConfig, DayOutcome, GameMode, GameState,
+    JourneySession, MechanicalPolicyId, OtDeluxe90sPolicy, OtDeluxeCrossingMethod,
+    OtDeluxeStartConfig, PaceId, PlayerAction, StrategyId, can_afford_bribe, can_use_permit,
     otdeluxe_crossing_options,
 };
 
+use crate::logic::events::{SharedEventSink, lock_sink};
 use crate::logic::policy::{GameplayStrategy, PlayerPolicy, PolicyDecision};
 
 /// Configuration for a simulation session.
@@ -18,6 +19,8 @@ pub struct SimulationConfig {
     pub mode: GameMode,
     pub strategy: GameplayStrategy,
     pub max_days: u32,
+    /// Runs `OTDeluxe` mechanics with this start when set.
+    pub otdeluxe_start: Option<OtDeluxeStartConfig>,
 }
 
 impl SimulationConfig {
@@ -28,6 +31,7 @@ impl SimulationConfig {
             mode,
             strategy,
             max_days: 200,
+            otdeluxe_start: None,
         }
     }
 
@@ -36,6 +40,12 @@ impl SimulationConfig {
         self.max_days = max_days;
         self
     }
+
+    #[must_use]
+    pub const fn with_otdeluxe_start(mut self, start: OtDeluxeStartConfig) -> Self {
+        self.otdeluxe_start = Some(start);
+        self
+    }
 }
 
 /// Snapshot of a resolved encounter.
@@ -80,6 +90,7 @@ pub struct SimulationSession {
     strategy: GameplayStrategy,
     conservative_heat_days: u32,
     aggressive_heat_days: u32,
+    event_sink: Option<SharedEventSink>,
 }
 
 impl SimulationSession {
@@ -91,14 +102,27 @@ impl SimulationSession {
         boss_config: BossConfig,
     ) -> Self {
         let strategy_id = strategy_id_for(config.strategy);
-        let mut session = JourneySession::new(
-            config.mode,
-            strategy_id,
-            config.seed,
-            encounters,
-            endgame_config,
-        );
-        session.state_mut().trail_distance = boss_config.distance_required;
+        let session = if let Some(start) = config.otdeluxe_start {
+            JourneySession::new_with_mechanics(
+                MechanicalPolicyId::OtDeluxe90s,
+                config.mode,
+                strategy_id,
+                config.seed,
+                encounters,
+                endgame_config,
+                Some(start),
+            )
+        } else {
+            let mut session = JourneySession::new(
+                config.mode,
+                strategy_id,
+                config.seed,
+                encounters,
+                endgame_config,
+            );
+            session.state_mut().trail_distance = boss_config.distance_required;
+            session
+        };
         Self {
             session,
             camp_config,
@@ -108,6 +132,44 @@ impl SimulationSession {
             strategy: config.strategy,
             conservative_heat_days: 0,
             aggressive_heat_days: 0,
+            event_sink: None,
+        }
+    }
+
+    /// Resume from `state` the way the web client does after loading a save.
+    pub fn from_state(
+        config: SimulationConfig,
+        state: GameState,
+        camp_config: CampConfig,
+        endgame_config: &EndgameTravelCfg,
+        boss_config: BossConfig,
+    ) -> Self {
+        // Matches the web client's `strategy_for_state`.
+        let strategy_id = state.policy.map_or(StrategyId::Balanced, StrategyId::from);
+        Self {
+            session: JourneySession::from_state(state, strategy_id, endgame_config),
+            camp_config,
+            endgame_config: endgame_config.clone(),
+            boss_config,
+            max_days: config.max_days,
+            strategy: config.strategy,
+            conservative_heat_days: 0,
+            aggressive_heat_days: 0,
+            event_sink: None,
+        }
+    }
+
+    /// Stream every simulated day's events and decision traces to `sink`.
+    #[must_use]
+    pub fn with_event_sink(mut self, sink: SharedEventSink) -> Self {
+        self.event_sink = Some(sink);
+        self
+    }
+
+    fn tick_day(&mut self) -> DayOutcome {
+        match &self.event_sink {
+            Some(sink) => self.session.tick_day_with_sink(&mut *lock_sink(sink)),
+            None => self.session.tick_day(),
         }
     }
 
@@ -117,7 +179,7 @@ impl SimulationSession {
     }
 
     #[must_use]
-    pub const fn state_mut(&mut self) -> &mut GameState {
+    pub fn state_mut(&mut self) -> &mut GameState {
         self.session.state_mut()
     }
 
@@ -137,7 +199,7 @@ impl SimulationSession {
             return outcome;
         }
         if let Some(decision) = self.resolve_encounter_choice(policy) {
-            let outcome = self.session.tick_day();
+            let outcome = self.tick_day();
             return self.finalize_outcome(outcome, Some(decision));
         }
 
@@ -151,11 +213,32 @@ impl SimulationSession {
 
         self.adjust_daily_pace();
 
-        let outcome = self.session.tick_day();
+        let outcome = self.tick_day();
         self.finalize_outcome(outcome, None)
     }
 
-    const fn queue_boss_rest(&mut self) {
+    /// Apply a browser test-bridge action id (`travel`, `encounter:1`, `crossing:bribe`, ...)
+    /// with the same semantics as the web client; returns the outcome when a day was ticked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `action_id` is not a bridge action or the state rejects it.
+    pub fn apply_action(&mut self, action_id: &str) -> anyhow::Result<Option<DayOutcome>> {
+        let otdeluxe = self.session.state().mechanical_policy == MechanicalPolicyId::OtDeluxe90s;
+        let action = PlayerAction::parse(action_id, otdeluxe)
+            .ok_or_else(|| anyhow::anyhow!("unknown bridge action `{action_id}`"))?;
+        let cfgs = ActionConfigs {
+            camp: &self.camp_config,
+            endgame: &self.endgame_config,
+            boss: &self.boss_config,
+        };
+        let follow_up = action
+            .apply(self.session.state_mut(), &cfgs)
+            .ok_or_else(|| anyhow::anyhow!("bridge action `{action_id}` was rejected"))?;
+        Ok((follow_up == ActionFollowUp::TickDay).then(|| self.tick_day()))
+    }
+
+    fn queue_boss_rest(&mut self) {
         let boss_ready = {
             let state = self.session.state();
             state.boss.readiness.ready
@@ -240,21 +323,9 @@ impl SimulationSession {
             return None;
         }
         let prompt = state.ot_deluxe.route.pending_prompt?;
-        let decision = match (prompt, self.strategy) {
-            (OtDeluxeRoutePrompt::SubletteCutoff, GameplayStrategy::Aggressive) => {
-                OtDeluxeRouteDecision::SubletteCutoff
-            }
-            (OtDeluxeRoutePrompt::DallesShortcut, GameplayStrategy::Aggressive) => {
-                OtDeluxeRouteDecision::DallesShortcut
-            }
-            (OtDeluxeRoutePrompt::DallesFinal, GameplayStrategy::Aggressive) => {
-                OtDeluxeRouteDecision::RaftColumbia
-            }
-            (OtDeluxeRoutePrompt::DallesFinal, _) => OtDeluxeRouteDecision::BarlowRoad,
-            _ => OtDeluxeRouteDecision::StayOnTrail,
-        };
+        let decision = self.strategy.route_decision(prompt);
         self.session.state_mut().set_route_prompt_choice(decision);
-        let outcome = self.session.tick_day();
+        let outcome = self.tick_day();
         Some(self.finalize_outcome(outcome, None))
     }
 
@@ -284,7 +355,7 @@ impl SimulationSession {
             self.session
                 .state_mut()
                 .set_otdeluxe_crossing_choice(method);
-            let outcome = self.session.tick_day();
+            let outcome = self.tick_day();
             return Some(self.finalize_outcome(outcome, None));
         }
 
@@ -299,7 +370,7 @@ impl SimulationSession {
             CrossingChoice::Detour
         };
         self.session.state_mut().set_crossing_choice(choice);
-        let outcome = self.session.tick_day();
+        let outcome = self.tick_day();
         Some(self.finalize_outcome(outcome, None))
     }
 
@@ -316,12 +387,13 @@ impl SimulationSession {
             .pending_purchase
             .is_some();
         if !pending_purchase {
-            let _ = self
-                .session
-                .state_mut()
-                .set_otdeluxe_store_purchase(Vec::new());
+            let lines = self.strategy.store_purchase(self.session.state());
+            let state = self.session.state_mut();
+            if !state.set_otdeluxe_store_purchase(lines) {
+                let _ = state.set_otdeluxe_store_purchase(Vec::new());
+            }
         }
-        let outcome = self.session.tick_day();
+        let outcome = self.tick_day();
         Some(self.finalize_outcome(outcome, None))
     }
 
@@ -494,7 +566,9 @@ mod tests {
     use dystrail_game::data::{Choice, Effects, Encounter};
     use dystrail_game::journey::TravelDayKind;
     use dystrail_game::otdeluxe_state::{OtDeluxePartyMember, OtDeluxeRiverState};
-    use dystrail_game::{CrossingKind, OtDeluxeRiver, OtDeluxeTrailVariant, PendingCrossing};
+    use dystrail_game::{
+        CrossingKind, OtDeluxeRiver, OtDeluxeRoutePrompt, OtDeluxeTrailVariant, PendingCrossing,
+    };
     use std::collections::VecDeque;
 
     #[test]
@@ -503,6 +577,23 @@ mod tests {
         assert_eq!(config.max_days, 200);
         let config = config.with_max_days(15);
         assert_eq!(config.max_days, 15);
+        assert!(config.otdeluxe_start.is_none());
+    }
+
+    #[test]
+    fn otdeluxe_start_config_selects_mechanics_and_departure() {
+        let config = SimulationConfig::new(GameMode::Classic, GameplayStrategy::Balanced, 3)
+            .with_otdeluxe_start(OtDeluxeStartConfig::default().with_departure(6, 1848));
+        let session = SimulationSession::new(
+            config,
+            EncounterData::empty(),
+            CampConfig::default(),
+            &EndgameTravelCfg::default_config(),
+            BossConfig::default(),
+        );
+        let state = session.state();
+        assert_eq!(state.mechanical_policy, MechanicalPolicyId::OtDeluxe90s);
+        assert_eq!(state.ot_deluxe.departure.month, 6);
     }
 
     #[test]
@@ -552,6 +643,7 @@ mod tests {
             strategy,
             conservative_heat_days: 0,
             aggressive_heat_days: 0,
+            event_sink: None,
         }
     }
 
@@ -710,6 +802,25 @@ mod tests {
         assert!(session.state().ot_deluxe.store.pending_node.is_none());
     }
 
+    #[test]
+    fn resolve_store_buys_what_the_strategy_wants() {
+        let mut session = make_session(
+            MechanicalPolicyId::OtDeluxe90s,
+            GameplayStrategy::ResourceManager,
+            15,
+        );
+        session.state_mut().ot_deluxe.inventory.cash_cents = 50_000;
+        session.state_mut().ot_deluxe.inventory.food_lbs = 0;
+        session.state_mut().ot_deluxe.store.pending_node = Some(0);
+
+        let _ = session.try_resolve_store();
+
+        let inventory = &session.state().ot_deluxe.inventory;
+        assert!(inventory.food_lbs > 0);
+        assert_eq!(inventory.spares_wheels, 1);
+        assert!(inventory.cash_cents < 50_000);
+    }
+
     #[test]
     fn advance_short_circuits_on_route_prompt() {
         let mut session = make_session(
@@ -3,21 +3,25 @@ mod common;
 mod logic;
 
 use anyhow::{Context, Result};
-use clap::{Parser, ValueEnum};
+use clap::{Parser, Subcommand, ValueEnum};
 use colored::Colorize;
+use dystrail_game::{Graveyard, GraveyardStorage};
 use std::fs::File;
 use std::io::{BufWriter, Write, stdout};
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
 use browser::{BrowserConfig, BrowserKind, TestBridge, new_session};
 use common::scenario::{CombinedScenario, ScenarioCtx, get_scenario, list_scenarios};
+use common::visual::{Viewport, VisualConfig, VisualRegression};
 use common::{artifacts_dir, capture_artifacts, split_csv};
 use logic::{
-    GameTester, LogicTester, PlayabilityAggregate, PlayabilityRecord, SeedInfo, TesterAssets,
-    aggregate_playability, resolve_seed_inputs, run_playability_analysis,
-    validate_playability_targets,
+    FileEventSink, FileGraveyardStorage, FileRunHistoryStorage, GameTester, GameplayStrategy,
+    LogicTester, PlayabilityAggregate, PlayabilityRecord, SeedInfo, SimulationPlan, TesterAssets,
+    aggregate_playability, bury_playability_graves, explain_day, finish_event_stream,
+    open_event_stream, record_playability_history, resolve_seed_inputs, run_departure_sweep,
+    run_playability_analysis, validate_playability_targets,
 };
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -87,6 +91,22 @@ struct Args {
     #[arg(long)]
     output: Option<PathBuf>,
 
+    /// Append playability runs to a JSON run-history file (personal bests, exports)
+    #[arg(long)]
+    history: Option<PathBuf>,
+
+    /// Trail graveyard JSON: graves are surfaced during runs and OTDeluxe deaths are buried in it
+    #[arg(long)]
+    graveyard: Option<PathBuf>,
+
+    /// Stream every simulated day's kernel events and decision traces to a JSONL file
+    #[arg(long)]
+    emit_events: Option<PathBuf>,
+
+    /// Also sweep playability under OTDeluxe mechanics for these departure months (3-7, comma-separated)
+    #[arg(long, value_delimiter = ',', value_parser = clap::value_parser!(u8).range(3..=7))]
+    departure_months: Vec<u8>,
+
     // Browser-specific options
     /// Browsers to run (chrome,edge,firefox,safari) - browser mode only
     #[arg(long, default_value = "chrome")]
@@ -100,6 +120,22 @@ struct Args {
     #[arg(long, default_value = "target/test-artifacts")]
     artifacts_dir: String,
 
+    /// Visual regression baseline store (`<scenario>/<screen>/<browser>-<viewport>.png`)
+    #[arg(long, default_value = "dystrail-tester/visual-baselines")]
+    baselines_dir: PathBuf,
+
+    /// Overwrite visual baselines with this run's screenshots
+    #[arg(long)]
+    update_baselines: bool,
+
+    /// Fraction of perceptually different pixels tolerated per screen
+    #[arg(long, default_value_t = 0.001)]
+    visual_threshold: f64,
+
+    /// Window sizes for visual regression screenshots (comma-separated WIDTHxHEIGHT)
+    #[arg(long, value_delimiter = ',', default_value = "1280x800")]
+    viewports: Vec<Viewport>,
+
     /// Connect to a Selenium Grid/Appium hub instead of local drivers
     #[arg(long)]
     hub: Option<String>,
@@ -107,6 +143,36 @@ struct Args {
     /// Run headless where supported
     #[arg(long, value_enum, default_value_t = HeadlessMode::Headless)]
     headless: HeadlessMode,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Replay a seed and explain the weighted decisions made on one day
+    Explain(ExplainArgs),
+}
+
+#[derive(Debug, clap::Args)]
+struct ExplainArgs {
+    /// Numeric seed or share code to replay
+    #[arg(long)]
+    seed: String,
+
+    /// Day (1-based) whose decision traces to explain
+    #[arg(long)]
+    day: u32,
+
+    /// Game mode for numeric seeds (share codes carry their own)
+    #[arg(long, default_value = "classic")]
+    #[arg(value_parser = ["classic", "deep"])]
+    game_mode: String,
+
+    /// Strategy that drives encounter choices during the replay
+    #[arg(long, default_value = "balanced")]
+    #[arg(value_parser = ["balanced", "conservative", "aggressive", "resource-manager"])]
+    strategy: String,
 }
 
 #[tokio::main]
@@ -114,6 +180,10 @@ async fn main() -> Result<()> {
     env_logger::init();
     let args = Args::parse();
 
+    if let Some(Command::Explain(explain)) = &args.command {
+        return run_explain(&args, explain);
+    }
+
     if maybe_list_scenarios(&args)? {
         return Ok(());
     }
@@ -127,7 +197,14 @@ async fn main() -> Result<()> {
     let seed_infos = resolve_seed_inputs(&seed_tokens)?;
     let logic_seeds: Vec<u64> = seed_infos.iter().map(|s| s.seed).collect();
     let tester_assets = Arc::new(TesterAssets::load_default());
-    let game_tester = GameTester::new(tester_assets, args.verbose);
+    let event_stream = open_event_stream_arg(&args)?;
+    let mut game_tester = GameTester::new(tester_assets, args.verbose);
+    if let Some(stream) = &event_stream {
+        game_tester = game_tester.with_event_sink(stream.clone());
+    }
+    if let Some(graveyard) = load_graveyard_arg(&args)? {
+        game_tester = game_tester.with_graveyard(graveyard);
+    }
 
     let all_results = run_logic_scenarios(&args, &scenarios, &logic_seeds, &game_tester);
 
@@ -135,6 +212,9 @@ async fn main() -> Result<()> {
 
     let (playability_records, playability_aggregates) =
         gather_playability(&args, &game_tester, &seed_infos, playability_iterations)?;
+    record_history(&args, playability_records.as_deref())?;
+    bury_graves(&args, playability_records.as_deref())?;
+    finish_event_stream_arg(&args, event_stream.as_deref())?;
 
     write_reports(
         &args,
@@ -166,6 +246,47 @@ fn maybe_list_scenarios(args: &Args) -> Result<bool> {
     Ok(true)
 }
 
+fn run_explain(args: &Args, explain: &ExplainArgs) -> Result<()> {
+    let seed_info = resolve_seed_inputs(std::slice::from_ref(&explain.seed))?
+        .into_iter()
+        .next()
+        .context("explain requires a seed")?;
+    let mode = seed_info
+        .source_mode
+        .unwrap_or(if explain.game_mode == "deep" {
+            dystrail_game::GameMode::Deep
+        } else {
+            dystrail_game::GameMode::Classic
+        });
+    let strategy = match explain.strategy.as_str() {
+        "conservative" => GameplayStrategy::Conservative,
+        "aggressive" => GameplayStrategy::Aggressive,
+        "resource-manager" => GameplayStrategy::ResourceManager,
+        _ => GameplayStrategy::Balanced,
+    };
+    let plan = SimulationPlan::new(mode, strategy);
+    let explanations = explain_day(
+        Arc::new(TesterAssets::load_default()),
+        &plan,
+        seed_info.seed,
+        explain.day,
+    );
+
+    let mut output_target = OutputTarget::new(args.output.clone())?;
+    let code = dystrail_game::encode_friendly(mode.is_deep(), seed_info.seed);
+    writeln!(
+        output_target.writer(),
+        "🔍 Day {} of {code} ({strategy}): {} decision(s)",
+        explain.day,
+        explanations.len()
+    )?;
+    for explanation in &explanations {
+        write!(output_target.writer(), "{explanation}")?;
+    }
+    output_target.flush_inner()?;
+    Ok(())
+}
+
 fn announce_banner() {
     println!("{}", "🎮 Dystrail Automated Tester".bright_cyan().bold());
     println!("{}", "================================".cyan());
@@ -229,6 +350,16 @@ fn build_browser_config(args: &Args) -> BrowserConfig {
     }
 }
 
+fn build_visual_regression(args: &Args) -> VisualRegression {
+    VisualRegression::new(VisualConfig {
+        baseline_dir: args.baselines_dir.clone(),
+        output_dir: PathBuf::from(&args.artifacts_dir).join("visual"),
+        threshold: args.visual_threshold,
+        update_baselines: args.update_baselines,
+        viewports: args.viewports.clone(),
+    })
+}
+
 fn browser_label(kind: BrowserKind) -> String {
     format!("{kind:?}").to_lowercase()
 }
@@ -302,6 +433,7 @@ async fn run_browser_scenarios(
     println!("{}", "-".repeat(30).blue());
 
     let browsers = split_csv(&args.browsers);
+    let visual = build_visual_regression(args);
 
     for browser_name in browsers {
         let Some(kind) = parse_browser_kind(&browser_name) else {
@@ -319,11 +451,23 @@ async fn run_browser_scenarios(
             }
         };
 
-        run_browser_scenarios_for_driver(args, scenarios, seed_infos, game_tester, kind, &driver)
-            .await;
+        run_browser_scenarios_for_driver(
+            args,
+            scenarios,
+            seed_infos,
+            game_tester,
+            kind,
+            &driver,
+            &visual,
+        )
+        .await;
         let _ = driver.quit().await;
     }
 
+    if let Some(report) = visual.write_report()? {
+        println!("📸 Visual regression report: {}", report.display());
+    }
+
     Ok(())
 }
 
@@ -334,6 +478,7 @@ async fn run_browser_scenarios_for_driver(
     game_tester: &GameTester,
     kind: BrowserKind,
     driver: &thirtyfour::WebDriver,
+    visual: &VisualRegression,
 ) {
     for scenario_name in scenarios {
         if let Some(scenario) = get_scenario(scenario_name, game_tester) {
@@ -344,6 +489,8 @@ async fn run_browser_scenarios_for_driver(
                     seed: seed_info.seed,
                     bridge,
                     verbose: args.verbose,
+                    browser: browser_label(kind),
+                    visual,
                 };
 
                 let label = browser_label(kind);
@@ -394,11 +541,18 @@ fn gather_playability(
     let mut playability_records: Option<Vec<PlayabilityRecord>> = None;
     let mut playability_aggregates: Option<Vec<PlayabilityAggregate>> = None;
     let require_playability = matches!(args.report.as_str(), "console" | "csv")
-        || matches!(args.mode, TestMode::Logic | TestMode::Both);
+        || matches!(args.mode, TestMode::Logic | TestMode::Both)
+        || args.history.is_some();
 
     if require_playability {
-        let playability =
+        let mut playability =
             run_playability_analysis(game_tester, seed_infos, playability_iterations)?;
+        playability.extend(run_departure_sweep(
+            game_tester,
+            seed_infos,
+            playability_iterations,
+            &args.departure_months,
+        )?);
         playability_aggregates = Some(aggregate_playability(&playability));
         playability_records = Some(playability);
     }
@@ -406,6 +560,59 @@ fn gather_playability(
     Ok((playability_records, playability_aggregates))
 }
 
+fn open_event_stream_arg(args: &Args) -> Result<Option<Arc<Mutex<FileEventSink>>>> {
+    args.emit_events
+        .as_deref()
+        .map(|path| {
+            open_event_stream(path)
+                .with_context(|| format!("Failed to open event stream at {}", path.display()))
+        })
+        .transpose()
+}
+
+fn finish_event_stream_arg(args: &Args, stream: Option<&Mutex<FileEventSink>>) -> Result<()> {
+    let (Some(path), Some(stream)) = (args.emit_events.as_ref(), stream) else {
+        return Ok(());
+    };
+    let written = finish_event_stream(stream)
+        .with_context(|| format!("Failed to write event stream at {}", path.display()))?;
+    println!("🧾 Wrote {written} event records to {}", path.display());
+    Ok(())
+}
+
+fn record_history(args: &Args, playability_records: Option<&[PlayabilityRecord]>) -> Result<()> {
+    let (Some(path), Some(records)) = (args.history.as_ref(), playability_records) else {
+        return Ok(());
+    };
+    let storage = FileRunHistoryStorage::new(path);
+    let recorded = record_playability_history(&storage, records)
+        .with_context(|| format!("Failed to record run history at {}", path.display()))?;
+    println!("📜 Recorded {recorded} runs to {}", path.display());
+    Ok(())
+}
+
+fn load_graveyard_arg(args: &Args) -> Result<Option<Graveyard>> {
+    args.graveyard
+        .as_deref()
+        .map(|path| {
+            FileGraveyardStorage::new(path)
+                .load_graveyard()
+                .with_context(|| format!("Failed to load graveyard at {}", path.display()))
+        })
+        .transpose()
+}
+
+fn bury_graves(args: &Args, playability_records: Option<&[PlayabilityRecord]>) -> Result<()> {
+    let (Some(path), Some(records)) = (args.graveyard.as_ref(), playability_records) else {
+        return Ok(());
+    };
+    let storage = FileGraveyardStorage::new(path);
+    let buried = bury_playability_graves(&storage, records)
+        .with_context(|| format!("Failed to bury graves at {}", path.display()))?;
+    println!("🪦 Buried {buried} graves in {}", path.display());
+    Ok(())
+}
+
 fn finalize_run(
     results: &[logic::ScenarioResult],
     playability_records: Option<&[PlayabilityRecord]>,
@@ -541,6 +748,18 @@ mod tests {
     use std::time::Duration;
     use tokio::sync::oneshot;
 
+    /// A temp file path unique to this test run.
+    fn unique_temp_path(stem: &str, extension: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "{stem}-{}-{}.{extension}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos()
+        ))
+    }
+
     fn base_args() -> Args {
         Args {
             mode: TestMode::Logic,
@@ -552,11 +771,23 @@ mod tests {
             report: "json".to_string(),
             verbose: false,
             output: None,
+            history: None,
+            graveyard: None,
+            emit_events: None,
+            departure_months: Vec::new(),
             browsers: "chrome".to_string(),
             base_url: "http://localhost:5173/?test=1".to_string(),
             artifacts_dir: "target/test-artifacts".to_string(),
+            baselines_dir: PathBuf::from("dystrail-tester/visual-baselines"),
+            update_baselines: false,
+            visual_threshold: 0.001,
+            viewports: vec![Viewport {
+                width: 1280,
+                height: 800,
+            }],
             hub: None,
             headless: HeadlessMode::Headless,
+            command: None,
         }
     }
 
@@ -593,6 +824,8 @@ mod tests {
             seed_code: "CL-ORANGE42".to_string(),
             seed_value: 42,
             metrics: sample_metrics(),
+            history: None,
+            tombstones: Vec::new(),
         }
     }
 
@@ -696,6 +929,79 @@ mod tests {
         assert!(aggregates.is_none());
     }
 
+    #[test]
+    fn run_explain_writes_day_breakdown() {
+        let path = std::env::temp_dir().join(format!(
+            "dystrail-explain-{}.txt",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos()
+        ));
+        let mut args = base_args();
+        args.output = Some(path.clone());
+        let explain = ExplainArgs {
+            seed: "CL-ORANGE42".to_string(),
+            day: 3,
+            game_mode: "deep".to_string(),
+            strategy: "balanced".to_string(),
+        };
+        run_explain(&args, &explain).unwrap();
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert!(text.starts_with("🔍 Day 3 of CL-ORANGE42 (Balanced)"));
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn record_history_writes_playability_runs() {
+        let path = unique_temp_path("dystrail-run-history", "json");
+        let args = Args {
+            history: Some(path.clone()),
+            ..base_args()
+        };
+        record_history(&args, None).unwrap();
+        assert!(!path.exists());
+
+        let mut record = sample_record();
+        let state = dystrail_game::GameState::default();
+        let summary =
+            dystrail_game::result_summary(&state, &dystrail_game::ResultConfig::default()).unwrap();
+        record.history = Some(dystrail_game::RunHistoryEntry::from_run(&state, &summary));
+        record_history(&args, Some(&[record])).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.contains(&summary.seed));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn emit_events_opens_and_finishes_stream() {
+        assert!(open_event_stream_arg(&base_args()).unwrap().is_none());
+        finish_event_stream_arg(&base_args(), None).unwrap();
+
+        let path = unique_temp_path("dystrail-events-arg", "jsonl");
+        let args = Args {
+            emit_events: Some(path.clone()),
+            ..base_args()
+        };
+        let stream = open_event_stream_arg(&args).unwrap().unwrap();
+        let tester = GameTester::new(Arc::new(TesterAssets::load_default()), false)
+            .with_event_sink(stream.clone());
+        let plan = logic::SimulationPlan::new(
+            dystrail_game::GameMode::Classic,
+            GameplayStrategy::Conservative,
+        )
+        .with_max_days(3);
+        let _ = tester.run_plan(&plan, 42);
+        finish_event_stream_arg(&args, Some(&stream)).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(
+            contents
+                .lines()
+                .all(|line| line.contains("\"schema_version\":1"))
+        );
+        let _ = std::fs::remove_file(&path);
+    }
+
     #[test]
     fn write_reports_emits_json_output() {
         let args = Args {
@@ -1148,6 +1454,12 @@ mod tests {
                     "day": 2,
                     "pos": { "x": 1 }
                 })
+            } else if script.contains("__dystrailTest.phase") {
+                json!("travel")
+            } else if script.contains("__dystrailTest.save") {
+                json!("{}")
+            } else if script.contains("__dystrailTest.load") {
+                json!({ "ok": true, "phase": "travel", "day": 2, "ended": false })
             } else {
                 serde_json::Value::Null
             };
@@ -1222,6 +1534,7 @@ mod tests {
             &tester,
             BrowserKind::Chrome,
             &driver,
+            &build_visual_regression(&args),
         )
         .await;
 
@@ -1257,6 +1570,7 @@ mod tests {
             &tester,
             BrowserKind::Chrome,
             &driver,
+            &build_visual_regression(&args),
         )
         .await;
 
@@ -1301,6 +1615,7 @@ mod tests {
             &tester,
             BrowserKind::Chrome,
             &driver,
+            &build_visual_regression(&args),
         )
         .await;
 
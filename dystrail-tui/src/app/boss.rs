@@ -0,0 +1,40 @@
+//! Boss fight for Dystrail runs.
+
+use super::{App, Screen};
+use dystrail_game::run_boss_minigame;
+
+impl App {
+    pub(super) fn boss_body(&self) -> Vec<String> {
+        let cfg = &self.assets.boss;
+        let chance = format!("{:.0}", cfg.base_victory_chance * 100.0);
+        vec![
+            self.strings.t("boss.phases_hint"),
+            self.strings.tr(
+                "boss.stats.rounds",
+                &[
+                    ("rounds", &cfg.rounds.to_string()),
+                    ("passes", &cfg.passes_required.to_string()),
+                ],
+            ),
+            self.strings.tr(
+                "boss.stats.sanity",
+                &[("sanity", &cfg.sanity_loss_per_round.to_string())],
+            ),
+            self.strings.tr(
+                "boss.stats.pants",
+                &[("pants", &cfg.pants_gain_per_round.to_string())],
+            ),
+            self.strings.tr("boss.stats.chance", &[("chance", &chance)]),
+            self.strings.t("boss.reminder"),
+        ]
+    }
+
+    pub(super) fn boss(&mut self) {
+        let Some(session) = self.session.as_mut() else {
+            return;
+        };
+        let cfg = &self.assets.boss;
+        session.with_state_mut(|gs| run_boss_minigame(gs, cfg));
+        self.set_screen(Screen::Result);
+    }
+}
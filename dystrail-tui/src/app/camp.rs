@@ -0,0 +1,82 @@
+//! Camp actions for Dystrail runs.
+
+use super::{Action, App, MenuItem, Screen};
+use dystrail_game::{
+    Part, camp_forage_with_endgame, camp_repair_hack_with_endgame, camp_repair_spare,
+    camp_rest_with_endgame,
+};
+
+impl App {
+    pub(super) fn camp_items(&self) -> Vec<MenuItem> {
+        let Some(gs) = self.state() else {
+            return Vec::new();
+        };
+        let breakdown = gs.breakdown.as_ref().map(|b| b.part);
+        let spares = &gs.inventory.spares;
+        let has_spare = breakdown.is_some_and(|part| {
+            let count = match part {
+                Part::Tire => spares.tire,
+                Part::Battery => spares.battery,
+                Part::Alternator => spares.alt,
+                Part::FuelPump => spares.pump,
+            };
+            count > 0
+        });
+        let mut items = vec![
+            MenuItem::new(self.strings.t("camp.menu.rest"), Action::CampRest),
+            MenuItem::new(self.strings.t("camp.menu.forage"), Action::CampForage),
+        ];
+        if let Some(part) = breakdown {
+            items.push(
+                MenuItem::new(
+                    format!(
+                        "{} — {}",
+                        self.strings.t("camp.menu.use_spare"),
+                        self.strings.t(part.key())
+                    ),
+                    Action::CampSpare(part),
+                )
+                .enabled_if(has_spare),
+            );
+            items.push(MenuItem::new(
+                self.strings.t("vehicle.hack_fix"),
+                Action::CampHack,
+            ));
+        }
+        items.push(MenuItem::new(
+            self.strings.t("camp.menu.close"),
+            Action::Back,
+        ));
+        items
+    }
+
+    pub(super) fn camp(&mut self, action: &Action) {
+        let Some(session) = self.session.as_mut() else {
+            return;
+        };
+        let camp_cfg = &self.assets.camp;
+        let endgame = &self.assets.endgame;
+        let outcome = session.with_state_mut(|gs| match action {
+            Action::CampRest => camp_rest_with_endgame(gs, camp_cfg, endgame),
+            Action::CampForage => camp_forage_with_endgame(gs, camp_cfg, endgame),
+            Action::CampSpare(part) => camp_repair_spare(gs, camp_cfg, *part),
+            _ => camp_repair_hack_with_endgame(gs, camp_cfg, endgame),
+        });
+        let announce = match (action, outcome.message.as_str()) {
+            (Action::CampRest, "log.camp.rest") => Some("camp.announce.rest"),
+            (Action::CampForage, "log.camp.forage") if outcome.supplies_delta > 0 => {
+                Some("camp.announce.forage_sup")
+            }
+            (Action::CampForage, "log.camp.forage") => Some("camp.announce.forage_none"),
+            (
+                Action::CampSpare(_) | Action::CampHack,
+                "log.camp.repair" | "log.camp.repair.hack",
+            ) => Some("vehicle.announce.cleared"),
+            _ => None,
+        };
+        let line = self.strings.t(announce.unwrap_or(&outcome.message));
+        self.log.push(line);
+        self.set_screen(Screen::Travel);
+        self.sync_screen();
+    }
+}
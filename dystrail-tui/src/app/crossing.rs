@@ -0,0 +1,155 @@
+//! Checkpoint, bridge and river crossings.
+
+use super::{Action, App, MenuItem};
+use crate::i18n::fmt_cents;
+use dystrail_game::{
+    CrossingChoice, CrossingKind, GameState, MechanicalPolicyId, OtDeluxe90sPolicy,
+    OtDeluxeCrossingMethod, OtDeluxeGuidePayment, OtDeluxeRiver, calculate_bribe_cost,
+    can_afford_bribe, can_use_permit, otdeluxe_crossing_options, otdeluxe_guide_payment,
+};
+
+impl App {
+    pub(super) fn crossing_title(&self) -> String {
+        match self.state() {
+            Some(gs) if gs.mechanical_policy == MechanicalPolicyId::OtDeluxe90s => {
+                self.strings.t("ot_cross.title")
+            }
+            Some(gs) => match gs.pending_crossing.map(|pending| pending.kind) {
+                Some(CrossingKind::BridgeOut) => self.strings.t("cross.types.bridge_out"),
+                _ => self.strings.t("cross.types.checkpoint"),
+            },
+            None => self.strings.t("cross.title"),
+        }
+    }
+
+    pub(super) fn crossing_body(&self) -> Vec<String> {
+        let Some(gs) = self.state() else {
+            return Vec::new();
+        };
+        if gs.mechanical_policy != MechanicalPolicyId::OtDeluxe90s {
+            return vec![
+                self.strings.t("cross.prompt"),
+                self.strings.t("cross.desc.detour"),
+                self.strings.t("cross.desc.bribe"),
+                self.strings.t("cross.desc.permit"),
+            ];
+        }
+        let crossing = &gs.ot_deluxe.crossing;
+        let river = crossing
+            .river_kind
+            .map(|kind| {
+                self.strings
+                    .t(&format!("ot_cross.river.{}", river_key(kind)))
+            })
+            .unwrap_or_default();
+        let mut lines = vec![self.strings.tr("ot_cross.prompt", &[("river", &river)])];
+        if let Some(state) = crossing.river.as_ref() {
+            let bed = self.strings.t(&format!(
+                "ot_cross.bed.{}",
+                format!("{:?}", state.bed).to_lowercase()
+            ));
+            lines.push(self.strings.tr(
+                "ot_cross.stats",
+                &[
+                    ("width", &format!("{:.0}", state.width_ft)),
+                    ("depth", &format!("{:.1}", state.depth_ft)),
+                    ("swiftness", &format!("{:.2}", state.swiftness)),
+                    ("bed", &bed),
+                ],
+            ));
+        }
+        lines
+    }
+
+    pub(super) fn crossing_items(&self) -> Vec<MenuItem> {
+        let Some(gs) = self.state() else {
+            return Vec::new();
+        };
+        if gs.mechanical_policy == MechanicalPolicyId::OtDeluxe90s {
+            return self.otdeluxe_crossing_items(gs);
+        }
+        let Some(kind) = gs.pending_crossing.map(|pending| pending.kind) else {
+            return Vec::new();
+        };
+        let Some(cfg) = self.assets.crossings.types.get(&kind) else {
+            return Vec::new();
+        };
+        let cost = calculate_bribe_cost(cfg.bribe.base_cost_cents, gs.mods.bribe_discount_pct);
+        vec![
+            MenuItem::new(
+                self.strings.tr(
+                    "cross.options.detour",
+                    &[
+                        ("days", &cfg.detour.days.to_string()),
+                        ("supplies", &cfg.detour.supplies.to_string()),
+                        ("pants", &cfg.detour.pants.to_string()),
+                    ],
+                ),
+                Action::Crossing(CrossingChoice::Detour),
+            ),
+            MenuItem::new(
+                self.strings
+                    .tr("cross.options.bribe", &[("cost", &fmt_cents(cost))]),
+                Action::Crossing(CrossingChoice::Bribe),
+            )
+            .enabled_if(can_afford_bribe(gs, &self.assets.crossings, kind)),
+            MenuItem::new(
+                self.strings.t("cross.options.permit"),
+                Action::Crossing(CrossingChoice::Permit),
+            )
+            .enabled_if(can_use_permit(gs, &kind)),
+        ]
+    }
+
+    fn otdeluxe_crossing_items(&self, gs: &GameState) -> Vec<MenuItem> {
+        let crossing = &gs.ot_deluxe.crossing;
+        let (Some(river), Some(river_state)) = (crossing.river_kind, crossing.river.as_ref())
+        else {
+            return Vec::new();
+        };
+        let policy = OtDeluxe90sPolicy::default();
+        let inventory = &gs.ot_deluxe.inventory;
+        let options = otdeluxe_crossing_options(&policy.crossings, river, river_state, inventory);
+        let ferry_cost = fmt_cents(i64::from(policy.crossings.ferry_cost_cents));
+        let guide = match otdeluxe_guide_payment(&policy.crossings, inventory) {
+            Some(OtDeluxeGuidePayment::Cash(cents)) => self.strings.tr(
+                "ot_cross.options.guide_cash",
+                &[("cost", &fmt_cents(i64::from(cents)))],
+            ),
+            Some(OtDeluxeGuidePayment::ClothesSets(sets)) => self
+                .strings
+                .tr("ot_cross.options.guide", &[("sets", &sets.to_string())]),
+            None => self.strings.tr("ot_cross.options.guide", &[("sets", "-")]),
+        };
+        [
+            (
+                OtDeluxeCrossingMethod::Ford,
+                self.strings.t("ot_cross.options.ford"),
+            ),
+            (
+                OtDeluxeCrossingMethod::CaulkFloat,
+                self.strings.t("ot_cross.options.caulk_float"),
+            ),
+            (
+                OtDeluxeCrossingMethod::Ferry,
+                self.strings
+                    .tr("ot_cross.options.ferry", &[("cost", &ferry_cost)]),
+            ),
+            (OtDeluxeCrossingMethod::Guide, guide),
+        ]
+        .into_iter()
+        .map(|(method, label)| {
+            MenuItem::new(label, Action::OtCrossing(method)).enabled_if(options.is_allowed(method))
+        })
+        .collect()
+    }
+}
+
+const fn river_key(river: OtDeluxeRiver) -> &'static str {
+    match river {
+        OtDeluxeRiver::Kansas => "kansas",
+        OtDeluxeRiver::BigBlue => "big_blue",
+        OtDeluxeRiver::Green => "green",
+        OtDeluxeRiver::Snake => "snake",
+    }
+}
@@ -0,0 +1,32 @@
+//! Encounter choices.
+
+use super::{Action, App, MenuItem};
+
+impl App {
+    pub(super) fn encounter_body(&self) -> Vec<String> {
+        self.state()
+            .and_then(|gs| gs.current_encounter.as_ref())
+            .map(|enc| vec![enc.name.clone(), enc.desc.clone()])
+            .unwrap_or_default()
+    }
+
+    pub(super) fn encounter_items(&self) -> Vec<MenuItem> {
+        self.state()
+            .and_then(|gs| gs.current_encounter.as_ref())
+            .map(|enc| {
+                enc.choices
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, choice)| {
+                        MenuItem::new(choice.label.clone(), Action::EncounterChoice(idx))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub(super) fn choose_encounter(&mut self, idx: usize) {
+        self.with_state(|gs| gs.apply_choice(idx));
+        self.sync_screen();
+    }
+}
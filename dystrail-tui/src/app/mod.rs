@@ -0,0 +1,743 @@
+//! Keyboard-driven screen state machine wrapping a [`JourneySession`].
+//!
+//! Each screen lives in its own module, mirroring the web client's components.
+
+mod boss;
+mod camp;
+mod crossing;
+mod encounter;
+mod mode_select;
+mod occupation;
+mod outfitting;
+mod pace_diet;
+mod persona;
+mod result;
+mod route_prompt;
+mod saves;
+mod store;
+mod travel;
+
+use crate::assets::Assets;
+use crate::i18n::Strings;
+use crate::storage::FileGameStorage;
+use dystrail_game::state::DayIntent;
+use dystrail_game::{
+    Cart, CrossingChoice, DayOutcome, DietId, GameMode, GameState, GraveyardStorage,
+    JourneySession, MechanicalPolicyId, OtDeluxeCrossingMethod, OtDeluxeOccupation, OtDeluxePace,
+    OtDeluxeRations, OtDeluxeRouteDecision, OtDeluxeStartConfig, PaceId, Part, VehicleClass,
+    encode_friendly,
+};
+use store::OT_STORE_ITEMS;
+
+/// Screens of the terminal client, mirroring the web phases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Screen {
+    Menu,
+    ModeSelect,
+    Persona,
+    Occupation,
+    Outfitting,
+    Travel,
+    PaceDiet,
+    Camp,
+    Encounter,
+    Crossing,
+    RoutePrompt,
+    Store,
+    Boss,
+    Result,
+}
+
+impl Screen {
+    /// Pick the screen a live run should show, matching the web phase order.
+    #[must_use]
+    pub fn for_state(state: &GameState) -> Self {
+        let dystrail = state.mechanical_policy == MechanicalPolicyId::DystrailLegacy;
+        let otdeluxe = state.mechanical_policy == MechanicalPolicyId::OtDeluxe90s;
+        if state.ending.is_some() || state.stats.pants >= 100 {
+            Self::Result
+        } else if state.ot_deluxe.route.pending_prompt.is_some() {
+            Self::RoutePrompt
+        } else if (otdeluxe && state.ot_deluxe.crossing.choice_pending)
+            || (dystrail && state.pending_crossing.is_some())
+        {
+            Self::Crossing
+        } else if otdeluxe && state.ot_deluxe.store.pending_node.is_some() {
+            Self::Store
+        } else if state.current_encounter.is_some() {
+            Self::Encounter
+        } else if dystrail && state.boss.readiness.ready && !state.boss.outcome.attempted {
+            Self::Boss
+        } else {
+            Self::Travel
+        }
+    }
+}
+
+/// Terminal-agnostic key presses understood by [`App`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    Enter,
+    Back,
+    Char(char),
+}
+
+/// Choices made before a run starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunSetup {
+    pub mode: GameMode,
+    pub mechanics: MechanicalPolicyId,
+    pub vehicle: VehicleClass,
+    pub departure_month: u8,
+    pub share_code: Option<String>,
+}
+
+impl Default for RunSetup {
+    fn default() -> Self {
+        Self {
+            mode: GameMode::Classic,
+            mechanics: MechanicalPolicyId::DystrailLegacy,
+            vehicle: VehicleClass::default(),
+            departure_month: OtDeluxeStartConfig::default().departure_month,
+            share_code: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SetupRow {
+    Mode,
+    Mechanics,
+    Vehicle,
+    Departure,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Action {
+    NewRun,
+    Load,
+    Quit,
+    Setup(SetupRow),
+    BeginSetup,
+    Persona(usize),
+    Occupation(OtDeluxeOccupation),
+    CartItem(String),
+    Checkout,
+    Travel,
+    OpenPaceDiet,
+    OpenCamp,
+    Intent(DayIntent),
+    Save,
+    MainMenu,
+    Pace(PaceId),
+    Diet(DietId),
+    OtPace(OtDeluxePace),
+    OtRations(OtDeluxeRations),
+    Back,
+    CampRest,
+    CampForage,
+    CampSpare(Part),
+    CampHack,
+    EncounterChoice(usize),
+    Crossing(CrossingChoice),
+    OtCrossing(OtDeluxeCrossingMethod),
+    Route(OtDeluxeRouteDecision),
+    OtStoreItem(usize),
+    OtStoreBuy,
+    OtStoreLeave,
+    Boss,
+    Replay,
+}
+
+/// One selectable row on the current screen.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MenuItem {
+    pub label: String,
+    pub enabled: bool,
+    action: Action,
+}
+
+impl MenuItem {
+    fn new(label: String, action: Action) -> Self {
+        Self {
+            label,
+            enabled: true,
+            action,
+        }
+    }
+
+    fn enabled_if(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+/// Terminal client state: the current screen plus the run it drives.
+pub struct App {
+    strings: Strings,
+    assets: Assets,
+    storage: FileGameStorage,
+    save_slot: String,
+    screen: Screen,
+    cursor: usize,
+    setup: RunSetup,
+    seed: u64,
+    share_code: String,
+    pending: Option<GameState>,
+    cart: Cart,
+    ot_cart: [u16; OT_STORE_ITEMS.len()],
+    session: Option<JourneySession>,
+    log: Vec<String>,
+    quit: bool,
+}
+
+impl App {
+    #[must_use]
+    pub fn new(
+        strings: Strings,
+        assets: Assets,
+        storage: FileGameStorage,
+        save_slot: String,
+        setup: RunSetup,
+    ) -> Self {
+        Self {
+            strings,
+            assets,
+            storage,
+            save_slot,
+            screen: Screen::Menu,
+            cursor: 0,
+            setup,
+            seed: 0,
+            share_code: String::new(),
+            pending: None,
+            cart: Cart::new(),
+            ot_cart: [0; OT_STORE_ITEMS.len()],
+            session: None,
+            log: Vec::new(),
+            quit: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    #[must_use]
+    pub const fn should_quit(&self) -> bool {
+        self.quit
+    }
+
+    #[must_use]
+    pub const fn strings(&self) -> &Strings {
+        &self.strings
+    }
+
+    #[must_use]
+    pub fn log(&self) -> &[String] {
+        &self.log
+    }
+
+    /// The live run, or the run being outfitted.
+    #[must_use]
+    pub fn state(&self) -> Option<&GameState> {
+        self.session
+            .as_ref()
+            .map(JourneySession::state)
+            .or(self.pending.as_ref())
+    }
+
+    /// Apply one key press.
+    pub fn handle_key(&mut self, key: Key) {
+        let items = self.items();
+        match key {
+            Key::Char('q') => self.quit = true,
+            Key::Up => self.cursor = self.cursor.saturating_sub(1),
+            Key::Down => {
+                self.cursor = (self.cursor + 1).min(items.len().saturating_sub(1));
+            }
+            Key::Enter => {
+                if let Some(item) = items.get(self.cursor) {
+                    self.activate_item(item);
+                }
+            }
+            Key::Char(c @ '1'..='9') => {
+                let idx = c as usize - '1' as usize;
+                if let Some(item) = items.get(idx) {
+                    self.cursor = idx;
+                    self.activate_item(item);
+                }
+            }
+            Key::Left | Key::Char('-') => {
+                if let Some(item) = items.get(self.cursor) {
+                    self.adjust(&item.action, -1);
+                }
+            }
+            Key::Right | Key::Char('+' | '=') => {
+                if let Some(item) = items.get(self.cursor) {
+                    self.adjust(&item.action, 1);
+                }
+            }
+            Key::Back => self.back(),
+            Key::Char('s') if self.screen == Screen::Travel => self.save(),
+            Key::Char('l') if matches!(self.screen, Screen::Travel | Screen::Menu) => {
+                self.load();
+            }
+            Key::Char(_) => {}
+        }
+    }
+
+    fn activate_item(&mut self, item: &MenuItem) {
+        if item.enabled {
+            self.activate(item.action.clone());
+        }
+    }
+
+    fn set_screen(&mut self, screen: Screen) {
+        self.screen = screen;
+        self.cursor = 0;
+        if screen == Screen::Result {
+            self.bury_dead();
+        }
+    }
+
+    /// Hand the stored graveyard to a run that is about to start.
+    fn load_trail_graves(&self, session: &mut JourneySession) {
+        if let Ok(graveyard) = self.storage.load_graveyard() {
+            session.with_state_mut(|gs| gs.set_trail_graves(&graveyard));
+        }
+    }
+
+    fn bury_dead(&self) {
+        if let Some(gs) = self.state() {
+            let code = encode_friendly(gs.mode.is_deep(), gs.seed);
+            let _ = self.storage.bury_run(gs, &code);
+        }
+    }
+
+    fn sync_screen(&mut self) {
+        if let Some(state) = self.state() {
+            let next = Screen::for_state(state);
+            if next != self.screen {
+                self.set_screen(next);
+            }
+        }
+    }
+
+    fn back(&mut self) {
+        let target = match self.screen {
+            Screen::ModeSelect => Screen::Menu,
+            Screen::Persona | Screen::Occupation => Screen::ModeSelect,
+            Screen::Outfitting => Screen::Persona,
+            Screen::PaceDiet | Screen::Camp => Screen::Travel,
+            _ => return,
+        };
+        self.set_screen(target);
+    }
+
+    /// Title line for the current screen.
+    #[must_use]
+    pub fn title(&self) -> String {
+        let key = match self.screen {
+            Screen::Menu => "menu.title",
+            Screen::ModeSelect => "mode.title",
+            Screen::Persona => "persona.choose",
+            Screen::Occupation => "otdeluxe.occupation.title",
+            Screen::Outfitting => "store.title",
+            Screen::Travel => "travel.title",
+            Screen::PaceDiet => "pacediet.title",
+            Screen::Camp => "camp.title",
+            Screen::Encounter => "log.encounter",
+            Screen::Crossing => return self.crossing_title(),
+            Screen::RoutePrompt => return self.route_prompt_text("title"),
+            Screen::Store => "otdeluxe.store.title",
+            Screen::Boss => "boss.title",
+            Screen::Result => "result.title",
+        };
+        self.strings.t(key)
+    }
+
+    /// Status and description lines shown above the menu.
+    #[must_use]
+    pub fn body(&self) -> Vec<String> {
+        match self.screen {
+            Screen::Menu => vec![self.strings.t("menu.subtitle")],
+            Screen::ModeSelect => vec![self.strings.t("mode.subtitle")],
+            Screen::Persona => self.persona_body(),
+            Screen::Outfitting => self.outfitting_body(),
+            Screen::Encounter => self.encounter_body(),
+            Screen::Crossing => self.crossing_body(),
+            Screen::RoutePrompt => vec![self.route_prompt_text("prompt")],
+            Screen::Store => self.ot_store_body(),
+            Screen::Boss => self.boss_body(),
+            Screen::Result => self.result_body(),
+            Screen::Camp => vec![self.strings.t("camp.desc")],
+            Screen::Occupation | Screen::Travel | Screen::PaceDiet => Vec::new(),
+        }
+    }
+
+    /// Key hints for the current screen.
+    #[must_use]
+    pub fn help(&self) -> String {
+        let mut help = self.strings.t("tui.help.list");
+        match self.screen {
+            Screen::ModeSelect | Screen::Outfitting | Screen::Store => {
+                help.push_str(" · ");
+                help.push_str(&self.strings.t("tui.help.adjust"));
+            }
+            Screen::Travel => {
+                help.push_str(" · ");
+                help.push_str(&self.strings.t("tui.help.travel"));
+            }
+            _ => {}
+        }
+        help
+    }
+
+    /// Selectable rows for the current screen.
+    #[must_use]
+    pub fn items(&self) -> Vec<MenuItem> {
+        match self.screen {
+            Screen::Menu => vec![
+                MenuItem::new(self.strings.t("menu.start_journey"), Action::NewRun),
+                MenuItem::new(self.strings.t("save.load"), Action::Load),
+                MenuItem::new(self.strings.t("menu.quit"), Action::Quit),
+            ],
+            Screen::ModeSelect => self.mode_select_items(),
+            Screen::Persona => self.persona_items(),
+            Screen::Occupation => self.occupation_items(),
+            Screen::Outfitting => self.outfitting_items(),
+            Screen::Travel => self.travel_items(),
+            Screen::PaceDiet => self.pace_diet_items(),
+            Screen::Camp => self.camp_items(),
+            Screen::Encounter => self.encounter_items(),
+            Screen::Crossing => self.crossing_items(),
+            Screen::RoutePrompt => self.route_prompt_items(),
+            Screen::Store => self.ot_store_items(),
+            Screen::Boss => vec![MenuItem::new(self.strings.t("boss.begin"), Action::Boss)],
+            Screen::Result => vec![
+                MenuItem::new(self.strings.t("result.menu.replay_seed"), Action::Replay),
+                MenuItem::new(self.strings.t("result.menu.new_run"), Action::NewRun),
+                MenuItem::new(self.strings.t("menu.quit"), Action::Quit),
+            ],
+        }
+    }
+
+    fn activate(&mut self, action: Action) {
+        match action {
+            Action::NewRun => {
+                self.reset_run();
+                self.set_screen(Screen::ModeSelect);
+            }
+            Action::Load => self.load(),
+            Action::Quit => self.quit = true,
+            Action::Setup(row) => self.adjust_setup(row, 1),
+            Action::BeginSetup => self.begin_setup(),
+            Action::Persona(idx) => self.choose_persona(idx),
+            Action::Occupation(occupation) => self.start_otdeluxe(occupation),
+            Action::CartItem(id) => self.adjust_cart(&id, 1),
+            Action::Checkout => self.checkout(),
+            Action::Travel => self.tick_with(|_| {}),
+            Action::OpenPaceDiet => self.set_screen(Screen::PaceDiet),
+            Action::OpenCamp => self.set_screen(Screen::Camp),
+            Action::Intent(intent) => self.tick_with(|gs| gs.intent.pending = intent),
+            Action::Save => self.save(),
+            Action::MainMenu => {
+                self.reset_run();
+                self.set_screen(Screen::Menu);
+            }
+            Action::Pace(pace) => self.with_state(|gs| gs.pace = pace),
+            Action::Diet(diet) => self.with_state(|gs| gs.diet = diet),
+            Action::OtPace(pace) => self.with_state(|gs| gs.ot_deluxe.pace = pace),
+            Action::OtRations(rations) => self.with_state(|gs| gs.ot_deluxe.rations = rations),
+            Action::Back => self.back(),
+            Action::CampRest | Action::CampForage | Action::CampSpare(_) | Action::CampHack => {
+                self.camp(&action)
+            }
+            Action::EncounterChoice(idx) => self.choose_encounter(idx),
+            Action::Crossing(choice) => self.tick_with(|gs| gs.set_crossing_choice(choice)),
+            Action::OtCrossing(method) => {
+                self.tick_with(|gs| gs.set_otdeluxe_crossing_choice(method));
+            }
+            Action::Route(decision) => self.tick_with(|gs| gs.set_route_prompt_choice(decision)),
+            Action::OtStoreItem(idx) => self.adjust_ot_cart(idx, 1),
+            Action::OtStoreBuy => self.ot_store_checkout(true),
+            Action::OtStoreLeave => self.ot_store_checkout(false),
+            Action::Boss => self.boss(),
+            Action::Replay => self.replay(),
+        }
+    }
+
+    fn adjust(&mut self, action: &Action, delta: i32) {
+        match action {
+            Action::Setup(row) => self.adjust_setup(*row, delta),
+            Action::CartItem(id) => self.adjust_cart(id, delta),
+            Action::OtStoreItem(idx) => self.adjust_ot_cart(*idx, delta),
+            _ => {}
+        }
+    }
+
+    fn reset_run(&mut self) {
+        self.session = None;
+        self.pending = None;
+        self.cart.clear();
+        self.ot_cart = [0; OT_STORE_ITEMS.len()];
+        self.log.clear();
+    }
+
+    fn with_state(&mut self, f: impl FnOnce(&mut GameState)) {
+        if let Some(session) = self.session.as_mut() {
+            session.with_state_mut(f);
+        }
+    }
+
+    fn tick_with(&mut self, prepare: impl FnOnce(&mut GameState)) {
+        let Some(session) = self.session.as_mut() else {
+            return;
+        };
+        session.with_state_mut(prepare);
+        let outcome = session.tick_day();
+        self.push_outcome(&outcome);
+        self.sync_screen();
+    }
+
+    fn push_outcome(&mut self, outcome: &DayOutcome) {
+        if outcome.events.is_empty() {
+            self.log.push(self.strings.t(&outcome.log_key));
+        } else {
+            let entries: Vec<String> = outcome
+                .events
+                .iter()
+                .filter_map(|event| event.ui_key.as_deref())
+                .map(|key| self.strings.t(key))
+                .collect();
+            self.log.extend(entries);
+        }
+    }
+
+    fn begin_session(&mut self, mut session: JourneySession) {
+        self.load_trail_graves(&mut session);
+        let mode_key = if session.state().mode.is_deep() {
+            "mode.deep"
+        } else {
+            "mode.classic"
+        };
+        let mode = self.strings.t(mode_key);
+        self.log = vec![self.strings.tr("log.run_begins", &[("mode", &mode)])];
+        self.session = Some(session);
+        self.set_screen(Screen::Travel);
+        self.sync_screen();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_dir(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("dystrail-tui-app-{name}-{}", std::process::id()))
+    }
+
+    fn test_app(name: &str, mechanics: MechanicalPolicyId) -> App {
+        let dir = test_dir(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        let setup = RunSetup {
+            mechanics,
+            share_code: Some(String::from("CL-ORANGE42")),
+            ..RunSetup::default()
+        };
+        App::new(
+            Strings::english().unwrap(),
+            Assets::load().unwrap(),
+            FileGameStorage::new(dir),
+            String::from("test"),
+            setup,
+        )
+    }
+
+    fn press_item(app: &mut App, idx: usize) {
+        while app.cursor() > idx {
+            app.handle_key(Key::Up);
+        }
+        while app.cursor() < idx {
+            app.handle_key(Key::Down);
+        }
+        app.handle_key(Key::Enter);
+    }
+
+    fn press_last(app: &mut App) {
+        let last = app.items().len() - 1;
+        press_item(app, last);
+    }
+
+    fn start_run(app: &mut App) {
+        app.handle_key(Key::Enter);
+        assert_eq!(app.screen, Screen::ModeSelect);
+        press_last(app);
+        match app.setup.mechanics {
+            MechanicalPolicyId::DystrailLegacy => {
+                assert_eq!(app.screen, Screen::Persona);
+                app.handle_key(Key::Char('1'));
+                assert_eq!(app.screen, Screen::Outfitting);
+                app.handle_key(Key::Right);
+                app.handle_key(Key::Right);
+                press_last(app);
+            }
+            MechanicalPolicyId::OtDeluxe90s => {
+                assert_eq!(app.screen, Screen::Occupation);
+                app.handle_key(Key::Char('1'));
+            }
+        }
+        assert!(app.session.is_some());
+        assert_eq!(app.share_code, "CL-ORANGE42");
+    }
+
+    fn play_to_result(app: &mut App) {
+        for _ in 0..5_000 {
+            match app.screen {
+                Screen::Result => return,
+                Screen::Store => outfit_wagon(app),
+                Screen::Crossing => {
+                    let idx = app
+                        .items()
+                        .iter()
+                        .position(|item| item.enabled)
+                        .expect("a crossing option is always available");
+                    press_item(app, idx);
+                }
+                Screen::Travel | Screen::Encounter | Screen::RoutePrompt | Screen::Boss => {
+                    app.handle_key(Key::Char('1'))
+                }
+                other => panic!("unexpected screen {other:?}"),
+            }
+        }
+        panic!("run did not finish");
+    }
+
+    fn outfit_wagon(app: &mut App) {
+        let needs_oxen = app.state().is_some_and(|gs| gs.ot_deluxe.oxen.healthy == 0);
+        if needs_oxen {
+            for (idx, presses) in [(0, 4), (1, 8), (3, 2)] {
+                press_item(app, idx);
+                for _ in 1..presses {
+                    app.handle_key(Key::Right);
+                }
+            }
+            let checkout = app.items().len() - 2;
+            press_item(app, checkout);
+        } else {
+            press_last(app);
+        }
+    }
+
+    #[test]
+    fn plays_a_dystrail_run_to_the_result_screen() {
+        let mut app = test_app("dystrail", MechanicalPolicyId::DystrailLegacy);
+        start_run(&mut app);
+        play_to_result(&mut app);
+        assert!(app.state().unwrap().day > 1);
+        assert!(app.body().len() > 3);
+        assert!(app.log().len() > 1);
+
+        press_item(&mut app, 0);
+        assert_eq!(app.screen, Screen::Persona);
+        assert_eq!(app.share_code, "CL-ORANGE42");
+    }
+
+    #[test]
+    fn plays_an_otdeluxe_run_to_the_result_screen() {
+        let mut app = test_app("otdeluxe", MechanicalPolicyId::OtDeluxe90s);
+        start_run(&mut app);
+        assert_eq!(
+            app.state().unwrap().mechanical_policy,
+            MechanicalPolicyId::OtDeluxe90s
+        );
+        assert_eq!(app.screen, Screen::Store);
+        play_to_result(&mut app);
+        let gs = app.state().unwrap();
+        assert!(gs.ot_deluxe.oxen.healthy + gs.ot_deluxe.oxen.sick > 0);
+        assert!(gs.ot_deluxe.miles_traveled > 0.0);
+    }
+
+    #[test]
+    fn finished_runs_leave_graves_for_the_next_run() {
+        let mut app = test_app("graves", MechanicalPolicyId::OtDeluxe90s);
+        start_run(&mut app);
+        play_to_result(&mut app);
+        let deaths = app.state().unwrap().ot_deluxe.deaths.len();
+        let graveyard = app.storage.load_graveyard().unwrap();
+        assert_eq!(graveyard.len(), deaths);
+
+        press_item(&mut app, 0);
+        assert_eq!(app.screen, Screen::Occupation);
+        app.handle_key(Key::Char('1'));
+        assert_eq!(app.state().unwrap().trail_graves, graveyard.tombstones);
+        let _ = std::fs::remove_dir_all(test_dir("graves"));
+    }
+
+    #[test]
+    fn saves_and_loads_through_the_menu() {
+        let mut app = test_app("save", MechanicalPolicyId::DystrailLegacy);
+        start_run(&mut app);
+        while app.state().unwrap().day < 4 {
+            if app.screen == Screen::Travel {
+                app.handle_key(Key::Char('1'));
+            } else {
+                play_step_off_travel(&mut app);
+            }
+        }
+        assert_eq!(app.screen, Screen::Travel);
+        let day = app.state().unwrap().day;
+        app.handle_key(Key::Char('s'));
+        assert_eq!(app.log().last().unwrap(), &app.strings.t("save.saved"));
+
+        let mut fresh = App::new(
+            Strings::english().unwrap(),
+            Assets::load().unwrap(),
+            app.storage.clone(),
+            String::from("test"),
+            RunSetup::default(),
+        );
+        fresh.handle_key(Key::Char('l'));
+        assert_eq!(fresh.screen, Screen::Travel);
+        assert_eq!(fresh.state().unwrap().day, day);
+        assert_eq!(fresh.share_code, "CL-ORANGE42");
+        let _ = std::fs::remove_dir_all(test_dir("save"));
+    }
+
+    fn play_step_off_travel(app: &mut App) {
+        match app.screen {
+            Screen::Crossing => {
+                let idx = app.items().iter().position(|item| item.enabled).unwrap();
+                press_item(app, idx);
+            }
+            _ => app.handle_key(Key::Char('1')),
+        }
+    }
+
+    #[test]
+    fn missing_save_is_reported() {
+        let mut app = test_app("missing", MechanicalPolicyId::DystrailLegacy);
+        app.handle_key(Key::Char('l'));
+        assert_eq!(app.screen, Screen::Menu);
+        assert_eq!(app.log(), [app.strings.t("tui.no_save")]);
+    }
+
+    #[test]
+    fn escape_backs_out_of_setup_and_q_quits() {
+        let mut app = test_app("back", MechanicalPolicyId::DystrailLegacy);
+        app.handle_key(Key::Enter);
+        app.handle_key(Key::Down);
+        app.handle_key(Key::Right);
+        assert_eq!(app.setup.mechanics, MechanicalPolicyId::OtDeluxe90s);
+        app.handle_key(Key::Back);
+        assert_eq!(app.screen, Screen::Menu);
+        app.handle_key(Key::Char('q'));
+        assert!(app.should_quit());
+    }
+}
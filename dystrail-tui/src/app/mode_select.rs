@@ -0,0 +1,140 @@
+//! Mode and rule-set selection before a run starts.
+
+use super::{Action, App, MenuItem, Screen, SetupRow};
+use dystrail_game::{
+    GameMode, GameState, MechanicalPolicyId, OTDELUXE_DEPARTURE_MONTHS, VehicleClass,
+    decode_to_seed, generate_code_from_entropy,
+};
+
+impl App {
+    pub(super) fn mode_select_items(&self) -> Vec<MenuItem> {
+        let mode_key = if self.setup.mode.is_deep() {
+            "mode.deep"
+        } else {
+            "mode.classic"
+        };
+        let mechanics_key = match self.setup.mechanics {
+            MechanicalPolicyId::DystrailLegacy => "history.mechanics.dystrail",
+            MechanicalPolicyId::OtDeluxe90s => "history.mechanics.otdeluxe",
+        };
+        let row = |label_key: &str, value: String, row: SetupRow| {
+            MenuItem::new(
+                format!("{}: < {value} >", self.strings.t(label_key)),
+                Action::Setup(row),
+            )
+        };
+        let mut items = vec![
+            row("mode.title", self.strings.t(mode_key), SetupRow::Mode),
+            row(
+                "mode.mechanics",
+                self.strings.t(mechanics_key),
+                SetupRow::Mechanics,
+            ),
+        ];
+        match self.setup.mechanics {
+            MechanicalPolicyId::DystrailLegacy => items.push(row(
+                "mode.vehicle",
+                self.strings
+                    .t(&format!("vehicle.classes.{}", self.setup.vehicle.key())),
+                SetupRow::Vehicle,
+            )),
+            MechanicalPolicyId::OtDeluxe90s => items.push(row(
+                "mode.departure",
+                self.strings
+                    .t(&format!("mode.months.{}", self.setup.departure_month)),
+                SetupRow::Departure,
+            )),
+        }
+        items.push(MenuItem::new(
+            self.strings.t("ui.continue"),
+            Action::BeginSetup,
+        ));
+        items
+    }
+
+    pub(super) fn adjust_setup(&mut self, row: SetupRow, delta: i32) {
+        match row {
+            SetupRow::Mode => {
+                self.setup.mode = if self.setup.mode.is_deep() {
+                    GameMode::Classic
+                } else {
+                    GameMode::Deep
+                };
+            }
+            SetupRow::Mechanics => {
+                self.setup.mechanics = match self.setup.mechanics {
+                    MechanicalPolicyId::DystrailLegacy => MechanicalPolicyId::OtDeluxe90s,
+                    MechanicalPolicyId::OtDeluxe90s => MechanicalPolicyId::DystrailLegacy,
+                };
+            }
+            SetupRow::Vehicle => {
+                let classes = VehicleClass::ALL;
+                let idx = classes
+                    .iter()
+                    .position(|class| *class == self.setup.vehicle)
+                    .unwrap_or(0);
+                self.setup.vehicle = classes[cycle(idx, classes.len(), delta)];
+            }
+            SetupRow::Departure => {
+                let months: Vec<u8> = OTDELUXE_DEPARTURE_MONTHS.collect();
+                let idx = months
+                    .iter()
+                    .position(|month| *month == self.setup.departure_month)
+                    .unwrap_or(0);
+                self.setup.departure_month = months[cycle(idx, months.len(), delta)];
+            }
+        }
+    }
+
+    pub(super) fn begin_setup(&mut self) {
+        let requested = self.setup.share_code.take();
+        let decoded = requested.as_deref().and_then(decode_to_seed);
+        let (is_deep, seed, code) =
+            if let (Some(code), Some((is_deep, seed))) = (requested, decoded) {
+                (is_deep, seed, code)
+            } else {
+                let code = generate_code_from_entropy(self.setup.mode.is_deep(), entropy());
+                let Some((is_deep, seed)) = decode_to_seed(&code) else {
+                    return;
+                };
+                (is_deep, seed, code)
+            };
+        self.setup.mode = if is_deep {
+            GameMode::Deep
+        } else {
+            GameMode::Classic
+        };
+        self.seed = seed;
+        self.share_code = code;
+        self.reset_run();
+        match self.setup.mechanics {
+            MechanicalPolicyId::DystrailLegacy => {
+                let mut state = GameState::default().with_seed(
+                    seed,
+                    self.setup.mode,
+                    self.assets.encounters.clone(),
+                );
+                state.select_vehicle_class(self.setup.vehicle);
+                self.pending = Some(state);
+                self.set_screen(Screen::Persona);
+            }
+            MechanicalPolicyId::OtDeluxe90s => self.set_screen(Screen::Occupation),
+        }
+    }
+}
+
+fn cycle(idx: usize, len: usize, delta: i32) -> usize {
+    if delta < 0 {
+        (idx + len - 1) % len
+    } else {
+        (idx + 1) % len
+    }
+}
+
+fn entropy() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| {
+            u64::try_from(elapsed.as_nanos() & u128::from(u64::MAX)).unwrap_or_default()
+        })
+}
@@ -0,0 +1,46 @@
+//! Occupation selection that starts an OTDeluxe run.
+
+use super::{Action, App, MenuItem};
+use dystrail_game::{
+    JourneySession, MechanicalPolicyId, OtDeluxeOccupation, OtDeluxeStartConfig, StrategyId,
+};
+
+const OCCUPATIONS: [(OtDeluxeOccupation, &str); 8] = [
+    (OtDeluxeOccupation::Banker, "banker"),
+    (OtDeluxeOccupation::Doctor, "doctor"),
+    (OtDeluxeOccupation::Merchant, "merchant"),
+    (OtDeluxeOccupation::Blacksmith, "blacksmith"),
+    (OtDeluxeOccupation::Carpenter, "carpenter"),
+    (OtDeluxeOccupation::Saddlemaker, "saddlemaker"),
+    (OtDeluxeOccupation::Farmer, "farmer"),
+    (OtDeluxeOccupation::Teacher, "teacher"),
+];
+
+impl App {
+    pub(super) fn occupation_items(&self) -> Vec<MenuItem> {
+        OCCUPATIONS
+            .iter()
+            .map(|(occupation, key)| {
+                MenuItem::new(
+                    self.strings.t(&format!("otdeluxe.occupations.{key}")),
+                    Action::Occupation(*occupation),
+                )
+            })
+            .collect()
+    }
+
+    pub(super) fn start_otdeluxe(&mut self, occupation: OtDeluxeOccupation) {
+        let mut start = OtDeluxeStartConfig::new(occupation);
+        start.departure_month = self.setup.departure_month;
+        let session = JourneySession::new_with_mechanics(
+            MechanicalPolicyId::OtDeluxe90s,
+            self.setup.mode,
+            StrategyId::Balanced,
+            self.seed,
+            self.assets.encounters.clone(),
+            &self.assets.endgame,
+            Some(start),
+        );
+        self.begin_session(session);
+    }
+}
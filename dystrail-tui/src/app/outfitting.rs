@@ -0,0 +1,138 @@
+//! Outfitting store for Dystrail runs.
+
+use super::{Action, App, MenuItem};
+use crate::i18n::fmt_cents;
+use dystrail_game::{
+    Grants, JourneySession, StrategyId, calculate_cart_total, calculate_effective_price,
+};
+
+impl App {
+    fn store_discount(&self) -> f64 {
+        self.pending
+            .as_ref()
+            .map_or(0.0, |gs| f64::from(gs.mods.store_discount_pct))
+    }
+
+    fn cart_total(&self) -> i64 {
+        calculate_cart_total(&self.cart, &self.assets.store, self.store_discount())
+    }
+
+    pub(super) fn outfitting_body(&self) -> Vec<String> {
+        let budget = self.pending.as_ref().map_or(0, |gs| gs.budget_cents);
+        let total = self.cart_total();
+        vec![
+            self.strings
+                .tr("store.menu.home", &[("budget", &fmt_cents(budget))]),
+            self.strings.tr(
+                "store.cart.total",
+                &[
+                    ("sum", &fmt_cents(total)),
+                    ("left", &fmt_cents(budget - total)),
+                ],
+            ),
+        ]
+    }
+
+    pub(super) fn outfitting_items(&self) -> Vec<MenuItem> {
+        let discount = self.store_discount();
+        let mut items: Vec<MenuItem> = self
+            .assets
+            .store
+            .categories
+            .iter()
+            .flat_map(|category| category.items.iter())
+            .map(|item| {
+                let key = format!("store.items.{}.name", item.id);
+                let name = self.strings.t(&key);
+                let name = if name == key { item.name.clone() } else { name };
+                let price = calculate_effective_price(item.price_cents, discount);
+                let qty = self.cart.get_quantity(&item.id);
+                MenuItem::new(
+                    format!("{name} — {} × {qty}", fmt_cents(price)),
+                    Action::CartItem(item.id.clone()),
+                )
+            })
+            .collect();
+        items.push(MenuItem::new(
+            self.strings.t("store.cart.checkout"),
+            Action::Checkout,
+        ));
+        items
+    }
+
+    pub(super) fn adjust_cart(&mut self, item_id: &str, delta: i32) {
+        let Some(item) = self.assets.store.find_item(item_id).cloned() else {
+            return;
+        };
+        if delta < 0 {
+            self.cart.remove_item(item_id, 1);
+            return;
+        }
+        let cap = if item.unique { 1 } else { item.max_qty };
+        if self.cart.get_quantity(item_id) >= cap {
+            self.log.push(
+                self.strings
+                    .tr("store.alerts.max_qty", &[("item", &item.name)]),
+            );
+            return;
+        }
+        let spare_room = self.pending.as_ref().map_or(0, |gs| {
+            gs.store_cart_spare_room(&self.cart, &self.assets.store)
+        });
+        if item.grants.spare_count() > spare_room {
+            self.log.push(
+                self.strings
+                    .tr("store.alerts.no_spare_room", &[("item", &item.name)]),
+            );
+            return;
+        }
+        let budget = self.pending.as_ref().map_or(0, |gs| gs.budget_cents);
+        let price = calculate_effective_price(item.price_cents, self.store_discount());
+        if self.cart_total() + price > budget {
+            self.log.push(self.strings.t("store.alerts.over_budget"));
+            return;
+        }
+        self.cart.add_item(item_id, 1);
+        if let Some(line) = self.cart.find_line_mut(item_id) {
+            line.item_name.clone_from(&item.name);
+        }
+    }
+
+    pub(super) fn checkout(&mut self) {
+        let Some(mut state) = self.pending.take() else {
+            return;
+        };
+        let discount = f64::from(state.mods.store_discount_pct);
+        let dropped = state.fit_store_cart(&mut self.cart, &self.assets.store, discount);
+        if dropped > 0 {
+            self.log.push(self.strings.tr(
+                "store.alerts.spares_dropped",
+                &[("count", &dropped.to_string())],
+            ));
+        }
+        let total = self.cart.total_cents;
+        let mut grants = Grants::default();
+        let mut tags = Vec::new();
+        for line in &self.cart.lines {
+            let Some(item) = self.assets.store.find_item(&line.item_id) else {
+                continue;
+            };
+            grants.supplies += item.grants.supplies * line.qty;
+            grants.credibility += item.grants.credibility * line.qty;
+            grants.spare_tire += item.grants.spare_tire * line.qty;
+            grants.spare_battery += item.grants.spare_battery * line.qty;
+            grants.spare_alt += item.grants.spare_alt * line.qty;
+            grants.spare_pump += item.grants.spare_pump * line.qty;
+            grants.medicine += item.grants.medicine * line.qty;
+            grants
+                .vehicle_upgrades
+                .extend(item.grants.vehicle_upgrades.iter().copied());
+            tags.extend(item.tags.iter().cloned());
+        }
+        state.apply_store_purchase(total, &grants, &tags);
+        self.cart.clear();
+        let strategy = state.policy.map_or(StrategyId::Balanced, StrategyId::from);
+        let session = JourneySession::from_state(state, strategy, &self.assets.endgame);
+        self.begin_session(session);
+    }
+}
@@ -0,0 +1,96 @@
+//! Pace and diet (or rations) settings.
+
+use super::{Action, App, MenuItem};
+use dystrail_game::{DietId, MechanicalPolicyId, OtDeluxePace, OtDeluxeRations, PaceId};
+
+pub(super) const PACES: [(PaceId, &str, &str); 3] = [
+    (
+        PaceId::Steady,
+        "pacediet.menu.pace_steady",
+        "pacediet.tooltips.steady",
+    ),
+    (
+        PaceId::Heated,
+        "pacediet.menu.pace_heated",
+        "pacediet.tooltips.heated",
+    ),
+    (
+        PaceId::Blitz,
+        "pacediet.menu.pace_blitz",
+        "pacediet.tooltips.blitz",
+    ),
+];
+
+pub(super) const DIETS: [(DietId, &str, &str); 3] = [
+    (
+        DietId::Quiet,
+        "pacediet.menu.diet_quiet",
+        "pacediet.tooltips.quiet",
+    ),
+    (
+        DietId::Mixed,
+        "pacediet.menu.diet_mixed",
+        "pacediet.tooltips.mixed",
+    ),
+    (
+        DietId::Doom,
+        "pacediet.menu.diet_doom",
+        "pacediet.tooltips.doom",
+    ),
+];
+
+pub(super) const OT_PACES: [(OtDeluxePace, &str); 3] = [
+    (OtDeluxePace::Steady, "otdeluxe.pace.steady"),
+    (OtDeluxePace::Strenuous, "otdeluxe.pace.strenuous"),
+    (OtDeluxePace::Grueling, "otdeluxe.pace.grueling"),
+];
+
+pub(super) const OT_RATIONS: [(OtDeluxeRations, &str); 3] = [
+    (OtDeluxeRations::Filling, "otdeluxe.rations.filling"),
+    (OtDeluxeRations::Meager, "otdeluxe.rations.meager"),
+    (OtDeluxeRations::BareBones, "otdeluxe.rations.bare_bones"),
+];
+
+impl App {
+    pub(super) fn pace_diet_items(&self) -> Vec<MenuItem> {
+        let Some(gs) = self.state() else {
+            return Vec::new();
+        };
+        let marked =
+            |selected: bool, label: String| format!("{} {label}", if selected { "●" } else { "○" });
+        let mut items: Vec<MenuItem> = if gs.mechanical_policy == MechanicalPolicyId::OtDeluxe90s {
+            OT_PACES
+                .iter()
+                .map(|(pace, key)| {
+                    MenuItem::new(
+                        marked(*pace == gs.ot_deluxe.pace, self.strings.t(key)),
+                        Action::OtPace(*pace),
+                    )
+                })
+                .chain(OT_RATIONS.iter().map(|(rations, key)| {
+                    MenuItem::new(
+                        marked(*rations == gs.ot_deluxe.rations, self.strings.t(key)),
+                        Action::OtRations(*rations),
+                    )
+                }))
+                .collect()
+        } else {
+            PACES
+                .iter()
+                .map(|(pace, key, tip)| {
+                    let label = format!("{} — {}", self.strings.t(key), self.strings.t(tip));
+                    MenuItem::new(marked(*pace == gs.pace, label), Action::Pace(*pace))
+                })
+                .chain(DIETS.iter().map(|(diet, key, tip)| {
+                    let label = format!("{} — {}", self.strings.t(key), self.strings.t(tip));
+                    MenuItem::new(marked(*diet == gs.diet, label), Action::Diet(*diet))
+                }))
+                .collect()
+        };
+        items.push(MenuItem::new(
+            self.strings.t("pacediet.menu.back"),
+            Action::Back,
+        ));
+        items
+    }
+}
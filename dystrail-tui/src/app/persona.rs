@@ -0,0 +1,65 @@
+//! Persona selection for Dystrail runs.
+
+use super::{Action, App, MenuItem, Screen};
+
+impl App {
+    pub(super) fn persona_body(&self) -> Vec<String> {
+        let Some(persona) = self.assets.personas.iter().nth(self.cursor) else {
+            return vec![self.strings.t("persona.preview_wait")];
+        };
+        let start = &persona.start;
+        let (sup, cred, san, mor, allies, budget) = (
+            start.supplies.to_string(),
+            start.credibility.to_string(),
+            start.sanity.to_string(),
+            start.morale.to_string(),
+            start.allies.to_string(),
+            start.budget.to_string(),
+        );
+        let mult = format!("{:.2}", persona.score_mult);
+        vec![
+            persona.desc.clone(),
+            self.strings.tr(
+                "persona.preview",
+                &[
+                    ("sup", &sup),
+                    ("cred", &cred),
+                    ("san", &san),
+                    ("mor", &mor),
+                    ("allies", &allies),
+                    ("budget", &budget),
+                ],
+            ),
+            self.strings.tr("persona.mult", &[("mult", &mult)]),
+        ]
+    }
+
+    pub(super) fn persona_items(&self) -> Vec<MenuItem> {
+        self.assets
+            .personas
+            .iter()
+            .enumerate()
+            .map(|(idx, persona)| {
+                let key = format!("persona.{}.name", persona.id);
+                let name = self.strings.t(&key);
+                let label = if name == key {
+                    persona.name.clone()
+                } else {
+                    name
+                };
+                MenuItem::new(label, Action::Persona(idx))
+            })
+            .collect()
+    }
+
+    pub(super) fn choose_persona(&mut self, idx: usize) {
+        let Some(persona) = self.assets.personas.iter().nth(idx).cloned() else {
+            return;
+        };
+        if let Some(state) = self.pending.as_mut() {
+            state.apply_persona(&persona);
+            self.cart.clear();
+            self.set_screen(Screen::Outfitting);
+        }
+    }
+}
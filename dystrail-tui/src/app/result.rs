@@ -0,0 +1,61 @@
+//! End-of-run summary.
+
+use super::App;
+use dystrail_game::{encode_friendly, result_summary};
+
+impl App {
+    pub(super) fn result_body(&self) -> Vec<String> {
+        let Some(gs) = self.state() else {
+            return Vec::new();
+        };
+        let summary = match result_summary(gs, &self.assets.result) {
+            Ok(summary) => summary,
+            Err(error) => {
+                return vec![
+                    self.strings.t("result.error.title"),
+                    self.strings
+                        .tr("result.error.announcement", &[("error", &error)]),
+                ];
+            }
+        };
+        let label = |key: &str, value: String| format!("{}: {value}", self.strings.t(key));
+        let mut lines = vec![
+            self.strings.t(&summary.headline_key),
+            self.strings.t(&summary.epilogue_key),
+            String::new(),
+            label("result.labels.score", summary.score.to_string()),
+            label("result.labels.days", summary.days.to_string()),
+            label(
+                "result.labels.miles",
+                format!("{:.0}", summary.miles_traveled),
+            ),
+            label("result.labels.encounters", summary.encounters.to_string()),
+            label("result.labels.supplies", summary.supplies.to_string()),
+            label("result.labels.credibility", summary.credibility.to_string()),
+            label("result.labels.pants_pct", format!("{}%", summary.pants_pct)),
+            label(
+                "result.labels.breakdowns",
+                summary.vehicle_breakdowns.to_string(),
+            ),
+            label("result.labels.seed", self.share_code.clone()),
+        ];
+        if let Some(persona) = summary.persona_name {
+            lines.push(label("result.labels.persona", persona));
+        }
+        lines
+    }
+
+    pub(super) fn replay(&mut self) {
+        let Some(gs) = self.state() else {
+            return;
+        };
+        let code = if self.share_code.is_empty() {
+            encode_friendly(gs.mode.is_deep(), gs.seed)
+        } else {
+            self.share_code.clone()
+        };
+        self.setup.mechanics = gs.mechanical_policy;
+        self.setup.share_code = Some(code);
+        self.begin_setup();
+    }
+}
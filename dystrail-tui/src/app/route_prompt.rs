@@ -0,0 +1,61 @@
+//! OTDeluxe route forks.
+
+use super::{Action, App, MenuItem};
+use dystrail_game::{OtDeluxeRouteDecision, OtDeluxeRoutePrompt};
+
+impl App {
+    fn route_prompt_key(&self) -> Option<&'static str> {
+        self.state()
+            .and_then(|gs| gs.ot_deluxe.route.pending_prompt)
+            .map(|prompt| match prompt {
+                OtDeluxeRoutePrompt::SubletteCutoff => "route_prompt.sublette",
+                OtDeluxeRoutePrompt::DallesShortcut => "route_prompt.dalles_shortcut",
+                OtDeluxeRoutePrompt::DallesFinal => "route_prompt.dalles_final",
+            })
+    }
+
+    pub(super) fn route_prompt_text(&self, field: &str) -> String {
+        self.route_prompt_key()
+            .map(|base| self.strings.t(&format!("{base}.{field}")))
+            .unwrap_or_default()
+    }
+
+    pub(super) fn route_prompt_items(&self) -> Vec<MenuItem> {
+        let Some(prompt) = self
+            .state()
+            .and_then(|gs| gs.ot_deluxe.route.pending_prompt)
+        else {
+            return Vec::new();
+        };
+        let Some(base) = self.route_prompt_key() else {
+            return Vec::new();
+        };
+        let options: [(&str, OtDeluxeRouteDecision); 2] = match prompt {
+            OtDeluxeRoutePrompt::SubletteCutoff => [
+                ("stay", OtDeluxeRouteDecision::StayOnTrail),
+                ("take", OtDeluxeRouteDecision::SubletteCutoff),
+            ],
+            OtDeluxeRoutePrompt::DallesShortcut => [
+                ("stay", OtDeluxeRouteDecision::StayOnTrail),
+                ("take", OtDeluxeRouteDecision::DallesShortcut),
+            ],
+            OtDeluxeRoutePrompt::DallesFinal => [
+                ("raft", OtDeluxeRouteDecision::RaftColumbia),
+                ("barlow", OtDeluxeRouteDecision::BarlowRoad),
+            ],
+        };
+        options
+            .into_iter()
+            .map(|(key, decision)| {
+                MenuItem::new(
+                    format!(
+                        "{} — {}",
+                        self.strings.t(&format!("{base}.options.{key}")),
+                        self.strings.t(&format!("{base}.desc.{key}"))
+                    ),
+                    Action::Route(decision),
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,49 @@
+//! Saving and loading the run in the configured slot.
+
+use super::{App, Screen};
+use dystrail_game::{GameStorage, JourneySession, StrategyId, encode_friendly};
+
+impl App {
+    pub(super) fn save(&mut self) {
+        let Some(gs) = self.session.as_ref().map(JourneySession::state) else {
+            return;
+        };
+        let line = match self.storage.save_game(&self.save_slot, gs) {
+            Ok(()) => self.strings.t("save.saved"),
+            Err(error) => self
+                .strings
+                .tr("tui.save_failed", &[("error", &error.to_string())]),
+        };
+        self.log.push(line);
+    }
+
+    pub(super) fn load(&mut self) {
+        let loaded = match self.storage.load_game(&self.save_slot) {
+            Ok(Some(gs)) => gs,
+            Ok(None) => {
+                self.log.push(self.strings.t("tui.no_save"));
+                return;
+            }
+            Err(_) => {
+                self.log.push(self.strings.t("save.error"));
+                return;
+            }
+        };
+        let Ok(gs) = loaded.rehydrate(self.assets.encounters.clone()) else {
+            self.log.push(self.strings.t("save.incompatible"));
+            return;
+        };
+        self.seed = gs.seed;
+        self.share_code = encode_friendly(gs.mode.is_deep(), gs.seed);
+        self.setup.mode = gs.mode;
+        self.setup.mechanics = gs.mechanical_policy;
+        let strategy = gs.policy.map_or(StrategyId::Balanced, StrategyId::from);
+        let mut session = JourneySession::from_state(gs, strategy, &self.assets.endgame);
+        self.load_trail_graves(&mut session);
+        self.pending = None;
+        self.session = Some(session);
+        self.log.push(self.strings.t("save.loaded"));
+        self.set_screen(Screen::Travel);
+        self.sync_screen();
+    }
+}
@@ -0,0 +1,150 @@
+//! OTDeluxe fort stores.
+
+use super::{Action, App, MenuItem};
+use crate::i18n::fmt_cents;
+use dystrail_game::mechanics::otdeluxe90s::OtDeluxeStorePolicy;
+use dystrail_game::{
+    GameState, OtDeluxe90sPolicy, OtDeluxeStoreItem, OtDeluxeStoreLineItem,
+    otdeluxe_price_cents_at_node, quote_otdeluxe_store_purchase,
+};
+
+/// OTDeluxe store rows with the quantity one key press adds.
+pub(super) const OT_STORE_ITEMS: [(OtDeluxeStoreItem, &str, u16); 8] = [
+    (OtDeluxeStoreItem::Oxen, "oxen", 1),
+    (OtDeluxeStoreItem::FoodLb, "food", 50),
+    (OtDeluxeStoreItem::ClothesSet, "clothes", 1),
+    (OtDeluxeStoreItem::AmmoBox, "ammo", 1),
+    (OtDeluxeStoreItem::Wheel, "wheel", 1),
+    (OtDeluxeStoreItem::Axle, "axle", 1),
+    (OtDeluxeStoreItem::Tongue, "tongue", 1),
+    (OtDeluxeStoreItem::Medicine, "medicine", 1),
+];
+
+impl App {
+    fn ot_store_node(gs: &GameState) -> u8 {
+        gs.ot_deluxe
+            .store
+            .pending_node
+            .unwrap_or(gs.ot_deluxe.route.current_node_index)
+    }
+
+    fn ot_store_lines(&self) -> Vec<OtDeluxeStoreLineItem> {
+        OT_STORE_ITEMS
+            .iter()
+            .zip(self.ot_cart)
+            .filter(|(_, quantity)| *quantity > 0)
+            .map(|((item, _, _), quantity)| OtDeluxeStoreLineItem {
+                item: *item,
+                quantity,
+            })
+            .collect()
+    }
+
+    pub(super) fn ot_store_body(&self) -> Vec<String> {
+        let Some(gs) = self.state() else {
+            return Vec::new();
+        };
+        let policy = store_policy();
+        let cash = u64::from(gs.ot_deluxe.inventory.cash_cents);
+        let quote = quote_otdeluxe_store_purchase(
+            &policy,
+            Self::ot_store_node(gs),
+            &gs.ot_deluxe.inventory,
+            gs.ot_deluxe.oxen,
+            &self.ot_store_lines(),
+        );
+        let total = quote.map_or(0, |receipt| u64::from(receipt.total_cost_cents));
+        let as_cents = |value: u64| fmt_cents(i64::try_from(value).unwrap_or(i64::MAX));
+        vec![
+            self.strings
+                .tr("otdeluxe.store.cash", &[("amount", &as_cents(cash))]),
+            self.strings
+                .tr("otdeluxe.store.total", &[("amount", &as_cents(total))]),
+            self.strings.tr(
+                "otdeluxe.store.remaining",
+                &[("amount", &as_cents(cash.saturating_sub(total)))],
+            ),
+        ]
+    }
+
+    pub(super) fn ot_store_items(&self) -> Vec<MenuItem> {
+        let Some(gs) = self.state() else {
+            return Vec::new();
+        };
+        let policy = store_policy();
+        let node = Self::ot_store_node(gs);
+        let mut items: Vec<MenuItem> = OT_STORE_ITEMS
+            .iter()
+            .zip(self.ot_cart)
+            .enumerate()
+            .map(|(idx, ((item, key, _), quantity))| {
+                let price = otdeluxe_price_cents_at_node(&policy, *item, node);
+                MenuItem::new(
+                    format!(
+                        "{} — {} × {quantity}",
+                        self.strings.t(&format!("otdeluxe.store.items.{key}.name")),
+                        fmt_cents(i64::from(price))
+                    ),
+                    Action::OtStoreItem(idx),
+                )
+            })
+            .collect();
+        items.push(MenuItem::new(
+            self.strings.t("otdeluxe.store.checkout"),
+            Action::OtStoreBuy,
+        ));
+        items.push(MenuItem::new(
+            self.strings.t("otdeluxe.store.leave"),
+            Action::OtStoreLeave,
+        ));
+        items
+    }
+
+    pub(super) fn adjust_ot_cart(&mut self, idx: usize, delta: i32) {
+        let (Some(slot), Some((_, _, step))) =
+            (self.ot_cart.get(idx).copied(), OT_STORE_ITEMS.get(idx))
+        else {
+            return;
+        };
+        let next = if delta < 0 {
+            slot.saturating_sub(*step)
+        } else {
+            slot.saturating_add(*step)
+        };
+        let previous = std::mem::replace(&mut self.ot_cart[idx], next);
+        let Some(gs) = self.state() else {
+            return;
+        };
+        let affordable = quote_otdeluxe_store_purchase(
+            &store_policy(),
+            Self::ot_store_node(gs),
+            &gs.ot_deluxe.inventory,
+            gs.ot_deluxe.oxen,
+            &self.ot_store_lines(),
+        )
+        .is_ok_and(|receipt| receipt.total_cost_cents <= gs.ot_deluxe.inventory.cash_cents);
+        if !affordable && delta > 0 {
+            self.ot_cart[idx] = previous;
+        }
+    }
+
+    pub(super) fn ot_store_checkout(&mut self, buy: bool) {
+        let lines = if buy {
+            self.ot_store_lines()
+        } else {
+            Vec::new()
+        };
+        let Some(session) = self.session.as_mut() else {
+            return;
+        };
+        if !session.with_state_mut(|gs| gs.set_otdeluxe_store_purchase(lines)) {
+            return;
+        }
+        self.ot_cart = [0; OT_STORE_ITEMS.len()];
+        self.tick_with(|_| {});
+    }
+}
+
+fn store_policy() -> OtDeluxeStorePolicy {
+    OtDeluxe90sPolicy::default().store
+}
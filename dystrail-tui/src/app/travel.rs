@@ -0,0 +1,152 @@
+//! Travel screen and the status bar of a live run.
+
+use super::{
+    Action, App, MenuItem,
+    pace_diet::{DIETS, OT_PACES, OT_RATIONS, PACES},
+};
+use crate::i18n::fmt_cents;
+use dystrail_game::state::DayIntent;
+use dystrail_game::{JourneySession, MechanicalPolicyId};
+
+impl App {
+    /// Stats lines for the status bar of a live run.
+    #[must_use]
+    pub fn status_lines(&self) -> Vec<String> {
+        let Some(gs) = self.session.as_ref().map(JourneySession::state) else {
+            return Vec::new();
+        };
+        let weather_key = format!("weather.states.{:?}", gs.weather_state.today);
+        let weather = self.strings.tr(
+            "weather.summary.label_only",
+            &[("weather", &self.strings.t(&weather_key))],
+        );
+        let code = format!("{} {}", self.strings.t("game.seed_label"), self.share_code);
+        if gs.mechanical_policy == MechanicalPolicyId::OtDeluxe90s {
+            let ot = &gs.ot_deluxe;
+            let pace = OT_PACES
+                .iter()
+                .find(|(pace, _)| *pace == ot.pace)
+                .map(|(_, key)| self.strings.t(key))
+                .unwrap_or_default();
+            let rations = OT_RATIONS
+                .iter()
+                .find(|(rations, _)| *rations == ot.rations)
+                .map(|(_, key)| self.strings.t(key))
+                .unwrap_or_default();
+            let cash = fmt_cents(i64::from(ot.inventory.cash_cents));
+            return vec![
+                format!(
+                    "{} · {:.0} mi · {weather} · {code}",
+                    self.strings.tr(
+                        "stats.day_region",
+                        &[
+                            ("day", &gs.day.to_string()),
+                            (
+                                "region",
+                                &format!(
+                                    "{}/{}/{}",
+                                    ot.calendar.month, ot.calendar.day_in_month, ot.calendar.year
+                                )
+                            )
+                        ]
+                    ),
+                    ot.miles_traveled
+                ),
+                format!(
+                    "{} {} · {} {} lbs · {} {} · {cash} · {pace} · {rations}",
+                    self.strings.t("otdeluxe.store.items.oxen.name"),
+                    ot.oxen.total(),
+                    self.strings.t("otdeluxe.store.items.food.name"),
+                    ot.inventory.food_lbs,
+                    self.strings.t("otdeluxe.store.items.ammo.name"),
+                    ot.inventory.bullets,
+                ),
+            ];
+        }
+        let region = self
+            .strings
+            .t(&format!("region.{}", gs.region.asset_key().to_lowercase()));
+        let s = &gs.stats;
+        let pace = PACES
+            .iter()
+            .find(|(pace, _, _)| *pace == gs.pace)
+            .map(|(_, key, _)| self.strings.t(key))
+            .unwrap_or_default();
+        let diet = DIETS
+            .iter()
+            .find(|(diet, _, _)| *diet == gs.diet)
+            .map(|(_, key, _)| self.strings.t(key))
+            .unwrap_or_default();
+        let mut lines = vec![
+            format!(
+                "{} · {:.0}/{:.0} mi · {weather} · {code}",
+                self.strings.tr(
+                    "stats.day_region",
+                    &[("day", &gs.day.to_string()), ("region", &region)]
+                ),
+                gs.miles_traveled_actual,
+                gs.trail_distance,
+            ),
+            format!(
+                "{} {} · {} {} · {} {} · {} {} · {} {} · {} {} · {} · {}",
+                self.strings.t("stats.hp_short"),
+                s.hp,
+                self.strings.t("stats.sup_short"),
+                s.supplies,
+                self.strings.t("stats.sanity_short"),
+                s.sanity,
+                self.strings.t("stats.cred_short"),
+                s.credibility,
+                self.strings.t("stats.mor_short"),
+                s.morale,
+                self.strings.t("stats.allies_short"),
+                s.allies,
+                self.strings
+                    .tr("stats.pants", &[("pct", &s.pants.to_string())]),
+                self.strings
+                    .tr("store.budget", &[("amount", &fmt_cents(gs.budget_cents))]),
+            ),
+            format!("{pace} · {diet}"),
+        ];
+        if let Some(breakdown) = &gs.breakdown {
+            lines.push(self.strings.tr(
+                "vehicle.breakdown",
+                &[("part", &self.strings.t(breakdown.part.key()))],
+            ));
+        }
+        lines
+    }
+
+    pub(super) fn travel_items(&self) -> Vec<MenuItem> {
+        let otdeluxe = self
+            .state()
+            .is_some_and(|gs| gs.mechanical_policy == MechanicalPolicyId::OtDeluxe90s);
+        let mut items = vec![
+            MenuItem::new(self.strings.t("travel.next"), Action::Travel),
+            MenuItem::new(self.strings.t("pacediet.title"), Action::OpenPaceDiet),
+        ];
+        if otdeluxe {
+            for (key, intent) in [
+                ("travel.rest", DayIntent::Rest),
+                ("travel.hunt", DayIntent::Hunt),
+                ("travel.fish", DayIntent::Fish),
+                ("travel.forage", DayIntent::Forage),
+                ("travel.trade", DayIntent::Trade),
+            ] {
+                items.push(MenuItem::new(self.strings.t(key), Action::Intent(intent)));
+            }
+        } else {
+            items.push(MenuItem::new(
+                self.strings.t("camp.title"),
+                Action::OpenCamp,
+            ));
+        }
+        items.push(MenuItem::new(self.strings.t("save.save"), Action::Save));
+        items.push(MenuItem::new(self.strings.t("save.load"), Action::Load));
+        items.push(MenuItem::new(
+            self.strings.t("tui.quit_to_menu"),
+            Action::MainMenu,
+        ));
+        items
+    }
+}
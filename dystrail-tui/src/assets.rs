@@ -0,0 +1,58 @@
+//! Game data bundled from the web client's static assets.
+
+use dystrail_game::{
+    BossConfig, CampConfig, CrossingConfig, EncounterData, EndgameTravelCfg, PersonasList,
+    ResultConfig, Store,
+};
+
+const GAME_JSON: &str = include_str!("../../dystrail-web/static/assets/data/game.json");
+const PERSONAS_JSON: &str = include_str!("../../dystrail-web/static/assets/data/personas.json");
+const STORE_JSON: &str = include_str!("../../dystrail-web/static/assets/data/store.json");
+const CROSSINGS_JSON: &str = include_str!("../../dystrail-web/static/assets/data/crossings.json");
+const RESULT_JSON: &str = include_str!("../../dystrail-web/static/assets/data/result.json");
+
+/// Everything a run needs besides the player's choices.
+#[derive(Debug, Clone)]
+pub struct Assets {
+    pub encounters: EncounterData,
+    pub personas: PersonasList,
+    pub store: Store,
+    pub camp: CampConfig,
+    pub crossings: CrossingConfig,
+    pub boss: BossConfig,
+    pub endgame: EndgameTravelCfg,
+    pub result: ResultConfig,
+}
+
+impl Assets {
+    /// Parse the bundled data files.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any bundled JSON cannot be parsed.
+    pub fn load() -> Result<Self, serde_json::Error> {
+        Ok(Self {
+            encounters: EncounterData::from_json(GAME_JSON)?,
+            personas: PersonasList::from_json(PERSONAS_JSON)?,
+            store: serde_json::from_str(STORE_JSON)?,
+            camp: CampConfig::load_from_static(),
+            crossings: serde_json::from_str(CROSSINGS_JSON)?,
+            boss: BossConfig::load_from_static(),
+            endgame: EndgameTravelCfg::load_from_static(),
+            result: serde_json::from_str(RESULT_JSON)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_assets_parse() {
+        let assets = Assets::load().unwrap();
+        assert!(!assets.encounters.encounters.is_empty());
+        assert!(assets.personas.iter().next().is_some());
+        assert!(!assets.store.categories.is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+//! English string table shared with the web client.
+
+use serde_json::Value;
+
+const EN_JSON: &str = include_str!("../../dystrail-web/i18n/en.json");
+const TUI_EN_JSON: &str = include_str!("../i18n/en.json");
+
+/// Lookup table over `dystrail-web/i18n/en.json` plus the terminal-only `i18n/en.json`.
+#[derive(Debug, Clone)]
+pub struct Strings {
+    root: Value,
+}
+
+impl Strings {
+    /// Load the bundled English strings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bundled JSON cannot be parsed.
+    pub fn english() -> Result<Self, serde_json::Error> {
+        let mut root: Value = serde_json::from_str(EN_JSON)?;
+        let tui: Value = serde_json::from_str(TUI_EN_JSON)?;
+        if let (Some(root), Value::Object(tui)) = (root.as_object_mut(), tui) {
+            root.extend(tui);
+        }
+        Ok(Self { root })
+    }
+
+    /// Translate `key`, falling back to the key itself when it is missing.
+    #[must_use]
+    pub fn t(&self, key: &str) -> String {
+        self.tr(key, &[])
+    }
+
+    /// Translate `key`, substituting `{name}` and `{{name}}` placeholders.
+    #[must_use]
+    pub fn tr(&self, key: &str, args: &[(&str, &str)]) -> String {
+        let Some(value) = key
+            .split('.')
+            .try_fold(&self.root, |current, part| current.get(part))
+        else {
+            return key.to_string();
+        };
+        let mut text = match value {
+            Value::String(text) => text.clone(),
+            Value::Object(map) => {
+                let count = args
+                    .iter()
+                    .find(|(name, _)| *name == "count")
+                    .and_then(|(_, value)| value.parse::<f64>().ok());
+                let category = count.map(|count| {
+                    if (count - 1.0).abs() < f64::EPSILON {
+                        "one"
+                    } else if count.abs() < f64::EPSILON {
+                        "zero"
+                    } else {
+                        "other"
+                    }
+                });
+                let Some(text) = category
+                    .and_then(|category| map.get(category))
+                    .or_else(|| map.get("_"))
+                    .and_then(Value::as_str)
+                else {
+                    return key.to_string();
+                };
+                text.to_string()
+            }
+            _ => return key.to_string(),
+        };
+        for (name, value) in args {
+            text = text.replace(&format!("{{{{{name}}}}}"), value);
+            text = text.replace(&format!("{{{name}}}"), value);
+        }
+        text
+    }
+}
+
+/// Format a cent amount as dollars.
+#[must_use]
+pub fn fmt_cents(cents: i64) -> String {
+    let sign = if cents < 0 { "-" } else { "" };
+    let abs = cents.unsigned_abs();
+    format!("{sign}${}.{:02}", abs / 100, abs % 100)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookups_interpolate_and_fall_back() {
+        let strings = Strings::english().unwrap();
+        assert_eq!(strings.t("travel.next"), "Travel Next Leg");
+        assert_eq!(
+            strings.tr("log.run_begins", &[("mode", "Classic")]),
+            "Run begins (Classic)"
+        );
+        assert_eq!(strings.t("log.encounter"), "Encounter!");
+        assert_eq!(strings.t("tui.log_title"), "Log");
+        assert_eq!(strings.t("missing.key"), "missing.key");
+    }
+
+    #[test]
+    fn cents_format_as_dollars() {
+        assert_eq!(fmt_cents(12_345), "$123.45");
+        assert_eq!(fmt_cents(-5), "-$0.05");
+    }
+}
@@ -0,0 +1,110 @@
+//! Terminal client for Dystrail.
+
+mod app;
+mod assets;
+mod i18n;
+mod storage;
+mod ui;
+
+use anyhow::{Context, Result};
+use app::{App, Key, RunSetup};
+use assets::Assets;
+use clap::{Parser, ValueEnum};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::execute;
+use crossterm::terminal::{
+    EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
+};
+use dystrail_game::MechanicalPolicyId;
+use i18n::Strings;
+use ratatui::Terminal;
+use ratatui::backend::CrosstermBackend;
+use std::io;
+use std::path::PathBuf;
+use storage::FileGameStorage;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Mechanics {
+    Dystrail,
+    Otdeluxe,
+}
+
+impl From<Mechanics> for MechanicalPolicyId {
+    fn from(value: Mechanics) -> Self {
+        match value {
+            Mechanics::Dystrail => Self::DystrailLegacy,
+            Mechanics::Otdeluxe => Self::OtDeluxe90s,
+        }
+    }
+}
+
+#[derive(Parser, Debug)]
+#[command(name = "dystrail-tui")]
+#[command(about = "Play Dystrail in the terminal")]
+#[command(version)]
+struct Args {
+    /// Share code to start from (e.g., "CL-ORANGE42")
+    #[arg(short, long)]
+    code: Option<String>,
+
+    /// Rule set for new runs
+    #[arg(short, long, value_enum, default_value = "dystrail")]
+    mechanics: Mechanics,
+
+    /// Directory for save files
+    #[arg(long)]
+    save_dir: Option<PathBuf>,
+
+    /// Save slot name
+    #[arg(long, default_value = "default")]
+    slot: String,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let strings = Strings::english().context("parsing bundled strings")?;
+    let assets = Assets::load().context("parsing bundled game data")?;
+    let storage = FileGameStorage::new(args.save_dir.unwrap_or_else(FileGameStorage::default_dir));
+    let setup = RunSetup {
+        mechanics: args.mechanics.into(),
+        share_code: args.code,
+        ..RunSetup::default()
+    };
+    let mut app = App::new(strings, assets, storage, args.slot, setup);
+
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+    let result = run(&mut terminal, &mut app);
+    disable_raw_mode()?;
+    execute!(io::stdout(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+    result
+}
+
+fn run(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mut App) -> Result<()> {
+    while !app.should_quit() {
+        terminal.draw(|frame| ui::draw(frame, app))?;
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            break;
+        }
+        let key = match key.code {
+            KeyCode::Up | KeyCode::Char('k') => Key::Up,
+            KeyCode::Down | KeyCode::Char('j') => Key::Down,
+            KeyCode::Left | KeyCode::Char('h') => Key::Left,
+            KeyCode::Right => Key::Right,
+            KeyCode::Enter | KeyCode::Char(' ') => Key::Enter,
+            KeyCode::Esc | KeyCode::Backspace => Key::Back,
+            KeyCode::Char(c) => Key::Char(c),
+            _ => continue,
+        };
+        app.handle_key(key);
+    }
+    Ok(())
+}
@@ -0,0 +1,185 @@
+//! File-backed save and graveyard storage.
+
+use dystrail_game::{
+    GRAVEYARD_SCHEMA_VERSION, GameState, GameStorage, Graveyard, GraveyardStorage,
+    SAVE_SCHEMA_VERSION,
+};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Stores each save as a JSON file inside one directory.
+#[derive(Debug, Clone)]
+pub struct FileGameStorage {
+    dir: PathBuf,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FileStorageError {
+    #[error("Storage error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("Invalid save name: {0:?}")]
+    InvalidName(String),
+}
+
+impl FileGameStorage {
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Default save directory: `$XDG_DATA_HOME/dystrail`, then `~/.local/share/dystrail`.
+    #[must_use]
+    pub fn default_dir() -> PathBuf {
+        std::env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .or_else(|| {
+                std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share"))
+            })
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("dystrail")
+    }
+
+    fn save_path(&self, save_name: &str) -> Result<PathBuf, FileStorageError> {
+        let valid = !save_name.is_empty()
+            && save_name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(FileStorageError::InvalidName(save_name.to_string()));
+        }
+        Ok(self
+            .dir
+            .join(format!("save.v{SAVE_SCHEMA_VERSION}.{save_name}.json")))
+    }
+
+    fn graveyard_path(&self) -> PathBuf {
+        self.dir
+            .join(format!("graveyard.v{GRAVEYARD_SCHEMA_VERSION}.json"))
+    }
+
+    fn write_atomic(&self, path: &Path, serialized: &str) -> Result<(), FileStorageError> {
+        fs::create_dir_all(&self.dir)?;
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, serialized)?;
+        fs::rename(&tmp, path)?;
+        Ok(())
+    }
+}
+
+impl GameStorage for FileGameStorage {
+    type Error = FileStorageError;
+
+    fn save_game(&self, save_name: &str, game_state: &GameState) -> Result<(), Self::Error> {
+        let path = self.save_path(save_name)?;
+        fs::create_dir_all(&self.dir)?;
+        let serialized = serde_json::to_string(game_state)?;
+        self.write_atomic(&path, &serialized)
+    }
+
+    fn load_game(&self, save_name: &str) -> Result<Option<GameState>, Self::Error> {
+        let path = self.save_path(save_name)?;
+        match fs::read_to_string(&path) {
+            Ok(json) => Ok(Some(serde_json::from_str(&json)?)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn delete_save(&self, save_name: &str) -> Result<(), Self::Error> {
+        let path = self.save_path(save_name)?;
+        match fs::remove_file(path) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl GraveyardStorage for FileGameStorage {
+    type Error = FileStorageError;
+
+    fn load_graveyard(&self) -> Result<Graveyard, Self::Error> {
+        match fs::read_to_string(self.graveyard_path()) {
+            Ok(json) => Ok(Graveyard::from_json(&json)?),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Graveyard::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn save_graveyard(&self, graveyard: &Graveyard) -> Result<(), Self::Error> {
+        let serialized = serde_json::to_string(graveyard)?;
+        self.write_atomic(&self.graveyard_path(), &serialized)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dystrail_game::otdeluxe_state::OtDeluxePartyState;
+    use dystrail_game::{
+        EncounterData, GameMode, MechanicalPolicyId, OtDeluxeDeathCause, OtDeluxeSkillPolicy,
+    };
+
+    fn temp_storage(name: &str) -> FileGameStorage {
+        let dir = std::env::temp_dir().join(format!("dystrail-tui-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        FileGameStorage::new(dir)
+    }
+
+    #[test]
+    fn save_load_delete_round_trip() {
+        let storage = temp_storage("round-trip");
+        assert!(storage.load_game("slot").unwrap().is_none());
+
+        let mut state = GameState::default().with_seed(77, GameMode::Deep, EncounterData::empty());
+        state.day = 12;
+        storage.save_game("slot", &state).unwrap();
+
+        let loaded = storage.load_game("slot").unwrap().expect("save exists");
+        assert_eq!(loaded.seed, 77);
+        assert_eq!(loaded.day, 12);
+        assert_eq!(loaded.mode, GameMode::Deep);
+
+        storage.delete_save("slot").unwrap();
+        assert!(storage.load_game("slot").unwrap().is_none());
+        storage.delete_save("slot").unwrap();
+        let _ = fs::remove_dir_all(&storage.dir);
+    }
+
+    #[test]
+    fn rejects_path_like_names() {
+        let storage = temp_storage("names");
+        for name in ["", "../escape", "a/b", "dot.name"] {
+            assert!(matches!(
+                storage.load_game(name),
+                Err(FileStorageError::InvalidName(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn graveyard_survives_between_runs() {
+        let storage = temp_storage("graveyard");
+        assert!(storage.load_graveyard().unwrap().is_empty());
+
+        let mut state = GameState {
+            mechanical_policy: MechanicalPolicyId::OtDeluxe90s,
+            ..GameState::default()
+        };
+        state.ot_deluxe.party = OtDeluxePartyState::from_names(["Ada"]);
+        state.ot_deluxe.record_death(
+            0,
+            OtDeluxeDeathCause::Exhaustion,
+            &OtDeluxeSkillPolicy::default(),
+        );
+        assert_eq!(storage.bury_run(&state, "CL-ORANGE42").unwrap(), 1);
+        assert_eq!(storage.bury_run(&state, "CL-ORANGE42").unwrap(), 0);
+
+        let graveyard = storage.load_graveyard().unwrap();
+        assert_eq!(graveyard.len(), 1);
+        assert_eq!(graveyard.tombstones[0].name, "Ada");
+        let _ = fs::remove_dir_all(&storage.dir);
+    }
+}
@@ -0,0 +1,88 @@
+//! Ratatui rendering for [`App`].
+
+use crate::app::App;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+
+const LOG_LINES: usize = 8;
+
+pub fn draw(frame: &mut Frame, app: &App) {
+    let status = app.status_lines();
+    let body = app.body();
+    let items = app.items();
+    let status_height = u16::try_from(status.len()).unwrap_or(0) + 2;
+    let body_height = u16::try_from(body.len()).unwrap_or(0).min(12) + 2;
+    let [status_area, body_area, menu_area, log_area, help_area] = Layout::vertical([
+        Constraint::Length(if status.is_empty() { 0 } else { status_height }),
+        Constraint::Length(if body.is_empty() { 0 } else { body_height }),
+        Constraint::Min(3),
+        Constraint::Length(u16::try_from(LOG_LINES).unwrap_or(0) + 2),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    if !status.is_empty() {
+        let lines: Vec<Line> = status.into_iter().map(Line::from).collect();
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title("Dystrail")),
+            status_area,
+        );
+    }
+
+    if !body.is_empty() {
+        let lines: Vec<Line> = body.into_iter().map(Line::from).collect();
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: true })
+                .block(Block::default().borders(Borders::ALL)),
+            body_area,
+        );
+    }
+
+    let list_items: Vec<ListItem> = items
+        .iter()
+        .enumerate()
+        .map(|(idx, item)| {
+            let style = if item.enabled {
+                Style::default()
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{}. ", idx + 1), Style::default().fg(Color::Yellow)),
+                Span::styled(item.label.clone(), style),
+            ]))
+        })
+        .collect();
+    let mut list_state = ListState::default().with_selected(Some(app.cursor()));
+    frame.render_stateful_widget(
+        List::new(list_items)
+            .block(Block::default().borders(Borders::ALL).title(app.title()))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_symbol("> "),
+        menu_area,
+        &mut list_state,
+    );
+
+    let log = app.log();
+    let recent: Vec<Line> = log[log.len().saturating_sub(LOG_LINES)..]
+        .iter()
+        .map(|entry| Line::from(entry.as_str()))
+        .collect();
+    frame.render_widget(
+        Paragraph::new(recent).wrap(Wrap { trim: true }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(app.strings().t("tui.log_title")),
+        ),
+        log_area,
+    );
+
+    frame.render_widget(
+        Paragraph::new(app.help()).style(Style::default().fg(Color::DarkGray)),
+        help_area,
+    );
+}
@@ -0,0 +1,114 @@
+#[cfg(target_arch = "wasm32")]
+use crate::app::phase::Phase;
+#[cfg(target_arch = "wasm32")]
+use crate::app::state::AppState;
+use crate::game::{GameState, GraveyardStorage};
+#[cfg(target_arch = "wasm32")]
+use yew::prelude::*;
+
+/// Hand the stored graveyard to a session that is about to start; saves never
+/// carry graves, so this runs for new, loaded and imported runs alike.
+pub fn load_trail_graves<S: GraveyardStorage>(storage: &S, state: &mut GameState) -> bool {
+    let Ok(graveyard) = storage.load_graveyard() else {
+        return false;
+    };
+    state.set_trail_graves(&graveyard);
+    true
+}
+
+/// Bury the deaths of a finished run; graves already in the yard are skipped.
+pub fn bury_finished_run<S: GraveyardStorage>(
+    storage: &S,
+    state: &GameState,
+    share_code: &str,
+) -> usize {
+    storage.bury_run(state, share_code).unwrap_or(0)
+}
+
+#[cfg(target_arch = "wasm32")]
+#[hook]
+pub fn use_bury_finished_runs(app_state: &AppState) {
+    let session = app_state.session.clone();
+    use_effect_with(*app_state.phase, move |phase| {
+        if *phase == Phase::Result
+            && let Some(sess) = session.as_ref()
+        {
+            let state = sess.state();
+            let share_code = crate::game::encode_friendly(state.mode.is_deep(), state.seed);
+            let _ = bury_finished_run(&crate::game::WebGameStorage, state, &share_code);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{
+        Graveyard, MechanicalPolicyId, OtDeluxeDeathCause, OtDeluxeSkillPolicy,
+        otdeluxe_state::OtDeluxePartyState,
+    };
+    use std::cell::RefCell;
+    use std::convert::Infallible;
+
+    #[derive(Default)]
+    struct MemoryGraveyard {
+        graveyard: RefCell<Graveyard>,
+    }
+
+    impl GraveyardStorage for MemoryGraveyard {
+        type Error = Infallible;
+
+        fn load_graveyard(&self) -> Result<Graveyard, Self::Error> {
+            Ok(self.graveyard.borrow().clone())
+        }
+
+        fn save_graveyard(&self, graveyard: &Graveyard) -> Result<(), Self::Error> {
+            *self.graveyard.borrow_mut() = graveyard.clone();
+            Ok(())
+        }
+    }
+
+    fn run_with_death() -> GameState {
+        let mut state = GameState {
+            mechanical_policy: MechanicalPolicyId::OtDeluxe90s,
+            ..GameState::default()
+        };
+        state.ot_deluxe.party = OtDeluxePartyState::from_names(["Ada", "Ben"]);
+        state.ot_deluxe.miles_traveled = 80.0;
+        state.ot_deluxe.record_death(
+            0,
+            OtDeluxeDeathCause::Drowning,
+            &OtDeluxeSkillPolicy::default(),
+        );
+        state.ot_deluxe.set_epitaph(0, "Swept away");
+        state
+    }
+
+    #[test]
+    fn finished_runs_feed_the_next_session() {
+        let storage = MemoryGraveyard::default();
+        let finished = run_with_death();
+        assert_eq!(bury_finished_run(&storage, &finished, "CL-ORANGE42"), 1);
+        assert_eq!(bury_finished_run(&storage, &finished, "CL-ORANGE42"), 0);
+
+        let mut next = GameState::default();
+        assert!(load_trail_graves(&storage, &mut next));
+        assert_eq!(next.trail_graves.len(), 1);
+        assert_eq!(next.trail_graves[0].epitaph.as_deref(), Some("Swept away"));
+        assert_eq!(
+            next.trail_graves[0].share_code.as_deref(),
+            Some("CL-ORANGE42")
+        );
+    }
+
+    #[test]
+    fn graveyard_helpers_tolerate_missing_storage() {
+        let mut state = run_with_death();
+        assert!(!load_trail_graves(&crate::game::WebGameStorage, &mut state));
+        assert!(state.trail_graves.is_empty());
+        assert_eq!(
+            bury_finished_run(&crate::game::WebGameStorage, &state, "CL-ORANGE42"),
+            0
+        );
+    }
+}
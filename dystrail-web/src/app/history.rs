@@ -0,0 +1,95 @@
+#[cfg(target_arch = "wasm32")]
+use crate::app::phase::Phase;
+#[cfg(target_arch = "wasm32")]
+use crate::app::state::AppState;
+use crate::game::{GameState, ResultConfig, RunHistoryEntry, RunHistoryStorage, result_summary};
+#[cfg(target_arch = "wasm32")]
+use yew::prelude::*;
+
+/// Append a finished run to the stored history; repeated renders of the same
+/// result are ignored by the history itself.
+pub fn record_finished_run<S: RunHistoryStorage>(
+    storage: &S,
+    state: &GameState,
+    result_config: &ResultConfig,
+    recorded_at_ms: u64,
+) -> bool {
+    let Ok(summary) = result_summary(state, result_config) else {
+        return false;
+    };
+    let entry = RunHistoryEntry::from_run(state, &summary).with_recorded_at(recorded_at_ms);
+    storage.record_run(entry).unwrap_or(false)
+}
+
+#[cfg(target_arch = "wasm32")]
+#[hook]
+pub fn use_record_finished_runs(app_state: &AppState) {
+    let session = app_state.session.clone();
+    let result_config = app_state.result_config.clone();
+    use_effect_with(*app_state.phase, move |phase| {
+        if *phase == Phase::Result
+            && let Some(sess) = session.as_ref()
+        {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let now_ms = js_sys::Date::now().max(0.0) as u64;
+            let _ = record_finished_run(
+                &crate::game::WebGameStorage,
+                sess.state(),
+                &result_config,
+                now_ms,
+            );
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::RunHistory;
+    use std::cell::RefCell;
+    use std::convert::Infallible;
+
+    #[derive(Default)]
+    struct MemoryHistory {
+        history: RefCell<RunHistory>,
+    }
+
+    impl RunHistoryStorage for MemoryHistory {
+        type Error = Infallible;
+
+        fn load_history(&self) -> Result<RunHistory, Self::Error> {
+            Ok(self.history.borrow().clone())
+        }
+
+        fn save_history(&self, history: &RunHistory) -> Result<(), Self::Error> {
+            *self.history.borrow_mut() = history.clone();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn record_finished_run_stores_each_run_once() {
+        let storage = MemoryHistory::default();
+        let state = GameState {
+            seed: 77,
+            ..GameState::default()
+        };
+        let cfg = ResultConfig::default();
+        assert!(record_finished_run(&storage, &state, &cfg, 1_000));
+        assert!(!record_finished_run(&storage, &state, &cfg, 2_000));
+        let history = storage.history.borrow();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history.entries[0].recorded_at_ms, Some(1_000));
+    }
+
+    #[test]
+    fn record_finished_run_tolerates_missing_storage() {
+        let state = GameState::default();
+        assert!(!record_finished_run(
+            &crate::game::WebGameStorage,
+            &state,
+            &ResultConfig::default(),
+            0,
+        ));
+    }
+}
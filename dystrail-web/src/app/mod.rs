@@ -6,6 +6,8 @@ use yew::prelude::*;
 use yew_router::prelude::*;
 
 pub mod bootstrap;
+pub mod graveyard;
+pub mod history;
 pub mod phase;
 pub mod routing;
 pub mod state;
@@ -31,6 +33,8 @@ pub fn app() -> Html {
 pub fn app_inner() -> Html {
     let app_state = state::use_app_state();
     bootstrap::use_bootstrap(&app_state);
+    history::use_record_finished_runs(&app_state);
+    graveyard::use_bury_finished_runs(&app_state);
     test_bridge::use_test_bridge(&app_state);
 
     let navigator = use_navigator();
@@ -65,6 +69,7 @@ mod tests {
             Phase::Menu,
             Phase::About,
             Phase::Settings,
+            Phase::History,
             Phase::Persona,
             Phase::ModeSelect,
             Phase::Outfitting,
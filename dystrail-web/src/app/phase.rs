@@ -10,6 +10,7 @@ pub enum Phase {
     Menu,
     About,
     Settings,
+    History,
     Persona,
     ModeSelect,
     Outfitting,
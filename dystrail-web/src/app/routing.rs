@@ -30,7 +30,10 @@ fn next_phase_for_route(current_phase: Phase, route: Option<Route>) -> Option<Ph
 #[cfg(any(target_arch = "wasm32", test))]
 const fn is_route_transition_allowed(current: Phase, next: Phase) -> bool {
     match current {
-        Phase::Menu => matches!(next, Phase::About | Phase::Settings | Phase::Persona),
+        Phase::Menu => matches!(
+            next,
+            Phase::About | Phase::Settings | Phase::History | Phase::Persona
+        ),
         Phase::ModeSelect => matches!(next, Phase::Persona | Phase::Outfitting),
         Phase::Travel => matches!(
             next,
@@ -55,7 +58,7 @@ const fn is_route_transition_allowed(current: Phase, next: Phase) -> bool {
         Phase::Boss => matches!(next, Phase::Result),
         Phase::Persona => matches!(next, Phase::ModeSelect),
         Phase::Outfitting => matches!(next, Phase::ModeSelect | Phase::Travel),
-        Phase::Boot | Phase::About | Phase::Settings | Phase::Result => {
+        Phase::Boot | Phase::About | Phase::Settings | Phase::History | Phase::Result => {
             matches!(next, Phase::Menu)
         }
     }
@@ -125,6 +128,8 @@ mod tests {
         assert!(!is_route_transition_allowed(Phase::Menu, Phase::Boot));
         assert!(!is_route_transition_allowed(Phase::Menu, Phase::Travel));
         assert!(is_route_transition_allowed(Phase::Settings, Phase::Menu));
+        assert!(is_route_transition_allowed(Phase::Menu, Phase::History));
+        assert!(is_route_transition_allowed(Phase::History, Phase::Menu));
         assert!(is_route_transition_allowed(
             Phase::ModeSelect,
             Phase::Outfitting
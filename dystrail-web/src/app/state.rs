@@ -2,6 +2,7 @@ use crate::app::phase::Phase;
 use crate::game::boss::BossConfig;
 use crate::game::data::EncounterData;
 use crate::game::endgame::EndgameTravelCfg;
+use crate::game::journey::EventDecisionTrace;
 use crate::game::pacing::PacingConfig;
 use crate::game::state::GameState;
 use crate::game::weather::WeatherConfig;
@@ -32,6 +33,8 @@ pub struct AppState {
     pub save_focus_target: UseStateHandle<AttrValue>,
     pub show_settings: UseStateHandle<bool>,
     pub current_language: UseStateHandle<String>,
+    pub decision_traces: UseStateHandle<Vec<EventDecisionTrace>>,
+    pub show_decision_traces: UseStateHandle<bool>,
 }
 
 #[hook]
@@ -58,6 +61,10 @@ pub fn use_app_state() -> AppState {
         save_focus_target: use_state(|| AttrValue::from("save-open-btn")),
         show_settings: use_state(|| false),
         current_language: use_state(crate::i18n::current_lang),
+        decision_traces: use_state(Vec::<EventDecisionTrace>::new),
+        show_decision_traces: use_state(
+            crate::components::ui::decision_trace_overlay::overlay_enabled,
+        ),
     }
 }
 
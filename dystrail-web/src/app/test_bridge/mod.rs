@@ -1,4 +1,6 @@
 #[cfg(any(test, target_arch = "wasm32"))]
+mod protocol;
+#[cfg(any(test, target_arch = "wasm32"))]
 mod shared;
 #[cfg(not(target_arch = "wasm32"))]
 mod stub;
@@ -0,0 +1,522 @@
+//! Scripted-play protocol behind `window.__dystrailTest`.
+//!
+//! Action ids are the game crate's [`PlayerAction`] ids, so browser runs can
+//! replay the decisions of logic-mode runs.
+
+use crate::app::phase::{Phase, phase_for_state, session_from_state};
+use crate::game::boss::BossConfig;
+use crate::game::data::EncounterData;
+use crate::game::endgame::EndgameTravelCfg;
+use crate::game::journey::Event;
+use crate::game::state::{DayIntent, DietId, GameState, PaceId};
+use crate::game::{
+    ActionConfigs, ActionFollowUp, CampConfig, CrossingChoice, CrossingConfig, DayOutcome,
+    JourneySession, MechanicalPolicyId, OtDeluxe90sPolicy, OtDeluxeCrossingMethod,
+    OtDeluxeRouteDecision, OtDeluxeRoutePrompt, PlayerAction, can_afford_bribe, can_use_permit,
+    otdeluxe_crossing_options,
+};
+use serde::Serialize;
+use thiserror::Error;
+
+const PACES: [PaceId; 3] = [PaceId::Steady, PaceId::Heated, PaceId::Blitz];
+const DIETS: [DietId; 3] = [DietId::Mixed, DietId::Quiet, DietId::Doom];
+const OT_CROSSING_METHODS: [OtDeluxeCrossingMethod; 4] = [
+    OtDeluxeCrossingMethod::Ford,
+    OtDeluxeCrossingMethod::CaulkFloat,
+    OtDeluxeCrossingMethod::Ferry,
+    OtDeluxeCrossingMethod::Guide,
+];
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub(super) enum BridgeError {
+    #[error("no run in progress")]
+    NoSession,
+    #[error("unknown action `{0}`")]
+    UnknownAction(String),
+    #[error("action `{action}` is not available in phase `{phase}`")]
+    Unavailable { action: String, phase: &'static str },
+    #[error("invalid save: {0}")]
+    InvalidSave(String),
+}
+
+/// An action the current phase accepts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(super) struct BridgeAction {
+    pub id: String,
+    pub label: String,
+}
+
+impl BridgeAction {
+    fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+        }
+    }
+}
+
+/// Events recorded since a cursor; pass `cursor` back to continue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(super) struct EventPage {
+    pub cursor: usize,
+    pub events: Vec<Event>,
+}
+
+/// Kernel events from every day ticked through the bridge, in order.
+#[derive(Debug, Default)]
+pub(super) struct EventJournal {
+    events: Vec<Event>,
+}
+
+impl EventJournal {
+    pub(super) fn record(&mut self, outcome: &DayOutcome) {
+        self.events.extend(outcome.events.iter().cloned());
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.events.clear();
+    }
+
+    pub(super) fn since(&self, cursor: usize) -> EventPage {
+        let start = cursor.min(self.events.len());
+        EventPage {
+            cursor: self.events.len(),
+            events: self.events[start..].to_vec(),
+        }
+    }
+}
+
+/// Configs the protocol needs to resolve actions outside the daily tick.
+pub(super) struct BridgeConfigs<'a> {
+    pub crossing: &'a CrossingConfig,
+    pub camp: &'a CampConfig,
+    pub endgame: &'a EndgameTravelCfg,
+    pub boss: &'a BossConfig,
+}
+
+/// What a performed action changed; `outcome` is set when a day was ticked.
+pub(super) struct Performed {
+    pub session: JourneySession,
+    pub outcome: Option<DayOutcome>,
+    pub phase: Phase,
+}
+
+pub(super) const fn phase_label(phase: Phase) -> &'static str {
+    match phase {
+        Phase::Boot => "boot",
+        Phase::Menu => "menu",
+        Phase::About => "about",
+        Phase::Settings => "settings",
+        Phase::History => "history",
+        Phase::Persona => "persona",
+        Phase::ModeSelect => "mode-select",
+        Phase::Outfitting => "outfitting",
+        Phase::Travel => "travel",
+        Phase::Inventory => "inventory",
+        Phase::PaceDiet => "pace-diet",
+        Phase::Map => "map",
+        Phase::Store => "store",
+        Phase::Crossing => "crossing",
+        Phase::RoutePrompt => "route",
+        Phase::Camp => "camp",
+        Phase::Encounter => "encounter",
+        Phase::Boss => "boss",
+        Phase::Result => "result",
+    }
+}
+
+/// An action whose label is its id argument, e.g. `route:stay_on_trail` → "stay on trail".
+fn argument_action(action: &PlayerAction) -> BridgeAction {
+    let id = action.to_string();
+    let label = id
+        .split_once(':')
+        .map_or(id.as_str(), |(_, arg)| arg)
+        .replace('_', " ");
+    BridgeAction::new(id, label)
+}
+
+const fn route_decisions(prompt: OtDeluxeRoutePrompt) -> [OtDeluxeRouteDecision; 2] {
+    match prompt {
+        OtDeluxeRoutePrompt::SubletteCutoff => [
+            OtDeluxeRouteDecision::StayOnTrail,
+            OtDeluxeRouteDecision::SubletteCutoff,
+        ],
+        OtDeluxeRoutePrompt::DallesShortcut => [
+            OtDeluxeRouteDecision::StayOnTrail,
+            OtDeluxeRouteDecision::DallesShortcut,
+        ],
+        OtDeluxeRoutePrompt::DallesFinal => [
+            OtDeluxeRouteDecision::RaftColumbia,
+            OtDeluxeRouteDecision::BarlowRoad,
+        ],
+    }
+}
+
+fn otdeluxe_crossing_methods(gs: &GameState) -> Vec<OtDeluxeCrossingMethod> {
+    let (Some(river_kind), Some(river_state)) = (
+        gs.ot_deluxe.crossing.river_kind,
+        gs.ot_deluxe.crossing.river.as_ref(),
+    ) else {
+        return Vec::new();
+    };
+    let policy = OtDeluxe90sPolicy::default();
+    let options = otdeluxe_crossing_options(
+        &policy.crossings,
+        river_kind,
+        river_state,
+        &gs.ot_deluxe.inventory,
+    );
+    OT_CROSSING_METHODS
+        .into_iter()
+        .filter(|method| options.is_allowed(*method))
+        .collect()
+}
+
+fn dystrail_crossing_choices(gs: &GameState, cfg: &CrossingConfig) -> Vec<CrossingChoice> {
+    let Some(pending) = gs.pending_crossing else {
+        return Vec::new();
+    };
+    let mut choices = vec![CrossingChoice::Detour];
+    if can_afford_bribe(gs, cfg, pending.kind) {
+        choices.push(CrossingChoice::Bribe);
+    }
+    if can_use_permit(gs, &pending.kind) {
+        choices.push(CrossingChoice::Permit);
+    }
+    choices
+}
+
+/// Actions accepted by the phase `gs` is in.
+pub(super) fn available_actions(
+    gs: &GameState,
+    crossing_cfg: &CrossingConfig,
+) -> Vec<BridgeAction> {
+    let otdeluxe = gs.mechanical_policy == MechanicalPolicyId::OtDeluxe90s;
+    match phase_for_state(gs) {
+        Phase::RoutePrompt => gs
+            .ot_deluxe
+            .route
+            .pending_prompt
+            .map(route_decisions)
+            .into_iter()
+            .flatten()
+            .map(|decision| argument_action(&PlayerAction::Route(decision)))
+            .collect(),
+        Phase::Crossing if otdeluxe => otdeluxe_crossing_methods(gs)
+            .into_iter()
+            .map(|method| argument_action(&PlayerAction::OtCrossing(method)))
+            .collect(),
+        Phase::Crossing => dystrail_crossing_choices(gs, crossing_cfg)
+            .into_iter()
+            .map(|choice| argument_action(&PlayerAction::Crossing(choice)))
+            .collect(),
+        Phase::Store => vec![BridgeAction::new(
+            PlayerAction::StoreLeave.to_string(),
+            "leave store",
+        )],
+        Phase::Encounter => gs
+            .current_encounter
+            .iter()
+            .flat_map(|encounter| encounter.choices.iter().enumerate())
+            .map(|(idx, choice)| {
+                BridgeAction::new(PlayerAction::Encounter(idx).to_string(), &choice.label)
+            })
+            .collect(),
+        Phase::Boss => vec![BridgeAction::new(
+            PlayerAction::Boss.to_string(),
+            "face the filibuster",
+        )],
+        Phase::Travel => {
+            let mut actions = vec![BridgeAction::new(
+                PlayerAction::Travel.to_string(),
+                "travel one day",
+            )];
+            if otdeluxe {
+                actions.extend(
+                    [
+                        DayIntent::Trade,
+                        DayIntent::Hunt,
+                        DayIntent::Fish,
+                        DayIntent::Forage,
+                    ]
+                    .into_iter()
+                    .map(|intent| {
+                        let id = PlayerAction::Intent(intent).to_string();
+                        BridgeAction::new(id.clone(), id)
+                    }),
+                );
+            } else {
+                actions.push(BridgeAction::new(
+                    PlayerAction::CampRest.to_string(),
+                    "rest at camp",
+                ));
+                actions.push(BridgeAction::new(
+                    PlayerAction::CampForage.to_string(),
+                    "forage at camp",
+                ));
+            }
+            actions.extend(
+                PACES
+                    .into_iter()
+                    .filter(|pace| *pace != gs.pace)
+                    .map(|pace| {
+                        let action = argument_action(&PlayerAction::Pace(pace));
+                        let label = format!("set pace {}", action.label);
+                        BridgeAction::new(action.id, label)
+                    }),
+            );
+            actions.extend(
+                DIETS
+                    .into_iter()
+                    .filter(|diet| *diet != gs.diet)
+                    .map(|diet| {
+                        let action = argument_action(&PlayerAction::Diet(diet));
+                        let label = format!("set diet {}", action.label);
+                        BridgeAction::new(action.id, label)
+                    }),
+            );
+            actions
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Validate `action_id` against the current phase and apply it to a copy of `session`.
+pub(super) fn perform_action(
+    session: Option<&JourneySession>,
+    action_id: &str,
+    cfgs: &BridgeConfigs<'_>,
+) -> Result<Performed, BridgeError> {
+    let mut session = session.cloned().ok_or(BridgeError::NoSession)?;
+    let gs = session.state();
+    let otdeluxe = gs.mechanical_policy == MechanicalPolicyId::OtDeluxe90s;
+    let action = PlayerAction::parse(action_id.trim(), otdeluxe)
+        .ok_or_else(|| BridgeError::UnknownAction(action_id.to_string()))?;
+    let phase = phase_for_state(gs);
+    let listed = available_actions(gs, cfgs.crossing)
+        .iter()
+        .any(|action| action.id == action_id.trim());
+    let allowed = listed || (matches!(action, PlayerAction::StoreBuy(_)) && phase == Phase::Store);
+    if !allowed {
+        return Err(BridgeError::Unavailable {
+            action: action_id.to_string(),
+            phase: phase_label(phase),
+        });
+    }
+
+    let ends_run = action == PlayerAction::Boss;
+    let action_cfgs = ActionConfigs {
+        camp: cfgs.camp,
+        endgame: cfgs.endgame,
+        boss: cfgs.boss,
+    };
+    let follow_up = session
+        .with_state_mut(|gs| action.apply(gs, &action_cfgs))
+        .ok_or_else(|| BridgeError::Unavailable {
+            action: action_id.to_string(),
+            phase: phase_label(phase),
+        })?;
+    if ends_run {
+        return Ok(Performed {
+            session,
+            outcome: None,
+            phase: Phase::Result,
+        });
+    }
+    let outcome = (follow_up == ActionFollowUp::TickDay).then(|| session.tick_day());
+    let phase = phase_for_state(session.state());
+    Ok(Performed {
+        session,
+        outcome,
+        phase,
+    })
+}
+
+/// Parse and rehydrate a save exported by `save()`.
+pub(super) fn load_session(
+    json: &str,
+    data: EncounterData,
+    endgame: &EndgameTravelCfg,
+) -> Result<JourneySession, BridgeError> {
+    let gs: GameState =
+        serde_json::from_str(json).map_err(|err| BridgeError::InvalidSave(err.to_string()))?;
+    let gs = gs
+        .rehydrate(data)
+        .map_err(|err| BridgeError::InvalidSave(err.to_string()))?;
+    Ok(session_from_state(gs, endgame))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::data::{Choice, Effects, Encounter};
+    use crate::game::state::{GameMode, PendingCrossing};
+    use crate::game::{CrossingKind, StrategyId};
+
+    fn session() -> JourneySession {
+        JourneySession::new(
+            GameMode::Classic,
+            StrategyId::Balanced,
+            0xD157,
+            EncounterData::load_from_static(),
+            &EndgameTravelCfg::default_config(),
+        )
+    }
+
+    fn with_configs<T>(f: impl FnOnce(&BridgeConfigs<'_>) -> T) -> T {
+        let crossing = CrossingConfig::default();
+        let camp = CampConfig::default_config();
+        let endgame = EndgameTravelCfg::default_config();
+        let boss = BossConfig::load_from_static();
+        f(&BridgeConfigs {
+            crossing: &crossing,
+            camp: &camp,
+            endgame: &endgame,
+            boss: &boss,
+        })
+    }
+
+    fn ids(actions: &[BridgeAction]) -> Vec<&str> {
+        actions.iter().map(|action| action.id.as_str()).collect()
+    }
+
+    #[test]
+    fn travel_phase_lists_travel_camp_and_preferences() {
+        let sess = session();
+        let actions = available_actions(sess.state(), &CrossingConfig::default());
+        let ids = ids(&actions);
+        assert_eq!(ids[0], "travel");
+        assert!(ids.contains(&"camp:rest"));
+        assert!(ids.contains(&"pace:heated"));
+        assert!(!ids.contains(&"pace:steady"));
+        assert!(ids.contains(&"diet:doom"));
+        assert!(!ids.contains(&"hunt"));
+    }
+
+    #[test]
+    fn encounter_phase_lists_choices_by_index() {
+        let mut sess = session();
+        sess.with_state_mut(|gs| {
+            gs.current_encounter = Some(Encounter {
+                id: String::from("enc"),
+                name: String::from("Encounter"),
+                desc: String::new(),
+                weight: 1,
+                regions: Vec::new(),
+                modes: Vec::new(),
+                choices: vec![
+                    Choice {
+                        label: String::from("Left"),
+                        effects: Effects::default(),
+                    },
+                    Choice {
+                        label: String::from("Right"),
+                        effects: Effects::default(),
+                    },
+                ],
+                hard_stop: false,
+                major_repair: false,
+                chainable: false,
+            });
+        });
+        let actions = available_actions(sess.state(), &CrossingConfig::default());
+        assert_eq!(ids(&actions), vec!["encounter:0", "encounter:1"]);
+        assert_eq!(actions[1].label, "Right");
+
+        let performed = with_configs(|cfgs| perform_action(Some(&sess), "encounter:1", cfgs))
+            .expect("choice applies");
+        assert!(performed.outcome.is_none());
+        assert!(performed.session.state().current_encounter.is_none());
+        assert_eq!(performed.phase, Phase::Travel);
+    }
+
+    #[test]
+    fn crossing_phase_always_offers_detour() {
+        let mut sess = session();
+        sess.with_state_mut(|gs| {
+            gs.pending_crossing = Some(PendingCrossing {
+                kind: CrossingKind::Checkpoint,
+                computed_miles_today: 0.0,
+            });
+        });
+        let actions = available_actions(sess.state(), &CrossingConfig::default());
+        assert_eq!(actions[0].id, "crossing:detour");
+
+        let performed = with_configs(|cfgs| perform_action(Some(&sess), "crossing:detour", cfgs))
+            .expect("detour resolves");
+        assert!(performed.outcome.is_some());
+        assert!(performed.session.state().pending_crossing.is_none());
+    }
+
+    #[test]
+    fn travel_ticks_a_day_and_records_events() {
+        let sess = session();
+        let performed =
+            with_configs(|cfgs| perform_action(Some(&sess), "travel", cfgs)).expect("travel");
+        assert!(performed.session.state().day > sess.state().day);
+        let outcome = performed.outcome.expect("travel ticks a day");
+
+        let mut journal = EventJournal::default();
+        journal.record(&outcome);
+        let page = journal.since(0);
+        assert_eq!(page.events, outcome.events);
+        assert_eq!(page.cursor, outcome.events.len());
+        assert!(journal.since(page.cursor).events.is_empty());
+        assert!(journal.since(usize::MAX).events.is_empty());
+        journal.clear();
+        assert_eq!(journal.since(0).cursor, 0);
+    }
+
+    #[test]
+    fn preferences_update_without_ticking() {
+        let sess = session();
+        let performed =
+            with_configs(|cfgs| perform_action(Some(&sess), "pace:blitz", cfgs)).expect("pace");
+        assert!(performed.outcome.is_none());
+        assert_eq!(performed.session.state().pace, PaceId::Blitz);
+        assert_eq!(performed.session.state().day, sess.state().day);
+    }
+
+    #[test]
+    fn rejects_unknown_and_unavailable_actions() {
+        let sess = session();
+        with_configs(|cfgs| {
+            assert_eq!(
+                perform_action(Some(&sess), "dance", cfgs).err(),
+                Some(BridgeError::UnknownAction(String::from("dance")))
+            );
+            assert_eq!(
+                perform_action(Some(&sess), "boss", cfgs).err(),
+                Some(BridgeError::Unavailable {
+                    action: String::from("boss"),
+                    phase: "travel",
+                })
+            );
+            assert_eq!(
+                perform_action(None, "travel", cfgs).err(),
+                Some(BridgeError::NoSession)
+            );
+        });
+    }
+
+    #[test]
+    fn save_round_trips_through_load_session() {
+        let sess = session();
+        let json = serde_json::to_string(sess.state()).expect("serialize");
+        let loaded = load_session(
+            &json,
+            EncounterData::load_from_static(),
+            &EndgameTravelCfg::default_config(),
+        )
+        .expect("load");
+        assert_eq!(loaded.state().seed, sess.state().seed);
+        assert!(matches!(
+            load_session(
+                "{",
+                EncounterData::empty(),
+                &EndgameTravelCfg::default_config()
+            ),
+            Err(BridgeError::InvalidSave(_))
+        ));
+    }
+}
@@ -92,6 +92,8 @@ mod tests {
                 save_focus_target: use_state(|| AttrValue::from("save-open-btn")),
                 show_settings: use_state(|| false),
                 current_language: use_state(|| String::from("en")),
+                decision_traces: use_state(Vec::new),
+                show_decision_traces: use_state(|| false),
             };
 
             if !*invoked.borrow() {
@@ -167,6 +169,8 @@ mod tests {
                 save_focus_target: use_state(|| AttrValue::from("save-open-btn")),
                 show_settings: use_state(|| false),
                 current_language: use_state(|| String::from("en")),
+                decision_traces: use_state(Vec::new),
+                show_decision_traces: use_state(|| false),
             };
 
             if !*invoked.borrow() {
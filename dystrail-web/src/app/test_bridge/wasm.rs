@@ -1,17 +1,28 @@
+use super::protocol::{
+    BridgeConfigs, BridgeError, EventJournal, Performed, available_actions, load_session,
+    perform_action, phase_label,
+};
 use super::shared::seed_session_with_data;
-use crate::app::phase::Phase;
+use crate::app::phase::{Phase, phase_for_state};
 use crate::app::state::AppState;
+use crate::app::view::commit_outcome;
 use crate::dom;
 use crate::game::data::EncounterData;
 use crate::game::state::{CollapseCause, Ending};
 use crate::game::{
-    CrossingKind, Encounter, MechanicalPolicyId, OtDeluxeRoutePrompt, PendingCrossing,
+    CrossingKind, Encounter, MechanicalPolicyId, OtDeluxeRoutePrompt, PendingCrossing, state_hash,
 };
 use serde::Serialize;
+use std::cell::RefCell;
+use std::rc::Rc;
 use wasm_bindgen::JsCast;
 use wasm_bindgen::prelude::*;
 use yew::prelude::*;
 
+type LatestState = Rc<RefCell<AppState>>;
+/// Set by `screen()` and cleared once the app has rendered the change.
+type RenderPending = Rc<RefCell<bool>>;
+
 #[derive(Serialize)]
 struct BridgeGameState {
     screen: Option<String>,
@@ -20,6 +31,32 @@ struct BridgeGameState {
     pos: Option<serde_json::Value>,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BridgeReport {
+    ok: bool,
+    phase: &'static str,
+    day: Option<u32>,
+    ended: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl BridgeReport {
+    fn failed(phase: Phase, err: &BridgeError) -> Self {
+        Self {
+            ok: false,
+            phase: phase_label(phase),
+            day: None,
+            ended: false,
+            state_hash: None,
+            error: Some(err.to_string()),
+        }
+    }
+}
+
 struct BridgeBindings {
     _seed: Closure<dyn FnMut(JsValue)>,
     _speed: Closure<dyn FnMut(JsValue)>,
@@ -27,6 +64,14 @@ struct BridgeBindings {
     _key: Closure<dyn FnMut(JsValue)>,
     _state: Closure<dyn FnMut() -> JsValue>,
     _screen: Closure<dyn FnMut(JsValue)>,
+    _ready: Closure<dyn FnMut() -> JsValue>,
+    _game_state: Closure<dyn FnMut() -> JsValue>,
+    _phase: Closure<dyn FnMut() -> JsValue>,
+    _actions: Closure<dyn FnMut() -> JsValue>,
+    _perform: Closure<dyn FnMut(JsValue) -> JsValue>,
+    _save: Closure<dyn FnMut() -> JsValue>,
+    _load: Closure<dyn FnMut(JsValue) -> JsValue>,
+    _events: Closure<dyn FnMut(JsValue) -> JsValue>,
 }
 
 impl BridgeBindings {
@@ -38,6 +83,14 @@ impl BridgeBindings {
             &self._key,
             &self._state,
             &self._screen,
+            &self._ready,
+            &self._game_state,
+            &self._phase,
+            &self._actions,
+            &self._perform,
+            &self._save,
+            &self._load,
+            &self._events,
         );
     }
 }
@@ -49,29 +102,6 @@ fn test_mode_enabled() -> bool {
         .unwrap_or(false)
 }
 
-fn screen_label(phase: Phase) -> &'static str {
-    match phase {
-        Phase::Boot => "boot",
-        Phase::Menu => "menu",
-        Phase::About => "about",
-        Phase::Settings => "settings",
-        Phase::Persona => "persona",
-        Phase::ModeSelect => "mode-select",
-        Phase::Outfitting => "outfitting",
-        Phase::Travel => "travel",
-        Phase::Inventory => "inventory",
-        Phase::PaceDiet => "pace-diet",
-        Phase::Map => "map",
-        Phase::Store => "store",
-        Phase::Crossing => "crossing",
-        Phase::RoutePrompt => "route",
-        Phase::Camp => "camp",
-        Phase::Encounter => "encounter",
-        Phase::Boss => "boss",
-        Phase::Result => "result",
-    }
-}
-
 fn ensure_data_loaded(state: &AppState) -> EncounterData {
     if state.data.encounters.is_empty() {
         let loaded = crate::game::load_encounter_data().unwrap_or_else(|_| EncounterData::empty());
@@ -139,6 +169,10 @@ fn set_screen(state: &AppState, raw: &str) {
             clear_run_state(state);
             state.phase.set(Phase::Settings);
         }
+        "history" => {
+            clear_run_state(state);
+            state.phase.set(Phase::History);
+        }
         "persona" => {
             clear_run_state(state);
             state.phase.set(Phase::Persona);
@@ -286,9 +320,58 @@ fn set_result_state(state: &AppState, ending: Ending) {
     state.phase.set(Phase::Result);
 }
 
-fn build_bridge(state: &AppState) -> BridgeBindings {
-    let seed_state = state.clone();
+fn to_js<T: Serialize>(value: &T) -> JsValue {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .unwrap_or(JsValue::NULL)
+}
+
+fn apply_performed(
+    state: &AppState,
+    performed: Performed,
+    journal: &RefCell<EventJournal>,
+) -> BridgeReport {
+    let Performed {
+        session,
+        outcome,
+        phase,
+    } = performed;
+    let day = session.state().day;
+    let hash = state_hash(session.state());
+    let ended = outcome.as_ref().is_some_and(|outcome| outcome.ended) || phase == Phase::Result;
+    state.pending_state.set(Some(session.state().clone()));
+    if let Some(outcome) = outcome {
+        journal.borrow_mut().record(&outcome);
+        commit_outcome(
+            session,
+            &outcome,
+            &state.logs,
+            &state.decision_traces,
+            &state.phase,
+            &state.session,
+        );
+    } else {
+        state.session.set(Some(session));
+        state.phase.set(phase);
+    }
+    BridgeReport {
+        ok: true,
+        phase: phase_label(phase),
+        day: Some(day),
+        ended,
+        state_hash: Some(hash),
+        error: None,
+    }
+}
+
+fn build_bridge(
+    latest: &LatestState,
+    journal: &Rc<RefCell<EventJournal>>,
+    render_pending: &RenderPending,
+) -> BridgeBindings {
+    let seed_latest = Rc::clone(latest);
     let seed = Closure::wrap(Box::new(move |value: JsValue| {
+        let seed_state = seed_latest.borrow().clone();
         if let Some(seed) = value.as_f64().map(|v| v as u64) {
             seed_state.run_seed.set(seed);
             seed_state
@@ -299,8 +382,9 @@ fn build_bridge(state: &AppState) -> BridgeBindings {
 
     let speed = Closure::wrap(Box::new(move |_value: JsValue| {}) as Box<dyn FnMut(JsValue)>);
 
-    let click_state = state.clone();
+    let click_latest = Rc::clone(latest);
     let click = Closure::wrap(Box::new(move |_x: JsValue, _y: JsValue| {
+        let click_state = click_latest.borrow().clone();
         let phase = *click_state.phase;
         match phase {
             Phase::Boot => {
@@ -332,14 +416,15 @@ fn build_bridge(state: &AppState) -> BridgeBindings {
             Phase::Result => {
                 click_state.phase.set(Phase::Menu);
             }
-            Phase::About | Phase::Settings => {
+            Phase::About | Phase::Settings | Phase::History => {
                 click_state.phase.set(Phase::Menu);
             }
         }
     }) as Box<dyn FnMut(JsValue, JsValue)>);
 
-    let key_state = state.clone();
+    let key_latest = Rc::clone(latest);
     let key = Closure::wrap(Box::new(move |value: JsValue| {
+        let key_state = key_latest.borrow().clone();
         if let Some(keys) = value.as_string() {
             let handlers = crate::app::view::AppHandlers::new(&key_state, None);
             for _ in keys.chars() {
@@ -350,10 +435,11 @@ fn build_bridge(state: &AppState) -> BridgeBindings {
         }
     }) as Box<dyn FnMut(JsValue)>);
 
-    let state_state = state.clone();
+    let state_latest = Rc::clone(latest);
     let state_fn = Closure::wrap(Box::new(move || {
+        let state_state = state_latest.borrow().clone();
         let phase = *state_state.phase;
-        let screen = Some(screen_label(phase).to_string());
+        let screen = Some(phase_label(phase).to_string());
         let snapshot = (*state_state.session)
             .clone()
             .map(|sess| sess.state().clone())
@@ -373,13 +459,109 @@ fn build_bridge(state: &AppState) -> BridgeBindings {
         .unwrap_or(JsValue::NULL)
     }) as Box<dyn FnMut() -> JsValue>);
 
-    let screen_state = state.clone();
+    let screen_latest = Rc::clone(latest);
+    let screen_pending = Rc::clone(render_pending);
     let screen = Closure::wrap(Box::new(move |value: JsValue| {
+        let screen_state = screen_latest.borrow().clone();
         if let Some(screen) = value.as_string() {
+            *screen_pending.borrow_mut() = true;
             set_screen(&screen_state, &screen);
         }
     }) as Box<dyn FnMut(JsValue)>);
 
+    let ready_pending = Rc::clone(render_pending);
+    let ready = Closure::wrap(
+        Box::new(move || JsValue::from_bool(!*ready_pending.borrow()))
+            as Box<dyn FnMut() -> JsValue>,
+    );
+
+    let game_state_latest = Rc::clone(latest);
+    let game_state = Closure::wrap(Box::new(move || {
+        let state = game_state_latest.borrow().clone();
+        (*state.session)
+            .as_ref()
+            .and_then(|sess| serde_json::to_string(sess.state()).ok())
+            .map_or(JsValue::NULL, |json| JsValue::from_str(&json))
+    }) as Box<dyn FnMut() -> JsValue>);
+
+    let phase_latest = Rc::clone(latest);
+    let phase = Closure::wrap(Box::new(move || {
+        let state = phase_latest.borrow().clone();
+        JsValue::from_str(phase_label(*state.phase))
+    }) as Box<dyn FnMut() -> JsValue>);
+
+    let actions_latest = Rc::clone(latest);
+    let actions = Closure::wrap(Box::new(move || {
+        let state = actions_latest.borrow().clone();
+        let actions = (*state.session)
+            .as_ref()
+            .map(|sess| available_actions(sess.state(), &state.crossing_config))
+            .unwrap_or_default();
+        to_js(&actions)
+    }) as Box<dyn FnMut() -> JsValue>);
+
+    let perform_latest = Rc::clone(latest);
+    let perform_journal = Rc::clone(journal);
+    let perform = Closure::wrap(Box::new(move |value: JsValue| {
+        let state = perform_latest.borrow().clone();
+        let action_id = value.as_string().unwrap_or_default();
+        let cfgs = BridgeConfigs {
+            crossing: &state.crossing_config,
+            camp: &state.camp_config,
+            endgame: &state.endgame_config,
+            boss: &state.boss_config,
+        };
+        let report = match perform_action((*state.session).as_ref(), &action_id, &cfgs) {
+            Ok(performed) => apply_performed(&state, performed, &perform_journal),
+            Err(err) => BridgeReport::failed(*state.phase, &err),
+        };
+        to_js(&report)
+    }) as Box<dyn FnMut(JsValue) -> JsValue>);
+
+    let save_latest = Rc::clone(latest);
+    let save = Closure::wrap(Box::new(move || {
+        let state = save_latest.borrow().clone();
+        (*state.session)
+            .as_ref()
+            .and_then(|sess| serde_json::to_string(sess.state()).ok())
+            .map_or(JsValue::NULL, |json| JsValue::from_str(&json))
+    }) as Box<dyn FnMut() -> JsValue>);
+
+    let load_latest = Rc::clone(latest);
+    let load_journal = Rc::clone(journal);
+    let load = Closure::wrap(Box::new(move |value: JsValue| {
+        let state = load_latest.borrow().clone();
+        let json = value.as_string().unwrap_or_default();
+        let data = ensure_data_loaded(&state);
+        let report = match load_session(&json, data, &state.endgame_config) {
+            Ok(sess) => {
+                let phase = phase_for_state(sess.state());
+                let report = BridgeReport {
+                    ok: true,
+                    phase: phase_label(phase),
+                    day: Some(sess.state().day),
+                    ended: phase == Phase::Result,
+                    state_hash: Some(state_hash(sess.state())),
+                    error: None,
+                };
+                load_journal.borrow_mut().clear();
+                state.run_seed.set(sess.state().seed);
+                state.pending_state.set(Some(sess.state().clone()));
+                state.session.set(Some(sess));
+                state.phase.set(phase);
+                report
+            }
+            Err(err) => BridgeReport::failed(*state.phase, &err),
+        };
+        to_js(&report)
+    }) as Box<dyn FnMut(JsValue) -> JsValue>);
+
+    let events_journal = Rc::clone(journal);
+    let events = Closure::wrap(Box::new(move |value: JsValue| {
+        let cursor = value.as_f64().map_or(0, |v| v as usize);
+        to_js(&events_journal.borrow().since(cursor))
+    }) as Box<dyn FnMut(JsValue) -> JsValue>);
+
     BridgeBindings {
         _seed: seed,
         _speed: speed,
@@ -387,6 +569,14 @@ fn build_bridge(state: &AppState) -> BridgeBindings {
         _key: key,
         _state: state_fn,
         _screen: screen,
+        _ready: ready,
+        _game_state: game_state,
+        _phase: phase,
+        _actions: actions,
+        _perform: perform,
+        _save: save,
+        _load: load,
+        _events: events,
     }
 }
 
@@ -395,36 +585,25 @@ fn attach_bridge(bindings: &BridgeBindings) {
         return;
     };
     let bridge = js_sys::Object::new();
-    let _ = js_sys::Reflect::set(
-        &bridge,
-        &JsValue::from_str("seed"),
-        bindings._seed.as_ref().unchecked_ref(),
-    );
-    let _ = js_sys::Reflect::set(
-        &bridge,
-        &JsValue::from_str("speed"),
-        bindings._speed.as_ref().unchecked_ref(),
-    );
-    let _ = js_sys::Reflect::set(
-        &bridge,
-        &JsValue::from_str("click"),
-        bindings._click.as_ref().unchecked_ref(),
-    );
-    let _ = js_sys::Reflect::set(
-        &bridge,
-        &JsValue::from_str("key"),
-        bindings._key.as_ref().unchecked_ref(),
-    );
-    let _ = js_sys::Reflect::set(
-        &bridge,
-        &JsValue::from_str("state"),
-        bindings._state.as_ref().unchecked_ref(),
-    );
-    let _ = js_sys::Reflect::set(
-        &bridge,
-        &JsValue::from_str("screen"),
-        bindings._screen.as_ref().unchecked_ref(),
-    );
+    let entries: [(&str, &JsValue); 14] = [
+        ("seed", bindings._seed.as_ref()),
+        ("speed", bindings._speed.as_ref()),
+        ("click", bindings._click.as_ref()),
+        ("key", bindings._key.as_ref()),
+        ("state", bindings._state.as_ref()),
+        ("screen", bindings._screen.as_ref()),
+        ("ready", bindings._ready.as_ref()),
+        ("gameState", bindings._game_state.as_ref()),
+        ("phase", bindings._phase.as_ref()),
+        ("actions", bindings._actions.as_ref()),
+        ("perform", bindings._perform.as_ref()),
+        ("save", bindings._save.as_ref()),
+        ("load", bindings._load.as_ref()),
+        ("events", bindings._events.as_ref()),
+    ];
+    for (name, function) in entries {
+        let _ = js_sys::Reflect::set(&bridge, &JsValue::from_str(name), function.unchecked_ref());
+    }
     let _ = js_sys::Reflect::set(&window, &JsValue::from_str("__dystrailTest"), &bridge);
 }
 
@@ -432,7 +611,17 @@ fn attach_bridge(bindings: &BridgeBindings) {
 pub fn use_test_bridge(app_state: &AppState) {
     let bridge_handle = use_mut_ref(|| None::<BridgeBindings>);
     let installed = use_mut_ref(|| false);
-    let state = app_state.clone();
+    let journal = use_mut_ref(EventJournal::default);
+    // Handles captured at install time would keep reading the first render's values.
+    let latest = use_mut_ref(|| app_state.clone());
+    *latest.borrow_mut() = app_state.clone();
+    let render_pending = use_mut_ref(|| false);
+
+    // Effects run once a render is in the DOM, which is what `ready()` reports.
+    let rendered = Rc::clone(&render_pending);
+    use_effect(move || {
+        *rendered.borrow_mut() = false;
+    });
 
     use_effect_with((), move |()| {
         let cleanup = || {};
@@ -441,7 +630,7 @@ pub fn use_test_bridge(app_state: &AppState) {
         }
         *installed.borrow_mut() = true;
         if test_mode_enabled() {
-            let bindings = build_bridge(&state);
+            let bindings = build_bridge(&latest, &journal, &render_pending);
             attach_bridge(&bindings);
             bindings.keep();
             *bridge_handle.borrow_mut() = Some(bindings);
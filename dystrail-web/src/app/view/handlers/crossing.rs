@@ -10,6 +10,7 @@ use yew::prelude::*;
 pub fn build_crossing_choice(state: &AppState) -> Callback<u8> {
     let session_handle = state.session.clone();
     let logs = state.logs.clone();
+    let traces = state.decision_traces.clone();
     let phase = state.phase.clone();
     let crossing_config = state.crossing_config.clone();
 
@@ -45,13 +46,14 @@ pub fn build_crossing_choice(state: &AppState) -> Callback<u8> {
 
         sess.with_state_mut(|gs| gs.set_crossing_choice(choice));
         let outcome = sess.tick_day();
-        commit_outcome(sess, &outcome, &logs, &phase, &session_handle);
+        commit_outcome(sess, &outcome, &logs, &traces, &phase, &session_handle);
     })
 }
 
 pub fn build_otdeluxe_crossing_choice(state: &AppState) -> Callback<u8> {
     let session_handle = state.session.clone();
     let logs = state.logs.clone();
+    let traces = state.decision_traces.clone();
     let phase = state.phase.clone();
 
     Callback::from(move |idx: u8| {
@@ -93,6 +95,6 @@ pub fn build_otdeluxe_crossing_choice(state: &AppState) -> Callback<u8> {
 
         sess.with_state_mut(|gs| gs.set_otdeluxe_crossing_choice(method));
         let outcome = sess.tick_day();
-        commit_outcome(sess, &outcome, &logs, &phase, &session_handle);
+        commit_outcome(sess, &outcome, &logs, &traces, &phase, &session_handle);
     })
 }
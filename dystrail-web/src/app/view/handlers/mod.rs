@@ -14,15 +14,18 @@ use yew_router::prelude::Navigator;
 
 pub use boss::build_boss;
 pub use crossing::{build_crossing_choice, build_otdeluxe_crossing_choice};
+#[cfg(target_arch = "wasm32")]
+pub(crate) use outcome::commit_outcome;
 pub use prefs::{
-    build_begin_boot, build_go_home, build_lang_change, build_settings_hc_change, build_toggle_hc,
+    build_begin_boot, build_go_home, build_lang_change, build_settings_hc_change,
+    build_settings_traces_change, build_toggle_hc,
 };
 pub use route_prompt::build_route_prompt_choice;
 pub use storage::{build_export_state, build_import_state, build_load, build_save};
 pub use store::{build_store_leave, build_store_purchase};
 pub use travel::{
-    build_diet_change, build_encounter_choice, build_hunt, build_pace_change, build_trade,
-    build_travel,
+    build_diet_change, build_encounter_choice, build_epitaph, build_fish, build_forage, build_hunt,
+    build_pace_change, build_trade, build_travel,
 };
 
 #[derive(Clone)]
@@ -30,11 +33,14 @@ pub struct AppHandlers {
     pub travel: Callback<()>,
     pub trade: Callback<()>,
     pub hunt: Callback<()>,
+    pub fish: Callback<()>,
+    pub forage: Callback<()>,
     pub store_purchase: Callback<Vec<crate::game::OtDeluxeStoreLineItem>>,
     pub store_leave: Callback<()>,
     pub pace_change: Callback<PaceId>,
     pub diet_change: Callback<DietId>,
     pub encounter_choice: Callback<usize>,
+    pub epitaph: Callback<(usize, String)>,
     pub crossing_choice: Callback<u8>,
     pub otdeluxe_crossing_choice: Callback<u8>,
     pub route_prompt_choice: Callback<crate::game::OtDeluxeRouteDecision>,
@@ -46,6 +52,7 @@ pub struct AppHandlers {
     pub lang_change: Callback<String>,
     pub toggle_hc: Callback<bool>,
     pub settings_hc_change: Callback<bool>,
+    pub settings_traces_change: Callback<bool>,
     pub go_home: Callback<()>,
     pub begin_boot: Callback<()>,
 }
@@ -57,11 +64,14 @@ impl AppHandlers {
             travel: build_travel(state),
             trade: build_trade(state),
             hunt: build_hunt(state),
+            fish: build_fish(state),
+            forage: build_forage(state),
             store_purchase: build_store_purchase(state),
             store_leave: build_store_leave(state),
             pace_change: build_pace_change(state),
             diet_change: build_diet_change(state),
             encounter_choice: build_encounter_choice(state),
+            epitaph: build_epitaph(state),
             crossing_choice: build_crossing_choice(state),
             otdeluxe_crossing_choice: build_otdeluxe_crossing_choice(state),
             route_prompt_choice: build_route_prompt_choice(state),
@@ -73,6 +83,7 @@ impl AppHandlers {
             lang_change: build_lang_change(state),
             toggle_hc: build_toggle_hc(state),
             settings_hc_change: build_settings_hc_change(state),
+            settings_traces_change: build_settings_traces_change(state),
             go_home: build_go_home(state, navigator),
             begin_boot: build_begin_boot(state),
         }
@@ -155,6 +166,8 @@ mod tests {
             save_focus_target: use_state(|| AttrValue::from("save-open-btn")),
             show_settings: use_state(|| false),
             current_language: use_state(|| String::from("en")),
+            decision_traces: use_state(Vec::new),
+            show_decision_traces: use_state(|| false),
         }
     }
 
@@ -182,6 +195,7 @@ mod tests {
             handlers.lang_change.emit(String::from("es"));
             handlers.toggle_hc.emit(true);
             handlers.settings_hc_change.emit(false);
+            handlers.settings_traces_change.emit(true);
             handlers
                 .pace_change
                 .emit(crate::game::state::PaceId::Steady);
@@ -232,6 +246,9 @@ mod tests {
             invoked.set(true);
             handlers.trade.emit(());
             handlers.hunt.emit(());
+            handlers.fish.emit(());
+            handlers.forage.emit(());
+            handlers.epitaph.emit((0, String::from("Rest easy")));
             handlers.store_purchase.emit(Vec::new());
             handlers.store_leave.emit(());
             handlers.otdeluxe_crossing_choice.emit(1);
@@ -256,6 +273,8 @@ mod tests {
             handlers.travel.emit(());
             handlers.trade.emit(());
             handlers.hunt.emit(());
+            handlers.fish.emit(());
+            handlers.forage.emit(());
             handlers.crossing_choice.emit(9);
             handlers.otdeluxe_crossing_choice.emit(9);
             handlers.store_purchase.emit(Vec::new());
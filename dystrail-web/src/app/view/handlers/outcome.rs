@@ -1,4 +1,5 @@
 use crate::app::phase::{Phase, phase_for_state};
+use crate::game::journey::EventDecisionTrace;
 use crate::game::{DayOutcome, JourneySession};
 use std::ops::Deref;
 use yew::prelude::*;
@@ -7,6 +8,7 @@ pub fn commit_outcome(
     sess: JourneySession,
     outcome: &DayOutcome,
     logs: &UseStateHandle<Vec<String>>,
+    traces: &UseStateHandle<Vec<EventDecisionTrace>>,
     phase: &UseStateHandle<Phase>,
     session_handle: &UseStateHandle<Option<JourneySession>>,
 ) {
@@ -14,6 +16,7 @@ pub fn commit_outcome(
     lg.extend(outcome_log_entries(outcome));
     phase.set(phase_for_state(sess.state()));
     logs.set(lg);
+    traces.set(outcome.decision_traces.clone());
     session_handle.set(Some(sess));
 }
 
@@ -35,7 +38,9 @@ mod tests {
     use super::{commit_outcome, outcome_log_entries};
     use crate::app::phase::Phase;
     use crate::game::journey::event::{Event, EventId};
-    use crate::game::journey::{DayEffects, DayInputs, DayOutcome, MechanicalPolicyId};
+    use crate::game::journey::{
+        DayEffects, DayInputs, DayOutcome, EventDecisionTrace, MechanicalPolicyId,
+    };
     use crate::game::state::{DayIntent, DietId, GameMode, PaceId, Region, Season};
     use crate::game::weather::Weather;
     use crate::game::{EndgameTravelCfg, JourneySession, StrategyId};
@@ -97,13 +102,21 @@ mod tests {
         crate::i18n::set_lang("en");
         let invoked = use_state(|| false);
         let logs = use_state(Vec::<String>::new);
+        let traces = use_state(Vec::<EventDecisionTrace>::new);
         let phase = use_state(|| Phase::Menu);
         let session_handle = use_state(|| None::<JourneySession>);
 
         if !*invoked {
             invoked.set(true);
             let sess = base_session();
-            commit_outcome(sess, &props.outcome, &logs, &phase, &session_handle);
+            commit_outcome(
+                sess,
+                &props.outcome,
+                &logs,
+                &traces,
+                &phase,
+                &session_handle,
+            );
         }
 
         let log_text = outcome_log_entries(&props.outcome).join("|");
@@ -27,11 +27,19 @@ pub fn build_settings_hc_change(state: &AppState) -> Callback<bool> {
     })
 }
 
+pub fn build_settings_traces_change(state: &AppState) -> Callback<bool> {
+    let show_decision_traces = state.show_decision_traces.clone();
+    Callback::from(move |next: bool| {
+        show_decision_traces.set(next);
+    })
+}
+
 pub fn build_go_home(state: &AppState, navigator: Option<Navigator>) -> Callback<()> {
     let phase = state.phase.clone();
     let session = state.session.clone();
     let pending = state.pending_state.clone();
     let logs = state.logs.clone();
+    let traces = state.decision_traces.clone();
     let run_seed = state.run_seed.clone();
     let code = state.code.clone();
     let show_save = state.show_save.clone();
@@ -41,6 +49,7 @@ pub fn build_go_home(state: &AppState, navigator: Option<Navigator>) -> Callback
         session.set(None);
         pending.set(None);
         logs.set(Vec::new());
+        traces.set(Vec::new());
         run_seed.set(0);
         code.set(AttrValue::from(""));
         show_save.set(false);
@@ -55,6 +64,7 @@ pub fn build_begin_boot(state: &AppState) -> Callback<()> {
     let session = state.session.clone();
     let pending = state.pending_state.clone();
     let logs = state.logs.clone();
+    let traces = state.decision_traces.clone();
     let run_seed = state.run_seed.clone();
     let code = state.code.clone();
     let show_save = state.show_save.clone();
@@ -65,6 +75,7 @@ pub fn build_begin_boot(state: &AppState) -> Callback<()> {
             session.set(None);
             pending.set(None);
             logs.set(Vec::new());
+            traces.set(Vec::new());
             run_seed.set(0);
             code.set(AttrValue::from(""));
             show_save.set(false);
@@ -6,6 +6,7 @@ use yew::prelude::*;
 pub fn build_route_prompt_choice(state: &AppState) -> Callback<OtDeluxeRouteDecision> {
     let session_handle = state.session.clone();
     let logs = state.logs.clone();
+    let traces = state.decision_traces.clone();
     let phase = state.phase.clone();
 
     Callback::from(move |decision: OtDeluxeRouteDecision| {
@@ -21,6 +22,6 @@ pub fn build_route_prompt_choice(state: &AppState) -> Callback<OtDeluxeRouteDeci
 
         sess.with_state_mut(|gs| gs.set_route_prompt_choice(decision));
         let outcome = sess.tick_day();
-        commit_outcome(sess, &outcome, &logs, &phase, &session_handle);
+        commit_outcome(sess, &outcome, &logs, &traces, &phase, &session_handle);
     })
 }
@@ -1,5 +1,7 @@
+use crate::app::graveyard::load_trail_graves;
 use crate::app::phase::{phase_for_state, session_from_state};
 use crate::app::state::AppState;
+use crate::game::WebGameStorage;
 use crate::game::state::GameState;
 use yew::prelude::*;
 
@@ -39,6 +41,7 @@ where
         if let Some(mut gs) = load_fn() {
             if let Ok(rehydrated) = gs.rehydrate((*data_handle).clone()) {
                 gs = rehydrated;
+                load_trail_graves(&WebGameStorage, &mut gs);
                 let sess = session_from_state(gs, &endgame_cfg);
                 let next_phase = phase_for_state(sess.state());
                 run_seed_handle.set(sess.state().seed);
@@ -93,6 +96,7 @@ pub fn build_import_state(state: &AppState) -> Callback<String> {
                 return;
             };
             gs = rehydrated;
+            load_trail_graves(&WebGameStorage, &mut gs);
             let sess = session_from_state(gs, &endgame_cfg);
             let next_phase = phase_for_state(sess.state());
             run_seed_handle.set(sess.state().seed);
@@ -147,6 +151,8 @@ mod tests {
             save_focus_target: use_state(|| AttrValue::from("save-open-btn")),
             show_settings: use_state(|| false),
             current_language: use_state(|| String::from("en")),
+            decision_traces: use_state(Vec::new),
+            show_decision_traces: use_state(|| false),
         };
         (invoked, state, data)
     }
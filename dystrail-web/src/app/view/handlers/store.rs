@@ -6,6 +6,7 @@ use yew::prelude::*;
 pub fn build_store_purchase(state: &AppState) -> Callback<Vec<OtDeluxeStoreLineItem>> {
     let session_handle = state.session.clone();
     let logs = state.logs.clone();
+    let traces = state.decision_traces.clone();
     let phase = state.phase.clone();
     Callback::from(move |lines: Vec<OtDeluxeStoreLineItem>| {
         let Some(mut sess) = (*session_handle).clone() else {
@@ -19,13 +20,14 @@ pub fn build_store_purchase(state: &AppState) -> Callback<Vec<OtDeluxeStoreLineI
             return;
         }
         let outcome = sess.tick_day();
-        commit_outcome(sess, &outcome, &logs, &phase, &session_handle);
+        commit_outcome(sess, &outcome, &logs, &traces, &phase, &session_handle);
     })
 }
 
 pub fn build_store_leave(state: &AppState) -> Callback<()> {
     let session_handle = state.session.clone();
     let logs = state.logs.clone();
+    let traces = state.decision_traces.clone();
     let phase = state.phase.clone();
     Callback::from(move |()| {
         let Some(mut sess) = (*session_handle).clone() else {
@@ -39,6 +41,6 @@ pub fn build_store_leave(state: &AppState) -> Callback<()> {
             return;
         }
         let outcome = sess.tick_day();
-        commit_outcome(sess, &outcome, &logs, &phase, &session_handle);
+        commit_outcome(sess, &outcome, &logs, &traces, &phase, &session_handle);
     })
 }
@@ -8,36 +8,37 @@ use yew::prelude::*;
 pub fn build_travel(state: &AppState) -> Callback<()> {
     let session_handle = state.session.clone();
     let logs = state.logs.clone();
+    let traces = state.decision_traces.clone();
     let phase = state.phase.clone();
     Callback::from(move |()| {
         let Some(mut sess) = (*session_handle).clone() else {
             return;
         };
         let outcome = sess.tick_day();
-        commit_outcome(sess, &outcome, &logs, &phase, &session_handle);
+        commit_outcome(sess, &outcome, &logs, &traces, &phase, &session_handle);
     })
 }
 
 pub fn build_trade(state: &AppState) -> Callback<()> {
-    let session_handle = state.session.clone();
-    let logs = state.logs.clone();
-    let phase = state.phase.clone();
-    Callback::from(move |()| {
-        let Some(mut sess) = (*session_handle).clone() else {
-            return;
-        };
-        if sess.state().mechanical_policy != MechanicalPolicyId::OtDeluxe90s {
-            return;
-        }
-        sess.with_state_mut(|gs| gs.intent.pending = DayIntent::Trade);
-        let outcome = sess.tick_day();
-        commit_outcome(sess, &outcome, &logs, &phase, &session_handle);
-    })
+    build_otdeluxe_intent(state, DayIntent::Trade)
 }
 
 pub fn build_hunt(state: &AppState) -> Callback<()> {
+    build_otdeluxe_intent(state, DayIntent::Hunt)
+}
+
+pub fn build_fish(state: &AppState) -> Callback<()> {
+    build_otdeluxe_intent(state, DayIntent::Fish)
+}
+
+pub fn build_forage(state: &AppState) -> Callback<()> {
+    build_otdeluxe_intent(state, DayIntent::Forage)
+}
+
+fn build_otdeluxe_intent(state: &AppState, intent: DayIntent) -> Callback<()> {
     let session_handle = state.session.clone();
     let logs = state.logs.clone();
+    let traces = state.decision_traces.clone();
     let phase = state.phase.clone();
     Callback::from(move |()| {
         let Some(mut sess) = (*session_handle).clone() else {
@@ -46,9 +47,9 @@ pub fn build_hunt(state: &AppState) -> Callback<()> {
         if sess.state().mechanical_policy != MechanicalPolicyId::OtDeluxe90s {
             return;
         }
-        sess.with_state_mut(|gs| gs.intent.pending = DayIntent::Hunt);
+        sess.with_state_mut(|gs| gs.intent.pending = intent);
         let outcome = sess.tick_day();
-        commit_outcome(sess, &outcome, &logs, &phase, &session_handle);
+        commit_outcome(sess, &outcome, &logs, &traces, &phase, &session_handle);
     })
 }
 
@@ -72,6 +73,16 @@ pub fn build_diet_change(state: &AppState) -> Callback<DietId> {
     })
 }
 
+pub fn build_epitaph(state: &AppState) -> Callback<(usize, String)> {
+    let session_handle = state.session.clone();
+    Callback::from(move |(member_index, epitaph): (usize, String)| {
+        if let Some(mut sess) = (*session_handle).clone() {
+            sess.with_state_mut(|gs| gs.ot_deluxe.set_epitaph(member_index, &epitaph));
+            session_handle.set(Some(sess));
+        }
+    })
+}
+
 pub fn build_encounter_choice(state: &AppState) -> Callback<usize> {
     let session_handle = state.session.clone();
     let phase_handle = state.phase.clone();
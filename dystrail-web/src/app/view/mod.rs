@@ -2,6 +2,8 @@ mod handlers;
 mod phases;
 
 pub use handlers::AppHandlers;
+#[cfg(target_arch = "wasm32")]
+pub(crate) use handlers::commit_outcome;
 pub use phases::render_crossing;
 
 use crate::app::Phase;
@@ -25,7 +27,7 @@ fn build_open_save(
 const fn is_meta_phase(phase: Phase) -> bool {
     matches!(
         phase,
-        Phase::Boot | Phase::Menu | Phase::About | Phase::Settings
+        Phase::Boot | Phase::Menu | Phase::About | Phase::Settings | Phase::History
     )
 }
 
@@ -74,6 +76,7 @@ fn render_drawers(
                 open={*state.show_settings}
                 on_close={on_close_settings}
                 on_hc_changed={handlers.settings_hc_change.clone()}
+                on_traces_changed={handlers.settings_traces_change.clone()}
             />
         </>
     }
@@ -226,6 +229,8 @@ mod tests {
             save_focus_target: use_state(|| AttrValue::from("save-open-btn")),
             show_settings: use_state(|| false),
             current_language: use_state(|| String::from("en")),
+            decision_traces: use_state(Vec::new),
+            show_decision_traces: use_state(|| false),
         };
         render_app(&state, Some(&Route::Travel), None)
     }
@@ -290,6 +295,8 @@ mod tests {
             save_focus_target: use_state(|| AttrValue::from("save-open-btn")),
             show_settings: use_state(|| false),
             current_language: use_state(|| String::from("en")),
+            decision_traces: use_state(Vec::new),
+            show_decision_traces: use_state(|| false),
         };
         render_app(&state, Some(&Route::Menu), None)
     }
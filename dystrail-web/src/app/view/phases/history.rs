@@ -0,0 +1,32 @@
+use crate::app::phase::Phase;
+use crate::app::state::AppState;
+use crate::game::{RunHistory, RunHistoryStorage, WebGameStorage};
+use crate::pages::history::HistoryPage;
+use yew::prelude::*;
+
+fn copy_to_clipboard(text: &str) {
+    if let Some(win) = web_sys::window() {
+        let _ = win.navigator().clipboard().write_text(text);
+    }
+}
+
+pub fn render_history(state: &AppState) -> Html {
+    let history = WebGameStorage.load_history().unwrap_or_default();
+    let on_back = {
+        let phase = state.phase.clone();
+        Callback::from(move |()| phase.set(Phase::Menu))
+    };
+    let on_export_json = {
+        let history = history.clone();
+        Callback::from(move |()| {
+            if let Ok(json) = history.to_json() {
+                copy_to_clipboard(&json);
+            }
+        })
+    };
+    let on_export_csv = {
+        let history: RunHistory = history.clone();
+        Callback::from(move |()| copy_to_clipboard(&history.to_csv()))
+    };
+    html! { <HistoryPage {history} {on_back} {on_export_json} {on_export_csv} /> }
+}
@@ -1,5 +1,6 @@
 use crate::app::phase::Phase;
 use crate::app::state::AppState;
+use crate::game::OtDeluxeCargoItem;
 use crate::pages::inventory::InventoryPage;
 use std::rc::Rc;
 use yew::prelude::*;
@@ -11,6 +12,31 @@ pub fn render_inventory(state: &AppState) -> Html {
             let phase = state.phase.clone();
             Callback::from(move |()| phase.set(Phase::Travel))
         };
-        html! { <InventoryPage state={snapshot} {on_back} /> }
+        let on_discard = {
+            let session_handle = state.session.clone();
+            Callback::from(move |(item, qty): (OtDeluxeCargoItem, u16)| {
+                if let Some(mut sess) = (*session_handle).clone() {
+                    sess.with_state_mut(|gs| {
+                        gs.discard_otdeluxe_cargo(item, qty);
+                    });
+                    session_handle.set(Some(sess));
+                }
+            })
+        };
+        let on_treat = {
+            let session_handle = state.session.clone();
+            Callback::from(move |member: Option<usize>| {
+                if let Some(mut sess) = (*session_handle).clone() {
+                    sess.with_state_mut(|gs| {
+                        let _ = match member {
+                            Some(idx) => gs.treat_otdeluxe_member(idx).map(|_| ()),
+                            None => gs.treat_illness().map(|_| ()),
+                        };
+                    });
+                    session_handle.set(Some(sess));
+                }
+            })
+        };
+        html! { <InventoryPage state={snapshot} {on_back} {on_discard} {on_treat} /> }
     })
 }
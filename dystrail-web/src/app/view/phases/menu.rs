@@ -8,6 +8,7 @@ struct MenuActionHandlers {
     start_journey: Callback<()>,
     open_about: Callback<()>,
     open_settings: Callback<()>,
+    open_history: Callback<()>,
     quit: Callback<()>,
 }
 
@@ -66,6 +67,7 @@ fn menu_action_callback(handlers: MenuActionHandlers) -> Callback<MenuAction> {
         MenuAction::StartJourney => handlers.start_journey.emit(()),
         MenuAction::About => handlers.open_about.emit(()),
         MenuAction::Settings => handlers.open_settings.emit(()),
+        MenuAction::History => handlers.open_history.emit(()),
         MenuAction::Quit => handlers.quit.emit(()),
     })
 }
@@ -95,6 +97,11 @@ fn build_menu_action_handlers(state: &AppState) -> MenuActionHandlers {
         Callback::from(move |()| set_phase.emit(Phase::Settings))
     };
 
+    let open_history = {
+        let set_phase = set_phase.clone();
+        Callback::from(move |()| set_phase.emit(Phase::History))
+    };
+
     let quit = {
         let state = state.clone();
         let set_phase = set_phase;
@@ -108,6 +115,7 @@ fn build_menu_action_handlers(state: &AppState) -> MenuActionHandlers {
         start_journey,
         open_about,
         open_settings,
+        open_history,
         quit,
     }
 }
@@ -148,10 +156,14 @@ mod tests {
                 let called = called.clone();
                 Callback::from(move |()| *called.borrow_mut() = 3)
             },
-            quit: {
+            open_history: {
                 let called = called.clone();
                 Callback::from(move |()| *called.borrow_mut() = 4)
             },
+            quit: {
+                let called = called.clone();
+                Callback::from(move |()| *called.borrow_mut() = 5)
+            },
         };
 
         let invoked = use_mut_ref(|| false);
@@ -161,6 +173,7 @@ mod tests {
             on_action.emit(MenuAction::StartJourney);
             on_action.emit(MenuAction::About);
             on_action.emit(MenuAction::Settings);
+            on_action.emit(MenuAction::History);
             on_action.emit(MenuAction::Quit);
         }
 
@@ -171,7 +184,7 @@ mod tests {
     #[test]
     fn menu_action_callback_routes_actions() {
         let html = block_on(LocalServerRenderer::<MenuActionHarness>::new().render());
-        assert!(html.contains("data-called=\"4\""));
+        assert!(html.contains("data-called=\"5\""));
     }
 
     #[test]
@@ -273,6 +286,8 @@ mod tests {
                 save_focus_target: use_state(|| AttrValue::from("save-open-btn")),
                 show_settings: use_state(|| false),
                 current_language: use_state(|| String::from("en")),
+                decision_traces: use_state(Vec::new),
+                show_decision_traces: use_state(|| false),
             };
 
             if !*invoked.borrow() {
@@ -291,6 +306,7 @@ mod tests {
         let start_called = Rc::new(Cell::new(false));
         let about_called = Rc::new(Cell::new(false));
         let settings_called = Rc::new(Cell::new(false));
+        let history_called = Rc::new(Cell::new(false));
         let quit_called = Rc::new(Cell::new(false));
 
         let handlers = MenuActionHandlers {
@@ -306,6 +322,10 @@ mod tests {
                 let settings_called = settings_called.clone();
                 Callback::from(move |()| settings_called.set(true))
             },
+            open_history: {
+                let history_called = history_called.clone();
+                Callback::from(move |()| history_called.set(true))
+            },
             quit: {
                 let quit_called = quit_called.clone();
                 Callback::from(move |()| quit_called.set(true))
@@ -316,11 +336,13 @@ mod tests {
         on_action.emit(MenuAction::StartJourney);
         on_action.emit(MenuAction::About);
         on_action.emit(MenuAction::Settings);
+        on_action.emit(MenuAction::History);
         on_action.emit(MenuAction::Quit);
 
         assert!(start_called.get());
         assert!(about_called.get());
         assert!(settings_called.get());
+        assert!(history_called.get());
         assert!(quit_called.get());
     }
 
@@ -351,6 +373,8 @@ mod tests {
                 save_focus_target: use_state(|| AttrValue::from("save-open-btn")),
                 show_settings: use_state(|| true),
                 current_language: use_state(|| String::from("en")),
+                decision_traces: use_state(Vec::new),
+                show_decision_traces: use_state(|| false),
             };
             let handlers = build_menu_action_handlers(&state);
 
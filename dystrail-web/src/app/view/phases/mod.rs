@@ -3,6 +3,7 @@ mod boss;
 mod camp;
 mod crossing;
 mod encounter;
+mod history;
 mod inventory;
 mod map;
 mod menu;
@@ -30,6 +31,7 @@ pub use boss::render_boss;
 pub use camp::render_camp;
 pub use crossing::render_crossing;
 pub use encounter::render_encounter;
+pub use history::render_history;
 pub use inventory::render_inventory;
 pub use map::render_map;
 pub use menu::render_menu;
@@ -89,6 +91,7 @@ pub fn render_main_view(state: &AppState, handlers: &AppHandlers, route: Option<
         Phase::Menu => render_menu(state),
         Phase::About => render_about(state),
         Phase::Settings => render_settings(state, handlers),
+        Phase::History => render_history(state),
         Phase::Persona => render_persona(state),
         Phase::ModeSelect => render_mode_select(state),
         Phase::Outfitting => render_outfitting(state),
@@ -177,6 +180,8 @@ mod tests {
             save_focus_target: use_state(|| AttrValue::from("save-open-btn")),
             show_settings: use_state(|| false),
             current_language: use_state(|| String::from("en")),
+            decision_traces: use_state(Vec::new),
+            show_decision_traces: use_state(|| false),
         };
         let handlers = AppHandlers::new(&app_state, None);
         render_main_view(&app_state, &handlers, props.route.as_ref())
@@ -448,6 +453,15 @@ mod tests {
             data: EncounterData::empty(),
         });
         assert!(html.contains("settings-screen"));
+
+        let html = render_phase(PhaseHarnessProps {
+            phase: Phase::History,
+            route: Some(Route::History),
+            session: None,
+            pending_state: None,
+            data: EncounterData::empty(),
+        });
+        assert!(html.contains("history-screen"));
     }
 
     #[test]
@@ -1,5 +1,6 @@
 use crate::app::phase::Phase;
 use crate::app::state::AppState;
+use crate::game::VehicleClass;
 #[cfg(any(test, target_arch = "wasm32"))]
 use crate::game::data::EncounterData;
 #[cfg(any(test, target_arch = "wasm32"))]
@@ -26,13 +27,15 @@ fn build_mode_selection_outcome(
     pending_state: Option<crate::game::GameState>,
     data: &EncounterData,
     mode: GameMode,
+    departure_month: u8,
+    vehicle_class: VehicleClass,
     entropy: u64,
 ) -> Option<ModeSelectionOutcome> {
     let is_deep = matches!(mode, GameMode::Deep);
     let code = generate_code_from_entropy(is_deep, entropy);
     let (decoded_deep, seed) = decode_to_seed(&code)?;
     let base = pending_state.unwrap_or_default();
-    let gs = base.with_seed(
+    let mut gs = base.with_seed(
         seed,
         if decoded_deep {
             GameMode::Deep
@@ -41,6 +44,13 @@ fn build_mode_selection_outcome(
         },
         data.clone(),
     );
+    let year = gs.ot_deluxe.departure.year;
+    gs.ot_deluxe
+        .set_departure(crate::game::OtDeluxeCalendar::departure(
+            departure_month,
+            year,
+        ));
+    gs.select_vehicle_class(vehicle_class);
     Some(ModeSelectionOutcome {
         state: gs,
         seed,
@@ -63,14 +73,20 @@ pub fn render_mode_select(state: &AppState) -> Html {
         let phase_handle = state.phase.clone();
         #[cfg(target_arch = "wasm32")]
         {
-            Callback::from(move |mode: GameMode| {
+            Callback::from(move |(mode, month, class): (GameMode, u8, VehicleClass)| {
                 let entropy = next_entropy();
-                if let Some(outcome) = build_mode_selection_outcome(
+                if let Some(mut outcome) = build_mode_selection_outcome(
                     (*pending_handle).clone(),
                     &data_handle,
                     mode,
+                    month,
+                    class,
                     entropy,
                 ) {
+                    crate::app::graveyard::load_trail_graves(
+                        &crate::game::WebGameStorage,
+                        &mut outcome.state,
+                    );
                     pending_handle.set(Some(outcome.state));
                     run_seed_handle.set(outcome.seed);
                     code_handle.set(outcome.code);
@@ -87,7 +103,7 @@ pub fn render_mode_select(state: &AppState) -> Html {
                 run_seed_handle,
                 phase_handle,
             );
-            Callback::from(|_mode: GameMode| {})
+            Callback::from(|_choice: (GameMode, u8, VehicleClass)| {})
         }
     };
 
@@ -102,7 +118,14 @@ mod tests {
     fn build_mode_selection_outcome_handles_classic_and_deep() {
         let data = EncounterData::empty();
         let base = crate::game::GameState::default();
-        let classic = build_mode_selection_outcome(Some(base.clone()), &data, GameMode::Classic, 7);
+        let classic = build_mode_selection_outcome(
+            Some(base.clone()),
+            &data,
+            GameMode::Classic,
+            3,
+            VehicleClass::Minivan,
+            7,
+        );
         let Some(classic) = classic else {
             panic!("classic selection should produce a seed");
         };
@@ -111,7 +134,8 @@ mod tests {
         assert_eq!(classic.phase, Phase::Outfitting);
         assert!(!classic.code.is_empty());
 
-        let deep = build_mode_selection_outcome(Some(base), &data, GameMode::Deep, 9);
+        let deep =
+            build_mode_selection_outcome(Some(base), &data, GameMode::Deep, 6, VehicleClass::Rv, 9);
         let Some(deep) = deep else {
             panic!("deep selection should produce a seed");
         };
@@ -119,5 +143,10 @@ mod tests {
         assert_eq!(deep.state.seed, deep.seed);
         assert_eq!(deep.phase, Phase::Outfitting);
         assert!(!deep.code.is_empty());
+        assert_eq!(classic.state.ot_deluxe.departure.month, 3);
+        assert_eq!(deep.state.ot_deluxe.departure.month, 6);
+        assert_eq!(deep.state.ot_deluxe.calendar.month, 6);
+        assert_eq!(classic.state.vehicle.class, VehicleClass::Minivan);
+        assert_eq!(deep.state.vehicle.class, VehicleClass::Rv);
     }
 }
@@ -1,6 +1,7 @@
 use crate::app::phase::build_weather_badge;
 use crate::app::state::AppState;
 use crate::app::view::handlers::AppHandlers;
+use crate::components::ui::talk_panel::TalkPanel;
 use crate::pages::route_prompt::RoutePromptPage;
 use std::rc::Rc;
 use yew::prelude::*;
@@ -12,6 +13,6 @@ pub fn render_route_prompt(state: &AppState, handlers: &AppHandlers) -> Html {
         let pending = snapshot.ot_deluxe.route.pending_prompt;
         let state_rc = Rc::new(snapshot);
 
-        pending.map_or_else(Html::default, |prompt| html! { <RoutePromptPage state={state_rc.clone()} prompt={prompt} weather={weather_badge} on_choice={handlers.route_prompt_choice.clone()} /> })
+        pending.map_or_else(Html::default, |prompt| html! { <><TalkPanel state={state_rc.clone()} /><RoutePromptPage state={state_rc.clone()} prompt={prompt} weather={weather_badge} on_choice={handlers.route_prompt_choice.clone()} /></> })
     })
 }
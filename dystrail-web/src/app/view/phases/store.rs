@@ -1,6 +1,7 @@
 use crate::app::phase::build_weather_badge;
 use crate::app::state::AppState;
 use crate::app::view::handlers::AppHandlers;
+use crate::components::ui::talk_panel::TalkPanel;
 use crate::game::MechanicalPolicyId;
 use crate::pages::otdeluxe_store::OtDeluxeStorePage;
 use std::rc::Rc;
@@ -13,6 +14,6 @@ pub fn render_store(state: &AppState, handlers: &AppHandlers) -> Html {
         let state_rc = Rc::new(snapshot);
         if state_rc.mechanical_policy != MechanicalPolicyId::OtDeluxe90s { return Html::default(); }
         if state_rc.ot_deluxe.store.pending_node.is_none() { return Html::default(); }
-        html! { <OtDeluxeStorePage state={state_rc} weather={weather_badge} on_purchase={handlers.store_purchase.clone()} on_leave={handlers.store_leave.clone()} /> }
+        html! { <><TalkPanel state={state_rc.clone()} /><OtDeluxeStorePage state={state_rc} weather={weather_badge} on_purchase={handlers.store_purchase.clone()} on_leave={handlers.store_leave.clone()} /></> }
     })
 }
@@ -1,6 +1,9 @@
 use crate::app::phase::build_weather_badge;
 use crate::app::state::AppState;
 use crate::app::view::handlers::AppHandlers;
+use crate::components::ui::epitaph_prompt::EpitaphPrompt;
+use crate::components::ui::talk_panel::TalkPanel;
+use crate::game::MechanicalPolicyId;
 use crate::pages::travel::TravelPage;
 use std::rc::Rc;
 use yew::prelude::*;
@@ -8,8 +11,17 @@ use yew::prelude::*;
 pub fn render_travel(state: &AppState, handlers: &AppHandlers) -> Html {
     (*state.session).clone().map_or_else(Html::default, |sess| {
         let snapshot = sess.state().clone();
+        let epitaph_prompt = snapshot.ot_deluxe.pending_epitaph().map_or_else(Html::default, |death| {
+            html! { <EpitaphPrompt key={death.member_index} member_index={death.member_index} name={AttrValue::from(death.name.clone())} on_submit={handlers.epitaph.clone()} /> }
+        });
         let weather_badge = build_weather_badge(&snapshot, &state.weather_config);
         let state_rc = Rc::new(snapshot);
+        // OTDeluxe landmarks are store and route stops; Dystrail regions are always staffed.
+        let talk = if state_rc.mechanical_policy == MechanicalPolicyId::DystrailLegacy {
+            html! { <TalkPanel state={state_rc.clone()} /> }
+        } else {
+            Html::default()
+        };
         let pacing_config_rc = Rc::new((*state.pacing_config).clone());
         let on_open_inventory = {
             let phase = state.phase.clone();
@@ -24,6 +36,6 @@ pub fn render_travel(state: &AppState, handlers: &AppHandlers) -> Html {
             Callback::from(move |()| phase.set(crate::app::phase::Phase::Map))
         };
 
-        html! { <TravelPage state={state_rc} logs={(*state.logs).clone()} pacing_config={pacing_config_rc} weather_badge={weather_badge} data_ready={state.data_ready()} on_travel={handlers.travel.clone()} on_trade={handlers.trade.clone()} on_hunt={handlers.hunt.clone()} on_open_inventory={on_open_inventory} on_open_pace_diet={on_open_pace_diet} on_open_map={on_open_map} /> }
+        html! { <>{ epitaph_prompt }{ talk }<TravelPage state={state_rc} logs={(*state.logs).clone()} pacing_config={pacing_config_rc} weather_badge={weather_badge} data_ready={state.data_ready()} decision_traces={Rc::new((*state.decision_traces).clone())} show_decision_traces={*state.show_decision_traces} on_travel={handlers.travel.clone()} on_trade={handlers.trade.clone()} on_hunt={handlers.hunt.clone()} on_fish={handlers.fish.clone()} on_forage={handlers.forage.clone()} on_open_inventory={on_open_inventory} on_open_pace_diet={on_open_pace_diet} on_open_map={on_open_map} /></> }
     })
 }
@@ -2,7 +2,8 @@ use super::CampView;
 use crate::a11y::set_status;
 use crate::game::{
     CampConfig, CampOutcome, EndgameTravelCfg, GameState, camp_forage_with_endgame,
-    camp_repair_hack, camp_repair_spare, camp_rest_with_endgame, camp_therapy, can_repair,
+    camp_repair_hack_with_endgame, camp_repair_spare, camp_rest_with_endgame, camp_therapy,
+    can_repair,
 };
 use crate::i18n;
 use std::rc::Rc;
@@ -64,7 +65,8 @@ pub fn build_on_action(
                 }
             }
             (CampView::Repair, 2) => {
-                let result = camp_repair_hack(&mut new_state, &camp_config);
+                let result =
+                    camp_repair_hack_with_endgame(&mut new_state, &camp_config, &endgame_config);
                 view_setter.set(CampView::Main);
                 result
             }
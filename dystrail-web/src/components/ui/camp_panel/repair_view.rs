@@ -1,4 +1,4 @@
-use crate::game::vehicle::Breakdown;
+use crate::game::vehicle::{Breakdown, VehicleConfig};
 use crate::i18n;
 use web_sys::KeyboardEvent;
 use yew::prelude::*;
@@ -13,9 +13,18 @@ pub fn render_repair(
 ) -> Html {
     let part_name = breakdown.map_or_else(|| "Unknown".to_string(), |b| i18n::t(b.part.key()));
 
+    let costs = &VehicleConfig::default_config().repair_costs;
+    let sup = costs.hack_supplies.to_string();
+    let cred = costs.hack_cred.to_string();
+    let day = costs.hack_day.to_string();
+    let mut vars = std::collections::BTreeMap::new();
+    vars.insert("sup", sup.as_str());
+    vars.insert("cred", cred.as_str());
+    vars.insert("day", day.as_str());
+
     let items = vec![
         (1_u8, i18n::t("camp.menu.use_spare")),
-        (2, i18n::t("camp.menu.hack_fix")),
+        (2, i18n::tr("camp.menu.hack_fix", Some(&vars))),
         (0, i18n::t("camp.menu.back")),
     ];
 
@@ -0,0 +1,151 @@
+use crate::game::journey::EventDecisionTrace;
+use crate::i18n;
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// Persist the decision-trace overlay preference.
+#[cfg(target_arch = "wasm32")]
+pub fn set_overlay_enabled(enabled: bool) {
+    if let Ok(storage) = crate::dom::local_storage() {
+        let _ = storage.set_item("dystrail.debug_traces", if enabled { "1" } else { "0" });
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub const fn set_overlay_enabled(enabled: bool) {
+    let _ = enabled;
+}
+
+/// Whether the player opted into the decision-trace overlay.
+#[must_use]
+#[cfg(target_arch = "wasm32")]
+pub fn overlay_enabled() -> bool {
+    crate::dom::local_storage()
+        .ok()
+        .and_then(|storage| storage.get_item("dystrail.debug_traces").ok().flatten())
+        .is_some_and(|v| v == "1")
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub const fn overlay_enabled() -> bool {
+    false
+}
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    pub traces: Rc<Vec<EventDecisionTrace>>,
+}
+
+impl PartialEq for Props {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.traces, &other.traces)
+    }
+}
+
+/// Debug overlay explaining the weighted rolls behind the latest day.
+#[function_component(DecisionTraceOverlay)]
+pub fn decision_trace_overlay(p: &Props) -> Html {
+    let entries = p.traces.iter().map(|trace| {
+        let explanation = trace.explain();
+        let candidates = explanation.candidates.iter().map(|candidate| {
+            let class = if candidate.chosen {
+                "trace-candidate trace-chosen"
+            } else {
+                "trace-candidate"
+            };
+            html! {
+                <li class={class}>
+                    <span class="font-mono">{ candidate.id.clone() }</span>
+                    { ": " }
+                    { candidate.weight_line() }
+                </li>
+            }
+        });
+        html! {
+            <details class="trace-entry" data-testid="decision-trace">
+                <summary>
+                    <span class="font-mono">{ explanation.pool_id.clone() }</span>
+                    { " — " }
+                    { explanation.summary() }
+                </summary>
+                <ul class="text-xs">{ for candidates }</ul>
+            </details>
+        }
+    });
+
+    html! {
+        <aside class="trace-overlay" aria-labelledby="trace-overlay-title" data-testid="decision-trace-overlay">
+            <h3 id="trace-overlay-title">{ i18n::t("debug_traces.title") }</h3>
+            {
+                if p.traces.is_empty() {
+                    html! { <p class="text-xs opacity-70" data-testid="decision-trace-empty">{ i18n::t("debug_traces.empty") }</p> }
+                } else {
+                    html! { <>{ for entries }</> }
+                }
+            }
+        </aside>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::journey::{RollScale, RollValue, WeightFactor, WeightedCandidate};
+    use futures::executor::block_on;
+    use yew::LocalServerRenderer;
+
+    fn render(traces: Vec<EventDecisionTrace>) -> String {
+        crate::i18n::set_lang("en");
+        block_on(
+            LocalServerRenderer::<DecisionTraceOverlay>::with_props(Props {
+                traces: Rc::new(traces),
+            })
+            .render(),
+        )
+    }
+
+    #[test]
+    fn empty_overlay_renders_placeholder() {
+        let html = render(Vec::new());
+        assert!(html.contains("decision-trace-overlay"));
+        assert!(html.contains("decision-trace-empty"));
+    }
+
+    #[test]
+    fn overlay_explains_each_trace() {
+        let trace = EventDecisionTrace {
+            pool_id: String::from("dystrail.breakdown_part"),
+            roll: RollValue::U32(30),
+            roll_scale: RollScale::Final,
+            candidates: vec![
+                WeightedCandidate {
+                    id: String::from("vehicle.parts.tire"),
+                    base_weight: 20.0,
+                    multipliers: vec![WeightFactor {
+                        label: String::from("Storm"),
+                        value: 2.0,
+                    }],
+                    final_weight: 40.0,
+                },
+                WeightedCandidate {
+                    id: String::from("vehicle.parts.battery"),
+                    base_weight: 10.0,
+                    multipliers: Vec::new(),
+                    final_weight: 10.0,
+                },
+            ],
+            chosen_id: String::from("vehicle.parts.tire"),
+        };
+        let html = render(vec![trace]);
+        assert_eq!(html.matches("data-testid=\"decision-trace\"").count(), 1);
+        assert!(html.contains("Storm ×2, rolled 30 &lt; 40 → vehicle.parts.tire"));
+        assert_eq!(html.matches("trace-chosen").count(), 1);
+    }
+
+    #[test]
+    fn overlay_preference_defaults_off_natively() {
+        set_overlay_enabled(true);
+        assert!(!overlay_enabled());
+    }
+}
@@ -0,0 +1,92 @@
+use crate::game::OTDELUXE_EPITAPH_MAX_CHARS;
+use crate::i18n;
+use std::collections::BTreeMap;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct Props {
+    pub member_index: usize,
+    pub name: AttrValue,
+    /// Emits the member and the epitaph; an empty epitaph leaves the grave blank.
+    pub on_submit: Callback<(usize, String)>,
+}
+
+#[function_component(EpitaphPrompt)]
+pub fn epitaph_prompt(p: &Props) -> Html {
+    let text = use_state(String::new);
+    let oninput = {
+        let text = text.clone();
+        Callback::from(move |e: InputEvent| {
+            #[cfg(target_arch = "wasm32")]
+            {
+                if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                    text.set(input.value());
+                }
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                let _ = (&e, &text);
+            }
+        })
+    };
+    let on_carve = {
+        let cb = p.on_submit.clone();
+        let text = text.clone();
+        let idx = p.member_index;
+        Callback::from(move |_| cb.emit((idx, (*text).clone())))
+    };
+    let on_skip = {
+        let cb = p.on_submit.clone();
+        let idx = p.member_index;
+        Callback::from(move |_| cb.emit((idx, String::new())))
+    };
+    let mut args = BTreeMap::new();
+    args.insert("name", p.name.as_str());
+    let title = i18n::tr("tombstone.epitaph.title", Some(&args));
+    html! {
+        <section class="panel epitaph-prompt" role="dialog" aria-labelledby="epitaph-title">
+            <h3 id="epitaph-title">{ title }</h3>
+            <p class="muted">{ i18n::t("tombstone.epitaph.prompt") }</p>
+            <label for="epitaph-input">{ i18n::t("tombstone.epitaph.label") }</label>
+            <div class="controls">
+                <input
+                    id="epitaph-input"
+                    type="text"
+                    maxlength={OTDELUXE_EPITAPH_MAX_CHARS.to_string()}
+                    value={(*text).clone()}
+                    placeholder={i18n::t("tombstone.epitaph.placeholder")}
+                    {oninput}
+                />
+                <button class="retro-btn-primary" onclick={on_carve}>
+                    { i18n::t("tombstone.epitaph.carve") }
+                </button>
+                <button class="retro-btn-secondary" onclick={on_skip}>
+                    { i18n::t("tombstone.epitaph.skip") }
+                </button>
+            </div>
+        </section>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use yew::LocalServerRenderer;
+
+    #[test]
+    fn epitaph_prompt_names_the_dead() {
+        crate::i18n::set_lang("en");
+        let props = Props {
+            member_index: 1,
+            name: AttrValue::from("Ben"),
+            on_submit: Callback::noop(),
+        };
+        let html = block_on(LocalServerRenderer::<EpitaphPrompt>::with_props(props).render());
+        assert!(
+            html.contains("Ben has died"),
+            "title should name Ben: {html}"
+        );
+        assert!(html.contains("maxlength=\"60\""));
+    }
+}
@@ -11,7 +11,8 @@ pub fn activate_handler(on_select: Option<Callback<u8>>) -> Callback<u8> {
             1 => "menu.start_journey",
             2 => "menu.about",
             3 => "menu.accessibility",
-            4 => "menu.quit",
+            4 => "menu.history",
+            5 => "menu.quit",
             _ => "",
         };
         let label = i18n::t(label_key);
@@ -56,15 +57,15 @@ pub fn keydown_handler(
             e.prevent_default();
         } else if key == "ArrowDown" {
             let mut next = *focus_idx + 1;
-            if next > 4 {
+            if next > 5 {
                 next = 1;
             }
             focus_idx.set(next);
             e.prevent_default();
         } else if key == "ArrowUp" {
-            let mut prev = if *focus_idx <= 1 { 4 } else { *focus_idx - 1 };
+            let mut prev = if *focus_idx <= 1 { 5 } else { *focus_idx - 1 };
             if prev < 1 {
-                prev = 4;
+                prev = 5;
             }
             focus_idx.set(prev);
             e.prevent_default();
@@ -117,8 +118,8 @@ mod tests {
         let handler = activate_handler(Some(Callback::from(move |idx| {
             *selected_handle.borrow_mut() = Some(idx);
         })));
-        handler.emit(4);
-        assert_eq!(*selected.borrow(), Some(4));
+        handler.emit(5);
+        assert_eq!(*selected.borrow(), Some(5));
 
         let handler = activate_handler(None);
         handler.emit(42);
@@ -128,7 +129,7 @@ mod tests {
     fn activate_handler_covers_remaining_labels() {
         crate::i18n::set_lang("en");
         let handler = activate_handler(None);
-        for idx in 1..=4 {
+        for idx in 1..=5 {
             handler.emit(idx);
         }
     }
@@ -2,7 +2,7 @@ use yew::prelude::*;
 
 #[derive(Properties, PartialEq, Clone)]
 pub struct MenuItemProps {
-    pub index: u8,        // 1..=4
+    pub index: u8,        // 1..=5
     pub label: AttrValue, // resolved string
     pub focused: bool,    // active styling + aria-current
     pub posinset: u8,     // 1..=setsize
@@ -15,6 +15,7 @@ fn main_menu_renders_entries_and_helper() {
         "data-key=\"2\"",
         "data-key=\"3\"",
         "data-key=\"4\"",
+        "data-key=\"5\"",
     ] {
         assert!(
             html.contains(key),
@@ -13,7 +13,7 @@ pub struct MainMenuProps {
 pub fn main_menu(p: &MainMenuProps) -> Html {
     let focus_idx = use_state(|| 1_u8);
     let list_ref = use_node_ref();
-    let setsize = 4_u8; // 1..=4
+    let setsize = 5_u8; // 1..=5
 
     let activate = activate_handler(p.on_select.clone());
     let on_focus = {
@@ -29,7 +29,8 @@ pub fn main_menu(p: &MainMenuProps) -> Html {
         (1_u8, i18n::t("menu.start_journey"), true),
         (2, i18n::t("menu.about"), false),
         (3, i18n::t("menu.accessibility"), false),
-        (4, i18n::t("menu.quit"), false),
+        (4, i18n::t("menu.history"), false),
+        (5, i18n::t("menu.quit"), false),
     ];
 
     html! {
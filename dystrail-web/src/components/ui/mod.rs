@@ -1,6 +1,8 @@
 pub mod camp_panel;
 pub mod crossing_card;
+pub mod decision_trace_overlay;
 pub mod encounter_card;
+pub mod epitaph_prompt;
 pub mod main_menu;
 pub mod otdeluxe_crossing_card;
 pub mod otdeluxe_store_panel;
@@ -9,10 +11,12 @@ pub mod pace_diet_panel;
 pub mod persona_select;
 pub mod result_screen;
 pub mod route_prompt_card;
+pub mod run_stats;
 pub mod save_drawer;
 pub mod seed_footer;
 pub mod settings_dialog;
 pub mod share_code_bar;
 pub mod stats_bar;
+pub mod talk_panel;
 pub mod travel_panel;
 pub mod vehicle_status;
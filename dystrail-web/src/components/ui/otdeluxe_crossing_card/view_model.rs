@@ -1,6 +1,8 @@
+use crate::game::otdeluxe_state::OtDeluxeCrossingMethod;
 use crate::game::{
-    GameState, OtDeluxe90sPolicy, OtDeluxeCrossingOptions, OtDeluxeRiver, OtDeluxeRiverBed,
-    numbers::round_f64_to_i32, otdeluxe_crossing_options,
+    GameState, OtDeluxe90sPolicy, OtDeluxeCrossingInfo, OtDeluxeCrossingOptions,
+    OtDeluxeGuidePayment, OtDeluxeRiver, OtDeluxeRiverBed, numbers::round_f64_to_i32,
+    otdeluxe_crossing_options, otdeluxe_guide_payment,
 };
 use crate::i18n;
 use std::collections::BTreeMap;
@@ -36,6 +38,10 @@ pub fn build_otdeluxe_crossing_viewmodel(
         .river
         .as_ref()
         .ok_or_else(|| String::from("Missing river state"))?;
+    let info = gs
+        .otdeluxe_crossing_info()
+        .ok_or_else(|| String::from("Missing river state"))?;
+    let observed = &info.observed;
 
     let title = i18n::t("ot_cross.title");
     let river_name = i18n::t(river_name_key(river_kind));
@@ -43,14 +49,14 @@ pub fn build_otdeluxe_crossing_viewmodel(
     prompt_args.insert("river", river_name.as_str());
     let prompt = i18n::tr("ot_cross.prompt", Some(&prompt_args));
 
-    let width = round_one_decimal(river_state.width_ft);
-    let depth = round_one_decimal(river_state.depth_ft);
-    let swiftness_pct = round_f64_to_i32(f64::from(river_state.swiftness) * 100.0).clamp(0, 100);
+    let width = round_one_decimal(observed.width_ft);
+    let depth = round_one_decimal(observed.depth_ft);
+    let swiftness_pct = round_f64_to_i32(f64::from(observed.swiftness) * 100.0).clamp(0, 100);
     let swiftness_pct = u8::try_from(swiftness_pct).unwrap_or_default();
     let width_str = i18n::fmt_number(f64::from(width));
     let depth_str = i18n::fmt_number(f64::from(depth));
     let swiftness_str = i18n::fmt_pct(swiftness_pct);
-    let bed_str = i18n::t(bed_key(river_state.bed));
+    let bed_str = i18n::t(bed_key(observed.bed));
     let mut stats_args = BTreeMap::new();
     stats_args.insert("width", width_str.as_str());
     stats_args.insert("depth", depth_str.as_str());
@@ -67,19 +73,20 @@ pub fn build_otdeluxe_crossing_viewmodel(
     );
 
     let ford_label = i18n::t("ot_cross.options.ford");
-    let ford_desc = i18n::t("ot_cross.desc.ford");
+    let ford_desc = method_desc("ot_cross.desc.ford", &info, OtDeluxeCrossingMethod::Ford);
     let caulk_label = i18n::t("ot_cross.options.caulk_float");
-    let caulk_desc = i18n::t("ot_cross.desc.caulk_float");
+    let caulk_desc = method_desc(
+        "ot_cross.desc.caulk_float",
+        &info,
+        OtDeluxeCrossingMethod::CaulkFloat,
+    );
     let ferry_cost = i18n::fmt_currency(i64::from(policy.crossings.ferry_cost_cents));
     let mut ferry_args = BTreeMap::new();
     ferry_args.insert("cost", ferry_cost.as_str());
     let ferry_label = i18n::tr("ot_cross.options.ferry", Some(&ferry_args));
-    let ferry_desc = i18n::t("ot_cross.desc.ferry");
-    let guide_sets = policy.crossings.guide_cost_clothes_sets.to_string();
-    let mut guide_args = BTreeMap::new();
-    guide_args.insert("sets", guide_sets.as_str());
-    let guide_label = i18n::tr("ot_cross.options.guide", Some(&guide_args));
-    let guide_desc = i18n::t("ot_cross.desc.guide");
+    let ferry_desc = method_desc("ot_cross.desc.ferry", &info, OtDeluxeCrossingMethod::Ferry);
+    let guide_label = guide_label(&policy, gs);
+    let guide_desc = method_desc("ot_cross.desc.guide", &info, OtDeluxeCrossingMethod::Guide);
     let back_label = i18n::t("ot_cross.options.back");
 
     Ok(OtDeluxeCrossingViewModel {
@@ -99,6 +106,32 @@ pub fn build_otdeluxe_crossing_viewmodel(
     })
 }
 
+fn guide_label(policy: &OtDeluxe90sPolicy, gs: &GameState) -> String {
+    let payment = otdeluxe_guide_payment(&policy.crossings, &gs.ot_deluxe.inventory);
+    if let Some(OtDeluxeGuidePayment::Cash(cents)) = payment {
+        let cost = i18n::fmt_currency(i64::from(cents));
+        let mut args = BTreeMap::new();
+        args.insert("cost", cost.as_str());
+        return i18n::tr("ot_cross.options.guide_cash", Some(&args));
+    }
+    let sets = policy.crossings.guide_cost_clothes_sets.to_string();
+    let mut args = BTreeMap::new();
+    args.insert("sets", sets.as_str());
+    i18n::tr("ot_cross.options.guide", Some(&args))
+}
+
+fn method_desc(key: &str, info: &OtDeluxeCrossingInfo, method: OtDeluxeCrossingMethod) -> String {
+    let desc = i18n::t(key);
+    let Some(entry) = info.methods.iter().find(|entry| entry.method == method) else {
+        return desc;
+    };
+    let safe_pct = round_f64_to_i32(f64::from(entry.odds.safe) * 100.0).clamp(0, 100);
+    let pct = i18n::fmt_pct(u8::try_from(safe_pct).unwrap_or_default());
+    let mut args = BTreeMap::new();
+    args.insert("pct", pct.as_str());
+    format!("{desc} {}", i18n::tr("ot_cross.odds", Some(&args)))
+}
+
 const fn river_name_key(river: OtDeluxeRiver) -> &'static str {
     match river {
         OtDeluxeRiver::Kansas => "ot_cross.river.kansas",
@@ -178,5 +211,19 @@ mod tests {
         assert!(vm.stats.contains(&i18n::t("ot_cross.bed.muddy")));
         assert!(!vm.ferry_label.is_empty());
         assert!(!vm.guide_label.is_empty());
+        assert!(vm.guide_desc.starts_with(&i18n::t("ot_cross.desc.guide")));
+        assert!(vm.guide_desc.len() > i18n::t("ot_cross.desc.guide").len());
+    }
+
+    #[test]
+    fn guide_label_shows_cash_when_guide_takes_cash() {
+        crate::i18n::set_lang("en");
+        let mut policy = OtDeluxe90sPolicy::default();
+        policy.crossings.guide_cost_cents = 1_500;
+        let mut gs = GameState::default();
+        gs.ot_deluxe.inventory.clothes_sets = 0;
+        gs.ot_deluxe.inventory.cash_cents = 5_000;
+        let label = guide_label(&policy, &gs);
+        assert!(label.contains(&i18n::fmt_currency(1_500)));
     }
 }
@@ -54,4 +54,33 @@ fn store_panel_renders_cards_and_disabled_checkout() {
     assert!(html.contains("otdeluxe-store-title"));
     assert!(html.contains("store-card"));
     assert!(html.contains("retro-btn-primary"));
+    assert!(!html.contains("store-cargo-warning"));
+}
+
+#[test]
+fn store_panel_warns_when_wagon_is_overloaded() {
+    crate::i18n::set_lang("en");
+    let state = GameState {
+        mechanical_policy: MechanicalPolicyId::OtDeluxe90s,
+        ot_deluxe: OtDeluxeState {
+            inventory: OtDeluxeInventory {
+                food_lbs: 1_800,
+                ..OtDeluxeInventory::default()
+            },
+            oxen: OtDeluxeOxenState {
+                healthy: 2,
+                sick: 0,
+            },
+            ..OtDeluxeState::default()
+        },
+        ..GameState::default()
+    };
+    let props = OtDeluxeStorePanelProps {
+        state: Rc::new(state),
+        on_purchase: Callback::noop(),
+        on_leave: Callback::noop(),
+    };
+    let html = block_on(LocalServerRenderer::<OtDeluxeStorePanel>::with_props(props).render());
+    assert!(html.contains("store-cargo-warning"));
+    assert!(html.contains("800"));
 }
@@ -1,7 +1,8 @@
 use crate::game::mechanics::otdeluxe90s::OtDeluxeStorePolicy;
+use crate::game::numbers::round_f32_to_i32;
 use crate::game::otdeluxe_state::{OtDeluxeInventory, OtDeluxeOxenState};
 use crate::game::otdeluxe_store::{self, OtDeluxeStoreItem, OtDeluxeStoreLineItem};
-use crate::game::{GameState, OtDeluxe90sPolicy};
+use crate::game::{GameState, OtDeluxe90sPolicy, OtDeluxeCargoLoad};
 use crate::i18n;
 use crate::i18n::fmt_currency;
 use std::collections::BTreeMap;
@@ -33,7 +34,7 @@ const fn remaining_cash(available_cash: u64, total_cost_cents: u64) -> u64 {
     }
 }
 
-const STORE_ITEMS: [StoreItemDef; 7] = [
+const STORE_ITEMS: [StoreItemDef; 8] = [
     StoreItemDef {
         item: OtDeluxeStoreItem::Oxen,
         name_key: "otdeluxe.store.items.oxen.name",
@@ -69,6 +70,11 @@ const STORE_ITEMS: [StoreItemDef; 7] = [
         name_key: "otdeluxe.store.items.tongue.name",
         desc_key: "otdeluxe.store.items.tongue.desc",
     },
+    StoreItemDef {
+        item: OtDeluxeStoreItem::Medicine,
+        name_key: "otdeluxe.store.items.medicine.name",
+        desc_key: "otdeluxe.store.items.medicine.desc",
+    },
 ];
 
 #[derive(Properties, Clone)]
@@ -121,6 +127,11 @@ pub fn otdeluxe_store_panel(props: &OtDeluxeStorePanelProps) -> Html {
     let total_str = fmt_currency(u64_to_i64(total_cost_cents));
     let remaining_str = fmt_currency(u64_to_i64(cash_left));
 
+    let cargo = props
+        .state
+        .otdeluxe_cargo_after_purchase(&build_purchase_lines(cart.as_ref()));
+    let cargo_line = render_cargo_load(&cargo);
+
     let cash_label = render_amount("otdeluxe.store.cash", &cash_str);
     let total_label = render_amount("otdeluxe.store.total", &total_str);
     let remaining_label = render_amount("otdeluxe.store.remaining", &remaining_str);
@@ -169,6 +180,7 @@ pub fn otdeluxe_store_panel(props: &OtDeluxeStorePanelProps) -> Html {
                     <span>{ &total_label }</span>
                     <span class="value">{ &remaining_label }</span>
                 </div>
+                { cargo_line }
                 <aside class="store-recommendations" aria-labelledby="otdeluxe-store-recommend-title">
                     <h2 id="otdeluxe-store-recommend-title">
                         { i18n::t("otdeluxe.store.recommendations.title") }
@@ -200,6 +212,33 @@ pub fn otdeluxe_store_panel(props: &OtDeluxeStorePanelProps) -> Html {
     }
 }
 
+fn render_cargo_load(cargo: &OtDeluxeCargoLoad) -> Html {
+    let weight = round_f32_to_i32(cargo.weight_lbs).to_string();
+    let capacity = round_f32_to_i32(cargo.capacity_lbs).to_string();
+    let mut vars = BTreeMap::new();
+    vars.insert("weight", weight.as_str());
+    vars.insert("capacity", capacity.as_str());
+    let load_line = i18n::tr("otdeluxe.store.cargo_load", Some(&vars));
+    let warning = if cargo.is_overloaded() {
+        let over = round_f32_to_i32(cargo.overload_lbs()).max(1).to_string();
+        let mut vars = BTreeMap::new();
+        vars.insert("lbs", over.as_str());
+        html! {
+            <p class="store-cargo-warning" role="alert">
+                { i18n::tr("otdeluxe.store.cargo_overload", Some(&vars)) }
+            </p>
+        }
+    } else {
+        Html::default()
+    };
+    html! {
+        <div class="store-cargo" aria-live="polite">
+            <p class="muted">{ load_line }</p>
+            { warning }
+        </div>
+    }
+}
+
 fn emit_purchase_lines(cart: &[u16], on_purchase: &Callback<Vec<OtDeluxeStoreLineItem>>) {
     let lines = build_purchase_lines(cart);
     on_purchase.emit(lines);
@@ -425,6 +464,7 @@ pub(super) fn current_quantity(
         OtDeluxeStoreItem::Wheel => u16::from(inventory.spares_wheels),
         OtDeluxeStoreItem::Axle => u16::from(inventory.spares_axles),
         OtDeluxeStoreItem::Tongue => u16::from(inventory.spares_tongues),
+        OtDeluxeStoreItem::Medicine => inventory.medicine,
     }
 }
 
@@ -457,6 +497,7 @@ mod tests {
             spares_axles: 2,
             spares_tongues: 3,
             cash_cents: 0,
+            medicine: 0,
         };
         let oxen = OtDeluxeOxenState {
             healthy: 3,
@@ -72,6 +72,34 @@ pub fn announce_cannot_add(item: &StoreItem) {
     set_status(&message);
 }
 
+pub fn announce_no_spare_room(item: &StoreItem) {
+    let item_name = i18n::t(&format!("store.items.{}.name", item.id));
+    let message = i18n::tr(
+        "store.alerts.no_spare_room",
+        Some(&{
+            let mut vars = BTreeMap::new();
+            vars.insert("item", item_name.as_str());
+            vars
+        }),
+    );
+
+    set_status(&message);
+}
+
+pub fn announce_spares_dropped(count: i32) {
+    let count_str = count.to_string();
+    let message = i18n::tr(
+        "store.alerts.spares_dropped",
+        Some(&{
+            let mut vars = BTreeMap::new();
+            vars.insert("count", count_str.as_str());
+            vars
+        }),
+    );
+
+    set_status(&message);
+}
+
 pub fn format_currency(cents: i64) -> String {
     crate::i18n::fmt_currency(cents)
 }
@@ -112,4 +140,11 @@ mod tests {
         announce_cannot_add(&item(true));
         announce_cannot_add(&item(false));
     }
+
+    #[test]
+    fn announce_spare_alerts_render() {
+        crate::i18n::set_lang("en");
+        announce_no_spare_room(&item(false));
+        announce_spares_dropped(3);
+    }
 }
@@ -1,14 +1,22 @@
 use super::super::state::OutfittingStoreProps;
+use super::announce::announce_spares_dropped;
 use crate::game::store::Grants;
 
 pub fn handle_checkout(
     state: &crate::components::ui::outfitting_store::state::StoreState,
     props: &OutfittingStoreProps,
 ) {
+    let mut cart = state.cart.clone();
+    let dropped = props
+        .game_state
+        .fit_store_cart(&mut cart, &state.store_data, state.discount_pct);
+    if dropped > 0 {
+        announce_spares_dropped(dropped);
+    }
     let mut total_grants = Grants::default();
     let mut all_tags = Vec::new();
 
-    for line in &state.cart.lines {
+    for line in &cart.lines {
         if let Some(item) = state.store_data.find_item(&line.item_id) {
             total_grants.supplies += item.grants.supplies * line.qty;
             total_grants.credibility += item.grants.credibility * line.qty;
@@ -16,6 +24,10 @@ pub fn handle_checkout(
             total_grants.spare_battery += item.grants.spare_battery * line.qty;
             total_grants.spare_alt += item.grants.spare_alt * line.qty;
             total_grants.spare_pump += item.grants.spare_pump * line.qty;
+            total_grants.medicine += item.grants.medicine * line.qty;
+            total_grants
+                .vehicle_upgrades
+                .extend(item.grants.vehicle_upgrades.iter().copied());
 
             for tag in &item.tags {
                 all_tags.push(tag.clone());
@@ -24,7 +36,7 @@ pub fn handle_checkout(
     }
 
     let mut new_game_state = props.game_state.clone();
-    new_game_state.apply_store_purchase(state.cart.total_cents, &total_grants, &all_tags);
+    new_game_state.apply_store_purchase(cart.total_cents, &total_grants, &all_tags);
 
     props
         .on_continue
@@ -108,4 +120,60 @@ mod tests {
         assert_eq!(payload.1.supplies, 4);
         assert!(payload.2.contains(&String::from("hydrated")));
     }
+
+    #[test]
+    fn handle_checkout_charges_only_for_spares_that_fit() {
+        let item = StoreItem {
+            id: String::from("spare_tire"),
+            name: String::from("Spare Tire"),
+            desc: String::from("Desc"),
+            price_cents: 1_000,
+            unique: false,
+            max_qty: 20,
+            grants: Grants {
+                spare_tire: 1,
+                ..Grants::default()
+            },
+            tags: Vec::new(),
+            category: String::from("spares"),
+        };
+        let store = Store {
+            categories: vec![StoreCategory {
+                id: String::from("spares"),
+                name: String::from("Spares"),
+                items: vec![item],
+            }],
+            items: Vec::new(),
+        };
+        let game_state = GameState {
+            budget_cents: 50_000,
+            ..GameState::default()
+        };
+        let capacity = game_state.vehicle_modifiers().spare_capacity;
+        let mut cart = Cart::new();
+        cart.add_item("spare_tire", capacity + 3);
+        cart.total_cents = i64::from(capacity + 3) * 1_000;
+        let state = StoreState {
+            store_data: store,
+            cart,
+            current_screen: crate::components::ui::outfitting_store::state::StoreScreen::Cart,
+            focus_idx: 1,
+            discount_pct: 0.0,
+        };
+
+        let captured: CheckoutCapture = Rc::new(RefCell::new(None));
+        let captured_clone = captured.clone();
+        let props = OutfittingStoreProps {
+            game_state,
+            on_continue: Callback::from(move |payload| {
+                *captured_clone.borrow_mut() = Some(payload);
+            }),
+        };
+
+        handle_checkout(&state, &props);
+        let (bought, grants, _) = captured.borrow().clone().expect("payload");
+        assert_eq!(grants.spare_tire, capacity);
+        assert_eq!(bought.inventory.spares.tire, capacity);
+        assert_eq!(bought.budget_cents, 50_000 - i64::from(capacity) * 1_000);
+    }
 }
@@ -2,7 +2,7 @@
 use super::super::state::OutfittingStoreProps;
 use super::super::state::{StoreScreen, StoreState};
 #[cfg(target_arch = "wasm32")]
-use super::announce::{announce_cannot_add, announce_quantity_change};
+use super::announce::{announce_cannot_add, announce_no_spare_room, announce_quantity_change};
 use crate::game::store::calculate_cart_total;
 use crate::game::store::{Cart, StoreItem, calculate_effective_price};
 #[cfg(target_arch = "wasm32")]
@@ -18,6 +18,9 @@ pub enum QuantitySelectionOutcome {
     Blocked {
         item: StoreItem,
     },
+    NoSpareRoom {
+        item: StoreItem,
+    },
 }
 
 /// Announcement metadata for quantity changes.
@@ -30,11 +33,15 @@ pub enum QuantityAnnouncement {
 }
 
 /// Compute the next store state for a quantity selection without UI side effects.
+///
+/// `spare_room` is how many more spare parts the vehicle can carry on top of
+/// the cart; adds that would overflow it are refused.
 pub fn quantity_selection_outcome(
     index: u8,
     item_id: &str,
     state: &StoreState,
     budget_cents: i64,
+    spare_room: i32,
 ) -> QuantitySelectionOutcome {
     let Some(item) = state.store_data.find_item(item_id) else {
         return QuantitySelectionOutcome::Noop;
@@ -48,12 +55,16 @@ pub fn quantity_selection_outcome(
             new_state.focus_idx = 1;
         }
         1 => {
+            if !fits_spare_room(item, 1, spare_room) {
+                return QuantitySelectionOutcome::NoSpareRoom { item: item.clone() };
+            }
             if can_add_item(
                 &new_state.cart,
                 item,
                 1,
                 budget_cents,
                 new_state.discount_pct,
+                spare_room,
             ) {
                 new_state.cart.add_item(item_id, 1);
                 announcement = Some(QuantityAnnouncement::Change {
@@ -66,12 +77,16 @@ pub fn quantity_selection_outcome(
             }
         }
         2 => {
+            if !fits_spare_room(item, 5, spare_room) {
+                return QuantitySelectionOutcome::NoSpareRoom { item: item.clone() };
+            }
             if can_add_item(
                 &new_state.cart,
                 item,
                 5,
                 budget_cents,
                 new_state.discount_pct,
+                spare_room,
             ) {
                 new_state.cart.add_item(item_id, 5);
                 announcement = Some(QuantityAnnouncement::Change {
@@ -122,7 +137,16 @@ pub fn handle_quantity_selection(
     store_state: &UseStateHandle<StoreState>,
     props: &OutfittingStoreProps,
 ) {
-    match quantity_selection_outcome(index, item_id, state, props.game_state.budget_cents) {
+    let spare_room = props
+        .game_state
+        .store_cart_spare_room(&state.cart, &state.store_data);
+    match quantity_selection_outcome(
+        index,
+        item_id,
+        state,
+        props.game_state.budget_cents,
+        spare_room,
+    ) {
         QuantitySelectionOutcome::Update {
             state,
             announcement,
@@ -135,16 +159,25 @@ pub fn handle_quantity_selection(
         QuantitySelectionOutcome::Blocked { item } => {
             announce_cannot_add(&item);
         }
+        QuantitySelectionOutcome::NoSpareRoom { item } => {
+            announce_no_spare_room(&item);
+        }
         QuantitySelectionOutcome::Noop => {}
     }
 }
 
+/// Whether the vehicle has room for the spare parts `qty_to_add` of `item` grant.
+pub const fn fits_spare_room(item: &StoreItem, qty_to_add: i32, spare_room: i32) -> bool {
+    item.grants.spare_count() * qty_to_add <= spare_room
+}
+
 pub fn can_add_item(
     cart: &Cart,
     item: &StoreItem,
     qty_to_add: i32,
     budget_cents: i64,
     discount_pct: f64,
+    spare_room: i32,
 ) -> bool {
     let current_qty = cart.get_quantity(&item.id);
     let new_qty = current_qty + qty_to_add;
@@ -157,6 +190,10 @@ pub fn can_add_item(
         return false;
     }
 
+    if !fits_spare_room(item, qty_to_add, spare_room) {
+        return false;
+    }
+
     let effective_price = calculate_effective_price(item.price_cents, discount_pct);
     let additional_cost = effective_price * i64::from(qty_to_add);
     let new_total = cart.total_cents + additional_cost;
@@ -188,16 +225,21 @@ mod tests {
     fn can_add_item_respects_caps_and_budget() {
         let cart = Cart::new();
         let limited = item(false, 1, 100);
-        assert!(!can_add_item(&cart, &limited, 2, 500, 0.0));
+        assert!(!can_add_item(&cart, &limited, 2, 500, 0.0, 0));
 
         let unique = item(true, 5, 100);
-        assert!(!can_add_item(&cart, &unique, 2, 500, 0.0));
+        assert!(!can_add_item(&cart, &unique, 2, 500, 0.0, 0));
 
         let pricey = item(false, 5, 1_000);
-        assert!(!can_add_item(&cart, &pricey, 1, 500, 0.0));
+        assert!(!can_add_item(&cart, &pricey, 1, 500, 0.0, 0));
 
         let ok = item(false, 5, 100);
-        assert!(can_add_item(&cart, &ok, 1, 500, 0.0));
+        assert!(can_add_item(&cart, &ok, 1, 500, 0.0, 0));
+
+        let mut spare = item(false, 10, 100);
+        spare.grants.spare_tire = 1;
+        assert!(can_add_item(&cart, &spare, 2, 500, 0.0, 2));
+        assert!(!can_add_item(&cart, &spare, 3, 500, 0.0, 2));
     }
 
     fn store_state_with_item(qty: i32) -> (StoreState, StoreItem) {
@@ -227,7 +269,7 @@ mod tests {
     #[test]
     fn handle_quantity_selection_adds_items() {
         let (state, item) = store_state_with_item(0);
-        let outcome = quantity_selection_outcome(1, &item.id, &state, 10_000);
+        let outcome = quantity_selection_outcome(1, &item.id, &state, 10_000, 0);
         match outcome {
             QuantitySelectionOutcome::Update { state, .. } => {
                 assert_eq!(state.cart.get_quantity(&item.id), 1);
@@ -239,7 +281,7 @@ mod tests {
     #[test]
     fn handle_quantity_selection_adds_bulk_items() {
         let (state, item) = store_state_with_item(0);
-        let outcome = quantity_selection_outcome(2, &item.id, &state, 10_000);
+        let outcome = quantity_selection_outcome(2, &item.id, &state, 10_000, 0);
         match outcome {
             QuantitySelectionOutcome::Update { state, .. } => {
                 assert_eq!(state.cart.get_quantity(&item.id), 5);
@@ -251,7 +293,7 @@ mod tests {
     #[test]
     fn handle_quantity_selection_removes_items() {
         let (state, item) = store_state_with_item(2);
-        let outcome = quantity_selection_outcome(3, &item.id, &state, 10_000);
+        let outcome = quantity_selection_outcome(3, &item.id, &state, 10_000, 0);
         match outcome {
             QuantitySelectionOutcome::Update { state, .. } => {
                 assert_eq!(state.cart.get_quantity(&item.id), 1);
@@ -263,7 +305,7 @@ mod tests {
     #[test]
     fn handle_quantity_selection_removes_all_items() {
         let (state, item) = store_state_with_item(2);
-        let outcome = quantity_selection_outcome(4, &item.id, &state, 10_000);
+        let outcome = quantity_selection_outcome(4, &item.id, &state, 10_000, 0);
         match outcome {
             QuantitySelectionOutcome::Update { state, .. } => {
                 assert_eq!(state.cart.get_quantity(&item.id), 0);
@@ -275,7 +317,7 @@ mod tests {
     #[test]
     fn handle_quantity_selection_back_returns_to_category() {
         let (state, item) = store_state_with_item(0);
-        let outcome = quantity_selection_outcome(0, &item.id, &state, 10_000);
+        let outcome = quantity_selection_outcome(0, &item.id, &state, 10_000, 0);
         match outcome {
             QuantitySelectionOutcome::Update { state, .. } => {
                 assert!(matches!(state.current_screen, StoreScreen::Category(_)));
@@ -287,25 +329,41 @@ mod tests {
     #[test]
     fn handle_quantity_selection_blocks_when_over_budget() {
         let (state, item) = store_state_with_item(0);
-        let outcome = quantity_selection_outcome(1, &item.id, &state, 0);
+        let outcome = quantity_selection_outcome(1, &item.id, &state, 0, 0);
         assert!(matches!(outcome, QuantitySelectionOutcome::Blocked { .. }));
     }
 
     #[test]
     fn handle_quantity_selection_blocks_bulk_add_when_over_budget() {
         let (state, item) = store_state_with_item(0);
-        let outcome = quantity_selection_outcome(2, &item.id, &state, 0);
+        let outcome = quantity_selection_outcome(2, &item.id, &state, 0, 0);
         assert!(matches!(outcome, QuantitySelectionOutcome::Blocked { .. }));
     }
 
     #[test]
     fn quantity_selection_returns_noop_for_unknown_item_or_index() {
         let (state, _item) = store_state_with_item(0);
-        let outcome = quantity_selection_outcome(1, "unknown", &state, 10_000);
+        let outcome = quantity_selection_outcome(1, "unknown", &state, 10_000, 0);
         assert!(matches!(outcome, QuantitySelectionOutcome::Noop));
 
         let (state, item) = store_state_with_item(0);
-        let outcome = quantity_selection_outcome(9, &item.id, &state, 10_000);
+        let outcome = quantity_selection_outcome(9, &item.id, &state, 10_000, 0);
         assert!(matches!(outcome, QuantitySelectionOutcome::Noop));
     }
+
+    #[test]
+    fn quantity_selection_refuses_spares_beyond_the_vehicle_room() {
+        let (mut state, mut item) = store_state_with_item(0);
+        item.grants.spare_tire = 1;
+        state.store_data.categories[0].items[0] = item.clone();
+
+        let outcome = quantity_selection_outcome(2, &item.id, &state, 10_000, 4);
+        assert!(matches!(
+            outcome,
+            QuantitySelectionOutcome::NoSpareRoom { .. }
+        ));
+
+        let outcome = quantity_selection_outcome(1, &item.id, &state, 10_000, 4);
+        assert!(matches!(outcome, QuantitySelectionOutcome::Update { .. }));
+    }
 }
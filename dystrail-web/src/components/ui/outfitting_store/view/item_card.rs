@@ -1,7 +1,9 @@
-#[cfg(target_arch = "wasm32")]
-use super::super::handlers::announce::announce_quantity_change;
 use super::super::handlers::announce::format_currency;
+#[cfg(target_arch = "wasm32")]
+use super::super::handlers::announce::{announce_no_spare_room, announce_quantity_change};
 use super::super::handlers::quantity::can_add_item;
+#[cfg(target_arch = "wasm32")]
+use super::super::handlers::quantity::fits_spare_room;
 use super::super::state::StoreState;
 #[cfg(target_arch = "wasm32")]
 use crate::game::store::calculate_cart_total;
@@ -23,12 +25,14 @@ pub fn render_store_item_card(
     let effective_price = calculate_effective_price(item.price_cents, state.discount_pct);
     let price_str = format_currency(effective_price);
     let qty_in_cart = state.cart.get_quantity(&item.id);
+    let spare_room = game_state.store_cart_spare_room(&state.cart, &state.store_data);
     let can_add = can_add_item(
         &state.cart,
         item,
         1,
         game_state.budget_cents,
         state.discount_pct,
+        spare_room,
     );
     let initials = name
         .chars()
@@ -43,12 +47,15 @@ pub fn render_store_item_card(
         {
             Callback::from(move |_| {
                 let mut new_state = (*state).clone();
-                if can_add_item(
+                if !fits_spare_room(&item_clone, 1, spare_room) {
+                    announce_no_spare_room(&item_clone);
+                } else if can_add_item(
                     &new_state.cart,
                     &item_clone,
                     1,
                     budget,
                     new_state.discount_pct,
+                    spare_room,
                 ) {
                     new_state.cart.add_item(&item_clone.id, 1);
                     announce_quantity_change(&item_clone, 1, true, &new_state, budget);
@@ -64,7 +71,7 @@ pub fn render_store_item_card(
         }
         #[cfg(not(target_arch = "wasm32"))]
         {
-            let _ = (state, item_clone, budget);
+            let _ = (state, item_clone, budget, spare_room);
             Callback::from(|_| {})
         }
     };
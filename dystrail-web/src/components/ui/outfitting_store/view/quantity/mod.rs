@@ -33,7 +33,8 @@ pub fn render_quantity_screen(
 
     let current_qty = state.cart.get_quantity(item_id);
     let options = build_quantity_options(item, state, game_state, effective_price, current_qty);
-    let on_select = quantity_select_callback(item, game_state.budget_cents, state);
+    let spare_room = game_state.store_cart_spare_room(&state.cart, &state.store_data);
+    let on_select = quantity_select_callback(item, game_state.budget_cents, spare_room, state);
 
     html! {
         <main class="outfitting-store">
@@ -20,6 +20,7 @@ pub fn build_quantity_options(
     current_qty: i32,
 ) -> Vec<QuantityOption> {
     let mut options = Vec::new();
+    let spare_room = game_state.store_cart_spare_room(&state.cart, &state.store_data);
 
     let can_add_1 = can_add_item(
         &state.cart,
@@ -27,6 +28,7 @@ pub fn build_quantity_options(
         1,
         game_state.budget_cents,
         state.discount_pct,
+        spare_room,
     );
     options.push(if can_add_1 {
         let budget_preview = game_state.budget_cents - state.cart.total_cents - effective_price;
@@ -55,6 +57,7 @@ pub fn build_quantity_options(
             5,
             game_state.budget_cents,
             state.discount_pct,
+            spare_room,
         );
         let add5 = if can_add_5 {
             let budget_preview =
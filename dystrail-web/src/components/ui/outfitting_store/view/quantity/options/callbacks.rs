@@ -1,5 +1,5 @@
 use super::super::super::super::handlers::announce::{
-    announce_cannot_add, announce_quantity_change,
+    announce_cannot_add, announce_no_spare_room, announce_quantity_change,
 };
 use super::super::super::super::handlers::quantity::{
     QuantityAnnouncement, QuantitySelectionOutcome, quantity_selection_outcome,
@@ -11,13 +11,14 @@ use yew::prelude::*;
 pub fn quantity_select_callback(
     item: &StoreItem,
     budget_cents: i64,
+    spare_room: i32,
     store_state: &UseStateHandle<StoreState>,
 ) -> Callback<u8> {
     let store_state = store_state.clone();
     let item_id_owned = item.id.clone();
     Callback::from(move |index: u8| {
         let state = (*store_state).clone();
-        match quantity_selection_outcome(index, &item_id_owned, &state, budget_cents) {
+        match quantity_selection_outcome(index, &item_id_owned, &state, budget_cents, spare_room) {
             QuantitySelectionOutcome::Update {
                 state,
                 announcement,
@@ -30,6 +31,9 @@ pub fn quantity_select_callback(
             QuantitySelectionOutcome::Blocked { item } => {
                 announce_cannot_add(&item);
             }
+            QuantitySelectionOutcome::NoSpareRoom { item } => {
+                announce_no_spare_room(&item);
+            }
             QuantitySelectionOutcome::Noop => {}
         }
     })
@@ -100,7 +104,7 @@ mod tests {
             }
         });
         let invoked = use_mut_ref(|| false);
-        let callback = quantity_select_callback(&item, props.budget_cents, &store_state);
+        let callback = quantity_select_callback(&item, props.budget_cents, 0, &store_state);
 
         if !*invoked.borrow() {
             *invoked.borrow_mut() = true;
@@ -3,8 +3,9 @@ use super::menu::render_menu_item;
 use super::share::{
     resolved_epilogue_key, resolved_headline_key, resolved_mode_name, resolved_persona_name,
 };
-use crate::game::MechanicalPolicyId;
+use crate::components::ui::run_stats::RunStatsDashboard;
 use crate::game::ResultSummary;
+use crate::game::{MechanicalPolicyId, RunTimeline};
 use crate::i18n;
 use yew::prelude::*;
 
@@ -21,6 +22,7 @@ pub fn render_body(
     let headline_text = i18n::t(&headline_key);
     let epilogue_text = i18n::t(&epilogue_key);
     let show_thresholds = props.game_state.mechanical_policy == MechanicalPolicyId::DystrailLegacy;
+    let timeline = std::rc::Rc::new(RunTimeline::from_state(&props.game_state));
 
     html! {
         <main role="main" aria-labelledby="result-title" onkeydown={on_keydown} tabindex="0" class="result-screen" data-testid="result-screen">
@@ -36,6 +38,8 @@ pub fn render_body(
                 { render_stats(summary, show_thresholds) }
             </section>
 
+            <RunStatsDashboard {timeline} />
+
             <section class="epilogue-section">
                 <p class="epilogue">{ &epilogue_text }</p>
             </section>
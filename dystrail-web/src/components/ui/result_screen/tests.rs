@@ -130,6 +130,22 @@ fn result_screen_renders_summary() {
     assert!(html.contains("Result"));
 }
 
+#[test]
+fn result_screen_includes_run_stats() {
+    crate::i18n::set_lang("en");
+    let mut props = baseline_props();
+    props
+        .game_state
+        .day_records
+        .push(dystrail_game::DayRecord::new(
+            0,
+            dystrail_game::TravelDayKind::Travel,
+            14.0,
+        ));
+    let html = block_on(LocalServerRenderer::<ResultScreenWrapper>::with_props(props).render());
+    assert!(html.contains("run-stats-miles"));
+}
+
 #[test]
 fn result_screen_hides_thresholds_for_otdeluxe() {
     crate::i18n::set_lang("en");
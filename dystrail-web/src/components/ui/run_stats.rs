@@ -0,0 +1,310 @@
+use crate::game::crossings::CrossingKind;
+use crate::game::{CrossingOutcomeTelemetry, RunTimeline, TimelineDay};
+use crate::i18n;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::rc::Rc;
+use yew::prelude::*;
+
+const CHART_WIDTH: f32 = 300.0;
+const CHART_HEIGHT: f32 = 80.0;
+
+/// Stat series charted on the trajectory plot, with the clamp ceiling used to normalise them.
+const STAT_SERIES: [(&str, &str, i32); 4] = [
+    ("hp", "stats.hp_short", 10),
+    ("sanity", "stats.sanity_short", 10),
+    ("pants", "stats.pants_short", 100),
+    ("supplies", "stats.sup_short", 20),
+];
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    pub timeline: Rc<RunTimeline>,
+}
+
+impl PartialEq for Props {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.timeline, &other.timeline)
+    }
+}
+
+fn day_label(day: u32) -> String {
+    let day = day.to_string();
+    let mut args = BTreeMap::new();
+    args.insert("day", day.as_str());
+    i18n::tr("stats_dashboard.day", Some(&args))
+}
+
+fn stat_value(day: &TimelineDay, key: &str) -> Option<i32> {
+    match key {
+        "hp" => day.hp,
+        "sanity" => day.sanity,
+        "pants" => day.pants,
+        _ => day.supplies,
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn x_step(count: usize) -> f32 {
+    CHART_WIDTH / count.max(1) as f32
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn miles_bars(days: &[TimelineDay]) -> Html {
+    let peak = days
+        .iter()
+        .map(|day| day.miles)
+        .fold(0.0_f32, f32::max)
+        .max(1.0);
+    let step = x_step(days.len());
+    let bars = days.iter().enumerate().map(|(idx, day)| {
+        let height = (day.miles / peak) * CHART_HEIGHT;
+        html! {
+            <rect
+                x={format!("{:.1}", idx as f32 * step)}
+                y={format!("{:.1}", CHART_HEIGHT - height)}
+                width={format!("{:.1}", (step - 1.0).max(1.0))}
+                height={format!("{height:.1}")}
+                class="chart-bar"
+            >
+                <title>{ format!("{}: {:.1}", day_label(u32::from(day.day_index) + 1), day.miles) }</title>
+            </rect>
+        }
+    });
+    html! {
+        <svg viewBox={format!("0 0 {CHART_WIDTH} {CHART_HEIGHT}")} class="run-stats-chart" role="img"
+            aria-label={i18n::t("stats_dashboard.miles_per_day")} data-testid="run-stats-miles">
+            { for bars }
+        </svg>
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn stat_polyline(days: &[TimelineDay], key: &str, ceiling: i32) -> String {
+    let step = x_step(days.len());
+    let mut points = String::new();
+    for (idx, day) in days.iter().enumerate() {
+        let Some(value) = stat_value(day, key) else {
+            continue;
+        };
+        let ratio = value.clamp(0, ceiling) as f32 / ceiling as f32;
+        let x = (idx as f32).mul_add(step, step / 2.0);
+        let _ = write!(points, "{x:.1},{:.1} ", CHART_HEIGHT - ratio * CHART_HEIGHT);
+    }
+    points.trim_end().to_string()
+}
+
+fn stat_trajectories(days: &[TimelineDay]) -> Html {
+    let lines = STAT_SERIES.iter().map(|&(key, label_key, ceiling)| {
+        html! {
+            <polyline points={stat_polyline(days, key, ceiling)} fill="none" class={format!("chart-line chart-line-{key}")}>
+                <title>{ i18n::t(label_key) }</title>
+            </polyline>
+        }
+    });
+    let legend = STAT_SERIES.iter().map(|&(key, label_key, _)| {
+        html! { <li class={format!("chart-legend-{key}")}>{ i18n::t(label_key) }</li> }
+    });
+    html! {
+        <>
+            <svg viewBox={format!("0 0 {CHART_WIDTH} {CHART_HEIGHT}")} class="run-stats-chart" role="img"
+                aria-label={i18n::t("stats_dashboard.trajectories")} data-testid="run-stats-trajectories">
+                { for lines }
+            </svg>
+            <ul class="chart-legend">{ for legend }</ul>
+        </>
+    }
+}
+
+fn weather_items(timeline: &RunTimeline) -> Vec<Html> {
+    timeline
+        .weather_streaks
+        .iter()
+        .filter(|streak| streak.length > 1)
+        .map(|streak| {
+            let weather = i18n::t(&format!("weather.states.{:?}", streak.weather));
+            let length = streak.length.to_string();
+            let start = (u32::from(streak.start_day) + 1).to_string();
+            let mut args = BTreeMap::new();
+            args.insert("weather", weather.as_str());
+            args.insert("days", length.as_str());
+            args.insert("start", start.as_str());
+            html! { <li data-testid="run-stats-weather">{ i18n::tr("stats_dashboard.weather_streak", Some(&args)) }</li> }
+        })
+        .collect()
+}
+
+fn breakdown_items(timeline: &RunTimeline) -> Vec<Html> {
+    timeline
+        .breakdowns
+        .iter()
+        .map(|span| {
+            let part = i18n::t(span.part.key());
+            let days = span.days.to_string();
+            let mut args = BTreeMap::new();
+            args.insert("part", part.as_str());
+            args.insert("days", days.as_str());
+            html! {
+                <li data-testid="run-stats-breakdown">
+                    { format!("{} — {}", day_label(u32::from(span.start_day) + 1), i18n::tr("stats_dashboard.breakdown", Some(&args))) }
+                </li>
+            }
+        })
+        .collect()
+}
+
+fn encounter_items(timeline: &RunTimeline) -> Vec<Html> {
+    timeline
+        .encounters
+        .iter()
+        .map(|record| {
+            html! {
+                <li data-testid="run-stats-encounter">
+                    { format!("{} — {}: {}", day_label(record.day), record.name, record.choice_label) }
+                </li>
+            }
+        })
+        .collect()
+}
+
+fn crossing_items(timeline: &RunTimeline) -> Vec<Html> {
+    timeline
+        .crossings
+        .iter()
+        .map(|crossing| {
+            let kind = match crossing.kind {
+                CrossingKind::Checkpoint => i18n::t("cross.types.checkpoint"),
+                CrossingKind::BridgeOut => i18n::t("cross.types.bridge_out"),
+            };
+            let outcome = match crossing.outcome {
+                CrossingOutcomeTelemetry::Passed => i18n::t("stats_dashboard.crossing.passed"),
+                CrossingOutcomeTelemetry::Detoured => i18n::t("stats_dashboard.crossing.detoured"),
+                CrossingOutcomeTelemetry::Failed => i18n::t("stats_dashboard.crossing.failed"),
+            };
+            html! {
+                <li data-testid="run-stats-crossing">
+                    { format!("{} — {kind}: {outcome}", day_label(crossing.day)) }
+                </li>
+            }
+        })
+        .collect()
+}
+
+fn list_section(title_key: &str, items: Vec<Html>) -> Html {
+    if items.is_empty() {
+        return Html::default();
+    }
+    html! {
+        <div class="run-stats-list">
+            <h4>{ i18n::t(title_key) }</h4>
+            <ul class="text-xs">{ for items }</ul>
+        </div>
+    }
+}
+
+/// Charts and event lists derived from a run's day ledger.
+#[function_component(RunStatsDashboard)]
+pub fn run_stats_dashboard(p: &Props) -> Html {
+    let timeline = &p.timeline;
+    if timeline.days.is_empty() {
+        return html! {
+            <section class="run-stats" data-testid="run-stats">
+                <p class="text-xs opacity-70" data-testid="run-stats-empty">{ i18n::t("stats_dashboard.empty") }</p>
+            </section>
+        };
+    }
+
+    html! {
+        <section class="run-stats" aria-labelledby="run-stats-title" data-testid="run-stats">
+            <h3 id="run-stats-title">{ i18n::t("stats_dashboard.title") }</h3>
+            <h4>{ i18n::t("stats_dashboard.miles_per_day") }</h4>
+            { miles_bars(&timeline.days) }
+            <h4>{ i18n::t("stats_dashboard.trajectories") }</h4>
+            { stat_trajectories(&timeline.days) }
+            { list_section("stats_dashboard.weather_title", weather_items(timeline)) }
+            { list_section("stats_dashboard.breakdowns_title", breakdown_items(timeline)) }
+            { list_section("stats_dashboard.encounters_title", encounter_items(timeline)) }
+            { list_section("stats_dashboard.crossings_title", crossing_items(timeline)) }
+        </section>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::journey::{DayRecord, EndOfDaySnapshot, TravelDayKind};
+    use crate::game::state::EncounterChoiceRecord;
+    use crate::game::vehicle::Part;
+    use crate::game::weather::Weather;
+    use crate::game::{GameState, Stats};
+    use futures::executor::block_on;
+    use yew::LocalServerRenderer;
+
+    fn render(timeline: RunTimeline) -> String {
+        crate::i18n::set_lang("en");
+        block_on(
+            LocalServerRenderer::<RunStatsDashboard>::with_props(Props {
+                timeline: Rc::new(timeline),
+            })
+            .render(),
+        )
+    }
+
+    fn sample_state() -> GameState {
+        let mut state = GameState::default();
+        for (idx, weather) in [Weather::Storm, Weather::Storm, Weather::Clear]
+            .into_iter()
+            .enumerate()
+        {
+            let day = u16::try_from(idx).unwrap();
+            let mut record = DayRecord::new(day, TravelDayKind::Travel, 10.0);
+            record.snapshot = Some(EndOfDaySnapshot {
+                stats: Stats::default(),
+                weather,
+                breakdown: (idx == 2).then_some(Part::Battery),
+                ..state.day_snapshot()
+            });
+            state.day_records.push(record);
+        }
+        state.encounter_log.push(EncounterChoiceRecord {
+            day: 2,
+            encounter_id: "rally".to_string(),
+            name: "Roadside Rally".to_string(),
+            choice_index: 0,
+            choice_label: "Join in".to_string(),
+        });
+        state
+    }
+
+    #[test]
+    fn empty_timeline_renders_placeholder() {
+        let html = render(RunTimeline::from_state(&GameState::default()));
+        assert!(html.contains("run-stats-empty"));
+        assert!(!html.contains("run-stats-miles"));
+    }
+
+    #[test]
+    fn dashboard_renders_charts_and_lists() {
+        let html = render(RunTimeline::from_state(&sample_state()));
+        assert!(html.contains("run-stats-miles"));
+        assert!(html.contains("run-stats-trajectories"));
+        assert_eq!(html.matches("chart-bar").count(), 3);
+        assert_eq!(html.matches("data-testid=\"run-stats-weather\"").count(), 1);
+        assert_eq!(
+            html.matches("data-testid=\"run-stats-breakdown\"").count(),
+            1
+        );
+        assert!(html.contains("Roadside Rally: Join in"));
+        assert!(html.contains("Battery"));
+    }
+
+    #[test]
+    fn polyline_normalises_to_ceiling() {
+        let state = sample_state();
+        let timeline = RunTimeline::from_state(&state);
+        let points = stat_polyline(&timeline.days, "hp", 10);
+        assert_eq!(points, "50.0,0.0 150.0,0.0 250.0,0.0");
+        let pants = stat_polyline(&timeline.days, "pants", 100);
+        assert!(pants.ends_with(",80.0"));
+    }
+}
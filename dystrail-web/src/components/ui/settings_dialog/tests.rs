@@ -1,4 +1,7 @@
-use super::{Props, SettingsDialog, view::toggle_high_contrast};
+use super::{
+    Props, SettingsDialog,
+    view::{toggle_decision_traces, toggle_high_contrast},
+};
 use futures::executor::block_on;
 use yew::Callback;
 use yew::LocalServerRenderer;
@@ -11,6 +14,7 @@ fn settings_dialog_returns_empty_when_closed() {
         open: false,
         on_close: Callback::noop(),
         on_hc_changed: Callback::noop(),
+        on_traces_changed: Callback::noop(),
     };
     let html = block_on(LocalServerRenderer::<SettingsDialog>::with_props(props).render());
     assert!(!html.contains("drawer-body"));
@@ -23,9 +27,11 @@ fn settings_dialog_renders_controls_when_open() {
         open: true,
         on_close: Callback::noop(),
         on_hc_changed: Callback::noop(),
+        on_traces_changed: Callback::noop(),
     };
     let html = block_on(LocalServerRenderer::<SettingsDialog>::with_props(props).render());
     assert!(html.contains("High contrast"));
+    assert!(html.contains("traces-toggle"));
 }
 
 #[test]
@@ -52,3 +58,28 @@ fn toggle_high_contrast_updates_state() {
     let html = block_on(LocalServerRenderer::<HcHarness>::new().render());
     assert!(html.contains("data-hc=\"true\""));
 }
+
+#[test]
+fn toggle_decision_traces_notifies_listener() {
+    #[function_component(TracesHarness)]
+    fn traces_harness() -> Html {
+        let traces = use_state(|| false);
+        let called = use_mut_ref(|| false);
+        let observed = use_mut_ref(|| String::from("false"));
+        let on_change = {
+            let observed = observed.clone();
+            Callback::from(move |next: bool| {
+                *observed.borrow_mut() = next.to_string();
+            })
+        };
+        if !*called.borrow() {
+            *called.borrow_mut() = true;
+            toggle_decision_traces(&traces, &on_change);
+        }
+        let observed_value = observed.borrow().clone();
+        html! { <div data-traces={observed_value} /> }
+    }
+
+    let html = block_on(LocalServerRenderer::<TracesHarness>::new().render());
+    assert!(html.contains("data-traces=\"true\""));
+}
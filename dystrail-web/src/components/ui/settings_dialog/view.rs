@@ -7,6 +7,8 @@ pub struct Props {
     pub on_close: Callback<()>,
     #[prop_or_default]
     pub on_hc_changed: Callback<bool>,
+    #[prop_or_default]
+    pub on_traces_changed: Callback<bool>,
 }
 
 pub(super) fn toggle_high_contrast(hc: &UseStateHandle<bool>, hc_cb: &Callback<bool>) {
@@ -16,6 +18,13 @@ pub(super) fn toggle_high_contrast(hc: &UseStateHandle<bool>, hc_cb: &Callback<b
     hc_cb.emit(next);
 }
 
+pub(super) fn toggle_decision_traces(traces: &UseStateHandle<bool>, traces_cb: &Callback<bool>) {
+    let next = !**traces;
+    traces.set(next);
+    crate::components::ui::decision_trace_overlay::set_overlay_enabled(next);
+    traces_cb.emit(next);
+}
+
 #[function_component(SettingsDialog)]
 pub fn settings_dialog(p: &Props) -> Html {
     let ref_node = use_node_ref();
@@ -24,6 +33,7 @@ pub fn settings_dialog(p: &Props) -> Html {
         Callback::from(move |_| cb.emit(()))
     };
     let hc = use_state(crate::a11y::high_contrast_enabled);
+    let traces = use_state(crate::components::ui::decision_trace_overlay::overlay_enabled);
 
     use_focus_management(p.open, ref_node.clone());
 
@@ -39,6 +49,12 @@ pub fn settings_dialog(p: &Props) -> Html {
         Callback::from(move |_| toggle_high_contrast(&hc, &hc_cb))
     };
 
+    let on_toggle_traces = {
+        let traces = traces.clone();
+        let traces_cb = p.on_traces_changed.clone();
+        Callback::from(move |_| toggle_decision_traces(&traces, &traces_cb))
+    };
+
     html! {
       <div class="drawer" role="dialog" aria-modal="true" aria-labelledby="settings-title" ref={ref_node} onkeydown={on_keydown}>
         <div class="drawer-body">
@@ -47,6 +63,10 @@ pub fn settings_dialog(p: &Props) -> Html {
             <label for="hc-toggle"><strong>{ crate::i18n::t("ui.hc_toggle") }</strong></label>
             <input id="hc-toggle" type="checkbox" checked={*hc} onclick={on_toggle_hc} />
           </div>
+          <div class="field">
+            <label for="traces-toggle"><strong>{ crate::i18n::t("debug_traces.toggle") }</strong></label>
+            <input id="traces-toggle" type="checkbox" checked={*traces} onclick={on_toggle_traces} />
+          </div>
           <div class="controls">
             <button onclick={close}>{ crate::i18n::t("dialogs.close") }</button>
           </div>
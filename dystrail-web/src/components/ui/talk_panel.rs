@@ -0,0 +1,109 @@
+use crate::game::{GameState, TalkOutcome};
+use crate::i18n;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use yew::prelude::*;
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    pub state: Rc<GameState>,
+}
+
+impl PartialEq for Props {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+fn talk_lines(outcome: &TalkOutcome) -> Vec<String> {
+    let depth = outcome
+        .context
+        .river_depth_ft
+        .map(|depth| format!("{depth:.1}"))
+        .unwrap_or_default();
+    let mut args = BTreeMap::new();
+    args.insert("depth", depth.as_str());
+    outcome
+        .lines
+        .iter()
+        .map(|line| i18n::tr(&line.key, Some(&args)))
+        .collect()
+}
+
+/// "Talk to people" at landmark and store stops; talking never advances the day.
+#[function_component(TalkPanel)]
+pub fn talk_panel(p: &Props) -> Html {
+    let open = use_state(|| false);
+    let Some(outcome) = p.state.talk_at_landmark() else {
+        return Html::default();
+    };
+    let on_toggle = {
+        let open = open.clone();
+        Callback::from(move |_| open.set(!*open))
+    };
+    let label = if *open {
+        i18n::t("dialogue.talk.hide")
+    } else {
+        i18n::t("dialogue.talk.button")
+    };
+    html! {
+        <section class="panel talk-panel" aria-live="polite">
+            <button class="retro-btn-secondary" onclick={on_toggle} aria-expanded={open.to_string()}>
+                { label }
+            </button>
+            if *open {
+                <h3>{ i18n::t("dialogue.talk.title") }</h3>
+                <ul class="talk-lines">
+                    { for talk_lines(&outcome).into_iter().map(|line| html! { <li>{ line }</li> }) }
+                </ul>
+            }
+        </section>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{MechanicalPolicyId, OtDeluxeStartConfig};
+    use futures::executor::block_on;
+    use yew::LocalServerRenderer;
+
+    #[test]
+    fn talk_lines_fill_in_the_river_depth() {
+        crate::i18n::set_lang("en");
+        let mut state = GameState {
+            mechanical_policy: MechanicalPolicyId::OtDeluxe90s,
+            seed: 7,
+            ..GameState::default()
+        };
+        state.apply_otdeluxe_start_config(&OtDeluxeStartConfig::default());
+        let outcome = state
+            .talk_at_landmark()
+            .expect("Independence has townsfolk");
+        let lines = talk_lines(&outcome);
+        assert_eq!(lines.len(), outcome.lines.len());
+        assert!(lines.iter().all(|line| !line.contains("{depth}")));
+        assert!(lines.iter().all(|line| !line.starts_with("dialogue.")));
+    }
+
+    #[test]
+    fn talk_panel_hides_where_nobody_is_around() {
+        crate::i18n::set_lang("en");
+        let mut state = GameState {
+            mechanical_policy: MechanicalPolicyId::OtDeluxe90s,
+            ..GameState::default()
+        };
+        state.ot_deluxe.route.current_node_index = 6;
+        let props = Props {
+            state: Rc::new(state),
+        };
+        let html = block_on(LocalServerRenderer::<TalkPanel>::with_props(props).render());
+        assert!(!html.contains("talk-panel"));
+
+        let props = Props {
+            state: Rc::new(GameState::default()),
+        };
+        let html = block_on(LocalServerRenderer::<TalkPanel>::with_props(props).render());
+        assert!(html.contains("Talk to people"), "{html}");
+    }
+}
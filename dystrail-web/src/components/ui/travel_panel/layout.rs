@@ -13,6 +13,8 @@ pub enum PanelMode {
 pub struct IntentActions<'a> {
     pub on_trade: &'a Callback<MouseEvent>,
     pub on_hunt: &'a Callback<MouseEvent>,
+    pub on_fish: &'a Callback<MouseEvent>,
+    pub on_forage: &'a Callback<MouseEvent>,
 }
 
 pub struct PanelContext<'a> {
@@ -133,6 +135,12 @@ fn render_footer(ctx: &PanelContext<'_>) -> Html {
                 <button onclick={actions.on_hunt.clone()} aria-label={i18n::t("travel.hunt")} class="retro-btn-secondary">
                     { i18n::t("travel.hunt") }
                 </button>
+                <button onclick={actions.on_fish.clone()} aria-label={i18n::t("travel.fish")} class="retro-btn-secondary">
+                    { i18n::t("travel.fish") }
+                </button>
+                <button onclick={actions.on_forage.clone()} aria-label={i18n::t("travel.forage")} class="retro-btn-secondary">
+                    { i18n::t("travel.forage") }
+                </button>
             </>
         }
     });
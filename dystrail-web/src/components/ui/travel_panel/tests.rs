@@ -59,6 +59,8 @@ fn travel_panel_render_includes_weather_and_breakdown() {
             on_travel: Callback::noop(),
             on_trade: Callback::noop(),
             on_hunt: Callback::noop(),
+            on_fish: Callback::noop(),
+            on_forage: Callback::noop(),
             on_open_inventory: Callback::noop(),
             on_open_pace_diet: Callback::noop(),
             on_open_map: Callback::noop(),
@@ -141,9 +143,13 @@ fn panel_harness(props: &PanelHarnessProps) -> Html {
     let on_toggle_weather_details = Callback::from(|_e: MouseEvent| {});
     let on_trade = Callback::from(|_e: MouseEvent| {});
     let on_hunt = Callback::from(|_e: MouseEvent| {});
+    let on_fish = Callback::from(|_e: MouseEvent| {});
+    let on_forage = Callback::from(|_e: MouseEvent| {});
     let intent_actions = props.show_intents.then_some(IntentActions {
         on_trade: &on_trade,
         on_hunt: &on_hunt,
+        on_fish: &on_fish,
+        on_forage: &on_forage,
     });
 
     let ctx = PanelContext {
@@ -181,6 +187,8 @@ fn render_panel_main_renders_logs_and_intents() {
     assert!(html.contains("Entry"));
     assert!(html.contains("Trade"));
     assert!(html.contains("Hunt"));
+    assert!(html.contains("Fish"));
+    assert!(html.contains("Forage"));
 }
 
 #[test]
@@ -386,6 +394,8 @@ fn travel_panel_props_eq_tracks_logs_and_pace_diet() {
             on_travel: Callback::noop(),
             on_trade: Callback::noop(),
             on_hunt: Callback::noop(),
+            on_fish: Callback::noop(),
+            on_forage: Callback::noop(),
             on_open_inventory: Callback::noop(),
             on_open_pace_diet: Callback::noop(),
             on_open_map: Callback::noop(),
@@ -61,6 +61,8 @@ pub struct Props {
     pub on_travel: Callback<()>,
     pub on_trade: Callback<()>,
     pub on_hunt: Callback<()>,
+    pub on_fish: Callback<()>,
+    pub on_forage: Callback<()>,
     pub on_open_inventory: Callback<()>,
     pub on_open_pace_diet: Callback<()>,
     pub on_open_map: Callback<()>,
@@ -85,6 +87,8 @@ pub fn travel_panel(p: &Props) -> Html {
     let trigger_travel = p.on_travel.clone();
     let trigger_trade = p.on_trade.clone();
     let trigger_hunt = p.on_hunt.clone();
+    let trigger_fish = p.on_fish.clone();
+    let trigger_forage = p.on_forage.clone();
     let open_inventory_click = p.on_open_inventory.clone();
     let open_pace_diet_click = p.on_open_pace_diet.clone();
     let open_map_click = p.on_open_map.clone();
@@ -104,6 +108,14 @@ pub fn travel_panel(p: &Props) -> Html {
         let cb = trigger_hunt.clone();
         Callback::from(move |_| cb.emit(()))
     };
+    let on_fish_click: Callback<MouseEvent> = {
+        let cb = trigger_fish.clone();
+        Callback::from(move |_| cb.emit(()))
+    };
+    let on_forage_click: Callback<MouseEvent> = {
+        let cb = trigger_forage.clone();
+        Callback::from(move |_| cb.emit(()))
+    };
 
     let on_open_inventory = Callback::from(move |_e: MouseEvent| open_inventory_click.emit(()));
     let on_open_pace_diet = Callback::from(move |_e: MouseEvent| open_pace_diet_click.emit(()));
@@ -146,6 +158,8 @@ pub fn travel_panel(p: &Props) -> Html {
     let intent_actions = show_otdeluxe_intents.then_some(IntentActions {
         on_trade: &on_trade_click,
         on_hunt: &on_hunt_click,
+        on_fish: &on_fish_click,
+        on_forage: &on_forage_click,
     });
 
     let on_keydown = {
@@ -180,6 +194,14 @@ pub fn travel_panel(p: &Props) -> Html {
                     trigger_hunt.emit(());
                     e.prevent_default();
                 }
+                "f" | "F" if intents_enabled => {
+                    trigger_fish.emit(());
+                    e.prevent_default();
+                }
+                "g" | "G" if intents_enabled => {
+                    trigger_forage.emit(());
+                    e.prevent_default();
+                }
                 _ => {}
             })
         }
@@ -189,6 +211,8 @@ pub fn travel_panel(p: &Props) -> Html {
                 trigger_travel,
                 trigger_trade,
                 trigger_hunt,
+                trigger_fish,
+                trigger_forage,
                 open_inventory,
                 open_pace_diet,
                 open_map,
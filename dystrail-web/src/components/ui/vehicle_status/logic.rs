@@ -1,4 +1,5 @@
 use crate::game::Part;
+use crate::game::vehicle::VehicleConfig;
 use crate::i18n;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -20,11 +21,13 @@ pub(super) fn evaluate_selection(
     breakdown_part: Option<Part>,
     spare_counts: Option<(i32, i32, i32, i32)>,
 ) -> SelectionResolution {
+    let costs = &VehicleConfig::default_config().repair_costs;
     let used_spare_message = |part: Part| {
         let part_name = i18n::t(part.key());
+        let sup = costs.use_spare_supplies.to_string();
         let mut vars = std::collections::BTreeMap::new();
         vars.insert("part", part_name.as_str());
-        vars.insert("sup", "1");
+        vars.insert("sup", sup.as_str());
         i18n::tr("vehicle.announce.used_spare", Some(&vars))
     };
     let missing_spare_message = |part: Part| {
@@ -71,10 +74,13 @@ pub(super) fn evaluate_selection(
         },
         5 => {
             if breakdown_part.is_some() {
+                let sup = costs.hack_supplies.to_string();
+                let cred = costs.hack_cred.to_string();
+                let day = costs.hack_day.to_string();
                 let mut vars = std::collections::BTreeMap::new();
-                vars.insert("sup", "3");
-                vars.insert("cred", "1");
-                vars.insert("day", "1");
+                vars.insert("sup", sup.as_str());
+                vars.insert("cred", cred.as_str());
+                vars.insert("day", day.as_str());
                 SelectionResolution::Action(
                     VehicleAction::HackFix,
                     i18n::tr("vehicle.announce.hack_applied", Some(&vars)),
@@ -18,6 +18,16 @@ fn storage_key(save_name: &str) -> String {
     format!("dystrail.save.v{SAVE_SCHEMA_VERSION}.{save_name}")
 }
 
+#[cfg(target_arch = "wasm32")]
+fn history_storage_key() -> String {
+    format!("dystrail.history.v{RUN_HISTORY_SCHEMA_VERSION}")
+}
+
+#[cfg(target_arch = "wasm32")]
+fn graveyard_storage_key() -> String {
+    format!("dystrail.graveyard.v{GRAVEYARD_SCHEMA_VERSION}")
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum WebDataError {
     #[error("Network error: {0}")]
@@ -158,6 +168,96 @@ impl GameStorage for WebGameStorage {
     }
 }
 
+impl RunHistoryStorage for WebGameStorage {
+    type Error = WebStorageError;
+
+    fn load_history(&self) -> Result<RunHistory, Self::Error> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let storage = dom::local_storage()
+                .map_err(|err| WebStorageError::Storage(dom::js_error_message(&err)))?;
+            let value = storage
+                .get_item(&history_storage_key())
+                .map_err(|err| WebStorageError::Storage(dom::js_error_message(&err)))?;
+            match value {
+                Some(json) => Ok(RunHistory::from_json(&json)?),
+                None => Ok(RunHistory::default()),
+            }
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            Err(WebStorageError::Storage(String::from(
+                "Storage unavailable",
+            )))
+        }
+    }
+
+    fn save_history(&self, history: &RunHistory) -> Result<(), Self::Error> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let storage = dom::local_storage()
+                .map_err(|err| WebStorageError::Storage(dom::js_error_message(&err)))?;
+            let serialized = serde_json::to_string(history)?;
+            storage
+                .set_item(&history_storage_key(), &serialized)
+                .map_err(|err| WebStorageError::Storage(dom::js_error_message(&err)))?;
+            Ok(())
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = history;
+            Err(WebStorageError::Storage(String::from(
+                "Storage unavailable",
+            )))
+        }
+    }
+}
+
+impl GraveyardStorage for WebGameStorage {
+    type Error = WebStorageError;
+
+    fn load_graveyard(&self) -> Result<Graveyard, Self::Error> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let storage = dom::local_storage()
+                .map_err(|err| WebStorageError::Storage(dom::js_error_message(&err)))?;
+            let value = storage
+                .get_item(&graveyard_storage_key())
+                .map_err(|err| WebStorageError::Storage(dom::js_error_message(&err)))?;
+            match value {
+                Some(json) => Ok(Graveyard::from_json(&json)?),
+                None => Ok(Graveyard::default()),
+            }
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            Err(WebStorageError::Storage(String::from(
+                "Storage unavailable",
+            )))
+        }
+    }
+
+    fn save_graveyard(&self, graveyard: &Graveyard) -> Result<(), Self::Error> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let storage = dom::local_storage()
+                .map_err(|err| WebStorageError::Storage(dom::js_error_message(&err)))?;
+            let serialized = serde_json::to_string(graveyard)?;
+            storage
+                .set_item(&graveyard_storage_key(), &serialized)
+                .map_err(|err| WebStorageError::Storage(dom::js_error_message(&err)))?;
+            Ok(())
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = graveyard;
+            Err(WebStorageError::Storage(String::from(
+                "Storage unavailable",
+            )))
+        }
+    }
+}
+
 /// Create a web-compatible game engine with `WebDataLoader` and `WebGameStorage`
 #[must_use]
 pub const fn create_web_game_engine() -> dystrail_game::GameEngine<WebDataLoader, WebGameStorage> {
@@ -290,4 +390,32 @@ mod tests {
             .expect_err("delete should fail without storage");
         assert!(matches!(err, WebStorageError::Storage(_)));
     }
+
+    #[test]
+    fn web_history_errors_without_browser_storage() {
+        let storage = WebGameStorage;
+        let err = storage
+            .load_history()
+            .expect_err("history load should fail without storage");
+        assert!(matches!(err, WebStorageError::Storage(_)));
+
+        let err = storage
+            .save_history(&RunHistory::default())
+            .expect_err("history save should fail without storage");
+        assert!(matches!(err, WebStorageError::Storage(_)));
+    }
+
+    #[test]
+    fn web_graveyard_errors_without_browser_storage() {
+        let storage = WebGameStorage;
+        let err = storage
+            .load_graveyard()
+            .expect_err("graveyard load should fail without storage");
+        assert!(matches!(err, WebStorageError::Storage(_)));
+
+        let err = storage
+            .save_graveyard(&Graveyard::default())
+            .expect_err("graveyard save should fail without storage");
+        assert!(matches!(err, WebStorageError::Storage(_)));
+    }
 }
@@ -10,10 +10,7 @@ fn get_nested_value<'a>(obj: &'a Value, key: &str) -> Option<&'a Value> {
     let mut current = obj;
 
     for k in keys {
-        match current.get(k) {
-            Some(value) => current = value,
-            None => return None,
-        }
+        current = current.get(k)?;
     }
     Some(current)
 }
@@ -61,22 +58,19 @@ fn render_value(value: &Value, lang: &str, args: Option<&BTreeMap<&str, &str>>)
             if let Some(count_str) = args.and_then(|m| m.get("count")).copied() {
                 if let Ok(count) = count_str.parse::<f64>() {
                     let category = plural_category(lang, count);
-                    if let Some(s) = map.get(&category).and_then(Value::as_str) {
-                        s.to_string()
-                    } else if let Some(default) = map.get("_").and_then(Value::as_str) {
-                        default.to_string()
-                    } else {
-                        return None;
-                    }
+                    map.get(&category)
+                        .and_then(Value::as_str)
+                        .or_else(|| map.get("_").and_then(Value::as_str))
+                        .map(std::string::ToString::to_string)?
                 } else {
                     map.get("_")
                         .and_then(Value::as_str)
                         .map(std::string::ToString::to_string)?
                 }
-            } else if let Some(default) = map.get("_").and_then(Value::as_str) {
-                default.to_string()
             } else {
-                return None;
+                map.get("_")
+                    .and_then(Value::as_str)
+                    .map(std::string::ToString::to_string)?
             }
         }
         _ => return None,
@@ -0,0 +1,205 @@
+use crate::game::{GameMode, MechanicalPolicyId, RunHistory, RunHistoryEntry};
+use std::collections::BTreeMap;
+use yew::prelude::*;
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct HistoryPageProps {
+    pub history: RunHistory,
+    pub on_back: Callback<()>,
+    pub on_export_json: Callback<()>,
+    pub on_export_csv: Callback<()>,
+}
+
+const MODE_SLOTS: [(GameMode, MechanicalPolicyId); 4] = [
+    (GameMode::Classic, MechanicalPolicyId::DystrailLegacy),
+    (GameMode::Deep, MechanicalPolicyId::DystrailLegacy),
+    (GameMode::Classic, MechanicalPolicyId::OtDeluxe90s),
+    (GameMode::Deep, MechanicalPolicyId::OtDeluxe90s),
+];
+
+fn mode_label(mode: GameMode, mechanics: MechanicalPolicyId) -> String {
+    let mode_key = match mode {
+        GameMode::Classic => "mode.classic",
+        GameMode::Deep => "mode.deep",
+    };
+    let mechanics_key = match mechanics {
+        MechanicalPolicyId::DystrailLegacy => "history.mechanics.dystrail",
+        MechanicalPolicyId::OtDeluxe90s => "history.mechanics.otdeluxe",
+    };
+    format!(
+        "{} · {}",
+        crate::i18n::t(mode_key),
+        crate::i18n::t(mechanics_key)
+    )
+}
+
+fn run_stats_line(entry: &RunHistoryEntry) -> String {
+    let days = entry.days.to_string();
+    let miles = format!("{:.0}", entry.miles_traveled);
+    let score = entry.score.to_string();
+    let mut args = BTreeMap::new();
+    args.insert("score", score.as_str());
+    args.insert("days", days.as_str());
+    args.insert("miles", miles.as_str());
+    crate::i18n::tr("history.run_stats", Some(&args))
+}
+
+fn render_mode_bests(history: &RunHistory) -> Html {
+    let bests: Vec<Html> = MODE_SLOTS
+        .iter()
+        .filter_map(|&(mode, mechanics)| {
+            history.personal_best_for_mode(mode, mechanics).map(|best| {
+                html! {
+                    <li data-testid="history-mode-best">
+                        <span class="font-bold">{ mode_label(mode, mechanics) }</span>
+                        { " — " }
+                        <span>{ best.share_code.clone() }</span>
+                        { " · " }
+                        <span>{ run_stats_line(best) }</span>
+                    </li>
+                }
+            })
+        })
+        .collect();
+    if bests.is_empty() {
+        return Html::default();
+    }
+    html! {
+        <section aria-labelledby="history-bests-title" class="w-full text-left">
+            <h2 id="history-bests-title" class="text-sm font-bold">{ crate::i18n::t("history.bests_title") }</h2>
+            <ul class="text-xs space-y-1">{ for bests }</ul>
+        </section>
+    }
+}
+
+fn render_run(history: &RunHistory, entry: &RunHistoryEntry) -> Html {
+    let is_best = history.is_personal_best(entry);
+    html! {
+        <li class="border-b border-base-content/20 py-1" data-testid="history-run">
+            <div class="flex justify-between gap-2">
+                <span class="font-mono">{ entry.share_code.clone() }</span>
+                { if is_best {
+                    html! { <span class="badge badge-outline rounded-none" data-testid="history-pb">{ crate::i18n::t("history.personal_best") }</span> }
+                } else {
+                    Html::default()
+                } }
+            </div>
+            <div class="text-xs opacity-80">{ mode_label(entry.mode, entry.mechanics) }</div>
+            <div class="text-xs">{ crate::i18n::t(&entry.headline_key) }</div>
+            <div class="text-xs opacity-70">{ run_stats_line(entry) }</div>
+        </li>
+    }
+}
+
+#[function_component(HistoryPage)]
+pub fn history_page(props: &HistoryPageProps) -> Html {
+    let on_back = props.on_back.clone();
+    let on_export_json = props.on_export_json.clone();
+    let on_export_csv = props.on_export_csv.clone();
+    let history = &props.history;
+
+    let runs = if history.is_empty() {
+        html! { <p class="text-xs opacity-70" data-testid="history-empty">{ crate::i18n::t("history.empty") }</p> }
+    } else {
+        html! {
+            <ul class="w-full text-left max-h-80 overflow-y-auto" aria-label={crate::i18n::t("history.runs_title")}>
+                { for history.entries.iter().rev().map(|entry| render_run(history, entry)) }
+            </ul>
+        }
+    };
+
+    html! {
+        <div
+            class="min-h-screen flex items-center justify-center bg-base-300 font-sans shell-screen"
+            data-testid="history-screen"
+        >
+            <div class="card border border-base-content bg-base-200 w-[420px] max-w-full rounded-none shadow-none shell-card">
+                <div class="card-body items-center text-center gap-4">
+                    <h1 class="text-2xl font-bold">{ crate::i18n::t("history.title") }</h1>
+                    { render_mode_bests(history) }
+                    { runs }
+                    <div class="flex w-full gap-2">
+                        <button class="btn btn-ghost flex-1 rounded-none normal-case font-sans shell-btn" disabled={history.is_empty()} onclick={Callback::from(move |_| on_export_json.emit(()))} data-testid="history-export-json">
+                            { crate::i18n::t("history.export_json") }
+                        </button>
+                        <button class="btn btn-ghost flex-1 rounded-none normal-case font-sans shell-btn" disabled={history.is_empty()} onclick={Callback::from(move |_| on_export_csv.emit(()))} data-testid="history-export-csv">
+                            { crate::i18n::t("history.export_csv") }
+                        </button>
+                    </div>
+                    <button class="btn btn-ghost w-full justify-start rounded-none text-left normal-case font-sans shell-btn" onclick={Callback::from(move |_| on_back.emit(()))} data-testid="history-back">
+                        { crate::i18n::t("ui.back") }
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{Ending, RunHistoryEntry};
+    use futures::executor::block_on;
+    use yew::LocalServerRenderer;
+
+    fn entry(share_code: &str, mode: GameMode, score: i32) -> RunHistoryEntry {
+        RunHistoryEntry {
+            share_code: share_code.to_string(),
+            seed: 1,
+            mode,
+            mechanics: MechanicalPolicyId::DystrailLegacy,
+            persona_id: None,
+            ending: Ending::BossVictory,
+            headline_key: "result.headline.victory".to_string(),
+            ending_cause: None,
+            score,
+            passed_threshold: true,
+            days: 12,
+            miles_traveled: 340.0,
+            miles_curve: vec![20.0, 40.0],
+            recorded_at_ms: None,
+        }
+    }
+
+    fn render(history: RunHistory) -> String {
+        crate::i18n::set_lang("en");
+        block_on(
+            LocalServerRenderer::<HistoryPage>::with_props(HistoryPageProps {
+                history,
+                on_back: Callback::noop(),
+                on_export_json: Callback::noop(),
+                on_export_csv: Callback::noop(),
+            })
+            .render(),
+        )
+    }
+
+    #[test]
+    fn empty_history_renders_placeholder() {
+        let html = render(RunHistory::default());
+        assert!(html.contains("history-screen"));
+        assert!(html.contains("history-empty"));
+        assert!(!html.contains("history-mode-best"));
+    }
+
+    #[test]
+    fn runs_render_with_personal_best_badges() {
+        let mut history = RunHistory::default();
+        history.record(entry("CL-ORANGE42", GameMode::Classic, 400));
+        history.record(entry("CL-ORANGE42", GameMode::Classic, 900));
+        history.record(entry("DP-PEACH07", GameMode::Deep, 1_250));
+        let html = render(history);
+        assert_eq!(html.matches("data-testid=\"history-run\"").count(), 3);
+        assert_eq!(html.matches("data-testid=\"history-pb\"").count(), 2);
+        assert_eq!(html.matches("data-testid=\"history-mode-best\"").count(), 2);
+        assert!(html.contains("DP-PEACH07"));
+    }
+
+    #[test]
+    fn mode_label_combines_mode_and_mechanics() {
+        crate::i18n::set_lang("en");
+        let label = mode_label(GameMode::Deep, MechanicalPolicyId::OtDeluxe90s);
+        assert!(label.contains("The Deep End"));
+        assert!(label.contains("Oregon Trail Deluxe"));
+    }
+}
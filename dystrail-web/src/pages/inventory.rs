@@ -1,4 +1,6 @@
-use crate::game::GameState;
+use crate::game::{GameState, MechanicalPolicyId, OtDeluxeCargoItem};
+use crate::i18n;
+use std::collections::BTreeMap;
 use std::rc::Rc;
 use yew::prelude::*;
 
@@ -6,6 +8,168 @@ use yew::prelude::*;
 pub struct InventoryPageProps {
     pub state: Rc<GameState>,
     pub on_back: Callback<()>,
+    pub on_discard: Callback<(OtDeluxeCargoItem, u16)>,
+    /// Treat a party member by index, or the Dystrail traveler with `None`.
+    pub on_treat: Callback<Option<usize>>,
+}
+
+const fn cargo_label_key(item: OtDeluxeCargoItem) -> &'static str {
+    match item {
+        OtDeluxeCargoItem::Food => "otdeluxe.store.items.food.name",
+        OtDeluxeCargoItem::Bullets => "inventory.cargo.bullets",
+        OtDeluxeCargoItem::Clothes => "otdeluxe.store.items.clothes.name",
+        OtDeluxeCargoItem::Wheel => "otdeluxe.store.items.wheel.name",
+        OtDeluxeCargoItem::Axle => "otdeluxe.store.items.axle.name",
+        OtDeluxeCargoItem::Tongue => "otdeluxe.store.items.tongue.name",
+        OtDeluxeCargoItem::Medicine => "otdeluxe.store.items.medicine.name",
+    }
+}
+
+/// How much one press of a discard button throws off the wagon.
+const fn discard_step(item: OtDeluxeCargoItem) -> u16 {
+    match item {
+        OtDeluxeCargoItem::Food => 50,
+        OtDeluxeCargoItem::Bullets => 20,
+        OtDeluxeCargoItem::Clothes
+        | OtDeluxeCargoItem::Wheel
+        | OtDeluxeCargoItem::Axle
+        | OtDeluxeCargoItem::Tongue
+        | OtDeluxeCargoItem::Medicine => 1,
+    }
+}
+
+fn render_herd(state: &GameState) -> Html {
+    if state.mechanical_policy != MechanicalPolicyId::OtDeluxe90s {
+        return Html::default();
+    }
+    let herd = state.otdeluxe_herd();
+    let rows = herd.oxen.iter().map(|ox| {
+        let age = ox.age_years.to_string();
+        let health = ox.health.to_string();
+        let mut vars = BTreeMap::new();
+        vars.insert("name", ox.name.as_str());
+        vars.insert("age", age.as_str());
+        vars.insert("health", health.as_str());
+        let status_key = if ox.injured_days_remaining > 0 {
+            "inventory.herd.injured"
+        } else if ox.sick_days_remaining > 0 {
+            "inventory.herd.sick"
+        } else {
+            "inventory.herd.well"
+        };
+        html! {
+            <li>{ format!("{} ({})", i18n::tr("inventory.herd.ox", Some(&vars)), i18n::t(status_key)) }</li>
+        }
+    });
+    html! {
+        <div class="inventory-herd">
+            <h3 class="muted">{ i18n::t("inventory.herd.title") }</h3>
+            <ul>
+                { if herd.oxen.is_empty() {
+                    html! { <li>{ i18n::t("inventory.herd.none") }</li> }
+                } else {
+                    rows.collect::<Html>()
+                }}
+            </ul>
+        </div>
+    }
+}
+
+fn render_treatment(state: &GameState, on_treat: &Callback<Option<usize>>) -> Html {
+    if state.mechanical_policy != MechanicalPolicyId::OtDeluxe90s {
+        let on_click = on_treat.reform(|_e: MouseEvent| None);
+        return html! {
+            <div class="inventory-medicine">
+                <div role="listitem">{ format!("{}: {}", i18n::t("inventory.medicine"), state.inventory.medicine) }</div>
+                <button class="retro-btn-secondary" onclick={on_click}
+                    disabled={state.illness_days_remaining == 0 || state.inventory.medicine <= 0}>
+                    { i18n::t("inventory.treat.use_medicine") }
+                </button>
+            </div>
+        };
+    }
+    let medicine = state.ot_deluxe.inventory.medicine;
+    let rows = state
+        .ot_deluxe
+        .party
+        .members
+        .iter()
+        .enumerate()
+        .filter(|(_, member)| member.alive && member.has_affliction())
+        .map(|(idx, member)| {
+            let on_click = on_treat.reform(move |_e: MouseEvent| Some(idx));
+            let status_key = if member.treated {
+                "inventory.treat.treated"
+            } else if member.is_sick() {
+                "inventory.herd.sick"
+            } else {
+                "inventory.herd.injured"
+            };
+            let mut vars = BTreeMap::new();
+            vars.insert("name", member.name.as_str());
+            html! {
+                <li class="treat-row">
+                    <span>{ format!("{} ({})", member.name, i18n::t(status_key)) }</span>
+                    <button class="retro-btn-secondary" onclick={on_click}
+                        disabled={member.treated || medicine == 0}>
+                        { i18n::tr("inventory.treat.member", Some(&vars)) }
+                    </button>
+                </li>
+            }
+        })
+        .collect::<Vec<_>>();
+    html! {
+        <div class="inventory-medicine">
+            <h3 class="muted">{ format!("{}: {medicine}", i18n::t("inventory.medicine")) }</h3>
+            <ul>
+                { if rows.is_empty() {
+                    html! { <li>{ i18n::t("inventory.treat.none") }</li> }
+                } else {
+                    rows.into_iter().collect::<Html>()
+                }}
+            </ul>
+        </div>
+    }
+}
+
+fn render_cargo(state: &GameState, on_discard: &Callback<(OtDeluxeCargoItem, u16)>) -> Html {
+    if state.mechanical_policy != MechanicalPolicyId::OtDeluxe90s {
+        return Html::default();
+    }
+    let load = state.otdeluxe_cargo_load();
+    let weight = crate::game::numbers::round_f32_to_i32(load.weight_lbs).to_string();
+    let capacity = crate::game::numbers::round_f32_to_i32(load.capacity_lbs).to_string();
+    let mut vars = BTreeMap::new();
+    vars.insert("weight", weight.as_str());
+    vars.insert("capacity", capacity.as_str());
+    let load_line = i18n::tr("otdeluxe.store.cargo_load", Some(&vars));
+    let inventory = &state.ot_deluxe.inventory;
+    let rows = OtDeluxeCargoItem::ALL.iter().map(|item| {
+        let item = *item;
+        let qty = item.quantity(inventory);
+        let step = discard_step(item);
+        let on_click = on_discard.reform(move |_e: MouseEvent| (item, step));
+        let step_str = step.to_string();
+        let mut vars = BTreeMap::new();
+        vars.insert("qty", step_str.as_str());
+        html! {
+            <div role="listitem" class="cargo-row">
+                <span>{ format!("{}: {qty}", i18n::t(cargo_label_key(item))) }</span>
+                <button class="retro-btn-secondary" onclick={on_click} disabled={qty == 0}>
+                    { i18n::tr("inventory.cargo.discard", Some(&vars)) }
+                </button>
+            </div>
+        }
+    });
+    html! {
+        <div class="inventory-cargo">
+            <h3 class="muted">{ i18n::t("inventory.cargo.title") }</h3>
+            <p class={if load.is_overloaded() { "store-cargo-warning" } else { "muted" }}>{ load_line }</p>
+            <div class="stats-list" role="list">
+                { for rows }
+            </div>
+        </div>
+    }
 }
 
 impl PartialEq for InventoryPageProps {
@@ -25,6 +189,9 @@ pub fn inventory_page(props: &InventoryPageProps) -> Html {
         .collect::<Html>();
 
     let on_back = props.on_back.clone();
+    let cargo = render_cargo(&props.state, &props.on_discard);
+    let herd = render_herd(&props.state);
+    let treatment = render_treatment(&props.state, &props.on_treat);
 
     html! {
         <section class="panel retro-menu" aria-labelledby="inventory-title" data-testid="inventory-screen">
@@ -36,6 +203,9 @@ pub fn inventory_page(props: &InventoryPageProps) -> Html {
                 <div role="listitem">{ format!("{}: {}", crate::i18n::t("inventory.spare_alt"), spares.alt) }</div>
                 <div role="listitem">{ format!("{}: {}", crate::i18n::t("inventory.spare_pump"), spares.pump) }</div>
             </div>
+            { treatment }
+            { herd }
+            { cargo }
             <div class="inventory-tags">
                 <h3 class="muted">{ crate::i18n::t("inventory.tags") }</h3>
                 <ul>
@@ -65,16 +235,22 @@ mod tests {
         let props_a = InventoryPageProps {
             state: state.clone(),
             on_back: Callback::from(|()| ()),
+            on_discard: Callback::noop(),
+            on_treat: Callback::noop(),
         };
         let props_b = InventoryPageProps {
             state,
             on_back: Callback::from(|()| ()),
+            on_discard: Callback::noop(),
+            on_treat: Callback::noop(),
         };
         assert!(props_a == props_b);
 
         let props_c = InventoryPageProps {
             state: Rc::new(GameState::default()),
             on_back: Callback::from(|()| ()),
+            on_discard: Callback::noop(),
+            on_treat: Callback::noop(),
         };
         assert!(props_a != props_c);
     }
@@ -85,11 +261,15 @@ mod tests {
         let props = InventoryPageProps {
             state,
             on_back: Callback::from(|()| ()),
+            on_discard: Callback::noop(),
+            on_treat: Callback::noop(),
         };
         let state = props.state;
         let on_back = props.on_back;
+        let on_discard = props.on_discard;
+        let on_treat = props.on_treat;
         let rendered = html! {
-            <InventoryPage {state} {on_back} />
+            <InventoryPage {state} {on_back} {on_discard} {on_treat} />
         };
         let _ = format!("{rendered:?}");
     }
@@ -101,11 +281,15 @@ mod tests {
         let props = InventoryPageProps {
             state: Rc::new(state),
             on_back: Callback::from(|()| ()),
+            on_discard: Callback::noop(),
+            on_treat: Callback::noop(),
         };
         let state = props.state;
         let on_back = props.on_back;
+        let on_discard = props.on_discard;
+        let on_treat = props.on_treat;
         let rendered = html! {
-            <InventoryPage {state} {on_back} />
+            <InventoryPage {state} {on_back} {on_discard} {on_treat} />
         };
         let _ = format!("{rendered:?}");
     }
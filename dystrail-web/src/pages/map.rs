@@ -1,5 +1,6 @@
-use crate::game::GameState;
+use crate::components::ui::run_stats::RunStatsDashboard;
 use crate::game::state::Region;
+use crate::game::{GameState, RunTimeline};
 use std::rc::Rc;
 use yew::prelude::*;
 
@@ -29,6 +30,7 @@ pub fn map_page(props: &MapPageProps) -> Html {
     let miles_today = crate::i18n::fmt_number(f64::from(props.state.distance_today));
     let region = region_label(props.state.region);
     let on_back = props.on_back.clone();
+    let timeline = Rc::new(RunTimeline::from_state(&props.state));
 
     html! {
         <section class="panel retro-menu" aria-labelledby="map-title" data-testid="map-screen">
@@ -38,6 +40,7 @@ pub fn map_page(props: &MapPageProps) -> Html {
                 <div role="listitem">{ format!("{}: {}", crate::i18n::t("map.distance_today"), miles_today) }</div>
                 <div role="listitem">{ format!("{}: {}", crate::i18n::t("map.region"), region) }</div>
             </div>
+            <RunStatsDashboard {timeline} />
             <div class="controls">
                 <button class="retro-btn-secondary" onclick={Callback::from(move |_| on_back.emit(()))}>
                     { crate::i18n::t("ui.back") }
@@ -79,4 +82,18 @@ mod tests {
         assert!(!rust_belt.is_empty());
         assert!(!beltway.is_empty());
     }
+
+    #[test]
+    fn map_renders_run_stats_dashboard() {
+        crate::i18n::set_lang("en");
+        let html = futures::executor::block_on(
+            yew::LocalServerRenderer::<MapPage>::with_props(MapPageProps {
+                state: Rc::new(GameState::default()),
+                on_back: Callback::noop(),
+            })
+            .render(),
+        );
+        assert!(html.contains("map-screen"));
+        assert!(html.contains("run-stats-empty"));
+    }
 }
@@ -5,6 +5,7 @@ pub enum MenuAction {
     StartJourney,
     About,
     Settings,
+    History,
     Quit,
 }
 
@@ -52,7 +53,8 @@ const fn menu_action_for_index(idx: u8) -> Option<MenuAction> {
         1 => Some(MenuAction::StartJourney),
         2 => Some(MenuAction::About),
         3 => Some(MenuAction::Settings),
-        4 => Some(MenuAction::Quit),
+        4 => Some(MenuAction::History),
+        5 => Some(MenuAction::Quit),
         _ => None,
     }
 }
@@ -75,7 +77,11 @@ mod tests {
             menu_action_for_index(3),
             Some(MenuAction::Settings)
         ));
-        assert!(matches!(menu_action_for_index(4), Some(MenuAction::Quit)));
+        assert!(matches!(
+            menu_action_for_index(4),
+            Some(MenuAction::History)
+        ));
+        assert!(matches!(menu_action_for_index(5), Some(MenuAction::Quit)));
         assert!(menu_action_for_index(9).is_none());
     }
 
@@ -4,6 +4,7 @@ pub mod boss;
 pub mod camp;
 pub mod crossing;
 pub mod encounter;
+pub mod history;
 pub mod inventory;
 pub mod map;
 pub mod menu;
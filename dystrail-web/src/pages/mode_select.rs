@@ -1,21 +1,34 @@
 use crate::game::state::GameMode;
+use crate::game::{OTDELUXE_DEPARTURE_MONTHS, VehicleClass};
 use yew::prelude::*;
 
 #[derive(Properties, Clone, PartialEq)]
 pub struct ModeSelectPageProps {
-    pub on_continue: Callback<GameMode>,
+    /// Emits the chosen mode, departure month (3 = March) and vehicle class.
+    pub on_continue: Callback<(GameMode, u8, VehicleClass)>,
     pub on_back: Callback<()>,
 }
 
-fn emit_selected_mode(selected: Option<GameMode>, cb: &Callback<GameMode>) {
+fn emit_selected_mode(
+    selected: Option<GameMode>,
+    month: u8,
+    class: VehicleClass,
+    cb: &Callback<(GameMode, u8, VehicleClass)>,
+) {
     if let Some(mode) = selected {
-        cb.emit(mode);
+        cb.emit((mode, month, class));
     }
 }
 
+fn departure_month_key(month: u8) -> String {
+    format!("mode.months.{month}")
+}
+
 #[function_component(ModeSelectPage)]
 pub fn mode_select_page(props: &ModeSelectPageProps) -> Html {
     let selected = use_state(|| None::<GameMode>);
+    let month = use_state(|| *OTDELUXE_DEPARTURE_MONTHS.start());
+    let vehicle_class = use_state(VehicleClass::default);
 
     let on_select_classic = {
         let selected = selected.clone();
@@ -28,10 +41,51 @@ pub fn mode_select_page(props: &ModeSelectPageProps) -> Html {
 
     let on_continue = {
         let selected = selected.clone();
+        let month = month.clone();
+        let vehicle_class = vehicle_class.clone();
         let cb = props.on_continue.clone();
-        Callback::from(move |_| emit_selected_mode(*selected, &cb))
+        Callback::from(move |_| emit_selected_mode(*selected, *month, *vehicle_class, &cb))
     };
 
+    let month_buttons = OTDELUXE_DEPARTURE_MONTHS
+        .map(|value| {
+            let month_handle = month.clone();
+            let is_selected = *month == value;
+            html! {
+                <button
+                    type="button"
+                    role="radio"
+                    aria-checked={is_selected.to_string()}
+                    class={classes!("retro-btn-secondary", is_selected.then_some("selected"))}
+                    onclick={Callback::from(move |_| month_handle.set(value))}
+                    data-testid={format!("mode-month-{value}")}
+                >
+                    { crate::i18n::t(&departure_month_key(value)) }
+                </button>
+            }
+        })
+        .collect::<Html>();
+
+    let class_buttons = VehicleClass::ALL
+        .into_iter()
+        .map(|value| {
+            let class_handle = vehicle_class.clone();
+            let is_selected = *vehicle_class == value;
+            html! {
+                <button
+                    type="button"
+                    role="radio"
+                    aria-checked={is_selected.to_string()}
+                    class={classes!("retro-btn-secondary", is_selected.then_some("selected"))}
+                    onclick={Callback::from(move |_| class_handle.set(value))}
+                    data-testid={format!("mode-vehicle-{}", value.key().trim_start_matches("vehicle.classes."))}
+                >
+                    { crate::i18n::t(value.key()) }
+                </button>
+            }
+        })
+        .collect::<Html>();
+
     let on_back = props.on_back.clone();
 
     html! {
@@ -60,6 +114,14 @@ pub fn mode_select_page(props: &ModeSelectPageProps) -> Html {
                     { crate::i18n::t("mode.deep") }
                 </button>
             </div>
+            <h3 id="mode-departure">{ crate::i18n::t("mode.departure") }</h3>
+            <div class="mode-options" role="radiogroup" aria-labelledby="mode-departure">
+                { month_buttons }
+            </div>
+            <h3 id="mode-vehicle">{ crate::i18n::t("mode.vehicle") }</h3>
+            <div class="mode-options" role="radiogroup" aria-labelledby="mode-vehicle">
+                { class_buttons }
+            </div>
             <div class="controls">
                 <button class="retro-btn-secondary" onclick={Callback::from(move |_| on_back.emit(()))} data-testid="mode-back">
                     { crate::i18n::t("ui.back") }
@@ -85,10 +147,30 @@ mod tests {
 
     #[test]
     fn emit_selected_mode_emits_when_chosen() {
-        let seen = Rc::new(Cell::new(None::<GameMode>));
+        let seen = Rc::new(Cell::new(None::<(GameMode, u8, VehicleClass)>));
         let seen_ref = seen.clone();
-        let callback = Callback::from(move |mode| seen_ref.set(Some(mode)));
-        emit_selected_mode(Some(GameMode::Deep), &callback);
-        assert_eq!(seen.get(), Some(GameMode::Deep));
+        let callback = Callback::from(move |choice| seen_ref.set(Some(choice)));
+        emit_selected_mode(None, 4, VehicleClass::Rv, &callback);
+        assert_eq!(seen.get(), None);
+        emit_selected_mode(Some(GameMode::Deep), 5, VehicleClass::SchoolBus, &callback);
+        assert_eq!(
+            seen.get(),
+            Some((GameMode::Deep, 5, VehicleClass::SchoolBus))
+        );
+    }
+
+    #[test]
+    fn departure_month_keys_resolve_for_every_month() {
+        for month in OTDELUXE_DEPARTURE_MONTHS {
+            let key = departure_month_key(month);
+            assert_ne!(crate::i18n::t(&key), key);
+        }
+    }
+
+    #[test]
+    fn vehicle_class_keys_resolve_for_every_class() {
+        for class in VehicleClass::ALL {
+            assert_ne!(crate::i18n::t(class.key()), class.key());
+        }
     }
 }
@@ -1,4 +1,5 @@
 use crate::components::ui::stats_bar::WeatherBadge;
+use crate::game::journey::EventDecisionTrace;
 use crate::game::{GameState, PacingConfig};
 use std::rc::Rc;
 use yew::prelude::*;
@@ -10,9 +11,15 @@ pub struct TravelPageProps {
     pub pacing_config: Rc<PacingConfig>,
     pub weather_badge: WeatherBadge,
     pub data_ready: bool,
+    #[prop_or_default]
+    pub decision_traces: Rc<Vec<EventDecisionTrace>>,
+    #[prop_or_default]
+    pub show_decision_traces: bool,
     pub on_travel: Callback<()>,
     pub on_trade: Callback<()>,
     pub on_hunt: Callback<()>,
+    pub on_fish: Callback<()>,
+    pub on_forage: Callback<()>,
     pub on_open_inventory: Callback<()>,
     pub on_open_pace_diet: Callback<()>,
     pub on_open_map: Callback<()>,
@@ -24,6 +31,8 @@ impl PartialEq for TravelPageProps {
             && self.logs == other.logs
             && Rc::ptr_eq(&self.pacing_config, &other.pacing_config)
             && self.data_ready == other.data_ready
+            && Rc::ptr_eq(&self.decision_traces, &other.decision_traces)
+            && self.show_decision_traces == other.show_decision_traces
     }
 }
 
@@ -49,6 +58,8 @@ pub fn travel_page(props: &TravelPageProps) -> Html {
                 on_travel={props.on_travel.clone()}
                 on_trade={props.on_trade.clone()}
                 on_hunt={props.on_hunt.clone()}
+                on_fish={props.on_fish.clone()}
+                on_forage={props.on_forage.clone()}
                 on_open_inventory={props.on_open_inventory.clone()}
                 on_open_pace_diet={props.on_open_pace_diet.clone()}
                 on_open_map={props.on_open_map.clone()}
@@ -56,6 +67,13 @@ pub fn travel_page(props: &TravelPageProps) -> Html {
                 game_state={Some(props.state.clone())}
                 pacing_config={props.pacing_config.clone()}
             />
+            {
+                if props.show_decision_traces {
+                    html! { <crate::components::ui::decision_trace_overlay::DecisionTraceOverlay traces={props.decision_traces.clone()} /> }
+                } else {
+                    Html::default()
+                }
+            }
             {
                 if props.state.current_encounter.is_some() || props.data_ready {
                     Html::default()
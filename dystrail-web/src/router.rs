@@ -10,6 +10,8 @@ pub enum Route {
     About,
     #[at("/settings")]
     Settings,
+    #[at("/history")]
+    History,
     #[at("/persona")]
     Persona,
     #[at("/mode")]
@@ -51,6 +53,7 @@ impl Route {
             crate::app::Phase::Menu => Self::Menu,
             crate::app::Phase::About => Self::About,
             crate::app::Phase::Settings => Self::Settings,
+            crate::app::Phase::History => Self::History,
             crate::app::Phase::Persona => Self::Persona,
             crate::app::Phase::ModeSelect => Self::ModeSelect,
             crate::app::Phase::Outfitting => Self::Outfitting,
@@ -75,6 +78,7 @@ impl Route {
             Self::Menu => Some(crate::app::Phase::Menu),
             Self::About => Some(crate::app::Phase::About),
             Self::Settings => Some(crate::app::Phase::Settings),
+            Self::History => Some(crate::app::Phase::History),
             Self::Persona => Some(crate::app::Phase::Persona),
             Self::ModeSelect => Some(crate::app::Phase::ModeSelect),
             Self::Outfitting => Some(crate::app::Phase::Outfitting),
@@ -75,11 +75,12 @@ fn about_not_found_and_result_render_expected_ui() {
 #[rustfmt::skip]
 fn travel_camp_encounter_and_boss_render_expected_ui() {
     dystrail_web::i18n::set_lang("en");
-    let travel = block_on(LocalServerRenderer::<TravelPage>::with_props(TravelPageProps { state: Rc::new(base_state()), logs: vec!["log.booting".to_string()], pacing_config: Rc::new(PacingConfig::default()), weather_badge: weather_badge(), data_ready: true, on_travel: Callback::noop(), on_trade: Callback::noop(), on_hunt: Callback::noop(), on_open_inventory: Callback::noop(), on_open_pace_diet: Callback::noop(), on_open_map: Callback::noop() }).render());
+    let travel = block_on(LocalServerRenderer::<TravelPage>::with_props(TravelPageProps { state: Rc::new(base_state()), logs: vec!["log.booting".to_string()], pacing_config: Rc::new(PacingConfig::default()), weather_badge: weather_badge(), data_ready: true, decision_traces: Rc::new(Vec::new()), show_decision_traces: true, on_travel: Callback::noop(), on_trade: Callback::noop(), on_hunt: Callback::noop(), on_fish: Callback::noop(), on_forage: Callback::noop(), on_open_inventory: Callback::noop(), on_open_pace_diet: Callback::noop(), on_open_map: Callback::noop() }).render());
     let camp = block_on(LocalServerRenderer::<CampPage>::with_props(CampPageProps { state: Rc::new(base_state()), camp_config: Rc::new(CampConfig::default()), endgame_config: Rc::new(EndgameTravelCfg::default_config()), weather: weather_badge(), on_state_change: Callback::noop(), on_close: Callback::noop() }).render());
     let encounter = block_on(LocalServerRenderer::<EncounterPage>::with_props(EncounterPageProps { state: Rc::new(base_state()), weather: weather_badge(), on_choice: Callback::noop() }).render());
     let boss = block_on(LocalServerRenderer::<BossPage>::with_props(BossPageProps { state: base_state(), config: BossConfig::load_from_static(), weather: weather_badge(), on_begin: Callback::noop() }).render());
     assert!(travel.contains("travel-shell"));
+    assert!(travel.contains("decision-trace-overlay"));
     assert!(camp.contains("camp-modal"));
     assert!(encounter.contains("Loading encounters"));
     assert!(boss.contains("boss-panel"));
@@ -120,6 +121,8 @@ fn inventory_page_renders_empty_and_present_tags() {
     let empty = block_on(LocalServerRenderer::<InventoryPage>::with_props(InventoryPageProps {
         state: Rc::new(base_state()),
         on_back: Callback::noop(),
+        on_discard: Callback::noop(),
+        on_treat: Callback::noop(),
     }).render());
     assert!(empty.contains(&dystrail_web::i18n::t("inventory.tags_none")));
 
@@ -128,6 +131,51 @@ fn inventory_page_renders_empty_and_present_tags() {
     let tagged = block_on(LocalServerRenderer::<InventoryPage>::with_props(InventoryPageProps {
         state: Rc::new(tagged_state),
         on_back: Callback::noop(),
+        on_discard: Callback::noop(),
+        on_treat: Callback::noop(),
     }).render());
     assert!(tagged.contains("permit"));
 }
+
+#[test]
+#[rustfmt::skip]
+fn inventory_page_lists_otdeluxe_cargo_with_discard_controls() {
+    dystrail_web::i18n::set_lang("en");
+    let mut state = base_state();
+    state.mechanical_policy = MechanicalPolicyId::OtDeluxe90s;
+    state.ot_deluxe.inventory.food_lbs = 300;
+    state.ot_deluxe.oxen.healthy = 4;
+    let html = block_on(LocalServerRenderer::<InventoryPage>::with_props(InventoryPageProps {
+        state: Rc::new(state),
+        on_back: Callback::noop(),
+        on_discard: Callback::noop(),
+        on_treat: Callback::noop(),
+    }).render());
+    assert!(html.contains("Wagon cargo"));
+    assert!(html.contains("Discard 50"));
+    assert!(html.contains("Wagon load: 300 of 2000 lbs"));
+    assert!(html.contains("Buck, age"));
+    assert!(html.contains("health 100"));
+}
+
+#[test]
+#[rustfmt::skip]
+fn inventory_page_offers_treatment_for_afflicted_members() {
+    use dystrail_web::game::otdeluxe_state::{OtDeluxeAfflictionKind, OtDeluxePartyState};
+
+    dystrail_web::i18n::set_lang("en");
+    let mut state = base_state();
+    state.mechanical_policy = MechanicalPolicyId::OtDeluxe90s;
+    state.ot_deluxe.party = OtDeluxePartyState::from_names(["Ada", "Ben"]);
+    state.ot_deluxe.party.members[1].apply_affliction(OtDeluxeAfflictionKind::Illness, 5, Some("cholera"));
+    state.ot_deluxe.inventory.medicine = 2;
+    let html = block_on(LocalServerRenderer::<InventoryPage>::with_props(InventoryPageProps {
+        state: Rc::new(state),
+        on_back: Callback::noop(),
+        on_discard: Callback::noop(),
+        on_treat: Callback::noop(),
+    }).render());
+    assert!(html.contains("Medicine: 2"));
+    assert!(html.contains("Treat Ben"));
+    assert!(!html.contains("Treat Ada"));
+}
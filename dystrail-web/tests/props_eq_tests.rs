@@ -88,7 +88,8 @@ fn page_props_equality_for_otdeluxe_and_travel_routes() {
     let route_a = RoutePromptPageProps { state: state_rc.clone(), prompt: OtDeluxeRoutePrompt::SubletteCutoff, weather: weather_badge(), on_choice: Callback::<OtDeluxeRouteDecision>::noop() };
     let route_b = RoutePromptPageProps { state: state_rc.clone(), prompt: OtDeluxeRoutePrompt::SubletteCutoff, weather: weather_badge(), on_choice: Callback::<OtDeluxeRouteDecision>::noop() };
     assert!(route_a == route_b);
-    let travel_a = TravelPageProps { state: state_rc.clone(), logs: Vec::new(), pacing_config: shared_pacing.clone(), weather_badge: weather_badge(), data_ready: true, on_travel: Callback::noop(), on_trade: Callback::noop(), on_hunt: Callback::noop(), on_open_inventory: Callback::noop(), on_open_pace_diet: Callback::noop(), on_open_map: Callback::noop() };
-    let travel_b = TravelPageProps { state: state_rc, logs: Vec::new(), pacing_config: shared_pacing, weather_badge: weather_badge(), data_ready: true, on_travel: Callback::noop(), on_trade: Callback::noop(), on_hunt: Callback::noop(), on_open_inventory: Callback::noop(), on_open_pace_diet: Callback::noop(), on_open_map: Callback::noop() };
+    let shared_traces = Rc::new(Vec::new());
+    let travel_a = TravelPageProps { state: state_rc.clone(), logs: Vec::new(), pacing_config: shared_pacing.clone(), weather_badge: weather_badge(), data_ready: true, decision_traces: shared_traces.clone(), show_decision_traces: false, on_travel: Callback::noop(), on_trade: Callback::noop(), on_hunt: Callback::noop(), on_fish: Callback::noop(), on_forage: Callback::noop(), on_open_inventory: Callback::noop(), on_open_pace_diet: Callback::noop(), on_open_map: Callback::noop() };
+    let travel_b = TravelPageProps { state: state_rc, logs: Vec::new(), pacing_config: shared_pacing, weather_badge: weather_badge(), data_ready: true, decision_traces: shared_traces, show_decision_traces: false, on_travel: Callback::noop(), on_trade: Callback::noop(), on_hunt: Callback::noop(), on_fish: Callback::noop(), on_forage: Callback::noop(), on_open_inventory: Callback::noop(), on_open_pace_diet: Callback::noop(), on_open_map: Callback::noop() };
     assert!(travel_a == travel_b);
 }